@@ -0,0 +1,783 @@
+//! A registry of the structured data types of a server that the client has no generated code for.
+//! The definitions are read from the server, either from the DataTypeDefinition attribute of
+//! OPC UA 1.04 or from the legacy DataTypeDictionary, and values of those types are decoded from
+//! and encoded to the binary bodies of extension objects as a [`DynamicStructure`].
+//!
+//! [`DynamicStructure`]: ./struct.DynamicStructure.html
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read, Write};
+use std::result::Result;
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ReferenceTypeId};
+use opcua_types::service_types::*;
+use opcua_types::status_code::StatusCode;
+
+use crate::session::Session;
+
+/// The id of the DataTypeDefinition attribute, which was added in OPC UA 1.04
+const DATA_TYPE_DEFINITION_ATTRIBUTE_ID: u32 = 23;
+/// The id of the default binary encoding of a StructureDefinition
+const STRUCTURE_DEFINITION_ENCODING_ID: u32 = 122;
+/// The id of the default binary encoding of an EnumDefinition
+const ENUM_DEFINITION_ENCODING_ID: u32 = 123;
+/// The deepest chain of subtypes that is followed to find the built-in type of a data type
+const MAX_SUBTYPE_DEPTH: usize = 16;
+
+/// How the fields of a structure are encoded
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StructureType {
+    /// Every field is encoded
+    Structure,
+    /// An encoding mask is followed by the mandatory fields and the optional fields whose bit
+    /// is set in the mask
+    StructureWithOptionalFields,
+    /// A switch value is followed by the one field it selects, if any
+    Union,
+}
+
+/// A field of a structured data type
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructureField {
+    /// The name of the field
+    pub name: String,
+    /// The data type of the field
+    pub data_type: NodeId,
+    /// -1 for a scalar, 1 for an array or more for a multi-dimensional array
+    pub value_rank: i32,
+    /// Whether the field is optional in a `StructureWithOptionalFields`
+    pub is_optional: bool,
+}
+
+impl StructureField {
+    pub fn new(name: &str, data_type: NodeId, value_rank: i32, is_optional: bool) -> StructureField {
+        StructureField {
+            name: name.to_string(),
+            data_type,
+            value_rank,
+            is_optional,
+        }
+    }
+}
+
+/// The definition of a structured data type, i.e. its fields in the order they are encoded
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructureDefinition {
+    /// The id of the data type
+    pub data_type_id: NodeId,
+    /// The id of the default binary encoding of the data type
+    pub encoding_id: NodeId,
+    /// The name of the data type
+    pub name: String,
+    /// How the fields are encoded
+    pub structure_type: StructureType,
+    /// The fields of the data type
+    pub fields: Vec<StructureField>,
+}
+
+/// The definition of an enumerated data type, whose values are encoded as an `Int32`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDefinition {
+    /// The id of the data type
+    pub data_type_id: NodeId,
+    /// The name of the data type
+    pub name: String,
+    /// The values of the enumeration and their names
+    pub fields: Vec<(i64, String)>,
+}
+
+/// The value of a structured data type as its field names and values, in the order of the
+/// definition. Fields that are structures hold an `ExtensionObject`, that the registry can also
+/// decode, enumerations hold an `Int32` and arrays hold an `Array`. Optional fields which are
+/// not present and the fields of a union that are not selected are left out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicStructure {
+    /// The id of the data type
+    pub data_type_id: NodeId,
+    /// The field names and values
+    pub fields: Vec<(String, Variant)>,
+}
+
+impl DynamicStructure {
+    pub fn new<T>(data_type_id: T, fields: Vec<(String, Variant)>) -> DynamicStructure where T: Into<NodeId> {
+        DynamicStructure {
+            data_type_id: data_type_id.into(),
+            fields,
+        }
+    }
+
+    /// Returns the value of the field with the name
+    pub fn get(&self, name: &str) -> Option<&Variant> {
+        self.fields.iter().find(|(field_name, _)| field_name == name).map(|(_, value)| value)
+    }
+}
+
+/// How a value of a data type is encoded
+enum ValueType<'a> {
+    /// A built-in type, identified by its data type id
+    BuiltIn(u8),
+    Enumeration,
+    Structure(&'a StructureDefinition),
+}
+
+/// The definitions of the structured and enumerated data types that the client has loaded, with
+/// which it decodes and encodes the values of those types.
+#[derive(Debug, Default)]
+pub struct DataTypeRegistry {
+    /// Structures by their data type id
+    structures: HashMap<NodeId, StructureDefinition>,
+    /// The data type ids of structures by the id of their default binary encoding
+    encodings: HashMap<NodeId, NodeId>,
+    /// Enumerations by their data type id
+    enumerations: HashMap<NodeId, EnumDefinition>,
+    /// The supertypes of data types that are neither structures nor enumerations, e.g. a
+    /// vendor type that is a subtype of `Double`
+    supertypes: HashMap<NodeId, NodeId>,
+}
+
+impl DataTypeRegistry {
+    pub fn new() -> DataTypeRegistry {
+        DataTypeRegistry::default()
+    }
+
+    /// Adds the definition of a structure, replacing any definition of the same data type
+    pub fn add_structure(&mut self, definition: StructureDefinition) {
+        self.encodings.insert(definition.encoding_id.clone(), definition.data_type_id.clone());
+        self.structures.insert(definition.data_type_id.clone(), definition);
+    }
+
+    /// Adds the definition of an enumeration
+    pub fn add_enumeration(&mut self, definition: EnumDefinition) {
+        self.enumerations.insert(definition.data_type_id.clone(), definition);
+    }
+
+    /// Adds a data type whose values are encoded as those of its supertype
+    pub fn add_subtype(&mut self, data_type_id: NodeId, supertype_id: NodeId) {
+        self.supertypes.insert(data_type_id, supertype_id);
+    }
+
+    /// Finds the definition of a structure by its data type id
+    pub fn find_structure(&self, data_type_id: &NodeId) -> Option<&StructureDefinition> {
+        self.structures.get(data_type_id)
+    }
+
+    /// Finds the definition of a structure by the id of its default binary encoding, which is
+    /// the node id of an extension object holding one of its values
+    pub fn find_structure_by_encoding(&self, encoding_id: &NodeId) -> Option<&StructureDefinition> {
+        self.encodings.get(encoding_id).and_then(|data_type_id| self.structures.get(data_type_id))
+    }
+
+    /// Finds the definition of an enumeration by its data type id
+    pub fn find_enumeration(&self, data_type_id: &NodeId) -> Option<&EnumDefinition> {
+        self.enumerations.get(data_type_id)
+    }
+
+    /// Tests if the data type is a built-in type, `Enumeration` or has been added to the registry
+    pub fn contains(&self, data_type_id: &NodeId) -> bool {
+        built_in_type(data_type_id).is_some() ||
+            *data_type_id == DataTypeId::Enumeration.into() ||
+            self.structures.contains_key(data_type_id) ||
+            self.enumerations.contains_key(data_type_id) ||
+            self.supertypes.contains_key(data_type_id)
+    }
+
+    /// Adds a data type from the value of its DataTypeDefinition attribute, which is an extension
+    /// object holding a `StructureDefinition` or an `EnumDefinition`.
+    pub fn add_data_type_definition(&mut self, data_type_id: &NodeId, name: &str, definition: &ExtensionObject, decoding_limits: &DecodingLimits) -> Result<(), StatusCode> {
+        let body = binary_body(definition)?;
+        let mut stream = Cursor::new(body);
+        if definition.node_id == NodeId::new(0, STRUCTURE_DEFINITION_ENCODING_ID) {
+            let mut encoding_id = NodeId::decode(&mut stream, decoding_limits)?;
+            let _base_data_type = NodeId::decode(&mut stream, decoding_limits)?;
+            let structure_type = match i32::decode(&mut stream, decoding_limits)? {
+                0 => StructureType::Structure,
+                1 => StructureType::StructureWithOptionalFields,
+                2 => StructureType::Union,
+                structure_type => {
+                    error!("Data type {:?} has an invalid structure type {}", data_type_id, structure_type);
+                    return Err(StatusCode::BadDecodingError);
+                }
+            };
+            let mut fields = Vec::new();
+            for _ in 0..read_array_length(&mut stream, decoding_limits)? {
+                let name = UAString::decode(&mut stream, decoding_limits)?;
+                let _description = LocalizedText::decode(&mut stream, decoding_limits)?;
+                let data_type = NodeId::decode(&mut stream, decoding_limits)?;
+                let value_rank = i32::decode(&mut stream, decoding_limits)?;
+                let _array_dimensions: Option<Vec<u32>> = read_array(&mut stream, decoding_limits)?;
+                let _max_string_length = u32::decode(&mut stream, decoding_limits)?;
+                let is_optional = bool::decode(&mut stream, decoding_limits)?;
+                fields.push(StructureField::new(name.as_ref(), data_type, value_rank, is_optional));
+            }
+            if encoding_id.is_null() {
+                // The encoding is unknown so values cannot be found by it
+                encoding_id = data_type_id.clone();
+            }
+            self.add_structure(StructureDefinition {
+                data_type_id: data_type_id.clone(),
+                encoding_id,
+                name: name.to_string(),
+                structure_type,
+                fields,
+            });
+            Ok(())
+        } else if definition.node_id == NodeId::new(0, ENUM_DEFINITION_ENCODING_ID) {
+            let mut fields = Vec::new();
+            for _ in 0..read_array_length(&mut stream, decoding_limits)? {
+                let value = i64::decode(&mut stream, decoding_limits)?;
+                let _display_name = LocalizedText::decode(&mut stream, decoding_limits)?;
+                let _description = LocalizedText::decode(&mut stream, decoding_limits)?;
+                let name = UAString::decode(&mut stream, decoding_limits)?;
+                fields.push((value, name.as_ref().to_string()));
+            }
+            self.add_enumeration(EnumDefinition {
+                data_type_id: data_type_id.clone(),
+                name: name.to_string(),
+                fields,
+            });
+            Ok(())
+        } else {
+            error!("Data type {:?} has a definition of unknown type {:?}", data_type_id, definition.node_id);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+
+    /// Adds the structured and enumerated types of a legacy DataTypeDictionary, i.e. an OPC
+    /// binary schema document. The data types map the names of types in the dictionary to their
+    /// data type id and the id of their default binary encoding. Structures are added if they
+    /// are in the data types and the types of all their fields are known.
+    pub fn add_type_dictionary(&mut self, dictionary: &str, data_types: &HashMap<String, (NodeId, NodeId)>) -> Result<(), StatusCode> {
+        let dictionary = XmlNode::parse(dictionary)?;
+        if dictionary.local_name() != "TypeDictionary" {
+            error!("Type dictionary is a {} not a TypeDictionary", dictionary.name);
+            return Err(StatusCode::BadDecodingError);
+        }
+        let enumerated_types = dictionary.children_named("EnumeratedType")
+            .filter_map(|v| v.attribute("Name"))
+            .collect::<HashSet<&str>>();
+        for enumerated_type in dictionary.children_named("EnumeratedType") {
+            let name = enumerated_type.attribute("Name").unwrap_or("");
+            if let Some((data_type_id, _)) = data_types.get(name) {
+                let fields = enumerated_type.children_named("EnumeratedValue")
+                    .filter_map(|v| {
+                        let value = v.attribute("Value").and_then(|v| v.parse::<i64>().ok())?;
+                        Some((value, v.attribute("Name").unwrap_or("").to_string()))
+                    })
+                    .collect();
+                self.add_enumeration(EnumDefinition {
+                    data_type_id: data_type_id.clone(),
+                    name: name.to_string(),
+                    fields,
+                });
+            }
+        }
+
+        // The type of a field is a built-in type, an enumeration, which is an Int32, or another
+        // type in the dictionary
+        let field_data_type = |type_name: &str| -> Option<NodeId> {
+            let type_name = type_name.rsplit(':').next().unwrap_or(type_name);
+            if let Some(data_type_id) = built_in_type_by_name(type_name) {
+                Some(data_type_id.into())
+            } else if enumerated_types.contains(type_name) {
+                Some(DataTypeId::Int32.into())
+            } else {
+                data_types.get(type_name).map(|(data_type_id, _)| data_type_id.clone())
+            }
+        };
+
+        'structured_types: for structured_type in dictionary.children_named("StructuredType") {
+            let name = structured_type.attribute("Name").unwrap_or("");
+            let (data_type_id, encoding_id) = if let Some(ids) = data_types.get(name) {
+                ids.clone()
+            } else {
+                continue;
+            };
+            let fields = structured_type.children_named("Field").collect::<Vec<&XmlNode>>();
+            // Fields holding the lengths of arrays and the switches of unions are not values, and
+            // nor are bits, which make up the encoding mask of optional fields
+            let length_fields = fields.iter().filter_map(|v| v.attribute("LengthField")).collect::<HashSet<&str>>();
+            let union_switch_fields = fields.iter()
+                .filter(|v| v.attribute("SwitchValue").is_some())
+                .filter_map(|v| v.attribute("SwitchField"))
+                .collect::<HashSet<&str>>();
+            let mut structure_type = StructureType::Structure;
+            let mut definition_fields = Vec::new();
+            for field in fields {
+                let field_name = field.attribute("Name").unwrap_or("");
+                let type_name = field.attribute("TypeName").unwrap_or("");
+                if length_fields.contains(field_name) || union_switch_fields.contains(field_name) || type_name.ends_with(":Bit") {
+                    continue;
+                }
+                let is_optional = if field.attribute("SwitchValue").is_some() {
+                    structure_type = StructureType::Union;
+                    false
+                } else if field.attribute("SwitchField").is_some() {
+                    structure_type = StructureType::StructureWithOptionalFields;
+                    true
+                } else {
+                    false
+                };
+                let value_rank = if field.attribute("LengthField").is_some() { 1 } else { -1 };
+                if let Some(data_type) = field_data_type(type_name) {
+                    definition_fields.push(StructureField::new(field_name, data_type, value_rank, is_optional));
+                } else {
+                    warn!("Type {} of field {} of {} is not in the type dictionary, the type is skipped", type_name, field_name, name);
+                    continue 'structured_types;
+                }
+            }
+            self.add_structure(StructureDefinition {
+                data_type_id,
+                encoding_id,
+                name: name.to_string(),
+                structure_type,
+                fields: definition_fields,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decodes the binary body of an extension object holding a value of a structure in the
+    /// registry
+    pub fn decode(&self, extension_object: &ExtensionObject, decoding_limits: &DecodingLimits) -> Result<DynamicStructure, StatusCode> {
+        let definition = self.find_structure_by_encoding(&extension_object.node_id).ok_or_else(|| {
+            error!("Extension object encoding {:?} is not of a structure in the registry", extension_object.node_id);
+            StatusCode::BadDataTypeIdUnknown
+        })?;
+        let mut stream = Cursor::new(binary_body(extension_object)?);
+        let fields = self.decode_fields(definition, &mut stream, decoding_limits, 0)?;
+        Ok(DynamicStructure::new(definition.data_type_id.clone(), fields))
+    }
+
+    /// Encodes a value of a structure in the registry as an extension object with a binary body
+    pub fn encode(&self, value: &DynamicStructure) -> Result<ExtensionObject, StatusCode> {
+        let definition = self.find_structure(&value.data_type_id).ok_or_else(|| {
+            error!("Data type {:?} is not a structure in the registry", value.data_type_id);
+            StatusCode::BadDataTypeIdUnknown
+        })?;
+        let mut stream = Cursor::new(Vec::new());
+        self.encode_fields(definition, &value.fields, &mut stream)?;
+        Ok(ExtensionObject {
+            node_id: definition.encoding_id.clone(),
+            body: ExtensionObjectEncoding::ByteString(ByteString::from(stream.into_inner())),
+        })
+    }
+
+    /// Finds how values of the data type are encoded, following its supertypes
+    fn value_type(&self, data_type_id: &NodeId) -> Result<ValueType<'_>, StatusCode> {
+        let mut data_type_id = data_type_id;
+        for _ in 0..MAX_SUBTYPE_DEPTH {
+            if let Some(built_in_type) = built_in_type(data_type_id) {
+                return Ok(ValueType::BuiltIn(built_in_type));
+            } else if *data_type_id == DataTypeId::Enumeration.into() || self.enumerations.contains_key(data_type_id) {
+                return Ok(ValueType::Enumeration);
+            } else if let Some(definition) = self.structures.get(data_type_id) {
+                return Ok(ValueType::Structure(definition));
+            } else if let Some(supertype_id) = self.supertypes.get(data_type_id) {
+                data_type_id = supertype_id;
+            } else {
+                break;
+            }
+        }
+        error!("Data type {:?} is not in the registry", data_type_id);
+        Err(StatusCode::BadDataTypeIdUnknown)
+    }
+
+    fn decode_fields<S: Read>(&self, definition: &StructureDefinition, stream: &mut S, decoding_limits: &DecodingLimits, depth: usize) -> Result<Vec<(String, Variant)>, StatusCode> {
+        if depth > MAX_SUBTYPE_DEPTH {
+            error!("Structure {} is nested too deeply", definition.name);
+            return Err(StatusCode::BadDecodingError);
+        }
+        let mut fields = Vec::with_capacity(definition.fields.len());
+        match definition.structure_type {
+            StructureType::Structure => {
+                for field in &definition.fields {
+                    fields.push((field.name.clone(), self.decode_field(field, stream, decoding_limits, depth)?));
+                }
+            }
+            StructureType::StructureWithOptionalFields => {
+                let encoding_mask = u32::decode(stream, decoding_limits)?;
+                let mut optional_bit = 0;
+                for field in &definition.fields {
+                    if field.is_optional {
+                        let is_present = encoding_mask & (1 << optional_bit) != 0;
+                        optional_bit += 1;
+                        if !is_present {
+                            continue;
+                        }
+                    }
+                    fields.push((field.name.clone(), self.decode_field(field, stream, decoding_limits, depth)?));
+                }
+            }
+            StructureType::Union => {
+                let switch_field = u32::decode(stream, decoding_limits)? as usize;
+                if switch_field > definition.fields.len() {
+                    error!("Union {} has an invalid switch field {}", definition.name, switch_field);
+                    return Err(StatusCode::BadDecodingError);
+                } else if switch_field > 0 {
+                    let field = &definition.fields[switch_field - 1];
+                    fields.push((field.name.clone(), self.decode_field(field, stream, decoding_limits, depth)?));
+                }
+            }
+        }
+        Ok(fields)
+    }
+
+    fn decode_field<S: Read>(&self, field: &StructureField, stream: &mut S, decoding_limits: &DecodingLimits, depth: usize) -> Result<Variant, StatusCode> {
+        if field.value_rank > 1 {
+            let dimensions: Option<Vec<i32>> = read_array(stream, decoding_limits)?;
+            let dimensions = dimensions.unwrap_or_default();
+            // The dimensions come from the stream, so their product is checked rather than trusted
+            let len = if dimensions.iter().any(|v| *v < 0) {
+                None
+            } else {
+                dimensions.iter().try_fold(1i64, |len, v| len.checked_mul(i64::from(*v)))
+            };
+            let len = match len {
+                Some(len) if !dimensions.is_empty() && len <= i64::from(decoding_limits.max_array_length) => len,
+                _ => {
+                    error!("Field {} has invalid array dimensions {:?}", field.name, dimensions);
+                    return Err(StatusCode::BadDecodingError);
+                }
+            };
+            let values = (0..len).map(|_| self.decode_value(&field.data_type, stream, decoding_limits, depth)).collect::<Result<Vec<Variant>, StatusCode>>()?;
+            Ok(Variant::new_multi_dimension_array(values, dimensions))
+        } else if field.value_rank >= 0 {
+            let len = i32::decode(stream, decoding_limits)?;
+            if len == -1 {
+                Ok(Variant::Empty)
+            } else if len < -1 || len as u32 > decoding_limits.max_array_length {
+                error!("Field {} has an invalid array length {}", field.name, len);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                let values = (0..len).map(|_| self.decode_value(&field.data_type, stream, decoding_limits, depth)).collect::<Result<Vec<Variant>, StatusCode>>()?;
+                Ok(Variant::Array(values))
+            }
+        } else {
+            self.decode_value(&field.data_type, stream, decoding_limits, depth)
+        }
+    }
+
+    fn decode_value<S: Read>(&self, data_type_id: &NodeId, stream: &mut S, decoding_limits: &DecodingLimits, depth: usize) -> Result<Variant, StatusCode> {
+        match self.value_type(data_type_id)? {
+            ValueType::BuiltIn(22) => Ok(Variant::from(ExtensionObject::decode(stream, decoding_limits)?)),
+            ValueType::BuiltIn(24) => Variant::decode(stream, decoding_limits),
+            ValueType::BuiltIn(25) => {
+                error!("A DiagnosticInfo field cannot be held in a Variant");
+                Err(StatusCode::BadDecodingError)
+            }
+            ValueType::BuiltIn(built_in_type) => Variant::decode_variant_value(stream, built_in_type, decoding_limits),
+            ValueType::Enumeration => Ok(Variant::Int32(i32::decode(stream, decoding_limits)?)),
+            ValueType::Structure(definition) => {
+                // A nested structure is held as the extension object it would be on its own
+                let fields = self.decode_fields(definition, stream, decoding_limits, depth + 1)?;
+                self.encode(&DynamicStructure::new(definition.data_type_id.clone(), fields)).map(Variant::from)
+            }
+        }
+    }
+
+    fn encode_fields<S: Write>(&self, definition: &StructureDefinition, fields: &[(String, Variant)], stream: &mut S) -> Result<(), StatusCode> {
+        let field_value = |name: &str| {
+            fields.iter().find(|(field_name, _)| field_name == name).map(|(_, value)| value).filter(|value| **value != Variant::Empty)
+        };
+        match definition.structure_type {
+            StructureType::Structure | StructureType::StructureWithOptionalFields => {
+                if definition.structure_type == StructureType::StructureWithOptionalFields {
+                    let encoding_mask = definition.fields.iter()
+                        .filter(|field| field.is_optional)
+                        .enumerate()
+                        .filter(|(_, field)| field_value(&field.name).is_some())
+                        .fold(0u32, |encoding_mask, (bit, _)| encoding_mask | (1 << bit));
+                    encoding_mask.encode(stream)?;
+                }
+                for field in &definition.fields {
+                    match field_value(&field.name) {
+                        Some(value) => self.encode_field(field, value, stream)?,
+                        None if field.is_optional => {}
+                        None if field.value_rank >= 0 => self.encode_field(field, &Variant::Empty, stream)?,
+                        None => {
+                            error!("Structure {} has no value for field {}", definition.name, field.name);
+                            return Err(StatusCode::BadEncodingError);
+                        }
+                    }
+                }
+            }
+            StructureType::Union => {
+                // The first field with a value is the one that is selected
+                let selected = definition.fields.iter().enumerate()
+                    .find_map(|(idx, field)| field_value(&field.name).map(|value| (idx, field, value)));
+                if let Some((idx, field, value)) = selected {
+                    (idx as u32 + 1).encode(stream)?;
+                    self.encode_field(field, value, stream)?;
+                } else {
+                    0u32.encode(stream)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_field<S: Write>(&self, field: &StructureField, value: &Variant, stream: &mut S) -> Result<(), StatusCode> {
+        if field.value_rank > 1 {
+            if let Variant::MultiDimensionArray(ref mda) = *value {
+                write_array(stream, &Some(mda.dimensions.clone()))?;
+                mda.values.iter().try_for_each(|v| self.encode_value(&field.data_type, v, stream))
+            } else {
+                error!("Field {} is not a multi-dimensional array", field.name);
+                Err(StatusCode::BadEncodingError)
+            }
+        } else if field.value_rank >= 0 {
+            match *value {
+                Variant::Empty => {
+                    (-1i32).encode(stream)?;
+                    Ok(())
+                }
+                Variant::Array(ref values) => {
+                    (values.len() as i32).encode(stream)?;
+                    values.iter().try_for_each(|v| self.encode_value(&field.data_type, v, stream))
+                }
+                _ => {
+                    error!("Field {} is not an array", field.name);
+                    Err(StatusCode::BadEncodingError)
+                }
+            }
+        } else {
+            self.encode_value(&field.data_type, value, stream)
+        }
+    }
+
+    fn encode_value<S: Write>(&self, data_type_id: &NodeId, value: &Variant, stream: &mut S) -> Result<(), StatusCode> {
+        match (self.value_type(data_type_id)?, value) {
+            (ValueType::BuiltIn(22), Variant::ExtensionObject(ref value)) => {
+                value.encode(stream)?;
+            }
+            (ValueType::BuiltIn(22), Variant::Empty) => {
+                ExtensionObject::null().encode(stream)?;
+            }
+            (ValueType::BuiltIn(24), value) => {
+                value.encode(stream)?;
+            }
+            (ValueType::BuiltIn(built_in_type), value) if value.get_encoding_mask() == built_in_type => {
+                Variant::encode_variant_value(stream, value)?;
+            }
+            (ValueType::Enumeration, Variant::Int32(ref value)) => {
+                value.encode(stream)?;
+            }
+            (ValueType::Structure(definition), Variant::ExtensionObject(ref value)) if value.node_id == definition.encoding_id => {
+                // A nested structure is encoded without the header of its extension object
+                process_encode_io_result(stream.write(binary_body(value)?))?;
+            }
+            (_, value) => {
+                error!("Value {:?} is not of data type {:?}", value, data_type_id);
+                return Err(StatusCode::BadEncodingError);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the id of the built-in type of a data type that is a built-in type
+fn built_in_type(data_type_id: &NodeId) -> Option<u8> {
+    match data_type_id.identifier {
+        Identifier::Numeric(id) if data_type_id.namespace == 0 && (1..=25).contains(&id) => Some(id as u8),
+        _ => None
+    }
+}
+
+/// Returns the built-in type of a type name in an OPC binary schema
+fn built_in_type_by_name(type_name: &str) -> Option<DataTypeId> {
+    let data_type_id = match type_name {
+        "Boolean" => DataTypeId::Boolean,
+        "SByte" => DataTypeId::SByte,
+        "Byte" => DataTypeId::Byte,
+        "Int16" => DataTypeId::Int16,
+        "UInt16" => DataTypeId::UInt16,
+        "Int32" => DataTypeId::Int32,
+        "UInt32" => DataTypeId::UInt32,
+        "Int64" => DataTypeId::Int64,
+        "UInt64" => DataTypeId::UInt64,
+        "Float" => DataTypeId::Float,
+        "Double" => DataTypeId::Double,
+        "String" | "CharArray" => DataTypeId::String,
+        "DateTime" => DataTypeId::DateTime,
+        "Guid" => DataTypeId::Guid,
+        "ByteString" => DataTypeId::ByteString,
+        "XmlElement" => DataTypeId::XmlElement,
+        "NodeId" => DataTypeId::NodeId,
+        "ExpandedNodeId" => DataTypeId::ExpandedNodeId,
+        "StatusCode" => DataTypeId::StatusCode,
+        "QualifiedName" => DataTypeId::QualifiedName,
+        "LocalizedText" => DataTypeId::LocalizedText,
+        "ExtensionObject" => DataTypeId::Structure,
+        "DataValue" => DataTypeId::DataValue,
+        "Variant" => DataTypeId::BaseDataType,
+        "DiagnosticInfo" => DataTypeId::DiagnosticInfo,
+        _ => return None
+    };
+    Some(data_type_id)
+}
+
+fn binary_body(extension_object: &ExtensionObject) -> Result<&[u8], StatusCode> {
+    match extension_object.body {
+        ExtensionObjectEncoding::ByteString(ref body) => Ok(body.value.as_deref().unwrap_or(&[])),
+        _ => {
+            error!("Extension object {:?} does not have a binary body", extension_object.node_id);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+/// Reads the length of an array whose elements are decoded by the caller, where a null array
+/// has no elements
+fn read_array_length<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> Result<usize, StatusCode> {
+    let len = i32::decode(stream, decoding_limits)?;
+    if len < -1 || len as u32 > decoding_limits.max_array_length {
+        error!("Array length {} is invalid", len);
+        Err(StatusCode::BadDecodingError)
+    } else {
+        Ok(len.max(0) as usize)
+    }
+}
+
+impl Session {
+    /// Loads the definitions of the data types, and of the data types of their fields, from the
+    /// server into the registry. Data types that are in the registry already are not loaded again.
+    ///
+    /// The definition of a data type is read from its DataTypeDefinition attribute if the server
+    /// supports OPC UA 1.04, or else from the DataTypeDictionary that describes its default binary
+    /// encoding, which also adds the other types of the dictionary. A data type which is neither
+    /// a structure nor an enumeration is added as a subtype of its supertype.
+    pub fn load_data_types(&mut self, registry: &mut DataTypeRegistry, data_type_ids: &[NodeId]) -> Result<(), StatusCode> {
+        let decoding_limits = DecodingLimits::default();
+        let mut to_load = data_type_ids.to_vec();
+        let mut dictionaries = HashSet::new();
+        while let Some(data_type_id) = to_load.pop() {
+            if registry.contains(&data_type_id) {
+                continue;
+            }
+            let values = self.read_nodes(&[
+                read_value_id(&data_type_id, AttributeId::BrowseName as u32),
+                read_value_id(&data_type_id, DATA_TYPE_DEFINITION_ATTRIBUTE_ID),
+            ])?.unwrap_or_default();
+            let value = |idx: usize| values.get(idx).filter(|v| v.is_valid()).and_then(|v| v.value.clone());
+            let name = if let Some(Variant::QualifiedName(name)) = value(0) {
+                name.name.as_ref().to_string()
+            } else {
+                error!("Data type {:?} cannot be read from the server", data_type_id);
+                return Err(StatusCode::BadDataTypeIdUnknown);
+            };
+
+            if let Some(Variant::ExtensionObject(definition)) = value(1) {
+                registry.add_data_type_definition(&data_type_id, &name, &definition, &decoding_limits)?;
+            } else if let Some(encoding_id) = self.find_binary_encoding(&data_type_id)? {
+                self.load_type_dictionary(registry, &encoding_id, &mut dictionaries)?;
+            } else if let Some(supertype_id) = self.browse_target(&data_type_id, ReferenceTypeId::HasSubtype, BrowseDirection::Inverse)? {
+                registry.add_subtype(data_type_id.clone(), supertype_id.clone());
+                to_load.push(supertype_id);
+            }
+            if !registry.contains(&data_type_id) {
+                error!("Data type {} {:?} has no definition on the server", name, data_type_id);
+                return Err(StatusCode::BadDataTypeIdUnknown);
+            }
+            if let Some(definition) = registry.find_structure(&data_type_id) {
+                to_load.extend(definition.fields.iter().map(|field| field.data_type.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the id of the "Default Binary" encoding of a data type
+    fn find_binary_encoding(&mut self, data_type_id: &NodeId) -> Result<Option<NodeId>, StatusCode> {
+        let binary = DataEncoding::Binary.browse_name();
+        let references = self.browse_references(std::slice::from_ref(data_type_id), ReferenceTypeId::HasEncoding, BrowseDirection::Forward)?;
+        Ok(references.into_iter().flatten()
+            .find(|reference| reference.browse_name == binary)
+            .map(|reference| reference.node_id.node_id))
+    }
+
+    /// Reads the DataTypeDictionary that describes an encoding and adds its types to the
+    /// registry, unless the dictionary has been loaded already
+    fn load_type_dictionary(&mut self, registry: &mut DataTypeRegistry, encoding_id: &NodeId, dictionaries: &mut HashSet<NodeId>) -> Result<(), StatusCode> {
+        let dictionary_id = match self.browse_target(encoding_id, ReferenceTypeId::HasDescription, BrowseDirection::Forward)? {
+            Some(description_id) => self.browse_target(&description_id, ReferenceTypeId::HasComponent, BrowseDirection::Inverse)?,
+            None => None
+        };
+        let dictionary_id = dictionary_id.ok_or_else(|| {
+            error!("Encoding {:?} has no data type dictionary", encoding_id);
+            StatusCode::BadDataTypeIdUnknown
+        })?;
+        if !dictionaries.insert(dictionary_id.clone()) {
+            return Ok(());
+        }
+
+        // Each DataTypeDescription of the dictionary holds the name of a type in the dictionary
+        // and is the description of the encoding of a data type
+        let description_ids = self.browse_references(std::slice::from_ref(&dictionary_id), ReferenceTypeId::HasComponent, BrowseDirection::Forward)?
+            .into_iter().flatten()
+            .filter(|reference| reference.node_class == NodeClass::Variable)
+            .map(|reference| reference.node_id.node_id)
+            .collect::<Vec<NodeId>>();
+        let mut data_types = HashMap::new();
+        if !description_ids.is_empty() {
+            let names = self.read_nodes(&description_ids.iter().map(|v| read_value_id(v, AttributeId::Value as u32)).collect::<Vec<ReadValueId>>())?.unwrap_or_default();
+            let encoding_ids = self.browse_targets(&description_ids, ReferenceTypeId::HasDescription, BrowseDirection::Inverse)?;
+            let encoded_ids = encoding_ids.iter().map(|v| v.clone().unwrap_or_else(NodeId::null)).collect::<Vec<NodeId>>();
+            let data_type_ids = self.browse_targets(&encoded_ids, ReferenceTypeId::HasEncoding, BrowseDirection::Inverse)?;
+            for ((name, encoding_id), data_type_id) in names.into_iter().zip(encoding_ids).zip(data_type_ids) {
+                if let (Some(Variant::String(name)), Some(encoding_id), Some(data_type_id)) = (name.value, encoding_id, data_type_id) {
+                    data_types.insert(name.as_ref().to_string(), (data_type_id, encoding_id));
+                }
+            }
+        }
+
+        let dictionary = self.read_nodes(&[read_value_id(&dictionary_id, AttributeId::Value as u32)])?
+            .and_then(|values| values.into_iter().next())
+            .and_then(|value| value.value);
+        if let Some(Variant::ByteString(ByteString { value: Some(dictionary) })) = dictionary {
+            let dictionary = String::from_utf8(dictionary).map_err(|_| {
+                error!("Data type dictionary {:?} is not UTF-8", dictionary_id);
+                StatusCode::BadDecodingError
+            })?;
+            registry.add_type_dictionary(&dictionary, &data_types)
+        } else {
+            error!("Data type dictionary {:?} cannot be read from the server", dictionary_id);
+            Err(StatusCode::BadDataTypeIdUnknown)
+        }
+    }
+
+    /// Returns the first node that each node refers to with the reference type
+    fn browse_targets(&mut self, node_ids: &[NodeId], reference_type_id: ReferenceTypeId, browse_direction: BrowseDirection) -> Result<Vec<Option<NodeId>>, StatusCode> {
+        Ok(self.browse_references(node_ids, reference_type_id, browse_direction)?
+            .into_iter()
+            .map(|references| references.into_iter().next().map(|reference| reference.node_id.node_id))
+            .collect())
+    }
+
+    fn browse_target(&mut self, node_id: &NodeId, reference_type_id: ReferenceTypeId, browse_direction: BrowseDirection) -> Result<Option<NodeId>, StatusCode> {
+        Ok(self.browse_targets(std::slice::from_ref(node_id), reference_type_id, browse_direction)?.pop().and_then(|v| v))
+    }
+
+    /// Returns the references of each node of the reference type and its subtypes
+    fn browse_references(&mut self, node_ids: &[NodeId], reference_type_id: ReferenceTypeId, browse_direction: BrowseDirection) -> Result<Vec<Vec<ReferenceDescription>>, StatusCode> {
+        let nodes_to_browse = node_ids.iter().map(|node_id| BrowseDescription {
+            node_id: node_id.clone(),
+            browse_direction,
+            reference_type_id: reference_type_id.into(),
+            include_subtypes: true,
+            node_class_mask: 0,
+            result_mask: 0x3f,
+        }).collect::<Vec<BrowseDescription>>();
+        let results = self.browse(&nodes_to_browse)?.unwrap_or_default();
+        Ok(node_ids.iter().enumerate()
+            .map(|(idx, _)| results.get(idx).and_then(|result| result.references.clone()).unwrap_or_default())
+            .collect())
+    }
+}
+
+fn read_value_id(node_id: &NodeId, attribute_id: u32) -> ReadValueId {
+    ReadValueId {
+        node_id: node_id.clone(),
+        attribute_id,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    }
+}
@@ -36,6 +36,7 @@ mod session;
 mod callbacks;
 mod builder;
 mod session_retry;
+mod data_type_registry;
 
 use opcua_types::SupportedMessage;
 use opcua_types::service_types::ResponseHeader;
@@ -76,6 +77,7 @@ pub mod prelude {
         session::*,
         subscription::MonitoredItem,
         callbacks::*,
+        data_type_registry::*,
     };
 }
 
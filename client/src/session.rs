@@ -3,6 +3,7 @@
 //!
 //! The session also has async functionality but that is reserved for publish requests on subscriptions
 //! and events.
+use std::collections::HashMap;
 use std::result::Result;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex, RwLock};
@@ -100,6 +101,8 @@ pub struct Session {
     connection_status_callback: Option<Box<dyn OnConnectionStatusChange + Send + Sync + 'static>>,
     /// Session retry policy
     session_retry_policy: SessionRetryPolicy,
+    /// Node ids registered with the server, mapped to the node ids that the server returned for them
+    registered_nodes: HashMap<NodeId, NodeId>,
 }
 
 impl Drop for Session {
@@ -150,6 +153,7 @@ impl Session {
             message_queue,
             connection_status_callback: None,
             session_retry_policy: SessionRetryPolicy::default(),
+            registered_nodes: HashMap::new(),
         }
     }
 
@@ -202,44 +206,72 @@ impl Session {
                 // 2) activate session
                 self.activate_session()?;
 
-                // TODO transfer subscription
+                // 3) transfer the subscriptions from the old session, in case the server still has it
+                let subscription_ids = {
+                    let subscription_state = trace_read_lock_unwrap!(self.subscription_state);
+                    subscription_state.subscription_ids().unwrap_or_default()
+                };
+                let transferred_subscription_ids = if subscription_ids.is_empty() {
+                    Vec::new()
+                } else {
+                    match self.transfer_subscriptions(&subscription_ids, true) {
+                        Ok(results) => subscription_ids.iter()
+                            .zip(results.iter())
+                            .filter(|(_, result)| result.status_code.is_good())
+                            .map(|(subscription_id, _)| *subscription_id)
+                            .collect::<Vec<u32>>(),
+                        Err(status_code) => {
+                            info!("Subscriptions could not be transferred, {}", status_code);
+                            Vec::new()
+                        }
+                    }
+                };
 
-                // 3) reconstruct all subscriptions and monitored items from their client side cached values
-                {
-                    // clone to avoid some borrowing issues on self
-                    let subscription_state = self.subscription_state.clone();
-                    {
-                        let mut subscription_state = trace_write_lock_unwrap!(subscription_state);
-                        let mut subscriptions = subscription_state.drain_subscriptions();
-                        subscriptions.drain().for_each(|(_, sub)| {
-                            // Attempt to replicate the subscription
-                            if let Ok(subscription_id) = self.create_subscription_inner(
-                                sub.publishing_interval(),
-                                sub.lifetime_count(),
-                                sub.max_keep_alive_count(),
-                                sub.max_notifications_per_publish(),
-                                sub.priority(),
-                                sub.publishing_enabled(),
-                                sub.data_change_callback()) {
-
-                                // For each monitored item
-                                let items_to_create = sub.monitored_items().iter().map(|(_, item)| {
-                                    MonitoredItemCreateRequest {
-                                        item_to_monitor: item.item_to_monitor(),
-                                        monitoring_mode: item.monitoring_mode(),
-                                        requested_parameters: MonitoringParameters {
-                                            client_handle: item.client_handle(),
-                                            sampling_interval: item.sampling_interval(),
-                                            filter: ExtensionObject::null(),
-                                            queue_size: item.queue_size(),
-                                            discard_oldest: true,
-                                        },
-                                    }
-                                }).collect::<Vec<MonitoredItemCreateRequest>>();
-                                let _ = self.create_monitored_items(subscription_id, TimestampsToReturn::Both, &items_to_create);
+                // 4) reconstruct the other subscriptions and their monitored items from their client side cached values
+                let subscriptions = {
+                    // The transferred subscriptions are kept, the others are removed to be recreated
+                    let mut subscription_state = trace_write_lock_unwrap!(self.subscription_state);
+                    let mut subscriptions = subscription_state.drain_subscriptions();
+                    transferred_subscription_ids.iter().for_each(|subscription_id| {
+                        if let Some(subscription) = subscriptions.remove(subscription_id) {
+                            subscription_state.add_subscription(subscription);
+                        }
+                    });
+                    subscriptions
+                };
+                subscriptions.into_iter().for_each(|(_, sub)| {
+                    // Attempt to replicate the subscription
+                    if let Ok(subscription_id) = self.create_subscription_inner(
+                        sub.publishing_interval(),
+                        sub.lifetime_count(),
+                        sub.max_keep_alive_count(),
+                        sub.max_notifications_per_publish(),
+                        sub.priority(),
+                        sub.publishing_enabled(),
+                        sub.data_change_callback()) {
+
+                        // For each monitored item
+                        let items_to_create = sub.monitored_items().iter().map(|(_, item)| {
+                            MonitoredItemCreateRequest {
+                                item_to_monitor: item.item_to_monitor(),
+                                monitoring_mode: item.monitoring_mode(),
+                                requested_parameters: MonitoringParameters {
+                                    client_handle: item.client_handle(),
+                                    sampling_interval: item.sampling_interval(),
+                                    filter: ExtensionObject::null(),
+                                    queue_size: item.queue_size(),
+                                    discard_oldest: true,
+                                },
                             }
-                        });
+                        }).collect::<Vec<MonitoredItemCreateRequest>>();
+                        let _ = self.create_monitored_items(subscription_id, TimestampsToReturn::Both, &items_to_create);
                     }
+                });
+
+                // 5) register the nodes again, since the new session has none
+                let nodes_to_register = self.registered_nodes.drain().map(|(node_id, _)| node_id).collect::<Vec<NodeId>>();
+                if !nodes_to_register.is_empty() {
+                    let _ = self.register_nodes(&nodes_to_register);
                 }
 
                 Err(error)
@@ -669,6 +701,76 @@ impl Session {
         }
     }
 
+    /// Finds the nodes that relative paths lead to from starting nodes by sending a
+    /// [`TranslateBrowsePathsToNodeIdsRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `browse_paths` - The starting nodes and the relative paths to follow from them.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<BrowsePathResult>)` - A result per browse path, holding its status code and its
+    ///   targets.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`TranslateBrowsePathsToNodeIdsRequest`]: ./struct.TranslateBrowsePathsToNodeIdsRequest.html
+    ///
+    pub fn translate_browse_paths_to_node_ids(&mut self, browse_paths: &[BrowsePath]) -> Result<Vec<BrowsePathResult>, StatusCode> {
+        if browse_paths.is_empty() {
+            error!("translate_browse_paths_to_node_ids, was not supplied with any browse paths");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = TranslateBrowsePathsToNodeIdsRequest {
+                request_header: self.make_request_header(),
+                browse_paths: Some(browse_paths.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::TranslateBrowsePathsToNodeIdsResponse(response) = response {
+                debug!("translate_browse_paths_to_node_ids, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("translate_browse_paths_to_node_ids failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Finds the nodes that a relative path in its string form, as per OPC UA Part 4, Appendix A,
+    /// leads to from the starting node with [`translate_browse_paths_to_node_ids`], e.g. the
+    /// "/2:Pump.2:Speed" variable of an object.
+    ///
+    /// # Arguments
+    ///
+    /// * `starting_node` - The node to follow the path from.
+    /// * `path` - The relative path, see `relative_path::make_relative_path`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<BrowsePathTarget>)` - The targets of the path.
+    /// * `Err(StatusCode)` - `BadBrowseNameInvalid` if the path is malformed, the status code of
+    ///   the result if it is bad, or the status code reason for failure.
+    ///
+    /// [`translate_browse_paths_to_node_ids`]: #method.translate_browse_paths_to_node_ids
+    ///
+    pub fn translate_browse_path(&mut self, starting_node: &NodeId, path: &str) -> Result<Vec<BrowsePathTarget>, StatusCode> {
+        let relative_path = relative_path::make_relative_path(path).map_err(|_| {
+            error!("translate_browse_path, {} is not a valid relative path", path);
+            StatusCode::BadBrowseNameInvalid
+        })?;
+        let browse_path = BrowsePath {
+            starting_node: starting_node.clone(),
+            relative_path,
+        };
+        let result = self.translate_browse_paths_to_node_ids(&[browse_path])?.pop().ok_or(StatusCode::BadUnexpectedError)?;
+        if result.status_code.is_bad() {
+            Err(result.status_code)
+        } else {
+            Ok(result.targets.unwrap_or_default())
+        }
+    }
+
     /// Sends a ReadRequest to the server
     pub fn read_nodes(&mut self, nodes_to_read: &[ReadValueId]) -> Result<Option<Vec<DataValue>>, StatusCode> {
         if nodes_to_read.is_empty() {
@@ -681,7 +783,10 @@ impl Session {
                 request_header: self.make_request_header(),
                 max_age: 1f64,
                 timestamps_to_return: TimestampsToReturn::Server,
-                nodes_to_read: Some(nodes_to_read.to_vec()),
+                nodes_to_read: Some(nodes_to_read.iter().map(|node_to_read| ReadValueId {
+                    node_id: self.registered_node_id(&node_to_read.node_id),
+                    ..node_to_read.clone()
+                }).collect()),
             };
             let response = self.send_request(request)?;
             if let SupportedMessage::ReadResponse(response) = response {
@@ -704,7 +809,10 @@ impl Session {
         } else {
             let request = WriteRequest {
                 request_header: self.make_request_header(),
-                nodes_to_write: Some(nodes_to_write.to_vec()),
+                nodes_to_write: Some(nodes_to_write.iter().map(|node_to_write| WriteValue {
+                    node_id: self.registered_node_id(&node_to_write.node_id),
+                    ..node_to_write.clone()
+                }).collect()),
             };
             let response = self.send_request(request)?;
             if let SupportedMessage::WriteResponse(response) = response {
@@ -718,6 +826,396 @@ impl Session {
         }
     }
 
+    /// Registers nodes that the client will access repeatedly by sending a [`RegisterNodesRequest`]
+    /// to the server. The server returns a node id for each node, which may be an alias that it
+    /// can resolve faster than the original. [`read_nodes`] and [`write_value`] substitute the
+    /// returned node ids for the registered ones, so the caller may go on using the original node
+    /// ids.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes_to_register` - The nodes to register.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<NodeId>)` - The node ids returned by the server, one per node to register.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`RegisterNodesRequest`]: ./struct.RegisterNodesRequest.html
+    /// [`read_nodes`]: #method.read_nodes
+    /// [`write_value`]: #method.write_value
+    ///
+    pub fn register_nodes(&mut self, nodes_to_register: &[NodeId]) -> Result<Vec<NodeId>, StatusCode> {
+        if nodes_to_register.is_empty() {
+            error!("register_nodes, was not supplied with any nodes to register");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = RegisterNodesRequest {
+                request_header: self.make_request_header(),
+                nodes_to_register: Some(nodes_to_register.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::RegisterNodesResponse(response) = response {
+                debug!("register_nodes, success");
+                crate::process_service_result(&response.response_header)?;
+                let registered_node_ids = response.registered_node_ids.unwrap_or_default();
+                nodes_to_register.iter().zip(registered_node_ids.iter())
+                    .filter(|(node_id, registered_node_id)| node_id != registered_node_id)
+                    .for_each(|(node_id, registered_node_id)| {
+                        self.registered_nodes.insert(node_id.clone(), registered_node_id.clone());
+                    });
+                Ok(registered_node_ids)
+            } else {
+                error!("register_nodes failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Unregisters nodes that were registered with [`register_nodes`] by sending an
+    /// [`UnregisterNodesRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes_to_unregister` - The nodes to unregister, either the node ids that were registered
+    ///   or the node ids that the server returned for them.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The nodes were unregistered.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`register_nodes`]: #method.register_nodes
+    /// [`UnregisterNodesRequest`]: ./struct.UnregisterNodesRequest.html
+    ///
+    pub fn unregister_nodes(&mut self, nodes_to_unregister: &[NodeId]) -> Result<(), StatusCode> {
+        if nodes_to_unregister.is_empty() {
+            error!("unregister_nodes, was not supplied with any nodes to unregister");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let nodes_to_unregister = nodes_to_unregister.iter().map(|node_id| {
+                self.registered_nodes.remove(node_id).unwrap_or_else(|| {
+                    self.registered_nodes.retain(|_, registered_node_id| registered_node_id != node_id);
+                    node_id.clone()
+                })
+            }).collect::<Vec<NodeId>>();
+            let request = UnregisterNodesRequest {
+                request_header: self.make_request_header(),
+                nodes_to_unregister: Some(nodes_to_unregister),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::UnregisterNodesResponse(response) = response {
+                debug!("unregister_nodes, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(())
+            } else {
+                error!("unregister_nodes failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Finds the instances of one or more types that pass a filter by sending a
+    /// [`QueryFirstRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_types` - The types to find the instances of, whether the instances of their
+    ///   subtypes are included and the data to return from each instance.
+    /// * `filter` - The filter that the instances must pass, or an empty filter for all of them.
+    /// * `max_data_sets_to_return` - The maximum number of data sets to return, or 0 for no limit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<QueryDataSet>, ByteString))` - A data set for each instance found, and a
+    ///   continuation point for [`query_next`] if there are more data sets to return.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`QueryFirstRequest`]: ./struct.QueryFirstRequest.html
+    /// [`query_next`]: #method.query_next
+    ///
+    pub fn query_first(&mut self, node_types: &[NodeTypeDescription], filter: ContentFilter, max_data_sets_to_return: u32) -> Result<(Vec<QueryDataSet>, ByteString), StatusCode> {
+        if node_types.is_empty() {
+            error!("query_first, was not supplied with any node types");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = QueryFirstRequest {
+                request_header: self.make_request_header(),
+                view: ViewDescription {
+                    view_id: NodeId::null(),
+                    timestamp: DateTime::now(),
+                    view_version: 0,
+                },
+                node_types: Some(node_types.to_vec()),
+                filter,
+                max_data_sets_to_return,
+                max_references_to_return: 0,
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::QueryFirstResponse(response) = response {
+                debug!("query_first, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok((response.query_data_sets.unwrap_or_default(), response.continuation_point))
+            } else {
+                error!("query_first failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Returns more data sets of a query by sending a [`QueryNextRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_continuation_point` - If true, the continuation point is released and no data
+    ///   sets are returned.
+    /// * `continuation_point` - The continuation point from [`query_first`] or a previous call.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<QueryDataSet>, ByteString))` - The next data sets, and a continuation point if
+    ///   there are still more data sets to return.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`QueryNextRequest`]: ./struct.QueryNextRequest.html
+    /// [`query_first`]: #method.query_first
+    ///
+    pub fn query_next(&mut self, release_continuation_point: bool, continuation_point: &ByteString) -> Result<(Vec<QueryDataSet>, ByteString), StatusCode> {
+        let request = QueryNextRequest {
+            request_header: self.make_request_header(),
+            release_continuation_point,
+            continuation_point: continuation_point.clone(),
+        };
+        let response = self.send_request(request)?;
+        if let SupportedMessage::QueryNextResponse(response) = response {
+            debug!("query_next, success");
+            crate::process_service_result(&response.response_header)?;
+            Ok((response.query_data_sets.unwrap_or_default(), response.revised_continuation_point))
+        } else {
+            error!("query_next failed {:?}", response);
+            Err(crate::process_unexpected_response(response))
+        }
+    }
+
+    /// Reads the history of one or more nodes by sending a [`HistoryReadRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `history_read_details` - The details of the read, e.g. a [`ReadRawModifiedDetails`]
+    ///   encoded into an extension object.
+    /// * `timestamps_to_return` - The timestamps to return with each value. `Neither` is invalid.
+    /// * `release_continuation_points` - If true, the continuation points in `nodes_to_read` are
+    ///   released and no values are returned.
+    /// * `nodes_to_read` - The nodes to read, and a continuation point for each node that is
+    ///   resuming a previous read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<HistoryReadResult>)` - A list of results, one per node to read, holding the
+    ///   values and a continuation point if there are more values to read.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`HistoryReadRequest`]: ./struct.HistoryReadRequest.html
+    /// [`ReadRawModifiedDetails`]: ./struct.ReadRawModifiedDetails.html
+    ///
+    pub fn history_read(&mut self, history_read_details: ExtensionObject, timestamps_to_return: TimestampsToReturn, release_continuation_points: bool, nodes_to_read: &[HistoryReadValueId]) -> Result<Vec<HistoryReadResult>, StatusCode> {
+        if nodes_to_read.is_empty() {
+            error!("history_read, was not supplied with any nodes to read");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = HistoryReadRequest {
+                request_header: self.make_request_header(),
+                history_read_details,
+                timestamps_to_return,
+                release_continuation_points,
+                nodes_to_read: Some(nodes_to_read.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::HistoryReadResponse(response) = response {
+                debug!("history_read, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("history_read failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Updates the history of one or more nodes by sending a [`HistoryUpdateRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `history_update_details` - The updates to make, each one an [`UpdateDataDetails`] or
+    ///   [`DeleteRawModifiedDetails`] encoded into an extension object.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<HistoryUpdateResult>)` - A list of results, one per update, holding the status
+    ///   of the update and the result of each value inserted or replaced.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`HistoryUpdateRequest`]: ./struct.HistoryUpdateRequest.html
+    /// [`UpdateDataDetails`]: ./struct.UpdateDataDetails.html
+    /// [`DeleteRawModifiedDetails`]: ./struct.DeleteRawModifiedDetails.html
+    ///
+    pub fn history_update(&mut self, history_update_details: &[ExtensionObject]) -> Result<Vec<HistoryUpdateResult>, StatusCode> {
+        if history_update_details.is_empty() {
+            error!("history_update, was not supplied with any details to update");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = HistoryUpdateRequest {
+                request_header: self.make_request_header(),
+                history_update_details: Some(history_update_details.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::HistoryUpdateResponse(response) = response {
+                debug!("history_update, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("history_update failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Adds nodes to the server's address space by sending a [`AddNodesRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes_to_add` - List of nodes to add to the address space.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<AddNodesResult>)` - A list of results, one per node to add, holding the status and the id of the added node.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`AddNodesRequest`]: ./struct.AddNodesRequest.html
+    ///
+    pub fn add_nodes(&mut self, nodes_to_add: &[AddNodesItem]) -> Result<Vec<AddNodesResult>, StatusCode> {
+        if nodes_to_add.is_empty() {
+            error!("add_nodes, was not supplied with any items");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = AddNodesRequest {
+                request_header: self.make_request_header(),
+                nodes_to_add: Some(nodes_to_add.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::AddNodesResponse(response) = response {
+                debug!("add_nodes, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("add_nodes failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Adds references between nodes in the server's address space by sending a [`AddReferencesRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `references_to_add` - List of references to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StatusCode>)` - A list of status codes, one per reference to add.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`AddReferencesRequest`]: ./struct.AddReferencesRequest.html
+    ///
+    pub fn add_references(&mut self, references_to_add: &[AddReferencesItem]) -> Result<Vec<StatusCode>, StatusCode> {
+        if references_to_add.is_empty() {
+            error!("add_references, was not supplied with any items");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = AddReferencesRequest {
+                request_header: self.make_request_header(),
+                references_to_add: Some(references_to_add.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::AddReferencesResponse(response) = response {
+                debug!("add_references, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("add_references failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Deletes nodes from the server's address space by sending a [`DeleteNodesRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes_to_delete` - List of nodes to delete.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StatusCode>)` - A list of status codes, one per node to delete.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`DeleteNodesRequest`]: ./struct.DeleteNodesRequest.html
+    ///
+    pub fn delete_nodes(&mut self, nodes_to_delete: &[DeleteNodesItem]) -> Result<Vec<StatusCode>, StatusCode> {
+        if nodes_to_delete.is_empty() {
+            error!("delete_nodes, was not supplied with any items");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = DeleteNodesRequest {
+                request_header: self.make_request_header(),
+                nodes_to_delete: Some(nodes_to_delete.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::DeleteNodesResponse(response) = response {
+                debug!("delete_nodes, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("delete_nodes failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Deletes references between nodes in the server's address space by sending a [`DeleteReferencesRequest`] to the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `references_to_delete` - List of references to delete.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StatusCode>)` - A list of status codes, one per reference to delete.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`DeleteReferencesRequest`]: ./struct.DeleteReferencesRequest.html
+    ///
+    pub fn delete_references(&mut self, references_to_delete: &[DeleteReferencesItem]) -> Result<Vec<StatusCode>, StatusCode> {
+        if references_to_delete.is_empty() {
+            error!("delete_references, was not supplied with any items");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = DeleteReferencesRequest {
+                request_header: self.make_request_header(),
+                references_to_delete: Some(references_to_delete.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::DeleteReferencesResponse(response) = response {
+                debug!("delete_references, success");
+                crate::process_service_result(&response.response_header)?;
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("delete_references failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
     /// Create a subscription by sending a [`CreateSubscriptionRequest`] to the server.
     ///
     /// # Arguments
@@ -999,6 +1497,48 @@ impl Session {
         }
     }
 
+    /// Transfers subscriptions from another session of the same user to this session by sending
+    /// a [`TransferSubscriptionsRequest`] to the server, e.g. after reconnecting with a new session.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_ids` - one or more subscription identifiers.
+    /// * `send_initial_values` - A boolean parameter with the following values - `true` the first
+    ///   publish response after the transfer contains the current values of all monitored items,
+    ///   `false` it only contains the values that changed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TransferResult>)` - The result for each id, with the sequence numbers of the
+    ///   notifications that are available for republishing.
+    /// * `Err(StatusCode)` - Status code reason for failure
+    ///
+    /// [`TransferSubscriptionsRequest`]: ./struct.TransferSubscriptionsRequest.html
+    ///
+    pub fn transfer_subscriptions(&mut self, subscription_ids: &[u32], send_initial_values: bool) -> Result<Vec<TransferResult>, StatusCode> {
+        debug!("transfer_subscriptions, for subscriptions {:?}, send initial values {}", subscription_ids, send_initial_values);
+        if subscription_ids.is_empty() {
+            // No subscriptions
+            error!("transfer_subscriptions, no subscription ids were provided");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = TransferSubscriptionsRequest {
+                request_header: self.make_request_header(),
+                subscription_ids: Some(subscription_ids.to_vec()),
+                send_initial_values,
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::TransferSubscriptionsResponse(response) = response {
+                crate::process_service_result(&response.response_header)?;
+                debug!("transfer_subscriptions success");
+                Ok(response.results.unwrap_or_default())
+            } else {
+                error!("transfer_subscriptions failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
     /// Creates monitored items on a subscription by sending a [`CreateMonitoredItemsRequest`] to the server.
     ///
     /// # Arguments
@@ -1191,6 +1731,119 @@ impl Session {
         }
     }
 
+    /// Sets the monitoring mode of monitored items of a subscription by sending a [`SetMonitoringModeRequest`]
+    /// to the server. Items that are disabled don't sample, items that are sampling queue their
+    /// notifications without reporting them unless they are triggered, and items that are
+    /// reporting report their notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - The Server-assigned identifier for the Subscription that will report Notifications for these MonitoredItems.
+    /// * `monitoring_mode` - The monitoring mode to set.
+    /// * `monitored_item_ids` - List of Server-assigned ids for the MonitoredItems.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<StatusCode>)` - List of StatusCodes for the MonitoredItems. The size and
+    ///   order of the list matches the size and order of the `monitored_item_ids` request parameter.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`SetMonitoringModeRequest`]: ./struct.SetMonitoringModeRequest.html
+    ///
+    pub fn set_monitoring_mode(&mut self, subscription_id: u32, monitoring_mode: MonitoringMode, monitored_item_ids: &[u32]) -> Result<Vec<StatusCode>, StatusCode> {
+        debug!("set_monitoring_mode, subscription {} for {} items", subscription_id, monitored_item_ids.len());
+        if subscription_id == 0 {
+            error!("set_monitoring_mode, subscription id 0 is invalid");
+            Err(StatusCode::BadInvalidArgument)
+        } else if !self.subscription_exists(subscription_id) {
+            error!("set_monitoring_mode, subscription id {} does not exist", subscription_id);
+            Err(StatusCode::BadInvalidArgument)
+        } else if monitored_item_ids.is_empty() {
+            error!("set_monitoring_mode, called with no items");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let request = SetMonitoringModeRequest {
+                request_header: self.make_request_header(),
+                subscription_id,
+                monitoring_mode,
+                monitored_item_ids: Some(monitored_item_ids.to_vec()),
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::SetMonitoringModeResponse(response) = response {
+                crate::process_service_result(&response.response_header)?;
+                let results = response.results.unwrap_or_default();
+                {
+                    // Update the mode of the items that were set in our internal state
+                    let monitored_item_ids = monitored_item_ids.iter()
+                        .zip(results.iter())
+                        .filter(|(_, status_code)| status_code.is_good())
+                        .map(|(id, _)| *id)
+                        .collect::<Vec<u32>>();
+                    let mut subscription_state = trace_write_lock_unwrap!(self.subscription_state);
+                    subscription_state.set_monitoring_mode(subscription_id, &monitored_item_ids, monitoring_mode);
+                }
+                debug!("set_monitoring_mode, success");
+                Ok(results)
+            } else {
+                error!("set_monitoring_mode failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
+    /// Adds and removes the links from a triggering monitored item to the items that it triggers
+    /// by sending a [`SetTriggeringRequest`] to the server. When the triggering item samples a new
+    /// value, the linked items that are sampling report their queued notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - The Server-assigned identifier for the Subscription that contains the MonitoredItems.
+    /// * `triggering_item_id` - The Server-assigned id of the MonitoredItem that triggers the others.
+    /// * `links_to_add` - List of Server-assigned ids of the MonitoredItems to link to the triggering item.
+    /// * `links_to_remove` - List of Server-assigned ids of the MonitoredItems to unlink from the triggering item.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Option<Vec<StatusCode>>, Option<Vec<StatusCode>>))` - Lists of StatusCodes for the
+    ///   links to add and the links to remove. The size and order of each list matches the size
+    ///   and order of the request parameter. A list is `None` if its parameter is empty.
+    /// * `Err(StatusCode)` - Status code reason for failure.
+    ///
+    /// [`SetTriggeringRequest`]: ./struct.SetTriggeringRequest.html
+    ///
+    pub fn set_triggering(&mut self, subscription_id: u32, triggering_item_id: u32, links_to_add: &[u32], links_to_remove: &[u32]) -> Result<(Option<Vec<StatusCode>>, Option<Vec<StatusCode>>), StatusCode> {
+        debug!("set_triggering, subscription {}, triggering item {}, {} links to add, {} links to remove", subscription_id, triggering_item_id, links_to_add.len(), links_to_remove.len());
+        if subscription_id == 0 {
+            error!("set_triggering, subscription id 0 is invalid");
+            Err(StatusCode::BadInvalidArgument)
+        } else if !self.subscription_exists(subscription_id) {
+            error!("set_triggering, subscription id {} does not exist", subscription_id);
+            Err(StatusCode::BadInvalidArgument)
+        } else if links_to_add.is_empty() && links_to_remove.is_empty() {
+            error!("set_triggering, called with no links to add or remove");
+            Err(StatusCode::BadNothingToDo)
+        } else {
+            let links_to_add = if links_to_add.is_empty() { None } else { Some(links_to_add.to_vec()) };
+            let links_to_remove = if links_to_remove.is_empty() { None } else { Some(links_to_remove.to_vec()) };
+            let request = SetTriggeringRequest {
+                request_header: self.make_request_header(),
+                subscription_id,
+                triggering_item_id,
+                links_to_add,
+                links_to_remove,
+            };
+            let response = self.send_request(request)?;
+            if let SupportedMessage::SetTriggeringResponse(response) = response {
+                crate::process_service_result(&response.response_header)?;
+                debug!("set_triggering, success");
+                Ok((response.add_results, response.remove_results))
+            } else {
+                error!("set_triggering failed {:?}", response);
+                Err(crate::process_unexpected_response(response))
+            }
+        }
+    }
+
     /// Calls a single method on an object on the server by sending a [`CallRequest`] to the server.
     ///
     /// # Arguments
@@ -1365,6 +2018,12 @@ impl Session {
         }
     }
 
+    /// Returns the node id that the server returned when the node was registered, or the node id
+    /// itself if it is not registered
+    fn registered_node_id(&self, node_id: &NodeId) -> NodeId {
+        self.registered_nodes.get(node_id).unwrap_or(node_id).clone()
+    }
+
     /// Construct a request header for the session. All requests after create session are expected
     /// to supply an authentication token.
     fn make_request_header(&mut self) -> RequestHeader {
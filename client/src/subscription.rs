@@ -226,6 +226,14 @@ impl Subscription {
         })
     }
 
+    pub(crate) fn set_monitoring_mode(&mut self, monitored_item_ids: &[u32], monitoring_mode: MonitoringMode) {
+        monitored_item_ids.iter().for_each(|id| {
+            if let Some(ref mut monitored_item) = self.monitored_items.get_mut(id) {
+                monitored_item.set_monitoring_mode(monitoring_mode);
+            }
+        });
+    }
+
     fn monitored_item_id_from_handle(&self, client_handle: u32) -> Option<u32> {
         if let Some(monitored_item_id) = self.client_handles.get(&client_handle) {
             Some(*monitored_item_id)
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use opcua_types::MonitoringMode;
 use opcua_types::service_types::DataChangeNotification;
 
 use crate::subscription::*;
@@ -86,4 +87,10 @@ impl SubscriptionState {
             subscription.delete_monitored_items(items_to_delete);
         }
     }
+
+    pub(crate) fn set_monitoring_mode(&mut self, subscription_id: u32, monitored_item_ids: &[u32], monitoring_mode: MonitoringMode) {
+        if let Some(ref mut subscription) = self.subscriptions.get_mut(&subscription_id) {
+            subscription.set_monitoring_mode(monitored_item_ids, monitoring_mode);
+        }
+    }
 }
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use opcua_types::*;
+use opcua_types::node_ids::DataTypeId;
+use opcua_types::status_code::StatusCode;
+
+use crate::data_type_registry::*;
+
+fn point_definition() -> StructureDefinition {
+    StructureDefinition {
+        data_type_id: NodeId::new(2, 1000),
+        encoding_id: NodeId::new(2, 1001),
+        name: "Point".to_string(),
+        structure_type: StructureType::Structure,
+        fields: vec![
+            StructureField::new("X", DataTypeId::Double.into(), -1, false),
+            StructureField::new("Y", DataTypeId::Double.into(), -1, false),
+        ],
+    }
+}
+
+fn point(x: f64, y: f64) -> DynamicStructure {
+    DynamicStructure::new(NodeId::new(2, 1000), vec![
+        ("X".to_string(), Variant::Double(x)),
+        ("Y".to_string(), Variant::Double(y)),
+    ])
+}
+
+fn test_registry() -> DataTypeRegistry {
+    let mut registry = DataTypeRegistry::new();
+    registry.add_structure(point_definition());
+    registry.add_enumeration(EnumDefinition {
+        data_type_id: NodeId::new(2, 2000),
+        name: "Color".to_string(),
+        fields: vec![(0, "Red".to_string()), (1, "Green".to_string())],
+    });
+    // A vendor type which is a Duration, which is a Double
+    registry.add_subtype(NodeId::new(2, 2001), DataTypeId::Duration.into());
+    registry.add_subtype(DataTypeId::Duration.into(), DataTypeId::Double.into());
+    registry.add_structure(StructureDefinition {
+        data_type_id: NodeId::new(2, 1010),
+        encoding_id: NodeId::new(2, 1011),
+        name: "Shape".to_string(),
+        structure_type: StructureType::Structure,
+        fields: vec![
+            StructureField::new("Name", DataTypeId::String.into(), -1, false),
+            StructureField::new("Color", NodeId::new(2, 2000), -1, false),
+            StructureField::new("Period", NodeId::new(2, 2001), -1, false),
+            StructureField::new("Origin", NodeId::new(2, 1000), -1, false),
+            StructureField::new("Points", NodeId::new(2, 1000), 1, false),
+            StructureField::new("Tag", DataTypeId::BaseDataType.into(), -1, false),
+        ],
+    });
+    registry.add_structure(StructureDefinition {
+        data_type_id: NodeId::new(2, 1020),
+        encoding_id: NodeId::new(2, 1021),
+        name: "Options".to_string(),
+        structure_type: StructureType::StructureWithOptionalFields,
+        fields: vec![
+            StructureField::new("Id", DataTypeId::UInt32.into(), -1, false),
+            StructureField::new("Label", DataTypeId::String.into(), -1, true),
+            StructureField::new("Limit", DataTypeId::Int16.into(), -1, true),
+        ],
+    });
+    registry.add_structure(StructureDefinition {
+        data_type_id: NodeId::new(2, 1030),
+        encoding_id: NodeId::new(2, 1031),
+        name: "Choice".to_string(),
+        structure_type: StructureType::Union,
+        fields: vec![
+            StructureField::new("Number", DataTypeId::Int32.into(), -1, false),
+            StructureField::new("Text", DataTypeId::String.into(), -1, false),
+        ],
+    });
+    registry
+}
+
+fn body(extension_object: &ExtensionObject) -> Vec<u8> {
+    if let ExtensionObjectEncoding::ByteString(ref body) = extension_object.body {
+        body.value.clone().unwrap()
+    } else {
+        panic!("Extension object does not have a binary body");
+    }
+}
+
+#[test]
+fn encode_decode_structure() {
+    let registry = test_registry();
+    let decoding_limits = DecodingLimits::default();
+
+    // A structure is encoded as its fields in order
+    let extension_object = registry.encode(&point(1.5, -2.0)).unwrap();
+    assert_eq!(extension_object.node_id, NodeId::new(2, 1001));
+    let mut expected = Vec::new();
+    1.5f64.encode(&mut expected).unwrap();
+    (-2.0f64).encode(&mut expected).unwrap();
+    assert_eq!(body(&extension_object), expected);
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), point(1.5, -2.0));
+
+    // Nested structures and arrays of them are held as extension objects
+    let origin = registry.encode(&point(0.0, 0.0)).unwrap();
+    let points = vec![registry.encode(&point(1.0, 2.0)).unwrap(), registry.encode(&point(3.0, 4.0)).unwrap()];
+    let shape = DynamicStructure::new(NodeId::new(2, 1010), vec![
+        ("Name".to_string(), Variant::from("Line")),
+        ("Color".to_string(), Variant::Int32(1)),
+        ("Period".to_string(), Variant::Double(100.0)),
+        ("Origin".to_string(), Variant::from(origin)),
+        ("Points".to_string(), Variant::Array(points.iter().cloned().map(Variant::from).collect())),
+        ("Tag".to_string(), Variant::UInt16(7)),
+    ]);
+    let extension_object = registry.encode(&shape).unwrap();
+    let decoded = registry.decode(&extension_object, &decoding_limits).unwrap();
+    assert_eq!(decoded, shape);
+    if let Some(Variant::Array(values)) = decoded.get("Points") {
+        if let Variant::ExtensionObject(ref point2) = values[1] {
+            assert_eq!(registry.decode(point2, &decoding_limits).unwrap(), point(3.0, 4.0));
+        } else {
+            panic!("Point is not an extension object");
+        }
+    } else {
+        panic!("Points is not an array");
+    }
+
+    // A value of the wrong type or a missing field cannot be encoded
+    let mut wrong_type = point(1.0, 2.0);
+    wrong_type.fields[1].1 = Variant::Float(2.0);
+    assert_eq!(registry.encode(&wrong_type).unwrap_err(), StatusCode::BadEncodingError);
+    let mut missing_field = point(1.0, 2.0);
+    missing_field.fields.pop();
+    assert_eq!(registry.encode(&missing_field).unwrap_err(), StatusCode::BadEncodingError);
+    // A truncated body cannot be decoded
+    let mut truncated = registry.encode(&point(1.0, 2.0)).unwrap();
+    truncated.body = ExtensionObjectEncoding::ByteString(ByteString::from(vec![0u8; 12]));
+    assert!(registry.decode(&truncated, &decoding_limits).is_err());
+    // An unknown encoding cannot be decoded
+    let unknown = ExtensionObject { node_id: NodeId::new(2, 9999), body: ExtensionObjectEncoding::None };
+    assert_eq!(registry.decode(&unknown, &decoding_limits).unwrap_err(), StatusCode::BadDataTypeIdUnknown);
+}
+
+#[test]
+fn encode_decode_optional_fields_and_unions() {
+    let registry = test_registry();
+    let decoding_limits = DecodingLimits::default();
+
+    // The encoding mask has a bit for each optional field that is present
+    let options = DynamicStructure::new(NodeId::new(2, 1020), vec![
+        ("Id".to_string(), Variant::UInt32(5)),
+        ("Limit".to_string(), Variant::Int16(-1)),
+    ]);
+    let extension_object = registry.encode(&options).unwrap();
+    let mut expected = Vec::new();
+    2u32.encode(&mut expected).unwrap();
+    5u32.encode(&mut expected).unwrap();
+    (-1i16).encode(&mut expected).unwrap();
+    assert_eq!(body(&extension_object), expected);
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), options);
+
+    // The switch field of a union is the index of the field that follows, starting at 1
+    let choice = DynamicStructure::new(NodeId::new(2, 1030), vec![("Text".to_string(), Variant::from("abc"))]);
+    let extension_object = registry.encode(&choice).unwrap();
+    let mut expected = Vec::new();
+    2u32.encode(&mut expected).unwrap();
+    UAString::from("abc").encode(&mut expected).unwrap();
+    assert_eq!(body(&extension_object), expected);
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), choice);
+
+    let empty_choice = DynamicStructure::new(NodeId::new(2, 1030), Vec::new());
+    let extension_object = registry.encode(&empty_choice).unwrap();
+    assert_eq!(body(&extension_object), vec![0u8; 4]);
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), empty_choice);
+}
+
+#[test]
+fn decode_multi_dimension_array() {
+    let mut registry = DataTypeRegistry::new();
+    registry.add_structure(StructureDefinition {
+        data_type_id: NodeId::new(2, 1040),
+        encoding_id: NodeId::new(2, 1041),
+        name: "Grid".to_string(),
+        structure_type: StructureType::Structure,
+        fields: vec![StructureField::new("Cells", DataTypeId::Int32.into(), 2, false)],
+    });
+    let decoding_limits = DecodingLimits::default();
+    let decode = |dimensions: Vec<i32>, values: &[i32]| {
+        let mut body = Vec::new();
+        write_array(&mut body, &Some(dimensions)).unwrap();
+        values.iter().for_each(|v| { v.encode(&mut body).unwrap(); });
+        let extension_object = ExtensionObject {
+            node_id: NodeId::new(2, 1041),
+            body: ExtensionObjectEncoding::ByteString(ByteString::from(body)),
+        };
+        registry.decode(&extension_object, &decoding_limits)
+    };
+
+    let grid = decode(vec![2, 1], &[3, 4]).unwrap();
+    assert_eq!(grid.get("Cells"), Some(&Variant::new_multi_dimension_array(vec![Variant::Int32(3), Variant::Int32(4)], vec![2, 1])));
+    // Dimensions that are negative or whose product overflows are rejected
+    assert_eq!(decode(vec![-1, -1], &[3]).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(decode(vec![i32::MAX, i32::MAX, i32::MAX], &[]).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(decode(Vec::new(), &[]).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn add_data_type_definition() {
+    let decoding_limits = DecodingLimits::default();
+
+    // A StructureDefinition as it is read from the DataTypeDefinition attribute
+    let mut stream = Cursor::new(Vec::new());
+    NodeId::new(2, 3001).encode(&mut stream).unwrap();
+    NodeId::new(0, DataTypeId::Structure as u32).encode(&mut stream).unwrap();
+    1i32.encode(&mut stream).unwrap();
+    2i32.encode(&mut stream).unwrap();
+    for (name, data_type, value_rank, is_optional) in &[("Values", DataTypeId::Float, 1, false), ("Unit", DataTypeId::String, -1, true)] {
+        UAString::from(*name).encode(&mut stream).unwrap();
+        LocalizedText::null().encode(&mut stream).unwrap();
+        NodeId::new(0, *data_type as u32).encode(&mut stream).unwrap();
+        value_rank.encode(&mut stream).unwrap();
+        write_array::<_, u32>(&mut stream, &None).unwrap();
+        0u32.encode(&mut stream).unwrap();
+        is_optional.encode(&mut stream).unwrap();
+    }
+    let definition = ExtensionObject {
+        node_id: NodeId::new(0, 122),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(stream.into_inner())),
+    };
+    let mut registry = DataTypeRegistry::new();
+    registry.add_data_type_definition(&NodeId::new(2, 3000), "Samples", &definition, &decoding_limits).unwrap();
+    assert_eq!(registry.find_structure_by_encoding(&NodeId::new(2, 3001)).unwrap(), &StructureDefinition {
+        data_type_id: NodeId::new(2, 3000),
+        encoding_id: NodeId::new(2, 3001),
+        name: "Samples".to_string(),
+        structure_type: StructureType::StructureWithOptionalFields,
+        fields: vec![
+            StructureField::new("Values", DataTypeId::Float.into(), 1, false),
+            StructureField::new("Unit", DataTypeId::String.into(), -1, true),
+        ],
+    });
+
+    // An EnumDefinition
+    let mut stream = Cursor::new(Vec::new());
+    1i32.encode(&mut stream).unwrap();
+    7i64.encode(&mut stream).unwrap();
+    LocalizedText::new("", "Seven").encode(&mut stream).unwrap();
+    LocalizedText::null().encode(&mut stream).unwrap();
+    UAString::from("Seven").encode(&mut stream).unwrap();
+    let definition = ExtensionObject {
+        node_id: NodeId::new(0, 123),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(stream.into_inner())),
+    };
+    registry.add_data_type_definition(&NodeId::new(2, 3010), "Number", &definition, &decoding_limits).unwrap();
+    assert_eq!(registry.find_enumeration(&NodeId::new(2, 3010)).unwrap().fields, vec![(7, "Seven".to_string())]);
+    assert!(registry.contains(&NodeId::new(2, 3010)));
+
+    let samples = DynamicStructure::new(NodeId::new(2, 3000), vec![
+        ("Values".to_string(), Variant::from(vec![Variant::Float(1.0), Variant::Float(2.5)])),
+    ]);
+    let extension_object = registry.encode(&samples).unwrap();
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), samples);
+}
+
+const TEST_DICTIONARY: &str = r#"<opc:TypeDictionary xmlns:opc="http://opcfoundation.org/BinarySchema/" xmlns:ua="http://opcfoundation.org/UA/" xmlns:tns="urn:vendor" TargetNamespace="urn:vendor">
+  <opc:Import Namespace="http://opcfoundation.org/UA/" />
+  <opc:EnumeratedType Name="Mode" LengthInBits="32">
+    <opc:EnumeratedValue Name="Off" Value="0" />
+    <opc:EnumeratedValue Name="On" Value="1" />
+  </opc:EnumeratedType>
+  <opc:StructuredType Name="Reading" BaseType="ua:ExtensionObject">
+    <opc:Field Name="CommentSpecified" TypeName="opc:Bit" />
+    <opc:Field Name="Reserved1" TypeName="opc:Bit" Length="31" />
+    <opc:Field Name="Mode" TypeName="tns:Mode" />
+    <opc:Field Name="NoOfSamples" TypeName="opc:Int32" />
+    <opc:Field Name="Samples" TypeName="opc:Double" LengthField="NoOfSamples" />
+    <opc:Field Name="Comment" TypeName="ua:LocalizedText" SwitchField="CommentSpecified" />
+  </opc:StructuredType>
+  <opc:StructuredType Name="Value" BaseType="ua:ExtensionObject">
+    <opc:Field Name="SwitchField" TypeName="opc:UInt32" />
+    <opc:Field Name="Reading" TypeName="tns:Reading" SwitchField="SwitchField" SwitchValue="1" />
+    <opc:Field Name="Text" TypeName="opc:CharArray" SwitchField="SwitchField" SwitchValue="2" />
+  </opc:StructuredType>
+  <opc:StructuredType Name="Unknown" BaseType="ua:ExtensionObject">
+    <opc:Field Name="Other" TypeName="tns:Other" />
+  </opc:StructuredType>
+</opc:TypeDictionary>"#;
+
+#[test]
+fn add_type_dictionary() {
+    let decoding_limits = DecodingLimits::default();
+    let mut data_types = HashMap::new();
+    data_types.insert("Mode".to_string(), (NodeId::new(3, 10), NodeId::null()));
+    data_types.insert("Reading".to_string(), (NodeId::new(3, 20), NodeId::new(3, 21)));
+    data_types.insert("Value".to_string(), (NodeId::new(3, 30), NodeId::new(3, 31)));
+    data_types.insert("Unknown".to_string(), (NodeId::new(3, 40), NodeId::new(3, 41)));
+
+    let mut registry = DataTypeRegistry::new();
+    registry.add_type_dictionary(TEST_DICTIONARY, &data_types).unwrap();
+    assert_eq!(registry.find_enumeration(&NodeId::new(3, 10)).unwrap().fields, vec![(0, "Off".to_string()), (1, "On".to_string())]);
+    assert_eq!(registry.find_structure(&NodeId::new(3, 20)).unwrap(), &StructureDefinition {
+        data_type_id: NodeId::new(3, 20),
+        encoding_id: NodeId::new(3, 21),
+        name: "Reading".to_string(),
+        structure_type: StructureType::StructureWithOptionalFields,
+        fields: vec![
+            StructureField::new("Mode", DataTypeId::Int32.into(), -1, false),
+            StructureField::new("Samples", DataTypeId::Double.into(), 1, false),
+            StructureField::new("Comment", DataTypeId::LocalizedText.into(), -1, true),
+        ],
+    });
+    assert_eq!(registry.find_structure(&NodeId::new(3, 30)).unwrap().structure_type, StructureType::Union);
+    assert_eq!(registry.find_structure(&NodeId::new(3, 30)).unwrap().fields, vec![
+        StructureField::new("Reading", NodeId::new(3, 20), -1, false),
+        StructureField::new("Text", DataTypeId::String.into(), -1, false),
+    ]);
+    // A structure with a field of a type that isn't in the dictionary is skipped
+    assert!(registry.find_structure(&NodeId::new(3, 40)).is_none());
+
+    let reading = DynamicStructure::new(NodeId::new(3, 20), vec![
+        ("Mode".to_string(), Variant::Int32(1)),
+        ("Samples".to_string(), Variant::from(vec![Variant::Double(0.5)])),
+        ("Comment".to_string(), Variant::from(LocalizedText::new("en", "Calibrated"))),
+    ]);
+    let value = DynamicStructure::new(NodeId::new(3, 30), vec![
+        ("Reading".to_string(), Variant::from(registry.encode(&reading).unwrap())),
+    ]);
+    let extension_object = registry.encode(&value).unwrap();
+    assert_eq!(extension_object.node_id, NodeId::new(3, 31));
+    assert_eq!(registry.decode(&extension_object, &decoding_limits).unwrap(), value);
+
+    assert_eq!(registry.add_type_dictionary("<Schema/>", &data_types).unwrap_err(), StatusCode::BadDecodingError);
+}
@@ -9,6 +9,8 @@ use opcua_core::crypto::SecurityPolicy;
 use crate::config::{ClientConfig, ClientEndpoint, ClientUserToken, ANONYMOUS_USER_TOKEN_ID};
 use crate::builder::ClientBuilder;
 
+mod data_type_registry;
+
 fn make_test_file(filename: &str) -> PathBuf {
     let mut path = std::env::temp_dir();
     path.push(filename);
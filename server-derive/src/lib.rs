@@ -0,0 +1,98 @@
+//! Derive macros for the server side of OPC UA, i.e. object types of a server's or companion
+//! specification's own namespace that are modelled by Rust structs.
+//!
+//! `#[derive(UaObjectType)]` implements `UaObjectType` of `opcua-server` for a struct that models
+//! an object type, given its node id in the `type_id` attribute and optionally `browse_name` and
+//! `supertype_id`. Each field is a variable of the instances of the object type, a component unless
+//! marked `#[opcua(property)]`, that reads the field from the shared model and is only written
+//! by clients if marked `#[opcua(writable)]`. A field's data type is that of the built-in type or
+//! the `EncodableType` it holds, or is given by `data_type`. It is named after the field unless
+//! given a `browse_name`, and `#[opcua(skip)]` leaves a field out of the model.
+//!
+//! The code that is generated refers to `opcua_server` and `opcua_types`, which must be
+//! dependencies of the crate that uses the derive.
+//!
+//! ```ignore
+//! #[derive(UaObjectType)]
+//! #[opcua(type_id = "ns=2;i=1000", browse_name = "PumpType")]
+//! pub struct Pump {
+//!     #[opcua(property)]
+//!     pub serial_number: UAString,
+//!     #[opcua(writable)]
+//!     pub speed: f64,
+//! }
+//!
+//! let pump = Arc::new(RwLock::new(Pump { serial_number: "P1".into(), speed: 0.0 }));
+//! let pump_id = Pump::instantiate(&pump, &mut address_space, &AddressSpace::objects_folder_id(), "Pump1")?;
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, DeriveInput, Error, LitStr, Meta, NestedMeta, Result};
+
+mod object_type;
+
+/// Derives `UaObjectType` of `opcua-server` for a struct modelling an object type. See the crate
+/// documentation for the attributes.
+#[proc_macro_derive(UaObjectType, attributes(opcua))]
+pub fn derive_ua_object_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = if !input.generics.params.is_empty() {
+        Err(Error::new_spanned(&input.generics, "UaObjectType cannot be derived for a generic type"))
+    } else {
+        object_type::derive_object_type(&input)
+    };
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Returns the `#[opcua(...)]` items of the attributes
+fn opcua_attributes(attrs: &[Attribute]) -> Result<Vec<Meta>> {
+    let mut items = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("opcua")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(meta) => items.push(meta),
+                    NestedMeta::Literal(lit) => return Err(Error::new_spanned(lit, "Expected an opcua attribute item")),
+                }
+            },
+            meta => return Err(Error::new_spanned(meta, "Expected #[opcua(...)]")),
+        }
+    }
+    Ok(items)
+}
+
+/// Parses a numeric or string node id, e.g. "ns=2;i=5001" or "ns=2;s=Measurement", into the
+/// expression that creates it. The namespace is 0 if it is omitted.
+fn node_id(lit: &LitStr) -> Result<TokenStream2> {
+    let value = lit.value();
+    let (namespace, identifier) = if let Some(rest) = value.strip_prefix("ns=") {
+        let pos = rest.find(';').ok_or_else(|| Error::new_spanned(lit, "Expected a node id such as \"ns=2;i=5001\""))?;
+        let namespace = rest[..pos].parse::<u16>().map_err(|_| Error::new_spanned(lit, "Invalid namespace index"))?;
+        (namespace, &rest[pos + 1..])
+    } else {
+        (0, &value[..])
+    };
+    if let Some(identifier) = identifier.strip_prefix("i=") {
+        let identifier = identifier.parse::<u32>().map_err(|_| Error::new_spanned(lit, "Invalid numeric identifier"))?;
+        Ok(quote!(::opcua_types::NodeId::new(#namespace, #identifier)))
+    } else if let Some(identifier) = identifier.strip_prefix("s=") {
+        Ok(quote!(::opcua_types::NodeId::new(#namespace, #identifier)))
+    } else {
+        Err(Error::new_spanned(lit, "Expected a numeric or string node id such as \"ns=2;i=5001\""))
+    }
+}
+
+/// Converts a snake case field name to the Pascal case of OPC UA, e.g. `node_id` to `NodeId`
+fn pascal_case(name: &str) -> String {
+    name.split('_').filter(|part| !part.is_empty()).map(|part| {
+        let mut chars = part.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    }).collect()
+}
@@ -0,0 +1,152 @@
+//! `#[derive(UaObjectType)]`, which models an object type of the server's address space by a
+//! struct whose fields are the variables of its instances.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Lit, Meta, Result, Type};
+
+use crate::{node_id, opcua_attributes, pascal_case};
+
+/// The options of a field of a modelled object type
+#[derive(Default)]
+struct VariableOptions {
+    skip: bool,
+    is_property: bool,
+    writable: bool,
+    browse_name: Option<String>,
+    description: Option<String>,
+    data_type: Option<TokenStream2>,
+}
+
+pub(crate) fn derive_object_type(input: &DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let named = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new_spanned(ident, "UaObjectType can only be derived for a struct with named fields")),
+        },
+        _ => return Err(Error::new_spanned(ident, "UaObjectType can only be derived for a struct")),
+    };
+
+    let mut object_type_id = None;
+    let mut supertype_id = None;
+    let mut browse_name = ident.to_string();
+    for meta in opcua_attributes(&input.attrs)? {
+        match meta {
+            Meta::NameValue(ref value) => match value.lit {
+                Lit::Str(ref lit) if value.ident == "type_id" => object_type_id = Some(node_id(lit)?),
+                Lit::Str(ref lit) if value.ident == "supertype_id" => supertype_id = Some(node_id(lit)?),
+                Lit::Str(ref lit) if value.ident == "browse_name" => browse_name = lit.value(),
+                _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of an object type")),
+            },
+            _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of an object type")),
+        }
+    }
+    let object_type_id = object_type_id.ok_or_else(|| Error::new_spanned(ident, "An object type needs a type_id, e.g. #[opcua(type_id = \"ns=2;i=1000\")]"))?;
+    let supertype_id = supertype_id.map(|supertype_id| quote! {
+        fn supertype_id() -> ::opcua_types::NodeId {
+            #supertype_id
+        }
+    });
+
+    let mut variables = Vec::with_capacity(named.len());
+    for field in named {
+        let options = variable_options(&field.attrs)?;
+        if options.skip {
+            continue;
+        }
+        let (field_ident, ty) = (field.ident.as_ref().unwrap(), &field.ty);
+        let browse_name = options.browse_name.unwrap_or_else(|| pascal_case(&field_ident.to_string()));
+        let description = options.description.unwrap_or_default();
+        let is_property = options.is_property;
+        let data_type = options.data_type.unwrap_or_else(|| data_type(ty));
+        let set = if options.writable {
+            quote! {{
+                let set: fn(&mut #ident, &::opcua_types::Variant) -> Result<(), ::opcua_types::status_code::StatusCode> = |model, value| {
+                    model.#field_ident = <#ty as ::std::convert::TryFrom<&::opcua_types::Variant>>::try_from(value)?;
+                    Ok(())
+                };
+                Some(set)
+            }}
+        } else {
+            quote!(None)
+        };
+        variables.push(quote! {
+            ::opcua_server::prelude::ModelVariable {
+                browse_name: #browse_name,
+                description: #description,
+                is_property: #is_property,
+                data_type: #data_type,
+                get: |model| ::opcua_types::Variant::from(model.#field_ident.clone()),
+                set: #set,
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl ::opcua_server::prelude::UaObjectType for #ident {
+            fn object_type_id() -> ::opcua_types::NodeId {
+                #object_type_id
+            }
+
+            fn browse_name() -> &'static str {
+                #browse_name
+            }
+
+            #supertype_id
+
+            fn variables() -> Vec<::opcua_server::prelude::ModelVariable<Self>> {
+                vec![#(#variables),*]
+            }
+        }
+    })
+}
+
+fn variable_options(attrs: &[syn::Attribute]) -> Result<VariableOptions> {
+    let mut options = VariableOptions::default();
+    for meta in opcua_attributes(attrs)? {
+        match meta {
+            Meta::Word(ref ident) if ident == "skip" => options.skip = true,
+            Meta::Word(ref ident) if ident == "property" => options.is_property = true,
+            Meta::Word(ref ident) if ident == "component" => options.is_property = false,
+            Meta::Word(ref ident) if ident == "writable" => options.writable = true,
+            Meta::NameValue(ref value) => match value.lit {
+                Lit::Str(ref lit) if value.ident == "browse_name" => options.browse_name = Some(lit.value()),
+                Lit::Str(ref lit) if value.ident == "description" => options.description = Some(lit.value()),
+                Lit::Str(ref lit) if value.ident == "data_type" => options.data_type = Some(node_id(lit)?),
+                _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of a variable")),
+            },
+            _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of a variable")),
+        }
+    }
+    Ok(options)
+}
+
+/// The node id of the data type of a field, which is the built-in data type of a built-in type
+/// or else the data type of an `EncodableType`. Other types need a `data_type` attribute.
+fn data_type(ty: &Type) -> TokenStream2 {
+    let name = if let Type::Path(ref path) = *ty {
+        path.path.segments.iter().last().map(|segment| segment.ident.to_string()).unwrap_or_default()
+    } else {
+        String::new()
+    };
+    let data_type_id = match name.as_ref() {
+        "bool" => "Boolean",
+        "i8" => "SByte",
+        "u8" => "Byte",
+        "i16" => "Int16",
+        "u16" => "UInt16",
+        "i32" => "Int32",
+        "u32" => "UInt32",
+        "i64" => "Int64",
+        "u64" => "UInt64",
+        "f32" => "Float",
+        "f64" => "Double",
+        "UAString" | "String" => "String",
+        "DateTime" | "Guid" | "StatusCode" | "ByteString" | "QualifiedName" | "LocalizedText" | "NodeId" | "ExpandedNodeId" => &name,
+        "ExtensionObject" => "Structure",
+        _ => return quote!(<#ty as ::opcua_types::EncodableType>::data_type_id()),
+    };
+    let data_type_id = syn::Ident::new(data_type_id, proc_macro2::Span::call_site());
+    quote!(::opcua_types::NodeId::new(0, ::opcua_types::node_ids::DataTypeId::#data_type_id as u32))
+}
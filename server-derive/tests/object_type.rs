@@ -0,0 +1,137 @@
+use std::sync::{Arc, RwLock};
+
+use opcua_server::prelude::*;
+use opcua_types::{AttributeId, DataValue, LocalizedText, NodeId, UAString, Variant, node_ids::{DataTypeId, ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId}};
+use opcua_server_derive::UaObjectType;
+use opcua_types_derive::UaEncodable;
+
+#[derive(Debug, Clone, PartialEq, UaEncodable)]
+#[opcua(data_type_id = "ns=2;i=3001", binary_encoding_id = "ns=2;i=5001")]
+struct Range {
+    low: f64,
+    high: f64,
+}
+
+#[derive(UaObjectType)]
+#[opcua(type_id = "ns=2;i=1000", browse_name = "PumpType")]
+struct Pump {
+    #[opcua(property, description = "The serial number of the pump")]
+    serial_number: UAString,
+    #[opcua(writable)]
+    speed: f64,
+    #[opcua(browse_name = "Running")]
+    is_running: bool,
+    #[opcua(writable)]
+    range: Range,
+    #[opcua(skip)]
+    #[allow(dead_code)]
+    revolutions: u64,
+}
+
+#[derive(UaObjectType)]
+#[opcua(type_id = "ns=2;s=BoosterPumpType", supertype_id = "ns=2;i=1000")]
+struct BoosterPump {
+    boost: f32,
+}
+
+fn pump() -> Arc<RwLock<Pump>> {
+    Arc::new(RwLock::new(Pump {
+        serial_number: UAString::from("P-100"),
+        speed: 12.5,
+        is_running: true,
+        range: Range { low: 0.0, high: 50.0 },
+        revolutions: 0,
+    }))
+}
+
+fn children(address_space: &AddressSpace, node_id: &NodeId, reference_type_id: ReferenceTypeId) -> Vec<NodeId> {
+    address_space.find_references_from(node_id, Some((reference_type_id.into(), false)))
+        .unwrap_or_default().into_iter().map(|reference| reference.node_id).collect()
+}
+
+fn find_child(address_space: &AddressSpace, node_id: &NodeId, browse_name: &str) -> NodeId {
+    let mut children = children(address_space, node_id, ReferenceTypeId::HasComponent);
+    children.append(&mut self::children(address_space, node_id, ReferenceTypeId::HasProperty));
+    children.into_iter()
+        .find(|child| address_space.find_node(child).unwrap().as_node().browse_name().name.as_ref() == browse_name)
+        .unwrap_or_else(|| panic!("No child named {}", browse_name))
+}
+
+#[test]
+fn object_type() {
+    let mut address_space = AddressSpace::new();
+    Pump::add_object_type(&mut address_space);
+
+    let pump_type_id = NodeId::new(2, 1000u32);
+    let pump_type = address_space.find_node(&pump_type_id).unwrap().as_node();
+    assert_eq!(pump_type.browse_name().name.as_ref(), "PumpType");
+    assert!(address_space.has_reference(&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, &pump_type_id));
+
+    // Skipped fields are not modelled
+    assert_eq!(children(&address_space, &pump_type_id, ReferenceTypeId::HasProperty).len(), 1);
+    assert_eq!(children(&address_space, &pump_type_id, ReferenceTypeId::HasComponent).len(), 3);
+
+    // The instance declarations are mandatory, of the variable type and data type of their field
+    let serial_number = find_child(&address_space, &pump_type_id, "SerialNumber");
+    assert_eq!(serial_number, NodeId::new(2, "PumpType.SerialNumber"));
+    assert!(address_space.has_reference(&serial_number, ReferenceTypeId::HasTypeDefinition, &VariableTypeId::PropertyType.into()));
+    assert!(address_space.has_reference(&serial_number, ReferenceTypeId::HasModellingRule, &ObjectId::ModellingRule_Mandatory.into()));
+    let serial_number = address_space.find_variable(serial_number).unwrap();
+    assert_eq!(serial_number.description(), Some(LocalizedText::new("", "The serial number of the pump")));
+    assert_eq!(serial_number.find_attribute(AttributeId::DataType).unwrap().value, Some(Variant::from(NodeId::new(0, DataTypeId::String as u32))));
+    let speed = find_child(&address_space, &pump_type_id, "Speed");
+    assert!(address_space.has_reference(&speed, ReferenceTypeId::HasTypeDefinition, &VariableTypeId::BaseDataVariableType.into()));
+    let range = address_space.find_variable(find_child(&address_space, &pump_type_id, "Range")).unwrap();
+    assert_eq!(range.find_attribute(AttributeId::DataType).unwrap().value, Some(Variant::from(NodeId::new(2, 3001u32))));
+
+    // Adding it again does nothing
+    Pump::add_object_type(&mut address_space);
+
+    // A subtype of the pump type
+    BoosterPump::add_object_type(&mut address_space);
+    let booster_pump_type_id = NodeId::new(2, "BoosterPumpType");
+    assert_eq!(address_space.find_node(&booster_pump_type_id).unwrap().as_node().browse_name().name.as_ref(), "BoosterPump");
+    assert!(address_space.has_reference(&pump_type_id, ReferenceTypeId::HasSubtype, &booster_pump_type_id));
+}
+
+#[test]
+fn instantiate() {
+    let mut address_space = AddressSpace::new();
+    let model = pump();
+    let folder_id = address_space.add_folder("Pumps", "Pumps", &AddressSpace::objects_folder_id()).unwrap();
+    let pump_id = Pump::instantiate(&model, &mut address_space, &folder_id, "Pump1").unwrap();
+
+    // The object type is added along with the object, which the folder organizes
+    assert!(address_space.node_exists(&NodeId::new(2, 1000u32)));
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasTypeDefinition, &NodeId::new(2, 1000u32)));
+    assert!(address_space.has_reference(&folder_id, ReferenceTypeId::Organizes, &pump_id));
+    assert_eq!(address_space.find_node(&pump_id).unwrap().as_node().browse_name().name.as_ref(), "Pump1");
+
+    // Variables read the model
+    let speed_id = find_child(&address_space, &pump_id, "Speed");
+    assert_eq!(address_space.find_variable(speed_id.clone()).unwrap().value().value, Some(Variant::Double(12.5)));
+    model.write().unwrap().speed = 20.0;
+    assert_eq!(address_space.find_variable(speed_id.clone()).unwrap().value().value, Some(Variant::Double(20.0)));
+    let running_id = find_child(&address_space, &pump_id, "Running");
+    assert_eq!(address_space.find_variable(running_id.clone()).unwrap().value().value, Some(Variant::Boolean(true)));
+    let range_id = find_child(&address_space, &pump_id, "Range");
+    assert_eq!(address_space.find_variable(range_id.clone()).unwrap().value().value, Some(Variant::from(Range { low: 0.0, high: 50.0 })));
+
+    // Writable variables write the model, others are read only
+    let speed = address_space.find_variable_mut(speed_id).unwrap();
+    assert!(speed.is_writable());
+    speed.set_attribute(AttributeId::Value, DataValue::new(30.0f64)).unwrap();
+    assert_eq!(model.read().unwrap().speed, 30.0);
+    assert_eq!(speed.set_attribute(AttributeId::Value, DataValue::new("fast")).unwrap_err(), StatusCode::BadTypeMismatch);
+    assert_eq!(model.read().unwrap().speed, 30.0);
+    let range = address_space.find_variable_mut(range_id).unwrap();
+    range.set_attribute(AttributeId::Value, DataValue::new(Range { low: 1.0, high: 2.0 })).unwrap();
+    assert_eq!(model.read().unwrap().range, Range { low: 1.0, high: 2.0 });
+    assert!(!address_space.find_variable(running_id).unwrap().is_writable());
+
+    // An object that is not a folder has the instance as a component
+    let second_id = Pump::instantiate(&pump(), &mut address_space, &pump_id, "Pump2").unwrap();
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasComponent, &second_id));
+
+    assert_eq!(Pump::instantiate(&pump(), &mut address_space, &NodeId::new(2, "Missing"), "Pump3").unwrap_err(), StatusCode::BadParentNodeIdInvalid);
+}
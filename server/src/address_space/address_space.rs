@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex, RwLock};
 
 use chrono::Utc;
@@ -16,9 +16,12 @@ use crate::{
         node::{Node, NodeType, HasNodeId},
         object::Object,
         variable::Variable,
+        EventNotifier,
         method_impls,
     },
     diagnostics::ServerDiagnostics,
+    events::{event::Event, limit_alarm::{self, LimitAlarm}},
+    historical::{aggregates, HistoricalDataProvider, MemoryHistoricalDataProvider},
     state::ServerState,
     session::Session,
     constants,
@@ -86,16 +89,18 @@ macro_rules! server_diagnostics_summary {
 
 /// The `NodeId` is the target node. The reference is held in a list by the source node.
 /// The target node does not need to exist.
+/// The reference type is the node id of a reference type node, which may be one of the standard
+/// reference types of `ReferenceTypeId` or a custom one.
 #[derive(Debug, Clone)]
 pub struct Reference {
-    pub reference_type_id: ReferenceTypeId,
+    pub reference_type_id: NodeId,
     pub node_id: NodeId,
 }
 
 impl Reference {
-    pub fn new(reference_type_id: ReferenceTypeId, node_id: &NodeId) -> Reference {
+    pub fn new<T>(reference_type_id: T, node_id: &NodeId) -> Reference where T: Into<NodeId> {
         Reference {
-            reference_type_id,
+            reference_type_id: reference_type_id.into(),
             node_id: node_id.clone(),
         }
     }
@@ -107,7 +112,25 @@ pub enum ReferenceDirection {
     Inverse,
 }
 
-type MethodCallback = Box<dyn Fn(&AddressSpace, &ServerState, &mut Session, &CallMethodRequest) -> Result<CallMethodResult, StatusCode> + Send + Sync + 'static>;
+type MethodHandlerFn = dyn Fn(&AddressSpace, &ServerState, &mut Session, &CallMethodRequest) -> Result<CallMethodResult, StatusCode> + Send + Sync + 'static;
+
+type MethodMutHandlerFn = dyn Fn(&mut AddressSpace, &ServerState, &mut Session, &CallMethodRequest) -> Result<CallMethodResult, StatusCode> + Send + Sync + 'static;
+
+type MethodCallback = Box<MethodHandlerFn>;
+
+type MethodMutCallback = Box<MethodMutHandlerFn>;
+
+/// A registered method handler. A handler that modifies the address space is shared so it can
+/// be called with the address space mutably borrowed.
+#[derive(Clone)]
+enum MethodHandler {
+    Shared(Arc<MethodHandlerFn>),
+    Mut(Arc<MethodMutHandlerFn>),
+}
+
+type DataEncoder = dyn Fn(&ExtensionObject) -> Result<ExtensionObjectEncoding, StatusCode> + Send + Sync + 'static;
+
+type DataEncoderCallback = Box<DataEncoder>;
 
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 struct MethodKey {
@@ -127,11 +150,21 @@ pub struct AddressSpace {
     /// This is the last time that nodes or references to nodes were added or removed from the address space.
     last_modified: DateTimeUtc,
     /// Method handlers
-    method_handlers: HashMap<MethodKey, MethodCallback>,
+    method_handlers: HashMap<MethodKey, MethodHandler>,
+    /// Encoders that re-encode the binary bodies of structures, by the data type of the structure
+    /// and the encoding they produce
+    data_encoders: HashMap<(NodeId, DataEncoding), DataEncoderCallback>,
     /// Access to server diagnostics
     server_diagnostics: Option<Arc<RwLock<ServerDiagnostics>>>,
-    /// A map of subtypes
-    reference_type_subtypes: HashSet<(ReferenceTypeId, ReferenceTypeId)>,
+    /// Records and reads the values of historizing variables
+    historical_data_provider: Arc<RwLock<dyn HistoricalDataProvider + Send + Sync>>,
+    /// Recently raised events, oldest first, for monitored items to pick up
+    recent_events: VecDeque<Event>,
+    /// The number of the last event raised. Events are numbered from 1.
+    last_event_number: u64,
+    /// The limit alarms bound to variables, by the id of the variable, as the alarm id and its
+    /// definition
+    limit_alarms: HashMap<NodeId, Vec<(NodeId, LimitAlarm)>>,
 }
 
 impl AddressSpace {
@@ -145,8 +178,12 @@ impl AddressSpace {
             inverse_references: HashMap::new(),
             last_modified: Utc::now(),
             method_handlers: HashMap::new(),
+            data_encoders: HashMap::new(),
             server_diagnostics: None,
-            reference_type_subtypes: HashSet::new(),
+            historical_data_provider: Arc::new(RwLock::new(MemoryHistoricalDataProvider::new(constants::DEFAULT_HISTORY_CAPACITY))),
+            recent_events: VecDeque::with_capacity(constants::MAX_RECENT_EVENTS),
+            last_event_number: 0,
+            limit_alarms: HashMap::new(),
         };
         address_space.add_default_nodes();
         address_space
@@ -157,6 +194,29 @@ impl AddressSpace {
         self.last_modified.clone()
     }
 
+    /// Returns the provider that records and reads the values of historizing variables
+    pub fn historical_data_provider(&self) -> Arc<RwLock<dyn HistoricalDataProvider + Send + Sync>> {
+        self.historical_data_provider.clone()
+    }
+
+    /// Replaces the provider that records and reads the values of historizing variables. By
+    /// default values are held in memory by a `MemoryHistoricalDataProvider`.
+    pub fn set_historical_data_provider(&mut self, historical_data_provider: Arc<RwLock<dyn HistoricalDataProvider + Send + Sync>>) {
+        self.historical_data_provider = historical_data_provider;
+    }
+
+    /// Records the current value of a variable with the historical data provider if the variable is
+    /// historizing. This happens automatically when a value is set through `set_variable_value` or
+    /// written by a client, but must be called if the value is changed some other way.
+    pub fn record_historical_value(&self, node_id: &NodeId) {
+        if let Some(variable) = self.find_variable_by_ref(node_id) {
+            if variable.historizing() {
+                let mut historical_data_provider = trace_write_lock_unwrap!(self.historical_data_provider);
+                historical_data_provider.record_value(node_id, &variable.value());
+            }
+        }
+    }
+
     /// Sets the getter for a variable node
     pub fn set_variable_getter<N, F>(&mut self, variable_id: N, getter: F) where
         N: Into<NodeId>,
@@ -240,6 +300,17 @@ impl AddressSpace {
             v.set_value_direct(Variant::from(&server_profiles[..]), &now, &now);
         }
 
+        // Server_ServerCapabilities_AggregateFunctions organizes the aggregates that HistoryRead supports
+        {
+            let aggregate_functions_id: NodeId = ObjectId::Server_ServerCapabilities_AggregateFunctions.into();
+            for aggregate_function in aggregates::SUPPORTED_AGGREGATES {
+                let aggregate_function_id: NodeId = (*aggregate_function).into();
+                if !self.has_reference(&aggregate_functions_id, ReferenceTypeId::Organizes, &aggregate_function_id) {
+                    self.insert_reference(&aggregate_functions_id, &aggregate_function_id, ReferenceTypeId::Organizes);
+                }
+            }
+        }
+
         // Server_ServerCapabilities_LocaleIdArray
         // Server_ServerCapabilities_MinSupportedSampleRate
 
@@ -313,6 +384,7 @@ impl AddressSpace {
         // Server method handlers
         self.register_method_handler(ObjectId::Server, MethodId::Server_GetMonitoredItems, Box::new(method_impls::handle_get_monitored_items));
         self.register_method_handler(ObjectId::Server, MethodId::Server_ResendData, Box::new(method_impls::handle_resend_data));
+        self.register_method_handler(ObjectId::Server, MethodId::ConditionType_ConditionRefresh, Box::new(method_impls::handle_condition_refresh));
     }
 
     /// Returns the node id for the root folder
@@ -355,6 +427,40 @@ impl AddressSpace {
         expect_and_find_object!(self, &AddressSpace::views_folder_id())
     }
 
+    /// Returns the namespace URIs in the server's NamespaceArray, where the index of each is its
+    /// namespace index. The OPC UA namespace is always first.
+    pub fn namespace_uris(&self) -> Vec<String> {
+        let mut namespace_uris = Vec::new();
+        if let Some(variable) = self.find_variable(VariableId::Server_NamespaceArray) {
+            if let Some(Variant::Array(values)) = variable.value().value {
+                namespace_uris = values.into_iter().map(|v| v.to_string()).collect();
+            }
+        }
+        if namespace_uris.is_empty() {
+            namespace_uris.push(constants::OPC_UA_NAMESPACE_URI.to_string());
+        }
+        namespace_uris
+    }
+
+    /// Returns the namespace index of the URI in the NamespaceArray, if it is there
+    pub fn namespace_index(&self, namespace_uri: &str) -> Option<u16> {
+        self.namespace_uris().iter().position(|uri| uri == namespace_uri).map(|idx| idx as u16)
+    }
+
+    /// Returns the namespace index of the URI, adding the URI to the end of the NamespaceArray
+    /// if it is not there already.
+    pub fn register_namespace(&mut self, namespace_uri: &str) -> u16 {
+        let mut namespace_uris = self.namespace_uris();
+        if let Some(idx) = namespace_uris.iter().position(|uri| uri == namespace_uri) {
+            idx as u16
+        } else {
+            namespace_uris.push(namespace_uri.to_string());
+            let now = DateTime::now();
+            self.set_variable_value(VariableId::Server_NamespaceArray, &namespace_uris, &now, &now);
+            (namespace_uris.len() - 1) as u16
+        }
+    }
+
     /// Inserts a node into the address space node map and its references to other nodes.
     /// The tuple of references is the node id, reference type id and a bool which is false for
     /// a forward reference and indicating inverse
@@ -372,8 +478,8 @@ impl AddressSpace {
                 let (node_id_other, reference_type_id, reference_direction) = r;
                 self.insert_references(&[
                     match reference_direction {
-                        ReferenceDirection::Forward => (&node_id, node_id_other, *reference_type_id),
-                        ReferenceDirection::Inverse => (node_id_other, &node_id, *reference_type_id),
+                        ReferenceDirection::Forward => (&node_id, *node_id_other, *reference_type_id),
+                        ReferenceDirection::Inverse => (*node_id_other, &node_id, *reference_type_id),
                     }]);
             });
         }
@@ -396,26 +502,76 @@ impl AddressSpace {
         debug!("finished populating address space, number of nodes = {}, number of references = {}, number of reverse references = {}",
                self.node_map.len(), self.references.len(), self.inverse_references.len());
 
-        // Build up the map of subtypes
-        self.build_reference_type_subtypes();
+        // The Server object is the notifier of every event
+        if let Some(NodeType::Object(ref mut server)) = self.find_node_mut(&ObjectId::Server.into()) {
+            server.set_event_notifier(EventNotifier::SUBSCRIBE_TO_EVENTS);
+        }
+        // Conditions are refreshed through the Server object
+        self.insert_reference(&ObjectId::Server.into(), &MethodId::ConditionType_ConditionRefresh.into(), ReferenceTypeId::HasComponent);
     }
 
     // Inserts a bunch of references between two nodes into the address space
-    pub fn insert_references(&mut self, references: &[(&NodeId, &NodeId, ReferenceTypeId)]) {
+    pub fn insert_references<T>(&mut self, references: &[(&NodeId, &NodeId, T)]) where T: Into<NodeId> + Clone {
         references.iter().for_each(|reference| {
-            let (node_id_from, node_id_to, reference_type_id) = *reference;
+            let (node_id_from, node_id_to) = (reference.0, reference.1);
+            let reference_type_id: NodeId = reference.2.clone().into();
             if node_id_from == node_id_to {
                 panic!("Node id from == node id to {:?}", node_id_from);
             }
-            AddressSpace::add_reference(&mut self.references, node_id_from, Reference::new(reference_type_id, node_id_to));
+            AddressSpace::add_reference(&mut self.references, node_id_from, Reference::new(reference_type_id.clone(), node_id_to));
             AddressSpace::add_reference(&mut self.inverse_references, node_id_to, Reference::new(reference_type_id, node_id_from));
         });
         self.update_last_modified();
     }
 
     /// Inserts a single reference between two nodes in the address space
-    pub fn insert_reference(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId) {
-        self.insert_references(&[(node_id_from, node_id_to, reference_type_id)]);
+    pub fn insert_reference<T>(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: T) where T: Into<NodeId> {
+        self.insert_references(&[(node_id_from, node_id_to, reference_type_id.into())]);
+    }
+
+    /// Deletes a node from the address space, together with the references it holds to other nodes.
+    /// If `delete_target_references` is true, references from other nodes that target this node
+    /// are also deleted. Returns false if the node does not exist.
+    pub fn delete_node(&mut self, node_id: &NodeId, delete_target_references: bool) -> bool {
+        if self.node_map.remove(node_id).is_none() {
+            return false;
+        }
+
+        // Remove the references from this node, and the inverse references held by the targets
+        if let Some(references) = self.references.remove(node_id) {
+            references.iter().for_each(|r| {
+                AddressSpace::remove_reference(&mut self.inverse_references, &r.node_id, &r.reference_type_id, node_id);
+            });
+        }
+
+        // Remove the inverse references of this node, and if asked the references from other nodes
+        // that target it
+        if let Some(inverse_references) = self.inverse_references.remove(node_id) {
+            if delete_target_references {
+                inverse_references.iter().for_each(|r| {
+                    AddressSpace::remove_reference(&mut self.references, &r.node_id, &r.reference_type_id, node_id);
+                });
+            }
+        }
+
+        self.method_handlers.retain(|k, _| k.object_id != *node_id && k.method_id != *node_id);
+        let _ = self.limit_alarms.remove(node_id);
+        self.limit_alarms.values_mut().for_each(|limit_alarms| limit_alarms.retain(|(alarm_id, _)| alarm_id != node_id));
+
+        self.update_last_modified();
+        true
+    }
+
+    /// Deletes a reference between two nodes, both the forward reference and its inverse. Returns
+    /// false if the reference does not exist.
+    pub fn delete_reference<T>(&mut self, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: T) -> bool where T: Into<NodeId> {
+        let reference_type_id = reference_type_id.into();
+        let deleted = AddressSpace::remove_reference(&mut self.references, node_id_from, &reference_type_id, node_id_to);
+        AddressSpace::remove_reference(&mut self.inverse_references, node_id_to, &reference_type_id, node_id_from);
+        if deleted {
+            self.update_last_modified();
+        }
+        deleted
     }
 
     pub fn set_object_type(&mut self, node_id: &NodeId, object_type: ObjectTypeId) {
@@ -450,6 +606,11 @@ impl AddressSpace {
         self.node_map.get(node_id)
     }
 
+    /// Returns every node in the address space, in no particular order
+    pub(crate) fn nodes(&self) -> impl Iterator<Item=&NodeType> {
+        self.node_map.values()
+    }
+
     pub fn find_node_mut(&mut self, node_id: &NodeId) -> Option<&mut NodeType> {
         self.node_map.get_mut(node_id)
     }
@@ -458,67 +619,64 @@ impl AddressSpace {
         self.node_map.contains_key(node_id)
     }
 
-    /// Used by TranslateBrowsePathToNodeIds, e.g. it could be used from a starting node id pointing
+    /// Used by TranslateBrowsePathsToNodeIds, e.g. it could be used from a starting node id pointing
     /// to a type definition to find instances of that type.
+    ///
+    /// Returns the nodes that the relative path leads to from the node. References to nodes that
+    /// are not in this address space are dangling and skipped. An error is returned if the node
+    /// does not exist, the path is empty, a browse name other than the last is empty or there are
+    /// no matching nodes.
     pub(crate) fn find_nodes_relative_path(&self, node_id: &NodeId, relative_path: &RelativePath) -> Result<Vec<NodeId>, StatusCode> {
-        // TODO THIS CODE IS PROBABLY BROKEN - need test examples for TranslateBrowsePathToNodeIds
-        if self.find_node(node_id).is_none() {
-            Err(StatusCode::BadNodeIdUnknown)
-        } else {
-            let relative_path_elements = relative_path.elements.as_ref().unwrap();
-            if relative_path_elements.is_empty() {
-                Err(StatusCode::BadNothingToDo)
-            } else {
-                let mut matching_nodes = vec![node_id.clone()];
-                let mut next_matching_nodes = Vec::with_capacity(100);
-
-                // Traverse the relative path elements
-                for relative_path_element in relative_path_elements.iter() {
-                    next_matching_nodes.clear();
-
-                    if matching_nodes.is_empty() {
-                        break;
-                    }
+        if node_id.is_null() {
+            return Err(StatusCode::BadNodeIdInvalid);
+        } else if !self.node_exists(node_id) {
+            return Err(StatusCode::BadNodeIdUnknown);
+        }
+        let elements = match relative_path.elements {
+            Some(ref elements) if !elements.is_empty() => elements,
+            _ => return Err(StatusCode::BadNothingToDo),
+        };
+        if elements.iter().rev().skip(1).any(|element| element.target_name.name.is_empty()) {
+            return Err(StatusCode::BadBrowseNameInvalid);
+        }
 
-                    for node_id in &matching_nodes {
-                        // Iterate current set of nodes and put the results into next
-                        if let Some(mut result) = self.follow_relative_path(&node_id, relative_path_element) {
-                            next_matching_nodes.append(&mut result);
+        let mut node_ids = vec![node_id.clone()];
+        for element in elements {
+            let mut next_node_ids = Vec::new();
+            for node_id in &node_ids {
+                for target_id in self.follow_relative_path(node_id, element) {
+                    if let Some(node) = self.find_node(&target_id) {
+                        let matches = element.target_name.name.is_empty() || node.as_node().browse_name() == element.target_name;
+                        if matches && !next_node_ids.contains(&target_id) {
+                            next_node_ids.push(target_id);
                         }
                     }
-
-                    matching_nodes.clear();
-                    matching_nodes.append(&mut next_matching_nodes);
                 }
-
-                Ok(matching_nodes)
             }
+            node_ids = next_node_ids;
+        }
+
+        if node_ids.is_empty() {
+            Err(StatusCode::BadNoMatch)
+        } else {
+            Ok(node_ids)
         }
     }
 
-    fn follow_relative_path(&self, node_id: &NodeId, relative_path: &RelativePathElement) -> Option<Vec<NodeId>> {
-        let reference_type_id = relative_path.reference_type_id.as_reference_type_id().unwrap();
-        let reference_filter = Some((reference_type_id, relative_path.include_subtypes));
-        let references = if relative_path.is_inverse {
+    /// Returns the nodes that the references of the element's type and direction lead to from the
+    /// node. A null reference type follows every reference.
+    fn follow_relative_path(&self, node_id: &NodeId, element: &RelativePathElement) -> Vec<NodeId> {
+        let reference_filter = if element.reference_type_id.is_null() {
+            None
+        } else {
+            Some((element.reference_type_id.clone(), element.include_subtypes))
+        };
+        let references = if element.is_inverse {
             self.find_references_to(node_id, reference_filter)
         } else {
             self.find_references_from(node_id, reference_filter)
         };
-        if let Some(references) = references {
-            let compare_target_name = !relative_path.target_name.is_null();
-            let mut result = Vec::with_capacity(references.len());
-            for reference in &references {
-                if let Some(node) = self.find_node(&reference.node_id) {
-                    let node = node.as_node();
-                    if !compare_target_name || node.browse_name() == relative_path.target_name {
-                        result.push(reference.node_id.clone());
-                    }
-                }
-            }
-            Some(result)
-        } else {
-            None
-        }
+        references.unwrap_or_default().into_iter().map(|reference| reference.node_id).collect()
     }
 
     /// Adds a node as a child (organized by) another node. The type id says what kind of node the object
@@ -583,6 +741,21 @@ impl AddressSpace {
         }
     }
 
+    /// Removes the reference held by a node to a target. Returns true if there was such a reference.
+    fn remove_reference(reference_map: &mut HashMap<NodeId, Vec<Reference>>, node_id: &NodeId, reference_type_id: &NodeId, target_node_id: &NodeId) -> bool {
+        if let Some(references) = reference_map.get_mut(node_id) {
+            let len = references.len();
+            references.retain(|r| !(r.reference_type_id == *reference_type_id && r.node_id == *target_node_id));
+            let removed = references.len() != len;
+            if references.is_empty() {
+                reference_map.remove(node_id);
+            }
+            removed
+        } else {
+            false
+        }
+    }
+
     /// Find and return a variable with the specified node id or return None if it cannot be
     /// found or is not a variable
     pub fn find_variable<N>(&self, node_id: N) -> Option<&Variable> where N: Into<NodeId> {
@@ -636,9 +809,29 @@ impl AddressSpace {
         where V: Into<Variant> {
         if let Some(ref mut variable) = self.find_variable_mut_by_ref(node_id) {
             variable.set_value_direct(value, source_timestamp, server_timestamp);
-            true
         } else {
-            false
+            return false;
+        }
+        self.record_historical_value(node_id);
+        self.evaluate_limit_alarms(node_id);
+        true
+    }
+
+    /// Binds a limit alarm to its input variable
+    pub(crate) fn bind_limit_alarm(&mut self, input_node_id: &NodeId, alarm_id: &NodeId, limit_alarm: LimitAlarm) {
+        self.limit_alarms.entry(input_node_id.clone()).or_default().push((alarm_id.clone(), limit_alarm));
+    }
+
+    /// Evaluates the limit alarms bound to a variable against its current value. This happens
+    /// automatically when a value is set through `set_variable_value` or written by a client, but
+    /// must be called if the value is changed some other way.
+    pub fn evaluate_limit_alarms(&mut self, node_id: &NodeId) {
+        if let Some(limit_alarms) = self.limit_alarms.get(node_id).cloned() {
+            for (alarm_id, limit_alarm) in limit_alarms {
+                if let Err(status_code) = limit_alarm::evaluate(self, &alarm_id, node_id, &limit_alarm) {
+                    error!("Limit alarm {:?} could not be evaluated, {}", alarm_id, status_code);
+                }
+            }
         }
     }
 
@@ -654,23 +847,131 @@ impl AddressSpace {
 
     /// Registers a method callback on the specified object id and method id
     pub fn register_method_handler<N1, N2>(&mut self, object_id: N1, method_id: N2, handler: MethodCallback) where N1: Into<NodeId>, N2: Into<NodeId> {
+        self.insert_method_handler(object_id.into(), method_id.into(), MethodHandler::Shared(Arc::from(handler)));
+    }
+
+    /// Registers a method callback that modifies the address space on the specified object id and
+    /// method id. A method with such a handler can only be called through `call_method_mut`.
+    pub fn register_method_handler_mut<N1, N2>(&mut self, object_id: N1, method_id: N2, handler: MethodMutCallback) where N1: Into<NodeId>, N2: Into<NodeId> {
+        self.insert_method_handler(object_id.into(), method_id.into(), MethodHandler::Mut(Arc::from(handler)));
+    }
+
+    fn insert_method_handler(&mut self, object_id: NodeId, method_id: NodeId, handler: MethodHandler) {
         // Check the object id and method id actually exist as things in the address space
-        let object_id = object_id.into();
-        let method_id = method_id.into();
         if !is_object!(self, &object_id) || !is_method!(self, &method_id) {
             panic!("Invalid id {:?} / {:?} supplied to method handler", object_id, method_id)
         }
         let key = MethodKey { object_id, method_id };
-        if let Some(_) = self.method_handlers.insert(key, handler) {
+        if self.method_handlers.insert(key, handler).is_some() {
             trace!("Registration replaced a previous callback");
         }
     }
 
+    /// Registers an encoder that produces the body of a structure of the data type with another
+    /// encoding from its binary encoded body. Clients that read the structure with that data
+    /// encoding are returned the body the encoder produces. The data type must have an encoding
+    /// object for the encoding. An encoder for the XML encoding of a generated type replaces its
+    /// built-in XML encoding.
+    pub fn register_data_encoder<N>(&mut self, data_type_id: N, data_encoding: DataEncoding, encoder: DataEncoderCallback) where N: Into<NodeId> {
+        let data_type_id = data_type_id.into();
+        if !is_node!(self, &data_type_id, DataType) {
+            panic!("Invalid data type id {:?} supplied to data encoder", data_type_id)
+        }
+        if self.data_encoders.insert((data_type_id, data_encoding), encoder).is_some() {
+            trace!("Registration replaced a previous data encoder");
+        }
+    }
+
+    /// Finds the encoding object of the data type for the encoding, i.e. the target of the
+    /// data type's HasEncoding reference with the encoding's browse name
+    pub fn find_data_type_encoding(&self, data_type_id: &NodeId, data_encoding: DataEncoding) -> Option<NodeId> {
+        let browse_name = data_encoding.browse_name();
+        self.find_references_from(data_type_id, Some((ReferenceTypeId::HasEncoding.into(), false)))
+            .and_then(|references| {
+                references.into_iter()
+                    .map(|reference| reference.node_id)
+                    .find(|node_id| {
+                        self.find_node(node_id).map(|node| node.as_node().browse_name() == browse_name).unwrap_or(false)
+                    })
+            })
+    }
+
+    /// Returns the value with the structures in it encoded with the data encoding. Fails with
+    /// `BadDataEncodingInvalid` if the value does not hold structures or their data type has no
+    /// such encoding, and with `BadDataEncodingUnsupported` if the server cannot produce it.
+    pub fn encode_value(&self, value: &Variant, data_encoding: DataEncoding) -> Result<Variant, StatusCode> {
+        match *value {
+            Variant::ExtensionObject(ref extension_object) => {
+                Ok(Variant::from(self.encode_extension_object(extension_object, data_encoding)?))
+            }
+            Variant::Array(ref values) => {
+                let values = values.iter()
+                    .map(|value| self.encode_value(value, data_encoding))
+                    .collect::<Result<Vec<Variant>, StatusCode>>()?;
+                Ok(Variant::Array(values))
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let values = mda.values.iter()
+                    .map(|value| self.encode_value(value, data_encoding))
+                    .collect::<Result<Vec<Variant>, StatusCode>>()?;
+                Ok(Variant::new_multi_dimension_array(values, mda.dimensions.clone()))
+            }
+            _ => {
+                Err(StatusCode::BadDataEncodingInvalid)
+            }
+        }
+    }
+
+    /// Encodes a structure with the data encoding. The structure is identified by one of its data
+    /// type's encoding objects, or the data type itself.
+    fn encode_extension_object(&self, extension_object: &ExtensionObject, data_encoding: DataEncoding) -> Result<ExtensionObject, StatusCode> {
+        if extension_object.is_null() {
+            return Ok(extension_object.clone());
+        }
+        let data_type_id = if is_node!(self, &extension_object.node_id, DataType) {
+            extension_object.node_id.clone()
+        } else {
+            self.find_references_to(&extension_object.node_id, Some((ReferenceTypeId::HasEncoding.into(), false)))
+                .map(|references| references[0].node_id.clone())
+                .ok_or(StatusCode::BadDataEncodingInvalid)?
+        };
+        let encoding_id = self.find_data_type_encoding(&data_type_id, data_encoding)
+            .ok_or(StatusCode::BadDataEncodingInvalid)?;
+        // The body is binary if it is identified by the binary encoding or by the data type
+        let is_binary = if let ExtensionObjectEncoding::ByteString(_) = extension_object.body {
+            extension_object.node_id == data_type_id ||
+                self.find_data_type_encoding(&data_type_id, DataEncoding::Binary).as_ref() == Some(&extension_object.node_id)
+        } else {
+            false
+        };
+        let body = if extension_object.node_id == encoding_id || (is_binary && data_encoding == DataEncoding::Binary) {
+            // Already in the encoding
+            extension_object.body.clone()
+        } else if is_binary {
+            // Encoders take the binary encoded body. Generated types have a built-in XML encoding
+            // which is used unless an encoder is registered for them.
+            if let Some(encoder) = self.data_encoders.get(&(data_type_id, data_encoding)) {
+                encoder(extension_object)?
+            } else if data_encoding == DataEncoding::Xml {
+                extension_object.to_xml_encoding(&DecodingLimits::default())?.body
+            } else {
+                return Err(StatusCode::BadDataEncodingUnsupported);
+            }
+        } else {
+            return Err(StatusCode::BadDataEncodingUnsupported);
+        };
+        Ok(ExtensionObject {
+            node_id: encoding_id,
+            body,
+        })
+    }
+
     /// This finds the type definition (if any corresponding to the input object)
-    fn get_type_id(&self, node_id: &NodeId) -> Option<NodeId> {
+    pub(crate) fn get_type_id(&self, node_id: &NodeId) -> Option<NodeId> {
         if let Some(references) = self.references.get(&node_id) {
+            let has_type_definition: NodeId = ReferenceTypeId::HasTypeDefinition.into();
             if let Some(reference) = references.iter().find(|r| {
-                r.reference_type_id == ReferenceTypeId::HasTypeDefinition
+                r.reference_type_id == has_type_definition
             }) {
                 Some(reference.node_id.clone())
             } else {
@@ -682,7 +983,8 @@ impl AddressSpace {
     }
 
     /// Test if a reference relationship exists between one node and another node
-    fn has_reference(&self, from_node_id: &NodeId, reference_type: ReferenceTypeId, to_node_id: &NodeId) -> bool {
+    pub fn has_reference<T>(&self, from_node_id: &NodeId, reference_type: T, to_node_id: &NodeId) -> bool where T: Into<NodeId> {
+        let reference_type: NodeId = reference_type.into();
         if let Some(references) = self.references.get(&from_node_id) {
             references.iter().find(|r| {
                 r.reference_type_id == reference_type && r.node_id == *to_node_id
@@ -693,13 +995,22 @@ impl AddressSpace {
     }
 
     /// Tests if a method exists on a specific object. This will be true if the method id is
-    /// a HasComponent of the object itself, or a HasComponent of the object type
+    /// a HasComponent of the object itself, or a HasComponent of the object type or one of its
+    /// supertypes
     fn method_exists_on_object(&self, object_id: &NodeId, method_id: &NodeId) -> bool {
-        // Look for the method first on the object id, else on the object's type
+        // Look for the method first on the object id, else on the object's type and its supertypes
         if self.has_reference(object_id, ReferenceTypeId::HasComponent, method_id) {
             true
         } else if let Some(object_type_id) = self.get_type_id(object_id) {
-            self.has_reference(&object_type_id, ReferenceTypeId::HasComponent, method_id)
+            let mut type_id = Some(object_type_id);
+            while let Some(object_type_id) = type_id {
+                if self.has_reference(&object_type_id, ReferenceTypeId::HasComponent, method_id) {
+                    return true;
+                }
+                type_id = self.find_references_to(&object_type_id, Some((ReferenceTypeId::HasSubtype.into(), false)))
+                    .map(|references| references[0].node_id.clone());
+            }
+            false
         } else {
             error!("Method call to {:?} on {:?} but the method id is not on the object or its object type!", method_id, object_id);
             false
@@ -710,7 +1021,28 @@ impl AddressSpace {
     ///
     /// Calls require a registered handler to handle the method. If there is no handler, or if
     /// the request refers to a non existent object / method, the function will return an error.
+    /// A method whose handler modifies the address space must be called with `call_method_mut`.
     pub fn call_method(&self, server_state: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+        match self.find_method_handler(request)? {
+            MethodHandler::Shared(handler) => handler(self, server_state, session, request),
+            MethodHandler::Mut(_) => {
+                error!("Method call to {:?} on {:?} has a handler that modifies the address space, call_method_mut must be used", request.method_id, request.object_id);
+                Err(StatusCode::BadNotSupported)
+            }
+        }
+    }
+
+    /// Calls a method node with the supplied request and expecting a result, in the same way
+    /// as `call_method` but also for methods whose handler modifies the address space.
+    pub fn call_method_mut(&mut self, server_state: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+        match self.find_method_handler(request)? {
+            MethodHandler::Shared(handler) => handler(self, server_state, session, request),
+            MethodHandler::Mut(handler) => handler(self, server_state, session, request),
+        }
+    }
+
+    /// Finds the registered handler for a method call
+    fn find_method_handler(&self, request: &CallMethodRequest) -> Result<MethodHandler, StatusCode> {
         let (object_id, method_id) = (&request.object_id, &request.method_id);
 
         // Handle the call
@@ -732,9 +1064,8 @@ impl AddressSpace {
                 method_id: method_id.clone(),
             };
             if let Some(handler) = self.method_handlers.get(&key) {
-                // Call the handler
                 trace!("Method call to {:?} on {:?} being handled by a registered handler", method_id, object_id);
-                handler(self, server_state, session, request)
+                Ok(handler.clone())
             } else {
                 // TODO we could do a secondary search on a (NodeId::null(), method_id) here
                 //  so that method handler is reusable for multiple objects
@@ -744,63 +1075,39 @@ impl AddressSpace {
         }
     }
 
-    /// Builds a set of pairs which denote valid parent / subtypes
-    fn build_reference_type_subtypes(&mut self) {
-        // This is a hard coded hack but potentially it could be modified to build subtypes
-        // by walking the address space.
-
-        // TODO somehow work out subtypes
-
-        self.reference_type_subtypes = [
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasChild),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasSubtype),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::Organizes),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::Aggregates),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasProperty),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasComponent),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasOrderedComponent),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasEventSource),
-            (ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasNotifier),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::Aggregates),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::HasComponent),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::HasHistoricalConfiguration),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::HasProperty),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::HasOrderedComponent),
-            (ReferenceTypeId::HasChild, ReferenceTypeId::HasSubtype),
-            (ReferenceTypeId::Aggregates, ReferenceTypeId::HasComponent),
-            (ReferenceTypeId::Aggregates, ReferenceTypeId::HasHistoricalConfiguration),
-            (ReferenceTypeId::Aggregates, ReferenceTypeId::HasProperty),
-            (ReferenceTypeId::Aggregates, ReferenceTypeId::HasOrderedComponent),
-            (ReferenceTypeId::HasComponent, ReferenceTypeId::HasOrderedComponent),
-            (ReferenceTypeId::HasEventSource, ReferenceTypeId::HasNotifier),
-        ].iter().map(|(r1, r2)| (*r1, *r2)).collect()
-    }
-
-    fn reference_type_matches(&self, r1: ReferenceTypeId, r2: ReferenceTypeId, include_subtypes: bool) -> bool {
+    /// Tests if the node is a reference type, standard or custom
+    pub fn is_reference_type(&self, node_id: &NodeId) -> bool {
+        is_node!(self, node_id, ReferenceType)
+    }
+
+    /// Tests if the reference type `r2` matches `r1`, optionally also if it is a subtype of `r1`.
+    /// Subtypes are found by following the HasSubtype references of the address space, so they
+    /// include custom reference types.
+    pub fn reference_type_matches(&self, r1: &NodeId, r2: &NodeId, include_subtypes: bool) -> bool {
         if r1 == r2 {
             true
         } else if include_subtypes {
-            self.reference_type_subtypes.contains(&(r1, r2))
+            self.is_subtype(r2, r1)
         } else {
             false
         }
     }
 
-    fn filter_references_by_type(&self, references: &Vec<Reference>, reference_filter: Option<(ReferenceTypeId, bool)>) -> Vec<Reference> {
+    fn filter_references_by_type(&self, references: &[Reference], reference_filter: Option<(NodeId, bool)>) -> Vec<Reference> {
         if reference_filter.is_none() {
-            references.clone()
+            references.to_vec()
         } else {
             // Filter by type
             let (reference_type_id, include_subtypes) = reference_filter.unwrap();
             references.iter()
-                .filter(|r| self.reference_type_matches(reference_type_id, r.reference_type_id, include_subtypes))
+                .filter(|r| self.reference_type_matches(&reference_type_id, &r.reference_type_id, include_subtypes))
                 .map(|r| r.clone())
                 .collect::<Vec<Reference>>()
         }
     }
 
     /// Find and filter references that refer to the specified node.
-    fn find_references(&self, reference_map: &HashMap<NodeId, Vec<Reference>>, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
+    fn find_references(&self, reference_map: &HashMap<NodeId, Vec<Reference>>, node_id: &NodeId, reference_filter: Option<(NodeId, bool)>) -> Option<Vec<Reference>> {
         if let Some(ref node_references) = reference_map.get(node_id) {
             let result = self.filter_references_by_type(node_references, reference_filter);
             if result.is_empty() {
@@ -813,19 +1120,102 @@ impl AddressSpace {
         }
     }
 
+    /// Tests if the type is the base type or a subtype of it, by following HasSubtype references
+    /// from the type to its supertypes.
+    pub fn is_subtype(&self, type_id: &NodeId, base_type_id: &NodeId) -> bool {
+        let mut type_id = type_id;
+        // Guard against a loop in the type hierarchy
+        for _ in 0..self.node_map.len() {
+            if type_id == base_type_id {
+                return true;
+            }
+            type_id = match self.supertype_id(type_id) {
+                Some(supertype_id) => supertype_id,
+                None => {
+                    return false;
+                }
+            };
+        }
+        false
+    }
+
+    /// Returns the supertype of a type, which references the type by HasSubtype
+    fn supertype_id(&self, type_id: &NodeId) -> Option<&NodeId> {
+        let has_subtype: NodeId = ReferenceTypeId::HasSubtype.into();
+        self.inverse_references.get(type_id)?.iter()
+            .find(|r| r.reference_type_id == has_subtype)
+            .map(|r| &r.node_id)
+    }
+
+    /// Raises an event. The event is delivered to the monitored items of the Server object, of the
+    /// source node if it is an event notifier, and of any event notifier that the source node can
+    /// be reached from through HasEventSource references. Fails if the source node does not
+    /// exist or the event type is not BaseEventType or a subtype of it.
+    pub fn raise_event(&mut self, event: Event) -> Result<(), StatusCode> {
+        if !self.node_exists(&event.source_node) {
+            error!("Cannot raise event from source node {:?} which does not exist", event.source_node);
+            return Err(StatusCode::BadNodeIdUnknown);
+        }
+        if !self.is_subtype(&event.event_type, &ObjectTypeId::BaseEventType.into()) {
+            error!("Cannot raise event of type {:?} which is not an event type", event.event_type);
+            return Err(StatusCode::BadTypeDefinitionInvalid);
+        }
+        if self.recent_events.len() == constants::MAX_RECENT_EVENTS {
+            let _ = self.recent_events.pop_front();
+        }
+        self.recent_events.push_back(event);
+        self.last_event_number += 1;
+        Ok(())
+    }
+
+    /// Returns the number of the last event raised, or 0 if no events have been raised
+    pub fn last_event_number(&self) -> u64 {
+        self.last_event_number
+    }
+
+    /// Returns the recent events raised after the event number, oldest first. Events which are
+    /// no longer recent are not returned.
+    pub fn events_since(&self, event_number: u64) -> impl Iterator<Item=&Event> {
+        let first_event_number = self.last_event_number + 1 - self.recent_events.len() as u64;
+        let skip = event_number.saturating_sub(first_event_number - 1) as usize;
+        self.recent_events.iter().skip(skip)
+    }
+
+    /// Tests if the notifier is notified of events from the source node. The Server object is
+    /// notified of every event, and any other notifier of events from itself and from the nodes
+    /// reachable from it through HasEventSource references, which includes HasNotifier.
+    pub fn is_event_notifier_of(&self, notifier_id: &NodeId, source_node_id: &NodeId) -> bool {
+        if *notifier_id == ObjectId::Server.into() {
+            return true;
+        }
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![notifier_id.clone()];
+        while let Some(node_id) = to_visit.pop() {
+            if node_id == *source_node_id {
+                return true;
+            }
+            if let Some(references) = self.find_references_from(&node_id, Some((ReferenceTypeId::HasEventSource.into(), true))) {
+                to_visit.extend(references.into_iter().map(|r| r.node_id));
+            }
+            visited.insert(node_id);
+            to_visit.retain(|node_id| !visited.contains(node_id));
+        }
+        false
+    }
+
     /// Finds forward references from the specified node
-    pub fn find_references_from(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
+    pub fn find_references_from(&self, node_id: &NodeId, reference_filter: Option<(NodeId, bool)>) -> Option<Vec<Reference>> {
         self.find_references(&self.references, node_id, reference_filter)
     }
 
     /// Finds inverse references, it those that point to the specified node
-    pub fn find_references_to(&self, node_id: &NodeId, reference_filter: Option<(ReferenceTypeId, bool)>) -> Option<Vec<Reference>> {
+    pub fn find_references_to(&self, node_id: &NodeId, reference_filter: Option<(NodeId, bool)>) -> Option<Vec<Reference>> {
         self.find_references(&self.inverse_references, node_id, reference_filter)
     }
 
     /// Finds references for optionally forwards, inverse or both and return the references. The usize
     /// represents the index in the collection where the inverse references start (if applicable)
-    pub fn find_references_by_direction(&self, node_id: &NodeId, browse_direction: BrowseDirection, reference_filter: Option<(ReferenceTypeId, bool)>) -> (Vec<Reference>, usize) {
+    pub fn find_references_by_direction(&self, node_id: &NodeId, browse_direction: BrowseDirection, reference_filter: Option<(NodeId, bool)>) -> (Vec<Reference>, usize) {
         let mut references = Vec::new();
         let inverse_ref_idx: usize;
         match browse_direction {
@@ -842,7 +1232,7 @@ impl AddressSpace {
                 }
             }
             BrowseDirection::Both => {
-                if let Some(mut forward_references) = self.find_references_from(node_id, reference_filter) {
+                if let Some(mut forward_references) = self.find_references_from(node_id, reference_filter.clone()) {
                     references.append(&mut forward_references);
                 }
                 inverse_ref_idx = references.len();
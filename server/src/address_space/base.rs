@@ -257,6 +257,24 @@ impl Base {
         let _ = self.set_attribute(AttributeId::UserWriteMask, Variant::UInt32(user_write_mask.bits()).into());
     }
 
+    /// Sets the attributes common to every node class from the values supplied in a node attributes
+    /// struct such as `ObjectAttributes`. Only the attributes flagged in the mask are set, the rest
+    /// keep their present values.
+    pub fn set_common_attributes(&mut self, mask: AttributesMask, display_name: LocalizedText, description: LocalizedText, write_mask: u32, user_write_mask: u32) {
+        if mask.contains(AttributesMask::DISPLAY_NAME) {
+            self.set_display_name(display_name);
+        }
+        if mask.contains(AttributesMask::DESCRIPTION) {
+            self.set_description(description);
+        }
+        if mask.contains(AttributesMask::WRITE_MASK) {
+            self.set_write_mask(WriteMask::from_bits_truncate(write_mask));
+        }
+        if mask.contains(AttributesMask::USER_WRITE_MASK) {
+            self.set_user_write_mask(WriteMask::from_bits_truncate(user_write_mask));
+        }
+    }
+
     pub fn set_attribute_getter(&mut self, attribute_id: AttributeId, getter: Arc<Mutex<dyn AttributeGetter + Send>>) {
         if self.attribute_getters.is_none() {
             self.attribute_getters = Some(HashMap::new());
@@ -1,3 +1,5 @@
+use opcua_types::service_types::DataTypeAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -16,4 +18,15 @@ impl DataType {
             base: Base::new(NodeClass::DataType, node_id, browse_name, display_name, description, attributes),
         }
     }
+
+    /// Constructs a data type from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: DataTypeAttributes) -> Result<DataType, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let is_abstract = mask.contains(AttributesMask::IS_ABSTRACT) && attributes.is_abstract;
+        let mut node = DataType::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", is_abstract);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        Ok(node)
+    }
 }
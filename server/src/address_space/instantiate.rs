@@ -0,0 +1,247 @@
+//! Instantiates object types and variable types, i.e. creates an object or variable together with
+//! the children that its type and the supertypes of its type declare for their instances.
+
+use opcua_types::{
+    AttributeId, NodeId, Variant,
+    node_ids::{DataTypeId, ObjectId, ObjectTypeId, ReferenceTypeId},
+    status_code::StatusCode,
+};
+
+use crate::address_space::{
+    address_space::{AddressSpace, ReferenceDirection},
+    node::{Node, NodeType},
+    object::Object,
+    variable::VariableBuilder,
+};
+
+/// How `AddressSpace::instantiate` chooses the node ids of the nodes that it creates
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeIdStrategy {
+    /// Every node gets the next numeric node id, see `NodeId::next_numeric`
+    Numeric,
+    /// The instance gets the node id, and every node below it a string node id in the same
+    /// namespace made of its parent's identifier and its browse name, e.g. "Pump1.Motor.Speed"
+    BrowsePath(NodeId),
+}
+
+/// The attributes that a node copies from the instance declaration it is created from
+const DECLARATION_ATTRIBUTES: &[AttributeId] = &[
+    AttributeId::BrowseName, AttributeId::DisplayName, AttributeId::Description,
+    AttributeId::WriteMask, AttributeId::UserWriteMask, AttributeId::EventNotifier,
+    AttributeId::Value, AttributeId::DataType, AttributeId::ValueRank, AttributeId::ArrayDimensions,
+    AttributeId::AccessLevel, AttributeId::UserAccessLevel, AttributeId::MinimumSamplingInterval, AttributeId::Historizing,
+];
+
+/// The attributes that a variable copies from its variable type
+const VARIABLE_TYPE_ATTRIBUTES: &[AttributeId] = &[
+    AttributeId::Value, AttributeId::DataType, AttributeId::ValueRank, AttributeId::ArrayDimensions,
+];
+
+/// The state of an instantiation that is passed down the instance declarations
+struct Instantiation<'a> {
+    node_id_strategy: &'a NodeIdStrategy,
+    optional_children: &'a [&'a str],
+    /// The declarations that the node being instantiated and its parents were copied from
+    declaration_path: Vec<NodeId>,
+    /// The ids of the nodes that were created
+    created_node_ids: Vec<NodeId>,
+}
+
+/// Copies the attributes that the source node has onto the target node
+fn copy_attributes(from: &dyn Node, to: &mut dyn Node, attribute_ids: &[AttributeId]) {
+    for attribute_id in attribute_ids {
+        if let Some(value) = from.find_attribute(*attribute_id) {
+            let _ = to.set_attribute(*attribute_id, value);
+        }
+    }
+}
+
+impl AddressSpace {
+    /// Creates an instance of an object type or variable type under the parent, which organizes
+    /// it if it is a folder and otherwise has it as a component. A variable takes the value and
+    /// data type of its variable type.
+    ///
+    /// The instance gets a copy of each instance declaration of its type and the supertypes of
+    /// its type, i.e. the children they reference by HasComponent, HasProperty or another subtype
+    /// of Aggregates, where a declaration of a subtype overrides one of the same browse name in
+    /// a supertype. A copy has the attributes and default value of its declaration, the same
+    /// reference from its parent and the same type definition, and children of its own copied
+    /// from the declarations of the declaration and of its type definition, and so on down.
+    ///
+    /// Declarations with the Mandatory modelling rule are always copied and those with the
+    /// Optional modelling rule only if their browse path is in `optional_children`, as the browse
+    /// names from the instance to the child separated by '/', e.g. "Motor/Temperature". Other
+    /// declarations, such as placeholders, are not copied. Methods are not copied either, the
+    /// instance references the method of the type instead.
+    ///
+    /// Returns the node id of the instance, or an error if the parent does not exist, the type is
+    /// not a concrete object or variable type, the declarations to copy contain themselves so the
+    /// instance would never end, or a node id chosen by the strategy is in use. On an error no
+    /// nodes are added.
+    pub fn instantiate(&mut self, type_id: &NodeId, parent_node_id: &NodeId, browse_name: &str, node_id_strategy: NodeIdStrategy, optional_children: &[&str]) -> Result<NodeId, StatusCode> {
+        if !self.node_exists(parent_node_id) {
+            error!("Cannot instantiate {:?} under parent {:?} which does not exist", type_id, parent_node_id);
+            return Err(StatusCode::BadParentNodeIdInvalid);
+        }
+        let node_id = match node_id_strategy {
+            NodeIdStrategy::Numeric => NodeId::next_numeric(),
+            NodeIdStrategy::BrowsePath(ref node_id) => node_id.clone(),
+        };
+        if self.node_exists(&node_id) {
+            error!("Cannot instantiate {:?} as {:?} which already exists", type_id, node_id);
+            return Err(StatusCode::BadNodeIdExists);
+        }
+
+        let node = match self.find_node(type_id) {
+            Some(NodeType::ObjectType(ref object_type)) if !object_type.is_abstract() => {
+                NodeType::Object(Object::new(&node_id, browse_name, browse_name, ""))
+            }
+            Some(NodeType::VariableType(ref variable_type)) if !variable_type.is_abstract() => {
+                let mut variable = VariableBuilder::new(&node_id)
+                    .browse_name(browse_name)
+                    .display_name(browse_name)
+                    .build();
+                let data_type: NodeId = DataTypeId::BaseDataType.into();
+                let _ = variable.set_attribute(AttributeId::DataType, Variant::from(data_type).into());
+                copy_attributes(variable_type, &mut variable, VARIABLE_TYPE_ATTRIBUTES);
+                NodeType::Variable(variable)
+            }
+            _ => {
+                error!("Cannot instantiate {:?} which is not a concrete object type or variable type", type_id);
+                return Err(StatusCode::BadTypeDefinitionInvalid);
+            }
+        };
+        let parent_reference_type_id = if self.has_reference(parent_node_id, ReferenceTypeId::HasTypeDefinition, &ObjectTypeId::FolderType.into()) {
+            ReferenceTypeId::Organizes
+        } else {
+            ReferenceTypeId::HasComponent
+        };
+        self.insert(node, Some(&[
+            (parent_node_id, parent_reference_type_id, ReferenceDirection::Inverse),
+            (type_id, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+
+        let declaration_sources = self.type_hierarchy(type_id);
+        let mut instantiation = Instantiation {
+            node_id_strategy: &node_id_strategy,
+            optional_children,
+            declaration_path: Vec::new(),
+            created_node_ids: vec![node_id.clone()],
+        };
+        if let Err(status_code) = self.instantiate_declarations(&node_id, &declaration_sources, "", &mut instantiation) {
+            // Remove what was created so a failed call leaves the address space as it was
+            for created_node_id in &instantiation.created_node_ids {
+                self.delete_node(created_node_id, true);
+            }
+            return Err(status_code);
+        }
+        Ok(node_id)
+    }
+
+    /// Returns the type followed by its supertypes, most derived first
+    fn type_hierarchy(&self, type_id: &NodeId) -> Vec<NodeId> {
+        let mut type_ids = vec![type_id.clone()];
+        while let Some(references) = self.find_references_to(type_ids.last().unwrap(), Some((ReferenceTypeId::HasSubtype.into(), false))) {
+            let supertype_id = &references[0].node_id;
+            // Guard against a loop in the type hierarchy
+            if type_ids.contains(supertype_id) {
+                break;
+            }
+            type_ids.push(supertype_id.clone());
+        }
+        type_ids
+    }
+
+    /// Copies the instance declarations of the sources, which are the nodes that declare the
+    /// children of the instance in order of precedence, to the instance. The browse path is that
+    /// of the instance from the top of the instantiation. The ids of the nodes that are created
+    /// are added to the instantiation, also when an error is returned.
+    fn instantiate_declarations(&mut self, instance_id: &NodeId, declaration_sources: &[NodeId], browse_path: &str, instantiation: &mut Instantiation) -> Result<(), StatusCode> {
+        // A declaration overrides those of the same browse name further down the sources
+        let mut browse_names = Vec::new();
+        let mut declarations = Vec::new();
+        for source_id in declaration_sources {
+            for reference in self.find_references_from(source_id, Some((ReferenceTypeId::Aggregates.into(), true))).unwrap_or_default() {
+                if let Some(node) = self.find_node(&reference.node_id) {
+                    let browse_name = node.as_node().browse_name();
+                    if !browse_names.contains(&browse_name) {
+                        browse_names.push(browse_name);
+                        declarations.push((reference.reference_type_id, reference.node_id));
+                    }
+                }
+            }
+        }
+
+        let (mandatory, optional): (NodeId, NodeId) = (ObjectId::ModellingRule_Mandatory.into(), ObjectId::ModellingRule_Optional.into());
+        for (reference_type_id, declaration_id) in declarations {
+            let modelling_rule_id = self.find_references_from(&declaration_id, Some((ReferenceTypeId::HasModellingRule.into(), false)))
+                .map(|references| references[0].node_id.clone());
+            let name = self.find_node(&declaration_id).unwrap().as_node().browse_name().name;
+            let child_browse_path = if browse_path.is_empty() {
+                name.as_ref().to_string()
+            } else {
+                format!("{}/{}", browse_path, name.as_ref())
+            };
+            let is_selected = match modelling_rule_id {
+                Some(ref modelling_rule_id) if *modelling_rule_id == mandatory => true,
+                Some(ref modelling_rule_id) if *modelling_rule_id == optional => instantiation.optional_children.contains(&child_browse_path.as_str()),
+                _ => false,
+            };
+            if !is_selected {
+                continue;
+            }
+            // A declaration that is copied into its own copy, e.g. through a type definition that
+            // declares it again, would be copied forever
+            if instantiation.declaration_path.contains(&declaration_id) {
+                error!("Cannot instantiate {} because its declaration {:?} contains itself", child_browse_path, declaration_id);
+                return Err(StatusCode::BadTypeDefinitionInvalid);
+            }
+
+            let node_id = match *instantiation.node_id_strategy {
+                NodeIdStrategy::Numeric => NodeId::next_numeric(),
+                NodeIdStrategy::BrowsePath(_) => NodeId::new(instance_id.namespace, format!("{}.{}", instance_id.identifier, name.as_ref())),
+            };
+            if self.node_exists(&node_id) {
+                error!("Cannot instantiate {} as {:?} which already exists", child_browse_path, node_id);
+                return Err(StatusCode::BadNodeIdExists);
+            }
+            let node = match self.find_node(&declaration_id) {
+                Some(NodeType::Object(ref declaration)) => {
+                    let mut object = Object::new(&node_id, "", "", "");
+                    copy_attributes(declaration, &mut object, DECLARATION_ATTRIBUTES);
+                    NodeType::Object(object)
+                }
+                Some(NodeType::Variable(ref declaration)) => {
+                    let mut variable = VariableBuilder::new(&node_id).build();
+                    copy_attributes(declaration, &mut variable, DECLARATION_ATTRIBUTES);
+                    NodeType::Variable(variable)
+                }
+                Some(NodeType::Method(_)) => {
+                    // Methods are shared by the instances of the type
+                    self.insert_reference(instance_id, &declaration_id, reference_type_id.clone());
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let type_definition_id = self.find_references_from(&declaration_id, Some((ReferenceTypeId::HasTypeDefinition.into(), false)))
+                .map(|references| references[0].node_id.clone());
+            self.insert(node, None);
+            self.insert_reference(instance_id, &node_id, reference_type_id);
+            if let Some(ref type_definition_id) = type_definition_id {
+                self.insert_reference(&node_id, type_definition_id, ReferenceTypeId::HasTypeDefinition);
+            }
+            instantiation.created_node_ids.push(node_id.clone());
+
+            // The child's own children are declared by the declaration and then by its type
+            let mut declaration_sources = vec![declaration_id.clone()];
+            if let Some(ref type_definition_id) = type_definition_id {
+                declaration_sources.append(&mut self.type_hierarchy(type_definition_id));
+            }
+            instantiation.declaration_path.push(declaration_id);
+            self.instantiate_declarations(&node_id, &declaration_sources, &child_browse_path, instantiation)?;
+            instantiation.declaration_path.pop();
+        }
+        Ok(())
+    }
+}
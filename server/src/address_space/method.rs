@@ -1,3 +1,5 @@
+use opcua_types::service_types::MethodAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -34,6 +36,18 @@ impl Method {
         }
     }
 
+    /// Constructs a method from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: MethodAttributes) -> Result<Method, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let executable = mask.contains(AttributesMask::EXECUTABLE) && attributes.executable;
+        let user_executable = mask.contains(AttributesMask::USER_EXECUTABLE) && attributes.user_executable;
+        let mut node = Method::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", false, executable, user_executable);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        Ok(node)
+    }
+
     pub fn is_abstract(&self) -> bool {
         find_attribute_value_mandatory!(&self.base, IsAbstract, Boolean)
     }
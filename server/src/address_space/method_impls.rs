@@ -4,6 +4,7 @@ use opcua_types::service_types::{CallMethodRequest, CallMethodResult};
 
 use crate::{
     address_space::AddressSpace,
+    events::condition,
     state::ServerState,
     session::Session
 };
@@ -114,3 +115,154 @@ pub fn handle_get_monitored_items(_: &AddressSpace, _: &ServerState, session: &m
         Err(StatusCode::BadSubscriptionIdInvalid)
     }
 }
+
+/// This is the handler for the ConditionRefresh method call on the Server object.
+pub fn handle_condition_refresh(address_space: &AddressSpace, _: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for ConditionRefresh");
+
+    // OPC UA part 9 - ConditionRefresh([in] IntegerId SubscriptionId);
+    //
+    // SubscriptionId - Identifier of the subscription whose event monitored items are refreshed
+    //
+    // Return codes
+    //
+    // BadSubscriptionIdInvalid
+
+    ensure_input_argument_count(request, 1)?;
+
+    let subscription_id = get_input_argument!(request, 0, UInt32)?;
+
+    if let Some(subscription) = session.subscriptions.get_mut(*subscription_id) {
+        let conditions = condition::retained_condition_events(address_space);
+        subscription.refresh_conditions(address_space, &conditions);
+        Ok(CallMethodResult {
+            status_code: StatusCode::Good,
+            input_argument_results: Some(vec![StatusCode::Good]),
+            input_argument_diagnostic_infos: None,
+            output_arguments: None,
+        })
+    } else {
+        Err(StatusCode::BadSubscriptionIdInvalid)
+    }
+}
+
+/// This is the handler for the Enable method call on a condition.
+pub fn handle_condition_enable(address_space: &mut AddressSpace, _: &ServerState, _: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for Enable");
+
+    // OPC UA part 9 - Enable();
+    //
+    // Return codes
+    //
+    // BadConditionAlreadyEnabled
+
+    ensure_input_argument_count(request, 0)?;
+
+    condition::enable(address_space, &request.object_id)?;
+    Ok(CallMethodResult {
+        status_code: StatusCode::Good,
+        input_argument_results: None,
+        input_argument_diagnostic_infos: None,
+        output_arguments: None,
+    })
+}
+
+/// This is the handler for the Disable method call on a condition.
+pub fn handle_condition_disable(address_space: &mut AddressSpace, _: &ServerState, _: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for Disable");
+
+    // OPC UA part 9 - Disable();
+    //
+    // Return codes
+    //
+    // BadConditionAlreadyDisabled
+
+    ensure_input_argument_count(request, 0)?;
+
+    condition::disable(address_space, &request.object_id)?;
+    Ok(CallMethodResult {
+        status_code: StatusCode::Good,
+        input_argument_results: None,
+        input_argument_diagnostic_infos: None,
+        output_arguments: None,
+    })
+}
+
+/// Returns the id of the user calling a method on a condition, which is the id of the user token
+/// that the user was authenticated against
+fn client_user_id(session: &Session) -> &str {
+    match session.user_token_id {
+        Some(ref user_token_id) => user_token_id.as_str(),
+        None => ""
+    }
+}
+
+/// Calls the function with the event id and comment arguments of a method call on a condition
+fn handle_condition_comment<F>(address_space: &mut AddressSpace, session: &Session, request: &CallMethodRequest, f: F) -> Result<CallMethodResult, StatusCode>
+    where F: Fn(&mut AddressSpace, &NodeId, &ByteString, &LocalizedText, &str) -> Result<(), StatusCode> {
+    ensure_input_argument_count(request, 2)?;
+
+    let event_id = get_input_argument!(request, 0, ByteString)?;
+    let comment = get_input_argument!(request, 1, LocalizedText)?;
+
+    f(address_space, &request.object_id, event_id, comment, client_user_id(session))?;
+    Ok(CallMethodResult {
+        status_code: StatusCode::Good,
+        input_argument_results: Some(vec![StatusCode::Good, StatusCode::Good]),
+        input_argument_diagnostic_infos: None,
+        output_arguments: None,
+    })
+}
+
+/// This is the handler for the AddComment method call on a condition.
+pub fn handle_condition_add_comment(address_space: &mut AddressSpace, _: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for AddComment");
+
+    // OPC UA part 9 - AddComment([in] ByteString EventId, [in] LocalizedText Comment);
+    //
+    // EventId - Identifier of the event that the comment is on
+    // Comment - Comment to add to the condition
+    //
+    // Return codes
+    //
+    // BadConditionDisabled
+    // BadEventIdUnknown
+
+    handle_condition_comment(address_space, session, request, condition::add_comment)
+}
+
+/// This is the handler for the Acknowledge method call on a condition.
+pub fn handle_condition_acknowledge(address_space: &mut AddressSpace, _: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for Acknowledge");
+
+    // OPC UA part 9 - Acknowledge([in] ByteString EventId, [in] LocalizedText Comment);
+    //
+    // EventId - Identifier of the event that is acknowledged
+    // Comment - Optional comment to add to the condition
+    //
+    // Return codes
+    //
+    // BadConditionBranchAlreadyAcked
+    // BadConditionDisabled
+    // BadEventIdUnknown
+
+    handle_condition_comment(address_space, session, request, condition::acknowledge)
+}
+
+/// This is the handler for the Confirm method call on a condition.
+pub fn handle_condition_confirm(address_space: &mut AddressSpace, _: &ServerState, session: &mut Session, request: &CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+    debug!("Method handler for Confirm");
+
+    // OPC UA part 9 - Confirm([in] ByteString EventId, [in] LocalizedText Comment);
+    //
+    // EventId - Identifier of the event that is confirmed
+    // Comment - Optional comment to add to the condition
+    //
+    // Return codes
+    //
+    // BadConditionBranchAlreadyConfirmed
+    // BadConditionDisabled
+    // BadEventIdUnknown
+
+    handle_condition_comment(address_space, session, request, condition::confirm)
+}
@@ -129,16 +129,19 @@ pub mod object_type;
 pub mod variable_type;
 pub mod data_type;
 pub mod view;
+pub mod object_model;
 
-mod method_impls;
+pub(crate) mod method_impls;
+mod instantiate;
+mod nodeset;
 
 bitflags! {
     pub struct AccessLevel: u8 {
         const CURRENT_READ = 1;
         const CURRENT_WRITE = 2;
+        const HISTORY_READ = 4;
+        const HISTORY_WRITE = 8;
         // These can be uncommented if they become used
-        // const HISTORY_READ = 4;
-        // const HISTORY_WRITE = 8;
         // const SEMANTIC_CHANGE = 16;
         // const STATUS_WRITE = 32;
         // const TIMESTAMP_WRITE = 64;
@@ -149,16 +152,24 @@ bitflags! {
     pub struct UserAccessLevel: u8 {
         const CURRENT_READ = 1;
         const CURRENT_WRITE = 2;
+        const HISTORY_READ = 4;
+        const HISTORY_WRITE = 8;
         // These can be uncommented if they become used
-        // const HISTORY_READ = 4;
-        // const HISTORY_WRITE = 8;
         // const STATUS_WRITE = 32;
         // const TIMESTAMP_WRITE = 64;
     }
 }
 
+bitflags! {
+    pub struct EventNotifier: u8 {
+        const SUBSCRIBE_TO_EVENTS = 1;
+        const HISTORY_READ = 4;
+        const HISTORY_WRITE = 8;
+    }
+}
+
 pub mod types {
-    pub use super::{AttrFnGetter, AttrFnSetter};
+    pub use super::{AttrFnGetter, AttrFnSetter, EventNotifier};
     pub use super::address_space::{AddressSpace, ReferenceDirection};
     pub use super::data_type::DataType;
     pub use super::object::Object;
@@ -169,6 +180,8 @@ pub mod types {
     pub use super::variable_type::VariableType;
     pub use super::view::View;
     pub use super::node::{Node, NodeType};
+    pub use super::object_model::{ModelVariable, UaObjectType};
+    pub use super::instantiate::NodeIdStrategy;
 }
 
 pub use self::address_space::AddressSpace;
@@ -0,0 +1,684 @@
+//! Imports nodes into the address space from a NodeSet2 XML document, i.e. a `UANodeSet` as
+//! defined by the OPC UA XML schema of Part 6, and exports them to one.
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap, HashSet},
+    io::{Read, Write},
+    str::FromStr,
+};
+
+use opcua_types::{
+    *,
+    node_ids::DataTypeId,
+    status_code::StatusCode,
+};
+
+use crate::address_space::{
+    address_space::{AddressSpace, ReferenceDirection},
+    node::{HasNodeId, Node, NodeType},
+    types::{DataType, Method, Object, ObjectType, ReferenceType, Variable, VariableBuilder, VariableType, View},
+    AccessLevel, EventNotifier, UserAccessLevel,
+};
+
+/// The XML namespace of NodeSet documents
+const NODESET_XML_NAMESPACE: &str = "http://opcfoundation.org/UA/2011/03/UANodeSet.xsd";
+
+/// A reference of an imported node, as the target node, the reference type and the direction
+type NodeSetReference = (NodeId, NodeId, ReferenceDirection);
+
+/// The elements of a NodeSet document that are nodes
+const NODE_ELEMENTS: &[&str] = &["UAObject", "UAVariable", "UAMethod", "UAObjectType", "UAVariableType", "UADataType", "UAReferenceType", "UAView"];
+
+/// Maps the namespace indices and aliases of a NodeSet document onto the address space
+struct NodeSetContext {
+    /// The namespace index in the address space of each namespace index in the document
+    namespace_indices: Vec<u16>,
+    /// Node ids by their alias
+    aliases: HashMap<String, String>,
+}
+
+impl NodeSetContext {
+    /// Returns the namespace index in the address space of a namespace index in the document
+    fn namespace_index(&self, namespace: u16) -> Result<u16, StatusCode> {
+        self.namespace_indices.get(namespace as usize).cloned().ok_or_else(|| {
+            error!("NodeSet namespace index {} is not in its namespace URIs", namespace);
+            StatusCode::BadDecodingError
+        })
+    }
+
+    /// Parses a node id, which may be an alias, and maps it onto the address space
+    fn node_id(&self, value: &str) -> Result<NodeId, StatusCode> {
+        let value = value.trim();
+        let value = self.aliases.get(value).map(|v| v.as_str()).unwrap_or(value);
+        let node_id = NodeId::from_str(value).map_err(|_| {
+            error!("NodeSet node id {} is invalid", value);
+            StatusCode::BadDecodingError
+        })?;
+        self.map_node_id(node_id)
+    }
+
+    fn map_node_id(&self, node_id: NodeId) -> Result<NodeId, StatusCode> {
+        Ok(NodeId {
+            namespace: self.namespace_index(node_id.namespace)?,
+            identifier: node_id.identifier,
+        })
+    }
+
+    /// Parses a browse name of the form `<namespace index>:<name>`, where the namespace index is
+    /// optional, and maps it onto the address space
+    fn qualified_name(&self, value: &str) -> Result<QualifiedName, StatusCode> {
+        let (namespace, name) = match value.find(':') {
+            Some(pos) if value[..pos].chars().all(|c| c.is_ascii_digit()) && pos > 0 => {
+                let namespace = value[..pos].parse::<u16>().map_err(|_| StatusCode::BadDecodingError)?;
+                (namespace, &value[pos + 1..])
+            }
+            _ => (0, value)
+        };
+        Ok(QualifiedName::new(self.namespace_index(namespace)?, name))
+    }
+
+    /// Maps the node ids and qualified names in a value onto the address space
+    fn map_value(&self, value: Variant) -> Result<Variant, StatusCode> {
+        map_value_namespaces(value, &|namespace| self.namespace_index(namespace))
+    }
+
+    /// Makes a node from a `UAObject`, `UAVariable` or other node element, together with its
+    /// references. Returns `None` for any other element of the document.
+    fn node(&self, element: &XmlNode) -> Result<Option<(NodeType, Vec<NodeSetReference>)>, StatusCode> {
+        if !NODE_ELEMENTS.contains(&element.local_name()) {
+            return Ok(None);
+        }
+        let node_id = self.node_id(required_attribute(element, "NodeId")?)?;
+        if node_id.is_null() {
+            error!("NodeSet node has a null node id");
+            return Err(StatusCode::BadNodeIdInvalid);
+        }
+        let node: NodeType = match element.local_name() {
+            "UAObject" => {
+                let mut node = Object::new(&node_id, "", "", "");
+                node.set_event_notifier(EventNotifier::from_bits_truncate(number_attribute(element, "EventNotifier", 0u8)?));
+                node.into()
+            }
+            "UAVariable" => {
+                let mut node = VariableBuilder::new(&node_id).build();
+                self.set_variable_attributes(&mut node, element)?;
+                node.into()
+            }
+            "UAMethod" => {
+                let executable = bool_attribute(element, "Executable", true)?;
+                let user_executable = bool_attribute(element, "UserExecutable", true)?;
+                Method::new(&node_id, "", "", "", false, executable, user_executable).into()
+            }
+            "UAObjectType" => {
+                ObjectType::new(&node_id, "", "", "", bool_attribute(element, "IsAbstract", false)?).into()
+            }
+            "UAVariableType" => {
+                let is_abstract = bool_attribute(element, "IsAbstract", false)?;
+                let value_rank = number_attribute(element, "ValueRank", -1)?;
+                let mut node = VariableType::new(&node_id, "", "", "", is_abstract, value_rank);
+                self.set_value_attributes(&mut node, element)?;
+                node.into()
+            }
+            "UADataType" => {
+                DataType::new(&node_id, "", "", "", bool_attribute(element, "IsAbstract", false)?).into()
+            }
+            "UAReferenceType" => {
+                let inverse_name = element.child("InverseName").map(localized_text);
+                let symmetric = bool_attribute(element, "Symmetric", false)?;
+                let is_abstract = bool_attribute(element, "IsAbstract", false)?;
+                ReferenceType::new(&node_id, "", "", "", inverse_name, symmetric, is_abstract).into()
+            }
+            "UAView" => {
+                let event_notifier = number_attribute(element, "EventNotifier", 0u8)? != 0;
+                let contains_no_loops = bool_attribute(element, "ContainsNoLoops", false)?;
+                View::new(&node_id, "", "", "", event_notifier, contains_no_loops).into()
+            }
+            _ => unreachable!()
+        };
+        let mut node = node;
+        self.set_common_attributes(node.as_mut_node(), element)?;
+        Ok(Some((node, self.references(element)?)))
+    }
+
+    /// Sets the attributes that every node class has
+    fn set_common_attributes(&self, node: &mut dyn Node, element: &XmlNode) -> Result<(), StatusCode> {
+        let browse_name = self.qualified_name(required_attribute(element, "BrowseName")?)?;
+        let display_name = element.child("DisplayName").map(localized_text)
+            .unwrap_or_else(|| LocalizedText::new("", browse_name.name.as_ref()));
+        set_attribute(node, AttributeId::BrowseName, Variant::from(browse_name))?;
+        set_attribute(node, AttributeId::DisplayName, Variant::from(display_name))?;
+        if let Some(description) = element.child("Description") {
+            set_attribute(node, AttributeId::Description, Variant::from(localized_text(description)))?;
+        }
+        set_attribute(node, AttributeId::WriteMask, Variant::UInt32(number_attribute(element, "WriteMask", 0)?))?;
+        set_attribute(node, AttributeId::UserWriteMask, Variant::UInt32(number_attribute(element, "UserWriteMask", 0)?))
+    }
+
+    /// Sets the attributes of a variable, including those it shares with variable types
+    fn set_variable_attributes(&self, node: &mut Variable, element: &XmlNode) -> Result<(), StatusCode> {
+        self.set_value_attributes(node, element)?;
+        set_attribute(node, AttributeId::ValueRank, Variant::Int32(number_attribute(element, "ValueRank", -1)?))?;
+        node.set_access_level(AccessLevel::from_bits_truncate(number_attribute(element, "AccessLevel", AccessLevel::CURRENT_READ.bits())?));
+        node.set_user_access_level(UserAccessLevel::from_bits_truncate(number_attribute(element, "UserAccessLevel", UserAccessLevel::CURRENT_READ.bits())?));
+        // The attribute is a Duration in the document but the variable holds whole milliseconds
+        let minimum_sampling_interval = number_attribute(element, "MinimumSamplingInterval", 0f64)?;
+        if minimum_sampling_interval.fract() != 0f64 {
+            warn!("NodeSet MinimumSamplingInterval {} of {:?} is rounded to whole milliseconds", minimum_sampling_interval, node.node_id());
+        }
+        node.set_minimum_sampling_interval(minimum_sampling_interval.round() as i32);
+        node.set_historizing(bool_attribute(element, "Historizing", false)?);
+        Ok(())
+    }
+
+    /// Sets the data type, array dimensions and value of a variable or variable type
+    fn set_value_attributes(&self, node: &mut dyn Node, element: &XmlNode) -> Result<(), StatusCode> {
+        let data_type = self.node_id(element.attribute("DataType").unwrap_or("i=24"))?;
+        set_attribute(node, AttributeId::DataType, Variant::from(data_type))?;
+        if let Some(array_dimensions) = element.attribute("ArrayDimensions") {
+            let array_dimensions = array_dimensions.split(',')
+                .map(|v| v.trim().parse::<u32>().map_err(|_| {
+                    error!("NodeSet array dimensions {} are invalid", array_dimensions);
+                    StatusCode::BadDecodingError
+                }))
+                .collect::<Result<Vec<u32>, StatusCode>>()?;
+            set_attribute(node, AttributeId::ArrayDimensions, Variant::from(&array_dimensions[..]))?;
+        }
+        if let Some(value) = element.child("Value") {
+            let value = Variant::decode_xml(Some(value), &DecodingLimits::default())?;
+            node.set_attribute(AttributeId::Value, DataValue::new(self.map_value(value)?))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the references of a node element. The reference types may be custom reference
+    /// types, which may be defined in the same document.
+    fn references(&self, element: &XmlNode) -> Result<Vec<NodeSetReference>, StatusCode> {
+        let mut references = Vec::new();
+        if let Some(element) = element.child("References") {
+            for reference in element.children_named("Reference") {
+                let reference_type = self.node_id(required_attribute(reference, "ReferenceType")?)?;
+                let target = self.node_id(&reference.text)?;
+                let direction = if bool_attribute(reference, "IsForward", true)? {
+                    ReferenceDirection::Forward
+                } else {
+                    ReferenceDirection::Inverse
+                };
+                references.push((target, reference_type, direction));
+            }
+        }
+        Ok(references)
+    }
+}
+
+/// Maps the namespace indices of the node ids and qualified names in a value, including those
+/// in arrays, with the function
+fn map_value_namespaces<F>(value: Variant, f: &F) -> Result<Variant, StatusCode> where F: Fn(u16) -> Result<u16, StatusCode> {
+    let map_node_id = |node_id: NodeId| -> Result<NodeId, StatusCode> {
+        Ok(NodeId { namespace: f(node_id.namespace)?, identifier: node_id.identifier })
+    };
+    let value = match value {
+        Variant::NodeId(node_id) => Variant::from(map_node_id(*node_id)?),
+        Variant::ExpandedNodeId(node_id) => {
+            let mut node_id = *node_id;
+            if node_id.namespace_uri.is_null() {
+                node_id.node_id = map_node_id(node_id.node_id)?;
+            }
+            Variant::from(node_id)
+        }
+        Variant::QualifiedName(name) => {
+            Variant::from(QualifiedName::new(f(name.namespace_index)?, name.name.as_ref()))
+        }
+        Variant::Array(values) => {
+            Variant::Array(values.into_iter().map(|v| map_value_namespaces(v, f)).collect::<Result<Vec<Variant>, StatusCode>>()?)
+        }
+        Variant::MultiDimensionArray(mda) => {
+            let mda = *mda;
+            let values = mda.values.into_iter().map(|v| map_value_namespaces(v, f)).collect::<Result<Vec<Variant>, StatusCode>>()?;
+            Variant::new_multi_dimension_array(values, mda.dimensions)
+        }
+        value => value
+    };
+    Ok(value)
+}
+
+fn required_attribute<'a>(element: &'a XmlNode, name: &str) -> Result<&'a str, StatusCode> {
+    element.attribute(name).ok_or_else(|| {
+        error!("NodeSet element {} has no {} attribute", element.name, name);
+        StatusCode::BadDecodingError
+    })
+}
+
+fn bool_attribute(element: &XmlNode, name: &str, default: bool) -> Result<bool, StatusCode> {
+    match element.attribute(name).map(|v| v.trim()) {
+        None => Ok(default),
+        Some("true") | Some("1") => Ok(true),
+        Some("false") | Some("0") => Ok(false),
+        Some(value) => {
+            error!("NodeSet attribute {} value {} is not a boolean", name, value);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+fn number_attribute<T: FromStr>(element: &XmlNode, name: &str, default: T) -> Result<T, StatusCode> {
+    if let Some(value) = element.attribute(name) {
+        value.trim().parse::<T>().map_err(|_| {
+            error!("NodeSet attribute {} value {} is not a number", name, value);
+            StatusCode::BadDecodingError
+        })
+    } else {
+        Ok(default)
+    }
+}
+
+/// Returns the localized text of a `DisplayName`, `Description` or `InverseName` element
+fn localized_text(element: &XmlNode) -> LocalizedText {
+    LocalizedText::new(element.attribute("Locale").unwrap_or(""), &element.text)
+}
+
+fn set_attribute(node: &mut dyn Node, attribute_id: AttributeId, value: Variant) -> Result<(), StatusCode> {
+    node.set_attribute(attribute_id, DataValue::new(value))
+}
+
+fn attribute(node: &dyn Node, attribute_id: AttributeId) -> Option<Variant> {
+    node.find_attribute(attribute_id).and_then(|v| v.value)
+}
+
+/// Orders node ids by namespace, then numerically or alphabetically by identifier, so that
+/// exports of the same nodes are identical
+fn node_id_sort_key(node_id: &NodeId) -> (u16, u8, u32, String) {
+    match node_id.identifier {
+        Identifier::Numeric(value) => (node_id.namespace, 0, value, String::new()),
+        Identifier::String(ref value) => (node_id.namespace, 1, 0, value.as_ref().to_string()),
+        Identifier::Guid(ref value) => (node_id.namespace, 2, 0, value.to_string()),
+        Identifier::ByteString(_) => (node_id.namespace, 3, 0, node_id.to_string()),
+    }
+}
+
+/// Maps the namespace indices of the address space onto a NodeSet document and collects the
+/// aliases of its reference types and data types as the document is made
+struct NodeSetWriter<'a> {
+    address_space: &'a AddressSpace,
+    /// The namespace index in the address space of each namespace index in the document after 0
+    namespaces: RefCell<Vec<u16>>,
+    /// Standard node ids by their alias
+    aliases: RefCell<BTreeMap<String, NodeId>>,
+}
+
+impl<'a> NodeSetWriter<'a> {
+    /// Returns the namespace index in the document of a namespace index in the address space
+    fn namespace_index(&self, namespace: u16) -> u16 {
+        if namespace == 0 {
+            return 0;
+        }
+        let mut namespaces = self.namespaces.borrow_mut();
+        let idx = namespaces.iter().position(|v| *v == namespace).unwrap_or_else(|| {
+            namespaces.push(namespace);
+            namespaces.len() - 1
+        });
+        (idx + 1) as u16
+    }
+
+    fn node_id(&self, node_id: &NodeId) -> String {
+        NodeId {
+            namespace: self.namespace_index(node_id.namespace),
+            identifier: node_id.identifier.clone(),
+        }.to_string()
+    }
+
+    /// Returns the alias of a standard reference type or data type, which is its browse name,
+    /// or its node id if it has no alias
+    fn alias(&self, node_id: &NodeId) -> String {
+        if node_id.namespace == 0 {
+            if let Some(node) = self.address_space.find_node(node_id) {
+                let alias = node.as_node().browse_name().name.as_ref().to_string();
+                let mut aliases = self.aliases.borrow_mut();
+                if !alias.is_empty() && aliases.get(&alias).map(|v| v == node_id).unwrap_or(true) {
+                    aliases.insert(alias.clone(), node_id.clone());
+                    return alias;
+                }
+            }
+        }
+        self.node_id(node_id)
+    }
+
+    fn qualified_name(&self, name: &QualifiedName) -> String {
+        if name.namespace_index == 0 {
+            name.name.as_ref().to_string()
+        } else {
+            format!("{}:{}", self.namespace_index(name.namespace_index), name.name.as_ref())
+        }
+    }
+
+    fn localized_text(&self, name: &str, text: &LocalizedText) -> XmlNode {
+        let mut element = XmlNode::new_text(name, text.text.as_ref());
+        if !text.locale.is_empty() {
+            element.set_attribute("Locale", text.locale.as_ref());
+        }
+        element
+    }
+
+    /// Returns the `Value` element of a value, whose body is in the XML namespace of the types
+    fn value(&self, value: Variant) -> Option<XmlNode> {
+        let value = map_value_namespaces(value, &|namespace| Ok(self.namespace_index(namespace))).ok()?;
+        let mut element = value.encode_xml("Value")?;
+        if let Some(body) = element.children.first_mut() {
+            body.set_attribute("xmlns", TYPES_XML_NAMESPACE);
+        }
+        Some(element)
+    }
+
+    /// Sets the data type, value rank and array dimensions of a variable or variable type
+    fn set_value_attributes(&self, element: &mut XmlNode, node: &dyn Node) {
+        if let Some(Variant::NodeId(data_type)) = attribute(node, AttributeId::DataType) {
+            if *data_type != DataTypeId::BaseDataType.into() {
+                element.set_attribute("DataType", self.alias(&data_type));
+            }
+        }
+        if let Some(Variant::Int32(value_rank)) = attribute(node, AttributeId::ValueRank) {
+            if value_rank != -1 {
+                element.set_attribute("ValueRank", value_rank.to_string());
+            }
+        }
+        if let Some(Variant::Array(array_dimensions)) = attribute(node, AttributeId::ArrayDimensions) {
+            if !array_dimensions.is_empty() {
+                let array_dimensions = array_dimensions.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+                element.set_attribute("ArrayDimensions", array_dimensions.join(","));
+            }
+        }
+    }
+
+    /// Makes the element of a node with its attributes and references
+    fn node(&self, node_type: &NodeType) -> XmlNode {
+        let node = node_type.as_node();
+        let node_id = node.node_id();
+        let name = match node_type {
+            NodeType::Object(_) => "UAObject",
+            NodeType::ObjectType(_) => "UAObjectType",
+            NodeType::ReferenceType(_) => "UAReferenceType",
+            NodeType::Variable(_) => "UAVariable",
+            NodeType::VariableType(_) => "UAVariableType",
+            NodeType::View(_) => "UAView",
+            NodeType::DataType(_) => "UADataType",
+            NodeType::Method(_) => "UAMethod",
+        };
+        let mut element = XmlNode::new(name);
+        element.set_attribute("NodeId", self.node_id(&node_id));
+        element.set_attribute("BrowseName", self.qualified_name(&node.browse_name()));
+        for (name, attribute_id) in &[("WriteMask", AttributeId::WriteMask), ("UserWriteMask", AttributeId::UserWriteMask)] {
+            if let Some(Variant::UInt32(write_mask)) = attribute(node, *attribute_id) {
+                if write_mask != 0 {
+                    element.set_attribute(name, write_mask.to_string());
+                }
+            }
+        }
+        // Attributes which have their default value are left out
+        let is_true = |attribute_id| attribute(node, attribute_id) == Some(Variant::Boolean(true));
+        let is_false = |attribute_id| attribute(node, attribute_id) == Some(Variant::Boolean(false));
+        match node_type {
+            NodeType::Object(_) => {
+                if let Some(Variant::Byte(event_notifier)) = attribute(node, AttributeId::EventNotifier) {
+                    if event_notifier != 0 {
+                        element.set_attribute("EventNotifier", event_notifier.to_string());
+                    }
+                }
+            }
+            NodeType::Variable(_) => {
+                self.set_value_attributes(&mut element, node);
+                for (name, attribute_id) in &[("AccessLevel", AttributeId::AccessLevel), ("UserAccessLevel", AttributeId::UserAccessLevel)] {
+                    if let Some(Variant::Byte(access_level)) = attribute(node, *attribute_id) {
+                        if access_level != AccessLevel::CURRENT_READ.bits() {
+                            element.set_attribute(name, access_level.to_string());
+                        }
+                    }
+                }
+                if let Some(Variant::Double(minimum_sampling_interval)) = attribute(node, AttributeId::MinimumSamplingInterval) {
+                    if minimum_sampling_interval != 0f64 {
+                        element.set_attribute("MinimumSamplingInterval", minimum_sampling_interval.to_string());
+                    }
+                }
+                if is_true(AttributeId::Historizing) {
+                    element.set_attribute("Historizing", "true");
+                }
+            }
+            NodeType::Method(_) => {
+                if is_false(AttributeId::Executable) {
+                    element.set_attribute("Executable", "false");
+                }
+                if is_false(AttributeId::UserExecutable) {
+                    element.set_attribute("UserExecutable", "false");
+                }
+            }
+            NodeType::VariableType(_) => {
+                self.set_value_attributes(&mut element, node);
+            }
+            NodeType::ReferenceType(_) => {
+                if is_true(AttributeId::Symmetric) {
+                    element.set_attribute("Symmetric", "true");
+                }
+            }
+            NodeType::View(_) => {
+                if is_true(AttributeId::ContainsNoLoops) {
+                    element.set_attribute("ContainsNoLoops", "true");
+                }
+                if is_true(AttributeId::EventNotifier) {
+                    element.set_attribute("EventNotifier", "1");
+                }
+            }
+            NodeType::ObjectType(_) | NodeType::DataType(_) => {}
+        }
+        if is_true(AttributeId::IsAbstract) {
+            element.set_attribute("IsAbstract", "true");
+        }
+
+        element.add_child(self.localized_text("DisplayName", &node.display_name()));
+        if let Some(description) = node.description().filter(|v| !v.text.is_empty() || !v.locale.is_empty()) {
+            element.add_child(self.localized_text("Description", &description));
+        }
+        let mut references = XmlNode::new("References");
+        for reference in self.address_space.find_references_from(&node_id, None).unwrap_or_default() {
+            let mut element = XmlNode::new_text("Reference", self.node_id(&reference.node_id));
+            element.set_attribute("ReferenceType", self.alias(&reference.reference_type_id));
+            references.add_child(element);
+        }
+        for reference in self.address_space.find_references_to(&node_id, None).unwrap_or_default() {
+            let mut element = XmlNode::new_text("Reference", self.node_id(&reference.node_id));
+            element.set_attribute("ReferenceType", self.alias(&reference.reference_type_id));
+            element.set_attribute("IsForward", "false");
+            references.add_child(element);
+        }
+        if !references.children.is_empty() {
+            element.add_child(references);
+        }
+        match node_type {
+            NodeType::Variable(_) | NodeType::VariableType(_) => {
+                if let Some(value) = attribute(node, AttributeId::Value) {
+                    element.add_child(self.value(value));
+                }
+            }
+            NodeType::ReferenceType(_) => {
+                if let Some(Variant::LocalizedText(inverse_name)) = attribute(node, AttributeId::InverseName) {
+                    element.add_child(self.localized_text("InverseName", &inverse_name));
+                }
+            }
+            _ => {}
+        }
+        element
+    }
+}
+
+impl AddressSpace {
+    /// Imports the nodes of a NodeSet2 XML document, i.e. a `UANodeSet`, into the address space
+    /// and returns the ids of the imported nodes.
+    ///
+    /// The namespace URIs of the document are added to the NamespaceArray if they are not there
+    /// already and its node ids, browse names and values are mapped onto the namespace indices
+    /// of the address space. Objects, variables, methods, views and types are created with
+    /// their attributes, and variable values are decoded from the XML encoding. References are
+    /// imported whatever their reference type, including reference types defined by the document
+    /// itself. Data type definitions are skipped.
+    ///
+    /// Fails with `BadDecodingError` if the document is malformed and `BadNodeIdExists` if it
+    /// holds a node that already exists, in which case no nodes are imported.
+    pub fn import_nodeset<R>(&mut self, mut reader: R) -> Result<Vec<NodeId>, StatusCode> where R: Read {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml).map_err(|err| {
+            error!("NodeSet cannot be read, {}", err);
+            StatusCode::BadDecodingError
+        })?;
+        let node_set = XmlNode::parse(&xml)?;
+        if node_set.local_name() != "UANodeSet" {
+            error!("NodeSet document is a {} not a UANodeSet", node_set.name);
+            return Err(StatusCode::BadDecodingError);
+        }
+
+        // Namespace 0 is always the OPC UA namespace, the rest are in the order of the document.
+        // URIs that are not in the NamespaceArray get the indices they will have once they are
+        // registered, which happens only if every node can be made.
+        let mut namespace_uris = self.namespace_uris();
+        let mut namespace_indices = vec![0];
+        if let Some(document_namespace_uris) = node_set.child("NamespaceUris") {
+            for namespace_uri in document_namespace_uris.children_named("Uri") {
+                let namespace_uri = namespace_uri.text.trim();
+                let namespace_index = match namespace_uris.iter().position(|uri| uri == namespace_uri) {
+                    Some(namespace_index) => namespace_index,
+                    None => {
+                        namespace_uris.push(namespace_uri.to_string());
+                        namespace_uris.len() - 1
+                    }
+                };
+                namespace_indices.push(namespace_index as u16);
+            }
+        }
+        let aliases = node_set.child("Aliases")
+            .map(|aliases| {
+                aliases.children_named("Alias")
+                    .filter_map(|alias| alias.attribute("Alias").map(|name| (name.to_string(), alias.text.trim().to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let context = NodeSetContext { namespace_indices, aliases };
+
+        // Every node is made before any is inserted so a document that fails leaves the address
+        // space as it was
+        let mut nodes = Vec::new();
+        let mut node_ids = HashSet::new();
+        for element in &node_set.children {
+            if let Some((node, references)) = context.node(element)? {
+                let node_id = node.node_id();
+                if self.node_exists(&node_id) || !node_ids.insert(node_id.clone()) {
+                    error!("NodeSet node {:?} already exists", node_id);
+                    return Err(StatusCode::BadNodeIdExists);
+                }
+                nodes.push((node, references));
+            }
+        }
+        for namespace_uri in &namespace_uris {
+            self.register_namespace(namespace_uri);
+        }
+
+        // References are inserted after the nodes because they may refer to nodes later in the
+        // document. Each is usually declared by both of the nodes it connects.
+        let mut references = Vec::new();
+        let mut imported_node_ids = Vec::with_capacity(nodes.len());
+        for (node, node_references) in nodes {
+            let node_id = node.node_id();
+            self.insert(node, None);
+            for (target_node_id, reference_type_id, direction) in node_references {
+                match direction {
+                    ReferenceDirection::Forward => references.push((node_id.clone(), target_node_id, reference_type_id)),
+                    ReferenceDirection::Inverse => references.push((target_node_id, node_id.clone(), reference_type_id)),
+                }
+            }
+            imported_node_ids.push(node_id);
+        }
+        for (node_id_from, node_id_to, reference_type_id) in references {
+            if node_id_from == node_id_to {
+                warn!("NodeSet reference from {:?} to itself is skipped", node_id_from);
+            } else if !self.has_reference(&node_id_from, reference_type_id.clone(), &node_id_to) {
+                self.insert_reference(&node_id_from, &node_id_to, reference_type_id);
+            }
+        }
+        Ok(imported_node_ids)
+    }
+}
+
+impl AddressSpace {
+    /// Exports the nodes in the namespaces, which are namespace indices of the address space, as
+    /// a NodeSet2 XML document, i.e. a `UANodeSet`, that is written to the writer.
+    ///
+    /// The document holds the namespace URIs of the nodes and of the nodes they refer to, the
+    /// models of the exported namespaces and aliases for the standard reference types and data
+    /// types. Each node is written with its attributes and its forward and inverse references,
+    /// and variable values in the XML encoding. Nodes are ordered by node id so that exports of
+    /// the same nodes are identical.
+    ///
+    /// Fails with `BadEncodingError` if a namespace of the document is not in the
+    /// NamespaceArray or the document cannot be written.
+    pub fn export_nodeset<W>(&self, namespaces: &[u16], mut writer: W) -> Result<(), StatusCode> where W: Write {
+        let nodeset_writer = NodeSetWriter {
+            address_space: self,
+            namespaces: RefCell::new(Vec::new()),
+            aliases: RefCell::new(BTreeMap::new()),
+        };
+        // The exported namespaces come first in the document, in the order they are given
+        let models = namespaces.iter().filter(|v| **v != 0).map(|v| nodeset_writer.namespace_index(*v)).collect::<Vec<u16>>();
+
+        let mut nodes = self.nodes().filter(|v| namespaces.contains(&v.node_id().namespace)).collect::<Vec<&NodeType>>();
+        nodes.sort_by_cached_key(|v| node_id_sort_key(&v.node_id()));
+        let nodes = nodes.into_iter().map(|v| nodeset_writer.node(v)).collect::<Vec<XmlNode>>();
+
+        let namespace_uris = self.namespace_uris();
+        let document_namespace_uris = nodeset_writer.namespaces.borrow().iter()
+            .map(|v| namespace_uris.get(*v as usize).cloned().ok_or_else(|| {
+                error!("Namespace index {} is not in the NamespaceArray and cannot be exported", v);
+                StatusCode::BadEncodingError
+            }))
+            .collect::<Result<Vec<String>, StatusCode>>()?;
+
+        let mut node_set = XmlNode::new("UANodeSet");
+        node_set.set_attribute("xmlns", NODESET_XML_NAMESPACE);
+        if !document_namespace_uris.is_empty() {
+            let mut element = XmlNode::new("NamespaceUris");
+            element.children = document_namespace_uris.iter().map(|v| XmlNode::new_text("Uri", v.as_str())).collect();
+            node_set.add_child(element);
+        }
+        if !models.is_empty() {
+            // Every model requires the OPC UA namespace and the other namespaces of the document
+            let mut required_models = vec![namespace_uris[0].as_str()];
+            required_models.extend(document_namespace_uris.iter().enumerate()
+                .filter(|(idx, _)| !models.contains(&(*idx as u16 + 1)))
+                .map(|(_, v)| v.as_str()));
+            let mut element = XmlNode::new("Models");
+            for namespace in &models {
+                let mut model = XmlNode::new("Model");
+                model.set_attribute("ModelUri", document_namespace_uris[*namespace as usize - 1].as_str());
+                for required_model_uri in &required_models {
+                    let mut required_model = XmlNode::new("RequiredModel");
+                    required_model.set_attribute("ModelUri", *required_model_uri);
+                    model.add_child(required_model);
+                }
+                element.add_child(model);
+            }
+            node_set.add_child(element);
+        }
+        let aliases = nodeset_writer.aliases.borrow();
+        if !aliases.is_empty() {
+            let mut element = XmlNode::new("Aliases");
+            for (alias, node_id) in aliases.iter() {
+                let mut alias_element = XmlNode::new_text("Alias", node_id.to_string());
+                alias_element.set_attribute("Alias", alias.as_str());
+                element.add_child(alias_element);
+            }
+            node_set.add_child(element);
+        }
+        node_set.children.extend(nodes);
+
+        let xml = format!("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n{}\n", node_set.to_pretty_xml_string());
+        writer.write_all(xml.as_bytes()).map_err(|err| {
+            error!("NodeSet cannot be written, {}", err);
+            StatusCode::BadEncodingError
+        })
+    }
+}
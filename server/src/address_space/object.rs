@@ -1,4 +1,6 @@
-use crate::address_space::{base::Base, node::Node};
+use opcua_types::service_types::ObjectAttributes;
+
+use crate::address_space::{base::Base, node::Node, EventNotifier};
 
 #[derive(Debug)]
 pub struct Object {
@@ -18,7 +20,30 @@ impl Object {
         }
     }
 
-    pub fn event_notifier(&self) -> bool {
-        find_attribute_value_mandatory!(&self.base, EventNotifier, Boolean)
+    /// Constructs an object from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: ObjectAttributes) -> Result<Object, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let mut node = Object::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "");
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        if mask.contains(AttributesMask::EVENT_NOTIFIER) {
+            let _ = node.base.set_attribute(AttributeId::EventNotifier, Variant::Byte(attributes.event_notifier).into());
+        }
+        Ok(node)
+    }
+
+    pub fn event_notifier(&self) -> EventNotifier {
+        let bits = find_attribute_value_mandatory!(&self.base, EventNotifier, Byte);
+        EventNotifier::from_bits_truncate(bits)
+    }
+
+    pub fn set_event_notifier(&mut self, event_notifier: EventNotifier) {
+        let _ = self.base.set_attribute(AttributeId::EventNotifier, Variant::Byte(event_notifier.bits).into());
+    }
+
+    /// Tests if clients can subscribe to the events of the object
+    pub fn is_event_notifier(&self) -> bool {
+        self.event_notifier().contains(EventNotifier::SUBSCRIBE_TO_EVENTS)
     }
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+//! Object types modelled by Rust types. The fields of a model are the variables of the instances
+//! of its object type, which read and write the fields of a shared model value, so the address
+//! space and the application state cannot drift apart.
+
+use std::sync::{Arc, Mutex, RwLock};
+
+use opcua_types::{
+    AttributeId, DataValue, NodeId, Variant,
+    node_ids::{ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId},
+    status_code::StatusCode,
+};
+
+use crate::address_space::{
+    AccessLevel, AddressSpace, AttrFnGetter, AttrFnSetter, UserAccessLevel,
+    address_space::ReferenceDirection,
+    instantiate::NodeIdStrategy,
+    node::Node,
+    object_type::ObjectType,
+    variable::{Variable, VariableBuilder},
+};
+
+/// Writes a value into a field of a model
+pub type ModelSetter<T> = fn(&mut T, &Variant) -> Result<(), StatusCode>;
+
+/// A variable of the instances of a modelled object type, i.e. one of its instance declarations,
+/// and the functions that read and write the field of the model that it is bound to.
+pub struct ModelVariable<T> {
+    /// The browse and display name of the variable
+    pub browse_name: &'static str,
+    pub description: &'static str,
+    /// Whether the variable is a property, referenced by HasProperty and of `PropertyType`,
+    /// rather than a component, referenced by HasComponent and of `BaseDataVariableType`
+    pub is_property: bool,
+    pub data_type: NodeId,
+    /// Reads the value of the field from the model
+    pub get: fn(&T) -> Variant,
+    /// Writes the value of the field into the model, or `None` if clients cannot write it
+    pub set: Option<ModelSetter<T>>,
+}
+
+impl<T> ModelVariable<T> {
+    fn reference_type_id(&self) -> ReferenceTypeId {
+        if self.is_property { ReferenceTypeId::HasProperty } else { ReferenceTypeId::HasComponent }
+    }
+
+    fn variable_type_id(&self) -> VariableTypeId {
+        if self.is_property { VariableTypeId::PropertyType } else { VariableTypeId::BaseDataVariableType }
+    }
+
+    /// Creates the variable node, which is writable if the field can be set
+    fn variable(&self, node_id: &NodeId, value: Variant) -> Variable {
+        let (access_level, user_access_level) = if self.set.is_some() {
+            (AccessLevel::CURRENT_READ | AccessLevel::CURRENT_WRITE, UserAccessLevel::CURRENT_READ | UserAccessLevel::CURRENT_WRITE)
+        } else {
+            (AccessLevel::CURRENT_READ, UserAccessLevel::CURRENT_READ)
+        };
+        let mut variable = VariableBuilder::new(node_id)
+            .browse_name(self.browse_name)
+            .display_name(self.browse_name)
+            .description(self.description)
+            .access_level(access_level)
+            .user_access_level(user_access_level)
+            .value(value)
+            .build();
+        let _ = variable.set_attribute(AttributeId::DataType, Variant::from(self.data_type.clone()).into());
+        variable
+    }
+}
+
+/// Implemented by a Rust type that models an object type. `#[derive(UaObjectType)]` in
+/// `opcua-types-derive` implements it for a struct from its fields and attributes.
+pub trait UaObjectType: Send + Sync + Sized + 'static {
+    /// The node id of the object type
+    fn object_type_id() -> NodeId;
+
+    /// The browse name of the object type
+    fn browse_name() -> &'static str;
+
+    /// The node id of the supertype of the object type
+    fn supertype_id() -> NodeId {
+        ObjectTypeId::BaseObjectType.into()
+    }
+
+    /// The variables of the instances of the object type, in order
+    fn variables() -> Vec<ModelVariable<Self>>;
+
+    /// Adds the object type with its instance declarations, which are the variables of the
+    /// model with the Mandatory modelling rule, as a subtype of its supertype. Nothing is added
+    /// if the object type is already in the address space.
+    fn add_object_type(address_space: &mut AddressSpace) {
+        let object_type_id = Self::object_type_id();
+        if address_space.node_exists(&object_type_id) {
+            return;
+        }
+        let browse_name = Self::browse_name();
+        address_space.insert(ObjectType::new(&object_type_id, browse_name, browse_name, "", false), Some(&[
+            (&Self::supertype_id(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+        ]));
+        let mandatory: NodeId = ObjectId::ModellingRule_Mandatory.into();
+        for variable in Self::variables() {
+            // Instance declarations have ids made from the type's so they are the same every time
+            let node_id = NodeId::new(object_type_id.namespace, format!("{}.{}", browse_name, variable.browse_name));
+            address_space.insert(variable.variable(&node_id, Variant::Empty), Some(&[
+                (&object_type_id, variable.reference_type_id(), ReferenceDirection::Inverse),
+                (&variable.variable_type_id().into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+                (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+            ]));
+        }
+    }
+
+    /// Adds an object of the object type under the parent with `AddressSpace::instantiate`, which
+    /// adds the object type too if it is not already in the address space. The value of each
+    /// variable of the object is read from the model and, if its field can be set, written to it.
+    /// Returns the id of the object.
+    fn instantiate(model: &Arc<RwLock<Self>>, address_space: &mut AddressSpace, parent_node_id: &NodeId, browse_name: &str) -> Result<NodeId, StatusCode> {
+        if !address_space.node_exists(parent_node_id) {
+            return Err(StatusCode::BadParentNodeIdInvalid);
+        }
+        Self::add_object_type(address_space);
+        let object_id = address_space.instantiate(&Self::object_type_id(), parent_node_id, browse_name, NodeIdStrategy::Numeric, &[])?;
+
+        let children = address_space.find_references_from(&object_id, Some((ReferenceTypeId::Aggregates.into(), true))).unwrap_or_default();
+        for variable in Self::variables() {
+            let node_id = children.iter().map(|reference| &reference.node_id).find(|node_id| {
+                match address_space.find_node(node_id) {
+                    Some(node) => node.as_node().browse_name().name.as_ref() == variable.browse_name,
+                    None => false,
+                }
+            }).cloned();
+            let node = match node_id.and_then(|node_id| address_space.find_variable_mut_by_ref(&node_id)) {
+                Some(node) => node,
+                None => continue,
+            };
+
+            let (get, model_getter) = (variable.get, model.clone());
+            node.set_value_getter(Arc::new(Mutex::new(AttrFnGetter::new(move |_, _| {
+                let value = get(&*trace_read_lock_unwrap!(model_getter));
+                Ok(Some(DataValue::new(value)))
+            }))));
+            if let Some(set) = variable.set {
+                let model_setter = model.clone();
+                node.set_value_setter(Arc::new(Mutex::new(AttrFnSetter::new(move |_, _, data_value: DataValue| {
+                    let value = data_value.value.unwrap_or(Variant::Empty);
+                    set(&mut *trace_write_lock_unwrap!(model_setter), &value)
+                }))));
+            }
+        }
+        Ok(object_id)
+    }
+}
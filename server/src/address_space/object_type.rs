@@ -1,3 +1,5 @@
+use opcua_types::service_types::ObjectTypeAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -18,6 +20,17 @@ impl ObjectType {
         }
     }
 
+    /// Constructs an object type from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: ObjectTypeAttributes) -> Result<ObjectType, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let is_abstract = mask.contains(AttributesMask::IS_ABSTRACT) && attributes.is_abstract;
+        let mut node = ObjectType::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", is_abstract);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        Ok(node)
+    }
+
     pub fn is_abstract(&self) -> bool {
         find_attribute_value_mandatory!(&self.base, IsAbstract, Boolean)
     }
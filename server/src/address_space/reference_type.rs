@@ -1,3 +1,5 @@
+use opcua_types::service_types::ReferenceTypeAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -23,6 +25,19 @@ impl ReferenceType {
         }
     }
 
+    /// Constructs a reference type from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: ReferenceTypeAttributes) -> Result<ReferenceType, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let inverse_name = if mask.contains(AttributesMask::INVERSE_NAME) { Some(attributes.inverse_name) } else { None };
+        let symmetric = mask.contains(AttributesMask::SYMMETRIC) && attributes.symmetric;
+        let is_abstract = mask.contains(AttributesMask::IS_ABSTRACT) && attributes.is_abstract;
+        let mut node = ReferenceType::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", inverse_name, symmetric, is_abstract);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        Ok(node)
+    }
+
     pub fn symmetric(&self) -> bool {
         find_attribute_value_mandatory!(&self.base, Symmetric, Boolean)
     }
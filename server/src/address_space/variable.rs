@@ -2,6 +2,7 @@ use std::sync::{Arc, Mutex};
 use std::convert::Into;
 
 use opcua_types::node_ids::DataTypeId;
+use opcua_types::service_types::VariableAttributes;
 
 use crate::address_space::{
     AttributeGetter, AttributeSetter,
@@ -66,8 +67,16 @@ impl VariableBuilder {
         self
     }
 
+    /// Sets whether the server records the history of the variable's value. A historizing
+    /// variable's history is also made readable through its access levels.
     pub fn historizing(mut self, historizing: bool) -> Self {
         let _ = self.node.set_attribute(AttributeId::Historizing, Variant::Boolean(historizing).into());
+        if historizing {
+            let access_level = self.node.access_level() | AccessLevel::HISTORY_READ;
+            self.node.set_access_level(access_level);
+            let user_access_level = self.node.user_access_level() | UserAccessLevel::HISTORY_READ;
+            self.node.set_user_access_level(user_access_level);
+        }
         self
     }
 
@@ -92,7 +101,7 @@ impl VariableBuilder {
     }
 
     pub fn minimum_sampling_interval(mut self, minimum_sampling_interval: i32) -> Self {
-        let _ = self.node.set_attribute(AttributeId::MinimumSamplingInterval, Variant::Int32(minimum_sampling_interval).into());
+        let _ = self.node.set_attribute(AttributeId::MinimumSamplingInterval, Variant::Double(f64::from(minimum_sampling_interval)).into());
         self
     }
 
@@ -180,6 +189,48 @@ impl Variable {
         builder.build()
     }
 
+    /// Constructs a variable from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values. If no data type is specified, it
+    /// is inferred from the value.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: VariableAttributes) -> Result<Variable, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let mut node = VariableBuilder::new(node_id)
+            .display_name(browse_name.name.as_ref())
+            .build();
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        if mask.contains(AttributesMask::DATA_TYPE) {
+            let _ = node.base.set_attribute(AttributeId::DataType, Variant::from(attributes.data_type).into());
+        } else if let Some(data_type) = attributes.value.data_type() {
+            let data_type: NodeId = data_type.into();
+            let _ = node.base.set_attribute(AttributeId::DataType, Variant::from(data_type).into());
+        }
+        if mask.contains(AttributesMask::VALUE) {
+            node.set_value(attributes.value);
+        }
+        if mask.contains(AttributesMask::VALUE_RANK) {
+            let _ = node.base.set_attribute(AttributeId::ValueRank, Variant::Int32(attributes.value_rank).into());
+        }
+        if mask.contains(AttributesMask::ARRAY_DIMENSIONS) {
+            if let Some(array_dimensions) = attributes.array_dimensions {
+                let _ = node.base.set_attribute(AttributeId::ArrayDimensions, Variant::from(&array_dimensions[..]).into());
+            }
+        }
+        if mask.contains(AttributesMask::ACCESS_LEVEL) {
+            node.set_access_level(AccessLevel::from_bits_truncate(attributes.access_level));
+        }
+        if mask.contains(AttributesMask::USER_ACCESS_LEVEL) {
+            node.set_user_access_level(UserAccessLevel::from_bits_truncate(attributes.user_access_level));
+        }
+        if mask.contains(AttributesMask::MINIMUM_SAMPLING_INTERVAL) {
+            node.set_minimum_sampling_interval(attributes.minimum_sampling_interval as i32);
+        }
+        if mask.contains(AttributesMask::HISTORIZING) {
+            let _ = node.base.set_attribute(AttributeId::Historizing, Variant::Boolean(attributes.historizing).into());
+        }
+        Ok(node)
+    }
+
     pub fn is_valid(&self) -> bool {
         !self.base.node_id().is_null()
     }
@@ -215,7 +266,7 @@ impl Variable {
 
     /// Gets the minimum sampling interval, if the attribute was set
     pub fn minimum_sampling_interval(&self) -> Option<i32> {
-        find_attribute_value_optional!(&self.base, MinimumSamplingInterval, Int32)
+        find_attribute_value_optional!(&self.base, MinimumSamplingInterval, Double).map(|v| v.round() as i32)
     }
 
     /// Sets the minimum sampling interval
@@ -225,7 +276,7 @@ impl Variable {
     /// The value 0 means server is to monitor the value continuously. The value -1 means indeterminate.
     pub fn set_minimum_sampling_interval(&mut self, minimum_sampling_interval: i32) {
         let now = DateTime::now();
-        let _ = self.base.set_attribute_value(AttributeId::MinimumSamplingInterval, Variant::Int32(minimum_sampling_interval), &now, &now);
+        let _ = self.base.set_attribute_value(AttributeId::MinimumSamplingInterval, Variant::Double(f64::from(minimum_sampling_interval)), &now, &now);
     }
 
     pub fn is_readable(&self) -> bool {
@@ -280,6 +331,36 @@ impl Variable {
         find_attribute_value_mandatory!(&self.base, Historizing, Boolean)
     }
 
+    pub fn set_historizing(&mut self, historizing: bool) {
+        let _ = self.base.set_attribute(AttributeId::Historizing, Variant::Boolean(historizing).into());
+    }
+
+    pub fn is_history_readable(&self) -> bool {
+        self.access_level().contains(AccessLevel::HISTORY_READ)
+    }
+
+    pub fn is_user_history_readable(&self) -> bool {
+        self.user_access_level().contains(UserAccessLevel::HISTORY_READ)
+    }
+
+    pub fn is_history_writable(&self) -> bool {
+        self.access_level().contains(AccessLevel::HISTORY_WRITE)
+    }
+
+    pub fn is_user_history_writable(&self) -> bool {
+        self.user_access_level().contains(UserAccessLevel::HISTORY_WRITE)
+    }
+
+    pub fn set_history_writable(&mut self, history_writable: bool) {
+        let mut access_level = self.access_level();
+        if history_writable {
+            access_level.insert(AccessLevel::HISTORY_WRITE);
+        } else {
+            access_level.remove(AccessLevel::HISTORY_WRITE);
+        }
+        self.set_access_level(access_level);
+    }
+
     pub fn array_dimensions(&self) -> Option<Vec<u32>> {
         if let Some(values) = find_attribute_value_optional!(&self.base, ArrayDimensions, Array) {
             // The expectation is that this Vec<Variant> is a non-zero Vec<u32>
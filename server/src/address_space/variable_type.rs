@@ -1,3 +1,5 @@
+use opcua_types::service_types::VariableTypeAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -23,6 +25,29 @@ impl VariableType {
         }
     }
 
+    /// Constructs a variable type from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: VariableTypeAttributes) -> Result<VariableType, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let is_abstract = mask.contains(AttributesMask::IS_ABSTRACT) && attributes.is_abstract;
+        let value_rank = if mask.contains(AttributesMask::VALUE_RANK) { attributes.value_rank } else { -1 };
+        let mut node = VariableType::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", is_abstract, value_rank);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        if mask.contains(AttributesMask::VALUE) {
+            let _ = node.base.set_attribute(AttributeId::Value, DataValue::new(attributes.value));
+        }
+        if mask.contains(AttributesMask::DATA_TYPE) {
+            let _ = node.base.set_attribute(AttributeId::DataType, Variant::from(attributes.data_type).into());
+        }
+        if mask.contains(AttributesMask::ARRAY_DIMENSIONS) {
+            if let Some(array_dimensions) = attributes.array_dimensions {
+                let _ = node.base.set_attribute(AttributeId::ArrayDimensions, Variant::from(&array_dimensions[..]).into());
+            }
+        }
+        Ok(node)
+    }
+
     pub fn is_abstract(&self) -> bool {
         find_attribute_value_mandatory!(&self.base, IsAbstract, Boolean)
     }
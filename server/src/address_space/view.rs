@@ -1,3 +1,5 @@
+use opcua_types::service_types::ViewAttributes;
+
 use crate::address_space::{base::Base, node::Node};
 
 #[derive(Debug)]
@@ -19,6 +21,18 @@ impl View {
         }
     }
 
+    /// Constructs a view from the attributes supplied in an AddNodes request. Attributes that
+    /// are not flagged as specified take their default values.
+    pub fn from_attributes(node_id: &NodeId, browse_name: QualifiedName, attributes: ViewAttributes) -> Result<View, ()> {
+        let mask = AttributesMask::from_bits_truncate(attributes.specified_attributes);
+        let event_notifier = mask.contains(AttributesMask::EVENT_NOTIFIER) && attributes.event_notifier != 0;
+        let contains_no_loops = mask.contains(AttributesMask::CONTAINS_NO_LOOPS) && attributes.contains_no_loops;
+        let mut node = View::new(node_id, browse_name.name.as_ref(), browse_name.name.as_ref(), "", event_notifier, contains_no_loops);
+        node.base.set_browse_name(browse_name);
+        node.base.set_common_attributes(mask, attributes.display_name, attributes.description, attributes.write_mask, attributes.user_write_mask);
+        Ok(node)
+    }
+
     pub fn event_notifier(&self) -> bool {
         find_attribute_value_mandatory!(&self.base, EventNotifier, Boolean)
     }
@@ -126,12 +126,20 @@ impl Transport for TcpTransport {
         self.client_address
     }
 
-    // Terminates the connection and the session
+    // Terminates the connection and the session. An activated session is detached instead so it
+    // can be reactivated on another connection until its session timeout expires.
     fn finish(&mut self, status_code: StatusCode) {
         if !self.is_finished() {
             self.transport_state = TransportState::Finished(status_code);
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
             let mut session = trace_write_lock_unwrap!(self.session);
-            session.set_terminated();
+            if session.is_detachable() {
+                info!("Session {} is being detached from its connection", session.session_id);
+                session.detach();
+                server_state.detach_session(self.session.clone());
+            } else {
+                session.set_terminated();
+            }
         }
     }
 
@@ -353,6 +361,7 @@ impl TcpTransport {
         register_runtime_component!(id);
 
         let transport_for_take_while = transport.clone();
+        let transport_for_error = transport.clone();
 
         // Connection state is maintained for looping through each task
         let connection = Arc::new(RwLock::new(ReadState {
@@ -421,6 +430,8 @@ impl TcpTransport {
                 Ok(())
             }).map_err(move |e| {
             error!("Read loop error {:?}", e);
+            let mut transport = trace_write_lock_unwrap!(transport_for_error);
+            transport.finish(StatusCode::BadCommunicationError);
         }).and_then(move |_| {
             let connection = trace_write_lock_unwrap!(connection_for_terminate);
             // Some handlers might wish to send their message and terminate, in which case this is
@@ -434,6 +445,9 @@ impl TcpTransport {
                 };
                 if terminate {
                     transport.finish(StatusCode::BadConnectionClosed);
+                } else if !transport.is_finished() {
+                    // The client has closed the socket
+                    transport.finish(StatusCode::BadCommunicationError);
                 }
                 // Other session status
                 transport.is_finished()
@@ -758,6 +772,12 @@ impl TcpTransport {
             }
             MessageChunkType::Message => {
                 let response = self.message_handler.handle_message(request_id, request)?;
+                // The message handler changes session when a detached session is reactivated
+                let session = self.message_handler.session();
+                if !Arc::ptr_eq(&session, &self.session) {
+                    self.session_id = trace_read_lock_unwrap!(session).session_id.clone();
+                    self.session = session;
+                }
                 if response.is_none() {
                     // No response for the message at this time
                     return Ok(());
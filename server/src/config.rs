@@ -64,6 +64,11 @@ pub struct ServerEndpoint {
     pub security_level: u8,
     /// User tokens
     pub user_token_ids: BTreeSet<String>,
+    /// User tokens that are permitted to call the NodeManagement services (AddNodes, AddReferences,
+    /// DeleteNodes, DeleteReferences) on this endpoint. Empty by default, i.e. nobody may modify
+    /// the address space remotely.
+    #[serde(default)]
+    pub node_management_user_token_ids: BTreeSet<String>,
 }
 
 /// Convenience method to make an endpoint from a tuple
@@ -75,6 +80,7 @@ impl<'a> From<(&'a str, SecurityPolicy, MessageSecurityMode, &'a [&'a str])> for
             security_mode: v.2.to_string(),
             security_level: Self::security_level(v.1),
             user_token_ids: v.3.iter().map(|id| id.to_string()).collect(),
+            node_management_user_token_ids: BTreeSet::new(),
         }
     }
 }
@@ -87,6 +93,7 @@ impl ServerEndpoint {
             security_mode: security_mode.to_string(),
             security_level: Self::security_level(security_policy),
             user_token_ids: user_token_ids.iter().map(|id| id.clone()).collect(),
+            node_management_user_token_ids: BTreeSet::new(),
         }
     }
 
@@ -144,6 +151,14 @@ impl ServerEndpoint {
             }
         }
 
+        // Validate that node management user token ids are also user token ids of the endpoint
+        for id in &self.node_management_user_token_ids {
+            if !self.user_token_ids.contains(id) {
+                error!("Node management user token id {} is not a user token id of the endpoint", id);
+                valid = false;
+            }
+        }
+
         // Validate the security policy and mode
         let security_policy = SecurityPolicy::from_str(&self.security_policy).unwrap();
         let security_mode = MessageSecurityMode::from(self.security_mode.as_ref());
@@ -184,6 +199,11 @@ impl ServerEndpoint {
     pub fn supports_user_token_id(&self, id: &str) -> bool {
         self.user_token_ids.contains(id)
     }
+
+    /// Test if the user token id is permitted to call the NodeManagement services
+    pub fn supports_node_management(&self, id: &str) -> bool {
+        self.node_management_user_token_ids.contains(id)
+    }
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
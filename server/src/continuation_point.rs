@@ -1,9 +1,10 @@
-//! Provides a browse continuation point type for tracking a browse operation initiated by a client.
+//! Provides continuation point types for tracking browse, history read and query operations
+//! initiated by a client.
 
 use std::sync::{Arc, Mutex};
 
-use opcua_types::ByteString;
-use opcua_types::service_types::ReferenceDescription;
+use opcua_types::{ByteString, DataValue, ExtensionObject, NodeId};
+use opcua_types::service_types::{ModificationInfo, QueryDataSet, ReferenceDescription};
 
 use crate::DateTimeUtc;
 
@@ -24,4 +25,30 @@ impl BrowseContinuationPoint {
     pub fn is_valid_browse_continuation_point(&self, address_space: &AddressSpace) -> bool {
         self.address_space_last_modified >= address_space.last_modified()
     }
-}
\ No newline at end of file
+}
+
+/// A continuation point for the remainder of a HistoryRead of a node. The point holds the entire
+/// result of the read and the index of the next value to return.
+#[derive(Clone)]
+pub struct HistoryContinuationPoint {
+    pub id: ByteString,
+    /// The node that was read, which a read continuing from the point must be for
+    pub node_id: NodeId,
+    /// The history read details of the read, which a read continuing from the point must repeat
+    pub history_read_details: ExtensionObject,
+    pub max_values_per_node: usize,
+    pub starting_index: usize,
+    pub data_values: Arc<Mutex<Vec<DataValue>>>,
+    /// Modification infos corresponding to each value when the read was for modified values
+    pub modification_infos: Option<Arc<Mutex<Vec<ModificationInfo>>>>,
+}
+
+/// A continuation point for the remainder of a QueryFirst. The point holds the entire result of
+/// the query and the index of the next data set to return.
+#[derive(Clone)]
+pub struct QueryContinuationPoint {
+    pub id: ByteString,
+    pub max_data_sets_to_return: usize,
+    pub starting_index: usize,
+    pub query_data_sets: Arc<Mutex<Vec<QueryDataSet>>>,
+}
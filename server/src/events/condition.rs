@@ -0,0 +1,458 @@
+//! Conditions of OPC UA Part 9, Alarms and Conditions. A condition is an object of ConditionType
+//! or one of its subtypes whose variables hold the state of something in its source node, e.g.
+//! an alarm on a variable. Each change to the state of a condition is reported as an event of the
+//! condition's type whose fields are the values of the condition's variables.
+//!
+//! A condition is added with [`add_condition`], which also registers the handlers of the methods
+//! that clients call to enable, disable, acknowledge, confirm and comment on it. The server
+//! activates and deactivates an alarm with [`set_active`].
+//!
+//! Conditions do not have branches, only the current state of a condition is kept.
+//!
+//! [`add_condition`]: fn.add_condition.html
+//! [`set_active`]: fn.set_active.html
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, MethodId, ObjectTypeId, ReferenceTypeId, VariableTypeId};
+use opcua_types::status_code::StatusCode;
+
+use crate::{
+    address_space::{
+        address_space::{AddressSpace, ReferenceDirection},
+        method_impls,
+        node::{Node, NodeType},
+        object::Object,
+        variable::Variable,
+    },
+    events::event::Event,
+};
+
+/// Returns the name of the state of a two state variable, e.g. "Active" for the ActiveState when
+/// it is true.
+fn state_name(state: &str, value: bool) -> &'static str {
+    match (state, value) {
+        ("EnabledState", true) => "Enabled",
+        ("EnabledState", false) => "Disabled",
+        ("AckedState", true) => "Acknowledged",
+        ("AckedState", false) => "Unacknowledged",
+        ("ConfirmedState", true) => "Confirmed",
+        ("ConfirmedState", false) => "Unconfirmed",
+        ("ActiveState", true) | ("HighHighState", true) | ("HighState", true) | ("LowState", true) | ("LowLowState", true) => "Active",
+        ("ActiveState", false) | ("HighHighState", false) | ("HighState", false) | ("LowState", false) | ("LowLowState", false) => "Inactive",
+        (_, true) => "True",
+        (_, false) => "False",
+    }
+}
+
+/// Makes the id of a variable beneath the parent from the parent's id and the variable's name
+fn child_id(parent_id: &NodeId, name: &str) -> NodeId {
+    NodeId::new(parent_id.namespace, format!("{}.{}", parent_id.identifier, name))
+}
+
+/// Adds a variable of the variable type beneath the parent, returning its id
+pub(crate) fn add_variable<V>(address_space: &mut AddressSpace, parent_id: &NodeId, name: &str, reference_type_id: ReferenceTypeId, variable_type_id: VariableTypeId, data_type: DataTypeId, value: V) -> NodeId where V: Into<Variant> {
+    let node_id = child_id(parent_id, name);
+    let variable = Variable::new_with_data_type(&node_id, name, name, "", data_type, value);
+    address_space.insert(variable, Some(&[
+        (parent_id, reference_type_id, ReferenceDirection::Inverse),
+        (&variable_type_id.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+    ]));
+    node_id
+}
+
+pub(crate) fn add_property<V>(address_space: &mut AddressSpace, parent_id: &NodeId, name: &str, data_type: DataTypeId, value: V) -> NodeId where V: Into<Variant> {
+    add_variable(address_space, parent_id, name, ReferenceTypeId::HasProperty, VariableTypeId::PropertyType, data_type, value)
+}
+
+pub(crate) fn add_two_state_variable(address_space: &mut AddressSpace, parent_id: &NodeId, name: &str, value: bool) {
+    let state_id = add_variable(address_space, parent_id, name, ReferenceTypeId::HasComponent, VariableTypeId::TwoStateVariableType,
+                                DataTypeId::LocalizedText, LocalizedText::new("", state_name(name, value)));
+    add_property(address_space, &state_id, "Id", DataTypeId::Boolean, value);
+}
+
+fn add_condition_variable<V>(address_space: &mut AddressSpace, parent_id: &NodeId, name: &str, data_type: DataTypeId, value: V, now: &DateTime) where V: Into<Variant> {
+    let variable_id = add_variable(address_space, parent_id, name, ReferenceTypeId::HasComponent, VariableTypeId::ConditionVariableType, data_type, value);
+    add_property(address_space, &variable_id, "SourceTimestamp", DataTypeId::UtcTime, now.clone());
+}
+
+/// Adds a condition of the condition type, which is ConditionType or one of its subtypes, to the
+/// source node. The condition is enabled and has the variables of its type, which for an alarm
+/// start out inactive and acknowledged. A confirmable condition also has a ConfirmedState, which
+/// only applies to AcknowledgeableConditionType and its subtypes.
+///
+/// Fails with `BadNodeIdExists` if the node id is in use, `BadNodeIdUnknown` if the source node
+/// does not exist, or `BadTypeDefinitionInvalid` if the type is not a condition type.
+pub fn add_condition<T>(address_space: &mut AddressSpace, node_id: &NodeId, browse_name: &str, condition_type_id: T, source_node_id: &NodeId, confirmable: bool) -> Result<(), StatusCode> where T: Into<NodeId> {
+    let condition_type_id = condition_type_id.into();
+    if address_space.node_exists(node_id) {
+        error!("Cannot add condition {:?} because the node id is in use", node_id);
+        return Err(StatusCode::BadNodeIdExists);
+    }
+    let source_name = match address_space.find_node(source_node_id) {
+        Some(node) => node.as_node().display_name().text,
+        None => {
+            error!("Cannot add condition to source node {:?} which does not exist", source_node_id);
+            return Err(StatusCode::BadNodeIdUnknown);
+        }
+    };
+    if !address_space.is_subtype(&condition_type_id, &ObjectTypeId::ConditionType.into()) {
+        error!("Cannot add condition of type {:?} which is not a condition type", condition_type_id);
+        return Err(StatusCode::BadTypeDefinitionInvalid);
+    }
+    let is_acknowledgeable = address_space.is_subtype(&condition_type_id, &ObjectTypeId::AcknowledgeableConditionType.into());
+    let is_alarm = address_space.is_subtype(&condition_type_id, &ObjectTypeId::AlarmConditionType.into());
+
+    address_space.insert(Object::new(node_id, browse_name, browse_name, ""), Some(&[
+        (&condition_type_id, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (source_node_id, ReferenceTypeId::HasCondition, ReferenceDirection::Inverse),
+    ]));
+
+    let now = DateTime::now();
+
+    // Properties of BaseEventType
+    add_property(address_space, node_id, "EventId", DataTypeId::ByteString, ByteString::null());
+    add_property(address_space, node_id, "EventType", DataTypeId::NodeId, condition_type_id.clone());
+    add_property(address_space, node_id, "SourceNode", DataTypeId::NodeId, source_node_id.clone());
+    add_property(address_space, node_id, "SourceName", DataTypeId::String, source_name);
+    add_property(address_space, node_id, "Time", DataTypeId::UtcTime, now.clone());
+    add_property(address_space, node_id, "ReceiveTime", DataTypeId::UtcTime, now.clone());
+    add_property(address_space, node_id, "Message", DataTypeId::LocalizedText, LocalizedText::new("", ""));
+    add_property(address_space, node_id, "Severity", DataTypeId::UInt16, 0u16);
+
+    // Variables of ConditionType
+    let condition_class_id: NodeId = ObjectTypeId::BaseConditionClassType.into();
+    add_property(address_space, node_id, "ConditionClassId", DataTypeId::NodeId, condition_class_id);
+    add_property(address_space, node_id, "ConditionClassName", DataTypeId::LocalizedText, LocalizedText::new("", "BaseConditionClass"));
+    add_property(address_space, node_id, "ConditionName", DataTypeId::String, browse_name);
+    add_property(address_space, node_id, "BranchId", DataTypeId::NodeId, NodeId::null());
+    add_property(address_space, node_id, "Retain", DataTypeId::Boolean, false);
+    add_two_state_variable(address_space, node_id, "EnabledState", true);
+    add_condition_variable(address_space, node_id, "Quality", DataTypeId::StatusCode, StatusCode::Good, &now);
+    add_condition_variable(address_space, node_id, "LastSeverity", DataTypeId::UInt16, 0u16, &now);
+    add_condition_variable(address_space, node_id, "Comment", DataTypeId::LocalizedText, LocalizedText::new("", ""), &now);
+    add_property(address_space, node_id, "ClientUserId", DataTypeId::String, UAString::null());
+
+    address_space.register_method_handler_mut(node_id.clone(), MethodId::ConditionType_Enable, Box::new(method_impls::handle_condition_enable));
+    address_space.register_method_handler_mut(node_id.clone(), MethodId::ConditionType_Disable, Box::new(method_impls::handle_condition_disable));
+    address_space.register_method_handler_mut(node_id.clone(), MethodId::ConditionType_AddComment, Box::new(method_impls::handle_condition_add_comment));
+
+    // Variables of AcknowledgeableConditionType
+    if is_acknowledgeable {
+        add_two_state_variable(address_space, node_id, "AckedState", true);
+        address_space.register_method_handler_mut(node_id.clone(), MethodId::AcknowledgeableConditionType_Acknowledge, Box::new(method_impls::handle_condition_acknowledge));
+        if confirmable {
+            add_two_state_variable(address_space, node_id, "ConfirmedState", true);
+            address_space.register_method_handler_mut(node_id.clone(), MethodId::AcknowledgeableConditionType_Confirm, Box::new(method_impls::handle_condition_confirm));
+        }
+    }
+
+    // Variables of AlarmConditionType
+    if is_alarm {
+        add_two_state_variable(address_space, node_id, "ActiveState", false);
+        add_property(address_space, node_id, "InputNode", DataTypeId::NodeId, NodeId::null());
+        add_property(address_space, node_id, "SuppressedOrShelved", DataTypeId::Boolean, false);
+    }
+
+    update_retain(address_space, node_id, &now);
+    Ok(())
+}
+
+/// Finds the variable at the browse path from the condition, following HasComponent and
+/// HasProperty references
+fn find_variable_id(address_space: &AddressSpace, node_id: &NodeId, browse_path: &[&str]) -> Option<NodeId> {
+    let mut node_id = node_id.clone();
+    for name in browse_path {
+        let browse_name = QualifiedName::new(0, name);
+        let references = address_space.find_references_from(&node_id, Some((ReferenceTypeId::Aggregates.into(), true)))?;
+        node_id = references.into_iter()
+            .map(|r| r.node_id)
+            .find(|node_id| {
+                address_space.find_node(node_id).map_or(false, |node| node.as_node().browse_name() == browse_name)
+            })?;
+    }
+    Some(node_id)
+}
+
+/// Returns the value of the variable at the browse path from the condition
+pub(crate) fn value(address_space: &AddressSpace, condition_id: &NodeId, browse_path: &[&str]) -> Option<Variant> {
+    let variable_id = find_variable_id(address_space, condition_id, browse_path)?;
+    address_space.find_variable_by_ref(&variable_id).and_then(|variable| variable.value().value)
+}
+
+/// Sets the value of the variable at the browse path from the condition, which must exist
+pub(crate) fn set_value<V>(address_space: &mut AddressSpace, condition_id: &NodeId, browse_path: &[&str], value: V, now: &DateTime) -> Result<(), StatusCode> where V: Into<Variant> {
+    let variable_id = find_variable_id(address_space, condition_id, browse_path).ok_or_else(|| {
+        error!("Condition {:?} has no variable {:?}", condition_id, browse_path);
+        StatusCode::BadNotSupported
+    })?;
+    address_space.set_variable_value_by_ref(&variable_id, value, now, now);
+    Ok(())
+}
+
+/// Returns the value of a two state variable of the condition, or `None` if it does not have it
+pub(crate) fn state(address_space: &AddressSpace, condition_id: &NodeId, state: &str) -> Option<bool> {
+    match value(address_space, condition_id, &[state, "Id"]) {
+        Some(Variant::Boolean(value)) => Some(value),
+        _ => None
+    }
+}
+
+pub(crate) fn set_state(address_space: &mut AddressSpace, condition_id: &NodeId, state: &str, value: bool, now: &DateTime) -> Result<(), StatusCode> {
+    set_value(address_space, condition_id, &[state, "Id"], value, now)?;
+    set_value(address_space, condition_id, &[state], LocalizedText::new("", state_name(state, value)), now)
+}
+
+/// Sets the value of a condition variable and its source timestamp
+fn set_condition_variable<V>(address_space: &mut AddressSpace, condition_id: &NodeId, name: &str, value: V, now: &DateTime) -> Result<(), StatusCode> where V: Into<Variant> {
+    set_value(address_space, condition_id, &[name], value, now)?;
+    set_value(address_space, condition_id, &[name, "SourceTimestamp"], now.clone(), now)
+}
+
+/// Tests if the node is a condition
+fn is_condition(address_space: &AddressSpace, node_id: &NodeId) -> bool {
+    match address_space.get_type_id(node_id) {
+        Some(type_id) => address_space.is_subtype(&type_id, &ObjectTypeId::ConditionType.into()),
+        None => false
+    }
+}
+
+/// Checks that the node is a condition, failing with `BadNodeIdUnknown` if it is not
+fn expect_condition(address_space: &AddressSpace, condition_id: &NodeId) -> Result<(), StatusCode> {
+    if is_condition(address_space, condition_id) {
+        Ok(())
+    } else {
+        error!("Node {:?} is not a condition", condition_id);
+        Err(StatusCode::BadNodeIdUnknown)
+    }
+}
+
+/// Tests if the condition is enabled
+pub fn is_enabled(address_space: &AddressSpace, condition_id: &NodeId) -> bool {
+    state(address_space, condition_id, "EnabledState").unwrap_or(false)
+}
+
+/// Tests if the condition should be retained, i.e. it is in a state that is interesting for a
+/// client. An alarm is retained while it is active, unacknowledged or unconfirmed, and any
+/// other acknowledgeable condition while it is unacknowledged or unconfirmed. A condition that
+/// is not acknowledgeable is retained while it is enabled.
+fn should_retain(address_space: &AddressSpace, condition_id: &NodeId) -> bool {
+    let active = state(address_space, condition_id, "ActiveState");
+    let acked = state(address_space, condition_id, "AckedState");
+    let confirmed = state(address_space, condition_id, "ConfirmedState");
+    is_enabled(address_space, condition_id) &&
+        (active.unwrap_or_else(|| acked.is_none()) || acked == Some(false) || confirmed == Some(false))
+}
+
+fn update_retain(address_space: &mut AddressSpace, condition_id: &NodeId, now: &DateTime) {
+    let retain = should_retain(address_space, condition_id);
+    let _ = set_value(address_space, condition_id, &["Retain"], retain, now);
+}
+
+/// Reports the state of the condition as a new event
+fn report(address_space: &mut AddressSpace, condition_id: &NodeId, now: &DateTime) -> Result<(), StatusCode> {
+    update_retain(address_space, condition_id, now);
+    set_value(address_space, condition_id, &["EventId"], ByteString::random(16), now)?;
+    set_value(address_space, condition_id, &["Time"], now.clone(), now)?;
+    set_value(address_space, condition_id, &["ReceiveTime"], now.clone(), now)?;
+    let event = condition_event(address_space, condition_id).ok_or(StatusCode::BadNodeIdUnknown)?;
+    address_space.raise_event(event)
+}
+
+/// Collects the values of the variables beneath the node by their browse paths. Objects beneath
+/// the node, such as the state machine of a limit alarm, are part of the browse paths of the
+/// variables beneath them.
+fn collect_fields(address_space: &AddressSpace, node_id: &NodeId, browse_path: &mut Vec<QualifiedName>, fields: &mut Vec<(Vec<QualifiedName>, Variant)>) {
+    if let Some(references) = address_space.find_references_from(node_id, Some((ReferenceTypeId::Aggregates.into(), true))) {
+        for reference in references {
+            match address_space.find_node(&reference.node_id) {
+                Some(NodeType::Variable(ref variable)) => {
+                    browse_path.push(variable.browse_name());
+                    fields.push((browse_path.clone(), variable.value().value.unwrap_or(Variant::Empty)));
+                }
+                Some(NodeType::Object(ref object)) => {
+                    browse_path.push(object.browse_name());
+                }
+                _ => {
+                    continue;
+                }
+            }
+            collect_fields(address_space, &reference.node_id, browse_path, fields);
+            let _ = browse_path.pop();
+        }
+    }
+}
+
+/// Returns the event that reports the current state of the condition, with the id and time of
+/// the last time it was reported, or `None` if the node is not a condition.
+pub fn condition_event(address_space: &AddressSpace, condition_id: &NodeId) -> Option<Event> {
+    if !is_condition(address_space, condition_id) {
+        return None;
+    }
+    let event_type = address_space.get_type_id(condition_id)?;
+    let mut fields = Vec::new();
+    collect_fields(address_space, condition_id, &mut Vec::new(), &mut fields);
+
+    let mut event = Event::new(event_type, &NodeId::null(), "", "", 0);
+    event.condition_id = Some(condition_id.clone());
+    for (browse_path, value) in fields {
+        // The properties of BaseEventType are fields of the event
+        if browse_path.len() == 1 && browse_path[0].namespace_index == 0 {
+            match (browse_path[0].name.as_ref(), value) {
+                ("EventId", Variant::ByteString(v)) => event.event_id = v,
+                ("EventType", _) => {}
+                ("SourceNode", Variant::NodeId(v)) => event.source_node = *v,
+                ("SourceName", Variant::String(v)) => event.source_name = v,
+                ("Time", Variant::DateTime(v)) => event.time = *v,
+                ("ReceiveTime", Variant::DateTime(v)) => event.receive_time = *v,
+                ("Message", Variant::LocalizedText(v)) => event.message = *v,
+                ("Severity", Variant::UInt16(v)) => event.severity = v,
+                (_, value) => event.set_field(&browse_path, value),
+            }
+        } else {
+            event.set_field(&browse_path, value);
+        }
+    }
+    Some(event)
+}
+
+/// Returns the events that report the current state of the conditions that are retained, for
+/// refreshing the conditions of a client
+pub fn retained_condition_events(address_space: &AddressSpace) -> Vec<Event> {
+    // Find the instances of ConditionType and its subtypes
+    let mut type_ids: Vec<NodeId> = vec![ObjectTypeId::ConditionType.into()];
+    let mut events = Vec::new();
+    while let Some(type_id) = type_ids.pop() {
+        if let Some(references) = address_space.find_references_from(&type_id, Some((ReferenceTypeId::HasSubtype.into(), false))) {
+            type_ids.extend(references.into_iter().map(|r| r.node_id));
+        }
+        if let Some(references) = address_space.find_references_to(&type_id, Some((ReferenceTypeId::HasTypeDefinition.into(), false))) {
+            events.extend(references.iter()
+                .filter(|r| value(address_space, &r.node_id, &["Retain"]) == Some(Variant::Boolean(true)))
+                .filter_map(|r| condition_event(address_space, &r.node_id)));
+        }
+    }
+    events
+}
+
+/// Enables the condition and reports its state. Fails with `BadConditionAlreadyEnabled` if it
+/// is enabled.
+pub fn enable(address_space: &mut AddressSpace, condition_id: &NodeId) -> Result<(), StatusCode> {
+    expect_condition(address_space, condition_id)?;
+    if is_enabled(address_space, condition_id) {
+        return Err(StatusCode::BadConditionAlreadyEnabled);
+    }
+    let now = DateTime::now();
+    set_state(address_space, condition_id, "EnabledState", true, &now)?;
+    report(address_space, condition_id, &now)
+}
+
+/// Disables the condition and reports it as disabled, which is the last event that it reports
+/// until it is enabled. Fails with `BadConditionAlreadyDisabled` if it is disabled.
+pub fn disable(address_space: &mut AddressSpace, condition_id: &NodeId) -> Result<(), StatusCode> {
+    expect_condition(address_space, condition_id)?;
+    if !is_enabled(address_space, condition_id) {
+        return Err(StatusCode::BadConditionAlreadyDisabled);
+    }
+    let now = DateTime::now();
+    set_state(address_space, condition_id, "EnabledState", false, &now)?;
+    report(address_space, condition_id, &now)
+}
+
+/// Checks that the condition is enabled and the event id is that of its last event
+fn check_event_id(address_space: &AddressSpace, condition_id: &NodeId, event_id: &ByteString) -> Result<(), StatusCode> {
+    expect_condition(address_space, condition_id)?;
+    if !is_enabled(address_space, condition_id) {
+        Err(StatusCode::BadConditionDisabled)
+    } else if value(address_space, condition_id, &["EventId"]) != Some(Variant::from(event_id.clone())) {
+        error!("Event id {:?} is not the last event of condition {:?}", event_id, condition_id);
+        Err(StatusCode::BadEventIdUnknown)
+    } else {
+        Ok(())
+    }
+}
+
+/// Sets the comment of the condition and the user that set it, unless the comment is empty
+fn set_comment(address_space: &mut AddressSpace, condition_id: &NodeId, comment: &LocalizedText, client_user_id: &str, now: &DateTime) -> Result<(), StatusCode> {
+    set_value(address_space, condition_id, &["ClientUserId"], client_user_id, now)?;
+    if comment.text.is_null() || comment.text.as_ref().is_empty() {
+        Ok(())
+    } else {
+        set_condition_variable(address_space, condition_id, "Comment", comment.clone(), now)
+    }
+}
+
+/// Adds a comment to the last event of the condition, and reports the state of the condition.
+/// Fails with `BadConditionDisabled` if the condition is disabled or `BadEventIdUnknown` if the
+/// event id is not that of its last event.
+pub fn add_comment(address_space: &mut AddressSpace, condition_id: &NodeId, event_id: &ByteString, comment: &LocalizedText, client_user_id: &str) -> Result<(), StatusCode> {
+    check_event_id(address_space, condition_id, event_id)?;
+    let now = DateTime::now();
+    set_comment(address_space, condition_id, comment, client_user_id, &now)?;
+    report(address_space, condition_id, &now)
+}
+
+/// Acknowledges the last event of the condition with an optional comment, and reports its
+/// state. Fails like `add_comment`, with `BadConditionBranchAlreadyAcked` if it is acknowledged,
+/// or with `BadNotSupported` if the condition is not acknowledgeable.
+pub fn acknowledge(address_space: &mut AddressSpace, condition_id: &NodeId, event_id: &ByteString, comment: &LocalizedText, client_user_id: &str) -> Result<(), StatusCode> {
+    check_event_id(address_space, condition_id, event_id)?;
+    match state(address_space, condition_id, "AckedState") {
+        Some(true) => Err(StatusCode::BadConditionBranchAlreadyAcked),
+        Some(false) => {
+            let now = DateTime::now();
+            set_state(address_space, condition_id, "AckedState", true, &now)?;
+            set_comment(address_space, condition_id, comment, client_user_id, &now)?;
+            report(address_space, condition_id, &now)
+        }
+        None => Err(StatusCode::BadNotSupported)
+    }
+}
+
+/// Confirms the last event of the condition with an optional comment, and reports its state.
+/// Fails like `add_comment`, with `BadConditionBranchAlreadyConfirmed` if it is confirmed, or
+/// with `BadNotSupported` if the condition is not confirmable.
+pub fn confirm(address_space: &mut AddressSpace, condition_id: &NodeId, event_id: &ByteString, comment: &LocalizedText, client_user_id: &str) -> Result<(), StatusCode> {
+    check_event_id(address_space, condition_id, event_id)?;
+    match state(address_space, condition_id, "ConfirmedState") {
+        Some(true) => Err(StatusCode::BadConditionBranchAlreadyConfirmed),
+        Some(false) => {
+            let now = DateTime::now();
+            set_state(address_space, condition_id, "ConfirmedState", true, &now)?;
+            set_comment(address_space, condition_id, comment, client_user_id, &now)?;
+            report(address_space, condition_id, &now)
+        }
+        None => Err(StatusCode::BadNotSupported)
+    }
+}
+
+/// Sets the alarm active or inactive with the severity and message, and reports its state if it
+/// is enabled. An alarm becoming active must be acknowledged, and confirmed if it is
+/// confirmable. An alarm that stays active only changes its severity and message. Fails with
+/// `BadNotSupported` if the condition is not an alarm.
+pub fn set_active(address_space: &mut AddressSpace, condition_id: &NodeId, active: bool, severity: u16, message: &str) -> Result<(), StatusCode> {
+    expect_condition(address_space, condition_id)?;
+    let was_active = state(address_space, condition_id, "ActiveState").ok_or(StatusCode::BadNotSupported)?;
+    let now = DateTime::now();
+    if active && !was_active {
+        set_state(address_space, condition_id, "AckedState", false, &now)?;
+        if state(address_space, condition_id, "ConfirmedState").is_some() {
+            set_state(address_space, condition_id, "ConfirmedState", false, &now)?;
+        }
+    }
+    if active != was_active {
+        set_state(address_space, condition_id, "ActiveState", active, &now)?;
+    }
+    if let Some(Variant::UInt16(last_severity)) = value(address_space, condition_id, &["Severity"]) {
+        if last_severity != severity {
+            set_condition_variable(address_space, condition_id, "LastSeverity", last_severity, &now)?;
+            set_value(address_space, condition_id, &["Severity"], severity, &now)?;
+        }
+    }
+    set_value(address_space, condition_id, &["Message"], LocalizedText::new("", message), &now)?;
+    if is_enabled(address_space, condition_id) {
+        report(address_space, condition_id, &now)
+    } else {
+        update_retain(address_space, condition_id, &now);
+        Ok(())
+    }
+}
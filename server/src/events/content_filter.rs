@@ -0,0 +1,477 @@
+//! Validation and evaluation of content filters, i.e. the where clauses of event filters and
+//! the filters of queries. A filter is a list of elements where the first element is the root
+//! of the expression and the operands of an element may refer to elements after it.
+
+use std::{cmp::Ordering, collections::HashSet};
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ObjectId, ReferenceTypeId};
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::{
+    AttributeOperand, ContentFilter, ContentFilterElement, ContentFilterElementResult, ContentFilterResult,
+    ElementOperand, FilterOperator, LiteralOperand, NodeClass, SimpleAttributeOperand,
+};
+
+use crate::{
+    address_space::AddressSpace,
+    events::{event::Event, event_filter, operator},
+};
+
+/// Something that a content filter is evaluated against, an event or a node.
+pub trait FilterTarget {
+    /// Returns the node id of the target if it is a node in the address space
+    fn node_id(&self) -> Option<&NodeId>;
+
+    /// Returns the type definition of the target
+    fn type_definition_id(&self, address_space: &AddressSpace) -> Option<NodeId>;
+
+    /// Returns the value that the operand refers to in the target, or `None` if there is no such
+    /// value.
+    fn value(&self, address_space: &AddressSpace, operand: &SimpleAttributeOperand) -> Option<Variant>;
+}
+
+impl FilterTarget for Event {
+    fn node_id(&self) -> Option<&NodeId> {
+        self.condition_id.as_ref()
+    }
+
+    fn type_definition_id(&self, _: &AddressSpace) -> Option<NodeId> {
+        Some(self.event_type.clone())
+    }
+
+    fn value(&self, address_space: &AddressSpace, operand: &SimpleAttributeOperand) -> Option<Variant> {
+        event_filter::select_field(address_space, operand, self)
+    }
+}
+
+impl FilterTarget for NodeId {
+    fn node_id(&self) -> Option<&NodeId> {
+        Some(self)
+    }
+
+    fn type_definition_id(&self, address_space: &AddressSpace) -> Option<NodeId> {
+        address_space.get_type_id(self)
+    }
+
+    fn value(&self, address_space: &AddressSpace, operand: &SimpleAttributeOperand) -> Option<Variant> {
+        // The node must be an instance of the type or one of its subtypes
+        if !operand.type_definition_id.is_null() {
+            match self.type_definition_id(address_space) {
+                Some(ref type_id) if address_space.is_subtype(type_id, &operand.type_definition_id) => {}
+                _ => {
+                    return None;
+                }
+            }
+        }
+        // Follow the browse path down the hierarchy from the node
+        let mut node_id = self.clone();
+        if let Some(ref browse_path) = operand.browse_path {
+            for browse_name in browse_path {
+                let references = address_space.find_references_from(&node_id, Some((ReferenceTypeId::HierarchicalReferences.into(), true)))?;
+                node_id = references.into_iter()
+                    .map(|r| r.node_id)
+                    .find(|node_id| {
+                        address_space.find_node(node_id).map_or(false, |node| node.as_node().browse_name() == *browse_name)
+                    })?;
+            }
+        }
+        attribute_value(address_space, &node_id, operand.attribute_id)
+    }
+}
+
+/// Returns the value of an attribute of a node
+fn attribute_value(address_space: &AddressSpace, node_id: &NodeId, attribute_id: u32) -> Option<Variant> {
+    let attribute_id = AttributeId::from_u32(attribute_id).ok()?;
+    let node = address_space.find_node(node_id)?;
+    node.as_node().find_attribute(attribute_id).and_then(|data_value| data_value.value)
+}
+
+/// The operands of a filter element, decoded from their extension objects
+enum Operand {
+    Element(ElementOperand),
+    Literal(LiteralOperand),
+    Attribute(AttributeOperand),
+    SimpleAttribute(SimpleAttributeOperand),
+}
+
+impl Operand {
+    fn decode(operand: &ExtensionObject) -> Result<Operand, StatusCode> {
+        let decoding_limits = DecodingLimits::default();
+        let object_id = operand.node_id.as_object_id().map_err(|_| {
+            error!("Filter operand has an unrecognized type {:?}", operand.node_id);
+            StatusCode::BadFilterOperandInvalid
+        })?;
+        let operand = match object_id {
+            ObjectId::ElementOperand_Encoding_DefaultBinary => Operand::Element(operand.decode_inner::<ElementOperand>(&decoding_limits)?),
+            ObjectId::LiteralOperand_Encoding_DefaultBinary => Operand::Literal(operand.decode_inner::<LiteralOperand>(&decoding_limits)?),
+            ObjectId::AttributeOperand_Encoding_DefaultBinary => Operand::Attribute(operand.decode_inner::<AttributeOperand>(&decoding_limits)?),
+            ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary => Operand::SimpleAttribute(operand.decode_inner::<SimpleAttributeOperand>(&decoding_limits)?),
+            _ => {
+                error!("Filter operand has an unrecognized type {:?}", object_id);
+                return Err(StatusCode::BadFilterOperandInvalid);
+            }
+        };
+        Ok(operand)
+    }
+
+    /// Returns the value of a literal operand holding a node id
+    fn as_node_id(&self) -> Option<&NodeId> {
+        match self {
+            Operand::Literal(LiteralOperand { value: Variant::NodeId(ref node_id) }) => Some(node_id),
+            _ => None
+        }
+    }
+}
+
+/// Decodes the operands of a filter element. An element without operands has an empty list.
+fn decode_operands(element: &ContentFilterElement) -> Vec<Result<Operand, StatusCode>> {
+    match element.filter_operands {
+        Some(ref operands) => operands.iter().map(Operand::decode).collect(),
+        None => Vec::new()
+    }
+}
+
+/// Tests if the operator accepts the number of operands
+fn is_operand_count_valid(filter_operator: FilterOperator, operand_count: usize) -> bool {
+    match filter_operator {
+        FilterOperator::IsNull | FilterOperator::Not | FilterOperator::InView | FilterOperator::OfType => operand_count == 1,
+        FilterOperator::Between => operand_count == 3,
+        FilterOperator::InList => operand_count >= 2,
+        // Source, target, reference type and optionally the hops and whether subtypes of the
+        // types and the reference type are included
+        FilterOperator::RelatedTo => operand_count >= 3 && operand_count <= 6,
+        _ => operand_count == 2
+    }
+}
+
+/// Validates the operand of a filter element that refers to a field of an event or to an attribute
+/// of a node, given the address space
+type SimpleAttributeValidator = fn(&AddressSpace, &SimpleAttributeOperand) -> StatusCode;
+
+/// Validates a content filter that is evaluated against events, returning a result for each
+/// element. Fails with `BadContentFilterInvalid` and the results if any element is invalid. An
+/// empty filter is valid.
+pub fn validate(address_space: &AddressSpace, filter: &ContentFilter) -> Result<ContentFilterResult, (StatusCode, ContentFilterResult)> {
+    validate_filter(address_space, filter, event_filter::validate_select_clause)
+}
+
+/// Validates a content filter that is evaluated against nodes, e.g. the filter of a query, whose
+/// simple attribute operands refer to the attributes of a node or of the nodes beneath it.
+/// Otherwise like [`validate`].
+///
+/// [`validate`]: ./fn.validate.html
+pub fn validate_node_filter(address_space: &AddressSpace, filter: &ContentFilter) -> Result<ContentFilterResult, (StatusCode, ContentFilterResult)> {
+    validate_filter(address_space, filter, validate_node_operand)
+}
+
+/// Validates a simple attribute operand that refers to an attribute of a node, whose type if
+/// it has one must be an object type or variable type
+fn validate_node_operand(address_space: &AddressSpace, operand: &SimpleAttributeOperand) -> StatusCode {
+    let is_type_valid = operand.type_definition_id.is_null() || match address_space.find_node(&operand.type_definition_id) {
+        Some(node) => {
+            let node_class = node.as_node().node_class();
+            node_class == NodeClass::ObjectType || node_class == NodeClass::VariableType
+        }
+        None => false
+    };
+    if !is_type_valid {
+        StatusCode::BadTypeDefinitionInvalid
+    } else if !operand.index_range.is_null() {
+        // Index ranges are not supported
+        StatusCode::BadIndexRangeInvalid
+    } else if AttributeId::from_u32(operand.attribute_id).is_err() {
+        StatusCode::BadAttributeIdInvalid
+    } else {
+        StatusCode::Good
+    }
+}
+
+fn validate_filter(address_space: &AddressSpace, filter: &ContentFilter, validate_simple_attribute: SimpleAttributeValidator) -> Result<ContentFilterResult, (StatusCode, ContentFilterResult)> {
+    let elements = match filter.elements {
+        Some(ref elements) if !elements.is_empty() => elements,
+        _ => {
+            return Ok(ContentFilterResult {
+                element_results: None,
+                element_diagnostic_infos: None,
+            });
+        }
+    };
+    let element_results = elements.iter().enumerate()
+        .map(|(index, element)| validate_element(address_space, elements.len(), index, element, validate_simple_attribute))
+        .collect::<Vec<ContentFilterElementResult>>();
+    let is_valid = element_results.iter().all(|result| result.status_code.is_good());
+    let result = ContentFilterResult {
+        element_results: Some(element_results),
+        element_diagnostic_infos: None,
+    };
+    if is_valid {
+        Ok(result)
+    } else {
+        error!("Content filter is invalid {:?}", result);
+        Err((StatusCode::BadContentFilterInvalid, result))
+    }
+}
+
+/// Validates a filter element and its operands
+fn validate_element(address_space: &AddressSpace, element_count: usize, index: usize, element: &ContentFilterElement, validate_simple_attribute: SimpleAttributeValidator) -> ContentFilterElementResult {
+    let operands = decode_operands(element);
+    let operand_status_codes = operands.iter().enumerate().map(|(i, operand)| {
+        match operand {
+            Ok(operand) => validate_operand(address_space, element_count, index, element.filter_operator, i, operand, validate_simple_attribute),
+            Err(status_code) => *status_code,
+        }
+    }).collect::<Vec<StatusCode>>();
+    let status_code = if !is_operand_count_valid(element.filter_operator, operands.len()) {
+        StatusCode::BadFilterOperandCountMismatch
+    } else if operand_status_codes.iter().any(|status_code| status_code.is_bad()) {
+        StatusCode::BadFilterOperandInvalid
+    } else {
+        StatusCode::Good
+    };
+    ContentFilterElementResult {
+        status_code,
+        operand_status_codes: Some(operand_status_codes),
+        operand_diagnostic_infos: None,
+    }
+}
+
+/// Validates an operand of a filter element, checking that it is the kind of operand that the
+/// operator needs in its position
+fn validate_operand(address_space: &AddressSpace, element_count: usize, index: usize, filter_operator: FilterOperator, operand_index: usize, operand: &Operand, validate_simple_attribute: SimpleAttributeValidator) -> StatusCode {
+    // Operators whose operands must be literal node ids of a type, view or reference type
+    let needs_node_id = match filter_operator {
+        FilterOperator::InView | FilterOperator::OfType => true,
+        FilterOperator::Cast => operand_index == 1,
+        FilterOperator::RelatedTo => operand_index <= 2,
+        _ => false
+    };
+    if needs_node_id {
+        return match operand.as_node_id() {
+            Some(node_id) if address_space.node_exists(node_id) => StatusCode::Good,
+            Some(_) => StatusCode::BadNodeIdUnknown,
+            None => StatusCode::BadFilterOperandInvalid,
+        };
+    }
+    match operand {
+        Operand::Element(ref operand) => {
+            // An element may only refer to elements after it, which prevents loops
+            let operand_index = operand.index as usize;
+            if operand_index > index && operand_index < element_count {
+                StatusCode::Good
+            } else {
+                error!("Element {} refers to element {} which is not after it", index, operand.index);
+                StatusCode::BadFilterOperandInvalid
+            }
+        }
+        Operand::Literal(_) => StatusCode::Good,
+        Operand::SimpleAttribute(ref operand) => validate_simple_attribute(address_space, operand),
+        Operand::Attribute(ref operand) => {
+            if !address_space.node_exists(&operand.node_id) {
+                StatusCode::BadNodeIdUnknown
+            } else if AttributeId::from_u32(operand.attribute_id).is_err() {
+                StatusCode::BadAttributeIdInvalid
+            } else if !operand.index_range.is_null() {
+                // Index ranges are not supported
+                StatusCode::BadIndexRangeInvalid
+            } else {
+                StatusCode::Good
+            }
+        }
+    }
+}
+
+/// Evaluates a content filter against the target, returning true if the target passes the
+/// filter. An empty filter passes everything, and a filter whose result is null or not a
+/// boolean passes nothing.
+pub fn evaluate(address_space: &AddressSpace, filter: &ContentFilter, target: &dyn FilterTarget) -> bool {
+    match filter.elements {
+        Some(ref elements) if !elements.is_empty() => {
+            let evaluator = Evaluator { address_space, elements, target };
+            evaluator.evaluate_element(0) == Variant::Boolean(true)
+        }
+        _ => true
+    }
+}
+
+struct Evaluator<'a> {
+    address_space: &'a AddressSpace,
+    elements: &'a [ContentFilterElement],
+    target: &'a dyn FilterTarget,
+}
+
+impl<'a> Evaluator<'a> {
+    /// Evaluates an element, yielding an empty variant for a null result
+    fn evaluate_element(&self, index: usize) -> Variant {
+        let element = &self.elements[index];
+        let operands = match decode_operands(element).into_iter().collect::<Result<Vec<Operand>, StatusCode>>() {
+            Ok(operands) => operands,
+            Err(_) => {
+                return Variant::Empty;
+            }
+        };
+        if !is_operand_count_valid(element.filter_operator, operands.len()) {
+            return Variant::Empty;
+        }
+        let value = |i: usize| self.operand_value(index, &operands[i]);
+        match element.filter_operator {
+            FilterOperator::Equals => operator::equals(&value(0), &value(1)),
+            FilterOperator::IsNull => Variant::Boolean(value(0) == Variant::Empty),
+            FilterOperator::GreaterThan => operator::compare_with(&value(0), &value(1), |o| o == Ordering::Greater),
+            FilterOperator::LessThan => operator::compare_with(&value(0), &value(1), |o| o == Ordering::Less),
+            FilterOperator::GreaterThanOrEqual => operator::compare_with(&value(0), &value(1), |o| o != Ordering::Less),
+            FilterOperator::LessThanOrEqual => operator::compare_with(&value(0), &value(1), |o| o != Ordering::Greater),
+            FilterOperator::Like => operator::like(&value(0), &value(1)),
+            FilterOperator::Not => operator::not(&value(0)),
+            FilterOperator::Between => {
+                let v = value(0);
+                operator::and(&operator::compare_with(&v, &value(1), |o| o != Ordering::Less), &operator::compare_with(&v, &value(2), |o| o != Ordering::Greater))
+            }
+            FilterOperator::InList => {
+                let v = value(0);
+                (1..operands.len()).fold(Variant::Boolean(false), |result, i| operator::or(&result, &operator::equals(&v, &value(i))))
+            }
+            FilterOperator::And => operator::and(&value(0), &value(1)),
+            FilterOperator::Or => operator::or(&value(0), &value(1)),
+            FilterOperator::Cast => {
+                match operands[1].as_node_id() {
+                    Some(data_type_id) => operator::cast(&value(0), data_type_id),
+                    None => Variant::Empty
+                }
+            }
+            FilterOperator::InView => {
+                match (self.target.node_id(), operands[0].as_node_id()) {
+                    (Some(node_id), Some(view_id)) => Variant::Boolean(self.is_in_view(node_id, view_id)),
+                    _ => Variant::Empty
+                }
+            }
+            FilterOperator::OfType => {
+                match (self.target.type_definition_id(self.address_space), operands[0].as_node_id()) {
+                    (Some(type_id), Some(base_type_id)) => Variant::Boolean(self.address_space.is_subtype(&type_id, base_type_id)),
+                    (None, Some(_)) => Variant::Boolean(false),
+                    _ => Variant::Empty
+                }
+            }
+            FilterOperator::RelatedTo => self.related_to(index, &operands),
+            FilterOperator::BitwiseAnd => operator::bitwise(&value(0), &value(1), |lhs, rhs| lhs & rhs),
+            FilterOperator::BitwiseOr => operator::bitwise(&value(0), &value(1), |lhs, rhs| lhs | rhs),
+        }
+    }
+
+    /// Returns the value of an operand of an element
+    fn operand_value(&self, index: usize, operand: &Operand) -> Variant {
+        match operand {
+            Operand::Element(ref operand) => {
+                let operand_index = operand.index as usize;
+                if operand_index > index && operand_index < self.elements.len() {
+                    self.evaluate_element(operand_index)
+                } else {
+                    Variant::Empty
+                }
+            }
+            Operand::Literal(ref operand) => operand.value.clone(),
+            Operand::SimpleAttribute(ref operand) => self.target.value(self.address_space, operand).unwrap_or(Variant::Empty),
+            Operand::Attribute(ref operand) => {
+                let has_browse_path = operand.browse_path.elements.as_ref().map_or(false, |elements| !elements.is_empty());
+                let node_id = if has_browse_path {
+                    match self.address_space.find_nodes_relative_path(&operand.node_id, &operand.browse_path) {
+                        Ok(ref node_ids) if !node_ids.is_empty() => node_ids[0].clone(),
+                        _ => {
+                            return Variant::Empty;
+                        }
+                    }
+                } else {
+                    operand.node_id.clone()
+                };
+                attribute_value(self.address_space, &node_id, operand.attribute_id).unwrap_or(Variant::Empty)
+            }
+        }
+    }
+
+    /// Tests if the node is in the view, i.e. it can be reached from the view through
+    /// hierarchical references
+    fn is_in_view(&self, node_id: &NodeId, view_id: &NodeId) -> bool {
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![view_id.clone()];
+        while let Some(visit_id) = to_visit.pop() {
+            if let Some(references) = self.address_space.find_references_from(&visit_id, Some((ReferenceTypeId::HierarchicalReferences.into(), true))) {
+                for reference in references {
+                    if reference.node_id == *node_id {
+                        return true;
+                    }
+                    if !visited.contains(&reference.node_id) {
+                        to_visit.push(reference.node_id.clone());
+                    }
+                }
+            }
+            visited.insert(visit_id);
+        }
+        false
+    }
+
+    /// Tests if the target is of the source type and is related to a node of the target type by
+    /// references of the reference type, within the number of hops.
+    fn related_to(&self, index: usize, operands: &[Operand]) -> Variant {
+        let node_id = match self.target.node_id() {
+            Some(node_id) => node_id,
+            None => {
+                return Variant::Empty;
+            }
+        };
+        let (source_type_id, target_type_id, reference_type_id) = match (operands[0].as_node_id(), operands[1].as_node_id(), operands[2].as_node_id()) {
+            (Some(source_type_id), Some(target_type_id), Some(reference_type_id)) => (source_type_id, target_type_id, reference_type_id),
+            _ => {
+                return Variant::Empty;
+            }
+        };
+        if !self.address_space.is_reference_type(reference_type_id) {
+            return Variant::Empty;
+        }
+        let optional_operand = |i: usize| operands.get(i).map(|operand| self.operand_value(index, operand));
+        let hops = match optional_operand(3) {
+            Some(Variant::Empty) | None => 1,
+            Some(ref hops) => {
+                match operator::cast(hops, &DataTypeId::UInt32.into()) {
+                    Variant::UInt32(hops) => hops,
+                    _ => {
+                        return Variant::Empty;
+                    }
+                }
+            }
+        };
+        let include_type_subtypes = optional_operand(4).map_or(true, |v| v != Variant::Boolean(false));
+        let include_reference_subtypes = optional_operand(5).map_or(true, |v| v != Variant::Boolean(false));
+
+        let is_of_type = |node_id: &NodeId, type_id: &NodeId| {
+            match self.address_space.get_type_id(node_id) {
+                Some(ref node_type_id) if include_type_subtypes => self.address_space.is_subtype(node_type_id, type_id),
+                Some(ref node_type_id) => node_type_id == type_id,
+                // Types are related to their own type
+                None => node_id == type_id || (include_type_subtypes && self.address_space.is_subtype(node_id, type_id))
+            }
+        };
+        if !is_of_type(node_id, source_type_id) {
+            return Variant::Boolean(false);
+        }
+
+        // Follow the references out to the number of hops, looking for a node of the target type
+        let mut visited = HashSet::new();
+        let mut nodes = vec![node_id.clone()];
+        for _ in 0..hops {
+            let mut next_nodes = Vec::new();
+            for node_id in &nodes {
+                if let Some(references) = self.address_space.find_references_from(node_id, Some((reference_type_id.clone(), include_reference_subtypes))) {
+                    for reference in references {
+                        if is_of_type(&reference.node_id, target_type_id) {
+                            return Variant::Boolean(true);
+                        }
+                        if visited.insert(reference.node_id.clone()) {
+                            next_nodes.push(reference.node_id);
+                        }
+                    }
+                }
+            }
+            nodes = next_nodes;
+        }
+        Variant::Boolean(false)
+    }
+}
@@ -0,0 +1,88 @@
+use opcua_types::*;
+
+/// An event of BaseEventType or one of its subtypes. The properties of BaseEventType are fields
+/// of the struct, and the properties that a subtype adds are held by their browse path from the
+/// event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// Generated by the server to uniquely identify the event
+    pub event_id: ByteString,
+    /// The type of the event, BaseEventType or one of its subtypes
+    pub event_type: NodeId,
+    /// The node that the event originated from
+    pub source_node: NodeId,
+    /// Description of the source of the event, normally the display name of the source node
+    pub source_name: UAString,
+    /// The time that the event occurred
+    pub time: DateTime,
+    /// The time that the server received the event
+    pub receive_time: DateTime,
+    /// Human readable description of the event
+    pub message: LocalizedText,
+    /// Urgency of the event, from 1 (lowest) to 1000 (highest)
+    pub severity: u16,
+    /// The condition whose state the event reports, if it is a condition event
+    pub condition_id: Option<NodeId>,
+    /// Properties added by the event's subtype, by their browse path from the event
+    fields: Vec<(Vec<QualifiedName>, Variant)>,
+}
+
+impl Event {
+    /// Creates an event of the type with a new event id, occurring now.
+    pub fn new<T>(event_type: T, source_node: &NodeId, source_name: &str, message: &str, severity: u16) -> Event where T: Into<NodeId> {
+        let now = DateTime::now();
+        Event {
+            event_id: ByteString::random(16),
+            event_type: event_type.into(),
+            source_node: source_node.clone(),
+            source_name: UAString::from(source_name),
+            time: now.clone(),
+            receive_time: now,
+            message: LocalizedText::new("", message),
+            severity,
+            condition_id: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Sets the value of a property that the event's subtype adds, by its browse path from the
+    /// event.
+    pub fn set_field<V>(&mut self, browse_path: &[QualifiedName], value: V) where V: Into<Variant> {
+        let value = value.into();
+        if let Some(field) = self.fields.iter_mut().find(|(p, _)| p.as_slice() == browse_path) {
+            field.1 = value;
+        } else {
+            self.fields.push((browse_path.to_vec(), value));
+        }
+    }
+
+    /// Sets the value of a property that the event's subtype adds, where the property is in
+    /// namespace 0 directly beneath the event, e.g. "ConditionName".
+    pub fn set_property<V>(&mut self, name: &str, value: V) where V: Into<Variant> {
+        self.set_field(&[QualifiedName::new(0, name)], value);
+    }
+
+    /// Returns the value of the property at the browse path from the event, or `None` if the
+    /// event has no such property.
+    pub fn field(&self, browse_path: &[QualifiedName]) -> Option<Variant> {
+        if browse_path.len() == 1 && browse_path[0].namespace_index == 0 {
+            let value = match browse_path[0].name.as_ref() {
+                "EventId" => Some(Variant::from(self.event_id.clone())),
+                "EventType" => Some(Variant::from(self.event_type.clone())),
+                "SourceNode" => Some(Variant::from(self.source_node.clone())),
+                "SourceName" => Some(Variant::from(self.source_name.clone())),
+                "Time" => Some(Variant::from(self.time.clone())),
+                "ReceiveTime" => Some(Variant::from(self.receive_time.clone())),
+                "Message" => Some(Variant::from(self.message.clone())),
+                "Severity" => Some(Variant::from(self.severity)),
+                _ => None
+            };
+            if value.is_some() {
+                return value;
+            }
+        }
+        self.fields.iter()
+            .find(|(p, _)| p.as_slice() == browse_path)
+            .map(|(_, value)| value.clone())
+    }
+}
@@ -0,0 +1,107 @@
+use opcua_types::*;
+use opcua_types::node_ids::ObjectTypeId;
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::{ContentFilterResult, EventFilter, EventFilterResult, SimpleAttributeOperand};
+
+use crate::{
+    address_space::AddressSpace,
+    events::{content_filter, event::Event},
+};
+
+/// Validates the event filter of a monitored item. The result holds a status for each select
+/// clause and the result of validating the where clause. Fails with `BadEventFilterInvalid` if
+/// the filter has no select clauses, or with the result if the where clause is invalid.
+pub fn validate(address_space: &AddressSpace, filter: &EventFilter) -> Result<EventFilterResult, (StatusCode, EventFilterResult)> {
+    let select_clauses = match filter.select_clauses {
+        Some(ref select_clauses) if !select_clauses.is_empty() => select_clauses,
+        _ => {
+            error!("Event filter has no select clauses");
+            return Err((StatusCode::BadEventFilterInvalid, EventFilterResult {
+                select_clause_results: None,
+                select_clause_diagnostic_infos: None,
+                where_clause_result: ContentFilterResult {
+                    element_results: None,
+                    element_diagnostic_infos: None,
+                },
+            }));
+        }
+    };
+    let select_clause_results = select_clauses.iter()
+        .map(|select_clause| validate_select_clause(address_space, select_clause))
+        .collect();
+    let (status_code, where_clause_result) = match content_filter::validate(address_space, &filter.where_clause) {
+        Ok(where_clause_result) => (StatusCode::Good, where_clause_result),
+        Err((status_code, where_clause_result)) => (status_code, where_clause_result),
+    };
+    let result = EventFilterResult {
+        select_clause_results: Some(select_clause_results),
+        select_clause_diagnostic_infos: None,
+        where_clause_result,
+    };
+    if status_code.is_good() {
+        Ok(result)
+    } else {
+        Err((status_code, result))
+    }
+}
+
+/// Tests if the event passes the where clause of the filter
+pub fn matches(address_space: &AddressSpace, filter: &EventFilter, event: &Event) -> bool {
+    content_filter::evaluate(address_space, &filter.where_clause, event)
+}
+
+/// Validates a select clause, returning a good status if it is valid
+pub(crate) fn validate_select_clause(address_space: &AddressSpace, select_clause: &SimpleAttributeOperand) -> StatusCode {
+    let has_browse_path = select_clause.browse_path.as_ref().map_or(false, |browse_path| !browse_path.is_empty());
+    if !select_clause.type_definition_id.is_null() && !address_space.is_subtype(&select_clause.type_definition_id, &ObjectTypeId::BaseEventType.into()) {
+        StatusCode::BadTypeDefinitionInvalid
+    } else if !select_clause.index_range.is_null() {
+        // Index ranges are not supported
+        StatusCode::BadIndexRangeInvalid
+    } else {
+        match AttributeId::from_u32(select_clause.attribute_id) {
+            Ok(AttributeId::Value) if has_browse_path => StatusCode::Good,
+            // The node id of the event itself, which only conditions have
+            Ok(AttributeId::NodeId) if !has_browse_path => StatusCode::Good,
+            Ok(_) if !has_browse_path => StatusCode::BadBrowseNameInvalid,
+            Ok(_) => StatusCode::Good,
+            Err(_) => StatusCode::BadAttributeIdInvalid,
+        }
+    }
+}
+
+/// Returns the values that the select clauses of the filter select from the event. A clause
+/// that does not select a field of the event yields an empty value.
+pub fn select_fields(address_space: &AddressSpace, filter: &EventFilter, event: &Event) -> Vec<Variant> {
+    if let Some(ref select_clauses) = filter.select_clauses {
+        select_clauses.iter()
+            .map(|select_clause| select_field(address_space, select_clause, event).unwrap_or(Variant::Empty))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Returns the value of the event field that the operand refers to, if there is one
+pub(crate) fn select_field(address_space: &AddressSpace, select_clause: &SimpleAttributeOperand, event: &Event) -> Option<Variant> {
+    // The field must belong to the type of the event or one of its supertypes
+    if !select_clause.type_definition_id.is_null() && !address_space.is_subtype(&event.event_type, &select_clause.type_definition_id) {
+        return None;
+    }
+    match select_clause.browse_path {
+        Some(ref browse_path) if !browse_path.is_empty() => {
+            if select_clause.attribute_id == AttributeId::Value as u32 {
+                event.field(browse_path)
+            } else {
+                None
+            }
+        }
+        _ => {
+            if select_clause.attribute_id == AttributeId::NodeId as u32 {
+                event.condition_id.clone().map(Variant::from)
+            } else {
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Limit alarms of OPC UA Part 9 that are bound to a variable, their input node. The server
+//! evaluates the alarm whenever the value of the variable is set through
+//! [`AddressSpace::set_variable_value`] or written by a client, activating and deactivating the
+//! alarm and changing its severity as the value crosses the limits.
+//!
+//! An exclusive limit alarm is in at most one of the HighHigh, High, Low and LowLow states, which
+//! its LimitState holds. A non-exclusive limit alarm has a two state variable for each limit, and
+//! more than one can be active, e.g. the HighState and HighHighState of a value above both limits.
+//!
+//! [`AddressSpace::set_variable_value`]: ../../address_space/address_space/struct.AddressSpace.html#method.set_variable_value
+
+use opcua_types::*;
+use opcua_types::node_ids::{DataTypeId, ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId};
+use opcua_types::status_code::StatusCode;
+
+use crate::{
+    address_space::{
+        address_space::{AddressSpace, ReferenceDirection},
+        node::NodeType,
+        object::Object,
+    },
+    events::condition,
+};
+
+/// The kind of a limit alarm, i.e. whether its limit states exclude each other
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum LimitAlarmKind {
+    /// An alarm of ExclusiveLimitAlarmType
+    Exclusive,
+    /// An alarm of NonExclusiveLimitAlarmType
+    NonExclusive,
+}
+
+/// The limits of a limit alarm, from the highest to the lowest
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Limit {
+    HighHigh,
+    High,
+    Low,
+    LowLow,
+}
+
+impl Limit {
+    fn name(&self) -> &'static str {
+        match *self {
+            Limit::HighHigh => "HighHigh",
+            Limit::High => "High",
+            Limit::Low => "Low",
+            Limit::LowLow => "LowLow",
+        }
+    }
+
+    fn is_high(&self) -> bool {
+        *self == Limit::HighHigh || *self == Limit::High
+    }
+
+    /// The state of the ExclusiveLimitStateMachineType for the limit
+    fn state_id(&self) -> NodeId {
+        match *self {
+            Limit::HighHigh => ObjectId::ExclusiveLimitStateMachineType_HighHigh.into(),
+            Limit::High => ObjectId::ExclusiveLimitStateMachineType_High.into(),
+            Limit::Low => ObjectId::ExclusiveLimitStateMachineType_Low.into(),
+            Limit::LowLow => ObjectId::ExclusiveLimitStateMachineType_LowLow.into(),
+        }
+    }
+}
+
+/// Which of the limits are exceeded, in the order of `LIMITS`
+type LimitsExceeded = [bool; 4];
+
+const LIMITS: [Limit; 4] = [Limit::HighHigh, Limit::High, Limit::Low, Limit::LowLow];
+
+/// The definition of a limit alarm on a variable. Each limit that is set is checked against the
+/// value of the variable, and a limit that is exceeded sets the severity of the alarm. A high limit
+/// is exceeded by a value that is greater than or equal to it, a low limit by a value that is less
+/// than or equal to it. Once a limit is exceeded, the value must return inside it by the deadband
+/// for it to no longer be exceeded, so that a value that hovers around a limit does not flood
+/// clients with events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitAlarm {
+    kind: LimitAlarmKind,
+    /// The limits and the severity of the alarm when they are exceeded, in the order of `LIMITS`
+    limits: [Option<(f64, u16)>; 4],
+    deadband: f64,
+}
+
+impl LimitAlarm {
+    /// Creates the definition of an exclusive limit alarm with no limits
+    pub fn exclusive() -> LimitAlarm {
+        Self::new(LimitAlarmKind::Exclusive)
+    }
+
+    /// Creates the definition of a non-exclusive limit alarm with no limits
+    pub fn non_exclusive() -> LimitAlarm {
+        Self::new(LimitAlarmKind::NonExclusive)
+    }
+
+    fn new(kind: LimitAlarmKind) -> LimitAlarm {
+        LimitAlarm {
+            kind,
+            limits: [None; 4],
+            deadband: 0f64,
+        }
+    }
+
+    fn limit(mut self, limit: Limit, value: f64, severity: u16) -> Self {
+        self.limits[limit as usize] = Some((value, severity));
+        self
+    }
+
+    /// Sets the HighHigh limit and the severity of the alarm when it is exceeded
+    pub fn high_high(self, limit: f64, severity: u16) -> Self {
+        self.limit(Limit::HighHigh, limit, severity)
+    }
+
+    /// Sets the High limit and the severity of the alarm when it is exceeded
+    pub fn high(self, limit: f64, severity: u16) -> Self {
+        self.limit(Limit::High, limit, severity)
+    }
+
+    /// Sets the Low limit and the severity of the alarm when it is exceeded
+    pub fn low(self, limit: f64, severity: u16) -> Self {
+        self.limit(Limit::Low, limit, severity)
+    }
+
+    /// Sets the LowLow limit and the severity of the alarm when it is exceeded
+    pub fn low_low(self, limit: f64, severity: u16) -> Self {
+        self.limit(Limit::LowLow, limit, severity)
+    }
+
+    /// Sets the deadband, the amount by which a value must return inside an exceeded limit for
+    /// the limit to no longer be exceeded
+    pub fn deadband(mut self, deadband: f64) -> Self {
+        self.deadband = deadband.abs();
+        self
+    }
+
+    /// Tests if the limits are in order, i.e. each limit that is set is greater than the limits
+    /// below it
+    fn is_valid(&self) -> bool {
+        let values = self.limits.iter().filter_map(|limit| limit.map(|(value, _)| value)).collect::<Vec<f64>>();
+        values.iter().all(|value| value.is_finite()) && values.windows(2).all(|pair| pair[0] > pair[1])
+    }
+
+    /// Returns which limits the value exceeds, given which limits were exceeded before
+    fn limits_exceeded(&self, value: f64, previous: &LimitsExceeded) -> LimitsExceeded {
+        let mut exceeded = [false; 4];
+        for (i, limit) in LIMITS.iter().enumerate() {
+            if let Some((limit_value, _)) = self.limits[i] {
+                exceeded[i] = if limit.is_high() {
+                    value >= limit_value || (previous[i] && value > limit_value - self.deadband)
+                } else {
+                    value <= limit_value || (previous[i] && value < limit_value + self.deadband)
+                };
+            }
+        }
+        exceeded
+    }
+
+    /// Returns the limit that an exclusive alarm is in when the limits are exceeded, which is
+    /// the most extreme of them
+    fn exclusive_limit(exceeded: &LimitsExceeded) -> Option<Limit> {
+        [Limit::HighHigh, Limit::High, Limit::LowLow, Limit::Low].iter()
+            .find(|limit| exceeded[**limit as usize])
+            .cloned()
+    }
+
+    /// Returns the severity of the alarm when the limits are exceeded, which is the severity of
+    /// the limit an exclusive alarm is in, or the highest severity of the limits exceeded by a
+    /// non-exclusive alarm
+    fn severity(&self, exceeded: &LimitsExceeded) -> Option<u16> {
+        match self.kind {
+            LimitAlarmKind::Exclusive => {
+                Self::exclusive_limit(exceeded).and_then(|limit| self.limits[limit as usize].map(|(_, severity)| severity))
+            }
+            LimitAlarmKind::NonExclusive => {
+                (0..4).filter(|i| exceeded[*i])
+                    .filter_map(|i| self.limits[i].map(|(_, severity)| severity))
+                    .max()
+            }
+        }
+    }
+}
+
+/// Adds a limit alarm for the input node, which must be a variable, and binds it to the variable
+/// so it is evaluated as the value changes. The alarm is a condition of ExclusiveLimitAlarmType
+/// or NonExclusiveLimitAlarmType whose source is the variable, with a property for each limit
+/// and the state of the limits. The alarm is evaluated against the current value at once.
+///
+/// Fails with `BadNodeIdUnknown` if the input node does not exist, `BadNodeClassInvalid` if it is
+/// not a variable, `BadOutOfRange` if the limits are not in order, or like
+/// [`condition::add_condition`].
+///
+/// [`condition::add_condition`]: ../condition/fn.add_condition.html
+pub fn add_limit_alarm(address_space: &mut AddressSpace, node_id: &NodeId, browse_name: &str, input_node_id: &NodeId, limit_alarm: LimitAlarm) -> Result<(), StatusCode> {
+    match address_space.find_node(input_node_id) {
+        Some(NodeType::Variable(_)) => {}
+        Some(_) => {
+            error!("Cannot add limit alarm to input node {:?} which is not a variable", input_node_id);
+            return Err(StatusCode::BadNodeClassInvalid);
+        }
+        None => {
+            error!("Cannot add limit alarm to input node {:?} which does not exist", input_node_id);
+            return Err(StatusCode::BadNodeIdUnknown);
+        }
+    }
+    if !limit_alarm.is_valid() {
+        error!("Limits of limit alarm {:?} are not in order", node_id);
+        return Err(StatusCode::BadOutOfRange);
+    }
+    let condition_type_id = match limit_alarm.kind {
+        LimitAlarmKind::Exclusive => ObjectTypeId::ExclusiveLimitAlarmType,
+        LimitAlarmKind::NonExclusive => ObjectTypeId::NonExclusiveLimitAlarmType,
+    };
+    condition::add_condition(address_space, node_id, browse_name, condition_type_id, input_node_id, false)?;
+
+    let now = DateTime::now();
+    condition::set_value(address_space, node_id, &["InputNode"], input_node_id.clone(), &now)?;
+    for (i, limit) in LIMITS.iter().enumerate() {
+        if let Some((value, _)) = limit_alarm.limits[i] {
+            condition::add_property(address_space, node_id, &format!("{}Limit", limit.name()), DataTypeId::Double, value);
+        }
+    }
+    match limit_alarm.kind {
+        LimitAlarmKind::Exclusive => {
+            let limit_state_id = NodeId::new(node_id.namespace, format!("{}.LimitState", node_id.identifier));
+            address_space.insert(Object::new(&limit_state_id, "LimitState", "LimitState", ""), Some(&[
+                (node_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+                (&ObjectTypeId::ExclusiveLimitStateMachineType.into(), ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+            ]));
+            let current_state_id = condition::add_variable(address_space, &limit_state_id, "CurrentState", ReferenceTypeId::HasComponent, VariableTypeId::FiniteStateVariableType,
+                                                           DataTypeId::LocalizedText, LocalizedText::new("", ""));
+            condition::add_property(address_space, &current_state_id, "Id", DataTypeId::NodeId, NodeId::null());
+        }
+        LimitAlarmKind::NonExclusive => {
+            for (i, limit) in LIMITS.iter().enumerate() {
+                if limit_alarm.limits[i].is_some() {
+                    condition::add_two_state_variable(address_space, node_id, &format!("{}State", limit.name()), false);
+                }
+            }
+        }
+    }
+
+    address_space.bind_limit_alarm(input_node_id, node_id, limit_alarm);
+    address_space.evaluate_limit_alarms(input_node_id);
+    Ok(())
+}
+
+/// Returns which limits of the alarm were exceeded, from its limit states
+fn previous_limits_exceeded(address_space: &AddressSpace, node_id: &NodeId, limit_alarm: &LimitAlarm) -> LimitsExceeded {
+    let mut exceeded = [false; 4];
+    match limit_alarm.kind {
+        LimitAlarmKind::Exclusive => {
+            if let Some(Variant::NodeId(state_id)) = condition::value(address_space, node_id, &["LimitState", "CurrentState", "Id"]) {
+                // A HighHigh or LowLow limit is beyond the High or Low limit
+                if let Some(limit) = LIMITS.iter().find(|limit| limit.state_id() == *state_id) {
+                    exceeded[*limit as usize] = true;
+                    match *limit {
+                        Limit::HighHigh => exceeded[Limit::High as usize] = true,
+                        Limit::LowLow => exceeded[Limit::Low as usize] = true,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        LimitAlarmKind::NonExclusive => {
+            for (i, limit) in LIMITS.iter().enumerate() {
+                exceeded[i] = condition::state(address_space, node_id, &format!("{}State", limit.name())).unwrap_or(false);
+            }
+        }
+    }
+    exceeded
+}
+
+/// Evaluates the limit alarm against the value of its input node, changing the state of the
+/// alarm if the limits that the value exceeds have changed. A value that is not numeric or is
+/// not good leaves the alarm as it is.
+pub(crate) fn evaluate(address_space: &mut AddressSpace, node_id: &NodeId, input_node_id: &NodeId, limit_alarm: &LimitAlarm) -> Result<(), StatusCode> {
+    let value = match address_space.get_variable_value(input_node_id.clone()) {
+        Ok(ref data_value) if data_value.is_valid() => data_value.value.as_ref().and_then(|value| value.as_f64()),
+        _ => None
+    };
+    let value = match value {
+        Some(value) if !value.is_nan() => value,
+        _ => {
+            return Ok(());
+        }
+    };
+    let previous = previous_limits_exceeded(address_space, node_id, limit_alarm);
+    let exceeded = limit_alarm.limits_exceeded(value, &previous);
+    if exceeded == previous {
+        return Ok(());
+    }
+
+    let now = DateTime::now();
+    let limit = LimitAlarm::exclusive_limit(&exceeded);
+    match limit_alarm.kind {
+        LimitAlarmKind::Exclusive => {
+            let (state_name, state_id) = match limit {
+                Some(limit) => (limit.name(), limit.state_id()),
+                None => ("", NodeId::null()),
+            };
+            condition::set_value(address_space, node_id, &["LimitState", "CurrentState"], LocalizedText::new("", state_name), &now)?;
+            condition::set_value(address_space, node_id, &["LimitState", "CurrentState", "Id"], state_id, &now)?;
+        }
+        LimitAlarmKind::NonExclusive => {
+            for (i, limit) in LIMITS.iter().enumerate() {
+                if limit_alarm.limits[i].is_some() && exceeded[i] != previous[i] {
+                    condition::set_state(address_space, node_id, &format!("{}State", limit.name()), exceeded[i], &now)?;
+                }
+            }
+        }
+    }
+
+    match (limit, limit_alarm.severity(&exceeded)) {
+        (Some(limit), Some(severity)) => {
+            let message = format!("{} limit exceeded", limit.name());
+            condition::set_active(address_space, node_id, true, severity, &message)
+        }
+        _ => {
+            let severity = match condition::value(address_space, node_id, &["Severity"]) {
+                Some(Variant::UInt16(severity)) => severity,
+                _ => 0
+            };
+            condition::set_active(address_space, node_id, false, severity, "Value is within limits")
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Provides events that the server raises and the filtering of events for the monitored items of
+//! subscriptions.
+//!
+//! An event is raised on the address space with [`AddressSpace::raise_event`]. A client receives
+//! events by creating a monitored item on the EventNotifier attribute of an object that is an
+//! event notifier, e.g. the Server object, with an [`EventFilter`] that selects the fields of the
+//! event to return, and a where clause that the event must pass.
+//!
+//! The where clause is a content filter. The [`content_filter`] module validates and evaluates
+//! content filters against events and against nodes.
+//!
+//! [`AddressSpace::raise_event`]: ../address_space/address_space/struct.AddressSpace.html#method.raise_event
+//! [`EventFilter`]: ../prelude/struct.EventFilter.html
+//! [`content_filter`]: content_filter/index.html
+
+pub mod condition;
+pub mod content_filter;
+pub mod event;
+pub mod event_filter;
+pub mod limit_alarm;
+pub mod operator;
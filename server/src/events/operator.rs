@@ -0,0 +1,278 @@
+//! Implementations of the filter operators on the values of their operands. Values are converted
+//! implicitly where Part 4 allows it, e.g. numbers of different types are compared by value and
+//! a string is compared to a number by parsing it. An operator whose operands cannot be converted
+//! yields `Variant::Empty` which stands for a null result.
+
+use std::{cmp::Ordering, collections::HashMap, sync::Mutex};
+
+use regex::Regex;
+
+use opcua_types::*;
+use opcua_types::node_ids::DataTypeId;
+
+/// Returns the value of an integer variant without loss of precision
+fn as_i128(value: &Variant) -> Option<i128> {
+    match *value {
+        Variant::SByte(v) => Some(v as i128),
+        Variant::Byte(v) => Some(v as i128),
+        Variant::Int16(v) => Some(v as i128),
+        Variant::UInt16(v) => Some(v as i128),
+        Variant::Int32(v) => Some(v as i128),
+        Variant::UInt32(v) => Some(v as i128),
+        Variant::Int64(v) => Some(v as i128),
+        Variant::UInt64(v) => Some(v as i128),
+        _ => None
+    }
+}
+
+/// Returns the text of a string or localized text variant
+fn as_str(value: &Variant) -> Option<&str> {
+    match *value {
+        Variant::String(ref v) => v.value.as_ref().map(|v| v.as_str()),
+        Variant::LocalizedText(ref v) => v.text.value.as_ref().map(|v| v.as_str()),
+        _ => None
+    }
+}
+
+/// Returns a numeric or boolean value as a double, parsing it if it is a string
+fn as_number(value: &Variant) -> Option<f64> {
+    match *value {
+        Variant::Boolean(v) => Some(if v { 1f64 } else { 0f64 }),
+        _ => value.as_f64().or_else(|| as_str(value).and_then(|v| v.trim().parse::<f64>().ok()))
+    }
+}
+
+/// Compares two values, returning `None` if they cannot be ordered
+pub fn compare(lhs: &Variant, rhs: &Variant) -> Option<Ordering> {
+    if let (Some(lhs), Some(rhs)) = (as_i128(lhs), as_i128(rhs)) {
+        return Some(lhs.cmp(&rhs));
+    }
+    match (lhs, rhs) {
+        (Variant::DateTime(ref lhs), Variant::DateTime(ref rhs)) => Some(lhs.checked_ticks().cmp(&rhs.checked_ticks())),
+        (Variant::StatusCode(lhs), Variant::StatusCode(rhs)) => Some(lhs.bits().cmp(&rhs.bits())),
+        _ => {
+            if lhs.is_numeric() || rhs.is_numeric() {
+                match (as_number(lhs), as_number(rhs)) {
+                    (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs),
+                    _ => None
+                }
+            } else if let (Some(lhs), Some(rhs)) = (as_str(lhs), as_str(rhs)) {
+                Some(lhs.cmp(rhs))
+            } else if let (Variant::Boolean(lhs), Variant::Boolean(rhs)) = (lhs, rhs) {
+                Some(lhs.cmp(rhs))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Tests if two values are equal. Values that cannot be ordered are equal if they are the same.
+pub fn equals(lhs: &Variant, rhs: &Variant) -> Variant {
+    if *lhs == Variant::Empty || *rhs == Variant::Empty {
+        Variant::Empty
+    } else if let Some(ordering) = compare(lhs, rhs) {
+        Variant::Boolean(ordering == Ordering::Equal)
+    } else {
+        Variant::Boolean(lhs == rhs)
+    }
+}
+
+/// Compares two values and tests the ordering, yielding null if they cannot be ordered
+pub fn compare_with<F>(lhs: &Variant, rhs: &Variant, test: F) -> Variant where F: Fn(Ordering) -> bool {
+    match compare(lhs, rhs) {
+        Some(ordering) => Variant::Boolean(test(ordering)),
+        None => Variant::Empty
+    }
+}
+
+/// Converts a Like pattern to a regular expression. In the pattern, `%` matches any string,
+/// `_` matches any single character, `[]` matches any of the characters in the list, `[^]`
+/// matches any character not in the list and `\` escapes the character after it.
+fn like_to_regex(pattern: &str) -> Result<Regex, ()> {
+    let mut regex = String::with_capacity(pattern.len() * 2);
+    regex.push('^');
+    let mut in_list = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let c = chars.next().ok_or(())?;
+                regex.push_str(&regex::escape(&c.to_string()));
+            }
+            '%' if !in_list => regex.push_str(".*"),
+            '_' if !in_list => regex.push('.'),
+            '[' if !in_list => {
+                in_list = true;
+                regex.push('[');
+            }
+            ']' if in_list => {
+                in_list = false;
+                regex.push(']');
+            }
+            '^' | '-' if in_list => regex.push(c),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    if in_list {
+        return Err(());
+    }
+    regex.push('$');
+    Regex::new(&regex).map_err(|_| ())
+}
+
+/// The most patterns that are kept compiled, so patterns read from the values of many events or
+/// nodes cannot grow the cache without bound
+const MAX_LIKE_PATTERNS: usize = 1000;
+
+lazy_static! {
+    /// The regular expressions of Like patterns by their pattern. A pattern is compiled when it is
+    /// first used and then reused by every filter that has it, so a filter that is evaluated
+    /// against many events or nodes compiles each of its patterns once. Invalid patterns are kept
+    /// as `None`.
+    static ref LIKE_PATTERNS: Mutex<HashMap<String, Option<Regex>>> = Mutex::new(HashMap::new());
+}
+
+/// Tests if the value matches the Like pattern, or returns `None` if the pattern is invalid
+fn is_like_match(value: &str, pattern: &str) -> Option<bool> {
+    let mut like_patterns = trace_lock_unwrap!(LIKE_PATTERNS);
+    if let Some(regex) = like_patterns.get(pattern) {
+        return regex.as_ref().map(|regex| regex.is_match(value));
+    }
+    let regex = like_to_regex(pattern).ok();
+    let is_match = regex.as_ref().map(|regex| regex.is_match(value));
+    if like_patterns.len() < MAX_LIKE_PATTERNS {
+        like_patterns.insert(pattern.to_string(), regex);
+    }
+    is_match
+}
+
+/// Tests if the value matches the Like pattern, compiling the pattern only if it has not been
+/// compiled already
+pub fn like(value: &Variant, pattern: &Variant) -> Variant {
+    match (as_str(value), as_str(pattern)) {
+        (Some(value), Some(pattern)) => {
+            match is_like_match(value, pattern) {
+                Some(is_match) => Variant::Boolean(is_match),
+                None => {
+                    error!("Like pattern {} is invalid", pattern);
+                    Variant::Empty
+                }
+            }
+        }
+        _ => Variant::Empty
+    }
+}
+
+/// Combines two values with the three-valued logic of And, where null and false is false
+pub fn and(lhs: &Variant, rhs: &Variant) -> Variant {
+    match (lhs, rhs) {
+        (Variant::Boolean(false), _) | (_, Variant::Boolean(false)) => Variant::Boolean(false),
+        (Variant::Boolean(true), Variant::Boolean(true)) => Variant::Boolean(true),
+        _ => Variant::Empty
+    }
+}
+
+/// Combines two values with the three-valued logic of Or, where null or true is true
+pub fn or(lhs: &Variant, rhs: &Variant) -> Variant {
+    match (lhs, rhs) {
+        (Variant::Boolean(true), _) | (_, Variant::Boolean(true)) => Variant::Boolean(true),
+        (Variant::Boolean(false), Variant::Boolean(false)) => Variant::Boolean(false),
+        _ => Variant::Empty
+    }
+}
+
+/// Negates a boolean value, where the negation of null is null
+pub fn not(value: &Variant) -> Variant {
+    match *value {
+        Variant::Boolean(value) => Variant::Boolean(!value),
+        _ => Variant::Empty
+    }
+}
+
+/// Combines two integer values bit by bit. The result has the type of the operands if they
+/// are the same, otherwise it is an Int64.
+pub fn bitwise<F>(lhs: &Variant, rhs: &Variant, op: F) -> Variant where F: Fn(i128, i128) -> i128 {
+    match (as_i128(lhs), as_i128(rhs)) {
+        (Some(lhs_value), Some(rhs_value)) => {
+            let data_type = if lhs.type_id() == rhs.type_id() { lhs.data_type() } else { None };
+            from_integer(op(lhs_value, rhs_value), data_type.unwrap_or(DataTypeId::Int64))
+        }
+        _ => Variant::Empty
+    }
+}
+
+/// Converts the value to the data type, yielding null if the conversion is not possible
+pub fn cast(value: &Variant, data_type_id: &NodeId) -> Variant {
+    match data_type_id.as_data_type_id() {
+        Ok(data_type) => cast_to(value, data_type),
+        Err(_) => Variant::Empty
+    }
+}
+
+/// Makes an integer variant of the data type, yielding null if the value is out of its range
+fn from_integer(value: i128, data_type: DataTypeId) -> Variant {
+    macro_rules! integer_variant {
+        ($t: ty, $variant: ident) => {
+            if value >= <$t>::min_value() as i128 && value <= <$t>::max_value() as i128 {
+                Variant::$variant(value as $t)
+            } else {
+                Variant::Empty
+            }
+        }
+    }
+    match data_type {
+        DataTypeId::SByte => integer_variant!(i8, SByte),
+        DataTypeId::Byte => integer_variant!(u8, Byte),
+        DataTypeId::Int16 => integer_variant!(i16, Int16),
+        DataTypeId::UInt16 => integer_variant!(u16, UInt16),
+        DataTypeId::Int32 => integer_variant!(i32, Int32),
+        DataTypeId::UInt32 => integer_variant!(u32, UInt32),
+        DataTypeId::Int64 => integer_variant!(i64, Int64),
+        DataTypeId::UInt64 => integer_variant!(u64, UInt64),
+        _ => Variant::Empty
+    }
+}
+
+fn cast_to(value: &Variant, data_type: DataTypeId) -> Variant {
+    if *value == Variant::Empty || value.data_type() == Some(data_type) {
+        return value.clone();
+    }
+    // Integers are converted exactly, anything else through its value as a double
+    let integer = as_i128(value).or_else(|| {
+        as_number(value).filter(|v| v.is_finite()).map(|v| v.round() as i128)
+    });
+    match data_type {
+        DataTypeId::Boolean => {
+            match as_str(value) {
+                Some("true") | Some("1") => Variant::Boolean(true),
+                Some("false") | Some("0") => Variant::Boolean(false),
+                Some(_) => Variant::Empty,
+                None => integer.map_or(Variant::Empty, |v| Variant::Boolean(v != 0))
+            }
+        }
+        DataTypeId::SByte | DataTypeId::Byte |
+        DataTypeId::Int16 | DataTypeId::UInt16 |
+        DataTypeId::Int32 | DataTypeId::UInt32 |
+        DataTypeId::Int64 | DataTypeId::UInt64 => integer.map_or(Variant::Empty, |v| from_integer(v, data_type)),
+        DataTypeId::Float => as_number(value).map_or(Variant::Empty, |v| Variant::Float(v as f32)),
+        DataTypeId::Double => as_number(value).map_or(Variant::Empty, Variant::Double),
+        DataTypeId::String => {
+            match *value {
+                Variant::Boolean(v) => Variant::from(v.to_string()),
+                Variant::LocalizedText(ref v) => Variant::String(v.text.clone()),
+                Variant::NodeId(ref v) => Variant::from(v.to_string()),
+                Variant::Float(v) => Variant::from(v.to_string()),
+                Variant::Double(v) => Variant::from(v.to_string()),
+                _ => integer.map_or(Variant::Empty, |v| Variant::from(v.to_string()))
+            }
+        }
+        DataTypeId::LocalizedText => {
+            match as_str(value) {
+                Some(v) => Variant::from(LocalizedText::new("", v)),
+                None => Variant::Empty
+            }
+        }
+        _ => Variant::Empty
+    }
+}
@@ -0,0 +1,445 @@
+//! Calculates the aggregates of a processed HistoryRead from raw historical values, following the
+//! rules of OPC UA Part 13.
+//!
+//! The time range of the read is divided into processing intervals and one value is calculated for
+//! each interval. Raw values are classified as good, uncertain or bad from their status codes, and
+//! the status of each calculated value reflects the quality of the data in its interval. Bounding
+//! values either side of an interval are used by the interpolating and time weighted aggregates.
+
+use opcua_types::*;
+use opcua_types::node_ids::ObjectId;
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::{AggregateConfiguration, ReadProcessedDetails, ReadRawModifiedDetails};
+
+use crate::constants;
+use crate::historical::data_value_ticks;
+
+/// The aggregates that the server supports
+pub const SUPPORTED_AGGREGATES: &[ObjectId] = &[
+    ObjectId::AggregateFunction_Interpolative,
+    ObjectId::AggregateFunction_Average,
+    ObjectId::AggregateFunction_TimeAverage,
+    ObjectId::AggregateFunction_Total,
+    ObjectId::AggregateFunction_Minimum,
+    ObjectId::AggregateFunction_Maximum,
+    ObjectId::AggregateFunction_Count,
+    ObjectId::AggregateFunction_Start,
+    ObjectId::AggregateFunction_End,
+    ObjectId::AggregateFunction_DurationGood,
+    ObjectId::AggregateFunction_DurationBad,
+];
+
+const TICKS_PER_MILLISECOND: i64 = 10_000;
+
+/// Returns the aggregate configuration that the server uses when the client asks for the server's
+/// defaults. Uncertain values are used in calculations, and an interval is only good or bad when
+/// all of its data is good or bad.
+pub fn default_aggregate_configuration() -> AggregateConfiguration {
+    AggregateConfiguration {
+        use_server_capabilities_defaults: true,
+        treat_uncertain_as_bad: false,
+        percent_data_bad: 100,
+        percent_data_good: 100,
+        use_sloped_extrapolation: false,
+    }
+}
+
+/// Tests if the aggregate is supported by the server
+pub fn is_supported_aggregate(aggregate_type: &NodeId) -> bool {
+    if let Ok(aggregate_type) = aggregate_type.as_object_id() {
+        SUPPORTED_AGGREGATES.contains(&aggregate_type)
+    } else {
+        false
+    }
+}
+
+/// Returns the details of the raw read which supplies the values of a processed read. The raw read
+/// covers the processed read's time range in ascending order and includes the bounding values.
+pub fn raw_read_details(details: &ReadProcessedDetails) -> ReadRawModifiedDetails {
+    let (start_time, end_time) = if details.end_time.checked_ticks() < details.start_time.checked_ticks() {
+        (details.end_time.clone(), details.start_time.clone())
+    } else {
+        (details.start_time.clone(), details.end_time.clone())
+    };
+    ReadRawModifiedDetails {
+        is_read_modified: false,
+        start_time,
+        end_time,
+        num_values_per_node: 0,
+        return_bounds: true,
+    }
+}
+
+/// The quality of a raw value, from its status code
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Quality {
+    Good,
+    Uncertain,
+    Bad,
+}
+
+/// A raw value prepared for the calculations
+struct Sample<'a> {
+    ticks: i64,
+    quality: Quality,
+    data_value: &'a DataValue,
+}
+
+impl<'a> Sample<'a> {
+    fn value(&self) -> Option<f64> {
+        self.data_value.value.as_ref().and_then(|v| v.as_f64())
+    }
+}
+
+/// Calculates the aggregate from the raw values for each processing interval of the read. The raw
+/// values should include the bounding values of the time range, and values denoting a missing bound
+/// are ignored. Values are returned in the order of the intervals, i.e. newest first if the end
+/// time is before the start time.
+pub fn calculate_aggregate(aggregate_type: &NodeId, details: &ReadProcessedDetails, raw_values: &[DataValue]) -> Result<Vec<DataValue>, StatusCode> {
+    let aggregate_type = match aggregate_type.as_object_id() {
+        Ok(aggregate_type) if SUPPORTED_AGGREGATES.contains(&aggregate_type) => aggregate_type,
+        _ => {
+            return Err(StatusCode::BadAggregateNotSupported);
+        }
+    };
+    let configuration = if details.aggregate_configuration.use_server_capabilities_defaults {
+        default_aggregate_configuration()
+    } else {
+        details.aggregate_configuration.clone()
+    };
+    if configuration.percent_data_bad > 100 || configuration.percent_data_good > 100 {
+        return Err(StatusCode::BadAggregateConfigurationRejected);
+    }
+
+    let intervals = intervals(details)?;
+
+    let mut samples = raw_values.iter()
+        .filter(|v| v.status != Some(StatusCode::BadBoundNotFound.bits()))
+        .map(|data_value| Sample {
+            ticks: data_value_ticks(data_value),
+            quality: quality(data_value, &configuration),
+            data_value,
+        })
+        .collect::<Vec<Sample<'_>>>();
+    samples.sort_by_key(|s| s.ticks);
+
+    let processing_interval = (details.processing_interval * TICKS_PER_MILLISECOND as f64) as i64;
+    let results = intervals.iter().map(|&(start, end, timestamp)| {
+        let partial = processing_interval > 0 && end - start < processing_interval;
+        let interval = Interval { start, end, samples: &samples, configuration: &configuration };
+        let mut data_value = interval.calculate(aggregate_type);
+        let status = StatusCode::from_bits_truncate(data_value.status());
+        if partial && !status.contains(StatusCode::IS_ERROR) {
+            data_value.status = Some((status | StatusCode::HISTORICAL_PARTIAL).bits());
+        }
+        if data_value.source_timestamp.is_none() {
+            data_value.source_timestamp = Some(DateTime::from(timestamp));
+        }
+        data_value.server_timestamp = data_value.source_timestamp.clone();
+        data_value
+    }).collect();
+    Ok(results)
+}
+
+/// Returns the (start, end, timestamp) of each processing interval in ticks, in the order the
+/// intervals are read. The start is always before the end, and the timestamp is the start of the
+/// interval in the direction of the read.
+fn intervals(details: &ReadProcessedDetails) -> Result<Vec<(i64, i64, i64)>, StatusCode> {
+    let start = details.start_time.checked_ticks();
+    let end = details.end_time.checked_ticks();
+    if start == 0 || end == 0 || start == end {
+        return Err(StatusCode::BadInvalidTimestampArgument);
+    }
+    if details.processing_interval < 0f64 || !details.processing_interval.is_finite() {
+        return Err(StatusCode::BadInvalidArgument);
+    }
+    let duration = (end - start).abs();
+    let processing_interval = (details.processing_interval * TICKS_PER_MILLISECOND as f64) as i64;
+    let processing_interval = if processing_interval <= 0 || processing_interval > duration {
+        // A single interval covers the whole range
+        duration
+    } else {
+        processing_interval
+    };
+    let count = (duration + processing_interval - 1) / processing_interval;
+    if count as usize > constants::MAX_HISTORY_PROCESSED_INTERVALS {
+        return Err(StatusCode::BadInvalidArgument);
+    }
+    let intervals = (0..count).map(|i| {
+        if end > start {
+            let interval_start = start + i * processing_interval;
+            (interval_start, (interval_start + processing_interval).min(end), interval_start)
+        } else {
+            let interval_end = start - i * processing_interval;
+            ((interval_end - processing_interval).max(end), interval_end, interval_end)
+        }
+    }).collect();
+    Ok(intervals)
+}
+
+fn quality(data_value: &DataValue, configuration: &AggregateConfiguration) -> Quality {
+    let status = StatusCode::from_bits_truncate(data_value.status());
+    if status.contains(StatusCode::IS_ERROR) {
+        Quality::Bad
+    } else if status.contains(StatusCode::IS_UNCERTAIN) {
+        if configuration.treat_uncertain_as_bad { Quality::Bad } else { Quality::Uncertain }
+    } else {
+        Quality::Good
+    }
+}
+
+/// A processing interval, from its start up to but not including its end
+struct Interval<'a> {
+    start: i64,
+    end: i64,
+    samples: &'a [Sample<'a>],
+    configuration: &'a AggregateConfiguration,
+}
+
+impl<'a> Interval<'a> {
+    fn calculate(&self, aggregate_type: ObjectId) -> DataValue {
+        match aggregate_type {
+            ObjectId::AggregateFunction_Interpolative => self.interpolative(),
+            ObjectId::AggregateFunction_Average => self.average(),
+            ObjectId::AggregateFunction_TimeAverage => self.time_average(false),
+            ObjectId::AggregateFunction_Total => self.time_average(true),
+            ObjectId::AggregateFunction_Minimum => self.extreme(false),
+            ObjectId::AggregateFunction_Maximum => self.extreme(true),
+            ObjectId::AggregateFunction_Count => self.count(),
+            ObjectId::AggregateFunction_Start => self.start_end(false),
+            ObjectId::AggregateFunction_End => self.start_end(true),
+            ObjectId::AggregateFunction_DurationGood => self.duration(true),
+            ObjectId::AggregateFunction_DurationBad => self.duration(false),
+            _ => panic!("Aggregate {:?} should have been validated", aggregate_type)
+        }
+    }
+
+    /// Raw values inside the interval which can be used in calculations, i.e. those which are not bad
+    fn usable_samples(&self) -> impl Iterator<Item=&'a Sample<'a>> {
+        let (start, end) = (self.start, self.end);
+        self.samples.iter().filter(move |s| s.ticks >= start && s.ticks < end && s.quality != Quality::Bad)
+    }
+
+    /// Returns the durations in ticks of good and bad data in the interval. The quality of each
+    /// raw value holds until the next raw value, and the time before the first value is bad.
+    fn quality_durations(&self) -> (i64, i64) {
+        let mut good = 0;
+        let mut bad = 0;
+        let mut quality = self.samples.iter().rev()
+            .find(|s| s.ticks <= self.start)
+            .map_or(Quality::Bad, |s| s.quality);
+        let mut t = self.start;
+        for sample in self.samples.iter().filter(|s| s.ticks > self.start && s.ticks < self.end) {
+            match quality {
+                Quality::Good => good += sample.ticks - t,
+                Quality::Bad => bad += sample.ticks - t,
+                Quality::Uncertain => {}
+            }
+            t = sample.ticks;
+            quality = sample.quality;
+        }
+        match quality {
+            Quality::Good => good += self.end - t,
+            Quality::Bad => bad += self.end - t,
+            Quality::Uncertain => {}
+        }
+        (good, bad)
+    }
+
+    /// Returns the status of the interval from the percentage of good and bad data in it
+    fn interval_status(&self) -> StatusCode {
+        let (good, bad) = self.quality_durations();
+        let duration = (self.end - self.start) as f64;
+        let percent_bad = bad as f64 * 100f64 / duration;
+        let percent_good = good as f64 * 100f64 / duration;
+        if percent_bad >= f64::from(self.configuration.percent_data_bad) {
+            StatusCode::BadNoData
+        } else if percent_good >= f64::from(self.configuration.percent_data_good) {
+            StatusCode::Good
+        } else {
+            StatusCode::UncertainDataSubNormal
+        }
+    }
+
+    fn calculated(value: Variant, status: StatusCode, bits: StatusCode) -> DataValue {
+        if status.contains(StatusCode::IS_ERROR) {
+            Self::no_data()
+        } else {
+            DataValue {
+                value: Some(value),
+                status: Some((status | bits).bits()),
+                source_timestamp: None,
+                source_picoseconds: None,
+                server_timestamp: None,
+                server_picoseconds: None,
+            }
+        }
+    }
+
+    fn no_data() -> DataValue {
+        DataValue {
+            value: None,
+            status: Some(StatusCode::BadNoData.bits()),
+            source_timestamp: None,
+            source_picoseconds: None,
+            server_timestamp: None,
+            server_picoseconds: None,
+        }
+    }
+
+    /// Returns the value at the time, interpolating between the nearest usable raw values either
+    /// side of it. Past the last raw value the value is extrapolated, which is uncertain.
+    fn value_at(&self, ticks: i64) -> Option<(f64, StatusCode, StatusCode)> {
+        let usable = |s: &&Sample<'_>| s.quality != Quality::Bad && s.value().is_some();
+        if let Some(sample) = self.samples.iter().filter(usable).find(|s| s.ticks == ticks) {
+            let status = if sample.quality == Quality::Good { StatusCode::Good } else { StatusCode::UncertainDataSubNormal };
+            return Some((sample.value().unwrap(), status, StatusCode::HISTORICAL_RAW));
+        }
+        let before = self.samples.iter().rev().filter(usable).find(|s| s.ticks < ticks);
+        let after = self.samples.iter().filter(usable).find(|s| s.ticks > ticks);
+        match (before, after) {
+            (Some(before), Some(after)) => {
+                let (v1, v2) = (before.value().unwrap(), after.value().unwrap());
+                let value = v1 + (v2 - v1) * ((ticks - before.ticks) as f64 / (after.ticks - before.ticks) as f64);
+                // Bad values skipped over, or uncertain bounds make the result uncertain
+                let skipped_bad = self.samples.iter().any(|s| s.ticks > before.ticks && s.ticks < after.ticks && s.quality == Quality::Bad);
+                let status = if before.quality == Quality::Good && after.quality == Quality::Good && !skipped_bad {
+                    StatusCode::Good
+                } else {
+                    StatusCode::UncertainDataSubNormal
+                };
+                Some((value, status, StatusCode::HISTORICAL_INTERPOLATED))
+            }
+            (Some(before), None) => {
+                let value = if self.configuration.use_sloped_extrapolation {
+                    // Extend the slope of the last two values
+                    let earlier = self.samples.iter().rev().filter(usable).find(|s| s.ticks < before.ticks);
+                    if let Some(earlier) = earlier {
+                        let (v1, v2) = (earlier.value().unwrap(), before.value().unwrap());
+                        v2 + (v2 - v1) * ((ticks - before.ticks) as f64 / (before.ticks - earlier.ticks) as f64)
+                    } else {
+                        before.value().unwrap()
+                    }
+                } else {
+                    before.value().unwrap()
+                };
+                Some((value, StatusCode::UncertainDataSubNormal, StatusCode::HISTORICAL_INTERPOLATED))
+            }
+            _ => None
+        }
+    }
+
+    fn interpolative(&self) -> DataValue {
+        if let Some((value, status, bits)) = self.value_at(self.start) {
+            Self::calculated(Variant::Double(value), status, bits)
+        } else {
+            Self::no_data()
+        }
+    }
+
+    fn average(&self) -> DataValue {
+        let values = self.usable_samples().filter_map(|s| s.value()).collect::<Vec<f64>>();
+        if values.is_empty() {
+            Self::no_data()
+        } else {
+            let average = values.iter().sum::<f64>() / values.len() as f64;
+            Self::calculated(Variant::Double(average), self.interval_status(), StatusCode::HISTORICAL_CALCULATED)
+        }
+    }
+
+    /// Calculates the time weighted average of the interval, using sloped interpolation between the
+    /// raw values and the interpolated bounding values. The total is the time average multiplied by
+    /// the duration of the interval in seconds.
+    fn time_average(&self, total: bool) -> DataValue {
+        let mut points = Vec::with_capacity(8);
+        if let Some((value, _, _)) = self.value_at(self.start) {
+            points.push((self.start, value));
+        }
+        points.extend(self.usable_samples()
+            .filter(|s| s.ticks > self.start)
+            .filter_map(|s| s.value().map(|v| (s.ticks, v))));
+        if let Some((value, _, _)) = self.value_at(self.end) {
+            points.push((self.end, value));
+        }
+        if points.is_empty() {
+            return Self::no_data();
+        }
+        let average = if points.len() == 1 {
+            points[0].1
+        } else {
+            let area: f64 = points.windows(2)
+                .map(|w| (w[1].0 - w[0].0) as f64 * (w[0].1 + w[1].1) / 2f64)
+                .sum();
+            let duration = (points[points.len() - 1].0 - points[0].0) as f64;
+            if duration > 0f64 { area / duration } else { points[0].1 }
+        };
+        let value = if total {
+            let seconds = (self.end - self.start) as f64 / (TICKS_PER_MILLISECOND * 1000) as f64;
+            average * seconds
+        } else {
+            average
+        };
+        Self::calculated(Variant::Double(value), self.interval_status(), StatusCode::HISTORICAL_CALCULATED)
+    }
+
+    /// Finds the minimum or maximum value in the interval. The value keeps the data type of the raw
+    /// value and the multi value bit is set if the extreme occurs more than once.
+    fn extreme(&self, maximum: bool) -> DataValue {
+        let mut extreme: Option<(&Sample<'_>, f64)> = None;
+        let mut occurrences = 0;
+        for sample in self.usable_samples() {
+            if let Some(value) = sample.value() {
+                let replace = match extreme {
+                    None => true,
+                    Some((_, current)) => if maximum { value > current } else { value < current }
+                };
+                if replace {
+                    extreme = Some((sample, value));
+                    occurrences = 1;
+                } else if extreme.map_or(false, |(_, current)| current == value) {
+                    occurrences += 1;
+                }
+            }
+        }
+        if let Some((sample, _)) = extreme {
+            let mut bits = StatusCode::HISTORICAL_CALCULATED;
+            if occurrences > 1 {
+                bits |= StatusCode::HISTORICAL_MULTI_VALUE;
+            }
+            Self::calculated(sample.data_value.value.clone().unwrap(), self.interval_status(), bits)
+        } else {
+            Self::no_data()
+        }
+    }
+
+    fn count(&self) -> DataValue {
+        let count = self.usable_samples().count();
+        let status = match self.interval_status() {
+            status if !status.contains(StatusCode::IS_ERROR) => status,
+            _ if count > 0 => StatusCode::UncertainDataSubNormal,
+            // An interval without any data has a count of zero
+            _ => StatusCode::Good,
+        };
+        Self::calculated(Variant::Int32(count as i32), status, StatusCode::HISTORICAL_CALCULATED)
+    }
+
+    /// Returns the first or last usable raw value in the interval, with its own timestamp
+    fn start_end(&self, end: bool) -> DataValue {
+        let sample = if end { self.usable_samples().last() } else { self.usable_samples().next() };
+        if let Some(sample) = sample {
+            let mut data_value = sample.data_value.clone();
+            data_value.status = Some((StatusCode::from_bits_truncate(data_value.status()) | StatusCode::HISTORICAL_RAW).bits());
+            data_value
+        } else {
+            Self::no_data()
+        }
+    }
+
+    /// Returns the duration in milliseconds of good or bad data in the interval
+    fn duration(&self, good: bool) -> DataValue {
+        let (good_ticks, bad_ticks) = self.quality_durations();
+        let ticks = if good { good_ticks } else { bad_ticks };
+        let milliseconds = ticks as f64 / TICKS_PER_MILLISECOND as f64;
+        Self::calculated(Variant::Double(milliseconds), StatusCode::Good, StatusCode::HISTORICAL_CALCULATED)
+    }
+}
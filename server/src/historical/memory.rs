@@ -0,0 +1,190 @@
+use std::collections::{HashMap, VecDeque};
+
+use opcua_types::*;
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::{DeleteRawModifiedDetails, PerformUpdateType, ReadRawModifiedDetails};
+
+use crate::historical::{data_value_ticks, HistoricalDataProvider, TimeRange};
+
+/// An in-memory historical data provider. Each variable's values are held in a ring buffer in
+/// timestamp order, so when the buffer is full the oldest value is discarded to make room for
+/// the newest.
+pub struct MemoryHistoricalDataProvider {
+    /// Maximum number of values held for each variable
+    capacity: usize,
+    /// Values of each variable, oldest to newest
+    values: HashMap<NodeId, VecDeque<DataValue>>,
+}
+
+impl MemoryHistoricalDataProvider {
+    /// Creates a provider which holds up to `capacity` values for each variable.
+    pub fn new(capacity: usize) -> MemoryHistoricalDataProvider {
+        MemoryHistoricalDataProvider {
+            capacity,
+            values: HashMap::new(),
+        }
+    }
+
+    /// Returns the maximum number of values held for each variable
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values held for the variable
+    pub fn len(&self, node_id: &NodeId) -> usize {
+        self.values.get(node_id).map_or(0, |values| values.len())
+    }
+
+    /// Discards all of the values held for the variable
+    pub fn clear(&mut self, node_id: &NodeId) {
+        self.values.remove(node_id);
+    }
+
+    /// Makes a data value denoting that there is no bounding value at the timestamp
+    fn bound_not_found(ticks: i64) -> DataValue {
+        DataValue {
+            value: None,
+            status: Some(StatusCode::BadBoundNotFound.bits()),
+            source_timestamp: Some(DateTime::from(ticks)),
+            source_picoseconds: None,
+            server_timestamp: None,
+            server_picoseconds: None,
+        }
+    }
+
+    /// Inserts the value in timestamp order, discarding the oldest value if the buffer is full.
+    /// Returns false if the value is not held, i.e. it is older than everything in a full buffer.
+    fn insert_value(values: &mut VecDeque<DataValue>, capacity: usize, data_value: &DataValue) -> bool {
+        if capacity == 0 {
+            return false;
+        }
+        let ticks = data_value_ticks(data_value);
+
+        // Values are usually recorded in time order so look for the insertion point from the back
+        let mut idx = values.len();
+        while idx > 0 && data_value_ticks(&values[idx - 1]) > ticks {
+            idx -= 1;
+        }
+        if idx > 0 && data_value_ticks(&values[idx - 1]) == ticks && values[idx - 1] == *data_value {
+            // The value has already been recorded
+            return true;
+        }
+        if values.len() >= capacity {
+            if idx == 0 {
+                // Value is older than everything in a full buffer
+                return false;
+            }
+            let _ = values.pop_front();
+            idx -= 1;
+        }
+        values.insert(idx, data_value.clone());
+        true
+    }
+}
+
+impl HistoricalDataProvider for MemoryHistoricalDataProvider {
+    fn record_value(&mut self, node_id: &NodeId, data_value: &DataValue) {
+        if self.capacity == 0 {
+            return;
+        }
+        let values = self.values.entry(node_id.clone()).or_default();
+        let _ = Self::insert_value(values, self.capacity, data_value);
+    }
+
+    fn read_raw_values(&self, node_id: &NodeId, details: &ReadRawModifiedDetails) -> Result<Vec<DataValue>, StatusCode> {
+        let range = TimeRange::from_details(details)?;
+        let values = if let Some(values) = self.values.get(node_id) {
+            values
+        } else {
+            return Ok(Vec::new());
+        };
+
+        let mut result: Vec<DataValue> = values.iter()
+            .filter(|v| range.contains(data_value_ticks(v)))
+            .cloned()
+            .collect();
+        if range.reverse {
+            result.reverse();
+        }
+
+        if details.return_bounds {
+            // The start bound is a value at the start time, or failing that the nearest value
+            // outside of the range.
+            let has_start_value = result.first().map_or(false, |v| data_value_ticks(v) == range.start);
+            if !has_start_value {
+                let start_bound = if range.reverse {
+                    values.iter().find(|v| data_value_ticks(v) > range.start)
+                } else {
+                    values.iter().rev().find(|v| data_value_ticks(v) < range.start)
+                };
+                let start_bound = start_bound.cloned().unwrap_or_else(|| Self::bound_not_found(range.start));
+                result.insert(0, start_bound);
+            }
+            // The end bound is a value at the end time, or failing that the nearest value outside of
+            // the range. Open ended ranges have no end bound.
+            if !range.is_open_ended() {
+                let end_bound = if range.reverse {
+                    values.iter().rev().find(|v| data_value_ticks(v) <= range.end)
+                } else {
+                    values.iter().find(|v| data_value_ticks(v) >= range.end)
+                };
+                result.push(end_bound.cloned().unwrap_or_else(|| Self::bound_not_found(range.end)));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn update_values(&mut self, node_id: &NodeId, perform_update_type: PerformUpdateType, data_values: &[DataValue]) -> Result<Vec<StatusCode>, StatusCode> {
+        if perform_update_type == PerformUpdateType::Remove {
+            // Remove only applies to structured data
+            return Err(StatusCode::BadHistoryOperationInvalid);
+        }
+        let capacity = self.capacity;
+        let values = self.values.entry(node_id.clone()).or_default();
+        let results = data_values.iter().map(|data_value| {
+            let ticks = data_value_ticks(data_value);
+            if ticks == 0 {
+                return StatusCode::BadInvalidTimestamp;
+            }
+            let existing = values.iter().position(|v| data_value_ticks(v) == ticks);
+            match (perform_update_type, existing) {
+                (PerformUpdateType::Insert, Some(_)) => StatusCode::BadEntryExists,
+                (PerformUpdateType::Replace, None) => StatusCode::BadNoEntryExists,
+                (_, Some(idx)) => {
+                    values[idx] = data_value.clone();
+                    StatusCode::GoodEntryReplaced
+                }
+                (_, None) => {
+                    if Self::insert_value(values, capacity, data_value) {
+                        StatusCode::GoodEntryInserted
+                    } else {
+                        StatusCode::BadResourceUnavailable
+                    }
+                }
+            }
+        }).collect();
+        Ok(results)
+    }
+
+    fn delete_raw_modified_values(&mut self, node_id: &NodeId, details: &DeleteRawModifiedDetails) -> Result<(), StatusCode> {
+        if details.is_delete_modified {
+            // Modified values are not kept
+            return Err(StatusCode::BadHistoryOperationUnsupported);
+        }
+        // Both times are required
+        let range = TimeRange::new(&details.start_time, &details.end_time, 0)?;
+        let deleted = if let Some(values) = self.values.get_mut(node_id) {
+            let len = values.len();
+            values.retain(|v| !range.contains(data_value_ticks(v)));
+            len - values.len()
+        } else {
+            0
+        };
+        if deleted == 0 {
+            Err(StatusCode::BadNoData)
+        } else {
+            Ok(())
+        }
+    }
+}
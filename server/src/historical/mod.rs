@@ -0,0 +1,149 @@
+//! Provides the interface between the server and a store of historical values, and an in-memory
+//! implementation of that interface.
+//!
+//! The server records the value of a historizing variable with the address space's
+//! [`HistoricalDataProvider`] whenever the value is set through the address space or written by a
+//! client. The HistoryRead service reads from the same provider, and the HistoryUpdate service
+//! inserts, replaces and deletes values in it.
+//!
+//! [`HistoricalDataProvider`]: ./trait.HistoricalDataProvider.html
+
+use opcua_types::*;
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::{DeleteRawModifiedDetails, ModificationInfo, PerformUpdateType, ReadProcessedDetails, ReadRawModifiedDetails};
+
+mod memory;
+
+pub mod aggregates;
+
+pub use self::memory::MemoryHistoricalDataProvider;
+
+/// A historical data provider records the values of historizing variables and returns them
+/// when they are requested by HistoryRead. Implement this trait to store history somewhere other
+/// than in memory, e.g. in a database.
+pub trait HistoricalDataProvider {
+    /// Records a new value for a historizing variable. The value's source timestamp is the time
+    /// that the value is recorded against.
+    fn record_value(&mut self, node_id: &NodeId, data_value: &DataValue);
+
+    /// Reads the raw values of the variable described by the details. Values must be in the
+    /// order described by [`TimeRange`], and include the bounding values if they are requested.
+    /// The server limits the number of values returned per node and creates the continuation
+    /// points, so an implementation returns every value in the range.
+    ///
+    /// [`TimeRange`]: ./struct.TimeRange.html
+    fn read_raw_values(&self, node_id: &NodeId, details: &ReadRawModifiedDetails) -> Result<Vec<DataValue>, StatusCode>;
+
+    /// Reads the modified values of the variable described by the details, i.e. values that
+    /// have been replaced, updated or deleted, with a modification info for each value. The
+    /// default implementation does not support modified values.
+    fn read_modified_values(&self, _node_id: &NodeId, _details: &ReadRawModifiedDetails) -> Result<Vec<(DataValue, ModificationInfo)>, StatusCode> {
+        Err(StatusCode::BadHistoryOperationUnsupported)
+    }
+
+    /// Reads the values of an aggregate of the variable for each processing interval described by
+    /// the details. The default implementation reads the raw values and calculates the aggregate
+    /// with the functions in [`aggregates`], so an implementation only needs to override this if it
+    /// can calculate aggregates itself.
+    ///
+    /// [`aggregates`]: ./aggregates/index.html
+    fn read_processed_values(&self, node_id: &NodeId, details: &ReadProcessedDetails, aggregate_type: &NodeId) -> Result<Vec<DataValue>, StatusCode> {
+        if !aggregates::is_supported_aggregate(aggregate_type) {
+            return Err(StatusCode::BadAggregateNotSupported);
+        }
+        let raw_values = self.read_raw_values(node_id, &aggregates::raw_read_details(details))?;
+        aggregates::calculate_aggregate(aggregate_type, details, &raw_values)
+    }
+
+    /// Inserts, replaces or updates the values of the variable, matching existing values by their
+    /// timestamp. Returns an operation result for each value, e.g. `GoodEntryInserted`,
+    /// `GoodEntryReplaced`, `BadEntryExists` or `BadNoEntryExists`. The default implementation
+    /// does not support updates.
+    fn update_values(&mut self, _node_id: &NodeId, _perform_update_type: PerformUpdateType, _data_values: &[DataValue]) -> Result<Vec<StatusCode>, StatusCode> {
+        Err(StatusCode::BadHistoryOperationUnsupported)
+    }
+
+    /// Deletes the raw or modified values of the variable from the start time up to but not
+    /// including the end time. The default implementation does not support deletes.
+    fn delete_raw_modified_values(&mut self, _node_id: &NodeId, _details: &DeleteRawModifiedDetails) -> Result<(), StatusCode> {
+        Err(StatusCode::BadHistoryOperationUnsupported)
+    }
+}
+
+/// The time range of a read, taken from the start and end time of the read details. A start or end
+/// time of zero ticks is unspecified. Part 11 defines the meaning of each combination:
+///
+/// * Start time before end time - values from the start time, up to but not including the end time,
+///   oldest first.
+/// * Start time after end time - values from the start time, down to but not including the end time,
+///   newest first.
+/// * Start time only - values from the start time onwards, oldest first.
+/// * End time only - values from the end time backwards, newest first.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimeRange {
+    /// Start of the range, in ticks
+    pub start: i64,
+    /// End of the range, in ticks. For an open ended range, this is the end or start of time.
+    pub end: i64,
+    /// Values are returned newest first
+    pub reverse: bool,
+    /// True if the end of the range is inclusive, i.e. only the end time was specified
+    end_inclusive: bool,
+}
+
+impl TimeRange {
+    /// Creates the time range for the start and end time. Fails if neither is specified, or if
+    /// one is missing but the number of values per node is unlimited.
+    pub fn new(start_time: &DateTime, end_time: &DateTime, num_values_per_node: u32) -> Result<TimeRange, StatusCode> {
+        let start = start_time.checked_ticks();
+        let end = end_time.checked_ticks();
+        match (start, end) {
+            (0, 0) => Err(StatusCode::BadInvalidTimestampArgument),
+            (0, end) => {
+                if num_values_per_node == 0 {
+                    Err(StatusCode::BadInvalidTimestampArgument)
+                } else {
+                    Ok(TimeRange { start: end, end: 0, reverse: true, end_inclusive: true })
+                }
+            }
+            (start, 0) => {
+                if num_values_per_node == 0 {
+                    Err(StatusCode::BadInvalidTimestampArgument)
+                } else {
+                    Ok(TimeRange { start, end: i64::max_value(), reverse: false, end_inclusive: true })
+                }
+            }
+            (start, end) => Ok(TimeRange { start, end, reverse: end < start, end_inclusive: false }),
+        }
+    }
+
+    /// Creates the time range from the read details.
+    pub fn from_details(details: &ReadRawModifiedDetails) -> Result<TimeRange, StatusCode> {
+        Self::new(&details.start_time, &details.end_time, details.num_values_per_node)
+    }
+
+    /// Tests if the range only has a start, i.e. it has no end time and therefore no end bound
+    pub fn is_open_ended(&self) -> bool {
+        self.end_inclusive
+    }
+
+    /// Tests if the timestamp, in ticks, is inside the range
+    pub fn contains(&self, ticks: i64) -> bool {
+        let (lower, upper) = if self.reverse { (self.end, self.start) } else { (self.start, self.end) };
+        let lower_ok = if self.reverse && !self.end_inclusive { ticks > lower } else { ticks >= lower };
+        let upper_ok = if !self.reverse && !self.end_inclusive { ticks < upper } else { ticks <= upper };
+        lower_ok && upper_ok
+    }
+}
+
+/// Returns the timestamp of the data value in ticks. The source timestamp is used if there is
+/// one, otherwise the server timestamp.
+pub fn data_value_ticks(data_value: &DataValue) -> i64 {
+    if let Some(ref source_timestamp) = data_value.source_timestamp {
+        source_timestamp.checked_ticks()
+    } else if let Some(ref server_timestamp) = data_value.server_timestamp {
+        server_timestamp.checked_ticks()
+    } else {
+        0
+    }
+}
@@ -88,6 +88,8 @@ pub mod config;
 pub mod address_space;
 pub mod util;
 pub mod continuation_point;
+pub mod historical;
+pub mod events;
 #[cfg(feature = "http")]
 pub mod http;
 
@@ -106,6 +108,8 @@ pub mod prelude {
         subscriptions::subscription::*,
         subscriptions::monitored_item::*,
         util::*,
+        historical::*,
+        events::event::*,
     };
 }
 
@@ -119,6 +123,8 @@ pub mod constants {
     pub const DEFAULT_MAX_SUBSCRIPTIONS: u32 = 100;
     /// Default, well known address for TCP discovery server
     pub const DEFAULT_DISCOVERY_SERVER_URL: &str = "opc.tcp://localhost:4840/UADiscovery";
+    /// The URI of the OPC UA namespace, which is always namespace 0
+    pub const OPC_UA_NAMESPACE_URI: &str = "http://opcfoundation.org/UA/";
 
     // Internally controlled values
 
@@ -132,6 +138,12 @@ pub mod constants {
     pub const MIN_SAMPLING_INTERVAL: f64 = (SUBSCRIPTION_TIMER_RATE_MS as f64) / 1000.0;
     /// Maximum data change queue allowed by clients on monitored items
     pub const MAX_DATA_CHANGE_QUEUE_SIZE: usize = 10;
+    /// Default event queue size of monitored items, used when clients ask for a queue size of 0
+    pub const DEFAULT_EVENT_QUEUE_SIZE: usize = 100;
+    /// Maximum event queue size allowed by clients on monitored items
+    pub const MAX_EVENT_QUEUE_SIZE: usize = 1000;
+    /// Maximum number of recently raised events that are held for monitored items to pick up
+    pub const MAX_RECENT_EVENTS: usize = 1000;
     /// The default size of preallocated vecs of monitored items per subscription
     pub const DEFAULT_MONITORED_ITEM_CAPACITY: usize = 100;
     /// Interval to check for HELLO timeout in millis. This can be fairly coarse because it's not
@@ -148,11 +160,27 @@ pub mod constants {
     /// Maximum browse continuation points
     pub const MAX_BROWSE_CONTINUATION_POINTS: usize = 10;
     /// Maximum history continuation points
-    pub const MAX_HISTORY_CONTINUATION_POINTS: usize = 0;
+    pub const MAX_HISTORY_CONTINUATION_POINTS: usize = 10;
+    /// Maximum nodes per HistoryRead request
+    pub const MAX_NODES_PER_HISTORY_READ: usize = 100;
+    /// Maximum nodes per HistoryUpdate request
+    pub const MAX_NODES_PER_HISTORY_UPDATE: usize = 100;
+    /// Maximum processing intervals that a processed HistoryRead may calculate for a node
+    pub const MAX_HISTORY_PROCESSED_INTERVALS: usize = 10000;
+    /// Default number of values the in-memory historical data provider holds for each variable
+    pub const DEFAULT_HISTORY_CAPACITY: usize = 1000;
     /// Maximum query continuation points
-    pub const MAX_QUERY_CONTINUATION_POINTS: usize = 0;
+    pub const MAX_QUERY_CONTINUATION_POINTS: usize = 10;
+    /// Maximum node types per QueryFirst request
+    pub const MAX_NODE_TYPES_PER_QUERY: usize = 100;
+    /// Maximum nodes per RegisterNodes / UnregisterNodes request
+    pub const MAX_NODES_PER_REGISTER_NODES: usize = 1000;
+    /// Maximum nodes registered in a session
+    pub const MAX_REGISTERED_NODES: usize = 1000;
     /// Maximum method calls per request
     pub const MAX_METHOD_CALLS: usize = 10;
+    /// Maximum nodes or references to add / delete per NodeManagement request
+    pub const MAX_NODES_PER_NODE_MANAGEMENT: usize = 100;
 }
 
 #[cfg(test)]
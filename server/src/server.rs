@@ -6,6 +6,7 @@ use std::marker::Sync;
 use std::time::{Instant, Duration};
 use std::thread;
 
+use chrono::Utc;
 use futures::{Future, Stream};
 use futures::future;
 use futures::sync::mpsc::{unbounded, UnboundedSender};
@@ -88,7 +89,7 @@ impl Server {
         let application_name = config.application_name.clone();
         let application_uri = UAString::from(config.application_uri.as_ref());
         let product_uri = UAString::from(config.product_uri.as_ref());
-        let namespaces = vec!["http://opcfoundation.org/UA/".to_string(), "urn:OPCUA-Rust-Internal".to_string(), config.application_uri.clone()];
+        let namespaces = vec![constants::OPC_UA_NAMESPACE_URI.to_string(), "urn:OPCUA-Rust-Internal".to_string(), config.application_uri.clone()];
         let start_time = DateTime::now();
         let servers = vec![config.application_uri.clone()];
         let base_endpoint = format!("opc.tcp://{}:{}", config.tcp_config.host, config.tcp_config.port);
@@ -127,6 +128,8 @@ impl Server {
             max_lifetime_count: constants::MAX_KEEP_ALIVE_COUNT * 3,
             diagnostics,
             abort: false,
+            sessions: Vec::new(),
+            detached_sessions: Vec::new(),
         };
         let server_state = Arc::new(RwLock::new(server_state));
 
@@ -215,6 +218,12 @@ impl Server {
                     server.start_pending_polling_actions();
                 }
 
+                // Start a timer that services the sessions whose connections were dropped
+                {
+                    let server = trace_read_lock_unwrap!(server);
+                    server.start_detached_sessions_timer();
+                }
+
                 // Start a server abort task loop
                 Self::start_abort_poll(server, tx_abort);
 
@@ -288,11 +297,11 @@ impl Server {
         // Go through all connections, removing those that have terminated
         let mut connections = trace_write_lock_unwrap!(self.connections);
         connections.retain(|connection| {
-            // Try to obtain the lock on the transport and the session and check if session is terminated
-            // if it is, then we'll use its termination status to sweep it out.
+            // Try to obtain the lock on the transport and check if it is finished. The session is not
+            // checked because it may have been detached and then attached to another connection.
             let mut lock = connection.try_read();
             if let Ok(ref mut connection) = lock {
-                !connection.is_session_terminated()
+                !connection.is_finished()
             } else {
                 true
             }
@@ -372,6 +381,32 @@ impl Server {
         tokio::spawn(task);
     }
 
+    /// Start a timer that ticks the subscriptions of detached sessions, i.e. sessions whose
+    /// connections were dropped, and closes those whose session timeout has expired.
+    fn start_detached_sessions_timer(&self) {
+        let server_state = self.server_state.clone();
+        let server_state_for_take_while = self.server_state.clone();
+        let address_space = self.address_space.clone();
+        let interval_duration = Duration::from_millis(constants::SUBSCRIPTION_TIMER_RATE_MS);
+        let task = Interval::new(Instant::now(), interval_duration)
+            .take_while(move |_| {
+                let server_state = trace_read_lock_unwrap!(server_state_for_take_while);
+                future::ok(!server_state.is_abort())
+            })
+            .for_each(move |_| {
+                let mut server_state = trace_write_lock_unwrap!(server_state);
+                server_state.tick_detached_sessions(&Utc::now(), &address_space);
+                Ok(())
+            })
+            .map(|_| {
+                info!("Detached sessions timer is finished");
+            })
+            .map_err(|err| {
+                error!("Detached sessions timer error = {:?}", err);
+            });
+        tokio::spawn(task);
+    }
+
     /// Start a timer that triggers every 5 minutes and causes the server to register itself with a discovery server
     fn start_discovery_server_registration_timer(&self, discovery_server_url: Option<String>) {
         if let Some(discovery_server_url) = discovery_server_url {
@@ -475,8 +510,10 @@ impl Server {
         let session = {
             Arc::new(RwLock::new(Session::new(self)))
         };
-        // TODO session should be stored in a sessions list so that disconnected sessions can be
-        //  reestablished if necessary
+        {
+            let mut server_state = trace_write_lock_unwrap!(self.server_state);
+            server_state.add_session(&session);
+        }
         let address_space = self.address_space.clone();
         let message_handler = MessageHandler::new(self.certificate_store.clone(), self.server_state.clone(), session.clone(), address_space.clone());
         TcpTransport::new(self.server_state.clone(), session, address_space, message_handler)
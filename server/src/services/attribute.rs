@@ -1,14 +1,32 @@
 use std::result::Result;
+use std::sync::{Arc, Mutex};
 
 use opcua_types::*;
+use opcua_types::node_ids::ObjectId;
 use opcua_types::status_code::StatusCode;
 use opcua_types::service_types::*;
 
 use crate::{
+    constants,
+    continuation_point::HistoryContinuationPoint,
     services::Service,
+    session::Session,
+    state::ServerState,
     address_space::{AccessLevel, AddressSpace, node::NodeType},
 };
 
+/// The history read details that the server supports, decoded from the request
+enum HistoryReadDetails {
+    RawModified(ReadRawModifiedDetails),
+    Processed(ReadProcessedDetails),
+}
+
+/// The history update details that the server supports, decoded from the request
+enum HistoryUpdateDetails {
+    UpdateData(UpdateDataDetails),
+    DeleteRawModified(DeleteRawModifiedDetails),
+}
+
 /// The attribute service. Allows attributes to be read and written from the address space.
 pub(crate) struct AttributeService;
 
@@ -27,7 +45,7 @@ impl AttributeService {
     /// elements or to read ranges of elements of the composite. Servers may make historical
     /// values available to Clients using this Service, although the historical values themselves
     /// are not visible in the AddressSpace.
-    pub fn read(&self, address_space: &AddressSpace, request: &ReadRequest) -> Result<SupportedMessage, StatusCode> {
+    pub fn read(&self, session: &Session, address_space: &AddressSpace, request: &ReadRequest) -> Result<SupportedMessage, StatusCode> {
         // Read nodes and their attributes
         let timestamps_to_return = request.timestamps_to_return;
 
@@ -38,7 +56,7 @@ impl AttributeService {
 
         let results = if let Some(ref nodes_to_read) = request.nodes_to_read {
             let results = nodes_to_read.iter().map(|node_to_read| {
-                Self::read_node_value(&address_space, node_to_read, timestamps_to_return)
+                Self::read_node_value(address_space, session.resolve_node_id(&node_to_read.node_id), node_to_read, timestamps_to_return)
             }).collect();
             Some(results)
         } else {
@@ -62,10 +80,10 @@ impl AttributeService {
     /// constructed Attribute values whose elements are indexed, such as an array, this Service
     /// allows Clients to write the entire set of indexed values as a composite, to write individual
     /// elements or to write ranges of elements of the composite.
-    pub fn write(&self, address_space: &mut AddressSpace, request: &WriteRequest) -> Result<SupportedMessage, StatusCode> {
+    pub fn write(&self, session: &Session, address_space: &mut AddressSpace, request: &WriteRequest) -> Result<SupportedMessage, StatusCode> {
         let results = if let Some(ref nodes_to_write) = request.nodes_to_write {
             let results = nodes_to_write.iter().map(|node_to_write| {
-                Self::write_node_value(address_space, node_to_write)
+                Self::write_node_value(address_space, session.resolve_node_id(&node_to_write.node_id), node_to_write)
             }).collect();
             Some(results)
         } else {
@@ -82,7 +100,434 @@ impl AttributeService {
         Ok(response.into())
     }
 
-    fn read_node_value(address_space: &AddressSpace, node_to_read: &ReadValueId, timestamps_to_return: TimestampsToReturn) -> DataValue {
+    /// Spec:
+    ///
+    /// This Service is used to read historical values or Events of one or more Nodes. Servers may
+    /// make historical values available to Clients using this Service, although the historical
+    /// values themselves are not visible in the AddressSpace.
+    ///
+    /// Raw, modified and processed values are supported, i.e. the details must be
+    /// ReadRawModifiedDetails or ReadProcessedDetails.
+    pub fn history_read(&self, server_state: &ServerState, session: &mut Session, address_space: &AddressSpace, request: &HistoryReadRequest) -> Result<SupportedMessage, StatusCode> {
+        let nodes_to_read = match request.nodes_to_read {
+            Some(ref nodes_to_read) if !nodes_to_read.is_empty() => nodes_to_read,
+            _ => {
+                warn!("HistoryReadRequest nothing to do");
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        if nodes_to_read.len() > constants::MAX_NODES_PER_HISTORY_READ {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
+        }
+        if request.timestamps_to_return == TimestampsToReturn::Neither {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTimestampsToReturnInvalid));
+        }
+
+        let results = if request.release_continuation_points {
+            nodes_to_read.iter().map(|node_to_read| {
+                session.remove_history_continuation_point(&node_to_read.continuation_point);
+                Self::history_read_result(StatusCode::Good)
+            }).collect()
+        } else {
+            let decoding_limits = {
+                let config = trace_read_lock_unwrap!(server_state.config);
+                config.decoding_limits()
+            };
+            match Self::decode_history_read_details(&request.history_read_details, &decoding_limits) {
+                Ok(HistoryReadDetails::RawModified(details)) => {
+                    nodes_to_read.iter().map(|node_to_read| {
+                        Self::history_read_raw_modified(session, address_space, &request.history_read_details, &details, request.timestamps_to_return, node_to_read)
+                    }).collect()
+                }
+                Ok(HistoryReadDetails::Processed(details)) => {
+                    // Each node to read has its own aggregate
+                    match details.aggregate_type {
+                        Some(ref aggregate_types) if aggregate_types.len() == nodes_to_read.len() => {
+                            nodes_to_read.iter().zip(aggregate_types.iter()).map(|(node_to_read, aggregate_type)| {
+                                Self::history_read_processed(session, address_space, &request.history_read_details, &details, aggregate_type, request.timestamps_to_return, node_to_read)
+                            }).collect()
+                        }
+                        _ => {
+                            return Ok(self.service_fault(&request.request_header, StatusCode::BadAggregateListMismatch));
+                        }
+                    }
+                }
+                Err(StatusCode::BadHistoryOperationUnsupported) => {
+                    nodes_to_read.iter().map(|_| {
+                        Self::history_read_result(StatusCode::BadHistoryOperationUnsupported)
+                    }).collect()
+                }
+                Err(status_code) => {
+                    return Ok(self.service_fault(&request.request_header, status_code));
+                }
+            }
+        };
+
+        let response = HistoryReadResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+
+        Ok(response.into())
+    }
+
+    /// Spec:
+    ///
+    /// This Service is used to update historical values or Events of one or more Nodes. Several
+    /// request parameters indicate how the Server is to update the historical value or Event.
+    /// Valid actions are Insert, Replace or Delete.
+    ///
+    /// Values may be inserted, replaced or updated with UpdateDataDetails and deleted with
+    /// DeleteRawModifiedDetails. The variable must have the HistoryWrite bit in its access level
+    /// and user access level.
+    pub fn history_update(&self, server_state: &ServerState, address_space: &AddressSpace, request: &HistoryUpdateRequest) -> Result<SupportedMessage, StatusCode> {
+        let history_update_details = match request.history_update_details {
+            Some(ref history_update_details) if !history_update_details.is_empty() => history_update_details,
+            _ => {
+                warn!("HistoryUpdateRequest nothing to do");
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        if history_update_details.len() > constants::MAX_NODES_PER_HISTORY_UPDATE {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
+        }
+
+        let decoding_limits = {
+            let config = trace_read_lock_unwrap!(server_state.config);
+            config.decoding_limits()
+        };
+        let results = history_update_details.iter().map(|history_update_details| {
+            match Self::decode_history_update_details(history_update_details, &decoding_limits) {
+                Ok(HistoryUpdateDetails::UpdateData(details)) => Self::history_update_data(address_space, &details),
+                Ok(HistoryUpdateDetails::DeleteRawModified(details)) => Self::history_delete_raw_modified(address_space, &details),
+                Err(status_code) => Self::history_update_result(status_code, None),
+            }
+        }).collect();
+
+        let response = HistoryUpdateResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+
+        Ok(response.into())
+    }
+
+    /// Decodes the history read details of the request. Details that are valid but not supported
+    /// yield `BadHistoryOperationUnsupported`.
+    fn decode_history_read_details(history_read_details: &ExtensionObject, decoding_limits: &DecodingLimits) -> Result<HistoryReadDetails, StatusCode> {
+        match history_read_details.node_id.as_object_id() {
+            Ok(ObjectId::ReadRawModifiedDetails_Encoding_DefaultBinary) => {
+                history_read_details.decode_inner::<ReadRawModifiedDetails>(decoding_limits)
+                    .map(HistoryReadDetails::RawModified)
+                    .map_err(|_| StatusCode::BadHistoryOperationInvalid)
+            }
+            Ok(ObjectId::ReadProcessedDetails_Encoding_DefaultBinary) => {
+                history_read_details.decode_inner::<ReadProcessedDetails>(decoding_limits)
+                    .map(HistoryReadDetails::Processed)
+                    .map_err(|_| StatusCode::BadHistoryOperationInvalid)
+            }
+            Ok(ObjectId::ReadEventDetails_Encoding_DefaultBinary) |
+            Ok(ObjectId::ReadAtTimeDetails_Encoding_DefaultBinary) => {
+                Err(StatusCode::BadHistoryOperationUnsupported)
+            }
+            _ => {
+                warn!("HistoryReadRequest has invalid history read details {:?}", history_read_details.node_id);
+                Err(StatusCode::BadHistoryOperationInvalid)
+            }
+        }
+    }
+
+    fn history_read_result(status_code: StatusCode) -> HistoryReadResult {
+        HistoryReadResult {
+            status_code,
+            continuation_point: ByteString::null(),
+            history_data: ExtensionObject::null(),
+        }
+    }
+
+    /// Reads the raw or modified values of a node, or continues a previous read of the node
+    fn history_read_raw_modified(session: &mut Session, address_space: &AddressSpace, history_read_details: &ExtensionObject, details: &ReadRawModifiedDetails, timestamps_to_return: TimestampsToReturn, node_to_read: &HistoryReadValueId) -> HistoryReadResult {
+        let continuation_point = if !node_to_read.continuation_point.is_null() {
+            // Continue from where the previous read stopped
+            match Self::take_history_continuation_point(session, history_read_details, node_to_read) {
+                Ok(continuation_point) => continuation_point,
+                Err(status_code) => {
+                    return Self::history_read_result(status_code);
+                }
+            }
+        } else {
+            if let Err(status_code) = Self::validate_history_read(address_space, node_to_read) {
+                return Self::history_read_result(status_code);
+            }
+            let historical_data_provider = address_space.historical_data_provider();
+            let historical_data_provider = trace_read_lock_unwrap!(historical_data_provider);
+            let result = if details.is_read_modified {
+                historical_data_provider.read_modified_values(&node_to_read.node_id, details).map(|values| {
+                    let (data_values, modification_infos): (Vec<_>, Vec<_>) = values.into_iter().unzip();
+                    (data_values, Some(Arc::new(Mutex::new(modification_infos))))
+                })
+            } else {
+                historical_data_provider.read_raw_values(&node_to_read.node_id, details).map(|data_values| (data_values, None))
+            };
+            match result {
+                Ok((data_values, modification_infos)) => HistoryContinuationPoint {
+                    id: ByteString::null(),
+                    node_id: node_to_read.node_id.clone(),
+                    history_read_details: history_read_details.clone(),
+                    max_values_per_node: details.num_values_per_node as usize,
+                    starting_index: 0,
+                    data_values: Arc::new(Mutex::new(data_values)),
+                    modification_infos,
+                },
+                Err(status_code) => {
+                    return Self::history_read_result(status_code);
+                }
+            }
+        };
+        Self::history_read_from_continuation_point(session, continuation_point, timestamps_to_return)
+    }
+
+    /// Reads the values of an aggregate of a node for each processing interval, or continues a
+    /// previous read of the node
+    fn history_read_processed(session: &mut Session, address_space: &AddressSpace, history_read_details: &ExtensionObject, details: &ReadProcessedDetails, aggregate_type: &NodeId, timestamps_to_return: TimestampsToReturn, node_to_read: &HistoryReadValueId) -> HistoryReadResult {
+        let continuation_point = if !node_to_read.continuation_point.is_null() {
+            match Self::take_history_continuation_point(session, history_read_details, node_to_read) {
+                Ok(continuation_point) => continuation_point,
+                Err(status_code) => {
+                    return Self::history_read_result(status_code);
+                }
+            }
+        } else {
+            if let Err(status_code) = Self::validate_history_read(address_space, node_to_read) {
+                return Self::history_read_result(status_code);
+            }
+            let historical_data_provider = address_space.historical_data_provider();
+            let historical_data_provider = trace_read_lock_unwrap!(historical_data_provider);
+            match historical_data_provider.read_processed_values(&node_to_read.node_id, details, aggregate_type) {
+                Ok(data_values) => HistoryContinuationPoint {
+                    id: ByteString::null(),
+                    node_id: node_to_read.node_id.clone(),
+                    history_read_details: history_read_details.clone(),
+                    max_values_per_node: 0,
+                    starting_index: 0,
+                    data_values: Arc::new(Mutex::new(data_values)),
+                    modification_infos: None,
+                },
+                Err(status_code) => {
+                    return Self::history_read_result(status_code);
+                }
+            }
+        };
+        Self::history_read_from_continuation_point(session, continuation_point, timestamps_to_return)
+    }
+
+    /// Takes the continuation point of the node to read from the session. The point must have been
+    /// made by a read of the same node with the same history read details, so a client cannot
+    /// continue one read with another's values.
+    fn take_history_continuation_point(session: &mut Session, history_read_details: &ExtensionObject, node_to_read: &HistoryReadValueId) -> Result<HistoryContinuationPoint, StatusCode> {
+        match session.find_history_continuation_point(&node_to_read.continuation_point) {
+            Some(continuation_point) => {
+                if continuation_point.node_id != node_to_read.node_id || continuation_point.history_read_details != *history_read_details {
+                    warn!("Continuation point was not made by a read of node {:?} with the same details", node_to_read.node_id);
+                    Err(StatusCode::BadContinuationPointInvalid)
+                } else {
+                    session.remove_history_continuation_point(&continuation_point.id);
+                    Ok(continuation_point)
+                }
+            }
+            None => Err(StatusCode::BadContinuationPointInvalid)
+        }
+    }
+
+    /// Tests if the node's history can be read by the session
+    fn validate_history_read(address_space: &AddressSpace, node_to_read: &HistoryReadValueId) -> Result<(), StatusCode> {
+        if let Some(node) = address_space.find_node(&node_to_read.node_id) {
+            if let NodeType::Variable(ref variable) = node {
+                if !variable.is_history_readable() {
+                    Err(StatusCode::BadNotReadable)
+                } else if !variable.is_user_history_readable() {
+                    Err(StatusCode::BadUserAccessDenied)
+                } else if !node_to_read.index_range.is_null() {
+                    // Index ranges are not supported
+                    Err(StatusCode::BadNotReadable)
+                } else if !node_to_read.data_encoding.is_null() {
+                    Err(StatusCode::BadDataEncodingUnsupported)
+                } else {
+                    Ok(())
+                }
+            } else {
+                // Only variables have historical values
+                Err(StatusCode::BadHistoryOperationUnsupported)
+            }
+        } else {
+            warn!("Cannot find node id {:?}", node_to_read.node_id);
+            Err(StatusCode::BadNodeIdUnknown)
+        }
+    }
+
+    /// Returns the next values held by the continuation point, up to the maximum number of values
+    /// per node. If more values remain, a continuation point for them is stored on the session.
+    fn history_read_from_continuation_point(session: &mut Session, continuation_point: HistoryContinuationPoint, timestamps_to_return: TimestampsToReturn) -> HistoryReadResult {
+        let (data_values, values_remaining) = {
+            let data_values = continuation_point.data_values.lock().unwrap();
+            let start = continuation_point.starting_index.min(data_values.len());
+            let end = if continuation_point.max_values_per_node > 0 {
+                (start + continuation_point.max_values_per_node).min(data_values.len())
+            } else {
+                data_values.len()
+            };
+            let page = data_values[start..end].iter()
+                .map(|data_value| Self::history_data_value(data_value, timestamps_to_return))
+                .collect::<Vec<DataValue>>();
+            (page, end < data_values.len())
+        };
+
+        let status_code = if data_values.is_empty() { StatusCode::GoodNoData } else { StatusCode::Good };
+        let history_data = if let Some(ref modification_infos) = continuation_point.modification_infos {
+            let modification_infos = modification_infos.lock().unwrap();
+            let start = continuation_point.starting_index.min(modification_infos.len());
+            let end = (start + data_values.len()).min(modification_infos.len());
+            let history_data = HistoryModifiedData {
+                data_values: Some(data_values),
+                modification_infos: Some(modification_infos[start..end].to_vec()),
+            };
+            ExtensionObject::from_encodable(ObjectId::HistoryModifiedData_Encoding_DefaultBinary, &history_data)
+        } else {
+            let history_data = HistoryData {
+                data_values: Some(data_values),
+            };
+            ExtensionObject::from_encodable(ObjectId::HistoryData_Encoding_DefaultBinary, &history_data)
+        };
+
+        let continuation_point_id = if values_remaining {
+            // Create a continuation point for the remainder of the result
+            let id = ByteString::random(6);
+            let starting_index = continuation_point.starting_index + continuation_point.max_values_per_node;
+            session.add_history_continuation_point(HistoryContinuationPoint {
+                id: id.clone(),
+                starting_index,
+                ..continuation_point
+            });
+            id
+        } else {
+            ByteString::null()
+        };
+
+        HistoryReadResult {
+            status_code,
+            continuation_point: continuation_point_id,
+            history_data,
+        }
+    }
+
+    /// Returns a copy of a historical value with the requested timestamps
+    fn history_data_value(data_value: &DataValue, timestamps_to_return: TimestampsToReturn) -> DataValue {
+        let mut data_value = data_value.clone();
+        match timestamps_to_return {
+            TimestampsToReturn::Source => {
+                data_value.server_timestamp = None;
+                data_value.server_picoseconds = None;
+            }
+            TimestampsToReturn::Server => {
+                data_value.source_timestamp = None;
+                data_value.source_picoseconds = None;
+            }
+            TimestampsToReturn::Both | TimestampsToReturn::Neither => {}
+        }
+        data_value
+    }
+
+    /// Decodes one of the history update details of the request. Details that are valid but not
+    /// supported yield `BadHistoryOperationUnsupported`.
+    fn decode_history_update_details(history_update_details: &ExtensionObject, decoding_limits: &DecodingLimits) -> Result<HistoryUpdateDetails, StatusCode> {
+        match history_update_details.node_id.as_object_id() {
+            Ok(ObjectId::UpdateDataDetails_Encoding_DefaultBinary) => {
+                history_update_details.decode_inner::<UpdateDataDetails>(decoding_limits)
+                    .map(HistoryUpdateDetails::UpdateData)
+                    .map_err(|_| StatusCode::BadHistoryOperationInvalid)
+            }
+            Ok(ObjectId::DeleteRawModifiedDetails_Encoding_DefaultBinary) => {
+                history_update_details.decode_inner::<DeleteRawModifiedDetails>(decoding_limits)
+                    .map(HistoryUpdateDetails::DeleteRawModified)
+                    .map_err(|_| StatusCode::BadHistoryOperationInvalid)
+            }
+            Ok(ObjectId::UpdateStructureDataDetails_Encoding_DefaultBinary) |
+            Ok(ObjectId::UpdateEventDetails_Encoding_DefaultBinary) |
+            Ok(ObjectId::DeleteAtTimeDetails_Encoding_DefaultBinary) |
+            Ok(ObjectId::DeleteEventDetails_Encoding_DefaultBinary) => {
+                Err(StatusCode::BadHistoryOperationUnsupported)
+            }
+            _ => {
+                warn!("HistoryUpdateRequest has invalid history update details {:?}", history_update_details.node_id);
+                Err(StatusCode::BadHistoryOperationInvalid)
+            }
+        }
+    }
+
+    fn history_update_result(status_code: StatusCode, operation_results: Option<Vec<StatusCode>>) -> HistoryUpdateResult {
+        HistoryUpdateResult {
+            status_code,
+            operation_results,
+            diagnostic_infos: None,
+        }
+    }
+
+    /// Inserts, replaces or updates values of a node
+    fn history_update_data(address_space: &AddressSpace, details: &UpdateDataDetails) -> HistoryUpdateResult {
+        if let Err(status_code) = Self::validate_history_update(address_space, &details.node_id) {
+            return Self::history_update_result(status_code, None);
+        }
+        let update_values = match details.update_values {
+            Some(ref update_values) if !update_values.is_empty() => update_values,
+            _ => {
+                return Self::history_update_result(StatusCode::BadNothingToDo, None);
+            }
+        };
+        let historical_data_provider = address_space.historical_data_provider();
+        let mut historical_data_provider = trace_write_lock_unwrap!(historical_data_provider);
+        match historical_data_provider.update_values(&details.node_id, details.perform_insert_replace, update_values) {
+            Ok(operation_results) => Self::history_update_result(StatusCode::Good, Some(operation_results)),
+            Err(status_code) => Self::history_update_result(status_code, None),
+        }
+    }
+
+    /// Deletes raw or modified values of a node
+    fn history_delete_raw_modified(address_space: &AddressSpace, details: &DeleteRawModifiedDetails) -> HistoryUpdateResult {
+        if let Err(status_code) = Self::validate_history_update(address_space, &details.node_id) {
+            return Self::history_update_result(status_code, None);
+        }
+        let historical_data_provider = address_space.historical_data_provider();
+        let mut historical_data_provider = trace_write_lock_unwrap!(historical_data_provider);
+        match historical_data_provider.delete_raw_modified_values(&details.node_id, details) {
+            Ok(_) => Self::history_update_result(StatusCode::Good, None),
+            Err(status_code) => Self::history_update_result(status_code, None),
+        }
+    }
+
+    /// Tests if the node's history can be updated
+    fn validate_history_update(address_space: &AddressSpace, node_id: &NodeId) -> Result<(), StatusCode> {
+        if let Some(node) = address_space.find_node(node_id) {
+            if let NodeType::Variable(ref variable) = node {
+                if !variable.is_history_writable() {
+                    Err(StatusCode::BadNotWritable)
+                } else if !variable.is_user_history_writable() {
+                    Err(StatusCode::BadUserAccessDenied)
+                } else {
+                    Ok(())
+                }
+            } else {
+                // Only variables have historical values
+                Err(StatusCode::BadHistoryOperationUnsupported)
+            }
+        } else {
+            warn!("Cannot find node id {:?}", node_id);
+            Err(StatusCode::BadNodeIdUnknown)
+        }
+    }
+
+    fn read_node_value(address_space: &AddressSpace, node_id: &NodeId, node_to_read: &ReadValueId, timestamps_to_return: TimestampsToReturn) -> DataValue {
         let mut result_value = DataValue {
             value: None,
             status: None,
@@ -92,19 +537,24 @@ impl AttributeService {
             server_picoseconds: None,
         };
         // Node node found
-        if let Some(node) = address_space.find_node(&node_to_read.node_id) {
+        if let Some(node) = address_space.find_node(node_id) {
             if let Ok(attribute_id) = AttributeId::from_u32(node_to_read.attribute_id) {
                 if let Some(attribute) = node.as_node().find_attribute(attribute_id) {
                     let is_readable = Self::is_readable(&node);
                     if !is_readable {
                         result_value.status = Some(StatusCode::BadNotReadable.bits())
-                    } else if !node_to_read.index_range.is_null() {
-                        // Index ranges are not supported
-                        result_value.status = Some(StatusCode::BadNotReadable.bits());
                     } else {
-                        // Result value is clone from the attribute
-                        result_value.value = attribute.value.clone();
-                        result_value.status = attribute.status;
+                        // Result value is clone from the attribute, or the part of it in the index range,
+                        // in the requested data encoding
+                        match Self::read_attribute_value(address_space, attribute_id, &attribute, node_to_read) {
+                            Ok(value) => {
+                                result_value.value = value;
+                                result_value.status = attribute.status;
+                            }
+                            Err(status_code) => {
+                                result_value.status = Some(status_code.bits());
+                            }
+                        }
                         match timestamps_to_return {
                             TimestampsToReturn::Source => {
                                 result_value.source_timestamp = attribute.source_timestamp.clone();
@@ -133,12 +583,40 @@ impl AttributeService {
                 result_value.status = Some(StatusCode::BadAttributeIdInvalid.bits());
             }
         } else {
-            warn!("Cannot find node id {:?}", node_to_read.node_id);
+            warn!("Cannot find node id {:?}", node_id);
             result_value.status = Some(StatusCode::BadNodeIdUnknown.bits());
         }
         result_value
     }
 
+    /// Returns the attribute's value, or the part of it that the index range selects, with the
+    /// structures in it encoded with the data encoding. A data encoding can only be asked for
+    /// the Value attribute.
+    fn read_attribute_value(address_space: &AddressSpace, attribute_id: AttributeId, attribute: &DataValue, node_to_read: &ReadValueId) -> Result<Option<Variant>, StatusCode> {
+        let data_encoding = DataEncoding::from_browse_name(&node_to_read.data_encoding)?;
+        if data_encoding.is_some() && attribute_id != AttributeId::Value {
+            return Err(StatusCode::BadDataEncodingInvalid);
+        }
+        let value = if node_to_read.index_range.is_null() {
+            attribute.value.clone()
+        } else {
+            Some(Self::read_index_range(attribute, &node_to_read.index_range)?)
+        };
+        match (value, data_encoding) {
+            (Some(value), Some(data_encoding)) => address_space.encode_value(&value, data_encoding).map(Some),
+            (value, _) => Ok(value)
+        }
+    }
+
+    /// Returns the part of the attribute's value that the index range selects
+    fn read_index_range(attribute: &DataValue, index_range: &UAString) -> Result<Variant, StatusCode> {
+        let index_range = index_range.as_ref().parse::<NumericRange>().map_err(|_| StatusCode::BadIndexRangeInvalid)?;
+        match attribute.value {
+            Some(ref value) => value.range_of(&index_range),
+            None => Err(StatusCode::BadIndexRangeNoData)
+        }
+    }
+
     fn is_readable(node: &NodeType) -> bool {
         // Check for access level, user access level
         if let NodeType::Variable(ref node) = *node {
@@ -149,22 +627,39 @@ impl AttributeService {
         true
     }
 
-    fn write_node_value(address_space: &mut AddressSpace, node_to_write: &WriteValue) -> StatusCode {
-        if let Some(node) = address_space.find_node_mut(&node_to_write.node_id) {
+    fn write_node_value(address_space: &mut AddressSpace, node_id: &NodeId, node_to_write: &WriteValue) -> StatusCode {
+        let status_code = Self::write_node_attribute(address_space, node_id, node_to_write);
+        if status_code.is_good() && node_to_write.attribute_id == AttributeId::Value as u32 {
+            address_space.record_historical_value(node_id);
+            address_space.evaluate_limit_alarms(node_id);
+        }
+        status_code
+    }
+
+    fn write_node_attribute(address_space: &mut AddressSpace, node_id: &NodeId, node_to_write: &WriteValue) -> StatusCode {
+        if let Some(node) = address_space.find_node_mut(node_id) {
             if let Ok(attribute_id) = AttributeId::from_u32(node_to_write.attribute_id) {
                 let is_writable = Self::is_writable(&node, attribute_id);
                 if !is_writable {
                     StatusCode::BadNotWritable
-                } else if !node_to_write.index_range.is_null() {
-                    // Index ranges are not supported
-                    StatusCode::BadWriteNotSupported
                 } else {
-                    let node = node.as_mut_node();
-                    let result = node.set_attribute(attribute_id, node_to_write.value.clone());
-                    if result.is_err() {
-                        result.unwrap_err()
+                    // The value is written whole, or into the part of the existing value in the index range
+                    let value = if node_to_write.index_range.is_null() {
+                        Ok(node_to_write.value.clone())
                     } else {
-                        StatusCode::Good
+                        Self::write_index_range(node, attribute_id, node_to_write)
+                    };
+                    match value {
+                        Ok(value) => {
+                            let node = node.as_mut_node();
+                            let result = node.set_attribute(attribute_id, value);
+                            if result.is_err() {
+                                result.unwrap_err()
+                            } else {
+                                StatusCode::Good
+                            }
+                        }
+                        Err(status_code) => status_code
                     }
                 }
             } else {
@@ -172,11 +667,25 @@ impl AttributeService {
                 StatusCode::BadAttributeIdInvalid
             }
         } else {
-            warn!("Cannot find node id {:?}", node_to_write.node_id);
+            warn!("Cannot find node id {:?}", node_id);
             StatusCode::BadNodeIdUnknown
         }
     }
 
+    /// Returns the attribute's existing value with the part that the index range selects replaced
+    /// by the value to write
+    fn write_index_range(node: &NodeType, attribute_id: AttributeId, node_to_write: &WriteValue) -> Result<DataValue, StatusCode> {
+        let index_range = node_to_write.index_range.as_ref().parse::<NumericRange>().map_err(|_| StatusCode::BadIndexRangeInvalid)?;
+        let mut value = node.as_node().find_attribute(attribute_id)
+            .and_then(|data_value| data_value.value)
+            .ok_or(StatusCode::BadIndexRangeNoData)?;
+        let range_value = node_to_write.value.value.as_ref().ok_or(StatusCode::BadTypeMismatch)?;
+        value.set_range_of(&index_range, range_value)?;
+        let mut data_value = node_to_write.value.clone();
+        data_value.value = Some(value);
+        Ok(data_value)
+    }
+
     fn is_writable(node: &NodeType, attribute_id: AttributeId) -> bool {
         use opcua_types::WriteMask;
 
@@ -13,6 +13,8 @@ use crate::{
         discovery::DiscoveryService,
         method::MethodService,
         monitored_item::MonitoredItemService,
+        node_management::NodeManagementService,
+        query::QueryService,
         session::SessionService,
         subscription::SubscriptionService,
         view::ViewService,
@@ -38,6 +40,10 @@ pub struct MessageHandler {
     method_service: MethodService,
     /// MonitoredItem service
     monitored_item_service: MonitoredItemService,
+    /// NodeManagement service
+    node_management_service: NodeManagementService,
+    /// Query service
+    query_service: QueryService,
     /// Session service
     session_service: SessionService,
     /// Subscription service
@@ -57,19 +63,24 @@ impl MessageHandler {
             discovery_service: DiscoveryService::new(),
             method_service: MethodService::new(),
             monitored_item_service: MonitoredItemService::new(),
+            node_management_service: NodeManagementService::new(),
+            query_service: QueryService::new(),
             session_service: SessionService::new(),
             view_service: ViewService::new(),
             subscription_service: SubscriptionService::new(),
         }
     }
 
+    /// Returns the session that messages are handled for
+    pub fn session(&self) -> Arc<RwLock<Session>> {
+        self.session.clone()
+    }
+
     /// Validates the request header information to ensure it is valid for the session.
     ///
     /// The request header should contain the session authentication token issued during a
     /// CreateSession or the request is invalid. An invalid token can cause the session to close.
     fn validate_request(&self, session: &mut Session, request_header: &RequestHeader) -> Result<(), SupportedMessage> {
-        // TODO if session's token is null, it might be possible to retrieve session state from a
-        //  previously closed session and reassociate it if the authentication token is recognized
         if session.authentication_token != request_header.authentication_token {
             // Session should terminate
             session.terminate_session = true;
@@ -79,7 +90,61 @@ impl MessageHandler {
         }
     }
 
+    /// Reactivates the detached session that the authentication token of the request belongs to,
+    /// if it does not belong to the current session. The detached session is activated on the
+    /// secure channel of the current session and replaces it if the activation succeeds, otherwise
+    /// it remains detached.
+    fn reactivate_detached_session(&mut self, request: &ActivateSessionRequest) -> Result<Option<SupportedMessage>, StatusCode> {
+        let mut server_state = trace_write_lock_unwrap!(self.server_state);
+        let secure_channel = {
+            let session = trace_read_lock_unwrap!(self.session);
+            if session.authentication_token == request.request_header.authentication_token {
+                return Ok(None);
+            }
+            session.secure_channel.clone()
+        };
+        let detached_session = if let Some(detached_session) = server_state.take_detached_session(&request.request_header.authentication_token) {
+            detached_session
+        } else {
+            return Ok(None);
+        };
+
+        let response = {
+            let mut session = trace_write_lock_unwrap!(detached_session);
+            let service_result = {
+                let secure_channel = trace_read_lock_unwrap!(secure_channel);
+                SessionService::verify_reactivating_client(&session, &secure_channel)
+            };
+            if service_result.is_bad() {
+                Ok(ServiceFault::new_supported_message(&request.request_header, service_result))
+            } else {
+                let previous_secure_channel = std::mem::replace(&mut session.secure_channel, secure_channel);
+                let response = self.session_service.activate_session(&mut server_state, &mut session, request);
+                if let Ok(SupportedMessage::ActivateSessionResponse(_)) = response {
+                    session.attach();
+                } else {
+                    session.secure_channel = previous_secure_channel;
+                }
+                response
+            }
+        };
+        if let Ok(SupportedMessage::ActivateSessionResponse(_)) = response {
+            info!("Detached session has been reactivated on a new connection");
+            self.session = detached_session;
+        } else {
+            server_state.detach_session(detached_session);
+        }
+        response.map(Some)
+    }
+
     pub fn handle_message(&mut self, request_id: u32, message: SupportedMessage) -> Result<Option<SupportedMessage>, StatusCode> {
+        // A client that lost its connection can reactivate its session on a new one
+        if let SupportedMessage::ActivateSessionRequest(ref request) = message {
+            if let Some(response) = self.reactivate_detached_session(request)? {
+                return Ok(Some(response));
+            }
+        }
+
         // Note address space has to be locked before server_state because of deadlock in address_space.rs
         // or other vars tied to state that will happen the other way around.
         let mut server_state = trace_write_lock_unwrap!(self.server_state);
@@ -160,6 +225,19 @@ impl MessageHandler {
                     Some(self.subscription_service.republish(&mut session, &request)?)
                 }
             }
+            SupportedMessage::TransferSubscriptionsRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    // The address space is released before the other sessions are locked, because
+                    // their subscription timers lock them and then the address space.
+                    drop(address_space);
+                    let other_sessions = server_state.sessions().into_iter()
+                        .filter(|other_session| !Arc::ptr_eq(other_session, &self.session))
+                        .collect::<Vec<Arc<RwLock<Session>>>>();
+                    Some(self.subscription_service.transfer_subscriptions(&server_state, &mut session, &other_sessions, &request)?)
+                }
+            }
             SupportedMessage::BrowseRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
@@ -181,32 +259,74 @@ impl MessageHandler {
                     Some(self.view_service.translate_browse_paths_to_node_ids(&address_space, &request)?)
                 }
             }
+            SupportedMessage::RegisterNodesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.view_service.register_nodes(&mut session, &address_space, &request)?)
+                }
+            }
+            SupportedMessage::UnregisterNodesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.view_service.unregister_nodes(&mut session, &request)?)
+                }
+            }
+            SupportedMessage::QueryFirstRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.query_service.query_first(&mut session, &address_space, &request)?)
+                }
+            }
+            SupportedMessage::QueryNextRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.query_service.query_next(&mut session, &request)?)
+                }
+            }
             SupportedMessage::ReadRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
                 } else {
-                    Some(self.attribute_service.read(&address_space, &request)?)
+                    Some(self.attribute_service.read(&session, &address_space, &request)?)
                 }
             }
             SupportedMessage::WriteRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
                 } else {
-                    Some(self.attribute_service.write(&mut address_space, &request)?)
+                    Some(self.attribute_service.write(&session, &mut address_space, &request)?)
+                }
+            }
+            SupportedMessage::HistoryReadRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.attribute_service.history_read(&server_state, &mut session, &address_space, &request)?)
+                }
+            }
+            SupportedMessage::HistoryUpdateRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.attribute_service.history_update(&server_state, &address_space, &request)?)
                 }
             }
             SupportedMessage::CreateMonitoredItemsRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
                 } else {
-                    Some(self.monitored_item_service.create_monitored_items(&mut session, &request)?)
+                    Some(self.monitored_item_service.create_monitored_items(&mut session, &address_space, &request)?)
                 }
             }
             SupportedMessage::ModifyMonitoredItemsRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
                 } else {
-                    Some(self.monitored_item_service.modify_monitored_items(&mut session, &request)?)
+                    Some(self.monitored_item_service.modify_monitored_items(&mut session, &address_space, &request)?)
                 }
             }
             SupportedMessage::DeleteMonitoredItemsRequest(request) => {
@@ -216,11 +336,53 @@ impl MessageHandler {
                     Some(self.monitored_item_service.delete_monitored_items(&mut session, &request)?)
                 }
             }
+            SupportedMessage::SetMonitoringModeRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.monitored_item_service.set_monitoring_mode(&mut session, &request)?)
+                }
+            }
+            SupportedMessage::SetTriggeringRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.monitored_item_service.set_triggering(&mut session, &request)?)
+                }
+            }
             SupportedMessage::CallRequest(request) => {
                 if let Err(response) = self.validate_request(&mut session, &request.request_header) {
                     Some(response)
                 } else {
-                    Some(self.method_service.call(&address_space, &server_state, &mut session, &request)?)
+                    Some(self.method_service.call(&mut address_space, &server_state, &mut session, &request)?)
+                }
+            }
+            SupportedMessage::AddNodesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.node_management_service.add_nodes(&server_state, &session, &mut address_space, &request)?)
+                }
+            }
+            SupportedMessage::AddReferencesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.node_management_service.add_references(&server_state, &session, &mut address_space, &request)?)
+                }
+            }
+            SupportedMessage::DeleteNodesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.node_management_service.delete_nodes(&server_state, &session, &mut address_space, &request)?)
+                }
+            }
+            SupportedMessage::DeleteReferencesRequest(request) => {
+                if let Err(response) = self.validate_request(&mut session, &request.request_header) {
+                    Some(response)
+                } else {
+                    Some(self.node_management_service.delete_references(&server_state, &session, &mut address_space, &request)?)
                 }
             }
             _ => {
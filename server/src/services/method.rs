@@ -22,7 +22,7 @@ impl MethodService {
         MethodService {}
     }
 
-    pub fn call(&self, address_space: &AddressSpace, server_state: &ServerState, session: &mut Session, request: &CallRequest) -> Result<SupportedMessage, StatusCode> {
+    pub fn call(&self, address_space: &mut AddressSpace, server_state: &ServerState, session: &mut Session, request: &CallRequest) -> Result<SupportedMessage, StatusCode> {
         if let Some(ref calls) = request.methods_to_call {
             if calls.len() >= constants::MAX_METHOD_CALLS {
                 return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
@@ -30,7 +30,7 @@ impl MethodService {
                 let results: Vec<CallMethodResult> = calls.iter().map(|request| {
                     trace!("Calling to {:?} on {:?}", request.method_id, request.object_id);
                     // Call the method via whatever is registered in the address space
-                    match address_space.call_method(server_state, session, request) {
+                    match address_space.call_method_mut(server_state, session, request) {
                         Ok(response) => response,
                         Err(status_code) => {
                             // Call didn't work for some reason
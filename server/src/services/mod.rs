@@ -15,6 +15,8 @@ pub mod attribute;
 pub mod discovery;
 pub mod method;
 pub mod monitored_item;
+pub mod node_management;
+pub mod query;
 pub mod session;
 pub mod subscription;
 pub mod view;
@@ -4,9 +4,10 @@ use opcua_types::*;
 use opcua_types::status_code::StatusCode;
 use opcua_types::service_types::*;
 
-use crate::{session::Session, services::Service};
+use crate::{address_space::AddressSpace, session::Session, services::Service};
 
-/// The monitored item service. Allows client to create, modify and delete monitored items on a subscription.
+/// The monitored item service. Allows client to create, modify and delete monitored items on a subscription,
+/// to set their monitoring mode and to link items that trigger others to report.
 pub(crate) struct MonitoredItemService;
 
 impl Service for MonitoredItemService {}
@@ -16,11 +17,11 @@ impl MonitoredItemService {
         MonitoredItemService {}
     }
 
-    pub fn create_monitored_items(&self, session: &mut Session, request: &CreateMonitoredItemsRequest) -> Result<SupportedMessage, StatusCode> {
+    pub fn create_monitored_items(&self, session: &mut Session, address_space: &AddressSpace, request: &CreateMonitoredItemsRequest) -> Result<SupportedMessage, StatusCode> {
         if let Some(ref items_to_create) = request.items_to_create {
             // Find subscription and add items to it
             if let Some(subscription) = session.subscriptions.get_mut(request.subscription_id) {
-                let results = Some(subscription.create_monitored_items(request.timestamps_to_return, items_to_create));
+                let results = Some(subscription.create_monitored_items(address_space, request.timestamps_to_return, items_to_create));
                 let response = CreateMonitoredItemsResponse {
                     response_header: ResponseHeader::new_good(&request.request_header),
                     results,
@@ -37,12 +38,12 @@ impl MonitoredItemService {
         }
     }
 
-    pub fn modify_monitored_items(&self, session: &mut Session, request: &ModifyMonitoredItemsRequest) -> Result<SupportedMessage, StatusCode> {
+    pub fn modify_monitored_items(&self, session: &mut Session, address_space: &AddressSpace, request: &ModifyMonitoredItemsRequest) -> Result<SupportedMessage, StatusCode> {
         if let Some(ref items_to_modify) = request.items_to_modify {
             // Find subscription and modify items in it
             let subscription_id = request.subscription_id;
             if let Some(subscription) = session.subscriptions.get_mut(subscription_id) {
-                let results = Some(subscription.modify_monitored_items(request.timestamps_to_return, items_to_modify));
+                let results = Some(subscription.modify_monitored_items(address_space, request.timestamps_to_return, items_to_modify));
                 let response = ModifyMonitoredItemsResponse {
                     response_header: ResponseHeader::new_good(&request.request_header),
                     results,
@@ -81,4 +82,56 @@ impl MonitoredItemService {
             Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo))
         }
     }
-}
\ No newline at end of file
+
+    pub fn set_monitoring_mode(&self, session: &mut Session, request: &SetMonitoringModeRequest) -> Result<SupportedMessage, StatusCode> {
+        match request.monitored_item_ids {
+            Some(ref monitored_item_ids) if !monitored_item_ids.is_empty() => {
+                // Find subscription and set the monitoring mode of its items
+                if let Some(subscription) = session.subscriptions.get_mut(request.subscription_id) {
+                    let results = Some(subscription.set_monitoring_mode(request.monitoring_mode, monitored_item_ids));
+                    let response = SetMonitoringModeResponse {
+                        response_header: ResponseHeader::new_good(&request.request_header),
+                        results,
+                        diagnostic_infos: None,
+                    };
+                    Ok(response.into())
+                } else {
+                    // No matching subscription
+                    Ok(self.service_fault(&request.request_header, StatusCode::BadSubscriptionIdInvalid))
+                }
+            }
+            _ => {
+                // No items to change so nothing to do
+                Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo))
+            }
+        }
+    }
+
+    pub fn set_triggering(&self, session: &mut Session, request: &SetTriggeringRequest) -> Result<SupportedMessage, StatusCode> {
+        let links_to_add = request.links_to_add.as_ref().map(|links| links.as_slice()).unwrap_or(&[]);
+        let links_to_remove = request.links_to_remove.as_ref().map(|links| links.as_slice()).unwrap_or(&[]);
+        if links_to_add.is_empty() && links_to_remove.is_empty() {
+            // No links to change so nothing to do
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+        }
+        // Find subscription and link its items
+        if let Some(subscription) = session.subscriptions.get_mut(request.subscription_id) {
+            match subscription.set_triggering(request.triggering_item_id, links_to_add, links_to_remove) {
+                Ok((add_results, remove_results)) => {
+                    let response = SetTriggeringResponse {
+                        response_header: ResponseHeader::new_good(&request.request_header),
+                        add_results: if request.links_to_add.is_some() { Some(add_results) } else { None },
+                        add_diagnostic_infos: None,
+                        remove_results: if request.links_to_remove.is_some() { Some(remove_results) } else { None },
+                        remove_diagnostic_infos: None,
+                    };
+                    Ok(response.into())
+                }
+                Err(status_code) => Ok(self.service_fault(&request.request_header, status_code))
+            }
+        } else {
+            // No matching subscription
+            Ok(self.service_fault(&request.request_header, StatusCode::BadSubscriptionIdInvalid))
+        }
+    }
+}
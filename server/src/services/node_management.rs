@@ -0,0 +1,404 @@
+use std::result::Result;
+
+use opcua_types::*;
+use opcua_types::node_ids::{ObjectId, ReferenceTypeId};
+use opcua_types::status_code::StatusCode;
+use opcua_types::service_types::*;
+
+use crate::{
+    address_space::{
+        AddressSpace,
+        node::NodeType,
+        types::{Object, ObjectType, Variable, VariableType, Method, ReferenceType, DataType, View},
+    },
+    services::Service,
+    session::Session,
+    state::ServerState,
+    constants,
+};
+
+/// The node management service. Allows clients to add and delete nodes and references in the
+/// address space. The service must be enabled for the user on the endpoint through the
+/// `node_management_user_token_ids` configuration setting.
+pub(crate) struct NodeManagementService;
+
+impl Service for NodeManagementService {}
+
+impl NodeManagementService {
+    pub fn new() -> NodeManagementService {
+        NodeManagementService {}
+    }
+
+    /// Implements the AddNodes service
+    pub fn add_nodes(&self, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, request: &AddNodesRequest) -> Result<SupportedMessage, StatusCode> {
+        if let Err(status_code) = Self::validate_node_management(server_state, session, &request.nodes_to_add) {
+            return Ok(self.service_fault(&request.request_header, status_code));
+        }
+        let decoding_limits = {
+            let config = trace_read_lock_unwrap!(server_state.config);
+            config.decoding_limits()
+        };
+        let nodes_to_add = request.nodes_to_add.as_ref().unwrap();
+        let results = nodes_to_add.iter().map(|node_to_add| {
+            match Self::add_node(address_space, node_to_add, &decoding_limits) {
+                Ok(added_node_id) => AddNodesResult {
+                    status_code: StatusCode::Good,
+                    added_node_id,
+                },
+                Err(status_code) => {
+                    error!("AddNodes failed to add {:?} with status code {}", node_to_add.browse_name, status_code);
+                    AddNodesResult {
+                        status_code,
+                        added_node_id: NodeId::null(),
+                    }
+                }
+            }
+        }).collect();
+        let response = AddNodesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+        Ok(response.into())
+    }
+
+    /// Implements the AddReferences service
+    pub fn add_references(&self, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, request: &AddReferencesRequest) -> Result<SupportedMessage, StatusCode> {
+        if let Err(status_code) = Self::validate_node_management(server_state, session, &request.references_to_add) {
+            return Ok(self.service_fault(&request.request_header, status_code));
+        }
+        let references_to_add = request.references_to_add.as_ref().unwrap();
+        let results = references_to_add.iter().map(|reference_to_add| {
+            match Self::add_reference(address_space, reference_to_add) {
+                Ok(_) => StatusCode::Good,
+                Err(status_code) => {
+                    error!("AddReferences failed to add reference from {:?} with status code {}", reference_to_add.source_node_id, status_code);
+                    status_code
+                }
+            }
+        }).collect();
+        let response = AddReferencesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+        Ok(response.into())
+    }
+
+    /// Implements the DeleteNodes service
+    pub fn delete_nodes(&self, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, request: &DeleteNodesRequest) -> Result<SupportedMessage, StatusCode> {
+        if let Err(status_code) = Self::validate_node_management(server_state, session, &request.nodes_to_delete) {
+            return Ok(self.service_fault(&request.request_header, status_code));
+        }
+        let nodes_to_delete = request.nodes_to_delete.as_ref().unwrap();
+        let results = nodes_to_delete.iter().map(|node_to_delete| {
+            if address_space.delete_node(&node_to_delete.node_id, node_to_delete.delete_target_references) {
+                StatusCode::Good
+            } else {
+                error!("DeleteNodes cannot delete node {:?} because it does not exist", node_to_delete.node_id);
+                StatusCode::BadNodeIdUnknown
+            }
+        }).collect();
+        let response = DeleteNodesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+        Ok(response.into())
+    }
+
+    /// Implements the DeleteReferences service
+    pub fn delete_references(&self, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, request: &DeleteReferencesRequest) -> Result<SupportedMessage, StatusCode> {
+        if let Err(status_code) = Self::validate_node_management(server_state, session, &request.references_to_delete) {
+            return Ok(self.service_fault(&request.request_header, status_code));
+        }
+        let references_to_delete = request.references_to_delete.as_ref().unwrap();
+        let results = references_to_delete.iter().map(|reference_to_delete| {
+            match Self::delete_reference(address_space, reference_to_delete) {
+                Ok(_) => StatusCode::Good,
+                Err(status_code) => {
+                    error!("DeleteReferences failed to delete reference from {:?} with status code {}", reference_to_delete.source_node_id, status_code);
+                    status_code
+                }
+            }
+        }).collect();
+        let response = DeleteReferencesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+        Ok(response.into())
+    }
+
+    /// Tests that the session's user is permitted to use the node management services on the
+    /// endpoint, and that the number of items in the request is acceptable.
+    fn validate_node_management<T>(server_state: &ServerState, session: &Session, items: &Option<Vec<T>>) -> Result<(), StatusCode> {
+        if !Self::is_node_management_permitted(server_state, session) {
+            Err(StatusCode::BadUserAccessDenied)
+        } else if let Some(ref items) = items {
+            if items.is_empty() {
+                Err(StatusCode::BadNothingToDo)
+            } else if items.len() > constants::MAX_NODES_PER_NODE_MANAGEMENT {
+                Err(StatusCode::BadTooManyOperations)
+            } else {
+                Ok(())
+            }
+        } else {
+            Err(StatusCode::BadNothingToDo)
+        }
+    }
+
+    /// Tests if the user token that the session was activated with is allowed to manage nodes
+    /// on the session's endpoint.
+    fn is_node_management_permitted(server_state: &ServerState, session: &Session) -> bool {
+        if let Some(ref user_token_id) = session.user_token_id {
+            let (security_policy, security_mode) = {
+                let secure_channel = trace_read_lock_unwrap!(session.secure_channel);
+                (secure_channel.security_policy(), secure_channel.security_mode())
+            };
+            let config = trace_read_lock_unwrap!(server_state.config);
+            if let Some(endpoint) = config.find_endpoint(session.endpoint_url.as_ref(), security_policy, security_mode) {
+                if endpoint.supports_node_management(user_token_id) {
+                    true
+                } else {
+                    error!("User token id {} is not permitted to manage nodes on endpoint {}", user_token_id, endpoint.path);
+                    false
+                }
+            } else {
+                error!("Cannot find endpoint for session to test node management permission");
+                false
+            }
+        } else {
+            error!("Session is not activated with a user token, so node management is not permitted");
+            false
+        }
+    }
+
+    /// Tests if the reference type is a valid, concrete reference type. If `hierarchical` is true
+    /// the type must also be a subtype of HierarchicalReferences.
+    fn validate_reference_type(address_space: &AddressSpace, reference_type_id: &NodeId, hierarchical: bool) -> Result<NodeId, StatusCode> {
+        if let Some(NodeType::ReferenceType(ref reference_type)) = address_space.find_node(reference_type_id) {
+            if reference_type.is_abstract() {
+                error!("Reference type {:?} is abstract", reference_type_id);
+                Err(StatusCode::BadReferenceNotAllowed)
+            } else if hierarchical && !address_space.reference_type_matches(&ReferenceTypeId::HierarchicalReferences.into(), reference_type_id, true) {
+                error!("Reference type {:?} is not hierarchical", reference_type_id);
+                Err(StatusCode::BadReferenceNotAllowed)
+            } else {
+                Ok(reference_type_id.clone())
+            }
+        } else {
+            Err(StatusCode::BadReferenceTypeIdInvalid)
+        }
+    }
+
+    /// Tests that the type definition is suitable for the node class of the node being added.
+    /// Objects and variables must have a concrete object type and variable type respectively.
+    /// Other node classes must not have a type definition.
+    fn validate_type_definition(address_space: &AddressSpace, node_class: NodeClass, type_definition: &ExpandedNodeId) -> Result<Option<NodeId>, StatusCode> {
+        match node_class {
+            NodeClass::Object | NodeClass::Variable => {
+                if type_definition.is_null() || type_definition.server_index != 0 {
+                    return Err(StatusCode::BadTypeDefinitionInvalid);
+                }
+                let type_definition_id = &type_definition.node_id;
+                let valid = match address_space.find_node(type_definition_id) {
+                    Some(NodeType::ObjectType(ref object_type)) => node_class == NodeClass::Object && !object_type.is_abstract(),
+                    Some(NodeType::VariableType(ref variable_type)) => node_class == NodeClass::Variable && !variable_type.is_abstract(),
+                    _ => false
+                };
+                if valid {
+                    Ok(Some(type_definition_id.clone()))
+                } else {
+                    error!("Type definition {:?} is not valid for node class {:?}", type_definition_id, node_class);
+                    Err(StatusCode::BadTypeDefinitionInvalid)
+                }
+            }
+            _ => {
+                if type_definition.is_null() {
+                    Ok(None)
+                } else {
+                    error!("Node class {:?} cannot have a type definition", node_class);
+                    Err(StatusCode::BadTypeDefinitionInvalid)
+                }
+            }
+        }
+    }
+
+    /// Decodes the node attributes extension object into the struct expected for the node class.
+    fn decode_node_attributes<T>(node_attributes: &ExtensionObject, expected_object_id: ObjectId, decoding_limits: &DecodingLimits) -> Result<T, StatusCode> where T: BinaryEncoder<T> {
+        match node_attributes.node_id.as_object_id() {
+            Ok(object_id) if object_id == expected_object_id => {
+                node_attributes.decode_inner::<T>(decoding_limits)
+                    .map_err(|_| StatusCode::BadNodeAttributesInvalid)
+            }
+            _ => {
+                error!("Node attributes are not the expected type {:?}", expected_object_id);
+                Err(StatusCode::BadNodeAttributesInvalid)
+            }
+        }
+    }
+
+    /// Creates a node of the requested class from the node attributes
+    fn create_node(node_id: &NodeId, item: &AddNodesItem, decoding_limits: &DecodingLimits) -> Result<NodeType, StatusCode> {
+        let browse_name = item.browse_name.clone();
+        let node_attributes = &item.node_attributes;
+        let node: Result<NodeType, ()> = match item.node_class {
+            NodeClass::Object => {
+                let attributes = Self::decode_node_attributes::<ObjectAttributes>(node_attributes, ObjectId::ObjectAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                Object::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::Variable => {
+                let attributes = Self::decode_node_attributes::<VariableAttributes>(node_attributes, ObjectId::VariableAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                Variable::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::Method => {
+                let attributes = Self::decode_node_attributes::<MethodAttributes>(node_attributes, ObjectId::MethodAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                Method::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::ObjectType => {
+                let attributes = Self::decode_node_attributes::<ObjectTypeAttributes>(node_attributes, ObjectId::ObjectTypeAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                ObjectType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::VariableType => {
+                let attributes = Self::decode_node_attributes::<VariableTypeAttributes>(node_attributes, ObjectId::VariableTypeAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                VariableType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::ReferenceType => {
+                let attributes = Self::decode_node_attributes::<ReferenceTypeAttributes>(node_attributes, ObjectId::ReferenceTypeAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                ReferenceType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::DataType => {
+                let attributes = Self::decode_node_attributes::<DataTypeAttributes>(node_attributes, ObjectId::DataTypeAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                DataType::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::View => {
+                let attributes = Self::decode_node_attributes::<ViewAttributes>(node_attributes, ObjectId::ViewAttributes_Encoding_DefaultBinary, decoding_limits)?;
+                View::from_attributes(node_id, browse_name, attributes).map(|n| n.into())
+            }
+            NodeClass::Unspecified => {
+                panic!("Node class should have been validated");
+            }
+        };
+        node.map_err(|_| StatusCode::BadNodeAttributesInvalid)
+    }
+
+    fn add_node(address_space: &mut AddressSpace, item: &AddNodesItem, decoding_limits: &DecodingLimits) -> Result<NodeId, StatusCode> {
+        // The requested node id must be local, and not already in use
+        let requested_new_node_id = &item.requested_new_node_id;
+        let node_id = if requested_new_node_id.server_index != 0 || !requested_new_node_id.namespace_uri.is_null() {
+            error!("Requested node id {:?} is not local to this server", requested_new_node_id);
+            return Err(StatusCode::BadNodeIdRejected);
+        } else if requested_new_node_id.is_null() {
+            NodeId::next_numeric()
+        } else if address_space.node_exists(&requested_new_node_id.node_id) {
+            return Err(StatusCode::BadNodeIdExists);
+        } else {
+            requested_new_node_id.node_id.clone()
+        };
+
+        if item.node_class == NodeClass::Unspecified {
+            return Err(StatusCode::BadNodeClassInvalid);
+        }
+
+        // The parent must exist and the reference to it must be hierarchical
+        let parent_node_id = &item.parent_node_id;
+        if parent_node_id.server_index != 0 || !address_space.node_exists(&parent_node_id.node_id) {
+            return Err(StatusCode::BadParentNodeIdInvalid);
+        }
+        let parent_node_id = &parent_node_id.node_id;
+        let reference_type_id = Self::validate_reference_type(address_space, &item.reference_type_id, true)?;
+
+        // Browse name must be supplied and unique amongst the parent's children
+        if item.browse_name.is_null() {
+            return Err(StatusCode::BadBrowseNameInvalid);
+        }
+        if let Some(children) = address_space.find_references_from(parent_node_id, Some((ReferenceTypeId::HierarchicalReferences.into(), true))) {
+            let duplicate = children.iter().any(|r| {
+                if let Some(node) = address_space.find_node(&r.node_id) {
+                    node.as_node().browse_name() == item.browse_name
+                } else {
+                    false
+                }
+            });
+            if duplicate {
+                return Err(StatusCode::BadBrowseNameDuplicated);
+            }
+        }
+
+        let type_definition_id = Self::validate_type_definition(address_space, item.node_class, &item.type_definition)?;
+        let node = Self::create_node(&node_id, item, decoding_limits)?;
+
+        address_space.insert(node, None);
+        address_space.insert_reference(parent_node_id, &node_id, reference_type_id);
+        if let Some(ref type_definition_id) = type_definition_id {
+            address_space.insert_reference(&node_id, type_definition_id, ReferenceTypeId::HasTypeDefinition);
+        }
+
+        Ok(node_id)
+    }
+
+    fn add_reference(address_space: &mut AddressSpace, item: &AddReferencesItem) -> Result<(), StatusCode> {
+        if !address_space.node_exists(&item.source_node_id) {
+            return Err(StatusCode::BadSourceNodeIdInvalid);
+        }
+        let reference_type_id = Self::validate_reference_type(address_space, &item.reference_type_id, false)?;
+        if item.target_node_id.server_index != 0 || !item.target_server_uri.is_null() {
+            return Err(StatusCode::BadReferenceLocalOnly);
+        }
+        let target_node_id = &item.target_node_id.node_id;
+        let target_node_class = if let Some(node) = address_space.find_node(target_node_id) {
+            node.as_node().node_class()
+        } else {
+            return Err(StatusCode::BadTargetNodeIdInvalid);
+        };
+        if item.target_node_class != NodeClass::Unspecified && item.target_node_class != target_node_class {
+            return Err(StatusCode::BadNodeClassInvalid);
+        }
+        if item.source_node_id == *target_node_id {
+            return Err(StatusCode::BadInvalidSelfReference);
+        }
+
+        let (node_id_from, node_id_to) = if item.is_forward {
+            (&item.source_node_id, target_node_id)
+        } else {
+            (target_node_id, &item.source_node_id)
+        };
+        if address_space.has_reference(node_id_from, reference_type_id.clone(), node_id_to) {
+            Err(StatusCode::BadDuplicateReferenceNotAllowed)
+        } else {
+            address_space.insert_reference(node_id_from, node_id_to, reference_type_id);
+            Ok(())
+        }
+    }
+
+    fn delete_reference(address_space: &mut AddressSpace, item: &DeleteReferencesItem) -> Result<(), StatusCode> {
+        if !address_space.node_exists(&item.source_node_id) {
+            return Err(StatusCode::BadSourceNodeIdInvalid);
+        }
+        if !address_space.is_reference_type(&item.reference_type_id) {
+            return Err(StatusCode::BadReferenceTypeIdInvalid);
+        }
+        let reference_type_id = &item.reference_type_id;
+        if item.target_node_id.server_index != 0 {
+            return Err(StatusCode::BadReferenceLocalOnly);
+        }
+        let target_node_id = &item.target_node_id.node_id;
+
+        let (node_id_from, node_id_to) = if item.is_forward {
+            (&item.source_node_id, target_node_id)
+        } else {
+            (target_node_id, &item.source_node_id)
+        };
+        let mut deleted = address_space.delete_reference(node_id_from, node_id_to, reference_type_id.clone());
+        if item.delete_bidirectional {
+            // Also delete the reference going the opposite way, if there is one
+            deleted |= address_space.delete_reference(node_id_to, node_id_from, reference_type_id.clone());
+        }
+        if deleted {
+            Ok(())
+        } else {
+            Err(StatusCode::BadNotFound)
+        }
+    }
+}
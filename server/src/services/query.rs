@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::result::Result;
+use std::sync::{Arc, Mutex};
+
+use opcua_types::*;
+use opcua_types::node_ids::ReferenceTypeId;
+use opcua_types::service_types::*;
+use opcua_types::status_code::StatusCode;
+
+use crate::{
+    address_space::AddressSpace,
+    constants,
+    continuation_point::QueryContinuationPoint,
+    events::content_filter,
+    services::Service,
+    session::Session,
+};
+
+/// The query service. Allows the client to find the instances of types in the address space
+/// that pass a content filter, and to read the values of their attributes and of related nodes.
+pub(crate) struct QueryService;
+
+impl Service for QueryService {}
+
+impl QueryService {
+    pub fn new() -> QueryService {
+        QueryService {}
+    }
+
+    pub fn query_first(&self, session: &mut Session, address_space: &AddressSpace, request: &QueryFirstRequest) -> Result<SupportedMessage, StatusCode> {
+        if !request.view.view_id.is_null() {
+            // Views are not supported
+            info!("Query request ignored because view was specified (views not supported)");
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadViewIdUnknown));
+        }
+        let node_types = match request.node_types {
+            Some(ref node_types) if !node_types.is_empty() => node_types,
+            _ => {
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        if node_types.len() > constants::MAX_NODE_TYPES_PER_QUERY {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
+        }
+
+        // The node types must be valid before anything is queried
+        let parsing_results = node_types.iter()
+            .map(|node_type| Self::parse_node_type(address_space, node_type))
+            .collect::<Vec<ParsingResult>>();
+        if parsing_results.iter().any(|result| result.status_code.is_bad()) {
+            let response = QueryFirstResponse {
+                response_header: ResponseHeader::new_service_result(&request.request_header, StatusCode::BadInvalidArgument),
+                query_data_sets: None,
+                continuation_point: ByteString::null(),
+                parsing_results: Some(parsing_results),
+                diagnostic_infos: None,
+                filter_result: ContentFilterResult {
+                    element_results: None,
+                    element_diagnostic_infos: None,
+                },
+            };
+            return Ok(response.into());
+        }
+
+        let filter_result = match content_filter::validate_node_filter(address_space, &request.filter) {
+            Ok(filter_result) => filter_result,
+            Err((status_code, filter_result)) => {
+                let response = QueryFirstResponse {
+                    response_header: ResponseHeader::new_service_result(&request.request_header, status_code),
+                    query_data_sets: None,
+                    continuation_point: ByteString::null(),
+                    parsing_results: None,
+                    diagnostic_infos: None,
+                    filter_result,
+                };
+                return Ok(response.into());
+            }
+        };
+
+        let query_data_sets = Self::query(address_space, node_types, &request.filter);
+        let (query_data_sets, continuation_point) = Self::query_data_sets_to_return(session, query_data_sets, 0, request.max_data_sets_to_return as usize);
+        let response = QueryFirstResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            query_data_sets: Some(query_data_sets),
+            continuation_point,
+            parsing_results: None,
+            diagnostic_infos: None,
+            filter_result,
+        };
+        Ok(response.into())
+    }
+
+    pub fn query_next(&self, session: &mut Session, request: &QueryNextRequest) -> Result<SupportedMessage, StatusCode> {
+        let continuation_point = match session.find_query_continuation_point(&request.continuation_point) {
+            Some(continuation_point) => continuation_point,
+            None => {
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadContinuationPointInvalid));
+            }
+        };
+        session.remove_query_continuation_point(&continuation_point.id);
+
+        let (query_data_sets, revised_continuation_point) = if request.release_continuation_point {
+            (None, ByteString::null())
+        } else {
+            let query_data_sets = continuation_point.query_data_sets.lock().unwrap().clone();
+            let (query_data_sets, revised_continuation_point) = Self::query_data_sets_to_return(session, query_data_sets, continuation_point.starting_index, continuation_point.max_data_sets_to_return);
+            (Some(query_data_sets), revised_continuation_point)
+        };
+        let response = QueryNextResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            query_data_sets,
+            revised_continuation_point,
+        };
+        Ok(response.into())
+    }
+
+    /// Validates a node type of a query, i.e. that it is an object type or variable type and that
+    /// the data to return from its instances can be read.
+    fn parse_node_type(address_space: &AddressSpace, node_type: &NodeTypeDescription) -> ParsingResult {
+        let status_code = match address_space.find_node(&node_type.type_definition_node.node_id) {
+            Some(node) => match node.as_node().node_class() {
+                NodeClass::ObjectType | NodeClass::VariableType => StatusCode::Good,
+                _ => StatusCode::BadNotTypeDefinition
+            },
+            None => StatusCode::BadNodeIdUnknown
+        };
+        let data_status_codes = node_type.data_to_return.as_ref().map(|data_to_return| {
+            data_to_return.iter().map(|data| {
+                let is_relative_path_valid = data.relative_path.elements.as_ref().map_or(true, |elements| {
+                    elements.iter().all(|element| address_space.is_reference_type(&element.reference_type_id))
+                });
+                if !is_relative_path_valid {
+                    StatusCode::BadReferenceTypeIdInvalid
+                } else if AttributeId::from_u32(data.attribute_id).is_err() {
+                    StatusCode::BadAttributeIdInvalid
+                } else if !data.index_range.is_null() {
+                    // Index ranges are not supported
+                    StatusCode::BadIndexRangeInvalid
+                } else {
+                    StatusCode::Good
+                }
+            }).collect::<Vec<StatusCode>>()
+        });
+        let status_code = if status_code.is_good() && data_status_codes.as_ref().map_or(false, |status_codes| status_codes.iter().any(|status_code| status_code.is_bad())) {
+            StatusCode::BadInvalidArgument
+        } else {
+            status_code
+        };
+        ParsingResult {
+            status_code,
+            data_status_codes,
+            data_diagnostic_infos: None,
+        }
+    }
+
+    /// Finds the instances of the node types that pass the filter, returning a data set for each.
+    /// An instance of more than one of the node types is returned once, for the first of them.
+    fn query(address_space: &AddressSpace, node_types: &[NodeTypeDescription], filter: &ContentFilter) -> Vec<QueryDataSet> {
+        let mut found = HashSet::new();
+        let mut query_data_sets = Vec::new();
+        for node_type in node_types {
+            for (node_id, type_id) in Self::find_instances(address_space, node_type) {
+                if found.contains(&node_id) || !content_filter::evaluate(address_space, filter, &node_id) {
+                    continue;
+                }
+                let values = node_type.data_to_return.as_ref().map(|data_to_return| {
+                    data_to_return.iter().map(|data| Self::data_value(address_space, &node_id, data)).collect()
+                });
+                query_data_sets.push(QueryDataSet {
+                    node_id: ExpandedNodeId::new(node_id.clone()),
+                    type_definition_node: ExpandedNodeId::new(type_id),
+                    values,
+                });
+                found.insert(node_id);
+            }
+        }
+        query_data_sets
+    }
+
+    /// Returns the instances of a node type and of its subtypes if they are included, with the
+    /// type of each instance
+    fn find_instances(address_space: &AddressSpace, node_type: &NodeTypeDescription) -> Vec<(NodeId, NodeId)> {
+        let mut type_ids = vec![node_type.type_definition_node.node_id.clone()];
+        if node_type.include_sub_types {
+            let mut index = 0;
+            while index < type_ids.len() {
+                if let Some(references) = address_space.find_references_from(&type_ids[index], Some((ReferenceTypeId::HasSubtype.into(), false))) {
+                    for reference in references {
+                        if !type_ids.contains(&reference.node_id) {
+                            type_ids.push(reference.node_id);
+                        }
+                    }
+                }
+                index += 1;
+            }
+        }
+        type_ids.iter().flat_map(|type_id| {
+            address_space.find_references_to(type_id, Some((ReferenceTypeId::HasTypeDefinition.into(), false)))
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |reference| (reference.node_id, type_id.clone()))
+        }).collect()
+    }
+
+    /// Returns the value of an attribute of the node that the relative path leads to from the
+    /// instance, or of the instance itself if the path is empty. The value is null if there is no
+    /// such node or attribute.
+    fn data_value(address_space: &AddressSpace, node_id: &NodeId, data: &QueryDataDescription) -> Variant {
+        let has_relative_path = data.relative_path.elements.as_ref().map_or(false, |elements| !elements.is_empty());
+        let node_id = if has_relative_path {
+            match address_space.find_nodes_relative_path(node_id, &data.relative_path) {
+                Ok(ref node_ids) if !node_ids.is_empty() => node_ids[0].clone(),
+                _ => {
+                    return Variant::Empty;
+                }
+            }
+        } else {
+            node_id.clone()
+        };
+        let attribute_id = match AttributeId::from_u32(data.attribute_id) {
+            Ok(attribute_id) => attribute_id,
+            Err(_) => {
+                return Variant::Empty;
+            }
+        };
+        address_space.find_node(&node_id)
+            .and_then(|node| node.as_node().find_attribute(attribute_id))
+            .and_then(|data_value| data_value.value)
+            .unwrap_or(Variant::Empty)
+    }
+
+    /// Returns the data sets to return from the starting index and a continuation point for
+    /// the remainder, if there are more than the maximum
+    fn query_data_sets_to_return(session: &mut Session, query_data_sets: Vec<QueryDataSet>, starting_index: usize, max_data_sets_to_return: usize) -> (Vec<QueryDataSet>, ByteString) {
+        let start = starting_index.min(query_data_sets.len());
+        if max_data_sets_to_return > 0 && query_data_sets.len() - start > max_data_sets_to_return {
+            let end = start + max_data_sets_to_return;
+            let query_data_sets_slice = query_data_sets[start..end].to_vec();
+            // Create a continuation point for the remainder of the result. The point will hold the entire result
+            let continuation_point = ByteString::random(6);
+            session.add_query_continuation_point(QueryContinuationPoint {
+                id: continuation_point.clone(),
+                max_data_sets_to_return,
+                starting_index: end,
+                query_data_sets: Arc::new(Mutex::new(query_data_sets)),
+            });
+            (query_data_sets_slice, continuation_point)
+        } else {
+            (query_data_sets[start..].to_vec(), ByteString::null())
+        }
+    }
+}
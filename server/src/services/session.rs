@@ -4,6 +4,7 @@ use opcua_types::*;
 use opcua_types::status_code::StatusCode;
 use opcua_types::service_types::*;
 
+use opcua_core::comms::secure_channel::SecureChannel;
 use opcua_core::crypto;
 use opcua_core::crypto::SecurityPolicy;
 use opcua_core::crypto::CertificateStore;
@@ -110,7 +111,9 @@ impl SessionService {
                 session.endpoint_url = request.endpoint_url.clone();
                 session.security_policy_uri = security_policy.to_uri().to_string();
                 session.user_identity = None;
+                session.user_token_id = None;
                 session.client_certificate = client_certificate;
+                session.client_application_uri = request.client_description.application_uri.clone();
                 session.session_nonce = server_nonce.clone();
 
                 CreateSessionResponse {
@@ -154,12 +157,28 @@ impl SessionService {
         };
 
         // Authenticate the user identity token
-        if service_result.is_good() {
-            service_result = server_state.authenticate_endpoint(endpoint_url, security_policy, security_mode, &request.user_identity_token);
+        let user_token_id = if service_result.is_good() {
+            match server_state.authenticated_user_token_id(endpoint_url, security_policy, security_mode, &request.user_identity_token) {
+                Ok(user_token_id) => Some(user_token_id),
+                Err(status_code) => {
+                    service_result = status_code;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        // A session whose connection was dropped can only be reactivated by the same user
+        if service_result.is_good() && session.terminated() && session.user_token_id != user_token_id {
+            error!("Detached session cannot be reactivated by a different user");
+            service_result = StatusCode::BadIdentityTokenRejected;
         }
 
         let response = if service_result.is_good() {
             session.activated = true;
+            session.user_identity = Some(request.user_identity_token.clone());
+            session.user_token_id = user_token_id;
             session.session_nonce = server_nonce;
             let diagnostic_infos = None;
             ActivateSessionResponse {
@@ -177,7 +196,12 @@ impl SessionService {
     pub fn close_session(&self, session: &mut Session, request: &CloseSessionRequest) -> Result<SupportedMessage, StatusCode> {
         session.authentication_token = NodeId::null();
         session.user_identity = None;
+        session.user_token_id = None;
         session.activated = false;
+        session.unregister_all_nodes();
+        if request.delete_subscriptions {
+            session.subscriptions.delete_all();
+        }
         let response = CloseSessionResponse {
             response_header: ResponseHeader::new_good(&request.request_header),
         };
@@ -193,6 +217,35 @@ impl SessionService {
         Ok(response.into())
     }
 
+    /// Verifies that a detached session is being reactivated on a secure channel of the client
+    /// application that created it. The channel must have the client certificate of the channel
+    /// the session was created on, and the certificate must be valid for the application uri that
+    /// the client supplied to CreateSession.
+    pub fn verify_reactivating_client(session: &Session, secure_channel: &SecureChannel) -> StatusCode {
+        let client_certificate = {
+            let secure_channel = trace_read_lock_unwrap!(session.secure_channel);
+            secure_channel.remote_cert()
+        };
+        match (client_certificate, secure_channel.remote_cert()) {
+            (None, None) => StatusCode::Good,
+            (Some(client_certificate), Some(remote_cert)) => {
+                if client_certificate.thumbprint().value != remote_cert.thumbprint().value {
+                    error!("Detached session cannot be reactivated on a secure channel with a different client certificate");
+                    StatusCode::BadSecurityChecksFailed
+                } else if remote_cert.is_application_uri_valid(session.client_application_uri.as_ref()).is_bad() {
+                    error!("Detached session cannot be reactivated by a client with a different application uri");
+                    StatusCode::BadSecurityChecksFailed
+                } else {
+                    StatusCode::Good
+                }
+            }
+            _ => {
+                error!("Detached session cannot be reactivated on a secure channel with a different client certificate");
+                StatusCode::BadSecurityChecksFailed
+            }
+        }
+    }
+
     /// Verifies that the supplied client signature was produced by the session's client certificate
     /// from the server's certificate and nonce.
     fn verify_client_signature(server_state: &ServerState, session: &Session, client_signature: &SignatureData) -> StatusCode {
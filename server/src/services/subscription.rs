@@ -1,4 +1,5 @@
 use std::result::Result;
+use std::sync::{Arc, RwLock};
 
 use opcua_types::*;
 use opcua_types::status_code::StatusCode;
@@ -185,6 +186,65 @@ impl SubscriptionService {
         }
     }
 
+    /// Handles a TransferSubscriptionsRequest. The subscriptions are moved from the other sessions
+    /// of the server with their queued and unacknowledged notifications, providing the sessions
+    /// have the same user identity as this one.
+    pub fn transfer_subscriptions(&self, server_state: &ServerState, session: &mut Session, other_sessions: &[Arc<RwLock<Session>>], request: &TransferSubscriptionsRequest) -> Result<SupportedMessage, StatusCode> {
+        let subscription_ids = match request.subscription_ids {
+            Some(ref subscription_ids) if !subscription_ids.is_empty() => subscription_ids,
+            _ => {
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        let results = subscription_ids.iter().map(|subscription_id| {
+            let (status_code, available_sequence_numbers) = Self::transfer_subscription(server_state, session, other_sessions, *subscription_id, request.send_initial_values);
+            TransferResult {
+                status_code,
+                available_sequence_numbers,
+            }
+        }).collect();
+        let response = TransferSubscriptionsResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            results: Some(results),
+            diagnostic_infos: None,
+        };
+        Ok(response.into())
+    }
+
+    /// Transfers a subscription to the session, returning the status code of the transfer and the
+    /// sequence numbers of the notifications that are available for republishing
+    fn transfer_subscription(server_state: &ServerState, session: &mut Session, other_sessions: &[Arc<RwLock<Session>>], subscription_id: u32, send_initial_values: bool) -> (StatusCode, Option<Vec<u32>>) {
+        if let Some(subscription) = session.subscriptions.get_mut(subscription_id) {
+            // The subscription already belongs to the session
+            if send_initial_values {
+                subscription.set_resend_data();
+            }
+            return (StatusCode::Good, session.subscriptions.available_sequence_numbers(subscription_id));
+        }
+        if server_state.max_subscriptions > 0 && session.subscriptions.len() >= server_state.max_subscriptions {
+            return (StatusCode::BadTooManySubscriptions, None);
+        }
+        for other_session in other_sessions {
+            let mut other_session = trace_write_lock_unwrap!(other_session);
+            if !other_session.subscriptions.contains(subscription_id) {
+                continue;
+            }
+            // Only the user who owns the subscription may transfer it
+            if session.user_token_id.is_none() || session.user_token_id != other_session.user_token_id {
+                error!("Subscription {} cannot be transferred to a session with a different user identity", subscription_id);
+                return (StatusCode::BadUserAccessDenied, None);
+            }
+            let (mut subscription, notification_messages) = other_session.subscriptions.transfer_out(subscription_id).unwrap();
+            subscription.reset_lifetime_counter();
+            if send_initial_values {
+                subscription.set_resend_data();
+            }
+            let available_sequence_numbers = session.subscriptions.transfer_in(subscription, notification_messages);
+            return (StatusCode::Good, available_sequence_numbers);
+        }
+        (StatusCode::BadSubscriptionIdInvalid, None)
+    }
+
     /// This function takes the requested values passed in a create / modify and returns revised
     /// values that conform to the server's limits. For simplicity the return type is a tuple
     fn revise_subscription_values(server_state: &ServerState, requested_publishing_interval: Duration, requested_max_keep_alive_count: u32, requested_lifetime_count: u32) -> (Duration, u32, u32) {
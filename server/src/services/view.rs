@@ -8,6 +8,7 @@ use opcua_types::service_types::*;
 
 use crate::{
     address_space::AddressSpace,
+    constants,
     session::Session,
     services::Service,
     continuation_point::BrowseContinuationPoint,
@@ -102,39 +103,22 @@ impl ViewService {
             }
             else {
                 let results = browse_paths.iter().map(|browse_path| {
-                    let node_id = browse_path.starting_node.clone();
-                    if browse_path.relative_path.elements.is_none() {
-                        BrowsePathResult {
-                            status_code: StatusCode::BadNothingToDo,
-                            targets: None,
-                        }
-                    } else {
-                        // Starting from the node_id, find paths
-                        match address_space.find_nodes_relative_path(&node_id, &browse_path.relative_path) {
-                            Err(err) => {
-                                BrowsePathResult {
-                                    status_code: err,
-                                    targets: None,
-
-                                }
+                    // Starting from the node_id, find paths
+                    match address_space.find_nodes_relative_path(&browse_path.starting_node, &browse_path.relative_path) {
+                        Err(err) => {
+                            BrowsePathResult {
+                                status_code: err,
+                                targets: None,
                             }
-                            Ok(result) => {
-                                let targets = if !result.is_empty() {
-                                    use std::u32;
-                                    let targets = result.iter().map(|node_id| {
-                                        BrowsePathTarget {
-                                            target_id: ExpandedNodeId::new(node_id.clone()),
-                                            remaining_path_index: u32::MAX,
-                                        }
-                                    }).collect();
-                                    Some(targets)
-                                } else {
-                                    None
-                                };
-                                BrowsePathResult {
-                                    status_code: StatusCode::Good,
-                                    targets,
-                                }
+                        }
+                        Ok(node_ids) => {
+                            let targets = node_ids.into_iter().map(|node_id| BrowsePathTarget {
+                                target_id: ExpandedNodeId::new(node_id),
+                                remaining_path_index: u32::MAX,
+                            }).collect();
+                            BrowsePathResult {
+                                status_code: StatusCode::Good,
+                                targets: Some(targets),
                             }
                         }
                     }
@@ -153,6 +137,49 @@ impl ViewService {
         }
     }
 
+    /// Registers nodes for repeated use in the session, returning a numeric node id that aliases
+    /// each node. Reads and writes of an alias are of the node that it aliases.
+    pub fn register_nodes(&self, session: &mut Session, address_space: &AddressSpace, request: &RegisterNodesRequest) -> Result<SupportedMessage, StatusCode> {
+        let nodes_to_register = match request.nodes_to_register {
+            Some(ref nodes_to_register) if !nodes_to_register.is_empty() => nodes_to_register,
+            _ => {
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        if nodes_to_register.len() > constants::MAX_NODES_PER_REGISTER_NODES {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
+        }
+        let registered_node_ids = match session.register_nodes(address_space, nodes_to_register) {
+            Ok(registered_node_ids) => registered_node_ids,
+            Err(status_code) => {
+                return Ok(self.service_fault(&request.request_header, status_code));
+            }
+        };
+        let response = RegisterNodesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+            registered_node_ids: Some(registered_node_ids),
+        };
+        Ok(response.into())
+    }
+
+    /// Unregisters nodes that were registered in the session
+    pub fn unregister_nodes(&self, session: &mut Session, request: &UnregisterNodesRequest) -> Result<SupportedMessage, StatusCode> {
+        let nodes_to_unregister = match request.nodes_to_unregister {
+            Some(ref nodes_to_unregister) if !nodes_to_unregister.is_empty() => nodes_to_unregister,
+            _ => {
+                return Ok(self.service_fault(&request.request_header, StatusCode::BadNothingToDo));
+            }
+        };
+        if nodes_to_unregister.len() > constants::MAX_NODES_PER_REGISTER_NODES {
+            return Ok(self.service_fault(&request.request_header, StatusCode::BadTooManyOperations));
+        }
+        nodes_to_unregister.iter().for_each(|node_id| session.unregister_node(node_id));
+        let response = UnregisterNodesResponse {
+            response_header: ResponseHeader::new_good(&request.request_header),
+        };
+        Ok(response.into())
+    }
+
     fn browse_nodes(session: &mut Session, address_space: &AddressSpace, nodes_to_browse: &[BrowseDescription], max_references_per_node: usize) -> Vec<BrowseResult> {
         nodes_to_browse.iter().map(|node_to_browse| {
             let browse_result = Self::browse_node(session, &address_space, 0, node_to_browse, max_references_per_node);
@@ -177,12 +204,10 @@ impl ViewService {
         // Request may wish to filter by a kind of reference
         let reference_type_id = if node_to_browse.reference_type_id.is_null() {
             None
+        } else if address_space.is_reference_type(&node_to_browse.reference_type_id) {
+            Some((node_to_browse.reference_type_id.clone(), node_to_browse.include_subtypes))
         } else {
-            if let Ok(reference_type_id) = node_to_browse.reference_type_id.as_reference_type_id() {
-                Some((reference_type_id, node_to_browse.include_subtypes))
-            } else {
-                None
-            }
+            return Err(StatusCode::BadReferenceTypeIdInvalid);
         };
 
         // Fetch the references to / from the given node to browse
@@ -217,7 +242,7 @@ impl ViewService {
 
             // Prepare the values to put into the struct according to the result mask
             let reference_type_id = if result_mask.contains(BrowseDescriptionResultMask::RESULT_MASK_REFERENCE_TYPE) {
-                reference.reference_type_id.into()
+                reference.reference_type_id.clone()
             } else {
                 NodeId::null()
             };
@@ -248,7 +273,7 @@ impl ViewService {
                 // shall be returned.
                 match target_node_class {
                     NodeClass::Object | NodeClass::Variable => {
-                        let type_defs = address_space.find_references_from(&target_node.node_id(), Some((ReferenceTypeId::HasTypeDefinition, false)));
+                        let type_defs = address_space.find_references_from(&target_node.node_id(), Some((ReferenceTypeId::HasTypeDefinition.into(), false)));
                         if let Some(type_defs) = type_defs {
                             ExpandedNodeId::new(type_defs[0].node_id.clone())
                         } else {
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock, Mutex};
 
 use chrono;
@@ -11,7 +11,8 @@ use opcua_types::status_code::StatusCode;
 
 use crate::{
     address_space::AddressSpace,
-    continuation_point::BrowseContinuationPoint,
+    constants,
+    continuation_point::{BrowseContinuationPoint, HistoryContinuationPoint, QueryContinuationPoint},
     diagnostics::ServerDiagnostics,
     DateTimeUtc,
     server::Server,
@@ -48,6 +49,8 @@ pub struct Session {
     pub security_policy_uri: String,
     /// Client's certificate
     pub client_certificate: Option<X509>,
+    /// Application uri of the client, from the client description supplied to CreateSession
+    pub client_application_uri: UAString,
     /// Authentication token for the session
     pub authentication_token: NodeId,
     /// Secure channel state
@@ -58,6 +61,8 @@ pub struct Session {
     pub session_timeout: f64,
     /// User identity token
     pub user_identity: Option<ExtensionObject>,
+    /// The id of the server user token that the user identity was authenticated against
+    pub user_token_id: Option<String>,
     /// Negotiated max request message size
     pub max_request_message_size: u32,
     /// Negotiated max response message size
@@ -68,6 +73,20 @@ pub struct Session {
     max_browse_continuation_points: usize,
     /// Browse continuation points (oldest to newest)
     browse_continuation_points: VecDeque<BrowseContinuationPoint>,
+    /// Maximum number of history continuation points
+    max_history_continuation_points: usize,
+    /// History continuation points (oldest to newest)
+    history_continuation_points: VecDeque<HistoryContinuationPoint>,
+    /// Maximum number of query continuation points
+    max_query_continuation_points: usize,
+    /// Query continuation points (oldest to newest)
+    query_continuation_points: VecDeque<QueryContinuationPoint>,
+    /// Nodes registered by RegisterNodes, keyed by the numeric node ids that alias them
+    registered_nodes: HashMap<NodeId, NodeId>,
+    /// The aliases of the registered nodes, keyed by the node ids they alias
+    registered_node_aliases: HashMap<NodeId, NodeId>,
+    /// The identifier of the next node id that aliases a registered node
+    next_registered_node_id: u32,
     /// Diagnostics associated with the session
     diagnostics: Arc<RwLock<ServerDiagnostics>>,
     /// Indicates if the session has received an ActivateSession
@@ -90,6 +109,8 @@ impl Session {
     #[cfg(test)]
     pub fn new_no_certificate_store(secure_channel: SecureChannel) -> Session {
         let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
+        let max_history_continuation_points = super::constants::MAX_HISTORY_CONTINUATION_POINTS;
+        let max_query_continuation_points = super::constants::MAX_QUERY_CONTINUATION_POINTS;
         let session = Session {
             subscriptions: Subscriptions::new(100, PUBLISH_REQUEST_TIMEOUT),
             session_id: next_session_id(),
@@ -98,17 +119,26 @@ impl Session {
             terminated: false,
             terminated_at: chrono::Utc::now(),
             client_certificate: None,
+            client_application_uri: UAString::null(),
             security_policy_uri: String::new(),
             authentication_token: NodeId::null(),
             secure_channel: Arc::new(RwLock::new(secure_channel)),
             session_nonce: ByteString::null(),
             session_timeout: 0f64,
             user_identity: None,
+            user_token_id: None,
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
             max_browse_continuation_points,
             browse_continuation_points: VecDeque::with_capacity(max_browse_continuation_points),
+            max_history_continuation_points,
+            history_continuation_points: VecDeque::with_capacity(max_history_continuation_points),
+            max_query_continuation_points,
+            query_continuation_points: VecDeque::with_capacity(max_query_continuation_points),
+            registered_nodes: HashMap::new(),
+            registered_node_aliases: HashMap::new(),
+            next_registered_node_id: 1,
             diagnostics: Arc::new(RwLock::new(ServerDiagnostics::default())),
         };
         {
@@ -120,6 +150,8 @@ impl Session {
 
     pub fn new(server: &Server) -> Session {
         let max_browse_continuation_points = super::constants::MAX_BROWSE_CONTINUATION_POINTS;
+        let max_history_continuation_points = super::constants::MAX_HISTORY_CONTINUATION_POINTS;
+        let max_query_continuation_points = super::constants::MAX_QUERY_CONTINUATION_POINTS;
 
         let server_state = server.server_state();
         let server_state = trace_read_lock_unwrap!(server_state);
@@ -138,17 +170,26 @@ impl Session {
             terminated: false,
             terminated_at: chrono::Utc::now(),
             client_certificate: None,
+            client_application_uri: UAString::null(),
             security_policy_uri: String::new(),
             authentication_token: NodeId::null(),
             secure_channel: Arc::new(RwLock::new(SecureChannel::new(server.certificate_store(), Role::Server, decoding_limits))),
             session_nonce: ByteString::null(),
             session_timeout: 0f64,
             user_identity: None,
+            user_token_id: None,
             max_request_message_size: 0,
             max_response_message_size: 0,
             endpoint_url: UAString::null(),
             max_browse_continuation_points,
             browse_continuation_points: VecDeque::with_capacity(max_browse_continuation_points),
+            max_history_continuation_points,
+            history_continuation_points: VecDeque::with_capacity(max_history_continuation_points),
+            max_query_continuation_points,
+            query_continuation_points: VecDeque::with_capacity(max_query_continuation_points),
+            registered_nodes: HashMap::new(),
+            registered_node_aliases: HashMap::new(),
+            next_registered_node_id: 1,
             diagnostics,
         };
         {
@@ -168,6 +209,31 @@ impl Session {
         self.terminated_at = chrono::Utc::now();
     }
 
+    /// Test if the session can outlive its connection, i.e. it is activated and has not been told
+    /// to terminate, so a client can reactivate it on another connection.
+    pub fn is_detachable(&self) -> bool {
+        self.activated && !self.terminate_session && !self.authentication_token.is_null()
+    }
+
+    /// Test if the session timeout has expired since the session was terminated
+    pub fn is_timed_out(&self, now: &DateTimeUtc) -> bool {
+        self.terminated && now.signed_duration_since(self.terminated_at).num_milliseconds() as f64 > self.session_timeout
+    }
+
+    /// Detaches the session from its connection. Publish requests from the connection are
+    /// discarded since their responses cannot be sent on any other.
+    pub fn detach(&mut self) {
+        self.set_terminated();
+        self.subscriptions.discard_publish_requests();
+    }
+
+    /// Attaches a detached session to a new connection, once it has been reactivated on the secure
+    /// channel of the connection.
+    pub fn attach(&mut self) {
+        info!("Session is being attached to a new connection");
+        self.terminated = false;
+    }
+
     pub fn enqueue_publish_request(&mut self, address_space: &AddressSpace, request_id: u32, request: PublishRequest) -> Result<(), StatusCode> {
         self.subscriptions.enqueue_publish_request(address_space, request_id, request)
     }
@@ -233,4 +299,110 @@ impl Session {
             !continuation_points_set.contains(&continuation_point.id)
         });
     }
+
+    pub fn add_history_continuation_point(&mut self, continuation_point: HistoryContinuationPoint) {
+        // Remove excess history continuation points
+        while !self.history_continuation_points.is_empty() && self.history_continuation_points.len() >= self.max_history_continuation_points {
+            let _ = self.history_continuation_points.pop_front();
+        }
+        self.history_continuation_points.push_back(continuation_point);
+    }
+
+    /// Find a history continuation point by id.
+    pub fn find_history_continuation_point(&self, id: &ByteString) -> Option<HistoryContinuationPoint> {
+        self.history_continuation_points.iter().find(|continuation_point| {
+            continuation_point.id.eq(id)
+        }).cloned()
+    }
+
+    pub fn remove_history_continuation_point(&mut self, continuation_point_id: &ByteString) {
+        self.history_continuation_points.retain(|continuation_point| {
+            !continuation_point.id.eq(continuation_point_id)
+        });
+    }
+
+    pub fn add_query_continuation_point(&mut self, continuation_point: QueryContinuationPoint) {
+        // Remove excess query continuation points
+        while !self.query_continuation_points.is_empty() && self.query_continuation_points.len() >= self.max_query_continuation_points {
+            let _ = self.query_continuation_points.pop_front();
+        }
+        self.query_continuation_points.push_back(continuation_point);
+    }
+
+    /// Find a query continuation point by id.
+    pub fn find_query_continuation_point(&self, id: &ByteString) -> Option<QueryContinuationPoint> {
+        self.query_continuation_points.iter().find(|continuation_point| {
+            continuation_point.id.eq(id)
+        }).cloned()
+    }
+
+    pub fn remove_query_continuation_point(&mut self, continuation_point_id: &ByteString) {
+        self.query_continuation_points.retain(|continuation_point| {
+            !continuation_point.id.eq(continuation_point_id)
+        });
+    }
+
+    /// Registers nodes for repeated use in the session, returning for each node a numeric node id
+    /// in the namespace of the node that aliases it until it is unregistered. A node that is
+    /// already registered keeps its alias. A node that already has a numeric id or that does not
+    /// exist is not aliased, and its own id is returned.
+    ///
+    /// If the nodes would take the session over `constants::MAX_REGISTERED_NODES` registered nodes
+    /// then none of them are registered and `BadTooManyOperations` is returned.
+    pub fn register_nodes(&mut self, address_space: &AddressSpace, node_ids: &[NodeId]) -> Result<Vec<NodeId>, StatusCode> {
+        let new_nodes = node_ids.iter()
+            .filter(|node_id| Self::is_aliasable(address_space, node_id) && !self.registered_node_aliases.contains_key(node_id))
+            .collect::<HashSet<_>>();
+        if self.registered_nodes.len() + new_nodes.len() > constants::MAX_REGISTERED_NODES {
+            error!("Registering {} more nodes would exceed the limit of {} registered nodes in the session", new_nodes.len(), constants::MAX_REGISTERED_NODES);
+            return Err(StatusCode::BadTooManyOperations);
+        }
+        Ok(node_ids.iter().map(|node_id| self.register_node(address_space, node_id)).collect())
+    }
+
+    fn is_aliasable(address_space: &AddressSpace, node_id: &NodeId) -> bool {
+        !node_id.is_numeric() && address_space.node_exists(node_id)
+    }
+
+    fn register_node(&mut self, address_space: &AddressSpace, node_id: &NodeId) -> NodeId {
+        if !Self::is_aliasable(address_space, node_id) {
+            return node_id.clone();
+        }
+        if let Some(alias) = self.registered_node_aliases.get(node_id) {
+            return alias.clone();
+        }
+        // The alias must not be the id of another node or alias
+        loop {
+            let alias = NodeId::new(node_id.namespace, self.next_registered_node_id);
+            self.next_registered_node_id = self.next_registered_node_id.wrapping_add(1);
+            if !address_space.node_exists(&alias) && !self.registered_nodes.contains_key(&alias) {
+                self.registered_nodes.insert(alias.clone(), node_id.clone());
+                self.registered_node_aliases.insert(node_id.clone(), alias.clone());
+                return alias;
+            }
+        }
+    }
+
+    /// Unregisters a node by the node id returned when it was registered. A node id that does not
+    /// alias a registered node is ignored.
+    pub fn unregister_node(&mut self, node_id: &NodeId) {
+        if let Some(registered_node_id) = self.registered_nodes.remove(node_id) {
+            let _ = self.registered_node_aliases.remove(&registered_node_id);
+        }
+    }
+
+    /// Unregisters all of the nodes registered in the session
+    pub fn unregister_all_nodes(&mut self) {
+        self.registered_nodes.clear();
+        self.registered_node_aliases.clear();
+    }
+
+    /// Returns the node that the node id aliases if it is registered, or the node id itself
+    pub fn resolve_node_id<'a>(&'a self, node_id: &'a NodeId) -> &'a NodeId {
+        if !self.registered_nodes.is_empty() && node_id.is_numeric() {
+            self.registered_nodes.get(node_id).unwrap_or(node_id)
+        } else {
+            node_id
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! Provides server state information, such as status, configuration, running servers and so on.
 
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, Weak};
 
 use opcua_core::prelude::*;
 use opcua_types::node_ids::ObjectId;
@@ -9,8 +9,12 @@ use opcua_types::service_types::{ApplicationDescription, RegisteredServer, Appli
 use opcua_types::service_types::ServerState as ServerStateType;
 use opcua_types::status_code::StatusCode;
 
-use crate::config::{ServerConfig, ServerEndpoint};
+use crate::address_space::AddressSpace;
+use crate::config::{ServerConfig, ServerEndpoint, ANONYMOUS_USER_TOKEN_ID};
 use crate::diagnostics::ServerDiagnostics;
+use crate::session::Session;
+use crate::subscriptions::subscription::TickReason;
+use crate::DateTimeUtc;
 
 const TOKEN_POLICY_ANONYMOUS: &str = "anonymous";
 const TOKEN_POLICY_USER_PASS_PLAINTEXT: &str = "userpass_plaintext";
@@ -57,6 +61,12 @@ pub struct ServerState {
     pub abort: bool,
     /// Diagnostic information
     pub diagnostics: Arc<RwLock<ServerDiagnostics>>,
+    /// The sessions of the server, held for as long as their connections or the detached sessions
+    /// hold them
+    pub(crate) sessions: Vec<Weak<RwLock<Session>>>,
+    /// Sessions whose connections were dropped, kept until their session timeout expires so that
+    /// clients can reactivate them on new connections
+    pub(crate) detached_sessions: Vec<Arc<RwLock<Session>>>,
 }
 
 impl ServerState {
@@ -225,6 +235,52 @@ impl ServerState {
         self.last_subscription_id
     }
 
+    /// Adds the session of a new connection to the sessions of the server
+    pub fn add_session(&mut self, session: &Arc<RwLock<Session>>) {
+        self.sessions.push(Arc::downgrade(session));
+    }
+
+    /// Returns the sessions of the server, forgetting those which have gone
+    pub fn sessions(&mut self) -> Vec<Arc<RwLock<Session>>> {
+        self.sessions.retain(|session| session.upgrade().is_some());
+        self.sessions.iter().filter_map(|session| session.upgrade()).collect()
+    }
+
+    /// Keeps a session whose connection was dropped until its session timeout expires
+    pub fn detach_session(&mut self, session: Arc<RwLock<Session>>) {
+        self.detached_sessions.push(session);
+    }
+
+    /// Removes and returns the detached session with the authentication token, if there is one
+    pub fn take_detached_session(&mut self, authentication_token: &NodeId) -> Option<Arc<RwLock<Session>>> {
+        let idx = self.detached_sessions.iter().position(|session| {
+            let session = trace_read_lock_unwrap!(session);
+            session.authentication_token == *authentication_token
+        });
+        idx.map(|idx| self.detached_sessions.remove(idx))
+    }
+
+    /// Ticks the subscriptions of the detached sessions so their notifications are queued until
+    /// the sessions are reactivated, and closes the sessions whose session timeout has expired,
+    /// deleting their subscriptions.
+    pub fn tick_detached_sessions(&mut self, now: &DateTimeUtc, address_space: &Arc<RwLock<AddressSpace>>) {
+        let diagnostics = self.diagnostics.clone();
+        self.detached_sessions.retain(|session| {
+            let mut session = trace_write_lock_unwrap!(session);
+            if session.is_timed_out(now) {
+                info!("Detached session {} has timed out and will be closed", session.session_id);
+                session.subscriptions.delete_all();
+                let mut diagnostics = trace_write_lock_unwrap!(diagnostics);
+                diagnostics.on_session_timeout();
+                false
+            } else {
+                let address_space = trace_read_lock_unwrap!(address_space);
+                let _ = session.tick_subscriptions(now, &address_space, TickReason::TickTimerFired);
+                true
+            }
+        });
+    }
+
     /// Authenticates access to an endpoint. The endpoint is described by its path, policy, mode and
     /// the token is supplied in an extension object that must be extracted and authenticated.
     ///
@@ -232,6 +288,15 @@ impl ServerState {
     /// or that the token cannot be used with the end point. The return codes reflect the responses
     /// that ActivateSession would expect from a service call.
     pub fn authenticate_endpoint(&self, endpoint_url: &str, security_policy: SecurityPolicy, security_mode: MessageSecurityMode, user_identity_token: &ExtensionObject) -> StatusCode {
+        match self.authenticated_user_token_id(endpoint_url, security_policy, security_mode, user_identity_token) {
+            Ok(_) => StatusCode::Good,
+            Err(status_code) => status_code,
+        }
+    }
+
+    /// Authenticates access to an endpoint as `authenticate_endpoint` does and returns the id of the
+    /// server user token that the identity was authenticated against.
+    pub fn authenticated_user_token_id(&self, endpoint_url: &str, security_policy: SecurityPolicy, security_mode: MessageSecurityMode, user_identity_token: &ExtensionObject) -> Result<String, StatusCode> {
         // Get security from endpoint url
         let config = trace_read_lock_unwrap!(self.config);
         let decoding_limits = config.decoding_limits();
@@ -256,7 +321,7 @@ impl ServerState {
                             } else {
                                 // Garbage in the extension object
                                 error!("User name identity token could not be decoded");
-                                StatusCode::BadIdentityTokenInvalid
+                                Err(StatusCode::BadIdentityTokenInvalid)
                             }
                         }
                         ObjectId::X509IdentityToken_Encoding_DefaultBinary => {
@@ -264,51 +329,51 @@ impl ServerState {
                             let result = user_identity_token.decode_inner::<X509IdentityToken>(&decoding_limits);
                             if let Ok(_) = result {
                                 error!("X509 identity token type is not supported");
-                                StatusCode::BadIdentityTokenRejected
+                                Err(StatusCode::BadIdentityTokenRejected)
                             } else {
                                 // Garbage in the extension object
                                 error!("X509 identity token could not be decoded");
-                                StatusCode::BadIdentityTokenInvalid
+                                Err(StatusCode::BadIdentityTokenInvalid)
                             }
                         }
                         _ => {
                             error!("User identity token type {:?} is unrecognized", object_id);
-                            StatusCode::BadIdentityTokenInvalid
+                            Err(StatusCode::BadIdentityTokenInvalid)
                         }
                     }
                 } else {
                     error!("Cannot read user identity token");
-                    StatusCode::BadIdentityTokenInvalid
+                    Err(StatusCode::BadIdentityTokenInvalid)
                 }
             }
         } else {
             error!("Cannot find endpoint that matches path \"{}\", security policy {:?}, and security mode {:?}", endpoint_url, security_policy, security_mode);
-            StatusCode::BadTcpEndpointUrlInvalid
+            Err(StatusCode::BadTcpEndpointUrlInvalid)
         }
     }
 
     /// Authenticates an anonymous token, i.e. does the endpoint support anonymous access or not
-    fn authenticate_anonymous_token(endpoint: &ServerEndpoint) -> StatusCode {
+    fn authenticate_anonymous_token(endpoint: &ServerEndpoint) -> Result<String, StatusCode> {
         if endpoint.supports_anonymous() {
             debug!("Anonymous identity is authenticated");
-            StatusCode::Good
+            Ok(ANONYMOUS_USER_TOKEN_ID.to_string())
         } else {
             error!("Endpoint \"{}\" does not support anonymous authentication", endpoint.path);
-            StatusCode::BadIdentityTokenRejected
+            Err(StatusCode::BadIdentityTokenRejected)
         }
     }
 
     /// Authenticates the username identity token with the supplied endpoint
-    fn authenticate_username_identity_token(&self, config: &ServerConfig, endpoint: &ServerEndpoint, token: &UserNameIdentityToken) -> StatusCode {
+    fn authenticate_username_identity_token(&self, config: &ServerConfig, endpoint: &ServerEndpoint, token: &UserNameIdentityToken) -> Result<String, StatusCode> {
         // TODO Server's user token policy should be checked here.
         // The policy_id should be used to determine the algorithm for encoding passwords etc.
         if !token.encryption_algorithm.is_null() {
             // Plaintext is the only supported algorithm at this time
             error!("Only unencrypted passwords are supported, {:?}", token);
-            StatusCode::BadIdentityTokenInvalid
+            Err(StatusCode::BadIdentityTokenInvalid)
         } else if token.user_name.is_null() {
             error!("User identify token supplies no user name");
-            StatusCode::BadIdentityTokenInvalid
+            Err(StatusCode::BadIdentityTokenInvalid)
         } else {
             // Iterate ids in endpoint
             for user_token_id in &endpoint.user_token_ids {
@@ -326,15 +391,15 @@ impl ServerState {
                         let valid = result.is_ok();
                         if !valid {
                             error!("Cannot authenticate \"{}\", password is invalid", server_user_token.user);
-                            return StatusCode::BadIdentityTokenRejected;
+                            return Err(StatusCode::BadIdentityTokenRejected);
                         } else {
-                            return StatusCode::Good;
+                            return Ok(user_token_id.clone());
                         }
                     }
                 }
             }
             error!("Cannot authenticate \"{}\", user not found for endpoint", token.user_name);
-            StatusCode::BadIdentityTokenRejected
+            Err(StatusCode::BadIdentityTokenRejected)
         }
     }
 }
@@ -1,19 +1,25 @@
 use std::result::Result;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 
 use chrono;
 
 use opcua_types::*;
 use opcua_types::status_code::StatusCode;
 use opcua_types::node_ids::ObjectId;
-use opcua_types::service_types::{TimestampsToReturn, DataChangeFilter, ReadValueId, MonitoredItemCreateRequest, MonitoredItemModifyRequest, MonitoredItemNotification};
+use opcua_types::service_types::{TimestampsToReturn, DataChangeFilter, EventFilter, EventFilterResult, EventFieldList, ReadValueId, MonitoredItemCreateRequest, MonitoredItemModifyRequest, MonitoredItemNotification};
 
-use crate::{constants, DateTimeUtc, address_space::AddressSpace};
+use crate::{
+    constants,
+    DateTimeUtc,
+    address_space::{AddressSpace, node::NodeType},
+    events::{event::Event, event_filter},
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum FilterType {
     None,
     DataChangeFilter(DataChangeFilter),
+    EventFilter(EventFilter),
 }
 
 impl FilterType {
@@ -26,6 +32,9 @@ impl FilterType {
         } else if filter_type_id == &ObjectId::DataChangeFilter_Encoding_DefaultBinary.into() {
             let decoding_limits = DecodingLimits::minimal();
             Ok(FilterType::DataChangeFilter(filter.decode_inner::<DataChangeFilter>(&decoding_limits)?))
+        } else if filter_type_id == &ObjectId::EventFilter_Encoding_DefaultBinary.into() {
+            let decoding_limits = DecodingLimits::default();
+            Ok(FilterType::EventFilter(filter.decode_inner::<EventFilter>(&decoding_limits)?))
         } else {
             error!("Requested data filter type is not supported, {:?}", filter_type_id);
             Err(StatusCode::BadFilterNotAllowed)
@@ -46,17 +55,40 @@ pub struct MonitoredItem {
     /// The notification queue is arranged from oldest to newest, i.e. pop front gets the oldest
     /// message, pop back gets the most recent.
     pub notification_queue: VecDeque<MonitoredItemNotification>,
+    /// The event queue of an item monitoring events, arranged from oldest to newest
+    pub event_queue: VecDeque<EventFieldList>,
     pub queue_overflow: bool,
+    /// The ids of the items in the same subscription that this item triggers, i.e. that report
+    /// their queued notifications when this item samples a new value or event
+    pub triggered_items: BTreeSet<u32>,
+    /// Set when a triggering item triggers this item, until its notifications are reported
+    triggered: bool,
     timestamps_to_return: TimestampsToReturn,
     last_sample_time: DateTimeUtc,
     last_data_value: Option<DataValue>,
+    /// The number of the last event that an item monitoring events has checked
+    last_event_number: u64,
+    /// The part of the attribute's value that is sampled, from the index range of the item to monitor
+    index_range: Option<NumericRange>,
+    /// The encoding of the structures in the sampled value, from the data encoding of the item to monitor
+    data_encoding: Option<DataEncoding>,
 }
 
 impl MonitoredItem {
     pub fn new(monitored_item_id: u32, timestamps_to_return: TimestampsToReturn, request: &MonitoredItemCreateRequest) -> Result<MonitoredItem, StatusCode> {
         let filter = FilterType::from_filter(&request.requested_parameters.filter)?;
+        Self::check_filter_type(&request.item_to_monitor, &filter)?;
         let sampling_interval = Self::sanitize_sampling_interval(request.requested_parameters.sampling_interval);
-        let queue_size = Self::sanitize_queue_size(request.requested_parameters.queue_size as usize);
+        let queue_size = Self::sanitize_queue_size(request.requested_parameters.queue_size as usize, Self::is_event_filter(&filter));
+        let index_range = if request.item_to_monitor.index_range.is_null() {
+            None
+        } else {
+            Some(request.item_to_monitor.index_range.as_ref().parse::<NumericRange>().map_err(|_| StatusCode::BadIndexRangeInvalid)?)
+        };
+        let data_encoding = DataEncoding::from_browse_name(&request.item_to_monitor.data_encoding)?;
+        if data_encoding.is_some() && request.item_to_monitor.attribute_id != AttributeId::Value as u32 {
+            return Err(StatusCode::BadDataEncodingInvalid);
+        }
         Ok(MonitoredItem {
             monitored_item_id,
             item_to_monitor: request.item_to_monitor.clone(),
@@ -70,17 +102,89 @@ impl MonitoredItem {
             last_data_value: None,
             queue_size,
             notification_queue: VecDeque::with_capacity(queue_size),
+            event_queue: VecDeque::new(),
             queue_overflow: false,
+            triggered_items: BTreeSet::new(),
+            triggered: false,
+            last_event_number: 0,
+            index_range,
+            data_encoding,
         })
     }
 
+    /// Tests if the item monitors events rather than the value of an attribute
+    pub fn is_event_item(&self) -> bool {
+        Self::is_event_filter(&self.filter)
+    }
+
+    fn is_event_filter(filter: &FilterType) -> bool {
+        if let FilterType::EventFilter(_) = filter { true } else { false }
+    }
+
+    /// Tests that an event filter is used if and only if the item monitors the EventNotifier
+    /// attribute
+    fn check_filter_type(item_to_monitor: &ReadValueId, filter: &FilterType) -> Result<(), StatusCode> {
+        let monitors_events = item_to_monitor.attribute_id == AttributeId::EventNotifier as u32;
+        if monitors_events != Self::is_event_filter(filter) {
+            error!("Monitored item for attribute {} has the wrong kind of filter", item_to_monitor.attribute_id);
+            Err(StatusCode::BadMonitoredItemFilterInvalid)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates the item against the address space, returning the filter result. An item
+    /// monitoring events must be on an event notifier and have a valid event filter, and it
+    /// receives the events raised from now on. On failure, the error holds the filter result
+    /// that explains it, which is null if there isn't one.
+    pub fn validate(&mut self, address_space: &AddressSpace) -> Result<ExtensionObject, (StatusCode, ExtensionObject)> {
+        if let FilterType::EventFilter(ref filter) = self.filter {
+            let is_event_notifier = match address_space.find_node(&self.item_to_monitor.node_id) {
+                Some(NodeType::Object(ref object)) => object.is_event_notifier(),
+                Some(NodeType::View(ref view)) => view.event_notifier(),
+                Some(_) => false,
+                None => {
+                    return Err((StatusCode::BadNodeIdUnknown, ExtensionObject::null()));
+                }
+            };
+            if !is_event_notifier {
+                error!("Node {:?} is not an event notifier", self.item_to_monitor.node_id);
+                return Err((StatusCode::BadNotReadable, ExtensionObject::null()));
+            }
+            let filter_result = Self::validate_event_filter(address_space, filter)?;
+            self.last_event_number = address_space.last_event_number();
+            Ok(filter_result)
+        } else {
+            Ok(ExtensionObject::null())
+        }
+    }
+
+    /// Validates an event filter, returning the encoded filter result whether it is valid or not
+    fn validate_event_filter(address_space: &AddressSpace, filter: &EventFilter) -> Result<ExtensionObject, (StatusCode, ExtensionObject)> {
+        let encode = |filter_result: &EventFilterResult| {
+            ExtensionObject::from_encodable(ObjectId::EventFilterResult_Encoding_DefaultBinary, filter_result)
+        };
+        event_filter::validate(address_space, filter)
+            .map(|filter_result| encode(&filter_result))
+            .map_err(|(status_code, filter_result)| (status_code, encode(&filter_result)))
+    }
+
     /// Modifies the existing item with the values of the modify request. On success, the result
-    /// holds the filter result.
-    pub fn modify(&mut self, timestamps_to_return: TimestampsToReturn, request: &MonitoredItemModifyRequest) -> Result<ExtensionObject, StatusCode> {
+    /// holds the filter result. On failure, the item is unchanged and the error holds the filter
+    /// result that explains it, which is null if there isn't one.
+    pub fn modify(&mut self, address_space: &AddressSpace, timestamps_to_return: TimestampsToReturn, request: &MonitoredItemModifyRequest) -> Result<ExtensionObject, (StatusCode, ExtensionObject)> {
+        let filter = FilterType::from_filter(&request.requested_parameters.filter)
+            .and_then(|filter| Self::check_filter_type(&self.item_to_monitor, &filter).map(|_| filter))
+            .map_err(|status_code| (status_code, ExtensionObject::null()))?;
+        let filter_result = if let FilterType::EventFilter(ref filter) = filter {
+            Self::validate_event_filter(address_space, filter)?
+        } else {
+            ExtensionObject::null()
+        };
         self.timestamps_to_return = timestamps_to_return;
-        self.filter = FilterType::from_filter(&request.requested_parameters.filter)?;
+        self.filter = filter;
         self.sampling_interval = Self::sanitize_sampling_interval(request.requested_parameters.sampling_interval);
-        self.queue_size = Self::sanitize_queue_size(request.requested_parameters.queue_size as usize);
+        self.queue_size = Self::sanitize_queue_size(request.requested_parameters.queue_size as usize, self.is_event_item());
         self.client_handle = request.requested_parameters.client_handle;
         self.discard_oldest = request.requested_parameters.discard_oldest;
 
@@ -97,24 +201,66 @@ impl MonitoredItem {
             let extra_capacity = self.queue_size - self.notification_queue.capacity();
             self.notification_queue.reserve(extra_capacity);
         }
-
-        // DataChangeFilter has no result but if the impl adds support for EventFilter, AggregateFilter
-        // then this filter result will have to be filled in.
-        let filter_result = ExtensionObject::null();
+        if self.event_queue.len() > self.queue_size {
+            // Discard old events
+            let discard = self.event_queue.len() - self.queue_size;
+            let _ = self.event_queue.drain(0..discard);
+        }
 
         Ok(filter_result)
     }
 
+    /// Changes the monitoring mode of the item. Disabling the item discards its queued
+    /// notifications and its last value, so it samples again as soon as it is enabled.
+    pub fn set_monitoring_mode(&mut self, monitoring_mode: MonitoringMode) {
+        if monitoring_mode == MonitoringMode::Disabled {
+            self.notification_queue.clear();
+            self.event_queue.clear();
+            self.queue_overflow = false;
+            self.last_data_value = None;
+            self.triggered = false;
+        }
+        self.monitoring_mode = monitoring_mode;
+    }
+
+    /// Marks the item as triggered, so an item in sampling mode reports its queued notifications
+    /// at the end of the publishing interval.
+    pub fn trigger(&mut self) {
+        self.triggered = true;
+    }
+
+    /// Tests if the queued notifications of the item are to be reported at the end of the
+    /// publishing interval, i.e. if the item is reporting or if it is sampling and was triggered
+    /// since the last report. The trigger is reset.
+    pub fn is_reported(&mut self) -> bool {
+        let triggered = self.triggered;
+        self.triggered = false;
+        match self.monitoring_mode {
+            MonitoringMode::Reporting => true,
+            MonitoringMode::Sampling => triggered,
+            MonitoringMode::Disabled => false,
+        }
+    }
+
     /// Called repeatedly on the monitored item.
     ///
     /// If the monitored item has a negative interval and subscription interval has elapsed,
     /// the value is tested immediately. Otherwise, the monitored items sampling interval is enforced
     /// the subscriptions and controls the rate.
     ///
-    /// Function returns true if a notification message was created and should be reported.
+    /// Function returns true if a notification message was created. It is reported if the item
+    /// is in reporting mode and it triggers the items linked to it in either mode.
     pub fn tick(&mut self, address_space: &AddressSpace, now: &DateTimeUtc, publishing_interval_elapsed: bool, resend_data: bool) -> bool {
         if self.monitoring_mode == MonitoringMode::Disabled {
+            // Events raised while disabled are never reported
+            if self.is_event_item() {
+                self.last_event_number = address_space.last_event_number();
+            }
             false
+        } else if self.is_event_item() {
+            // Events are checked on every tick
+            self.last_sample_time = *now;
+            self.check_events(address_space)
         } else {
             let check_value = if resend_data || self.last_data_value.is_none() {
                 // Always check on the first tick
@@ -135,10 +281,7 @@ impl MonitoredItem {
             };
             // Test the value (or don't)
             if check_value {
-                // Indicate a change if reporting is enabled
-                let value_has_changed = self.check_value(address_space, now, resend_data) && self.monitoring_mode == MonitoringMode::Reporting;
-                // println!("Monitored item using its own interval changed = {}", value_has_changed);
-                value_has_changed
+                self.check_value(address_space, now, resend_data)
             } else {
                 false
             }
@@ -160,7 +303,9 @@ impl MonitoredItem {
                 return false;
             }
             let attribute_id = attribute_id.unwrap();
-            let data_value = node.find_attribute(attribute_id);
+            let data_value = node.find_attribute(attribute_id)
+                .map(|data_value| self.apply_index_range(data_value))
+                .map(|data_value| self.apply_data_encoding(address_space, data_value));
             if let Some(mut data_value) = data_value {
                 // Test for data change
                 let data_change = if resend_data {
@@ -227,6 +372,121 @@ impl MonitoredItem {
         }
     }
 
+    /// Reduces the sampled value to the part that the item's index range selects, if it has one.
+    /// If the range selects nothing, the value is replaced by the bad status.
+    fn apply_index_range(&self, mut data_value: DataValue) -> DataValue {
+        if let Some(ref index_range) = self.index_range {
+            let value = match data_value.value {
+                Some(ref value) => value.range_of(index_range),
+                None => Err(StatusCode::BadIndexRangeNoData),
+            };
+            match value {
+                Ok(value) => {
+                    data_value.value = Some(value);
+                }
+                Err(status_code) => {
+                    data_value.value = None;
+                    data_value.status = Some(status_code.bits());
+                }
+            }
+        }
+        data_value
+    }
+
+    /// Encodes the structures in the sampled value with the item's data encoding, if it has one.
+    /// If they cannot be encoded, the value is replaced by the bad status.
+    fn apply_data_encoding(&self, address_space: &AddressSpace, mut data_value: DataValue) -> DataValue {
+        if let Some(data_encoding) = self.data_encoding {
+            if let Some(ref value) = data_value.value {
+                match address_space.encode_value(value, data_encoding) {
+                    Ok(value) => {
+                        data_value.value = Some(value);
+                    }
+                    Err(status_code) => {
+                        data_value.value = None;
+                        data_value.status = Some(status_code.bits());
+                    }
+                }
+            }
+        }
+        data_value
+    }
+
+    /// Enqueues the fields of the events raised since the last check that the item's notifier is
+    /// notified of. Returns true if there were any events.
+    fn check_events(&mut self, address_space: &AddressSpace) -> bool {
+        let events = if let FilterType::EventFilter(ref filter) = self.filter {
+            let notifier_id = &self.item_to_monitor.node_id;
+            address_space.events_since(self.last_event_number)
+                .filter(|event| address_space.is_event_notifier_of(notifier_id, &event.source_node) && event_filter::matches(address_space, filter, event))
+                .map(|event| event_filter::select_fields(address_space, filter, event))
+                .collect::<Vec<Vec<Variant>>>()
+        } else {
+            Vec::new()
+        };
+        self.last_event_number = address_space.last_event_number();
+        let has_events = !events.is_empty();
+        let client_handle = self.client_handle;
+        for event_fields in events {
+            self.enqueue_event_notification(EventFieldList {
+                client_handle,
+                event_fields: Some(event_fields),
+            });
+        }
+        has_events
+    }
+
+    /// Enqueues the fields of the refresh start event, the condition events that the item's
+    /// notifier is notified of and that pass its where clause, and the refresh end event. An
+    /// item that is not monitoring events or is disabled is not refreshed.
+    pub fn refresh_conditions(&mut self, address_space: &AddressSpace, refresh_start: &Event, conditions: &[Event], refresh_end: &Event) {
+        if self.monitoring_mode == MonitoringMode::Disabled {
+            return;
+        }
+        let events = if let FilterType::EventFilter(ref filter) = self.filter {
+            let notifier_id = &self.item_to_monitor.node_id;
+            let mut events = vec![event_filter::select_fields(address_space, filter, refresh_start)];
+            events.extend(conditions.iter()
+                .filter(|event| address_space.is_event_notifier_of(notifier_id, &event.source_node) && event_filter::matches(address_space, filter, event))
+                .map(|event| event_filter::select_fields(address_space, filter, event)));
+            events.push(event_filter::select_fields(address_space, filter, refresh_end));
+            events
+        } else {
+            return;
+        };
+        let client_handle = self.client_handle;
+        for event_fields in events {
+            self.enqueue_event_notification(EventFieldList {
+                client_handle,
+                event_fields: Some(event_fields),
+            });
+        }
+    }
+
+    /// Enqueues an event notification for the monitored item
+    pub fn enqueue_event_notification(&mut self, event_notification: EventFieldList) {
+        if self.event_queue.len() == self.queue_size {
+            trace!("Event overflow, node {:?}", self.item_to_monitor.node_id);
+            if self.discard_oldest {
+                let _ = self.event_queue.pop_front();
+            } else {
+                let _ = self.event_queue.pop_back();
+            }
+            self.queue_overflow = true;
+        }
+        self.event_queue.push_back(event_notification);
+    }
+
+    /// Retrieves all the event notifications from the queue, oldest to newest
+    pub fn all_event_notifications(&mut self) -> Option<Vec<EventFieldList>> {
+        if self.event_queue.is_empty() {
+            None
+        } else {
+            self.queue_overflow = false;
+            Some(self.event_queue.drain(..).collect())
+        }
+    }
+
     /// Enqueues a notification message for the monitored item
     pub fn enqueue_notification_message(&mut self, mut notification: MonitoredItemNotification) {
         // test for overflow
@@ -302,18 +562,22 @@ impl MonitoredItem {
     }
 
     /// Takes the requested queue size and ensures it is within the range supported by the server
-    fn sanitize_queue_size(requested_queue_size: usize) -> usize {
-        if requested_queue_size == 0 {
+    fn sanitize_queue_size(requested_queue_size: usize, is_event_item: bool) -> usize {
+        if is_event_item {
+            if requested_queue_size == 0 {
+                // For event monitored items 0 -> the default queue size for event notifications
+                constants::DEFAULT_EVENT_QUEUE_SIZE
+            } else if requested_queue_size > constants::MAX_EVENT_QUEUE_SIZE {
+                // MaxUInt32 is the maximum queue size the server supports for event notifications
+                constants::MAX_EVENT_QUEUE_SIZE
+            } else {
+                requested_queue_size
+            }
+        } else if requested_queue_size == 0 {
             // For data monitored items 0 -> 1
             1
-            // Future - for event monitored items, queue size should be the default queue size for event notifications
-        } else if requested_queue_size == 1 {
-            1
-            // Future - for event monitored items, the minimum queue size the server requires for event notifications
         } else if requested_queue_size > constants::MAX_DATA_CHANGE_QUEUE_SIZE {
             constants::MAX_DATA_CHANGE_QUEUE_SIZE
-            // Future - for event monitored items MaxUInt32 returns the maximum queue size the server support
-            // for event notifications
         } else {
             requested_queue_size
         }
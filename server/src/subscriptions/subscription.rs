@@ -5,6 +5,7 @@ use chrono;
 
 use opcua_types::*;
 use opcua_types::status_code::StatusCode;
+use opcua_types::node_ids::{ObjectId, ObjectTypeId};
 use opcua_types::service_types::{TimestampsToReturn, NotificationMessage, MonitoredItemCreateRequest, MonitoredItemCreateResult, MonitoredItemModifyRequest, MonitoredItemModifyResult};
 
 use crate::{
@@ -13,6 +14,7 @@ use crate::{
     subscriptions::monitored_item::MonitoredItem,
     address_space::AddressSpace,
     diagnostics::ServerDiagnostics,
+    events::event::Event,
 };
 
 /// The state of the subscription
@@ -177,15 +179,21 @@ impl Subscription {
     }
 
     /// Creates monitored items on the specified subscription, returning the creation results
-    pub fn create_monitored_items(&mut self, timestamps_to_return: TimestampsToReturn, items_to_create: &[MonitoredItemCreateRequest]) -> Vec<MonitoredItemCreateResult> {
+    pub fn create_monitored_items(&mut self, address_space: &AddressSpace, timestamps_to_return: TimestampsToReturn, items_to_create: &[MonitoredItemCreateRequest]) -> Vec<MonitoredItemCreateResult> {
         self.reset_lifetime_counter();
 
         // Add items to the subscription if they're not already in its
         items_to_create.iter().map(|item_to_create| {
             // Create a monitored item, if possible
             let monitored_item_id = self.next_monitored_item_id;
-            match MonitoredItem::new(monitored_item_id, timestamps_to_return, item_to_create) {
-                Ok(monitored_item) => {
+            let monitored_item = MonitoredItem::new(monitored_item_id, timestamps_to_return, item_to_create)
+                .map_err(|status_code| (status_code, ExtensionObject::null()))
+                .and_then(|mut monitored_item| {
+                    let filter_result = monitored_item.validate(address_space)?;
+                    Ok((monitored_item, filter_result))
+                });
+            match monitored_item {
+                Ok((monitored_item, filter_result)) => {
                     // Register the item with the subscription
                     let revised_sampling_interval = monitored_item.sampling_interval;
                     let revised_queue_size = monitored_item.queue_size as u32;
@@ -196,16 +204,16 @@ impl Subscription {
                         monitored_item_id,
                         revised_sampling_interval,
                         revised_queue_size,
-                        filter_result: ExtensionObject::null(),
+                        filter_result,
                     }
                 }
-                Err(status_code) => {
+                Err((status_code, filter_result)) => {
                     MonitoredItemCreateResult {
                         status_code,
                         monitored_item_id: 0,
                         revised_sampling_interval: 0f64,
                         revised_queue_size: 0,
-                        filter_result: ExtensionObject::null(),
+                        filter_result,
                     }
                 }
             }
@@ -213,13 +221,13 @@ impl Subscription {
     }
 
     /// Modify the specified monitored items, returning a result for each
-    pub fn modify_monitored_items(&mut self, timestamps_to_return: TimestampsToReturn, items_to_modify: &[MonitoredItemModifyRequest]) -> Vec<MonitoredItemModifyResult> {
+    pub fn modify_monitored_items(&mut self, address_space: &AddressSpace, timestamps_to_return: TimestampsToReturn, items_to_modify: &[MonitoredItemModifyRequest]) -> Vec<MonitoredItemModifyResult> {
         self.reset_lifetime_counter();
         items_to_modify.iter().map(|item_to_modify| {
             match self.monitored_items.get_mut(&item_to_modify.monitored_item_id) {
                 Some(monitored_item) => {
                     // Try to change the monitored item according to the modify request
-                    let modify_result = monitored_item.modify(timestamps_to_return, item_to_modify);
+                    let modify_result = monitored_item.modify(address_space, timestamps_to_return, item_to_modify);
                     match modify_result {
                         Ok(filter_result) => MonitoredItemModifyResult {
                             status_code: StatusCode::Good,
@@ -227,11 +235,11 @@ impl Subscription {
                             revised_queue_size: monitored_item.queue_size as u32,
                             filter_result,
                         },
-                        Err(err) => MonitoredItemModifyResult {
-                            status_code: err,
+                        Err((status_code, filter_result)) => MonitoredItemModifyResult {
+                            status_code,
                             revised_sampling_interval: 0f64,
                             revised_queue_size: 0,
-                            filter_result: ExtensionObject::null(),
+                            filter_result,
                         }
                     }
                 }
@@ -251,12 +259,64 @@ impl Subscription {
         self.reset_lifetime_counter();
         items_to_delete.iter().map(|item_to_delete| {
             match self.monitored_items.remove(item_to_delete) {
-                Some(_) => StatusCode::Good,
+                Some(_) => {
+                    // Remove the links from the items that trigger it
+                    for monitored_item in self.monitored_items.values_mut() {
+                        monitored_item.triggered_items.remove(item_to_delete);
+                    }
+                    StatusCode::Good
+                }
+                None => StatusCode::BadMonitoredItemIdInvalid
+            }
+        }).collect()
+    }
+
+    /// Sets the monitoring mode of the specified monitored items (by item id), returning a status
+    /// code for each
+    pub fn set_monitoring_mode(&mut self, monitoring_mode: MonitoringMode, monitored_item_ids: &[u32]) -> Vec<StatusCode> {
+        self.reset_lifetime_counter();
+        monitored_item_ids.iter().map(|monitored_item_id| {
+            match self.monitored_items.get_mut(monitored_item_id) {
+                Some(monitored_item) => {
+                    monitored_item.set_monitoring_mode(monitoring_mode);
+                    StatusCode::Good
+                }
                 None => StatusCode::BadMonitoredItemIdInvalid
             }
         }).collect()
     }
 
+    /// Removes and then adds links from the triggering item to the items it triggers, returning
+    /// a status code for each link to add and each link to remove. Fails if there is no
+    /// triggering item with the id.
+    pub fn set_triggering(&mut self, triggering_item_id: u32, links_to_add: &[u32], links_to_remove: &[u32]) -> Result<(Vec<StatusCode>, Vec<StatusCode>), StatusCode> {
+        self.reset_lifetime_counter();
+        if !self.monitored_items.contains_key(&triggering_item_id) {
+            return Err(StatusCode::BadMonitoredItemIdInvalid);
+        }
+        let add_results = links_to_add.iter().map(|monitored_item_id| {
+            if self.monitored_items.contains_key(monitored_item_id) {
+                StatusCode::Good
+            } else {
+                StatusCode::BadMonitoredItemIdInvalid
+            }
+        }).collect::<Vec<StatusCode>>();
+        let triggering_item = self.monitored_items.get_mut(&triggering_item_id).unwrap();
+        let remove_results = links_to_remove.iter().map(|monitored_item_id| {
+            if triggering_item.triggered_items.remove(monitored_item_id) {
+                StatusCode::Good
+            } else {
+                StatusCode::BadMonitoredItemIdInvalid
+            }
+        }).collect();
+        links_to_add.iter().zip(add_results.iter())
+            .filter(|(_, status_code)| status_code.is_good())
+            .for_each(|(monitored_item_id, _)| {
+                triggering_item.triggered_items.insert(*monitored_item_id);
+            });
+        Ok((add_results, remove_results))
+    }
+
     // Returns two vecs representing the server and client handles for each monitored item.
     // Called from the GetMonitoredItems impl
     pub fn get_handles(&self) -> (Vec<u32>, Vec<u32>) {
@@ -271,6 +331,18 @@ impl Subscription {
         self.resend_data = true;
     }
 
+    /// Refreshes the conditions of the items monitoring events, as the ConditionRefresh method
+    /// does. Each item is sent a RefreshStartEvent, then the events of the retained conditions that
+    /// pass its filter and then a RefreshEndEvent.
+    pub fn refresh_conditions(&mut self, address_space: &AddressSpace, conditions: &[Event]) {
+        let server_id: NodeId = ObjectId::Server.into();
+        let refresh_start = Event::new(ObjectTypeId::RefreshStartEventType, &server_id, "Server", "", 100);
+        let refresh_end = Event::new(ObjectTypeId::RefreshEndEventType, &server_id, "Server", "", 100);
+        for monitored_item in self.monitored_items.values_mut() {
+            monitored_item.refresh_conditions(address_space, &refresh_start, conditions, &refresh_end);
+        }
+    }
+
     /// Checks the subscription and monitored items for state change, messages. If the tick does
     /// nothing, the function returns None. Otherwise it returns one or more messages in an Vec.
     pub fn tick(&mut self, address_space: &AddressSpace, tick_reason: TickReason, publishing_req_queued: bool, now: &DateTimeUtc) -> Option<NotificationMessage> {
@@ -366,22 +438,41 @@ impl Subscription {
     /// The function returns notifications and a more_notifications boolean.
     fn tick_monitored_items(&mut self, address_space: &AddressSpace, now: &DateTimeUtc, publishing_interval_elapsed: bool, resend_data: bool) -> (Option<NotificationMessage>, bool) {
         let mut notification_messages = Vec::new();
+        let mut event_notifications = Vec::new();
+        let mut triggered_items = Vec::new();
         for (_, monitored_item) in &mut self.monitored_items {
-            // If this returns true then the monitored item wants to report its notification
-            let _ = monitored_item.tick(address_space, now, publishing_interval_elapsed, resend_data);
-            if publishing_interval_elapsed {
+            // If this returns true then the monitored item has a new notification and triggers
+            // the items linked to it
+            if monitored_item.tick(address_space, now, publishing_interval_elapsed, resend_data) {
+                triggered_items.extend(monitored_item.triggered_items.iter().cloned());
+            }
+        }
+        for monitored_item_id in triggered_items {
+            if let Some(monitored_item) = self.monitored_items.get_mut(&monitored_item_id) {
+                monitored_item.trigger();
+            }
+        }
+        if publishing_interval_elapsed {
+            for (_, monitored_item) in &mut self.monitored_items {
+                // Items that are sampling keep their notifications until they are triggered
+                if !monitored_item.is_reported() {
+                    continue;
+                }
                 // Take some / all of the monitored item's pending notifications
                 if let Some(mut item_notification_messages) = monitored_item.all_notification_messages() {
                     notification_messages.append(&mut item_notification_messages);
                 }
+                if let Some(mut item_event_notifications) = monitored_item.all_event_notifications() {
+                    event_notifications.append(&mut item_event_notifications);
+                }
             }
         }
 
-        if !notification_messages.is_empty() {
+        if !notification_messages.is_empty() || !event_notifications.is_empty() {
             use std;
             debug!("Create notification for subscription {}, sequence number {}", self.subscription_id, self.next_sequence_number);
             // Create a notification message and push it onto the queue
-            let notification = NotificationMessage::data_change(self.next_sequence_number, DateTime::now(), notification_messages);
+            let notification = NotificationMessage::new(self.next_sequence_number, DateTime::now(), notification_messages, event_notifications);
             // Advance next sequence number
             self.next_sequence_number = if self.next_sequence_number == std::u32::MAX {
                 1
@@ -394,6 +485,13 @@ impl Subscription {
         }
     }
 
+    /// Creates a notification message that tells the client of a change to the status of the
+    /// subscription, e.g. that it was transferred to another session. Like a keep alive, it has
+    /// the next sequence number but doesn't use it up.
+    pub fn status_change_notification(&self, status: StatusCode) -> NotificationMessage {
+        NotificationMessage::status_change(self.next_sequence_number, DateTime::now(), status)
+    }
+
     // See OPC UA Part 4 5.13.1.2 State Table
     //
     // This function implements the main guts of updating the subscription's state according to
@@ -40,6 +40,9 @@ pub struct Subscriptions {
     // Notifications that have been sent but have yet to be acknowledged (retransmission queue).
     // Key is (subscription_id, sequence_number). Value is notification message.
     retransmission_queue: BTreeMap<(u32, u32), NotificationMessage>,
+    // Status change notifications of the subscriptions that were transferred to another session,
+    // waiting for publish requests. Value is subscription id and notification message.
+    status_change_queue: VecDeque<(u32, NotificationMessage)>,
 }
 
 
@@ -54,6 +57,7 @@ impl Subscriptions {
             subscriptions: BTreeMap::new(),
             transmission_queue: VecDeque::with_capacity(max_publish_requests),
             retransmission_queue: BTreeMap::new(),
+            status_change_queue: VecDeque::new(),
         }
     }
 
@@ -75,6 +79,22 @@ impl Subscriptions {
         }
     }
 
+    /// Discards the queued publish requests and the publish responses that have not been taken,
+    /// e.g. because the connection they came from has gone. Notifications in the responses remain
+    /// in the retransmission queue.
+    pub fn discard_publish_requests(&mut self) {
+        self.publish_request_queue.clear();
+        self.publish_response_queue.clear();
+    }
+
+    /// Deletes all of the subscriptions along with the notifications that they have queued
+    pub fn delete_all(&mut self) {
+        self.subscriptions.clear();
+        self.transmission_queue.clear();
+        self.retransmission_queue.clear();
+        self.status_change_queue.clear();
+    }
+
     /// Returns the maximum number of subscriptions supported
     pub fn max_subscriptions(&self) -> usize {
         self.max_subscriptions
@@ -144,6 +164,35 @@ impl Subscriptions {
         self.subscriptions.get_mut(&subscription_id)
     }
 
+    /// Removes a subscription to transfer it to another session, returning it with the
+    /// notifications of its retransmission queue. The client is told that the subscription was
+    /// transferred on its next publish.
+    pub fn transfer_out(&mut self, subscription_id: u32) -> Option<(Subscription, Vec<NotificationMessage>)> {
+        let subscription = self.subscriptions.remove(&subscription_id)?;
+        let keys = self.retransmission_queue.keys()
+            .filter(|k| k.0 == subscription_id)
+            .cloned()
+            .collect::<Vec<(u32, u32)>>();
+        let notification_messages = keys.iter()
+            .filter_map(|k| self.retransmission_queue.remove(k))
+            .collect();
+        let status_change = subscription.status_change_notification(StatusCode::GoodSubscriptionTransferred);
+        self.status_change_queue.push_back((subscription_id, status_change));
+        Some((subscription, notification_messages))
+    }
+
+    /// Adds a subscription transferred from another session with the notifications of its
+    /// retransmission queue, returning the sequence numbers of the notifications that are
+    /// available for republishing.
+    pub fn transfer_in(&mut self, subscription: Subscription, notification_messages: Vec<NotificationMessage>) -> Option<Vec<u32>> {
+        let subscription_id = subscription.subscription_id;
+        notification_messages.into_iter().for_each(|notification_message| {
+            self.retransmission_queue.insert((subscription_id, notification_message.sequence_number), notification_message);
+        });
+        self.subscriptions.insert(subscription_id, subscription);
+        self.available_sequence_numbers(subscription_id)
+    }
+
     /// The tick causes the subscription manager to iterate through individual subscriptions calling tick
     /// on each in order of priority. In each case this could generate data change notifications. Data change
     /// notifications will be attached to the next available publish response and queued for sending
@@ -188,6 +237,13 @@ impl Subscriptions {
             }
         }
 
+        // Tell the client about the subscriptions that were transferred to another session
+        while !self.status_change_queue.is_empty() && !self.publish_request_queue.is_empty() {
+            let (subscription_id, notification_message) = self.status_change_queue.pop_front().unwrap();
+            let publish_request = self.publish_request_queue.pop_back().unwrap();
+            self.transmission_queue.push_front((subscription_id, publish_request, notification_message));
+        }
+
         // Iterate through notifications from oldest to latest in the transmission making publish
         // responses.
         while !self.transmission_queue.is_empty() {
@@ -200,8 +256,11 @@ impl Subscriptions {
             // Get a list of available sequence numbers
             let available_sequence_numbers = self.available_sequence_numbers(subscription_id);
 
-            // The notification to be sent is now put into the retransmission queue
-            self.retransmission_queue.insert((subscription_id, notification_message.sequence_number), notification_message.clone());
+            // The notification to be sent is now put into the retransmission queue, unless its
+            // subscription was transferred away
+            if self.subscriptions.contains_key(&subscription_id) {
+                self.retransmission_queue.insert((subscription_id, notification_message.sequence_number), notification_message.clone());
+            }
 
             // Acknowledge results
             let results = self.process_subscription_acknowledgements(&publish_request.request);
@@ -299,7 +358,7 @@ impl Subscriptions {
     }
 
     /// Returns the array of available sequence numbers in the retransmission queue for the specified subscription
-    fn available_sequence_numbers(&self, subscription_id: u32) -> Option<Vec<u32>> {
+    pub fn available_sequence_numbers(&self, subscription_id: u32) -> Option<Vec<u32>> {
         if self.retransmission_queue.is_empty() {
             None
         } else {
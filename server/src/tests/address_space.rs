@@ -1,3 +1,5 @@
+use opcua_types::node_ids::VariableTypeId;
+
 use crate::prelude::*;
 
 use crate::tests::*;
@@ -131,7 +133,7 @@ fn find_node_by_id() {
 fn find_references_from() {
     let address_space = make_sample_address_space();
 
-    let references = address_space.find_references_from(&AddressSpace::root_folder_id(), Some((ReferenceTypeId::Organizes, false)));
+    let references = address_space.find_references_from(&AddressSpace::root_folder_id(), Some((ReferenceTypeId::Organizes.into(), false)));
     assert!(references.is_some());
     let references = references.as_ref().unwrap();
     for r in references {
@@ -147,7 +149,7 @@ fn find_references_from() {
     }
     assert_eq!(references.len(), 4);
 
-    let references = address_space.find_references_from(&AddressSpace::objects_folder_id(), Some((ReferenceTypeId::Organizes, false)));
+    let references = address_space.find_references_from(&AddressSpace::objects_folder_id(), Some((ReferenceTypeId::Organizes.into(), false)));
     assert!(references.is_some());
     let references = references.unwrap();
     for r in references.iter() {
@@ -156,7 +158,7 @@ fn find_references_from() {
     assert_eq!(references.len(), 2);
 
     let r1 = &references[0];
-    assert_eq!(r1.reference_type_id, ReferenceTypeId::Organizes);
+    assert_eq!(r1.reference_type_id, ReferenceTypeId::Organizes.into());
     let child_node_id = r1.node_id.clone();
 
     let child = address_space.find_node(&child_node_id);
@@ -168,10 +170,10 @@ fn find_references_to() {
     let address_space = make_sample_address_space();
 
     //println!("{:#?}", address_space);
-    let references = address_space.find_references_to(&AddressSpace::root_folder_id(), Some((ReferenceTypeId::Organizes, false)));
+    let references = address_space.find_references_to(&AddressSpace::root_folder_id(), Some((ReferenceTypeId::Organizes.into(), false)));
     assert!(references.is_none());
 
-    let references = address_space.find_references_to(&AddressSpace::objects_folder_id(), Some((ReferenceTypeId::Organizes, false)));
+    let references = address_space.find_references_to(&AddressSpace::objects_folder_id(), Some((ReferenceTypeId::Organizes.into(), false)));
     assert!(references.is_some());
     let references = references.unwrap();
     assert_eq!(references.len(), 1);
@@ -247,4 +249,413 @@ fn variable_builder() {
     assert_eq!(v.array_dimensions().unwrap(), vec![1, 2, 3]);
     assert_eq!(v.historizing(), true);
     assert_eq!(v.value().value.unwrap(), Variant::from(999));
+    assert_eq!(v.minimum_sampling_interval(), Some(123));
+}
+
+const TEST_NODESET: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<UANodeSet xmlns="http://opcfoundation.org/UA/2011/03/UANodeSet.xsd" xmlns:uax="http://opcfoundation.org/UA/2008/02/Types.xsd">
+  <NamespaceUris>
+    <Uri>urn:test:model</Uri>
+  </NamespaceUris>
+  <Aliases>
+    <Alias Alias="Int32">i=6</Alias>
+    <Alias Alias="Organizes">i=35</Alias>
+    <Alias Alias="HasComponent">i=47</Alias>
+    <Alias Alias="HasSubtype">i=45</Alias>
+    <Alias Alias="HasTypeDefinition">i=40</Alias>
+  </Aliases>
+  <UAObjectType NodeId="ns=1;i=1000" BrowseName="1:MachineType">
+    <DisplayName>MachineType</DisplayName>
+    <References>
+      <Reference ReferenceType="HasSubtype" IsForward="false">i=58</Reference>
+    </References>
+  </UAObjectType>
+  <UAObject NodeId="ns=1;i=2000" BrowseName="1:Machine" EventNotifier="1">
+    <DisplayName Locale="en">Machine</DisplayName>
+    <Description>A machine</Description>
+    <References>
+      <Reference ReferenceType="Organizes" IsForward="false">i=85</Reference>
+      <Reference ReferenceType="HasTypeDefinition">ns=1;i=1000</Reference>
+      <Reference ReferenceType="HasComponent">ns=1;s=Speed</Reference>
+      <Reference ReferenceType="HasComponent">ns=1;i=2002</Reference>
+    </References>
+  </UAObject>
+  <UAVariable NodeId="ns=1;s=Speed" BrowseName="1:Speed" DataType="Int32" AccessLevel="3" MinimumSamplingInterval="99.6">
+    <References>
+      <Reference ReferenceType="HasComponent" IsForward="false">ns=1;i=2000</Reference>
+      <Reference ReferenceType="HasTypeDefinition">i=63</Reference>
+    </References>
+    <Value>
+      <uax:Int32>42</uax:Int32>
+    </Value>
+  </UAVariable>
+  <UAVariable NodeId="ns=1;i=2001" BrowseName="1:Names" DataType="i=12" ValueRank="1" ArrayDimensions="2" ParentNodeId="ns=1;i=2000">
+    <Value>
+      <uax:ListOfString>
+        <uax:String>Left</uax:String>
+        <uax:String>Right</uax:String>
+      </uax:ListOfString>
+    </Value>
+  </UAVariable>
+  <UAMethod NodeId="ns=1;i=2002" BrowseName="1:Start" UserExecutable="false" />
+</UANodeSet>
+"#;
+
+#[test]
+fn import_nodeset() {
+    let mut address_space = AddressSpace::new();
+    // The namespace of the document is mapped onto the next free index
+    assert_eq!(address_space.register_namespace("urn:test:other"), 1);
+    assert_eq!(address_space.register_namespace("urn:test:other"), 1);
+
+    let node_ids = address_space.import_nodeset(TEST_NODESET.as_bytes()).unwrap();
+    assert_eq!(node_ids.len(), 5);
+    assert_eq!(address_space.namespace_index("urn:test:model"), Some(2));
+    assert_eq!(address_space.namespace_uris(), vec!["http://opcfoundation.org/UA/", "urn:test:other", "urn:test:model"]);
+
+    let machine_type_id = NodeId::new(2, 1000);
+    let machine_id = NodeId::new(2, 2000);
+    let speed_id = NodeId::new(2, "Speed");
+    let names_id = NodeId::new(2, 2001);
+    let start_id = NodeId::new(2, 2002);
+    assert_eq!(node_ids, vec![machine_type_id.clone(), machine_id.clone(), speed_id.clone(), names_id.clone(), start_id.clone()]);
+
+    // Attributes
+    let machine = address_space.find_node(&machine_id).unwrap().as_node();
+    assert_eq!(machine.node_class(), NodeClass::Object);
+    assert_eq!(machine.browse_name(), QualifiedName::new(2, "Machine"));
+    assert_eq!(machine.display_name(), LocalizedText::new("en", "Machine"));
+    assert_eq!(machine.description().unwrap(), LocalizedText::new("", "A machine"));
+    assert_eq!(address_space.find_node(&machine_type_id).unwrap().as_node().node_class(), NodeClass::ObjectType);
+
+    let speed = address_space.find_variable(speed_id.clone()).unwrap();
+    assert_eq!(speed.browse_name(), QualifiedName::new(2, "Speed"));
+    assert_eq!(speed.display_name(), LocalizedText::new("", "Speed"));
+    assert_eq!(speed.value().value.unwrap(), Variant::Int32(42));
+    assert_eq!(speed.value_rank(), -1);
+    assert_eq!(speed.access_level(), crate::address_space::AccessLevel::CURRENT_READ | crate::address_space::AccessLevel::CURRENT_WRITE);
+    // A fractional sampling interval is rounded to whole milliseconds
+    assert_eq!(speed.minimum_sampling_interval(), Some(100));
+
+    let names = address_space.find_variable(names_id.clone()).unwrap();
+    assert_eq!(names.value().value.unwrap(), Variant::from(&["Left", "Right"][..]));
+    assert_eq!(names.value_rank(), 1);
+    assert_eq!(names.array_dimensions().unwrap(), vec![2]);
+
+    let start = address_space.find_node(&start_id).unwrap().as_node();
+    assert_eq!(start.node_class(), NodeClass::Method);
+    assert_eq!(start.find_attribute(AttributeId::UserExecutable).unwrap().value.unwrap(), Variant::Boolean(false));
+
+    // References, including those declared by only one of the nodes they connect
+    assert!(address_space.has_reference(&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, &machine_type_id));
+    assert!(address_space.has_reference(&ObjectId::ObjectsFolder.into(), ReferenceTypeId::Organizes, &machine_id));
+    assert!(address_space.has_reference(&machine_id, ReferenceTypeId::HasTypeDefinition, &machine_type_id));
+    assert!(address_space.has_reference(&machine_id, ReferenceTypeId::HasComponent, &speed_id));
+    assert!(address_space.has_reference(&machine_id, ReferenceTypeId::HasComponent, &start_id));
+    assert!(address_space.has_reference(&speed_id, ReferenceTypeId::HasTypeDefinition, &opcua_types::node_ids::VariableTypeId::BaseDataVariableType.into()));
+    assert!(!address_space.has_reference(&speed_id, ReferenceTypeId::HasComponent, &machine_id));
+
+    // Importing the same nodes again fails without changing anything
+    assert_eq!(address_space.import_nodeset(TEST_NODESET.as_bytes()).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert_eq!(address_space.namespace_uris().len(), 3);
+}
+
+#[test]
+fn import_nodeset_invalid() {
+    let mut address_space = AddressSpace::new();
+    assert_eq!(address_space.import_nodeset("<UANodeSet><UAObject".as_bytes()).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(address_space.import_nodeset("<NodeSet />".as_bytes()).unwrap_err(), StatusCode::BadDecodingError);
+    // A namespace index that isn't in the namespace URIs
+    assert_eq!(address_space.import_nodeset(r#"<UANodeSet><UAObject NodeId="ns=1;i=1" BrowseName="1:A" /></UANodeSet>"#.as_bytes()).unwrap_err(), StatusCode::BadDecodingError);
+    // A node id that appears twice
+    let xml = r#"<UANodeSet><UAObject NodeId="i=90000" BrowseName="A" /><UAObject NodeId="i=90000" BrowseName="B" /></UANodeSet>"#;
+    assert_eq!(address_space.import_nodeset(xml.as_bytes()).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert!(!address_space.node_exists(&NodeId::new(0, 90000)));
+    // The namespaces of a document that fails are not registered
+    let namespace_uris = address_space.namespace_uris();
+    let xml = r#"<UANodeSet><NamespaceUris><Uri>urn:test:failed</Uri></NamespaceUris><UAObject NodeId="ns=1;i=1" BrowseName="1:A" /><UAObject NodeId="ns=1;i=1" BrowseName="1:B" /></UANodeSet>"#;
+    assert_eq!(address_space.import_nodeset(xml.as_bytes()).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert_eq!(address_space.namespace_uris(), namespace_uris);
+}
+
+#[test]
+fn export_nodeset() {
+    let mut address_space = AddressSpace::new();
+    address_space.register_namespace("urn:test:other");
+    address_space.import_nodeset(TEST_NODESET.as_bytes()).unwrap();
+    let speed_id = NodeId::new(2, "Speed");
+    address_space.find_variable_mut(speed_id.clone()).unwrap().set_attribute(AttributeId::Description, DataValue::new(LocalizedText::new("en", "Rotations a minute"))).unwrap();
+
+    let mut xml = Vec::new();
+    address_space.export_nodeset(&[2], &mut xml).unwrap();
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<UANodeSet"));
+
+    // Namespace 2 of the address space is namespace 1 of the document
+    let node_set = XmlNode::parse(&xml).unwrap();
+    let namespace_uris = node_set.child("NamespaceUris").unwrap().children_named("Uri").map(|v| v.text.as_str()).collect::<Vec<&str>>();
+    assert_eq!(namespace_uris, vec!["urn:test:model"]);
+    let model = node_set.child("Models").unwrap().child("Model").unwrap();
+    assert_eq!(model.attribute("ModelUri"), Some("urn:test:model"));
+    assert_eq!(model.child("RequiredModel").unwrap().attribute("ModelUri"), Some("http://opcfoundation.org/UA/"));
+    let aliases = node_set.child("Aliases").unwrap().children_named("Alias")
+        .map(|v| (v.attribute("Alias").unwrap(), v.text.as_str())).collect::<Vec<(&str, &str)>>();
+    assert_eq!(aliases, vec![("HasComponent", "i=47"), ("HasSubtype", "i=45"), ("HasTypeDefinition", "i=40"), ("Int32", "i=6"), ("Organizes", "i=35"), ("String", "i=12")]);
+
+    // Nodes are in the order of their node ids
+    let node_ids = node_set.children.iter().filter_map(|v| v.attribute("NodeId")).collect::<Vec<&str>>();
+    assert_eq!(node_ids, vec!["ns=1;i=1000", "ns=1;i=2000", "ns=1;i=2001", "ns=1;i=2002", "ns=1;s=Speed"]);
+    let speed = node_set.children.iter().find(|v| v.attribute("NodeId") == Some("ns=1;s=Speed")).unwrap();
+    assert_eq!(speed.local_name(), "UAVariable");
+    assert_eq!(speed.attribute("BrowseName"), Some("1:Speed"));
+    assert_eq!(speed.attribute("DataType"), Some("Int32"));
+    assert_eq!(speed.attribute("AccessLevel"), Some("3"));
+    assert_eq!(speed.attribute("MinimumSamplingInterval"), Some("100"));
+    assert_eq!(speed.attribute("ValueRank"), None);
+    assert_eq!(speed.child("Value").unwrap().child("Int32").unwrap().text, "42");
+    let references = speed.child("References").unwrap().children_named("Reference")
+        .map(|v| (v.attribute("ReferenceType").unwrap(), v.attribute("IsForward"), v.text.as_str())).collect::<Vec<(&str, Option<&str>, &str)>>();
+    assert_eq!(references, vec![("HasTypeDefinition", None, "i=63"), ("HasComponent", Some("false"), "ns=1;i=2000")]);
+
+    // The document imports into another address space as the same nodes
+    let mut address_space2 = AddressSpace::new();
+    assert_eq!(address_space2.import_nodeset(xml.as_bytes()).unwrap().len(), 5);
+    let speed_id = NodeId::new(1, "Speed");
+    let speed = address_space2.find_variable(speed_id.clone()).unwrap();
+    assert_eq!(speed.description().unwrap(), LocalizedText::new("en", "Rotations a minute"));
+    assert_eq!(speed.value().value.unwrap(), Variant::Int32(42));
+    assert_eq!(speed.minimum_sampling_interval(), Some(100));
+    let names = address_space2.find_variable(NodeId::new(1, 2001)).unwrap();
+    assert_eq!(names.value().value.unwrap(), Variant::from(&["Left", "Right"][..]));
+    assert_eq!(names.array_dimensions().unwrap(), vec![2]);
+    let start = address_space2.find_node(&NodeId::new(1, 2002)).unwrap().as_node();
+    assert_eq!(start.find_attribute(AttributeId::UserExecutable).unwrap().value.unwrap(), Variant::Boolean(false));
+    assert!(address_space2.has_reference(&ObjectId::ObjectsFolder.into(), ReferenceTypeId::Organizes, &NodeId::new(1, 2000)));
+    assert!(address_space2.has_reference(&NodeId::new(1, 2000), ReferenceTypeId::HasComponent, &speed_id));
+
+    // Exporting again gives the same document
+    let mut xml2 = Vec::new();
+    address_space2.export_nodeset(&[1], &mut xml2).unwrap();
+    assert_eq!(String::from_utf8(xml2).unwrap(), xml);
+}
+
+#[test]
+fn instantiate_variable_type() {
+    let mut address_space = AddressSpace::new();
+    let analog_item_type_id: NodeId = VariableTypeId::AnalogItemType.into();
+    let analog_item_id = address_space.instantiate(&analog_item_type_id, &AddressSpace::objects_folder_id(), "Level", NodeIdStrategy::Numeric, &["EngineeringUnits"]).unwrap();
+
+    let analog_item = address_space.find_variable(analog_item_id.clone()).unwrap();
+    assert_eq!(analog_item.browse_name(), QualifiedName::new(0, "Level"));
+    assert!(address_space.has_reference(&analog_item_id, ReferenceTypeId::HasTypeDefinition, &analog_item_type_id));
+    assert!(address_space.has_reference(&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, &analog_item_id));
+
+    // The mandatory EURange and the selected optional EngineeringUnits, but not the other optional
+    // properties of the type and its supertype DataItemType
+    let properties = address_space.find_references_from(&analog_item_id, Some((ReferenceTypeId::HasProperty.into(), false))).unwrap();
+    let names = properties.iter()
+        .map(|r| address_space.find_node(&r.node_id).unwrap().as_node().browse_name().name.as_ref().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["EURange", "EngineeringUnits"]);
+
+    let eu_range_id = &properties[0].node_id;
+    let eu_range = address_space.find_variable_by_ref(eu_range_id).unwrap();
+    assert_eq!(eu_range.find_attribute(AttributeId::DataType).unwrap().value.unwrap(), Variant::from(NodeId::new(0, 884)));
+    assert!(address_space.has_reference(eu_range_id, ReferenceTypeId::HasTypeDefinition, &VariableTypeId::PropertyType.into()));
+    // Instances have no modelling rules
+    assert!(address_space.find_references_from(eu_range_id, Some((ReferenceTypeId::HasModellingRule.into(), false))).is_none());
+}
+
+/// Adds a device type with a serial number, and a pump type that is a subtype of it with a motor
+/// whose type has a temperature and a start method
+fn add_pump_types(address_space: &mut AddressSpace) {
+    let mandatory: NodeId = ObjectId::ModellingRule_Mandatory.into();
+    let optional: NodeId = ObjectId::ModellingRule_Optional.into();
+    let property_type: NodeId = VariableTypeId::PropertyType.into();
+    let data_variable_type: NodeId = VariableTypeId::BaseDataVariableType.into();
+    let (device_type_id, pump_type_id, motor_type_id) = (NodeId::new(1, "DeviceType"), NodeId::new(1, "PumpType"), NodeId::new(1, "MotorType"));
+
+    address_space.insert(ObjectType::new(&device_type_id, "DeviceType", "DeviceType", "", true), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    address_space.insert(Variable::new(&NodeId::new(1, "DeviceType.SerialNumber"), "SerialNumber", "SerialNumber", "", "Device"), Some(&[
+        (&device_type_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+        (&property_type, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+    address_space.insert(Variable::new(&NodeId::new(1, "DeviceType.Manufacturer"), "Manufacturer", "Manufacturer", "", "ACME"), Some(&[
+        (&device_type_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+        (&property_type, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&optional, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+
+    address_space.insert(ObjectType::new(&motor_type_id, "MotorType", "MotorType", "", false), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    address_space.insert(Variable::new(&NodeId::new(1, "MotorType.Temperature"), "Temperature", "Temperature", "", 20.0f64), Some(&[
+        (&motor_type_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&data_variable_type, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+    address_space.insert(Method::new(&NodeId::new(1, "MotorType.Start"), "Start", "Start", "", false, true, true), Some(&[
+        (&motor_type_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+
+    address_space.insert(ObjectType::new(&pump_type_id, "PumpType", "PumpType", "", false), Some(&[
+        (&device_type_id, ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    // Overrides the serial number of the device type
+    address_space.insert(Variable::new(&NodeId::new(1, "PumpType.SerialNumber"), "SerialNumber", "SerialNumber", "", "Pump"), Some(&[
+        (&pump_type_id, ReferenceTypeId::HasProperty, ReferenceDirection::Inverse),
+        (&property_type, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+    address_space.insert(Object::new(&NodeId::new(1, "PumpType.Motor"), "Motor", "Motor", ""), Some(&[
+        (&pump_type_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&motor_type_id, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+    // Declared by the motor of the pump type rather than the motor type
+    address_space.insert(Variable::new(&NodeId::new(1, "PumpType.Motor.Speed"), "Speed", "Speed", "", 0u32), Some(&[
+        (&NodeId::new(1, "PumpType.Motor"), ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+        (&data_variable_type, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        (&optional, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+    ]));
+}
+
+#[test]
+fn instantiate_object_type() {
+    let mut address_space = AddressSpace::new();
+    add_pump_types(&mut address_space);
+    let pump_type_id = NodeId::new(1, "PumpType");
+    let pump_id = NodeId::new(1, "Pump1");
+    let strategy = NodeIdStrategy::BrowsePath(pump_id.clone());
+    assert_eq!(address_space.instantiate(&pump_type_id, &AddressSpace::objects_folder_id(), "Pump1", strategy.clone(), &["Motor/Speed"]).unwrap(), pump_id);
+
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasTypeDefinition, &pump_type_id));
+    assert!(address_space.has_reference(&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, &pump_id));
+
+    // The serial number of the pump type overrides that of its supertype, and the optional
+    // manufacturer is left out
+    let serial_number_id = NodeId::new(1, "Pump1.SerialNumber");
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasProperty, &serial_number_id));
+    assert!(address_space.has_reference(&serial_number_id, ReferenceTypeId::HasTypeDefinition, &VariableTypeId::PropertyType.into()));
+    assert_eq!(address_space.find_variable(serial_number_id).unwrap().value().value.unwrap(), Variant::from("Pump"));
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pump1.Manufacturer")));
+
+    // The motor has the children of its type and of its declaration
+    let motor_id = NodeId::new(1, "Pump1.Motor");
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasComponent, &motor_id));
+    assert!(address_space.has_reference(&motor_id, ReferenceTypeId::HasTypeDefinition, &NodeId::new(1, "MotorType")));
+    let temperature_id = NodeId::new(1, "Pump1.Motor.Temperature");
+    assert!(address_space.has_reference(&motor_id, ReferenceTypeId::HasComponent, &temperature_id));
+    let temperature = address_space.find_variable(temperature_id).unwrap();
+    assert_eq!(temperature.value().value.unwrap(), Variant::Double(20.0));
+    assert_eq!(temperature.find_attribute(AttributeId::DataType).unwrap().value.unwrap(), Variant::from(NodeId::new(0, DataTypeId::Double as u32)));
+    assert!(address_space.node_exists(&NodeId::new(1, "Pump1.Motor.Speed")));
+    // The method is shared with the type
+    assert!(address_space.has_reference(&motor_id, ReferenceTypeId::HasComponent, &NodeId::new(1, "MotorType.Start")));
+
+    // The values of an instance are copies
+    let now = DateTime::now();
+    address_space.set_variable_value(NodeId::new(1, "Pump1.Motor.Temperature"), 30.0f64, &now, &now);
+    assert_eq!(address_space.get_variable_value(NodeId::new(1, "MotorType.Temperature")).unwrap().value.unwrap(), Variant::Double(20.0));
+
+    // A second instance with numeric ids has its own nodes
+    let pump2_id = address_space.instantiate(&pump_type_id, &pump_id, "Pump2", NodeIdStrategy::Numeric, &[]).unwrap();
+    assert!(address_space.has_reference(&pump_id, ReferenceTypeId::HasComponent, &pump2_id));
+    let motors = address_space.find_references_from(&pump2_id, Some((ReferenceTypeId::HasComponent.into(), false))).unwrap();
+    assert_eq!(motors.len(), 1);
+    assert_eq!(address_space.find_references_from(&motors[0].node_id, Some((ReferenceTypeId::HasComponent.into(), false))).unwrap().len(), 2);
+
+    // Errors
+    assert_eq!(address_space.instantiate(&pump_type_id, &AddressSpace::objects_folder_id(), "Pump1", strategy, &[]).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert_eq!(address_space.instantiate(&pump_type_id, &NodeId::new(1, "Missing"), "Pump3", NodeIdStrategy::Numeric, &[]).unwrap_err(), StatusCode::BadParentNodeIdInvalid);
+    assert_eq!(address_space.instantiate(&NodeId::new(1, "DeviceType"), &pump_id, "Device", NodeIdStrategy::Numeric, &[]).unwrap_err(), StatusCode::BadTypeDefinitionInvalid);
+    assert_eq!(address_space.instantiate(&AddressSpace::objects_folder_id(), &pump_id, "Objects", NodeIdStrategy::Numeric, &[]).unwrap_err(), StatusCode::BadTypeDefinitionInvalid);
+
+    // A child whose node id is in use fails the whole instantiation, leaving no nodes behind
+    let temperature_id = NodeId::new(1, "Pump3.Motor.Temperature");
+    address_space.add_variable(Variable::new(&temperature_id, "Temperature", "Temperature", "", 0f64), &AddressSpace::objects_folder_id()).unwrap();
+    let strategy = NodeIdStrategy::BrowsePath(NodeId::new(1, "Pump3"));
+    assert_eq!(address_space.instantiate(&pump_type_id, &AddressSpace::objects_folder_id(), "Pump3", strategy, &[]).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pump3")));
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pump3.SerialNumber")));
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pump3.Motor")));
+    assert!(address_space.node_exists(&temperature_id));
+    let organized = address_space.find_references_from(&AddressSpace::objects_folder_id(), Some((ReferenceTypeId::Organizes.into(), false))).unwrap();
+    assert!(organized.iter().all(|reference| reference.node_id != NodeId::new(1, "Pump3")));
+
+    // A type whose mandatory child is of a type that has a mandatory child of the first type
+    // can never be completed
+    let (pipe_type_id, valve_type_id) = (NodeId::new(1, "PipeType"), NodeId::new(1, "ValveType"));
+    let mandatory: NodeId = ObjectId::ModellingRule_Mandatory.into();
+    for (type_id, child_id, child_type_id) in &[(&pipe_type_id, NodeId::new(1, "PipeType.Valve"), &valve_type_id), (&valve_type_id, NodeId::new(1, "ValveType.Pipe"), &pipe_type_id)] {
+        address_space.insert(ObjectType::new(type_id, "", "", "", false), Some(&[
+            (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+        ]));
+        let name = child_id.identifier.to_string();
+        address_space.insert(Object::new(child_id, &name, &name, ""), Some(&[
+            (type_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+            (child_type_id, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+            (&mandatory, ReferenceTypeId::HasModellingRule, ReferenceDirection::Forward),
+        ]));
+    }
+    assert_eq!(address_space.instantiate(&pipe_type_id, &AddressSpace::objects_folder_id(), "Pipe1", NodeIdStrategy::BrowsePath(NodeId::new(1, "Pipe1")), &[]).unwrap_err(), StatusCode::BadTypeDefinitionInvalid);
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pipe1")));
+    assert!(!address_space.node_exists(&NodeId::new(1, "Pipe1.Valve")));
+}
+
+#[test]
+fn reference_type_subtypes() {
+    let address_space = AddressSpace::new();
+    let matches = |r1: ReferenceTypeId, r2: ReferenceTypeId, include_subtypes: bool| {
+        address_space.reference_type_matches(&r1.into(), &r2.into(), include_subtypes)
+    };
+    assert!(matches(ReferenceTypeId::Organizes, ReferenceTypeId::Organizes, false));
+    assert!(!matches(ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::Organizes, false));
+    assert!(matches(ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::Organizes, true));
+    assert!(matches(ReferenceTypeId::References, ReferenceTypeId::HasOrderedComponent, true));
+    assert!(matches(ReferenceTypeId::Aggregates, ReferenceTypeId::HasProperty, true));
+    assert!(matches(ReferenceTypeId::HasEventSource, ReferenceTypeId::HasNotifier, true));
+    assert!(matches(ReferenceTypeId::NonHierarchicalReferences, ReferenceTypeId::HasTypeDefinition, true));
+    assert!(!matches(ReferenceTypeId::HasProperty, ReferenceTypeId::Aggregates, true));
+    assert!(!matches(ReferenceTypeId::HierarchicalReferences, ReferenceTypeId::HasTypeDefinition, true));
+}
+
+#[test]
+fn custom_reference_type() {
+    let mut address_space = make_sample_address_space();
+    let has_pump_id = NodeId::new(1, "HasPump");
+    address_space.insert(ReferenceType::new(&has_pump_id, "HasPump", "HasPump", "", Some(LocalizedText::new("", "PumpOf")), false, false), Some(&[
+        (&ReferenceTypeId::HasComponent.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    assert!(address_space.is_reference_type(&has_pump_id));
+    assert!(!address_space.is_reference_type(&AddressSpace::objects_folder_id()));
+
+    let station_id = NodeId::new(1, "Station");
+    let pump_id = NodeId::new(1, "Pump");
+    address_space.add_folder_with_id(&station_id, "Station", "Station", &AddressSpace::objects_folder_id()).unwrap();
+    address_space.insert(Object::new(&pump_id, "Pump", "Pump", ""), None);
+    address_space.insert_reference(&station_id, &pump_id, has_pump_id.clone());
+    assert!(address_space.has_reference(&station_id, has_pump_id.clone(), &pump_id));
+    assert!(!address_space.has_reference(&station_id, ReferenceTypeId::HasComponent, &pump_id));
+
+    // Found by its own type, and by its supertypes when subtypes are included
+    let targets = |reference_type_id: NodeId, include_subtypes: bool| {
+        address_space.find_references_from(&station_id, Some((reference_type_id, include_subtypes)))
+            .map(|references| references.into_iter().map(|r| r.node_id).collect::<Vec<_>>())
+    };
+    assert_eq!(targets(has_pump_id.clone(), false), Some(vec![pump_id.clone()]));
+    assert_eq!(targets(ReferenceTypeId::HasComponent.into(), true), Some(vec![pump_id.clone()]));
+    assert_eq!(targets(ReferenceTypeId::HierarchicalReferences.into(), true), Some(vec![pump_id.clone()]));
+    assert_eq!(targets(ReferenceTypeId::HasComponent.into(), false), None);
+    assert_eq!(targets(ReferenceTypeId::Organizes.into(), true), None);
+    let references = address_space.find_references_to(&pump_id, Some((ReferenceTypeId::Aggregates.into(), true))).unwrap();
+    assert_eq!(references[0].reference_type_id, has_pump_id);
+    assert_eq!(references[0].node_id, station_id);
+
+    assert!(address_space.delete_reference(&station_id, &pump_id, has_pump_id.clone()));
+    assert!(address_space.find_references_to(&pump_id, Some((ReferenceTypeId::References.into(), true))).is_none());
 }
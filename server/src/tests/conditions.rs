@@ -0,0 +1,351 @@
+use opcua_types::node_ids::VariableTypeId;
+
+use crate::{
+    address_space::{object::Object, variable::Variable},
+    events::{condition, event::Event, event_filter, limit_alarm::{self, LimitAlarm}},
+};
+
+use super::*;
+
+fn make_address_space() -> (AddressSpace, NodeId) {
+    let mut address_space = AddressSpace::new();
+    let source_id = NodeId::new(1, "Boiler");
+    address_space.insert(Object::new(&source_id, "Boiler", "Boiler", ""), Some(&[
+        (&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+    ]));
+    (address_space, source_id)
+}
+
+fn field(event: &Event, browse_path: &[&str]) -> Option<Variant> {
+    let browse_path = browse_path.iter().map(|name| QualifiedName::new(0, name)).collect::<Vec<QualifiedName>>();
+    event.field(&browse_path)
+}
+
+/// Returns the last event raised, if any have been raised since the event number
+fn last_event(address_space: &AddressSpace, event_number: u64) -> Option<Event> {
+    address_space.events_since(event_number).last().cloned()
+}
+
+#[test]
+fn add_condition() {
+    let (mut address_space, source_id) = make_address_space();
+    let alarm_id = NodeId::new(1, "Alarm");
+
+    // The type must be a condition type and the source must exist
+    assert_eq!(condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::BaseEventType, &source_id, false).unwrap_err(), StatusCode::BadTypeDefinitionInvalid);
+    assert_eq!(condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &NodeId::new(1, "Missing"), false).unwrap_err(), StatusCode::BadNodeIdUnknown);
+    assert!(!address_space.node_exists(&alarm_id));
+
+    condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, true).unwrap();
+    assert_eq!(condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, true).unwrap_err(), StatusCode::BadNodeIdExists);
+    assert!(address_space.has_reference(&source_id, ReferenceTypeId::HasCondition, &alarm_id));
+    assert!(address_space.has_reference(&alarm_id, ReferenceTypeId::HasTypeDefinition, &ObjectTypeId::AlarmConditionType.into()));
+
+    // The state variables are two state variables
+    let active_state_id = NodeId::new(1, "Alarm.ActiveState");
+    assert!(address_space.has_reference(&alarm_id, ReferenceTypeId::HasComponent, &active_state_id));
+    assert!(address_space.has_reference(&active_state_id, ReferenceTypeId::HasTypeDefinition, &VariableTypeId::TwoStateVariableType.into()));
+    assert_eq!(address_space.get_variable_value(active_state_id).unwrap().value.unwrap(), Variant::from(LocalizedText::new("", "Inactive")));
+    assert_eq!(address_space.get_variable_value(NodeId::new(1, "Alarm.ActiveState.Id")).unwrap().value.unwrap(), Variant::from(false));
+
+    // A new alarm is enabled, inactive, acknowledged and confirmed, so it is not retained
+    assert!(condition::is_enabled(&address_space, &alarm_id));
+    let event = condition::condition_event(&address_space, &alarm_id).unwrap();
+    assert_eq!(event.event_type, ObjectTypeId::AlarmConditionType.into());
+    assert_eq!(event.source_node, source_id);
+    assert_eq!(event.source_name, UAString::from("Boiler"));
+    assert_eq!(event.condition_id, Some(alarm_id.clone()));
+    assert_eq!(field(&event, &["ConditionName"]), Some(Variant::from("Alarm")));
+    assert_eq!(field(&event, &["EnabledState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["AckedState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["ConfirmedState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(false)));
+    assert!(condition::retained_condition_events(&address_space).is_empty());
+
+    // An alarm that is not confirmable has no ConfirmedState, and a condition that is not
+    // acknowledgeable has no AckedState and cannot be activated
+    let off_normal_id = NodeId::new(1, "OffNormal");
+    condition::add_condition(&mut address_space, &off_normal_id, "OffNormal", ObjectTypeId::OffNormalAlarmType, &source_id, false).unwrap();
+    let event = condition::condition_event(&address_space, &off_normal_id).unwrap();
+    assert!(field(&event, &["ConfirmedState", "Id"]).is_none());
+    let condition_id = NodeId::new(1, "Condition");
+    condition::add_condition(&mut address_space, &condition_id, "Condition", ObjectTypeId::ConditionType, &source_id, false).unwrap();
+    let event = condition::condition_event(&address_space, &condition_id).unwrap();
+    assert!(field(&event, &["AckedState", "Id"]).is_none());
+    assert!(field(&event, &["ActiveState", "Id"]).is_none());
+    assert_eq!(condition::set_active(&mut address_space, &condition_id, true, 100, "Active").unwrap_err(), StatusCode::BadNotSupported);
+
+    assert!(condition::condition_event(&address_space, &source_id).is_none());
+}
+
+#[test]
+fn alarm_lifecycle() {
+    let (mut address_space, source_id) = make_address_space();
+    let alarm_id = NodeId::new(1, "Alarm");
+    condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, true).unwrap();
+
+    // Activating the alarm reports it as active and unacknowledged
+    let event_number = address_space.last_event_number();
+    condition::set_active(&mut address_space, &alarm_id, true, 500, "Too hot").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 500);
+    assert_eq!(event.message, LocalizedText::new("", "Too hot"));
+    assert_eq!(field(&event, &["ActiveState"]), Some(Variant::from(LocalizedText::new("", "Active"))));
+    assert_eq!(field(&event, &["ActiveState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["AckedState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["ConfirmedState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["LastSeverity"]), Some(Variant::from(0u16)));
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(true)));
+    assert_eq!(condition::retained_condition_events(&address_space), vec![event.clone()]);
+
+    // A change of severity is reported with the last severity
+    let event_number = address_space.last_event_number();
+    condition::set_active(&mut address_space, &alarm_id, true, 800, "Much too hot").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 800);
+    assert_eq!(field(&event, &["LastSeverity"]), Some(Variant::from(500u16)));
+
+    // Acknowledging requires the id of the last event
+    let comment = LocalizedText::new("", "Opened the valve");
+    assert_eq!(condition::acknowledge(&mut address_space, &alarm_id, &ByteString::from(vec![1u8, 2, 3]), &comment, "operator").unwrap_err(), StatusCode::BadEventIdUnknown);
+    let event_number = address_space.last_event_number();
+    condition::acknowledge(&mut address_space, &alarm_id, &event.event_id, &comment, "operator").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(field(&event, &["AckedState"]), Some(Variant::from(LocalizedText::new("", "Acknowledged"))));
+    assert_eq!(field(&event, &["AckedState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["Comment"]), Some(Variant::from(comment.clone())));
+    assert_eq!(field(&event, &["ClientUserId"]), Some(Variant::from("operator")));
+    assert_eq!(condition::acknowledge(&mut address_space, &alarm_id, &event.event_id, &comment, "operator").unwrap_err(), StatusCode::BadConditionBranchAlreadyAcked);
+
+    // An empty comment keeps the last comment
+    let event_number = address_space.last_event_number();
+    condition::confirm(&mut address_space, &alarm_id, &event.event_id, &LocalizedText::new("", ""), "supervisor").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(field(&event, &["ConfirmedState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["Comment"]), Some(Variant::from(comment)));
+    assert_eq!(field(&event, &["ClientUserId"]), Some(Variant::from("supervisor")));
+    assert_eq!(condition::confirm(&mut address_space, &alarm_id, &event.event_id, &LocalizedText::new("", ""), "supervisor").unwrap_err(), StatusCode::BadConditionBranchAlreadyConfirmed);
+
+    // The alarm is retained while it is active
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(true)));
+    let event_number = address_space.last_event_number();
+    condition::set_active(&mut address_space, &alarm_id, false, 100, "Normal").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(field(&event, &["ActiveState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["AckedState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(false)));
+    assert!(condition::retained_condition_events(&address_space).is_empty());
+
+    let event_number = address_space.last_event_number();
+    condition::add_comment(&mut address_space, &alarm_id, &event.event_id, &LocalizedText::new("", "Valve replaced"), "operator").unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(field(&event, &["Comment"]), Some(Variant::from(LocalizedText::new("", "Valve replaced"))));
+}
+
+#[test]
+fn enable_and_disable() {
+    let (mut address_space, source_id) = make_address_space();
+    let alarm_id = NodeId::new(1, "Alarm");
+    condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, false).unwrap();
+    condition::set_active(&mut address_space, &alarm_id, true, 500, "Too hot").unwrap();
+    let event_id = condition::condition_event(&address_space, &alarm_id).unwrap().event_id;
+    assert_eq!(condition::enable(&mut address_space, &alarm_id).unwrap_err(), StatusCode::BadConditionAlreadyEnabled);
+
+    // Disabling reports the alarm as disabled and not retained
+    let event_number = address_space.last_event_number();
+    condition::disable(&mut address_space, &alarm_id).unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(field(&event, &["EnabledState"]), Some(Variant::from(LocalizedText::new("", "Disabled"))));
+    assert_eq!(field(&event, &["EnabledState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(false)));
+    assert_eq!(condition::disable(&mut address_space, &alarm_id).unwrap_err(), StatusCode::BadConditionAlreadyDisabled);
+
+    // A disabled alarm cannot be acknowledged and its state changes are not reported
+    assert_eq!(condition::acknowledge(&mut address_space, &alarm_id, &event_id, &LocalizedText::new("", ""), "").unwrap_err(), StatusCode::BadConditionDisabled);
+    let event_number = address_space.last_event_number();
+    condition::set_active(&mut address_space, &alarm_id, false, 100, "Normal").unwrap();
+    condition::set_active(&mut address_space, &alarm_id, true, 900, "Far too hot").unwrap();
+    assert_eq!(address_space.last_event_number(), event_number);
+
+    // Enabling reports the current state
+    condition::enable(&mut address_space, &alarm_id).unwrap();
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 900);
+    assert_eq!(field(&event, &["EnabledState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["ActiveState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["Retain"]), Some(Variant::from(true)));
+}
+
+#[test]
+fn condition_event_fields() {
+    let (mut address_space, source_id) = make_address_space();
+    let alarm_id = NodeId::new(1, "Alarm");
+    condition::add_condition(&mut address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, false).unwrap();
+    condition::set_active(&mut address_space, &alarm_id, true, 500, "Too hot").unwrap();
+    let event = condition::condition_event(&address_space, &alarm_id).unwrap();
+
+    // The condition id is selected by the NodeId attribute of the ConditionType
+    let filter = EventFilter {
+        select_clauses: Some(vec![
+            SimpleAttributeOperand {
+                type_definition_id: ObjectTypeId::ConditionType.into(),
+                browse_path: None,
+                attribute_id: AttributeId::NodeId as u32,
+                index_range: UAString::null(),
+            },
+            SimpleAttributeOperand {
+                type_definition_id: ObjectTypeId::AlarmConditionType.into(),
+                browse_path: Some(vec![QualifiedName::new(0, "ActiveState"), QualifiedName::new(0, "Id")]),
+                attribute_id: AttributeId::Value as u32,
+                index_range: UAString::null(),
+            },
+        ]),
+        where_clause: ContentFilter {
+            elements: None,
+        },
+    };
+    assert_eq!(event_filter::validate(&address_space, &filter).unwrap().select_clause_results.unwrap(), vec![StatusCode::Good, StatusCode::Good]);
+    assert_eq!(event_filter::select_fields(&address_space, &filter, &event), vec![Variant::from(alarm_id), Variant::from(true)]);
+
+    // Other events have no node id
+    let event = Event::new(ObjectTypeId::BaseEventType, &source_id, "Boiler", "Hello", 100);
+    assert_eq!(event_filter::select_fields(&address_space, &filter, &event), vec![Variant::Empty, Variant::Empty]);
+}
+
+/// Adds a temperature variable to the boiler and returns its id
+fn add_temperature(address_space: &mut AddressSpace, source_id: &NodeId) -> NodeId {
+    let variable_id = NodeId::new(1, "Temperature");
+    let _ = address_space.add_variable(Variable::new(&variable_id, "Temperature", "Temperature", "", 20f64), source_id);
+    variable_id
+}
+
+fn set_temperature(address_space: &mut AddressSpace, variable_id: &NodeId, value: f64) {
+    let now = DateTime::now();
+    assert!(address_space.set_variable_value_by_ref(variable_id, value, &now, &now));
+}
+
+fn alarm_value(address_space: &AddressSpace, alarm_id: &NodeId, browse_path: &[&str]) -> Option<Variant> {
+    field(&condition::condition_event(address_space, alarm_id).unwrap(), browse_path)
+}
+
+#[test]
+fn add_limit_alarm() {
+    let (mut address_space, source_id) = make_address_space();
+    let variable_id = add_temperature(&mut address_space, &source_id);
+    let alarm_id = NodeId::new(1, "TemperatureAlarm");
+
+    // The input node must be a variable and the limits must be in order
+    assert_eq!(limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &NodeId::new(1, "Unknown"), LimitAlarm::exclusive().high(80.0, 500)).unwrap_err(), StatusCode::BadNodeIdUnknown);
+    assert_eq!(limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &source_id, LimitAlarm::exclusive().high(80.0, 500)).unwrap_err(), StatusCode::BadNodeClassInvalid);
+    assert_eq!(limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &variable_id, LimitAlarm::exclusive().high_high(80.0, 900).high(90.0, 500)).unwrap_err(), StatusCode::BadOutOfRange);
+    assert!(address_space.find_node(&alarm_id).is_none());
+
+    let limit_alarm = LimitAlarm::exclusive().high_high(90.0, 900).high(80.0, 500).low(5.0, 300);
+    limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &variable_id, limit_alarm).unwrap();
+    assert!(address_space.has_reference(&variable_id, ReferenceTypeId::HasCondition, &alarm_id));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["InputNode"]), Some(Variant::from(variable_id.clone())));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["HighHighLimit"]), Some(Variant::from(90f64)));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["HighLimit"]), Some(Variant::from(80f64)));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["LowLimit"]), Some(Variant::from(5f64)));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["LowLowLimit"]), None);
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["ActiveState", "Id"]), Some(Variant::from(false)));
+}
+
+#[test]
+fn exclusive_limit_alarm() {
+    let (mut address_space, source_id) = make_address_space();
+    let variable_id = add_temperature(&mut address_space, &source_id);
+    let alarm_id = NodeId::new(1, "TemperatureAlarm");
+    let limit_alarm = LimitAlarm::exclusive().high_high(90.0, 900).high(80.0, 500).low(5.0, 300).deadband(2.0);
+    limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &variable_id, limit_alarm).unwrap();
+
+    // A value within the limits raises no events
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 79.0);
+    assert!(last_event(&address_space, event_number).is_none());
+
+    // Exceeding the High limit activates the alarm
+    set_temperature(&mut address_space, &variable_id, 80.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 500);
+    assert_eq!(event.message, LocalizedText::new("", "High limit exceeded"));
+    assert_eq!(field(&event, &["ActiveState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["LimitState", "CurrentState"]), Some(Variant::from(LocalizedText::new("", "High"))));
+    let high_id: NodeId = ObjectId::ExclusiveLimitStateMachineType_High.into();
+    assert_eq!(field(&event, &["LimitState", "CurrentState", "Id"]), Some(Variant::from(high_id)));
+
+    // Exceeding the HighHigh limit changes the severity and the limit state
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 95.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 900);
+    assert_eq!(field(&event, &["LastSeverity"]), Some(Variant::from(500u16)));
+    assert_eq!(field(&event, &["LimitState", "CurrentState"]), Some(Variant::from(LocalizedText::new("", "HighHigh"))));
+
+    // A value within the deadband of the HighHigh limit leaves the alarm as it is
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 88.5);
+    assert!(last_event(&address_space, event_number).is_none());
+
+    // A value beyond the deadband returns to the High limit
+    set_temperature(&mut address_space, &variable_id, 87.5);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 500);
+    assert_eq!(field(&event, &["LimitState", "CurrentState"]), Some(Variant::from(LocalizedText::new("", "High"))));
+
+    // A value within the limits deactivates the alarm
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 50.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.message, LocalizedText::new("", "Value is within limits"));
+    assert_eq!(field(&event, &["ActiveState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["LimitState", "CurrentState", "Id"]), Some(Variant::from(NodeId::null())));
+
+    // Exceeding the Low limit
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 4.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 300);
+    assert_eq!(field(&event, &["LimitState", "CurrentState"]), Some(Variant::from(LocalizedText::new("", "Low"))));
+
+    // Removing the alarm unbinds it from the variable
+    address_space.delete_node(&alarm_id, true);
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 100.0);
+    assert!(last_event(&address_space, event_number).is_none());
+}
+
+#[test]
+fn non_exclusive_limit_alarm() {
+    let (mut address_space, source_id) = make_address_space();
+    let variable_id = add_temperature(&mut address_space, &source_id);
+    let alarm_id = NodeId::new(1, "TemperatureAlarm");
+    let limit_alarm = LimitAlarm::non_exclusive().high_high(90.0, 900).high(80.0, 500);
+    limit_alarm::add_limit_alarm(&mut address_space, &alarm_id, "TemperatureAlarm", &variable_id, limit_alarm).unwrap();
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["HighState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["LowState", "Id"]), None);
+
+    // Both high limits are active above the HighHigh limit
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 95.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 900);
+    assert_eq!(event.message, LocalizedText::new("", "HighHigh limit exceeded"));
+    assert_eq!(field(&event, &["HighHighState", "Id"]), Some(Variant::from(true)));
+    assert_eq!(field(&event, &["HighState"]), Some(Variant::from(LocalizedText::new("", "Active"))));
+    assert_eq!(field(&event, &["HighState", "Id"]), Some(Variant::from(true)));
+
+    let event_number = address_space.last_event_number();
+    set_temperature(&mut address_space, &variable_id, 85.0);
+    let event = last_event(&address_space, event_number).unwrap();
+    assert_eq!(event.severity, 500);
+    assert_eq!(field(&event, &["HighHighState", "Id"]), Some(Variant::from(false)));
+    assert_eq!(field(&event, &["HighState", "Id"]), Some(Variant::from(true)));
+
+    // A value that is not numeric leaves the alarm as it is
+    let event_number = address_space.last_event_number();
+    let now = DateTime::now();
+    address_space.set_variable_value_by_ref(&variable_id, "Broken", &now, &now);
+    assert!(last_event(&address_space, event_number).is_none());
+    assert_eq!(alarm_value(&address_space, &alarm_id, &["ActiveState", "Id"]), Some(Variant::from(true)));
+}
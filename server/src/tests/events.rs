@@ -0,0 +1,467 @@
+use crate::{
+    address_space::{EventNotifier, node::{Node, NodeType}, object::Object, view::View},
+    constants,
+    events::{content_filter, event::Event, event_filter},
+};
+
+use super::*;
+
+fn make_address_space() -> (AddressSpace, NodeId, NodeId) {
+    let mut address_space = AddressSpace::new();
+    // An event notifier area containing a source of events
+    let area_id = NodeId::new(1, "Area");
+    let mut area = Object::new(&area_id, "Area", "Area", "");
+    area.set_event_notifier(EventNotifier::SUBSCRIBE_TO_EVENTS);
+    address_space.insert(area, Some(&[
+        (&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+    ]));
+    let source_id = NodeId::new(1, "Source");
+    address_space.insert(Object::new(&source_id, "Source", "Source", ""), Some(&[
+        (&area_id, ReferenceTypeId::HasComponent, ReferenceDirection::Inverse),
+    ]));
+    address_space.insert_reference(&area_id, &source_id, ReferenceTypeId::HasEventSource);
+    (address_space, area_id, source_id)
+}
+
+fn select_clause(type_definition_id: ObjectTypeId, browse_path: &[&str]) -> SimpleAttributeOperand {
+    SimpleAttributeOperand {
+        type_definition_id: type_definition_id.into(),
+        browse_path: Some(browse_path.iter().map(|name| QualifiedName::new(0, *name)).collect()),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+    }
+}
+
+fn event_filter(select_clauses: Vec<SimpleAttributeOperand>) -> EventFilter {
+    EventFilter {
+        select_clauses: Some(select_clauses),
+        where_clause: ContentFilter {
+            elements: None,
+        },
+    }
+}
+
+#[test]
+fn event_notifier_attribute() {
+    let mut object = Object::new(&NodeId::new(1, "Object"), "Object", "Object", "");
+    assert_eq!(object.event_notifier(), EventNotifier::empty());
+    assert!(!object.is_event_notifier());
+
+    object.set_event_notifier(EventNotifier::SUBSCRIBE_TO_EVENTS | EventNotifier::HISTORY_READ);
+    assert_eq!(object.event_notifier(), EventNotifier::SUBSCRIBE_TO_EVENTS | EventNotifier::HISTORY_READ);
+    assert!(object.is_event_notifier());
+
+    // The attribute is a byte
+    let value = object.find_attribute(AttributeId::EventNotifier).unwrap();
+    assert_eq!(value.value, Some(Variant::Byte(5)));
+
+    // The server object is the notifier of all events
+    let address_space = AddressSpace::new();
+    if let Some(NodeType::Object(server)) = address_space.find_node(&ObjectId::Server.into()) {
+        assert!(server.is_event_notifier());
+    } else {
+        panic!("Server object not found");
+    }
+}
+
+#[test]
+fn event_fields() {
+    let source_id = NodeId::new(1, "Source");
+    let mut event = Event::new(ObjectTypeId::AuditEventType, &source_id, "Source", "Hello", 500);
+    event.set_property("ActionTimeStamp", DateTime::epoch());
+    event.set_field(&[QualifiedName::new(1, "Custom"), QualifiedName::new(1, "Value")], 10i32);
+
+    assert_eq!(event.event_id.value.as_ref().unwrap().len(), 16);
+    let event_type_id: NodeId = ObjectTypeId::AuditEventType.into();
+    assert_eq!(event.field(&[QualifiedName::new(0, "EventType")]), Some(Variant::from(event_type_id)));
+    assert_eq!(event.field(&[QualifiedName::new(0, "SourceNode")]), Some(Variant::from(source_id)));
+    assert_eq!(event.field(&[QualifiedName::new(0, "SourceName")]), Some(Variant::from("Source")));
+    assert_eq!(event.field(&[QualifiedName::new(0, "Message")]), Some(Variant::from(LocalizedText::new("", "Hello"))));
+    assert_eq!(event.field(&[QualifiedName::new(0, "Severity")]), Some(Variant::UInt16(500)));
+    assert_eq!(event.field(&[QualifiedName::new(0, "ActionTimeStamp")]), Some(Variant::from(DateTime::epoch())));
+    assert_eq!(event.field(&[QualifiedName::new(1, "Custom"), QualifiedName::new(1, "Value")]), Some(Variant::Int32(10)));
+    assert_eq!(event.field(&[QualifiedName::new(1, "Custom")]), None);
+    assert_eq!(event.field(&[QualifiedName::new(0, "Status")]), None);
+
+    // Setting a field again replaces its value
+    event.set_property("ActionTimeStamp", DateTime::ymd_hms(2019, 1, 1, 0, 0, 0));
+    assert_eq!(event.field(&[QualifiedName::new(0, "ActionTimeStamp")]), Some(Variant::from(DateTime::ymd_hms(2019, 1, 1, 0, 0, 0))));
+}
+
+#[test]
+fn raise_event() {
+    let (mut address_space, _, source_id) = make_address_space();
+    assert_eq!(address_space.last_event_number(), 0);
+    assert_eq!(address_space.events_since(0).count(), 0);
+
+    // The source must exist
+    let event = Event::new(ObjectTypeId::BaseEventType, &NodeId::new(1, "Unknown"), "Unknown", "", 100);
+    assert_eq!(address_space.raise_event(event), Err(StatusCode::BadNodeIdUnknown));
+
+    // The type must be an event type
+    let event = Event::new(ObjectTypeId::FolderType, &source_id, "Source", "", 100);
+    assert_eq!(address_space.raise_event(event), Err(StatusCode::BadTypeDefinitionInvalid));
+    assert_eq!(address_space.last_event_number(), 0);
+
+    let event = Event::new(ObjectTypeId::BaseEventType, &source_id, "Source", "First", 100);
+    assert!(address_space.raise_event(event).is_ok());
+    let event = Event::new(ObjectTypeId::AuditEventType, &source_id, "Source", "Second", 100);
+    assert!(address_space.raise_event(event).is_ok());
+    assert_eq!(address_space.last_event_number(), 2);
+
+    let messages = |address_space: &AddressSpace, event_number| -> Vec<String> {
+        address_space.events_since(event_number).map(|event| event.message.text.as_ref().to_string()).collect()
+    };
+    assert_eq!(messages(&address_space, 0), vec!["First", "Second"]);
+    assert_eq!(messages(&address_space, 1), vec!["Second"]);
+    assert!(messages(&address_space, 2).is_empty());
+
+    // Only the most recent events are kept
+    for _ in 0..constants::MAX_RECENT_EVENTS {
+        let event = Event::new(ObjectTypeId::BaseEventType, &source_id, "Source", "Later", 100);
+        assert!(address_space.raise_event(event).is_ok());
+    }
+    assert_eq!(address_space.events_since(0).count(), constants::MAX_RECENT_EVENTS);
+    assert_eq!(address_space.events_since(1).count(), constants::MAX_RECENT_EVENTS);
+    assert_eq!(address_space.events_since(address_space.last_event_number() - 1).count(), 1);
+}
+
+#[test]
+fn event_notifier_of() {
+    let (address_space, area_id, source_id) = make_address_space();
+    let server_id: NodeId = ObjectId::Server.into();
+    assert!(address_space.is_event_notifier_of(&server_id, &source_id));
+    assert!(address_space.is_event_notifier_of(&area_id, &source_id));
+    assert!(address_space.is_event_notifier_of(&area_id, &area_id));
+    assert!(!address_space.is_event_notifier_of(&source_id, &area_id));
+    assert!(!address_space.is_event_notifier_of(&area_id, &server_id));
+}
+
+#[test]
+fn validate_event_filter() {
+    let address_space = AddressSpace::new();
+
+    // No select clauses
+    assert_eq!(event_filter::validate(&address_space, &event_filter(vec![])).unwrap_err().0, StatusCode::BadEventFilterInvalid);
+
+    let mut bad_attribute = select_clause(ObjectTypeId::BaseEventType, &["Message"]);
+    bad_attribute.attribute_id = 100;
+    let mut bad_index_range = select_clause(ObjectTypeId::BaseEventType, &["Message"]);
+    bad_index_range.index_range = UAString::from("1:2");
+    let mut no_browse_path = select_clause(ObjectTypeId::BaseEventType, &[]);
+    no_browse_path.attribute_id = AttributeId::DisplayName as u32;
+
+    let filter = event_filter(vec![
+        select_clause(ObjectTypeId::BaseEventType, &["Message"]),
+        select_clause(ObjectTypeId::AuditEventType, &["ActionTimeStamp"]),
+        select_clause(ObjectTypeId::FolderType, &["Message"]),
+        bad_attribute,
+        bad_index_range,
+        no_browse_path,
+    ]);
+    let result = event_filter::validate(&address_space, &filter).unwrap();
+    assert_eq!(result.select_clause_results.unwrap(), vec![
+        StatusCode::Good,
+        StatusCode::Good,
+        StatusCode::BadTypeDefinitionInvalid,
+        StatusCode::BadAttributeIdInvalid,
+        StatusCode::BadIndexRangeInvalid,
+        StatusCode::BadBrowseNameInvalid,
+    ]);
+}
+
+#[test]
+fn select_event_fields() {
+    let (address_space, _, source_id) = make_address_space();
+    let filter = event_filter(vec![
+        select_clause(ObjectTypeId::BaseEventType, &["Message"]),
+        select_clause(ObjectTypeId::BaseEventType, &["Severity"]),
+        select_clause(ObjectTypeId::AuditEventType, &["ActionTimeStamp"]),
+        select_clause(ObjectTypeId::BaseEventType, &["Unknown"]),
+    ]);
+
+    // A base event has no fields of the audit event type
+    let event = Event::new(ObjectTypeId::BaseEventType, &source_id, "Source", "Hello", 100);
+    assert_eq!(event_filter::select_fields(&address_space, &filter, &event), vec![
+        Variant::from(LocalizedText::new("", "Hello")),
+        Variant::UInt16(100),
+        Variant::Empty,
+        Variant::Empty,
+    ]);
+
+    let mut event = Event::new(ObjectTypeId::AuditEventType, &source_id, "Source", "Audit", 200);
+    event.set_property("ActionTimeStamp", DateTime::epoch());
+    assert_eq!(event_filter::select_fields(&address_space, &filter, &event), vec![
+        Variant::from(LocalizedText::new("", "Audit")),
+        Variant::UInt16(200),
+        Variant::from(DateTime::epoch()),
+        Variant::Empty,
+    ]);
+}
+
+fn literal<V>(value: V) -> ExtensionObject where V: Into<Variant> {
+    ExtensionObject::from_encodable(ObjectId::LiteralOperand_Encoding_DefaultBinary, &LiteralOperand { value: value.into() })
+}
+
+fn node_literal<T>(node_id: T) -> ExtensionObject where T: Into<NodeId> {
+    literal(node_id.into())
+}
+
+fn element_operand(index: u32) -> ExtensionObject {
+    ExtensionObject::from_encodable(ObjectId::ElementOperand_Encoding_DefaultBinary, &ElementOperand { index })
+}
+
+fn event_field(name: &str) -> ExtensionObject {
+    ExtensionObject::from_encodable(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary, &select_clause(ObjectTypeId::BaseEventType, &[name]))
+}
+
+fn where_clause(elements: Vec<(FilterOperator, Vec<ExtensionObject>)>) -> ContentFilter {
+    ContentFilter {
+        elements: Some(elements.into_iter().map(|(filter_operator, filter_operands)| ContentFilterElement {
+            filter_operator,
+            filter_operands: Some(filter_operands),
+        }).collect()),
+    }
+}
+
+#[test]
+fn content_filter_operators() {
+    let (address_space, _, source_id) = make_address_space();
+    let mut event = Event::new(ObjectTypeId::AuditEventType, &source_id, "Source", "Pump 12 overheated", 500);
+    event.set_property("ActionTimeStamp", DateTime::epoch());
+    let evaluate = |filter_operator: FilterOperator, operands: Vec<ExtensionObject>| {
+        content_filter::evaluate(&address_space, &where_clause(vec![(filter_operator, operands)]), &event)
+    };
+
+    assert!(evaluate(FilterOperator::Equals, vec![event_field("Severity"), literal(500i32)]));
+    assert!(evaluate(FilterOperator::Equals, vec![event_field("Severity"), literal("500")]));
+    assert!(!evaluate(FilterOperator::Equals, vec![event_field("Severity"), literal(501u64)]));
+    assert!(evaluate(FilterOperator::Equals, vec![event_field("SourceNode"), literal(source_id.clone())]));
+    assert!(evaluate(FilterOperator::IsNull, vec![event_field("Unknown")]));
+    assert!(!evaluate(FilterOperator::IsNull, vec![event_field("Severity")]));
+    assert!(evaluate(FilterOperator::GreaterThan, vec![event_field("Severity"), literal(499.5f64)]));
+    assert!(!evaluate(FilterOperator::GreaterThan, vec![event_field("Severity"), literal(500u16)]));
+    assert!(evaluate(FilterOperator::LessThan, vec![event_field("Severity"), literal(501i64)]));
+    assert!(evaluate(FilterOperator::GreaterThanOrEqual, vec![event_field("Severity"), literal(500u16)]));
+    assert!(evaluate(FilterOperator::LessThanOrEqual, vec![event_field("Severity"), literal(500u16)]));
+    assert!(evaluate(FilterOperator::LessThan, vec![literal(DateTime::epoch()), event_field("Time")]));
+    // Comparisons with null are null, which does not pass
+    assert!(!evaluate(FilterOperator::GreaterThan, vec![event_field("Unknown"), literal(0i32)]));
+    assert!(!evaluate(FilterOperator::LessThanOrEqual, vec![event_field("Unknown"), literal(0i32)]));
+
+    assert!(evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump%")]));
+    assert!(evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump 1_ over%")]));
+    assert!(evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump [0-9][^3]%")]));
+    assert!(!evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump [0-9][^2]%")]));
+    assert!(!evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump")]));
+    assert!(evaluate(FilterOperator::Like, vec![literal("100%"), literal("100\\%")]));
+    assert!(!evaluate(FilterOperator::Like, vec![literal("1000"), literal("100\\%")]));
+    // Patterns are compiled once and reused, including invalid ones
+    assert!(evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump%")]));
+    assert!(!evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump [0-9")]));
+    assert!(!evaluate(FilterOperator::Like, vec![event_field("Message"), literal("Pump [0-9")]));
+
+    assert!(evaluate(FilterOperator::Between, vec![event_field("Severity"), literal(100i32), literal(500i32)]));
+    assert!(!evaluate(FilterOperator::Between, vec![event_field("Severity"), literal(100i32), literal(499i32)]));
+    assert!(evaluate(FilterOperator::InList, vec![event_field("Severity"), literal(100i32), literal(500i32)]));
+    assert!(!evaluate(FilterOperator::InList, vec![event_field("Severity"), literal(100i32), literal(200i32)]));
+
+    assert!(evaluate(FilterOperator::OfType, vec![node_literal(ObjectTypeId::BaseEventType)]));
+    assert!(evaluate(FilterOperator::OfType, vec![node_literal(ObjectTypeId::AuditEventType)]));
+    assert!(!evaluate(FilterOperator::OfType, vec![node_literal(ObjectTypeId::SystemEventType)]));
+
+    assert!(evaluate(FilterOperator::Equals, vec![event_field("Severity"), literal(0x1f4u16)]));
+    let bitwise_equals = |filter_operator: FilterOperator, lhs: u16, rhs: u16, result: u16| {
+        content_filter::evaluate(&address_space, &where_clause(vec![
+            (FilterOperator::Equals, vec![element_operand(1), literal(result)]),
+            (filter_operator, vec![literal(lhs), literal(rhs)]),
+        ]), &event)
+    };
+    assert!(bitwise_equals(FilterOperator::BitwiseAnd, 0b1100, 0b1010, 0b1000));
+    assert!(bitwise_equals(FilterOperator::BitwiseOr, 0b1100, 0b1010, 0b1110));
+
+    let cast_equals = |value: Variant, data_type: DataTypeId, result: Variant| {
+        content_filter::evaluate(&address_space, &where_clause(vec![
+            (FilterOperator::Equals, vec![element_operand(1), literal(result)]),
+            (FilterOperator::Cast, vec![literal(value), node_literal(data_type)]),
+        ]), &event)
+    };
+    assert!(cast_equals(Variant::from("12"), DataTypeId::Int32, Variant::Int32(12)));
+    assert!(cast_equals(Variant::Double(1.6), DataTypeId::Byte, Variant::Byte(2)));
+    assert!(cast_equals(Variant::Int32(1), DataTypeId::Boolean, Variant::Boolean(true)));
+    assert!(cast_equals(Variant::UInt16(7), DataTypeId::String, Variant::from("7")));
+    assert!(!cast_equals(Variant::Int32(300), DataTypeId::Byte, Variant::Byte(44)));
+
+    // InView and RelatedTo are about nodes, which events are not
+    assert!(!evaluate(FilterOperator::InView, vec![literal(AddressSpace::views_folder_id())]));
+}
+
+#[test]
+fn content_filter_logic() {
+    let (address_space, _, source_id) = make_address_space();
+    let event = Event::new(ObjectTypeId::BaseEventType, &source_id, "Source", "Hello", 500);
+    let evaluate = |filter: ContentFilter| content_filter::evaluate(&address_space, &filter, &event);
+
+    // An empty filter passes everything
+    assert!(evaluate(ContentFilter { elements: None }));
+    assert!(evaluate(ContentFilter { elements: Some(vec![]) }));
+
+    let is_true = || (FilterOperator::Equals, vec![literal(1i32), literal(1i32)]);
+    let is_false = || (FilterOperator::Equals, vec![literal(1i32), literal(2i32)]);
+    let is_null = || (FilterOperator::Equals, vec![event_field("Unknown"), literal(1i32)]);
+    let combine = |filter_operator: FilterOperator, lhs: (FilterOperator, Vec<ExtensionObject>), rhs: (FilterOperator, Vec<ExtensionObject>)| {
+        where_clause(vec![(filter_operator, vec![element_operand(1), element_operand(2)]), lhs, rhs])
+    };
+
+    assert!(evaluate(combine(FilterOperator::And, is_true(), is_true())));
+    assert!(!evaluate(combine(FilterOperator::And, is_true(), is_false())));
+    assert!(!evaluate(combine(FilterOperator::And, is_true(), is_null())));
+    assert!(evaluate(combine(FilterOperator::Or, is_false(), is_true())));
+    assert!(evaluate(combine(FilterOperator::Or, is_null(), is_true())));
+    assert!(!evaluate(combine(FilterOperator::Or, is_false(), is_null())));
+
+    assert!(evaluate(where_clause(vec![(FilterOperator::Not, vec![element_operand(1)]), is_false()])));
+    assert!(!evaluate(where_clause(vec![(FilterOperator::Not, vec![element_operand(1)]), is_true()])));
+    // The negation of null is null
+    assert!(!evaluate(where_clause(vec![(FilterOperator::Not, vec![element_operand(1)]), is_null()])));
+    // Not (null And false) is true
+    assert!(evaluate(where_clause(vec![
+        (FilterOperator::Not, vec![element_operand(1)]),
+        (FilterOperator::And, vec![element_operand(2), element_operand(3)]),
+        is_null(),
+        is_false(),
+    ])));
+
+    // A result that isn't a boolean does not pass
+    assert!(!evaluate(where_clause(vec![(FilterOperator::BitwiseOr, vec![literal(1i32), literal(2i32)])])));
+    // Elements may not refer to themselves or elements before them
+    assert!(!evaluate(where_clause(vec![(FilterOperator::Not, vec![element_operand(0)])])));
+}
+
+#[test]
+fn content_filter_nodes() {
+    let (mut address_space, area_id, source_id) = make_address_space();
+    address_space.set_object_type(&source_id, ObjectTypeId::BaseObjectType);
+    address_space.set_object_type(&area_id, ObjectTypeId::FolderType);
+    let variable_id = NodeId::new(1, "Temperature");
+    let _ = address_space.add_variable(Variable::new(&variable_id, "Temperature", "Temperature", "", 25i32), &source_id);
+    let view_id = NodeId::new(1, "View");
+    address_space.insert(View::new(&view_id, "View", "View", "", false, true), Some(&[
+        (&AddressSpace::views_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+        (&area_id, ReferenceTypeId::Organizes, ReferenceDirection::Forward),
+    ]));
+    let evaluate = |node_id: &NodeId, filter_operator: FilterOperator, operands: Vec<ExtensionObject>| {
+        content_filter::evaluate(&address_space, &where_clause(vec![(filter_operator, operands)]), node_id)
+    };
+
+    // Attributes of the node and its children
+    let temperature = |type_definition_id: ObjectTypeId| {
+        ExtensionObject::from_encodable(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary, &SimpleAttributeOperand {
+            type_definition_id: type_definition_id.into(),
+            browse_path: Some(vec![QualifiedName::new(0, "Temperature")]),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+        })
+    };
+    assert!(evaluate(&source_id, FilterOperator::GreaterThan, vec![temperature(ObjectTypeId::BaseObjectType), literal(20i32)]));
+    assert!(!evaluate(&source_id, FilterOperator::GreaterThan, vec![temperature(ObjectTypeId::FolderType), literal(20i32)]));
+    let attribute_operand = ExtensionObject::from_encodable(ObjectId::AttributeOperand_Encoding_DefaultBinary, &AttributeOperand {
+        node_id: variable_id.clone(),
+        alias: UAString::null(),
+        browse_path: RelativePath { elements: None },
+        attribute_id: AttributeId::BrowseName as u32,
+        index_range: UAString::null(),
+    });
+    assert!(evaluate(&source_id, FilterOperator::Equals, vec![attribute_operand, literal(QualifiedName::new(0, "Temperature"))]));
+
+    assert!(evaluate(&area_id, FilterOperator::OfType, vec![node_literal(ObjectTypeId::BaseObjectType)]));
+    assert!(!evaluate(&source_id, FilterOperator::OfType, vec![node_literal(ObjectTypeId::FolderType)]));
+
+    assert!(evaluate(&area_id, FilterOperator::InView, vec![literal(view_id.clone())]));
+    assert!(evaluate(&source_id, FilterOperator::InView, vec![literal(view_id.clone())]));
+    assert!(!evaluate(&AddressSpace::objects_folder_id(), FilterOperator::InView, vec![literal(view_id.clone())]));
+
+    // The area has a component of the base object type, but the source has none
+    let related_to = |hops: u32| vec![
+        node_literal(ObjectTypeId::FolderType),
+        node_literal(ObjectTypeId::BaseObjectType),
+        node_literal(ReferenceTypeId::HasComponent),
+        literal(hops),
+    ];
+    assert!(evaluate(&area_id, FilterOperator::RelatedTo, related_to(1)));
+    assert!(!evaluate(&source_id, FilterOperator::RelatedTo, related_to(1)));
+    // Following hierarchical references from the objects folder, the source is 2 hops away
+    let related_to = |hops: u32| vec![
+        node_literal(ObjectTypeId::FolderType),
+        node_literal(ObjectTypeId::BaseObjectType),
+        node_literal(ReferenceTypeId::HierarchicalReferences),
+        literal(hops),
+        literal(false),
+    ];
+    assert!(!evaluate(&AddressSpace::objects_folder_id(), FilterOperator::RelatedTo, related_to(1)));
+    assert!(evaluate(&AddressSpace::objects_folder_id(), FilterOperator::RelatedTo, related_to(2)));
+}
+
+#[test]
+fn validate_content_filter() {
+    let address_space = AddressSpace::new();
+
+    // An empty filter is valid
+    let result = content_filter::validate(&address_space, &ContentFilter { elements: None }).unwrap();
+    assert!(result.element_results.is_none());
+
+    let filter = where_clause(vec![
+        (FilterOperator::And, vec![element_operand(1), element_operand(2)]),
+        (FilterOperator::Equals, vec![event_field("Severity"), literal(100i32)]),
+        (FilterOperator::OfType, vec![node_literal(ObjectTypeId::AuditEventType)]),
+    ]);
+    let result = content_filter::validate(&address_space, &filter).unwrap();
+    let element_results = result.element_results.unwrap();
+    assert_eq!(element_results.len(), 3);
+    assert!(element_results.iter().all(|r| r.status_code == StatusCode::Good));
+    assert_eq!(element_results[0].operand_status_codes, Some(vec![StatusCode::Good, StatusCode::Good]));
+
+    let mut bad_event_field = select_clause(ObjectTypeId::BaseEventType, &["Severity"]);
+    bad_event_field.attribute_id = 100;
+    let bad_event_field = ExtensionObject::from_encodable(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary, &bad_event_field);
+    let filter = where_clause(vec![
+        // Refers to itself
+        (FilterOperator::Or, vec![element_operand(0), element_operand(2)]),
+        // Wrong number of operands
+        (FilterOperator::Between, vec![literal(1i32), literal(2i32)]),
+        // Operand which is not an operand
+        (FilterOperator::Equals, vec![literal(1i32), ExtensionObject::from_encodable(ObjectId::DataChangeFilter_Encoding_DefaultBinary, &DataChangeFilter {
+            trigger: DataChangeTrigger::Status,
+            deadband_type: 0,
+            deadband_value: 0f64,
+        })]),
+        // Bad attribute of the event
+        (FilterOperator::IsNull, vec![bad_event_field]),
+        // Type which is not a node id or doesn't exist
+        (FilterOperator::OfType, vec![literal(1i32)]),
+        (FilterOperator::OfType, vec![literal(NodeId::new(1, "Unknown"))]),
+        // Cast to a data type which is not a literal
+        (FilterOperator::Cast, vec![literal(1i32), event_field("Severity")]),
+    ]);
+    let (status_code, result) = content_filter::validate(&address_space, &filter).unwrap_err();
+    assert_eq!(status_code, StatusCode::BadContentFilterInvalid);
+    let element_results = result.element_results.unwrap();
+    let status_codes = element_results.iter().map(|r| (r.status_code, r.operand_status_codes.clone().unwrap())).collect::<Vec<_>>();
+    assert_eq!(status_codes, vec![
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::BadFilterOperandInvalid, StatusCode::Good]),
+        (StatusCode::BadFilterOperandCountMismatch, vec![StatusCode::Good, StatusCode::Good]),
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::Good, StatusCode::BadFilterOperandInvalid]),
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::BadAttributeIdInvalid]),
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::BadFilterOperandInvalid]),
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::BadNodeIdUnknown]),
+        (StatusCode::BadFilterOperandInvalid, vec![StatusCode::Good, StatusCode::BadFilterOperandInvalid]),
+    ]);
+
+    // An event filter with an invalid where clause is invalid
+    let mut filter = event_filter(vec![select_clause(ObjectTypeId::BaseEventType, &["Message"])]);
+    filter.where_clause = where_clause(vec![(FilterOperator::Not, vec![])]);
+    let (status_code, result) = event_filter::validate(&address_space, &filter).unwrap_err();
+    assert_eq!(status_code, StatusCode::BadContentFilterInvalid);
+    assert_eq!(result.select_clause_results, Some(vec![StatusCode::Good]));
+    assert_eq!(result.where_clause_result.element_results.unwrap()[0].status_code, StatusCode::BadFilterOperandCountMismatch);
+}
@@ -0,0 +1,310 @@
+use crate::historical::*;
+
+use super::*;
+
+fn data_value(value: i32, second: u16) -> DataValue {
+    let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 0, second);
+    DataValue::from((Variant::Int32(value), &timestamp, &timestamp))
+}
+
+fn read_raw_details(start_second: Option<u16>, end_second: Option<u16>, num_values_per_node: u32, return_bounds: bool) -> ReadRawModifiedDetails {
+    let time = |second: Option<u16>| second.map_or(DateTime::epoch(), |second| DateTime::ymd_hms(2019, 1, 1, 0, 0, second));
+    ReadRawModifiedDetails {
+        is_read_modified: false,
+        start_time: time(start_second),
+        end_time: time(end_second),
+        num_values_per_node,
+        return_bounds,
+    }
+}
+
+fn read_raw_values(provider: &MemoryHistoricalDataProvider, node_id: &NodeId, details: &ReadRawModifiedDetails) -> Vec<Option<i32>> {
+    provider.read_raw_values(node_id, details).unwrap().iter().map(|v| {
+        if let Some(Variant::Int32(v)) = v.value { Some(v) } else { None }
+    }).collect()
+}
+
+#[test]
+fn time_range() {
+    let time = |second| DateTime::ymd_hms(2019, 1, 1, 0, 0, second);
+
+    // Neither time
+    assert_eq!(TimeRange::new(&DateTime::epoch(), &DateTime::epoch(), 10).unwrap_err(), StatusCode::BadInvalidTimestampArgument);
+    // One time without a limit on the values
+    assert_eq!(TimeRange::new(&time(1), &DateTime::epoch(), 0).unwrap_err(), StatusCode::BadInvalidTimestampArgument);
+    assert_eq!(TimeRange::new(&DateTime::epoch(), &time(1), 0).unwrap_err(), StatusCode::BadInvalidTimestampArgument);
+
+    // Forwards excludes the end time
+    let range = TimeRange::new(&time(1), &time(3), 0).unwrap();
+    assert!(!range.reverse);
+    assert!(!range.contains(time(0).ticks()));
+    assert!(range.contains(time(1).ticks()));
+    assert!(range.contains(time(2).ticks()));
+    assert!(!range.contains(time(3).ticks()));
+
+    // Backwards excludes the end time
+    let range = TimeRange::new(&time(3), &time(1), 0).unwrap();
+    assert!(range.reverse);
+    assert!(range.contains(time(3).ticks()));
+    assert!(range.contains(time(2).ticks()));
+    assert!(!range.contains(time(1).ticks()));
+
+    // Open ended ranges
+    let range = TimeRange::new(&time(3), &DateTime::epoch(), 1).unwrap();
+    assert!(!range.reverse);
+    assert!(range.is_open_ended());
+    assert!(range.contains(time(59).ticks()));
+    assert!(!range.contains(time(2).ticks()));
+    let range = TimeRange::new(&DateTime::epoch(), &time(3), 1).unwrap();
+    assert!(range.reverse);
+    assert!(range.contains(time(3).ticks()));
+    assert!(range.contains(time(0).ticks()));
+    assert!(!range.contains(time(4).ticks()));
+}
+
+#[test]
+fn memory_provider_ring_buffer() {
+    let node_id = NodeId::new(1, "v1");
+    let mut provider = MemoryHistoricalDataProvider::new(5);
+    for i in 0..10 {
+        provider.record_value(&node_id, &data_value(i, i as u16));
+    }
+    // Only the newest values remain
+    assert_eq!(provider.len(&node_id), 5);
+    let details = read_raw_details(Some(0), Some(59), 0, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(5), Some(6), Some(7), Some(8), Some(9)]);
+
+    // Recording the same value again does nothing
+    provider.record_value(&node_id, &data_value(9, 9));
+    assert_eq!(provider.len(&node_id), 5);
+
+    // An older value is inserted in timestamp order, displacing the oldest
+    provider.record_value(&node_id, &data_value(100, 6));
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(6), Some(100), Some(7), Some(8), Some(9)]);
+
+    // A value older than everything in the full buffer is discarded
+    provider.record_value(&node_id, &data_value(200, 1));
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(6), Some(100), Some(7), Some(8), Some(9)]);
+
+    // Unknown nodes have no values
+    assert!(read_raw_values(&provider, &NodeId::new(1, "v2"), &details).is_empty());
+
+    provider.clear(&node_id);
+    assert_eq!(provider.len(&node_id), 0);
+}
+
+#[test]
+fn memory_provider_read_raw() {
+    let node_id = NodeId::new(1, "v1");
+    let mut provider = MemoryHistoricalDataProvider::new(100);
+    // Values 5 to 45 in steps of 5, recorded at the second of the same number
+    for i in 1..10 {
+        provider.record_value(&node_id, &data_value(i * 5, (i * 5) as u16));
+    }
+
+    // Forwards and backwards
+    let details = read_raw_details(Some(10), Some(25), 0, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(10), Some(15), Some(20)]);
+    let details = read_raw_details(Some(25), Some(10), 0, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(25), Some(20), Some(15)]);
+
+    // Open ended
+    let details = read_raw_details(Some(37), None, 1, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(40), Some(45)]);
+    let details = read_raw_details(None, Some(12), 1, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(10), Some(5)]);
+
+    // Bounds, where there is no value at the start time
+    let details = read_raw_details(Some(12), Some(25), 0, true);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(10), Some(15), Some(20), Some(25)]);
+    let details = read_raw_details(Some(22), Some(10), 0, true);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(25), Some(20), Some(15), Some(10)]);
+
+    // Bounds which do not exist
+    let details = read_raw_details(Some(1), Some(59), 0, true);
+    let values = provider.read_raw_values(&node_id, &details).unwrap();
+    assert_eq!(values.len(), 11);
+    assert_eq!(values[0].status, Some(StatusCode::BadBoundNotFound.bits()));
+    assert_eq!(values[10].status, Some(StatusCode::BadBoundNotFound.bits()));
+}
+
+#[test]
+fn memory_provider_update() {
+    let node_id = NodeId::new(1, "v1");
+    let mut provider = MemoryHistoricalDataProvider::new(5);
+    for i in 0..3 {
+        provider.record_value(&node_id, &data_value(i, i as u16));
+    }
+    let details = read_raw_details(Some(0), Some(59), 0, false);
+
+    // Insert only adds values at new timestamps
+    let results = provider.update_values(&node_id, PerformUpdateType::Insert, &[data_value(100, 1), data_value(103, 3)]).unwrap();
+    assert_eq!(results, vec![StatusCode::BadEntryExists, StatusCode::GoodEntryInserted]);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(0), Some(1), Some(2), Some(103)]);
+
+    // Replace only changes values at existing timestamps
+    let results = provider.update_values(&node_id, PerformUpdateType::Replace, &[data_value(101, 1), data_value(104, 4)]).unwrap();
+    assert_eq!(results, vec![StatusCode::GoodEntryReplaced, StatusCode::BadNoEntryExists]);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(0), Some(101), Some(2), Some(103)]);
+
+    // Update does either
+    let results = provider.update_values(&node_id, PerformUpdateType::Update, &[data_value(102, 2), data_value(104, 4)]).unwrap();
+    assert_eq!(results, vec![StatusCode::GoodEntryReplaced, StatusCode::GoodEntryInserted]);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(0), Some(101), Some(102), Some(103), Some(104)]);
+
+    // A value without a timestamp cannot be inserted, nor can one older than a full buffer
+    let results = provider.update_values(&node_id, PerformUpdateType::Update, &[DataValue { value: Some(Variant::Int32(1)), ..DataValue::null() }]).unwrap();
+    assert_eq!(results, vec![StatusCode::BadInvalidTimestamp]);
+    provider.record_value(&node_id, &data_value(5, 5));
+    let results = provider.update_values(&node_id, PerformUpdateType::Insert, &[data_value(200, 0)]).unwrap();
+    assert_eq!(results, vec![StatusCode::BadResourceUnavailable]);
+
+    // Remove is not for data values
+    assert_eq!(provider.update_values(&node_id, PerformUpdateType::Remove, &[data_value(1, 1)]).unwrap_err(), StatusCode::BadHistoryOperationInvalid);
+}
+
+#[test]
+fn memory_provider_delete() {
+    let node_id = NodeId::new(1, "v1");
+    let mut provider = MemoryHistoricalDataProvider::new(10);
+    for i in 0..5 {
+        provider.record_value(&node_id, &data_value(i, i as u16));
+    }
+    let delete_details = |start_second: u16, end_second: u16| DeleteRawModifiedDetails {
+        node_id: node_id.clone(),
+        is_delete_modified: false,
+        start_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, start_second),
+        end_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, end_second),
+    };
+
+    // Deletes from the start time up to but not including the end time
+    provider.delete_raw_modified_values(&node_id, &delete_details(1, 3)).unwrap();
+    let details = read_raw_details(Some(0), Some(59), 0, false);
+    assert_eq!(read_raw_values(&provider, &node_id, &details), vec![Some(0), Some(3), Some(4)]);
+
+    // Nothing to delete
+    assert_eq!(provider.delete_raw_modified_values(&node_id, &delete_details(1, 3)).unwrap_err(), StatusCode::BadNoData);
+
+    // Both times are required
+    let mut details = delete_details(0, 5);
+    details.end_time = DateTime::epoch();
+    assert_eq!(provider.delete_raw_modified_values(&node_id, &details).unwrap_err(), StatusCode::BadInvalidTimestampArgument);
+
+    // Modified values are not kept
+    let mut details = delete_details(0, 5);
+    details.is_delete_modified = true;
+    assert_eq!(provider.delete_raw_modified_values(&node_id, &details).unwrap_err(), StatusCode::BadHistoryOperationUnsupported);
+}
+
+fn processed_details(start_second: u16, end_second: u16, processing_interval: f64) -> ReadProcessedDetails {
+    ReadProcessedDetails {
+        start_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, start_second),
+        end_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, end_second),
+        processing_interval,
+        aggregate_type: None,
+        aggregate_configuration: aggregates::default_aggregate_configuration(),
+    }
+}
+
+/// Raw values of 10, 20, 30, 40 at 0, 10, 20 and 30 seconds, with a bad value at 35 seconds
+fn aggregate_raw_values() -> Vec<DataValue> {
+    let mut values = vec![data_value(10, 0), data_value(20, 10), data_value(30, 20), data_value(40, 30)];
+    let mut bad_value = data_value(1000, 35);
+    bad_value.status = Some(StatusCode::BadSensorFailure.bits());
+    values.push(bad_value);
+    values
+}
+
+fn calculate(aggregate: ObjectId, details: &ReadProcessedDetails) -> Vec<DataValue> {
+    aggregates::calculate_aggregate(&aggregate.into(), details, &aggregate_raw_values()).unwrap()
+}
+
+fn calculated_values(data_values: &[DataValue]) -> Vec<f64> {
+    data_values.iter().map(|v| v.value.as_ref().unwrap().as_f64().unwrap()).collect()
+}
+
+#[test]
+fn aggregate_intervals() {
+    // Invalid times and aggregates
+    let details = processed_details(10, 10, 0f64);
+    assert_eq!(aggregates::calculate_aggregate(&ObjectId::AggregateFunction_Average.into(), &details, &[]).unwrap_err(), StatusCode::BadInvalidTimestampArgument);
+    let details = processed_details(0, 40, -1f64);
+    assert_eq!(aggregates::calculate_aggregate(&ObjectId::AggregateFunction_Average.into(), &details, &[]).unwrap_err(), StatusCode::BadInvalidArgument);
+    let details = processed_details(0, 40, 0f64);
+    assert_eq!(aggregates::calculate_aggregate(&ObjectId::AggregateFunction_AnnotationCount.into(), &details, &[]).unwrap_err(), StatusCode::BadAggregateNotSupported);
+
+    // A zero processing interval is a single interval
+    let values = calculate(ObjectId::AggregateFunction_Count, &processed_details(0, 40, 0f64));
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value, Some(Variant::Int32(4)));
+
+    // Intervals are timestamped with their start and the last may be partial
+    let values = calculate(ObjectId::AggregateFunction_Count, &processed_details(0, 40, 15000f64));
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[0].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 0)));
+    assert_eq!(values[1].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 15)));
+    assert_eq!(values[2].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 30)));
+    assert!(!StatusCode::from_bits_truncate(values[1].status()).contains(StatusCode::HISTORICAL_PARTIAL));
+    assert!(StatusCode::from_bits_truncate(values[2].status()).contains(StatusCode::HISTORICAL_PARTIAL));
+
+    // Reading backwards starts from the start time
+    let values = calculate(ObjectId::AggregateFunction_Average, &processed_details(40, 0, 20000f64));
+    assert_eq!(calculated_values(&values), vec![35f64, 15f64]);
+    assert_eq!(values[0].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 40)));
+    assert_eq!(values[1].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 20)));
+
+    // An interval without any data
+    let values = calculate(ObjectId::AggregateFunction_Average, &processed_details(1, 3, 0f64));
+    assert_eq!(values[0].status(), StatusCode::BadNoData.bits());
+}
+
+#[test]
+fn aggregate_functions() {
+    let details = processed_details(0, 40, 20000f64);
+
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Average, &details)), vec![15f64, 35f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Minimum, &details)), vec![10f64, 30f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Maximum, &details)), vec![20f64, 40f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Count, &details)), vec![2f64, 2f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Interpolative, &details)), vec![10f64, 30f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_TimeAverage, &details)), vec![20f64, 37.5f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_Total, &details)), vec![400f64, 750f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_DurationGood, &details)), vec![20000f64, 15000f64]);
+    assert_eq!(calculated_values(&calculate(ObjectId::AggregateFunction_DurationBad, &details)), vec![0f64, 5000f64]);
+
+    // Start and end are raw values with their own timestamps, skipping the bad value
+    let values = calculate(ObjectId::AggregateFunction_Start, &details);
+    assert_eq!(calculated_values(&values), vec![10f64, 30f64]);
+    assert_eq!(values[1].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 20)));
+    let values = calculate(ObjectId::AggregateFunction_End, &details);
+    assert_eq!(calculated_values(&values), vec![20f64, 40f64]);
+    assert_eq!(values[1].source_timestamp, Some(DateTime::ymd_hms(2019, 1, 1, 0, 0, 30)));
+
+    // The first interval is all good data, the second has some bad data so it is uncertain
+    let values = calculate(ObjectId::AggregateFunction_Average, &details);
+    let status = StatusCode::from_bits_truncate(values[0].status());
+    assert_eq!(status & StatusCode::STATUS_MASK, StatusCode::Good);
+    assert!(status.contains(StatusCode::HISTORICAL_CALCULATED));
+    let status = StatusCode::from_bits_truncate(values[1].status());
+    assert_eq!(status & StatusCode::STATUS_MASK, StatusCode::UncertainDataSubNormal);
+
+    // Interpolating between values
+    let values = calculate(ObjectId::AggregateFunction_Interpolative, &processed_details(5, 45, 20000f64));
+    assert_eq!(calculated_values(&values), vec![15f64, 35f64]);
+    let status = StatusCode::from_bits_truncate(values[0].status());
+    assert_eq!(status & StatusCode::STATUS_MASK, StatusCode::Good);
+    assert!(status.contains(StatusCode::HISTORICAL_INTERPOLATED));
+
+    // Extrapolating past the last good value is uncertain
+    let values = calculate(ObjectId::AggregateFunction_Interpolative, &processed_details(40, 45, 0f64));
+    assert_eq!(calculated_values(&values), vec![40f64]);
+    let status = StatusCode::from_bits_truncate(values[0].status());
+    assert_eq!(status & StatusCode::STATUS_MASK, StatusCode::UncertainDataSubNormal);
+
+    // Percentages must be valid
+    let mut details = processed_details(0, 40, 0f64);
+    details.aggregate_configuration.use_server_capabilities_defaults = false;
+    details.aggregate_configuration.percent_data_good = 101;
+    assert_eq!(aggregates::calculate_aggregate(&ObjectId::AggregateFunction_Average.into(), &details, &[]).unwrap_err(), StatusCode::BadAggregateConfigurationRejected);
+}
+
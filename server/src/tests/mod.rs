@@ -30,6 +30,9 @@ use crate::{
 };
 
 mod address_space;
+mod conditions;
+mod events;
+mod historical;
 mod services;
 mod subscriptions;
 
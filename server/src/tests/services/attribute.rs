@@ -1,9 +1,10 @@
 use super::*;
 
 use opcua_types::WriteMask;
+use opcua_types::service_types::Argument;
 
 use crate::services::attribute::AttributeService;
-use crate::address_space::AccessLevel;
+use crate::address_space::{AccessLevel, UserAccessLevel};
 
 fn read_value(node_id: &NodeId, attribute_id: AttributeId) -> ReadValueId {
     ReadValueId {
@@ -53,8 +54,9 @@ fn read_test() {
             nodes_to_read: Some(nodes_to_read),
         };
 
+        let session = st.session.read().unwrap();
         let address_space = st.address_space.read().unwrap();
-        let response = ats.read(&address_space, &request);
+        let response = ats.read(&session, &address_space, &request);
         assert!(response.is_ok());
         let response: ReadResponse = supported_message_as!(response.unwrap(), ReadResponse);
 
@@ -82,7 +84,6 @@ fn read_test() {
 
     // OTHER POTENTIAL TESTS
 
-    // read index range
     // distinguish between read and user read
     // test max_age
     // test timestamps to return Server, Source, None, Both
@@ -162,8 +163,9 @@ fn write_test() {
     };
 
     // do a write with the following write
+    let session = st.session.read().unwrap();
     let mut address_space = st.address_space.write().unwrap();
-    let response = ats.write(&mut address_space, &request);
+    let response = ats.write(&session, &mut address_space, &request);
     assert!(response.is_ok());
     let response: WriteResponse = supported_message_as!(response.unwrap(), WriteResponse);
     let results = response.results.unwrap();
@@ -185,7 +187,539 @@ fn write_test() {
 
     // OTHER POTENTIAL TESTS
 
-    // write index range
     // distinguish between write and user write
     // test max_age
 }
+
+fn add_index_range_vars(address_space: &mut AddressSpace) -> (NodeId, NodeId, NodeId) {
+    let array_id = NodeId::new(1, "array");
+    let matrix_id = NodeId::new(1, "matrix");
+    let string_id = NodeId::new(1, "string");
+    let matrix = MultiDimensionArray::new((0..9).map(Variant::from).collect::<Vec<Variant>>(), vec![3, 3]);
+    let _ = address_space.add_variables(vec![
+        Variable::new(&array_id, "array", "array", "", vec![0i32, 1, 2, 3, 4]),
+        Variable::new(&matrix_id, "matrix", "matrix", "", Variant::from(matrix)),
+        Variable::new(&string_id, "string", "string", "", "Hello world"),
+    ], &AddressSpace::objects_folder_id());
+    for node_id in &[&array_id, &matrix_id, &string_id] {
+        let node = address_space.find_node_mut(node_id).unwrap();
+        let _ = node.as_mut_node().set_attribute(AttributeId::AccessLevel, DataValue::new((AccessLevel::CURRENT_READ | AccessLevel::CURRENT_WRITE).bits())).unwrap();
+    }
+    (array_id, matrix_id, string_id)
+}
+
+fn read_index_range(st: &ServiceTest, node_id: &NodeId, index_range: &str) -> DataValue {
+    let mut node_to_read = read_value(node_id, AttributeId::Value);
+    node_to_read.index_range = UAString::from(index_range);
+    let request = ReadRequest {
+        request_header: make_request_header(),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Neither,
+        nodes_to_read: Some(vec![node_to_read]),
+    };
+    let session = st.session.read().unwrap();
+    let address_space = st.address_space.read().unwrap();
+    let response = AttributeService::new().read(&session, &address_space, &request);
+    let response: ReadResponse = supported_message_as!(response.unwrap(), ReadResponse);
+    response.results.unwrap().remove(0)
+}
+
+#[test]
+fn read_index_range_test() {
+    let st = ServiceTest::new();
+    let (array_id, matrix_id, string_id) = {
+        let mut address_space = st.address_space.write().unwrap();
+        add_index_range_vars(&mut address_space)
+    };
+
+    // A single index and a range of an array
+    let result = read_index_range(&st, &array_id, "1");
+    assert_eq!(result.value, Some(Variant::from(vec![1i32])));
+    let result = read_index_range(&st, &array_id, "1:3");
+    assert_eq!(result.value, Some(Variant::from(vec![1i32, 2, 3])));
+
+    // A range past the end of the array returns what there is, but one outside it returns nothing
+    let result = read_index_range(&st, &array_id, "3:9");
+    assert_eq!(result.value, Some(Variant::from(vec![3i32, 4])));
+    let result = read_index_range(&st, &array_id, "5:9");
+    assert!(result.value.is_none());
+    assert_eq!(result.status, Some(StatusCode::BadIndexRangeNoData.bits()));
+
+    // A block of a multi dimensional array
+    let result = read_index_range(&st, &matrix_id, "1:2,0:1");
+    let expected = MultiDimensionArray::new(vec![Variant::from(3), Variant::from(4), Variant::from(6), Variant::from(7)], vec![2, 2]);
+    assert_eq!(result.value, Some(Variant::from(expected)));
+
+    // A substring
+    let result = read_index_range(&st, &string_id, "6:10");
+    assert_eq!(result.value, Some(Variant::from("world")));
+
+    // Malformed ranges, and ranges with the wrong number of dimensions
+    for (node_id, index_range) in &[(&array_id, "x"), (&array_id, "3:1"), (&array_id, "1,1"), (&matrix_id, "1"), (&string_id, "1,1")] {
+        let result = read_index_range(&st, node_id, index_range);
+        assert!(result.value.is_none());
+        assert_eq!(result.status, Some(StatusCode::BadIndexRangeInvalid.bits()));
+    }
+}
+
+#[test]
+fn write_index_range_test() {
+    let st = ServiceTest::new();
+    let (array_id, matrix_id, string_id) = {
+        let mut address_space = st.address_space.write().unwrap();
+        add_index_range_vars(&mut address_space)
+    };
+
+    let write_index_range = |node_id: &NodeId, index_range: &str, value: Variant| {
+        let mut node_to_write = write_value(node_id, AttributeId::Value, DataValue::new(value));
+        node_to_write.index_range = UAString::from(index_range);
+        let request = WriteRequest {
+            request_header: make_request_header(),
+            nodes_to_write: Some(vec![node_to_write]),
+        };
+        let session = st.session.read().unwrap();
+        let mut address_space = st.address_space.write().unwrap();
+        let response = AttributeService::new().write(&session, &mut address_space, &request);
+        let response: WriteResponse = supported_message_as!(response.unwrap(), WriteResponse);
+        response.results.unwrap()[0]
+    };
+
+    // Write part of an array
+    assert_eq!(write_index_range(&array_id, "1:2", Variant::from(vec![10i32, 20])), StatusCode::Good);
+    assert_eq!(read_index_range(&st, &array_id, "0:4").value, Some(Variant::from(vec![0i32, 10, 20, 3, 4])));
+
+    // The value must fit the range, which must be inside the array, and be of the same type
+    assert_eq!(write_index_range(&array_id, "1:2", Variant::from(vec![10i32])), StatusCode::BadIndexRangeInvalid);
+    assert_eq!(write_index_range(&array_id, "4:5", Variant::from(vec![10i32, 20])), StatusCode::BadIndexRangeNoData);
+    assert_eq!(write_index_range(&array_id, "1:2", Variant::from(vec![10u32, 20])), StatusCode::BadTypeMismatch);
+    assert_eq!(write_index_range(&array_id, "1:x", Variant::from(vec![10i32, 20])), StatusCode::BadIndexRangeInvalid);
+
+    // Write a column of a multi dimensional array
+    let column = MultiDimensionArray::new(vec![Variant::from(-1), Variant::from(-2), Variant::from(-3)], vec![3, 1]);
+    assert_eq!(write_index_range(&matrix_id, "0:2,1", Variant::from(column)), StatusCode::Good);
+    let result = read_index_range(&st, &matrix_id, "0:2,0:2");
+    let expected = MultiDimensionArray::new(vec![0, -1, 2, 3, -2, 5, 6, -3, 8].into_iter().map(Variant::from).collect::<Vec<Variant>>(), vec![3, 3]);
+    assert_eq!(result.value, Some(Variant::from(expected)));
+
+    // Write part of a string
+    assert_eq!(write_index_range(&string_id, "0:4", Variant::from("Howdy")), StatusCode::Good);
+    assert_eq!(read_index_range(&st, &string_id, "0:10").value, Some(Variant::from("Howdy world")));
+}
+
+fn add_structure_var(address_space: &mut AddressSpace) -> NodeId {
+    let argument = Argument {
+        name: UAString::from("arg"),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::null(),
+    };
+    let structure_id = NodeId::new(1, "structure");
+    let value = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &argument);
+    let _ = address_space.add_variable(Variable::new_data_value(&structure_id, "structure", "structure", "", DataTypeId::Argument, DataValue::new(value)), &AddressSpace::objects_folder_id());
+    structure_id
+}
+
+fn read_data_encoding(st: &ServiceTest, node_id: &NodeId, attribute_id: AttributeId, data_encoding: &str) -> DataValue {
+    let mut node_to_read = read_value(node_id, attribute_id);
+    node_to_read.data_encoding = QualifiedName::new(0, data_encoding);
+    let request = ReadRequest {
+        request_header: make_request_header(),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Neither,
+        nodes_to_read: Some(vec![node_to_read]),
+    };
+    let session = st.session.read().unwrap();
+    let address_space = st.address_space.read().unwrap();
+    let response = AttributeService::new().read(&session, &address_space, &request);
+    let response: ReadResponse = supported_message_as!(response.unwrap(), ReadResponse);
+    response.results.unwrap().remove(0)
+}
+
+#[test]
+fn read_data_encoding_test() {
+    let st = ServiceTest::new();
+    let (structure_id, array_id) = {
+        let mut address_space = st.address_space.write().unwrap();
+        let (array_id, _, _) = add_index_range_vars(&mut address_space);
+        (add_structure_var(&mut address_space), array_id)
+    };
+    let binary_value = Variant::from(ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &Argument {
+        name: UAString::from("arg"),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::null(),
+    }));
+
+    // The binary encoding is the value as it is
+    let result = read_data_encoding(&st, &structure_id, AttributeId::Value, "Default Binary");
+    assert_eq!(result.status, Some(StatusCode::Good.bits()));
+    assert_eq!(result.value, Some(binary_value.clone()));
+
+    // The XML encoding of a generated type is built in, until the server registers an encoder
+    let result = read_data_encoding(&st, &structure_id, AttributeId::Value, "Default XML");
+    assert_eq!(result.status, Some(StatusCode::Good.bits()));
+    let xml_value = match result.value {
+        Some(Variant::ExtensionObject(ref value)) => value.clone(),
+        _ => panic!("Value is not an extension object"),
+    };
+    assert_eq!(xml_value.node_id, ObjectId::Argument_Encoding_DefaultXml.into());
+    let xml_value = xml_value.to_binary_encoding(&DecodingLimits::default()).unwrap();
+    assert_eq!(Some(Variant::from(xml_value)), Some(binary_value));
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        address_space.register_data_encoder(DataTypeId::Argument, DataEncoding::Xml, Box::new(|extension_object| {
+            let argument = extension_object.decode_inner::<Argument>(&DecodingLimits::default())?;
+            Ok(ExtensionObjectEncoding::XmlElement(XmlElement::from(format!("<Argument><Name>{}</Name></Argument>", argument.name).as_ref())))
+        }));
+    }
+    let result = read_data_encoding(&st, &structure_id, AttributeId::Value, "Default XML");
+    assert_eq!(result.status, Some(StatusCode::Good.bits()));
+    assert_eq!(result.value, Some(Variant::from(ExtensionObject {
+        node_id: NodeId::new(0, 297),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<Argument><Name>arg</Name></Argument>")),
+    })));
+
+    // The data type has no JSON encoding, and there is no such encoding as "Default Foo"
+    let result = read_data_encoding(&st, &structure_id, AttributeId::Value, "Default JSON");
+    assert_eq!(result.status, Some(StatusCode::BadDataEncodingInvalid.bits()));
+    let result = read_data_encoding(&st, &structure_id, AttributeId::Value, "Default Foo");
+    assert_eq!(result.status, Some(StatusCode::BadDataEncodingInvalid.bits()));
+
+    // Values that are not structures and attributes other than the value cannot be encoded
+    let result = read_data_encoding(&st, &array_id, AttributeId::Value, "Default Binary");
+    assert_eq!(result.status, Some(StatusCode::BadDataEncodingInvalid.bits()));
+    let result = read_data_encoding(&st, &structure_id, AttributeId::BrowseName, "Default Binary");
+    assert_eq!(result.status, Some(StatusCode::BadDataEncodingInvalid.bits()));
+}
+
+fn history_read_value_id(node_id: &NodeId, continuation_point: ByteString) -> HistoryReadValueId {
+    HistoryReadValueId {
+        node_id: node_id.clone(),
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+        continuation_point,
+    }
+}
+
+fn history_read_request(start_time: DateTime, end_time: DateTime, num_values_per_node: u32, nodes_to_read: Vec<HistoryReadValueId>) -> HistoryReadRequest {
+    let details = ReadRawModifiedDetails {
+        is_read_modified: false,
+        start_time,
+        end_time,
+        num_values_per_node,
+        return_bounds: false,
+    };
+    HistoryReadRequest {
+        request_header: make_request_header(),
+        history_read_details: ExtensionObject::from_encodable(ObjectId::ReadRawModifiedDetails_Encoding_DefaultBinary, &details),
+        timestamps_to_return: TimestampsToReturn::Source,
+        release_continuation_points: false,
+        nodes_to_read: Some(nodes_to_read),
+    }
+}
+
+fn history_read(st: &ServiceTest, request: &HistoryReadRequest) -> Vec<HistoryReadResult> {
+    let ats = AttributeService::new();
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let address_space = st.address_space.read().unwrap();
+    let response = ats.history_read(&server_state, &mut session, &address_space, request);
+    let response: HistoryReadResponse = supported_message_as!(response.unwrap(), HistoryReadResponse);
+    response.results.unwrap()
+}
+
+fn history_data_values(result: &HistoryReadResult) -> Vec<i32> {
+    let history_data = result.history_data.decode_inner::<HistoryData>(&DecodingLimits::default()).unwrap();
+    history_data.data_values.unwrap().iter().map(|v| {
+        if let Some(Variant::Int32(v)) = v.value { v } else { panic!("Expecting an Int32 value") }
+    }).collect()
+}
+
+#[test]
+fn history_read_test() {
+    let st = ServiceTest::new();
+
+    let historizing_id = NodeId::new(1, "historizing");
+    let not_historizing_id = NodeId::new(1, "not_historizing");
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let variable = VariableBuilder::new(&historizing_id)
+            .browse_name("historizing")
+            .display_name("historizing")
+            .value(Variant::Int32(0))
+            .historizing(true)
+            .build();
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+        let _ = address_space.add_variable(Variable::new(&not_historizing_id, "not_historizing", "not_historizing", "", 0i32), &AddressSpace::objects_folder_id());
+
+        // Record values at one second intervals
+        for i in 0..10 {
+            let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 0, i as u16);
+            assert!(address_space.set_variable_value(historizing_id.clone(), i, &timestamp, &timestamp));
+        }
+    }
+
+    // Read the values from 2 to 8 seconds, 4 at a time
+    let start_time = DateTime::ymd_hms(2019, 1, 1, 0, 0, 2);
+    let end_time = DateTime::ymd_hms(2019, 1, 1, 0, 0, 8);
+    let request = history_read_request(start_time.clone(), end_time.clone(), 4, vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+        history_read_value_id(&not_historizing_id, ByteString::null()),
+        history_read_value_id(&NodeId::new(1, "xxx"), ByteString::null()),
+        history_read_value_id(&historizing_id, ByteString::from(vec![1u8, 2u8, 3u8])),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(history_data_values(&results[0]), vec![2, 3, 4, 5]);
+    assert!(!results[0].continuation_point.is_null());
+    assert_eq!(results[1].status_code, StatusCode::BadNotReadable);
+    assert_eq!(results[2].status_code, StatusCode::BadNodeIdUnknown);
+    assert_eq!(results[3].status_code, StatusCode::BadContinuationPointInvalid);
+
+    // The continuation point cannot continue a read of another node or with other details
+    let continuation_point = results[0].continuation_point.clone();
+    let request = history_read_request(start_time.clone(), end_time.clone(), 4, vec![
+        history_read_value_id(&not_historizing_id, continuation_point.clone()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::BadContinuationPointInvalid);
+    let request = history_read_request(start_time.clone(), end_time.clone(), 3, vec![
+        history_read_value_id(&historizing_id, continuation_point.clone()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::BadContinuationPointInvalid);
+
+    // Continue the read for the remaining values
+    let request = history_read_request(start_time.clone(), end_time.clone(), 4, vec![
+        history_read_value_id(&historizing_id, continuation_point.clone()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(history_data_values(&results[0]), vec![6, 7]);
+    assert!(results[0].continuation_point.is_null());
+
+    // The continuation point has been consumed
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::BadContinuationPointInvalid);
+
+    // Read backwards, releasing the continuation point
+    let request = history_read_request(end_time.clone(), start_time.clone(), 2, vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(history_data_values(&results[0]), vec![8, 7]);
+    let continuation_point = results[0].continuation_point.clone();
+    let mut request = history_read_request(end_time.clone(), start_time.clone(), 2, vec![
+        history_read_value_id(&historizing_id, continuation_point),
+    ]);
+    request.release_continuation_points = true;
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert!(results[0].history_data.is_null());
+    request.release_continuation_points = false;
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::BadContinuationPointInvalid);
+
+    // A value written by a client is recorded too
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let variable = address_space.find_variable_mut(historizing_id.clone()).unwrap();
+        variable.set_writable(true);
+        let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 1, 0);
+        let request = WriteRequest {
+            request_header: make_request_header(),
+            nodes_to_write: Some(vec![write_value(&historizing_id, AttributeId::Value, DataValue::from((Variant::Int32(100), &timestamp, &timestamp)))]),
+        };
+        let session = st.session.read().unwrap();
+        let _ = AttributeService::new().write(&session, &mut address_space, &request);
+    }
+    let request = history_read_request(DateTime::ymd_hms(2019, 1, 1, 0, 0, 30), DateTime::epoch(), 10, vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(history_data_values(&results[0]), vec![100]);
+
+    // Timestamps to return must not be neither
+    let mut request = history_read_request(start_time, end_time, 0, vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+    ]);
+    request.timestamps_to_return = TimestampsToReturn::Neither;
+    let ats = AttributeService::new();
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let address_space = st.address_space.read().unwrap();
+    let response = ats.history_read(&server_state, &mut session, &address_space, &request).unwrap();
+    let response: ServiceFault = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadTimestampsToReturnInvalid);
+}
+
+#[test]
+fn history_read_processed_test() {
+    let st = ServiceTest::new();
+
+    let historizing_id = NodeId::new(1, "historizing");
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let variable = VariableBuilder::new(&historizing_id)
+            .browse_name("historizing")
+            .display_name("historizing")
+            .value(Variant::Int32(0))
+            .historizing(true)
+            .build();
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+        for i in 0..10 {
+            let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 0, i as u16);
+            assert!(address_space.set_variable_value(historizing_id.clone(), i, &timestamp, &timestamp));
+        }
+
+        // The supported aggregates are organized by the aggregate functions folder
+        let aggregate_functions_id: NodeId = ObjectId::Server_ServerCapabilities_AggregateFunctions.into();
+        assert!(address_space.has_reference(&aggregate_functions_id, ReferenceTypeId::Organizes, &ObjectId::AggregateFunction_Average.into()));
+        assert!(!address_space.has_reference(&aggregate_functions_id, ReferenceTypeId::Organizes, &ObjectId::AggregateFunction_AnnotationCount.into()));
+    }
+
+    let processed_request = |aggregate_type: Vec<NodeId>, nodes_to_read: Vec<HistoryReadValueId>| {
+        let details = ReadProcessedDetails {
+            start_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, 0),
+            end_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, 10),
+            processing_interval: 5000f64,
+            aggregate_type: Some(aggregate_type),
+            aggregate_configuration: aggregates::default_aggregate_configuration(),
+        };
+        HistoryReadRequest {
+            request_header: make_request_header(),
+            history_read_details: ExtensionObject::from_encodable(ObjectId::ReadProcessedDetails_Encoding_DefaultBinary, &details),
+            timestamps_to_return: TimestampsToReturn::Source,
+            release_continuation_points: false,
+            nodes_to_read: Some(nodes_to_read),
+        }
+    };
+
+    // Each node has its own aggregate
+    let request = processed_request(vec![
+        ObjectId::AggregateFunction_Maximum.into(),
+        ObjectId::AggregateFunction_Count.into(),
+        ObjectId::AggregateFunction_AnnotationCount.into(),
+    ], vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+        history_read_value_id(&historizing_id, ByteString::null()),
+        history_read_value_id(&historizing_id, ByteString::null()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(history_data_values(&results[0]), vec![4, 9]);
+    assert_eq!(results[1].status_code, StatusCode::Good);
+    assert_eq!(history_data_values(&results[1]), vec![5, 5]);
+    assert_eq!(results[2].status_code, StatusCode::BadAggregateNotSupported);
+
+    // The number of aggregates must match the number of nodes
+    let request = processed_request(vec![ObjectId::AggregateFunction_Maximum.into()], vec![
+        history_read_value_id(&historizing_id, ByteString::null()),
+        history_read_value_id(&historizing_id, ByteString::null()),
+    ]);
+    let ats = AttributeService::new();
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let address_space = st.address_space.read().unwrap();
+    let response = ats.history_read(&server_state, &mut session, &address_space, &request).unwrap();
+    let response: ServiceFault = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadAggregateListMismatch);
+}
+
+fn history_update(st: &ServiceTest, history_update_details: Vec<ExtensionObject>) -> Vec<HistoryUpdateResult> {
+    let ats = AttributeService::new();
+    let server_state = st.server_state.read().unwrap();
+    let address_space = st.address_space.read().unwrap();
+    let request = HistoryUpdateRequest {
+        request_header: make_request_header(),
+        history_update_details: Some(history_update_details),
+    };
+    let response = ats.history_update(&server_state, &address_space, &request);
+    let response: HistoryUpdateResponse = supported_message_as!(response.unwrap(), HistoryUpdateResponse);
+    response.results.unwrap()
+}
+
+fn update_data_details(node_id: &NodeId, perform_insert_replace: PerformUpdateType, values: &[(i32, u16)]) -> ExtensionObject {
+    let update_values = values.iter().map(|&(value, second)| {
+        let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 0, second);
+        DataValue::from((Variant::Int32(value), &timestamp, &timestamp))
+    }).collect();
+    let details = UpdateDataDetails {
+        node_id: node_id.clone(),
+        perform_insert_replace,
+        update_values: Some(update_values),
+    };
+    ExtensionObject::from_encodable(ObjectId::UpdateDataDetails_Encoding_DefaultBinary, &details)
+}
+
+#[test]
+fn history_update_test() {
+    let st = ServiceTest::new();
+
+    let history_writable_id = NodeId::new(1, "history_writable");
+    let not_user_history_writable_id = NodeId::new(1, "not_user_history_writable");
+    let historizing_id = NodeId::new(1, "historizing");
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let variable = VariableBuilder::new(&history_writable_id)
+            .browse_name("history_writable")
+            .display_name("history_writable")
+            .value(Variant::Int32(0))
+            .historizing(true)
+            .access_level(AccessLevel::CURRENT_READ | AccessLevel::HISTORY_READ | AccessLevel::HISTORY_WRITE)
+            .user_access_level(UserAccessLevel::CURRENT_READ | UserAccessLevel::HISTORY_READ | UserAccessLevel::HISTORY_WRITE)
+            .build();
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+        let variable = VariableBuilder::new(&not_user_history_writable_id)
+            .browse_name("not_user_history_writable")
+            .display_name("not_user_history_writable")
+            .value(Variant::Int32(0))
+            .access_level(AccessLevel::HISTORY_WRITE)
+            .user_access_level(UserAccessLevel::CURRENT_READ)
+            .build();
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+        let variable = VariableBuilder::new(&historizing_id)
+            .browse_name("historizing")
+            .display_name("historizing")
+            .value(Variant::Int32(0))
+            .historizing(true)
+            .build();
+        let _ = address_space.add_variable(variable, &AddressSpace::objects_folder_id());
+
+        for i in 0..5 {
+            let timestamp = DateTime::ymd_hms(2019, 1, 1, 0, 0, i as u16);
+            assert!(address_space.set_variable_value(history_writable_id.clone(), i, &timestamp, &timestamp));
+        }
+    }
+
+    let delete_details = DeleteRawModifiedDetails {
+        node_id: history_writable_id.clone(),
+        is_delete_modified: false,
+        start_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, 0),
+        end_time: DateTime::ymd_hms(2019, 1, 1, 0, 0, 2),
+    };
+    let results = history_update(&st, vec![
+        update_data_details(&history_writable_id, PerformUpdateType::Insert, &[(100, 2), (105, 5)]),
+        update_data_details(&history_writable_id, PerformUpdateType::Replace, &[(103, 3)]),
+        ExtensionObject::from_encodable(ObjectId::DeleteRawModifiedDetails_Encoding_DefaultBinary, &delete_details),
+        update_data_details(&not_user_history_writable_id, PerformUpdateType::Insert, &[(1, 1)]),
+        update_data_details(&historizing_id, PerformUpdateType::Insert, &[(1, 1)]),
+        update_data_details(&NodeId::new(1, "xxx"), PerformUpdateType::Insert, &[(1, 1)]),
+        ExtensionObject::from_encodable(ObjectId::ReadRawModifiedDetails_Encoding_DefaultBinary, &delete_details),
+    ]);
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(results[0].operation_results, Some(vec![StatusCode::BadEntryExists, StatusCode::GoodEntryInserted]));
+    assert_eq!(results[1].status_code, StatusCode::Good);
+    assert_eq!(results[1].operation_results, Some(vec![StatusCode::GoodEntryReplaced]));
+    assert_eq!(results[2].status_code, StatusCode::Good);
+    assert_eq!(results[3].status_code, StatusCode::BadUserAccessDenied);
+    assert_eq!(results[4].status_code, StatusCode::BadNotWritable);
+    assert_eq!(results[5].status_code, StatusCode::BadNodeIdUnknown);
+    assert_eq!(results[6].status_code, StatusCode::BadHistoryOperationInvalid);
+
+    // The history reflects the updates
+    let request = history_read_request(DateTime::ymd_hms(2019, 1, 1, 0, 0, 0), DateTime::ymd_hms(2019, 1, 1, 0, 0, 59), 0, vec![
+        history_read_value_id(&history_writable_id, ByteString::null()),
+    ]);
+    let results = history_read(&st, &request);
+    assert_eq!(history_data_values(&results[0]), vec![2, 103, 4, 105]);
+}
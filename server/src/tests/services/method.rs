@@ -1,9 +1,11 @@
 use opcua_types::status_code::StatusCode;
 use opcua_types::service_types::{CallRequest, CallResponse, CallMethodRequest, CallMethodResult};
-use opcua_types::node_ids::{ObjectId, MethodId};
+use opcua_types::node_ids::{ObjectId, ObjectTypeId, MethodId, ReferenceTypeId};
 
 use super::*;
 
+use crate::address_space::object::Object;
+use crate::events::condition;
 use crate::services::method::MethodService;
 use crate::services::subscription::SubscriptionService;
 use crate::services::monitored_item::MonitoredItemService;
@@ -54,7 +56,7 @@ fn create_monitored_items_request<T>(subscription_id: u32, client_handle: u32, n
 }
 
 /// This is a convenience for tests
-fn call_single(s: &MethodService, address_space: &AddressSpace, server_state: &ServerState, session: &mut Session, request: CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
+fn call_single(s: &MethodService, address_space: &mut AddressSpace, server_state: &ServerState, session: &mut Session, request: CallMethodRequest) -> Result<CallMethodResult, StatusCode> {
     let response = s.call(address_space, server_state, session, &CallRequest {
         request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 1),
         methods_to_call: Some(vec![request]),
@@ -70,26 +72,26 @@ fn call_getmonitoreditems() {
     let s = MethodService::new();
 
     let (mut server_state, mut session) = st.get_server_state_and_session();
-    let address_space = st.address_space.write().unwrap();
+    let mut address_space = st.address_space.write().unwrap();
 
     // Call without a valid object id
     {
         let request = new_call_method_request(NodeId::null(), MethodId::Server_GetMonitoredItems, None);
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadNodeIdUnknown);
     }
 
     // Call without a valid method id
     {
         let request = new_call_method_request(ObjectId::Server, NodeId::null(), None);
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadMethodInvalid);
     }
 
     // Call without args
     {
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_GetMonitoredItems, None);
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadArgumentsMissing);
     }
 
@@ -97,7 +99,7 @@ fn call_getmonitoreditems() {
     {
         let args: Vec<Variant> = vec![100.into(), 100.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_GetMonitoredItems, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadTooManyArguments);
     }
 
@@ -105,7 +107,7 @@ fn call_getmonitoreditems() {
     {
         let args: Vec<Variant> = vec![100u8.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_GetMonitoredItems, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadInvalidArgument);
     }
 
@@ -113,7 +115,7 @@ fn call_getmonitoreditems() {
     {
         let args: Vec<Variant> = vec![100u32.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_GetMonitoredItems, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadSubscriptionIdInvalid);
     }
 
@@ -132,14 +134,14 @@ fn call_getmonitoreditems() {
         // Create a monitored item
         let monitored_item_id = {
             let request = create_monitored_items_request(subscription_id, 999, VariableId::Server_ServerStatus_CurrentTime);
-            let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(&mut session, &request).unwrap(), CreateMonitoredItemsResponse);
+            let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(&mut session, &address_space, &request).unwrap(), CreateMonitoredItemsResponse);
             response.results.unwrap()[0].monitored_item_id
         };
 
         // Call to get monitored items and verify handles
         let args: Vec<Variant> = vec![subscription_id.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_GetMonitoredItems, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::Good);
 
         // There should be two output args, each a vector of u32
@@ -172,12 +174,12 @@ fn call_resend_data() {
     let s = MethodService::new();
 
     let (mut server_state, mut session) = st.get_server_state_and_session();
-    let address_space = st.address_space.write().unwrap();
+    let mut address_space = st.address_space.write().unwrap();
 
     // Call without a valid object id
     {
         let request = new_call_method_request(NodeId::null(), MethodId::Server_ResendData, None);
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadNodeIdUnknown);
     }
 
@@ -186,7 +188,7 @@ fn call_resend_data() {
     {
         let args: Vec<Variant> = vec![100u32.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_ResendData, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::BadSubscriptionIdInvalid);
     }
 
@@ -205,7 +207,149 @@ fn call_resend_data() {
         // Call to get monitored items and verify handles
         let args: Vec<Variant> = vec![subscription_id.into()];
         let request = new_call_method_request(ObjectId::Server, MethodId::Server_ResendData, Some(args));
-        let response = call_single(&s, &address_space, &server_state, &mut session, request).unwrap();
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
         assert_eq!(response.status_code, StatusCode::Good);
     }
 }
+
+/// Adds an active alarm to the address space, returning its id and the id of its last event
+fn add_active_alarm(address_space: &mut AddressSpace, confirmable: bool) -> (NodeId, ByteString) {
+    let source_id = NodeId::new(1, "Boiler");
+    address_space.insert(Object::new(&source_id, "Boiler", "Boiler", ""), Some(&[
+        (&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+    ]));
+    let alarm_id = NodeId::new(1, "Alarm");
+    condition::add_condition(address_space, &alarm_id, "Alarm", ObjectTypeId::AlarmConditionType, &source_id, confirmable).unwrap();
+    condition::set_active(address_space, &alarm_id, true, 500, "Too hot").unwrap();
+    let event_id = condition::condition_event(address_space, &alarm_id).unwrap().event_id;
+    (alarm_id, event_id)
+}
+
+#[test]
+fn call_condition_methods() {
+    let st = ServiceTest::new();
+
+    let s = MethodService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (alarm_id, event_id) = add_active_alarm(&mut address_space, false);
+    let acked_state_id = NodeId::new(1, "Alarm.AckedState.Id");
+
+    // The methods of ConditionType and AcknowledgeableConditionType are called on the alarm
+    {
+        let request = new_call_method_request(alarm_id.clone(), MethodId::AcknowledgeableConditionType_Acknowledge, Some(vec![event_id.clone().into()]));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::BadArgumentsMissing);
+
+        let args: Vec<Variant> = vec![event_id.clone().into(), LocalizedText::new("", "On it").into()];
+        let request = new_call_method_request(alarm_id.clone(), MethodId::AcknowledgeableConditionType_Acknowledge, Some(args.clone()));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::Good);
+        assert_eq!(address_space.get_variable_value(acked_state_id).unwrap().value.unwrap(), Variant::from(true));
+
+        // The event id is no longer that of the last event
+        let request = new_call_method_request(alarm_id.clone(), MethodId::AcknowledgeableConditionType_Acknowledge, Some(args));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::BadEventIdUnknown);
+    }
+
+    // The alarm is not confirmable
+    {
+        let args: Vec<Variant> = vec![event_id.into(), LocalizedText::new("", "").into()];
+        let request = new_call_method_request(alarm_id.clone(), MethodId::AcknowledgeableConditionType_Confirm, Some(args));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::BadMethodInvalid);
+    }
+
+    {
+        // Disable modifies the address space so it cannot be called through a shared reference
+        let request = new_call_method_request(alarm_id.clone(), MethodId::ConditionType_Disable, None);
+        assert_eq!(address_space.call_method(&server_state, &mut session, &request).unwrap_err(), StatusCode::BadNotSupported);
+        assert!(condition::is_enabled(&address_space, &alarm_id));
+
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::Good);
+        assert!(!condition::is_enabled(&address_space, &alarm_id));
+
+        let request = new_call_method_request(alarm_id.clone(), MethodId::ConditionType_Disable, None);
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::BadConditionAlreadyDisabled);
+
+        let request = new_call_method_request(alarm_id.clone(), MethodId::ConditionType_Enable, None);
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::Good);
+        assert!(condition::is_enabled(&address_space, &alarm_id));
+    }
+}
+
+#[test]
+fn call_condition_refresh() {
+    let st = ServiceTest::new();
+
+    let s = MethodService::new();
+    let ss = SubscriptionService::new();
+    let mis = MonitoredItemService::new();
+
+    let (mut server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+
+    // Call with invalid subscription id
+    {
+        let args: Vec<Variant> = vec![100u32.into()];
+        let request = new_call_method_request(ObjectId::Server, MethodId::ConditionType_ConditionRefresh, Some(args));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::BadSubscriptionIdInvalid);
+    }
+
+    // A subscription monitoring the events of the server
+    let subscription_id = {
+        let request = create_subscription_request();
+        let response: CreateSubscriptionResponse = supported_message_as!(ss.create_subscription(&mut server_state, &mut session, &request).unwrap(), CreateSubscriptionResponse);
+        response.subscription_id
+    };
+    {
+        let select_clause = |name: &str| SimpleAttributeOperand {
+            type_definition_id: ObjectTypeId::BaseEventType.into(),
+            browse_path: Some(vec![QualifiedName::new(0, name)]),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+        };
+        let mut request = create_monitored_items_request(subscription_id, 999, ObjectId::Server);
+        let item_to_create = &mut request.items_to_create.as_mut().unwrap()[0];
+        item_to_create.item_to_monitor.attribute_id = AttributeId::EventNotifier as u32;
+        item_to_create.requested_parameters.queue_size = 10;
+        item_to_create.requested_parameters.filter = ExtensionObject::from_encodable(ObjectId::EventFilter_Encoding_DefaultBinary, &EventFilter {
+            select_clauses: Some(vec![select_clause("EventType"), select_clause("Message")]),
+            where_clause: ContentFilter {
+                elements: None,
+            },
+        });
+        let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(&mut session, &address_space, &request).unwrap(), CreateMonitoredItemsResponse);
+        assert_eq!(response.results.unwrap()[0].status_code, StatusCode::Good);
+    }
+
+    // The retained alarm is sent between the start and end of the refresh
+    let _ = add_active_alarm(&mut address_space, true);
+    {
+        let args: Vec<Variant> = vec![subscription_id.into()];
+        let request = new_call_method_request(ObjectId::Server, MethodId::ConditionType_ConditionRefresh, Some(args));
+        let response = call_single(&s, &mut address_space, &server_state, &mut session, request).unwrap();
+        assert_eq!(response.status_code, StatusCode::Good);
+
+        let subscription = session.subscriptions.get_mut(subscription_id).unwrap();
+        let monitored_item = subscription.monitored_items.values_mut().next().unwrap();
+        let events = monitored_item.all_event_notifications().unwrap().into_iter()
+            .map(|event| event.event_fields.unwrap())
+            .collect::<Vec<Vec<Variant>>>();
+        let event_fields = |event_type: ObjectTypeId, message: &str| {
+            let event_type: NodeId = event_type.into();
+            vec![Variant::from(event_type), Variant::from(LocalizedText::new("", message))]
+        };
+        assert_eq!(events, vec![
+            event_fields(ObjectTypeId::RefreshStartEventType, ""),
+            event_fields(ObjectTypeId::AlarmConditionType, "Too hot"),
+            event_fields(ObjectTypeId::RefreshEndEventType, ""),
+        ]);
+    }
+}
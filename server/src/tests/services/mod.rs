@@ -66,6 +66,8 @@ pub mod attribute;
 pub mod discovery;
 pub mod session;
 pub mod monitored_item;
+pub mod node_management;
+pub mod query;
 pub mod subscription;
 pub mod view;
 pub mod method;
\ No newline at end of file
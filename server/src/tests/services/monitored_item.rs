@@ -1,8 +1,12 @@
 use chrono;
 
 use crate::prelude::*;
+use crate::services::{monitored_item::MonitoredItemService, subscription::SubscriptionService};
+use crate::state::ServerState;
 use super::*;
 
+use opcua_types::service_types::Argument;
+
 fn test_var_node_id() -> NodeId {
     NodeId::new(1, 1)
 }
@@ -200,6 +204,95 @@ fn monitored_item_data_change_filter() {
     assert_eq!(monitored_item.notification_queue.len(), 2);
 }
 
+#[test]
+fn monitored_item_index_range() {
+    let mut address_space = AddressSpace::new();
+    let array_id = NodeId::new(1, "array");
+    let _ = address_space.add_variable(Variable::new(&array_id, "array", "array", "", vec![0i32, 1, 2, 3, 4]), &AddressSpace::objects_folder_id());
+
+    let make_request = |index_range: &str| {
+        let mut request = make_create_request(-1f64, 5);
+        request.item_to_monitor.node_id = array_id.clone();
+        request.item_to_monitor.index_range = UAString::from(index_range);
+        request.requested_parameters.filter = ExtensionObject::null();
+        request
+    };
+    let set_array_value = |address_space: &mut AddressSpace, value: Vec<i32>| {
+        let now = DateTime::now();
+        assert!(address_space.set_variable_value(array_id.clone(), value, &now, &now));
+    };
+
+    // A malformed range is rejected
+    assert_eq!(MonitoredItem::new(1, TimestampsToReturn::Both, &make_request("2:1")).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+
+    // The item samples only the elements in its range
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_request("1:2")).unwrap();
+    let now = chrono::Utc::now();
+    assert!(monitored_item.tick(&address_space, &now, true, false));
+    assert_eq!(monitored_item.oldest_notification_message().unwrap().value.value, Some(Variant::from(vec![1i32, 2])));
+
+    // A change outside the range is not a change of the item's value, one inside it is
+    set_array_value(&mut address_space, vec![0, 1, 2, 3, 40]);
+    assert!(!monitored_item.tick(&address_space, &now, true, false));
+    set_array_value(&mut address_space, vec![0, 10, 2, 3, 40]);
+    assert!(monitored_item.tick(&address_space, &now, true, false));
+    assert_eq!(monitored_item.oldest_notification_message().unwrap().value.value, Some(Variant::from(vec![10i32, 2])));
+
+    // A range outside the value has no data
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_request("7")).unwrap();
+    assert!(monitored_item.tick(&address_space, &now, true, false));
+    let value = monitored_item.oldest_notification_message().unwrap().value;
+    assert!(value.value.is_none());
+    assert_eq!(value.status, Some(StatusCode::BadIndexRangeNoData.bits()));
+}
+
+#[test]
+fn monitored_item_data_encoding() {
+    let mut address_space = AddressSpace::new();
+    let structure_id = NodeId::new(1, "structure");
+    let value = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &Argument {
+        name: UAString::from("arg"),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::null(),
+    });
+    let _ = address_space.add_variable(Variable::new_data_value(&structure_id, "structure", "structure", "", DataTypeId::Argument, DataValue::new(value)), &AddressSpace::objects_folder_id());
+    address_space.register_data_encoder(DataTypeId::Argument, DataEncoding::Xml, Box::new(|_| {
+        Ok(ExtensionObjectEncoding::XmlElement(XmlElement::from("<Argument/>")))
+    }));
+
+    let make_request = |attribute_id: AttributeId, data_encoding: &str| {
+        let mut request = make_create_request(-1f64, 5);
+        request.item_to_monitor.node_id = structure_id.clone();
+        request.item_to_monitor.attribute_id = attribute_id as u32;
+        request.item_to_monitor.data_encoding = QualifiedName::new(0, data_encoding);
+        request.requested_parameters.filter = ExtensionObject::null();
+        request
+    };
+
+    // Unknown encodings and encodings of attributes other than the value are rejected
+    assert_eq!(MonitoredItem::new(1, TimestampsToReturn::Both, &make_request(AttributeId::Value, "Default Foo")).unwrap_err(), StatusCode::BadDataEncodingInvalid);
+    assert_eq!(MonitoredItem::new(1, TimestampsToReturn::Both, &make_request(AttributeId::DisplayName, "Default XML")).unwrap_err(), StatusCode::BadDataEncodingInvalid);
+
+    // The item samples the value in its encoding
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_request(AttributeId::Value, "Default XML")).unwrap();
+    let now = chrono::Utc::now();
+    assert!(monitored_item.tick(&address_space, &now, true, false));
+    let expected = ExtensionObject {
+        node_id: NodeId::new(0, 297),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<Argument/>")),
+    };
+    assert_eq!(monitored_item.oldest_notification_message().unwrap().value.value, Some(Variant::from(expected)));
+
+    // An encoding the data type does not have is reported in the status of the value
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_request(AttributeId::Value, "Default JSON")).unwrap();
+    assert!(monitored_item.tick(&address_space, &now, true, false));
+    let value = monitored_item.oldest_notification_message().unwrap().value;
+    assert!(value.value.is_none());
+    assert_eq!(value.status, Some(StatusCode::BadDataEncodingInvalid.bits()));
+}
+
 fn populate_monitored_item(discard_oldest: bool) -> MonitoredItem {
     let client_handle = 999;
     let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &make_create_request(-1f64, 5)).unwrap();
@@ -249,3 +342,301 @@ fn monitored_item_queue_discard_oldest() {
         assert_first_notification_is_i32(&mut monitored_item, 10);
     }
 }
+
+fn make_event_create_request(node_id: NodeId, attribute_id: AttributeId, queue_size: u32) -> MonitoredItemCreateRequest {
+    let select_clause = |name: &str| SimpleAttributeOperand {
+        type_definition_id: ObjectTypeId::BaseEventType.into(),
+        browse_path: Some(vec![QualifiedName::new(0, name)]),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+    };
+    let filter = ExtensionObject::from_encodable(ObjectId::EventFilter_Encoding_DefaultBinary, &EventFilter {
+        select_clauses: Some(vec![select_clause("SourceNode"), select_clause("Message")]),
+        where_clause: ContentFilter {
+            elements: None,
+        },
+    });
+    MonitoredItemCreateRequest {
+        item_to_monitor: ReadValueId {
+            node_id,
+            attribute_id: attribute_id as u32,
+            index_range: UAString::null(),
+            data_encoding: QualifiedName::null(),
+        },
+        monitoring_mode: MonitoringMode::Reporting,
+        requested_parameters: MonitoringParameters {
+            client_handle: 999,
+            sampling_interval: 0f64,
+            filter,
+            queue_size,
+            discard_oldest: true,
+        },
+    }
+}
+
+#[test]
+fn monitored_item_event_filter() {
+    let mut address_space = make_address_space();
+    let server_id: NodeId = ObjectId::Server.into();
+
+    // An event filter is only allowed on the event notifier attribute
+    let request = make_event_create_request(server_id.clone(), AttributeId::Value, 5);
+    assert_eq!(MonitoredItem::new(1, TimestampsToReturn::Both, &request).unwrap_err(), StatusCode::BadMonitoredItemFilterInvalid);
+
+    // The node must be an event notifier
+    let request = make_event_create_request(AddressSpace::objects_folder_id(), AttributeId::EventNotifier, 5);
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &request).unwrap();
+    assert_eq!(monitored_item.validate(&address_space).unwrap_err().0, StatusCode::BadNotReadable);
+
+    // Events raised before the item is created are not reported
+    let _ = address_space.raise_event(Event::new(ObjectTypeId::BaseEventType, &test_var_node_id(), "test", "Before", 100));
+
+    let request = make_event_create_request(server_id, AttributeId::EventNotifier, 2);
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &request).unwrap();
+    assert!(monitored_item.is_event_item());
+    assert_eq!(monitored_item.queue_size, 2);
+    let filter_result = monitored_item.validate(&address_space).unwrap();
+    let filter_result = filter_result.decode_inner::<EventFilterResult>(&DecodingLimits::default()).unwrap();
+    assert_eq!(filter_result.select_clause_results.unwrap(), vec![StatusCode::Good, StatusCode::Good]);
+
+    let now = chrono::Utc::now();
+    assert_eq!(monitored_item.tick(&address_space, &now, false, false), false);
+
+    for message in &["First", "Second", "Third"] {
+        let _ = address_space.raise_event(Event::new(ObjectTypeId::BaseEventType, &test_var_node_id(), "test", message, 100));
+    }
+    assert_eq!(monitored_item.tick(&address_space, &now, false, false), true);
+    assert_eq!(monitored_item.tick(&address_space, &now, false, false), false);
+
+    // The queue holds the 2 newest events
+    assert!(monitored_item.queue_overflow);
+    let events = monitored_item.all_event_notifications().unwrap();
+    assert!(!monitored_item.queue_overflow);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].client_handle, 999);
+    assert_eq!(events[0].event_fields.as_ref().unwrap(), &vec![Variant::from(test_var_node_id()), Variant::from(LocalizedText::new("", "Second"))]);
+    assert_eq!(events[1].event_fields.as_ref().unwrap(), &vec![Variant::from(test_var_node_id()), Variant::from(LocalizedText::new("", "Third"))]);
+    assert!(monitored_item.all_event_notifications().is_none());
+}
+
+#[test]
+fn monitored_item_event_where_clause() {
+    let mut address_space = make_address_space();
+    let severity = ExtensionObject::from_encodable(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary, &SimpleAttributeOperand {
+        type_definition_id: ObjectTypeId::BaseEventType.into(),
+        browse_path: Some(vec![QualifiedName::new(0, "Severity")]),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+    });
+    let minimum_severity = ExtensionObject::from_encodable(ObjectId::LiteralOperand_Encoding_DefaultBinary, &LiteralOperand {
+        value: Variant::UInt16(500),
+    });
+
+    // Only events of at least the minimum severity are reported
+    let mut request = make_event_create_request(ObjectId::Server.into(), AttributeId::EventNotifier, 10);
+    let mut filter = request.requested_parameters.filter.decode_inner::<EventFilter>(&DecodingLimits::default()).unwrap();
+    filter.where_clause = ContentFilter {
+        elements: Some(vec![ContentFilterElement {
+            filter_operator: FilterOperator::GreaterThanOrEqual,
+            filter_operands: Some(vec![severity, minimum_severity]),
+        }]),
+    };
+    request.requested_parameters.filter = ExtensionObject::from_encodable(ObjectId::EventFilter_Encoding_DefaultBinary, &filter);
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &request).unwrap();
+    let filter_result = monitored_item.validate(&address_space).unwrap();
+    let filter_result = filter_result.decode_inner::<EventFilterResult>(&DecodingLimits::default()).unwrap();
+    assert_eq!(filter_result.where_clause_result.element_results.unwrap()[0].status_code, StatusCode::Good);
+
+    for (message, severity) in &[("Low", 100), ("High", 500), ("Higher", 900)] {
+        let _ = address_space.raise_event(Event::new(ObjectTypeId::BaseEventType, &test_var_node_id(), "test", message, *severity));
+    }
+    assert_eq!(monitored_item.tick(&address_space, &chrono::Utc::now(), false, false), true);
+    let messages = monitored_item.all_event_notifications().unwrap().into_iter()
+        .map(|event| event.event_fields.unwrap()[1].clone())
+        .collect::<Vec<Variant>>();
+    assert_eq!(messages, vec![Variant::from(LocalizedText::new("", "High")), Variant::from(LocalizedText::new("", "Higher"))]);
+
+    // An invalid where clause fails with its filter result
+    filter.where_clause.elements.as_mut().unwrap()[0].filter_operands = None;
+    request.requested_parameters.filter = ExtensionObject::from_encodable(ObjectId::EventFilter_Encoding_DefaultBinary, &filter);
+    let mut monitored_item = MonitoredItem::new(1, TimestampsToReturn::Both, &request).unwrap();
+    let (status_code, filter_result) = monitored_item.validate(&address_space).unwrap_err();
+    assert_eq!(status_code, StatusCode::BadContentFilterInvalid);
+    let filter_result = filter_result.decode_inner::<EventFilterResult>(&DecodingLimits::default()).unwrap();
+    assert_eq!(filter_result.where_clause_result.element_results.unwrap()[0].status_code, StatusCode::BadFilterOperandCountMismatch);
+}
+
+fn create_subscription(session: &mut Session, server_state: &mut ServerState, ss: &SubscriptionService) -> u32 {
+    let request = CreateSubscriptionRequest {
+        request_header: make_request_header(),
+        requested_publishing_interval: 100f64,
+        requested_lifetime_count: 0,
+        requested_max_keep_alive_count: 0,
+        max_notifications_per_publish: 0,
+        publishing_enabled: true,
+        priority: 0,
+    };
+    let response = supported_message_as!(ss.create_subscription(server_state, session, &request).unwrap(), CreateSubscriptionResponse);
+    // Put the subscription into normal state so its items are ticked
+    session.subscriptions.get_mut(response.subscription_id).unwrap().state = SubscriptionState::Normal;
+    response.subscription_id
+}
+
+/// Creates items monitoring the values of the nodes, using the index of each as its client handle
+fn create_monitored_items(session: &mut Session, address_space: &AddressSpace, mis: &MonitoredItemService, subscription_id: u32, items: &[(NodeId, MonitoringMode)]) -> Vec<u32> {
+    let items_to_create = items.iter().enumerate().map(|(i, (node_id, monitoring_mode))| {
+        MonitoredItemCreateRequest {
+            item_to_monitor: node_id.clone().into(),
+            monitoring_mode: *monitoring_mode,
+            requested_parameters: MonitoringParameters {
+                client_handle: i as u32,
+                sampling_interval: 0f64,
+                filter: ExtensionObject::null(),
+                queue_size: 5,
+                discard_oldest: true,
+            },
+        }
+    }).collect();
+    let request = CreateMonitoredItemsRequest {
+        request_header: make_request_header(),
+        subscription_id,
+        timestamps_to_return: TimestampsToReturn::Both,
+        items_to_create: Some(items_to_create),
+    };
+    let response = supported_message_as!(mis.create_monitored_items(session, address_space, &request).unwrap(), CreateMonitoredItemsResponse);
+    response.results.unwrap().iter().map(|result| result.monitored_item_id).collect()
+}
+
+/// Publishes after the publishing interval has elapsed, returning the client handles and the
+/// values of the items that reported, in order
+fn publish(session: &mut Session, address_space: &AddressSpace, ss: &SubscriptionService, seconds: i64) -> Vec<(u32, Variant)> {
+    let request = PublishRequest {
+        request_header: make_request_header(),
+        subscription_acknowledgements: None,
+    };
+    let _ = ss.async_publish(session, 1000 + seconds as u32, address_space, &request).unwrap();
+    let now = chrono::Utc::now() + chrono::Duration::seconds(seconds);
+    let _ = session.tick_subscriptions(&now, address_space, TickReason::TickTimerFired);
+    let mut values = Vec::new();
+    while let Some(response) = session.subscriptions.publish_response_queue.pop_front() {
+        let response = supported_message_as!(response.response, PublishResponse);
+        for notification in response.notification_message.notification_data.unwrap_or_default() {
+            let data_change = notification.decode_inner::<DataChangeNotification>(&DecodingLimits::default()).unwrap();
+            let mut items = data_change.monitored_items.unwrap_or_default().into_iter()
+                .map(|item| (item.client_handle, item.value.value.unwrap()))
+                .collect::<Vec<(u32, Variant)>>();
+            values.append(&mut items);
+        }
+    }
+    values.sort_by_key(|(client_handle, _)| *client_handle);
+    values
+}
+
+#[test]
+fn set_monitoring_mode() {
+    let st = ServiceTest::new();
+    let (mut server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, 1);
+    let ss = SubscriptionService::new();
+    let mis = MonitoredItemService::new();
+
+    let subscription_id = create_subscription(&mut session, &mut server_state, &ss);
+    let monitored_item_ids = create_monitored_items(&mut session, &address_space, &mis, subscription_id, &[(node_ids[0].clone(), MonitoringMode::Disabled)]);
+    let monitored_item_id = monitored_item_ids[0];
+
+    let set_monitoring_mode = |session: &mut Session, subscription_id: u32, monitoring_mode: MonitoringMode, monitored_item_ids: Option<Vec<u32>>| {
+        let request = SetMonitoringModeRequest {
+            request_header: make_request_header(),
+            subscription_id,
+            monitoring_mode,
+            monitored_item_ids,
+        };
+        mis.set_monitoring_mode(session, &request).unwrap()
+    };
+
+    // A disabled item doesn't report
+    assert!(publish(&mut session, &address_space, &ss, 1).is_empty());
+
+    // Enable the item and an unknown item
+    let response = supported_message_as!(set_monitoring_mode(&mut session, subscription_id, MonitoringMode::Reporting, Some(vec![monitored_item_id, 999])), SetMonitoringModeResponse);
+    assert_eq!(response.results.unwrap(), vec![StatusCode::Good, StatusCode::BadMonitoredItemIdInvalid]);
+    assert_eq!(session.subscriptions.get_mut(subscription_id).unwrap().monitored_items[&monitored_item_id].monitoring_mode, MonitoringMode::Reporting);
+    assert_eq!(publish(&mut session, &address_space, &ss, 2), vec![(0, Variant::Int32(0))]);
+
+    // A sampling item queues its values without reporting them
+    let _ = set_monitoring_mode(&mut session, subscription_id, MonitoringMode::Sampling, Some(vec![monitored_item_id]));
+    address_space.set_variable_value(node_ids[0].clone(), 1, &DateTime::now(), &DateTime::now());
+    assert!(publish(&mut session, &address_space, &ss, 3).is_empty());
+    assert_eq!(session.subscriptions.get_mut(subscription_id).unwrap().monitored_items[&monitored_item_id].notification_queue.len(), 1);
+
+    // Disabling the item discards its queue. When it is enabled again it reports the current value
+    let _ = set_monitoring_mode(&mut session, subscription_id, MonitoringMode::Disabled, Some(vec![monitored_item_id]));
+    assert!(session.subscriptions.get_mut(subscription_id).unwrap().monitored_items[&monitored_item_id].notification_queue.is_empty());
+    let _ = set_monitoring_mode(&mut session, subscription_id, MonitoringMode::Reporting, Some(vec![monitored_item_id]));
+    assert_eq!(publish(&mut session, &address_space, &ss, 4), vec![(0, Variant::Int32(1))]);
+
+    // Invalid requests
+    let response = set_monitoring_mode(&mut session, subscription_id, MonitoringMode::Reporting, None);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadNothingToDo);
+    let response = set_monitoring_mode(&mut session, subscription_id + 1, MonitoringMode::Reporting, Some(vec![monitored_item_id]));
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadSubscriptionIdInvalid);
+}
+
+#[test]
+fn set_triggering() {
+    let st = ServiceTest::new();
+    let (mut server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, 3);
+    let ss = SubscriptionService::new();
+    let mis = MonitoredItemService::new();
+
+    // The first item triggers the second, which is sampling. The third is sampling and never triggered.
+    let subscription_id = create_subscription(&mut session, &mut server_state, &ss);
+    let monitored_item_ids = create_monitored_items(&mut session, &address_space, &mis, subscription_id, &[
+        (node_ids[0].clone(), MonitoringMode::Reporting),
+        (node_ids[1].clone(), MonitoringMode::Sampling),
+        (node_ids[2].clone(), MonitoringMode::Sampling),
+    ]);
+
+    let set_triggering = |session: &mut Session, triggering_item_id: u32, links_to_add: Option<Vec<u32>>, links_to_remove: Option<Vec<u32>>| {
+        let request = SetTriggeringRequest {
+            request_header: make_request_header(),
+            subscription_id,
+            triggering_item_id,
+            links_to_add,
+            links_to_remove,
+        };
+        mis.set_triggering(session, &request).unwrap()
+    };
+
+    let response = supported_message_as!(set_triggering(&mut session, monitored_item_ids[0], Some(vec![monitored_item_ids[1], 999]), None), SetTriggeringResponse);
+    assert_eq!(response.add_results.unwrap(), vec![StatusCode::Good, StatusCode::BadMonitoredItemIdInvalid]);
+    assert!(response.remove_results.is_none());
+
+    // The initial value of the triggering item triggers the linked item
+    assert_eq!(publish(&mut session, &address_space, &ss, 1), vec![(0, Variant::Int32(0)), (1, Variant::Int32(1))]);
+
+    // The linked item doesn't report its own changes
+    address_space.set_variable_value(node_ids[1].clone(), 10, &DateTime::now(), &DateTime::now());
+    assert!(publish(&mut session, &address_space, &ss, 2).is_empty());
+
+    // Until the triggering item changes
+    address_space.set_variable_value(node_ids[0].clone(), 20, &DateTime::now(), &DateTime::now());
+    assert_eq!(publish(&mut session, &address_space, &ss, 3), vec![(0, Variant::Int32(20)), (1, Variant::Int32(10))]);
+
+    // Remove the link
+    let response = supported_message_as!(set_triggering(&mut session, monitored_item_ids[0], None, Some(vec![monitored_item_ids[1], monitored_item_ids[2]])), SetTriggeringResponse);
+    assert!(response.add_results.is_none());
+    assert_eq!(response.remove_results.unwrap(), vec![StatusCode::Good, StatusCode::BadMonitoredItemIdInvalid]);
+    address_space.set_variable_value(node_ids[1].clone(), 11, &DateTime::now(), &DateTime::now());
+    address_space.set_variable_value(node_ids[0].clone(), 21, &DateTime::now(), &DateTime::now());
+    assert_eq!(publish(&mut session, &address_space, &ss, 4), vec![(0, Variant::Int32(21))]);
+
+    // Invalid requests
+    let response = set_triggering(&mut session, 999, Some(vec![monitored_item_ids[1]]), None);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadMonitoredItemIdInvalid);
+    let response = set_triggering(&mut session, monitored_item_ids[0], Some(vec![]), None);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadNothingToDo);
+}
@@ -0,0 +1,453 @@
+use opcua_types::status_code::StatusCode;
+use opcua_types::node_ids::{ObjectId, ObjectTypeId, ReferenceTypeId, VariableTypeId, DataTypeId};
+
+use super::*;
+
+use crate::config::ANONYMOUS_USER_TOKEN_ID;
+use crate::services::node_management::NodeManagementService;
+
+/// Allows the anonymous user to manage nodes on the endpoint and makes the session look like it
+/// was activated by that user on that endpoint.
+fn enable_node_management(server_state: &ServerState, session: &mut Session) {
+    let mut config = server_state.config.write().unwrap();
+    let base_endpoint_url = config.base_endpoint_url();
+    let endpoint = config.endpoints.get_mut("none").unwrap();
+    endpoint.node_management_user_token_ids.insert(ANONYMOUS_USER_TOKEN_ID.to_string());
+    session.endpoint_url = UAString::from(endpoint.endpoint_url(&base_endpoint_url));
+    session.user_token_id = Some(ANONYMOUS_USER_TOKEN_ID.to_string());
+}
+
+fn object_attributes(display_name: &str) -> ExtensionObject {
+    // Clients may send every bit that applies to an object, such as those of the attributes that
+    // the item sets elsewhere, which the server must ignore
+    let specified_attributes = AttributesMask::DISPLAY_NAME | AttributesMask::DESCRIPTION | AttributesMask::EVENT_NOTIFIER |
+        AttributesMask::BROWSE_NAME | AttributesMask::NODE_CLASS | AttributesMask::NODE_ID | AttributesMask::ROLE_PERMISSIONS;
+    ExtensionObject::from_encodable(ObjectId::ObjectAttributes_Encoding_DefaultBinary, &ObjectAttributes {
+        specified_attributes: specified_attributes.bits(),
+        display_name: LocalizedText::new("", display_name),
+        description: LocalizedText::new("", "description"),
+        write_mask: 0,
+        user_write_mask: 0,
+        event_notifier: 1,
+    })
+}
+
+fn variable_attributes(display_name: &str, value: Variant) -> ExtensionObject {
+    let specified_attributes = AttributesMask::DISPLAY_NAME | AttributesMask::VALUE | AttributesMask::ACCESS_LEVEL | AttributesMask::USER_ACCESS_LEVEL;
+    ExtensionObject::from_encodable(ObjectId::VariableAttributes_Encoding_DefaultBinary, &VariableAttributes {
+        specified_attributes: specified_attributes.bits(),
+        display_name: LocalizedText::new("", display_name),
+        description: LocalizedText::null(),
+        write_mask: 0,
+        user_write_mask: 0,
+        value,
+        data_type: NodeId::null(),
+        value_rank: -1,
+        array_dimensions: None,
+        access_level: 3,
+        user_access_level: 3,
+        minimum_sampling_interval: 0f64,
+        historizing: false,
+    })
+}
+
+fn method_attributes(display_name: &str) -> ExtensionObject {
+    let specified_attributes = AttributesMask::DISPLAY_NAME | AttributesMask::EXECUTABLE | AttributesMask::USER_EXECUTABLE;
+    ExtensionObject::from_encodable(ObjectId::MethodAttributes_Encoding_DefaultBinary, &MethodAttributes {
+        specified_attributes: specified_attributes.bits(),
+        display_name: LocalizedText::new("", display_name),
+        description: LocalizedText::null(),
+        write_mask: 0,
+        user_write_mask: 0,
+        executable: true,
+        user_executable: true,
+    })
+}
+
+fn add_nodes_item<T>(requested_new_node_id: T, parent_node_id: NodeId, reference_type_id: ReferenceTypeId, browse_name: &str, node_class: NodeClass, node_attributes: ExtensionObject, type_definition: ExpandedNodeId) -> AddNodesItem
+    where T: Into<NodeId> {
+    AddNodesItem {
+        parent_node_id: parent_node_id.into(),
+        reference_type_id: reference_type_id.into(),
+        requested_new_node_id: requested_new_node_id.into().into(),
+        browse_name: QualifiedName::new(1, browse_name),
+        node_class,
+        node_attributes,
+        type_definition,
+    }
+}
+
+fn add_nodes_request(s: &NodeManagementService, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, nodes_to_add: Vec<AddNodesItem>) -> Result<SupportedMessage, StatusCode> {
+    s.add_nodes(server_state, session, address_space, &AddNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_add: Some(nodes_to_add),
+    })
+}
+
+/// This is a convenience for tests
+fn add_node_single(s: &NodeManagementService, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, item: AddNodesItem) -> AddNodesResult {
+    let response = add_nodes_request(s, server_state, session, address_space, vec![item]).unwrap();
+    let response: AddNodesResponse = supported_message_as!(response, AddNodesResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn add_reference_single(s: &NodeManagementService, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, item: AddReferencesItem) -> StatusCode {
+    let response = s.add_references(server_state, session, address_space, &AddReferencesRequest {
+        request_header: make_request_header(),
+        references_to_add: Some(vec![item]),
+    }).unwrap();
+    let response: AddReferencesResponse = supported_message_as!(response, AddReferencesResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn delete_node_single(s: &NodeManagementService, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, item: DeleteNodesItem) -> StatusCode {
+    let response = s.delete_nodes(server_state, session, address_space, &DeleteNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_delete: Some(vec![item]),
+    }).unwrap();
+    let response: DeleteNodesResponse = supported_message_as!(response, DeleteNodesResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn delete_reference_single(s: &NodeManagementService, server_state: &ServerState, session: &Session, address_space: &mut AddressSpace, item: DeleteReferencesItem) -> StatusCode {
+    let response = s.delete_references(server_state, session, address_space, &DeleteReferencesRequest {
+        request_header: make_request_header(),
+        references_to_delete: Some(vec![item]),
+    }).unwrap();
+    let response: DeleteReferencesResponse = supported_message_as!(response, DeleteReferencesResponse);
+    response.results.unwrap().remove(0)
+}
+
+fn add_references_item(source_node_id: &NodeId, reference_type_id: ReferenceTypeId, is_forward: bool, target_node_id: &NodeId, target_node_class: NodeClass) -> AddReferencesItem {
+    AddReferencesItem {
+        source_node_id: source_node_id.clone(),
+        reference_type_id: reference_type_id.into(),
+        is_forward,
+        target_server_uri: UAString::null(),
+        target_node_id: target_node_id.into(),
+        target_node_class,
+    }
+}
+
+fn is_reference(address_space: &AddressSpace, node_id_from: &NodeId, node_id_to: &NodeId, reference_type_id: ReferenceTypeId) -> bool {
+    let forward = address_space.find_references_from(node_id_from, Some((reference_type_id.into(), false)))
+        .map_or(false, |r| r.iter().any(|r| r.node_id == *node_id_to));
+    let inverse = address_space.find_references_to(node_id_to, Some((reference_type_id.into(), false)))
+        .map_or(false, |r| r.iter().any(|r| r.node_id == *node_id_from));
+    // Forward and inverse references should always agree
+    assert_eq!(forward, inverse);
+    forward
+}
+
+#[test]
+fn node_management_access_denied() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+
+    // The default configuration does not permit anybody to add nodes
+    let item = add_nodes_item(NodeId::new(1, "foo"), AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, "foo", NodeClass::Object,
+                              object_attributes("foo"), ObjectTypeId::FolderType.into());
+    let response = add_nodes_request(&s, &server_state, &session, &mut address_space, vec![item]).unwrap();
+    let response: ServiceFault = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadUserAccessDenied);
+    assert!(!address_space.node_exists(&NodeId::new(1, "foo")));
+}
+
+#[test]
+fn add_nodes_nothing_to_do() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let response = s.add_nodes(&server_state, &session, &mut address_space, &AddNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_add: None,
+    }).unwrap();
+    let response: ServiceFault = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadNothingToDo);
+
+    let response = add_nodes_request(&s, &server_state, &session, &mut address_space, vec![]).unwrap();
+    let response: ServiceFault = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadNothingToDo);
+}
+
+#[test]
+fn add_nodes() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let objects_folder_id = AddressSpace::objects_folder_id();
+
+    // Add a folder
+    let folder_id = NodeId::new(1, "folder");
+    let item = add_nodes_item(folder_id.clone(), objects_folder_id.clone(), ReferenceTypeId::Organizes, "folder", NodeClass::Object,
+                              object_attributes("Folder"), ObjectTypeId::FolderType.into());
+    let result = add_node_single(&s, &server_state, &session, &mut address_space, item);
+    assert_eq!(result.status_code, StatusCode::Good);
+    assert_eq!(result.added_node_id, folder_id);
+    if let Some(NodeType::Object(ref object)) = address_space.find_node(&folder_id) {
+        assert_eq!(object.browse_name(), QualifiedName::new(1, "folder"));
+        assert_eq!(object.display_name().text, UAString::from("Folder"));
+        assert_eq!(object.description().unwrap().text, UAString::from("description"));
+    } else {
+        panic!("Folder was not added");
+    }
+    assert!(is_reference(&address_space, &objects_folder_id, &folder_id, ReferenceTypeId::Organizes));
+    assert!(is_reference(&address_space, &folder_id, &ObjectTypeId::FolderType.into(), ReferenceTypeId::HasTypeDefinition));
+
+    // Add a variable to the folder, letting the server assign the node id
+    let item = add_nodes_item(NodeId::null(), folder_id.clone(), ReferenceTypeId::HasComponent, "var", NodeClass::Variable,
+                              variable_attributes("Var", Variant::from(123i32)), VariableTypeId::BaseDataVariableType.into());
+    let result = add_node_single(&s, &server_state, &session, &mut address_space, item);
+    assert_eq!(result.status_code, StatusCode::Good);
+    let var_id = result.added_node_id;
+    assert!(!var_id.is_null());
+    {
+        let var = address_space.find_variable_by_ref(&var_id).unwrap();
+        assert_eq!(var.value().value.unwrap(), Variant::from(123i32));
+        assert!(var.is_writable());
+        assert_eq!(var.display_name().text, UAString::from("Var"));
+        let data_type_id: NodeId = DataTypeId::Int32.into();
+        assert_eq!(var.find_attribute(AttributeId::DataType).unwrap().value.unwrap(), Variant::from(data_type_id));
+    }
+    assert!(is_reference(&address_space, &folder_id, &var_id, ReferenceTypeId::HasComponent));
+
+    // Add a method to the folder, methods have no type definition
+    let item = add_nodes_item(NodeId::new(1, "method"), folder_id.clone(), ReferenceTypeId::HasComponent, "method", NodeClass::Method,
+                              method_attributes("Method"), ExpandedNodeId::null());
+    let result = add_node_single(&s, &server_state, &session, &mut address_space, item);
+    assert_eq!(result.status_code, StatusCode::Good);
+    if let Some(NodeType::Method(ref method)) = address_space.find_node(&NodeId::new(1, "method")) {
+        assert!(method.executable());
+    } else {
+        panic!("Method was not added");
+    }
+}
+
+#[test]
+fn add_nodes_invalid() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let objects_folder_id = AddressSpace::objects_folder_id();
+    let folder_type_id: ExpandedNodeId = ObjectTypeId::FolderType.into();
+
+    let mut add_node = |item: AddNodesItem| {
+        add_node_single(&s, &server_state, &session, &mut address_space, item).status_code
+    };
+
+    // Requested node id already exists
+    let item = add_nodes_item(objects_folder_id.clone(), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              object_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadNodeIdExists);
+
+    // Parent does not exist
+    let item = add_nodes_item(NodeId::new(1, "x"), NodeId::new(1, "nonexistent"), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              object_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadParentNodeIdInvalid);
+
+    // Reference type is not a reference type
+    let mut item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                                  object_attributes("x"), folder_type_id.clone());
+    item.reference_type_id = ObjectTypeId::FolderType.into();
+    assert_eq!(add_node(item), StatusCode::BadReferenceTypeIdInvalid);
+
+    // Reference type is not hierarchical
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::HasTypeDefinition, "x", NodeClass::Object,
+                              object_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadReferenceNotAllowed);
+
+    // Reference type is abstract
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::HierarchicalReferences, "x", NodeClass::Object,
+                              object_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadReferenceNotAllowed);
+
+    // Browse name is missing
+    let mut item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                                  object_attributes("x"), folder_type_id.clone());
+    item.browse_name = QualifiedName::null();
+    assert_eq!(add_node(item), StatusCode::BadBrowseNameInvalid);
+
+    // Browse name duplicates another child of the parent
+    let mut item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                                  object_attributes("x"), folder_type_id.clone());
+    item.browse_name = QualifiedName::new(0, "Server");
+    assert_eq!(add_node(item), StatusCode::BadBrowseNameDuplicated);
+
+    // Node class is unspecified
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Unspecified,
+                              object_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadNodeClassInvalid);
+
+    // Object has no type definition
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              object_attributes("x"), ExpandedNodeId::null());
+    assert_eq!(add_node(item), StatusCode::BadTypeDefinitionInvalid);
+
+    // Object has a variable type as its type definition
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              object_attributes("x"), VariableTypeId::BaseDataVariableType.into());
+    assert_eq!(add_node(item), StatusCode::BadTypeDefinitionInvalid);
+
+    // Object has an abstract type definition
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              object_attributes("x"), ObjectTypeId::BaseEventType.into());
+    assert_eq!(add_node(item), StatusCode::BadTypeDefinitionInvalid);
+
+    // Method has a type definition
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::HasComponent, "x", NodeClass::Method,
+                              method_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadTypeDefinitionInvalid);
+
+    // Attributes do not match the node class
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              method_attributes("x"), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadNodeAttributesInvalid);
+
+    // Attributes are missing
+    let item = add_nodes_item(NodeId::new(1, "x"), objects_folder_id.clone(), ReferenceTypeId::Organizes, "x", NodeClass::Object,
+                              ExtensionObject::null(), folder_type_id.clone());
+    assert_eq!(add_node(item), StatusCode::BadNodeAttributesInvalid);
+
+    // None of the above should have been added
+    assert!(!address_space.node_exists(&NodeId::new(1, "x")));
+}
+
+#[test]
+fn add_references() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let (folder_id, node_ids) = add_many_vars_to_address_space(&mut address_space, 2);
+    let (v1, v2) = (&node_ids[0], &node_ids[1]);
+
+    // Add a forward reference
+    let item = add_references_item(v1, ReferenceTypeId::HasComponent, true, v2, NodeClass::Variable);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(is_reference(&address_space, v1, v2, ReferenceTypeId::HasComponent));
+
+    // Same again is a duplicate
+    let item = add_references_item(v1, ReferenceTypeId::HasComponent, true, v2, NodeClass::Unspecified);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadDuplicateReferenceNotAllowed);
+
+    // Add an inverse reference
+    let item = add_references_item(v1, ReferenceTypeId::Organizes, false, &folder_id, NodeClass::Unspecified);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadDuplicateReferenceNotAllowed);
+    let item = add_references_item(v1, ReferenceTypeId::HasComponent, false, &folder_id, NodeClass::Object);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(is_reference(&address_space, &folder_id, v1, ReferenceTypeId::HasComponent));
+
+    // Invalid source, target, reference type, node class, self reference
+    let item = add_references_item(&NodeId::new(1, "nonexistent"), ReferenceTypeId::HasComponent, true, v2, NodeClass::Unspecified);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadSourceNodeIdInvalid);
+    let item = add_references_item(v1, ReferenceTypeId::HasComponent, true, &NodeId::new(1, "nonexistent"), NodeClass::Unspecified);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadTargetNodeIdInvalid);
+    let mut item = add_references_item(v1, ReferenceTypeId::HasComponent, true, v2, NodeClass::Unspecified);
+    item.reference_type_id = NodeId::new(1, "nonexistent");
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadReferenceTypeIdInvalid);
+    let item = add_references_item(v1, ReferenceTypeId::HasProperty, true, v2, NodeClass::Object);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadNodeClassInvalid);
+    let item = add_references_item(v1, ReferenceTypeId::HasProperty, true, v1, NodeClass::Unspecified);
+    assert_eq!(add_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadInvalidSelfReference);
+}
+
+#[test]
+fn delete_nodes() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let (folder_id, node_ids) = add_many_vars_to_address_space(&mut address_space, 2);
+    let (v1, v2) = (&node_ids[0], &node_ids[1]);
+    address_space.insert_reference(v1, v2, ReferenceTypeId::HasProperty);
+
+    // Delete v1 but leave references that target it
+    let item = DeleteNodesItem { node_id: v1.clone(), delete_target_references: false };
+    assert_eq!(delete_node_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(!address_space.node_exists(v1));
+    assert!(address_space.find_references_from(v1, None).is_none());
+    assert!(address_space.find_references_to(v1, None).is_none());
+    assert!(address_space.find_references_to(v2, Some((ReferenceTypeId::HasProperty.into(), false))).is_none());
+    assert!(address_space.find_references_from(&folder_id, None).unwrap().iter().any(|r| r.node_id == *v1));
+
+    // Delete v2 and references that target it
+    let item = DeleteNodesItem { node_id: v2.clone(), delete_target_references: true };
+    assert_eq!(delete_node_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(!address_space.node_exists(v2));
+    assert!(!address_space.find_references_from(&folder_id, None).unwrap().iter().any(|r| r.node_id == *v2));
+
+    // Delete something that does not exist
+    let item = DeleteNodesItem { node_id: v2.clone(), delete_target_references: true };
+    assert_eq!(delete_node_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadNodeIdUnknown);
+}
+
+#[test]
+fn delete_references() {
+    let st = ServiceTest::new();
+    let s = NodeManagementService::new();
+
+    let (server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    enable_node_management(&server_state, &mut session);
+
+    let (folder_id, node_ids) = add_many_vars_to_address_space(&mut address_space, 2);
+    let (v1, v2) = (&node_ids[0], &node_ids[1]);
+    address_space.insert_reference(v1, v2, ReferenceTypeId::HasProperty);
+    address_space.insert_reference(v2, v1, ReferenceTypeId::HasProperty);
+
+    let delete_references_item = |source_node_id: &NodeId, is_forward: bool, target_node_id: &NodeId, delete_bidirectional: bool| {
+        DeleteReferencesItem {
+            source_node_id: source_node_id.clone(),
+            reference_type_id: ReferenceTypeId::HasProperty.into(),
+            is_forward,
+            target_node_id: target_node_id.into(),
+            delete_bidirectional,
+        }
+    };
+
+    // Delete the inverse reference of the folder organizing v1
+    let mut item = delete_references_item(v1, false, &folder_id, false);
+    item.reference_type_id = ReferenceTypeId::Organizes.into();
+    assert_eq!(delete_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(!is_reference(&address_space, &folder_id, v1, ReferenceTypeId::Organizes));
+
+    // Delete v1 -> v2 leaving v2 -> v1
+    let item = delete_references_item(v1, true, v2, false);
+    assert_eq!(delete_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(!is_reference(&address_space, v1, v2, ReferenceTypeId::HasProperty));
+    assert!(is_reference(&address_space, v2, v1, ReferenceTypeId::HasProperty));
+
+    // Reference no longer exists
+    let item = delete_references_item(v1, true, v2, false);
+    assert_eq!(delete_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadNotFound);
+
+    // Bidirectional delete removes v2 -> v1
+    let item = delete_references_item(v1, true, v2, true);
+    assert_eq!(delete_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::Good);
+    assert!(!is_reference(&address_space, v2, v1, ReferenceTypeId::HasProperty));
+
+    // Invalid source
+    let item = delete_references_item(&NodeId::new(1, "nonexistent"), true, v2, false);
+    assert_eq!(delete_reference_single(&s, &server_state, &session, &mut address_space, item), StatusCode::BadSourceNodeIdInvalid);
+}
@@ -0,0 +1,225 @@
+use crate::prelude::*;
+use crate::services::query::QueryService;
+use super::*;
+
+// Query service tests
+
+/// Adds a device type with a pump subtype, a device and pumps with a speed to the address space
+fn add_devices(address_space: &mut AddressSpace) -> (NodeId, NodeId) {
+    let device_type_id = NodeId::new(1, "DeviceType");
+    let pump_type_id = NodeId::new(1, "PumpType");
+    address_space.insert(ObjectType::new(&device_type_id, "DeviceType", "DeviceType", "", false), Some(&[
+        (&ObjectTypeId::BaseObjectType.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    address_space.insert(ObjectType::new(&pump_type_id, "PumpType", "PumpType", "", false), Some(&[
+        (&device_type_id, ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    for (name, type_id, speed) in &[("Device1", &device_type_id, 0i32), ("Pump1", &pump_type_id, 5), ("Pump2", &pump_type_id, 50)] {
+        let node_id = NodeId::new(1, *name);
+        address_space.insert(Object::new(&node_id, name, name, ""), Some(&[
+            (&AddressSpace::objects_folder_id(), ReferenceTypeId::Organizes, ReferenceDirection::Inverse),
+            (type_id, ReferenceTypeId::HasTypeDefinition, ReferenceDirection::Forward),
+        ]));
+        let speed_id = NodeId::new(1, format!("{}.Speed", name));
+        let _ = address_space.add_variable(Variable::new(&speed_id, "Speed", "Speed", "", *speed), &node_id);
+    }
+    (device_type_id, pump_type_id)
+}
+
+fn node_type(type_id: &NodeId, include_sub_types: bool, data_to_return: Vec<QueryDataDescription>) -> NodeTypeDescription {
+    NodeTypeDescription {
+        type_definition_node: ExpandedNodeId::new(type_id.clone()),
+        include_sub_types,
+        data_to_return: Some(data_to_return),
+    }
+}
+
+fn data_description(browse_path: &[&str], attribute_id: AttributeId) -> QueryDataDescription {
+    let elements = browse_path.iter().map(|name| RelativePathElement {
+        reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
+        is_inverse: false,
+        include_subtypes: true,
+        target_name: QualifiedName::new(0, name),
+    }).collect();
+    QueryDataDescription {
+        relative_path: RelativePath {
+            elements: Some(elements),
+        },
+        attribute_id: attribute_id as u32,
+        index_range: UAString::null(),
+    }
+}
+
+/// A filter passing the devices whose speed is greater than the value
+fn speed_greater_than(device_type_id: &NodeId, value: i32) -> ContentFilter {
+    let speed = ExtensionObject::from_encodable(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary, &SimpleAttributeOperand {
+        type_definition_id: device_type_id.clone(),
+        browse_path: Some(vec![QualifiedName::new(0, "Speed")]),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+    });
+    let value = ExtensionObject::from_encodable(ObjectId::LiteralOperand_Encoding_DefaultBinary, &LiteralOperand { value: value.into() });
+    ContentFilter {
+        elements: Some(vec![ContentFilterElement {
+            filter_operator: FilterOperator::GreaterThan,
+            filter_operands: Some(vec![speed, value]),
+        }]),
+    }
+}
+
+fn make_query_first_request(node_types: Vec<NodeTypeDescription>, filter: ContentFilter, max_data_sets_to_return: u32) -> QueryFirstRequest {
+    QueryFirstRequest {
+        request_header: make_request_header(),
+        view: ViewDescription {
+            view_id: NodeId::null(),
+            timestamp: DateTime::now(),
+            view_version: 0,
+        },
+        node_types: Some(node_types),
+        filter,
+        max_data_sets_to_return,
+        max_references_to_return: 0,
+    }
+}
+
+fn do_query_first(qs: &QueryService, session: &mut Session, address_space: &AddressSpace, request: &QueryFirstRequest) -> QueryFirstResponse {
+    let result = qs.query_first(session, address_space, request);
+    assert!(result.is_ok());
+    supported_message_as!(result.unwrap(), QueryFirstResponse)
+}
+
+fn do_query_next(qs: &QueryService, session: &mut Session, continuation_point: &ByteString, release_continuation_point: bool) -> SupportedMessage {
+    let request = QueryNextRequest {
+        request_header: make_request_header(),
+        release_continuation_point,
+        continuation_point: continuation_point.clone(),
+    };
+    qs.query_next(session, &request).unwrap()
+}
+
+fn data_set_node_ids(query_data_sets: &[QueryDataSet]) -> Vec<NodeId> {
+    query_data_sets.iter().map(|data_set| data_set.node_id.node_id.clone()).collect()
+}
+
+#[test]
+fn query_first() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (device_type_id, pump_type_id) = add_devices(&mut address_space);
+    let qs = QueryService::new();
+    let no_filter = ContentFilter { elements: None };
+
+    // Only instances of the type itself
+    let data_to_return = vec![data_description(&[], AttributeId::BrowseName), data_description(&["Speed"], AttributeId::Value)];
+    let request = make_query_first_request(vec![node_type(&device_type_id, false, data_to_return.clone())], no_filter.clone(), 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    assert_eq!(response.response_header.service_result, StatusCode::Good);
+    assert!(response.parsing_results.is_none());
+    assert!(response.continuation_point.is_null());
+    let query_data_sets = response.query_data_sets.unwrap();
+    assert_eq!(data_set_node_ids(&query_data_sets), vec![NodeId::new(1, "Device1")]);
+    assert_eq!(query_data_sets[0].type_definition_node.node_id, device_type_id);
+    assert_eq!(query_data_sets[0].values, Some(vec![Variant::from(QualifiedName::new(0, "Device1")), Variant::from(0i32)]));
+
+    // Instances of the subtypes, with the type that each is an instance of
+    let request = make_query_first_request(vec![node_type(&device_type_id, true, data_to_return.clone())], no_filter.clone(), 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    let query_data_sets = response.query_data_sets.unwrap();
+    assert_eq!(data_set_node_ids(&query_data_sets), vec![NodeId::new(1, "Device1"), NodeId::new(1, "Pump1"), NodeId::new(1, "Pump2")]);
+    assert_eq!(query_data_sets[2].type_definition_node.node_id, pump_type_id);
+    assert_eq!(query_data_sets[2].values, Some(vec![Variant::from(QualifiedName::new(0, "Pump2")), Variant::from(50i32)]));
+
+    // A path to a node that does not exist returns a null value
+    let request = make_query_first_request(vec![node_type(&pump_type_id, false, vec![data_description(&["Pressure"], AttributeId::Value)])], no_filter.clone(), 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    let query_data_sets = response.query_data_sets.unwrap();
+    assert_eq!(query_data_sets[0].values, Some(vec![Variant::Empty]));
+
+    // Filtered by the value of a related node
+    let request = make_query_first_request(vec![node_type(&device_type_id, true, data_to_return)], speed_greater_than(&device_type_id, 1), 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    assert_eq!(response.filter_result.element_results.unwrap()[0].status_code, StatusCode::Good);
+    assert_eq!(data_set_node_ids(&response.query_data_sets.unwrap()), vec![NodeId::new(1, "Pump1"), NodeId::new(1, "Pump2")]);
+}
+
+#[test]
+fn query_first_invalid() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (device_type_id, _) = add_devices(&mut address_space);
+    let qs = QueryService::new();
+    let no_filter = ContentFilter { elements: None };
+
+    // Nothing to do
+    let mut request = make_query_first_request(vec![], no_filter.clone(), 0);
+    let response = qs.query_first(&mut session, &address_space, &request).unwrap();
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadNothingToDo);
+
+    // Views are not supported
+    request.node_types = Some(vec![node_type(&device_type_id, false, vec![])]);
+    request.view.view_id = ObjectId::ObjectsFolder.into();
+    let response = qs.query_first(&mut session, &address_space, &request).unwrap();
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadViewIdUnknown);
+
+    // Node types that are not types, and data that cannot be returned
+    let request = make_query_first_request(vec![
+        node_type(&device_type_id, false, vec![data_description(&[], AttributeId::Value)]),
+        node_type(&NodeId::new(1, "Unknown"), false, vec![]),
+        node_type(&NodeId::new(1, "Device1"), false, vec![]),
+        node_type(&device_type_id, false, vec![QueryDataDescription {
+            relative_path: RelativePath { elements: None },
+            attribute_id: 999,
+            index_range: UAString::null(),
+        }]),
+    ], no_filter, 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    assert_eq!(response.response_header.service_result, StatusCode::BadInvalidArgument);
+    assert!(response.query_data_sets.is_none());
+    let parsing_results = response.parsing_results.unwrap();
+    assert_eq!(parsing_results.iter().map(|result| result.status_code).collect::<Vec<StatusCode>>(),
+               vec![StatusCode::Good, StatusCode::BadNodeIdUnknown, StatusCode::BadNotTypeDefinition, StatusCode::BadInvalidArgument]);
+    assert_eq!(parsing_results[3].data_status_codes, Some(vec![StatusCode::BadAttributeIdInvalid]));
+
+    // An invalid filter
+    let mut filter = speed_greater_than(&device_type_id, 1);
+    filter.elements.as_mut().unwrap()[0].filter_operands.as_mut().unwrap().pop();
+    let request = make_query_first_request(vec![node_type(&device_type_id, false, vec![])], filter, 0);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    assert_eq!(response.response_header.service_result, StatusCode::BadContentFilterInvalid);
+    assert_eq!(response.filter_result.element_results.unwrap()[0].status_code, StatusCode::BadFilterOperandCountMismatch);
+}
+
+#[test]
+fn query_next() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (device_type_id, _) = add_devices(&mut address_space);
+    let qs = QueryService::new();
+
+    // The data sets are returned two at a time
+    let request = make_query_first_request(vec![node_type(&device_type_id, true, vec![])], ContentFilter { elements: None }, 2);
+    let response = do_query_first(&qs, &mut session, &address_space, &request);
+    assert_eq!(data_set_node_ids(&response.query_data_sets.unwrap()), vec![NodeId::new(1, "Device1"), NodeId::new(1, "Pump1")]);
+    let continuation_point = response.continuation_point;
+    assert!(!continuation_point.is_null());
+
+    let response = supported_message_as!(do_query_next(&qs, &mut session, &continuation_point, false), QueryNextResponse);
+    assert_eq!(data_set_node_ids(&response.query_data_sets.unwrap()), vec![NodeId::new(1, "Pump2")]);
+    assert!(response.revised_continuation_point.is_null());
+
+    // The continuation point is used up
+    let response = do_query_next(&qs, &mut session, &continuation_point, false);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadContinuationPointInvalid);
+
+    // Releasing the continuation point returns nothing
+    let response = do_query_first(&qs, &mut session, &address_space, &make_query_first_request(vec![node_type(&device_type_id, true, vec![])], ContentFilter { elements: None }, 1));
+    let continuation_point = response.continuation_point;
+    let response = supported_message_as!(do_query_next(&qs, &mut session, &continuation_point, true), QueryNextResponse);
+    assert!(response.query_data_sets.is_none());
+    assert!(response.revised_continuation_point.is_null());
+    let response = do_query_next(&qs, &mut session, &continuation_point, false);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadContinuationPointInvalid);
+}
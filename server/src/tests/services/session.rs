@@ -1,6 +1,15 @@
+use std::sync::Arc;
+
+use chrono::{self, Utc};
+
 use crate::tests::*;
 
 use crate::builder::ServerBuilder;
+use crate::comms::transport::Transport;
+use crate::config::ANONYMOUS_USER_TOKEN_ID;
+use crate::services::message_handler::MessageHandler;
+use crate::subscriptions::subscription::TickReason;
+use super::*;
 
 #[test]
 fn anonymous_user_token() {
@@ -17,6 +26,8 @@ fn anonymous_user_token() {
     let result = server_state.authenticate_endpoint("opc.tcp://localhost:4855/", SecurityPolicy::None, MessageSecurityMode::None, &token);
     trace!("result = {:?}", result);
     assert!(result.is_good());
+    let result = server_state.authenticated_user_token_id("opc.tcp://localhost:4855/", SecurityPolicy::None, MessageSecurityMode::None, &token);
+    assert_eq!(result.unwrap(), ANONYMOUS_USER_TOKEN_ID);
 
     let result = server_state.authenticate_endpoint("opc.tcp://localhost:4855/x", SecurityPolicy::None, MessageSecurityMode::None, &token);
     trace!("result = {:?}", result);
@@ -47,6 +58,8 @@ fn user_name_pass_token() {
     let token = make_user_name_identity_token("sample", b"sample1");
     let result = server_state.authenticate_endpoint("opc.tcp://localhost:4855/", SecurityPolicy::None, MessageSecurityMode::None, &token);
     assert!(result.is_good());
+    let result = server_state.authenticated_user_token_id("opc.tcp://localhost:4855/", SecurityPolicy::None, MessageSecurityMode::None, &token);
+    assert_eq!(result.unwrap(), "sample_user");
 
     // Invalid tests
     let token = make_user_name_identity_token("samplex", b"sample1");
@@ -61,3 +74,194 @@ fn user_name_pass_token() {
     let result = server_state.authenticate_endpoint("opc.tcp://localhost:4855/", SecurityPolicy::None, MessageSecurityMode::None, &token);
     assert_eq!(result, StatusCode::BadIdentityTokenRejected);
 }
+
+fn make_activate_session_request(user_identity_token: ExtensionObject) -> ActivateSessionRequest {
+    ActivateSessionRequest {
+        request_header: make_request_header(),
+        client_signature: SignatureData { algorithm: UAString::null(), signature: ByteString::null() },
+        client_software_certificates: None,
+        locale_ids: None,
+        user_identity_token,
+        user_token_signature: SignatureData { algorithm: UAString::null(), signature: ByteString::null() },
+    }
+}
+
+fn make_client_cert(application_uri: &str) -> (X509, PrivateKey) {
+    let args = X509Data {
+        key_size: 1024,
+        common_name: "x".to_string(),
+        organization: "x.org".to_string(),
+        organizational_unit: "x.org ops".to_string(),
+        country: "EN".to_string(),
+        state: "London".to_string(),
+        alt_host_names: vec![application_uri.to_string(), "testhost".to_string()],
+        certificate_duration_days: 60,
+    };
+    CertificateStore::create_cert_and_pkey(&args).unwrap()
+}
+
+fn make_anonymous_identity_token() -> ExtensionObject {
+    let token = AnonymousIdentityToken {
+        policy_id: UAString::from(SecurityPolicy::None.to_uri())
+    };
+    ExtensionObject::from_encodable(ObjectId::AnonymousIdentityToken_Encoding_DefaultBinary, &token)
+}
+
+#[test]
+fn reactivate_detached_session() {
+    let st = ServiceTest::new();
+    let node_id = {
+        let mut address_space = st.address_space.write().unwrap();
+        let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, 1);
+        node_ids[0].clone()
+    };
+
+    // The first connection creates and activates a session with a monitored item
+    let mut transport = st.server.new_transport();
+    let session = transport.session();
+    {
+        let mut session = session.write().unwrap();
+        session.authentication_token = make_request_header().authentication_token;
+        session.endpoint_url = UAString::from("opc.tcp://127.0.0.1:4855/");
+        session.session_timeout = 50000f64;
+    }
+    let mut message_handler = MessageHandler::new(st.server.certificate_store(), st.server_state.clone(), session.clone(), st.address_space.clone());
+    let response = message_handler.handle_message(1, make_activate_session_request(make_anonymous_identity_token()).into()).unwrap().unwrap();
+    let _ = supported_message_as!(response, ActivateSessionResponse);
+
+    let request = CreateSubscriptionRequest {
+        request_header: make_request_header(),
+        requested_publishing_interval: 100f64,
+        requested_lifetime_count: 0,
+        requested_max_keep_alive_count: 0,
+        max_notifications_per_publish: 0,
+        publishing_enabled: true,
+        priority: 0,
+    };
+    let response = message_handler.handle_message(2, request.into()).unwrap().unwrap();
+    let subscription_id = supported_message_as!(response, CreateSubscriptionResponse).subscription_id;
+
+    let request = CreateMonitoredItemsRequest {
+        request_header: make_request_header(),
+        subscription_id,
+        timestamps_to_return: TimestampsToReturn::Both,
+        items_to_create: Some(vec![MonitoredItemCreateRequest {
+            item_to_monitor: node_id.clone().into(),
+            monitoring_mode: MonitoringMode::Reporting,
+            requested_parameters: MonitoringParameters {
+                client_handle: 1,
+                sampling_interval: 0.1,
+                filter: ExtensionObject::null(),
+                queue_size: 1,
+                discard_oldest: true,
+            },
+        }]),
+    };
+    let response = message_handler.handle_message(3, request.into()).unwrap().unwrap();
+    let _ = supported_message_as!(response, CreateMonitoredItemsResponse);
+
+    // A publish request from the first connection is outstanding when the connection drops
+    let request = PublishRequest {
+        request_header: make_request_header(),
+        subscription_acknowledgements: None,
+    };
+    assert!(message_handler.handle_message(4, request.clone().into()).unwrap().is_none());
+    transport.finish(StatusCode::BadCommunicationError);
+    {
+        let session = session.read().unwrap();
+        assert!(session.terminated());
+        assert!(session.subscriptions.publish_request_queue.is_empty());
+        let server_state = st.server_state.read().unwrap();
+        assert_eq!(server_state.detached_sessions.len(), 1);
+    }
+
+    // The subscription of the detached session goes on, so the change is queued
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let _ = address_space.set_variable_value(node_id.clone(), 99, &DateTime::now(), &DateTime::now());
+    }
+    {
+        let mut server_state = st.server_state.write().unwrap();
+        server_state.tick_detached_sessions(&(Utc::now() + chrono::Duration::seconds(1)), &st.address_space);
+        assert_eq!(server_state.detached_sessions.len(), 1);
+    }
+
+    // A new connection cannot reactivate the session with an identity that is rejected
+    let transport = st.server.new_transport();
+    let mut message_handler = MessageHandler::new(st.server.certificate_store(), st.server_state.clone(), transport.session(), st.address_space.clone());
+    let response = message_handler.handle_message(1, make_activate_session_request(make_user_name_identity_token("sample", b"sample1")).into()).unwrap().unwrap();
+    let response = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadIdentityTokenRejected);
+    assert!(!Arc::ptr_eq(&message_handler.session(), &session));
+    assert_eq!(st.server_state.read().unwrap().detached_sessions.len(), 1);
+
+    // Nor can it reactivate the session on a secure channel of another client application
+    let (client_cert, _) = make_client_cert("urn:testclient");
+    let (other_client_cert, _) = make_client_cert("urn:testclient");
+    {
+        let mut session = session.write().unwrap();
+        session.client_application_uri = UAString::from("urn:testclient");
+        session.secure_channel.write().unwrap().set_remote_cert(Some(client_cert.clone()));
+    }
+    let secure_channel = transport.session().read().unwrap().secure_channel.clone();
+    for remote_cert in &[None, Some(other_client_cert)] {
+        secure_channel.write().unwrap().set_remote_cert(remote_cert.clone());
+        let response = message_handler.handle_message(2, make_activate_session_request(make_anonymous_identity_token()).into()).unwrap().unwrap();
+        let response = supported_message_as!(response, ServiceFault);
+        assert_eq!(response.response_header.service_result, StatusCode::BadSecurityChecksFailed);
+    }
+    secure_channel.write().unwrap().set_remote_cert(Some(client_cert));
+    session.write().unwrap().client_application_uri = UAString::from("urn:otherclient");
+    let response = message_handler.handle_message(2, make_activate_session_request(make_anonymous_identity_token()).into()).unwrap().unwrap();
+    let response = supported_message_as!(response, ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadSecurityChecksFailed);
+    assert!(!Arc::ptr_eq(&message_handler.session(), &session));
+    assert_eq!(st.server_state.read().unwrap().detached_sessions.len(), 1);
+
+    // With the same identity and client application, the new connection reactivates the session
+    // on its secure channel
+    session.write().unwrap().client_application_uri = UAString::from("urn:testclient");
+    let response = message_handler.handle_message(2, make_activate_session_request(make_anonymous_identity_token()).into()).unwrap().unwrap();
+    let _ = supported_message_as!(response, ActivateSessionResponse);
+    assert!(Arc::ptr_eq(&message_handler.session(), &session));
+    assert!(st.server_state.read().unwrap().detached_sessions.is_empty());
+    {
+        let session = session.read().unwrap();
+        assert!(!session.terminated());
+        assert!(Arc::ptr_eq(&session.secure_channel, &secure_channel));
+    }
+
+    // The queued notification is delivered to the next publish request
+    assert!(message_handler.handle_message(3, request.into()).unwrap().is_none());
+    {
+        let mut session = session.write().unwrap();
+        {
+            let address_space = st.address_space.read().unwrap();
+            let _ = session.tick_subscriptions(&(Utc::now() + chrono::Duration::seconds(2)), &address_space, TickReason::TickTimerFired);
+        }
+        let response = session.subscriptions.publish_response_queue.pop_front().unwrap();
+        assert_eq!(response.request_id, 3);
+        let response = supported_message_as!(response.response, PublishResponse);
+        let notification_data = response.notification_message.notification_data.unwrap();
+        let data_change = notification_data[0].decode_inner::<DataChangeNotification>(&DecodingLimits::default()).unwrap();
+        let value = data_change.monitored_items.unwrap()[0].value.value.clone();
+        assert_eq!(value, Some(Variant::Int32(99)));
+    }
+
+    // The detached session is closed once its session timeout expires, deleting its subscription
+    session.write().unwrap().detach();
+    let mut server_state = st.server_state.write().unwrap();
+    server_state.detach_session(session.clone());
+    let (session_timeout_count, current_subscription_count) = {
+        let diagnostics = server_state.diagnostics.read().unwrap();
+        let summary = diagnostics.server_diagnostics_summary();
+        (summary.session_timeout_count, summary.current_subscription_count)
+    };
+    server_state.tick_detached_sessions(&(Utc::now() + chrono::Duration::seconds(60)), &st.address_space);
+    assert!(server_state.detached_sessions.is_empty());
+    assert!(session.read().unwrap().subscriptions.is_empty());
+    let diagnostics = server_state.diagnostics.read().unwrap();
+    let summary = diagnostics.server_diagnostics_summary();
+    assert_eq!(summary.session_timeout_count, session_timeout_count + 1);
+    assert_eq!(summary.current_subscription_count, current_subscription_count - 1);
+}
@@ -73,11 +73,11 @@ fn create_subscription(server_state: &mut ServerState, session: &mut Session, ss
     response.subscription_id
 }
 
-fn create_monitored_item<T>(subscription_id: u32, node_to_monitor: T, session: &mut Session, mis: &MonitoredItemService) where T: Into<NodeId> {
+fn create_monitored_item<T>(subscription_id: u32, node_to_monitor: T, session: &mut Session, address_space: &AddressSpace, mis: &MonitoredItemService) where T: Into<NodeId> {
     // Create a monitored item
     let request = create_monitored_items_request(subscription_id, vec![node_to_monitor]);
     debug!("CreateMonitoredItemsRequest {:#?}", request);
-    let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(session, &request).unwrap(), CreateMonitoredItemsResponse);
+    let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(session, address_space, &request).unwrap(), CreateMonitoredItemsResponse);
     debug!("CreateMonitoredItemsResponse {:#?}", response);
     // let result = response.results.unwrap()[0].monitored_item_id;
 }
@@ -145,7 +145,7 @@ fn publish_response_subscription() {
         let subscription_id = create_subscription(server_state, session, &ss);
 
         // Create a monitored item
-        create_monitored_item(subscription_id, VariableId::Server_ServerStatus_CurrentTime, session, &mis);
+        create_monitored_item(subscription_id, VariableId::Server_ServerStatus_CurrentTime, session, address_space, &mis);
 
         // Put the subscription into normal state
         session.subscriptions.get_mut(subscription_id).unwrap().state = SubscriptionState::Normal;
@@ -220,7 +220,7 @@ fn resend_data() {
         let subscription_id = create_subscription(server_state, session, &ss);
 
         // Create a monitored item (one that doesn't change)
-        create_monitored_item(subscription_id, VariableId::Server_ServerCapabilities_MaxArrayLength, session, &mis);
+        create_monitored_item(subscription_id, VariableId::Server_ServerCapabilities_MaxArrayLength, session, address_space, &mis);
 
         // Publish expect initial value
         let _notification_message = {
@@ -306,7 +306,7 @@ fn publish_keep_alive() {
                 (1, "v1"),
             ]);
             debug!("CreateMonitoredItemsRequest {:#?}", request);
-            let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(session, &request).unwrap(), CreateMonitoredItemsResponse);
+            let response: CreateMonitoredItemsResponse = supported_message_as!(mis.create_monitored_items(session, address_space, &request).unwrap(), CreateMonitoredItemsResponse);
             debug!("CreateMonitoredItemsResponse {:#?}", response);
             // let result = response.results.unwrap()[0].monitored_item_id;
         }
@@ -418,4 +418,86 @@ fn republish() {
         let response: ServiceFault = supported_message_as!(ss.republish(session, &request).unwrap(), ServiceFault);
         assert_eq!(response.response_header.service_result, StatusCode::BadMessageNotAvailable);
     })
-}
\ No newline at end of file
+}
+#[test]
+fn transfer_subscriptions() {
+    let st = ServiceTest::new();
+    let ss = SubscriptionService::new();
+    let address_space = trace_read_lock_unwrap!(st.address_space);
+
+    // A second session on another connection of the same server
+    let session2 = st.server.new_transport().session();
+
+    // Create a subscription on the first session with an unacknowledged notification and a
+    // publish request waiting for the next one
+    let subscription_id = {
+        let (mut server_state, mut session) = st.get_server_state_and_session();
+        session.user_token_id = Some("sample1".to_string());
+        let subscription_id = create_subscription(&mut server_state, &mut session, &ss);
+        let notification = NotificationMessage::data_change(1, DateTime::now(), vec![]);
+        session.subscriptions.retransmission_queue().insert((subscription_id, 1), notification);
+        let request = PublishRequest {
+            request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 1),
+            subscription_acknowledgements: None,
+        };
+        assert!(ss.async_publish(&mut session, 1001, &address_space, &request).unwrap().is_none());
+        subscription_id
+    };
+
+    let transfer_subscriptions = |subscription_ids: Option<Vec<u32>>| {
+        let mut server_state = trace_write_lock_unwrap!(st.server_state);
+        let mut session = trace_write_lock_unwrap!(session2);
+        let other_sessions = server_state.sessions().into_iter()
+            .filter(|other_session| !Arc::ptr_eq(other_session, &session2))
+            .collect::<Vec<_>>();
+        let request = TransferSubscriptionsRequest {
+            request_header: RequestHeader::new(&NodeId::null(), &DateTime::now(), 1),
+            subscription_ids,
+            send_initial_values: true,
+        };
+        ss.transfer_subscriptions(&server_state, &mut session, &other_sessions, &request).unwrap()
+    };
+
+    // A session of another user cannot take the subscription
+    {
+        let mut session = trace_write_lock_unwrap!(session2);
+        session.user_token_id = Some("sample2".to_string());
+    }
+    let response = supported_message_as!(transfer_subscriptions(Some(vec![subscription_id])), TransferSubscriptionsResponse);
+    let results = response.results.unwrap();
+    assert_eq!(results[0].status_code, StatusCode::BadUserAccessDenied);
+    assert!(trace_read_lock_unwrap!(st.session).subscriptions.contains(subscription_id));
+
+    // A session of the same user can, with its unacknowledged notification
+    {
+        let mut session = trace_write_lock_unwrap!(session2);
+        session.user_token_id = Some("sample1".to_string());
+    }
+    let response = supported_message_as!(transfer_subscriptions(Some(vec![subscription_id, subscription_id + 1])), TransferSubscriptionsResponse);
+    let results = response.results.unwrap();
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(results[0].available_sequence_numbers, Some(vec![1]));
+    assert_eq!(results[1].status_code, StatusCode::BadSubscriptionIdInvalid);
+    {
+        let mut session = trace_write_lock_unwrap!(session2);
+        assert!(session.subscriptions.contains(subscription_id));
+        assert!(session.subscriptions.find_notification_message(subscription_id, 1).is_ok());
+    }
+
+    // The first session tells its client that the subscription was transferred
+    {
+        let mut session = trace_write_lock_unwrap!(st.session);
+        assert!(!session.subscriptions.contains(subscription_id));
+        let _ = session.tick_subscriptions(&Utc::now(), &address_space, TickReason::TickTimerFired);
+        let response = session.subscriptions.publish_response_queue.pop_back().unwrap().response;
+        let response: PublishResponse = supported_message_as!(response, PublishResponse);
+        assert_eq!(response.subscription_id, subscription_id);
+        let notification_data = response.notification_message.notification_data.unwrap();
+        let status_change = notification_data[0].decode_inner::<StatusChangeNotification>(&DecodingLimits::default()).unwrap();
+        assert_eq!(status_change.status, StatusCode::GoodSubscriptionTransferred);
+    }
+
+    // Nothing to transfer
+    let response = supported_message_as!(transfer_subscriptions(None), ServiceFault);
+    assert_eq!(response.response_header.service_result, StatusCode::BadNothingToDo);
+}
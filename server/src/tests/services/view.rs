@@ -1,5 +1,8 @@
+use opcua_types::relative_path::make_relative_path;
+
 use crate::prelude::*;
-use crate::services::view::ViewService;
+use crate::constants::MAX_REGISTERED_NODES;
+use crate::services::{attribute::AttributeService, view::ViewService};
 use super::*;
 
 // View service tests
@@ -94,6 +97,49 @@ fn browse() {
     assert_eq!(r3.browse_name, QualifiedName::new(0, "Views"));
 }
 
+#[test]
+fn browse_custom_reference_type() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let vs = ViewService::new();
+    let mut address_space = st.address_space.write().unwrap();
+
+    // A reference type of our own namespace, and a folder that references a variable by it
+    let has_sensor_id = NodeId::new(1, "HasSensor");
+    address_space.insert(ReferenceType::new(&has_sensor_id, "HasSensor", "HasSensor", "", None, false, false), Some(&[
+        (&ReferenceTypeId::HasComponent.into(), ReferenceTypeId::HasSubtype, ReferenceDirection::Inverse),
+    ]));
+    let folder_id = address_space.add_folder("Line", "Line", &AddressSpace::objects_folder_id()).unwrap();
+    let sensor_id = NodeId::new(1, "Sensor");
+    address_space.insert(Variable::new(&sensor_id, "Sensor", "Sensor", "", 1.5f64), None);
+    address_space.insert_reference(&folder_id, &sensor_id, has_sensor_id.clone());
+
+    let browse = |session: &mut Session, reference_type_id: NodeId, include_subtypes: bool| {
+        let mut request = make_browse_request(std::slice::from_ref(&folder_id), 100, BrowseDirection::Forward, ReferenceTypeId::Organizes);
+        let node_to_browse = &mut request.nodes_to_browse.as_mut().unwrap()[0];
+        node_to_browse.reference_type_id = reference_type_id;
+        node_to_browse.include_subtypes = include_subtypes;
+        let response = supported_message_as!(vs.browse(session, &address_space, &request).unwrap(), BrowseResponse);
+        response.results.unwrap().remove(0)
+    };
+
+    // By the custom reference type and by its supertypes
+    for (reference_type_id, include_subtypes) in [(has_sensor_id.clone(), false), (ReferenceTypeId::HasComponent.into(), true), (ReferenceTypeId::HierarchicalReferences.into(), true)].iter().cloned() {
+        let result = browse(&mut session, reference_type_id, include_subtypes);
+        assert_eq!(result.status_code, StatusCode::Good);
+        let references = result.references.unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].node_id.node_id, sensor_id);
+        assert_eq!(references[0].reference_type_id, has_sensor_id);
+    }
+    let result = browse(&mut session, ReferenceTypeId::HasComponent.into(), false);
+    assert!(result.references.unwrap_or_default().is_empty());
+
+    // A node that is not a reference type
+    let result = browse(&mut session, folder_id.clone(), true);
+    assert_eq!(result.status_code, StatusCode::BadReferenceTypeIdInvalid);
+}
+
 #[test]
 fn browse_next() {
     // Set up a server with more nodes than can fit in a response to test Browse, BrowseNext response
@@ -215,23 +261,45 @@ fn browse_next() {
 fn translate_browse_paths_to_node_ids() {
     let st = ServiceTest::new();
 
-    // This is a very basic test of this service. It wants to find the relative path from root to the
-    // Objects folder and ensure that it comes back in the result
+    // A folder with a dangling reference to a node that is not in the address space
+    {
+        let mut address_space = st.address_space.write().unwrap();
+        let folder_id = address_space.add_folder("Line", "Line", &AddressSpace::objects_folder_id()).unwrap();
+        address_space.insert_reference(&folder_id, &NodeId::new(5, "Remote"), ReferenceTypeId::Organizes);
+    }
 
+    let browse_path = |starting_node: NodeId, path: &str| {
+        BrowsePath {
+            starting_node,
+            relative_path: make_relative_path(path).unwrap(),
+        }
+    };
+    let root_id: NodeId = ObjectId::RootFolder.into();
     let browse_paths = vec![
+        // Organizes is a subtype of HierarchicalReferences but not of HasChild
+        browse_path(root_id.clone(), "/Objects"),
+        browse_path(root_id.clone(), "<HasChild>Objects"),
+        browse_path(root_id.clone(), "<#Organizes>Objects/Server.ServerStatus.State"),
+        browse_path(AddressSpace::objects_folder_id(), "<!Organizes>Root"),
+        // An empty browse name matches any target, but only at the end of the path
+        browse_path(root_id.clone(), "/"),
         BrowsePath {
-            starting_node: ObjectId::RootFolder.into(),
+            starting_node: root_id.clone(),
             relative_path: RelativePath {
                 elements: Some(vec![
                     RelativePathElement {
-                        reference_type_id: ReferenceTypeId::HasChild.into(),
+                        reference_type_id: ReferenceTypeId::Organizes.into(),
                         is_inverse: false,
                         include_subtypes: true,
-                        target_name: QualifiedName::new(0, "Objects"),
-                    }
+                        target_name: QualifiedName::null(),
+                    },
+                    make_relative_path("/Server").unwrap().elements.unwrap().remove(0),
                 ]),
             },
-        }
+        },
+        browse_path(root_id.clone(), "/Objects/Line/Remote/Pump"),
+        browse_path(NodeId::new(5, "Unknown"), "/Objects"),
+        browse_path(root_id.clone(), ""),
     ];
 
     let request = TranslateBrowsePathsToNodeIdsRequest {
@@ -248,14 +316,170 @@ fn translate_browse_paths_to_node_ids() {
     debug!("result = {:#?}", result);
 
     let results = result.results.unwrap();
-    assert_eq!(results.len(), 1);
-    let r1 = &results[0];
+    assert_eq!(results.len(), 9);
+    let target_ids = |result: &BrowsePathResult| -> Vec<(NodeId, u32)> {
+        result.targets.as_ref().unwrap().iter().map(|target| (target.target_id.node_id.clone(), target.remaining_path_index)).collect()
+    };
+
+    assert_eq!(results[0].status_code, StatusCode::Good);
+    assert_eq!(target_ids(&results[0]), vec![(AddressSpace::objects_folder_id(), u32::MAX)]);
+
+    assert_eq!(results[1].status_code, StatusCode::BadNoMatch);
+    assert!(results[1].targets.is_none());
+
+    assert_eq!(results[2].status_code, StatusCode::Good);
+    assert_eq!(target_ids(&results[2]), vec![(VariableId::Server_ServerStatus_State.into(), u32::MAX)]);
+
+    assert_eq!(results[3].status_code, StatusCode::Good);
+    assert_eq!(target_ids(&results[3]), vec![(root_id.clone(), u32::MAX)]);
+
+    assert_eq!(results[4].status_code, StatusCode::Good);
+    let mut target_ids_4 = target_ids(&results[4]);
+    target_ids_4.sort_by_key(|(node_id, _)| format!("{:?}", node_id));
+    let mut expected: Vec<(NodeId, u32)> = vec![
+        (AddressSpace::objects_folder_id(), u32::MAX),
+        (ObjectId::TypesFolder.into(), u32::MAX),
+        (ObjectId::ViewsFolder.into(), u32::MAX),
+    ];
+    expected.sort_by_key(|(node_id, _)| format!("{:?}", node_id));
+    assert_eq!(target_ids_4, expected);
+
+    assert_eq!(results[5].status_code, StatusCode::BadBrowseNameInvalid);
+
+    // The reference to the node that is not in the address space dangles, so nothing matches
+    assert_eq!(results[6].status_code, StatusCode::BadNoMatch);
+    assert!(results[6].targets.is_none());
+
+    assert_eq!(results[7].status_code, StatusCode::BadNodeIdUnknown);
+    assert_eq!(results[8].status_code, StatusCode::BadNothingToDo);
+}
+
+#[test]
+fn register_nodes() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, 2);
+    address_space.find_variable_mut(node_ids[1].clone()).unwrap().set_writable(true);
+    let vs = ViewService::new();
+    let ats = AttributeService::new();
+
+    // Nothing to do
+    let request = RegisterNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_register: None,
+    };
+    let response = vs.register_nodes(&mut session, &address_space, &request).unwrap();
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadNothingToDo);
+
+    // String node ids are aliased by numeric node ids, other node ids are returned as they are
+    let server_id: NodeId = ObjectId::Server.into();
+    let unknown_id = NodeId::new(1, "vxxx");
+    let request = RegisterNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_register: Some(vec![node_ids[0].clone(), node_ids[1].clone(), server_id.clone(), unknown_id.clone()]),
+    };
+    let response = vs.register_nodes(&mut session, &address_space, &request).unwrap();
+    let registered_node_ids = supported_message_as!(response, RegisterNodesResponse).registered_node_ids.unwrap();
+    assert_eq!(registered_node_ids.len(), 4);
+    assert!(registered_node_ids[0].is_numeric());
+    assert_eq!(registered_node_ids[0].namespace, 1);
+    assert_ne!(registered_node_ids[0], registered_node_ids[1]);
+    assert!(!address_space.node_exists(&registered_node_ids[0]));
+    assert_eq!(registered_node_ids[2], server_id);
+    assert_eq!(registered_node_ids[3], unknown_id);
+
+    // Reads and writes of the aliases are of the registered nodes
+    let read_value = |node_id: &NodeId| ReadValueId {
+        node_id: node_id.clone(),
+        attribute_id: AttributeId::Value as u32,
+        index_range: UAString::null(),
+        data_encoding: QualifiedName::null(),
+    };
+    let request = WriteRequest {
+        request_header: make_request_header(),
+        nodes_to_write: Some(vec![WriteValue {
+            node_id: registered_node_ids[1].clone(),
+            attribute_id: AttributeId::Value as u32,
+            index_range: UAString::null(),
+            value: DataValue::new(100i32),
+        }]),
+    };
+    let response = ats.write(&session, &mut address_space, &request).unwrap();
+    assert_eq!(supported_message_as!(response, WriteResponse).results.unwrap(), vec![StatusCode::Good]);
+    let request = ReadRequest {
+        request_header: make_request_header(),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Neither,
+        nodes_to_read: Some(vec![read_value(&registered_node_ids[0]), read_value(&registered_node_ids[1]), read_value(&node_ids[1])]),
+    };
+    let response = ats.read(&session, &address_space, &request).unwrap();
+    let results = supported_message_as!(response, ReadResponse).results.unwrap();
+    assert_eq!(results.iter().map(|result| result.value.clone().unwrap()).collect::<Vec<Variant>>(), vec![Variant::Int32(0), Variant::Int32(100), Variant::Int32(100)]);
+
+    // Unregistered aliases no longer refer to the nodes
+    let request = UnregisterNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_unregister: Some(vec![registered_node_ids[0].clone()]),
+    };
+    let response = vs.unregister_nodes(&mut session, &request).unwrap();
+    let _ = supported_message_as!(response, UnregisterNodesResponse);
+    let request = ReadRequest {
+        request_header: make_request_header(),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Neither,
+        nodes_to_read: Some(vec![read_value(&registered_node_ids[0]), read_value(&registered_node_ids[1])]),
+    };
+    let response = ats.read(&session, &address_space, &request).unwrap();
+    let results = supported_message_as!(response, ReadResponse).results.unwrap();
+    assert_eq!(results[0].status, Some(StatusCode::BadNodeIdUnknown.bits()));
+    assert_eq!(results[1].value, Some(Variant::Int32(100)));
+
+    // A node that is still registered keeps its alias
+    let request = RegisterNodesRequest {
+        request_header: make_request_header(),
+        nodes_to_register: Some(vec![node_ids[1].clone(), node_ids[1].clone()]),
+    };
+    let response = vs.register_nodes(&mut session, &address_space, &request).unwrap();
+    assert_eq!(supported_message_as!(response, RegisterNodesResponse).registered_node_ids.unwrap(), vec![registered_node_ids[1].clone(), registered_node_ids[1].clone()]);
+
+    // Closing the session releases the rest
+    session.unregister_all_nodes();
+    assert_eq!(session.resolve_node_id(&registered_node_ids[1]), &registered_node_ids[1]);
+}
+
+#[test]
+fn register_too_many_nodes() {
+    let st = ServiceTest::new();
+    let (_server_state, mut session) = st.get_server_state_and_session();
+    let mut address_space = st.address_space.write().unwrap();
+    let (_, node_ids) = add_many_vars_to_address_space(&mut address_space, MAX_REGISTERED_NODES + 1);
+    let vs = ViewService::new();
+
+    let register_nodes = |session: &mut Session, node_ids: &[NodeId]| {
+        let request = RegisterNodesRequest {
+            request_header: make_request_header(),
+            nodes_to_register: Some(node_ids.to_vec()),
+        };
+        vs.register_nodes(session, &address_space, &request).unwrap()
+    };
 
-    // TODO broken
-    /*    let targets = r1.targets.as_ref().unwrap();
-        assert_eq!(targets.len(), 1);
-        let t1 = &targets[0];
-        assert_eq!(&t1.target_id.node_id, &AddressSpace::objects_folder_id()); */
+    let (last_node_id, node_ids) = node_ids.split_last().unwrap();
+    let response = register_nodes(&mut session, node_ids);
+    let registered_node_ids = supported_message_as!(response, RegisterNodesResponse).registered_node_ids.unwrap();
+    assert_eq!(registered_node_ids.len(), MAX_REGISTERED_NODES);
+
+    // The session is full so another node can't be registered, but a registered node can be again
+    let response = register_nodes(&mut session, &[node_ids[0].clone(), last_node_id.clone()]);
+    assert_eq!(supported_message_as!(response, ServiceFault).response_header.service_result, StatusCode::BadTooManyOperations);
+    let response = register_nodes(&mut session, &[node_ids[0].clone()]);
+    assert_eq!(supported_message_as!(response, RegisterNodesResponse).registered_node_ids.unwrap(), vec![registered_node_ids[0].clone()]);
+
+    // Unregistering a node makes room for another
+    session.unregister_node(&registered_node_ids[0]);
+    let response = register_nodes(&mut session, std::slice::from_ref(last_node_id));
+    let registered_node_ids = supported_message_as!(response, RegisterNodesResponse).registered_node_ids.unwrap();
+    assert_eq!(session.resolve_node_id(&registered_node_ids[0]), last_node_id);
 }
 
 ///
@@ -20,7 +20,8 @@ use crate::{
 let node_ids = {};
 
 function interested_in_node(node) {
-    return (!node.name.endsWith("_DefaultXml") && !node.name.startsWith("OpcUa_XmlSchema_"));
+    // The XML encoding objects are kept but not the XML schema and its dictionary entries
+    return !node.name.startsWith("OpcUa_XmlSchema_");
 }
 
 fs.createReadStream(status_code_csv)
@@ -46,7 +47,6 @@ fs.createReadStream(status_code_csv)
 pub enum ${key}Id {
 `);
             _.each(value, function (node) {
-                // Skip Xml junk
                 if (interested_in_node(node)) {
                     rs_out.write(`    ${node.name} = ${node.id},\n`);
                 }
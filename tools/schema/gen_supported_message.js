@@ -170,6 +170,8 @@ generate_supported_message([
     "CreateMonitoredItemsRequest", "CreateMonitoredItemsResponse",
     "ModifyMonitoredItemsRequest", "ModifyMonitoredItemsResponse",
     "DeleteMonitoredItemsRequest", "DeleteMonitoredItemsResponse",
+    "SetMonitoringModeRequest", "SetMonitoringModeResponse",
+    "SetTriggeringRequest", "SetTriggeringResponse",
     // Subscription service
     "CreateSubscriptionRequest", "CreateSubscriptionResponse",
     "ModifySubscriptionRequest", "ModifySubscriptionResponse",
@@ -180,10 +182,22 @@ generate_supported_message([
     "BrowseNextRequest", "BrowseNextResponse",
     "PublishRequest", "PublishResponse",
     "RepublishRequest", "RepublishResponse",
+    "TransferSubscriptionsRequest", "TransferSubscriptionsResponse",
     "TranslateBrowsePathsToNodeIdsRequest", "TranslateBrowsePathsToNodeIdsResponse",
+    "RegisterNodesRequest", "RegisterNodesResponse",
+    "UnregisterNodesRequest", "UnregisterNodesResponse",
+    // Query service
+    "QueryFirstRequest", "QueryFirstResponse",
+    "QueryNextRequest", "QueryNextResponse",
     // Attribute service
     "ReadRequest", "ReadResponse",
     "WriteRequest", "WriteResponse",
+    "HistoryReadRequest", "HistoryReadResponse", "HistoryUpdateRequest", "HistoryUpdateResponse",
     // Method service
     "CallRequest", "CallResponse",
+    // NodeManagement service
+    "AddNodesRequest", "AddNodesResponse",
+    "AddReferencesRequest", "AddReferencesResponse",
+    "DeleteNodesRequest", "DeleteNodesResponse",
+    "DeleteReferencesRequest", "DeleteReferencesResponse",
 ])
\ No newline at end of file
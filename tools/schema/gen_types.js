@@ -19,11 +19,11 @@ let ignored_types = [
     "Node", "InstanceNode", "TypeNode", "ObjectNode", "ObjectTypeNode", "VariableNode", "VariableTypeNode", "ReferenceTypeNode",
     "MethodNode", "ViewNode", "DataTypeNode", "ReferenceNode",
     // Excluded because they use unimplemented enums, or are used by unimplemented services
-    "ModificationInfo", "HistoryModifiedData", "UpdateDataDetails", "UpdateEventDetails", "UpdateStructureDataDetails", "RedundantServerDataType",
-    "ServerStatusDataType", "AxisInformation", "RegisterServer2Request", "RegisterServer2Response", "HistoryData", "HistoryEvent", "HistoryReadDetails",
-    "HistoryEventFieldList", "HistoryReadRequest", "HistoryReadResponse", "HistoryReadResult", "HistoryReadValueId", "HistoryUpdateDetails",
-    "HistoryUpdateRequest", "HistoryUpdateResponse", "HistoryUpdateResult", "SemanticChangeStructureDataType", "SemanticChangeStructureDataType",
-    "ReadAtTimeDetails", "ReadProcessedDetails"
+    "UpdateEventDetails", "UpdateStructureDataDetails", "RedundantServerDataType",
+    "ServerStatusDataType", "AxisInformation", "RegisterServer2Request", "RegisterServer2Response", "HistoryEvent", "HistoryReadDetails",
+    "HistoryEventFieldList", "HistoryUpdateDetails",
+    "SemanticChangeStructureDataType", "SemanticChangeStructureDataType",
+    "ReadAtTimeDetails"
 ];
 
 let basic_types_import_map = {
@@ -36,14 +36,15 @@ let basic_types_import_map = {
     "extension_object": ["ExtensionObject"],
     "data_types": ["MessageSecurityMode", "Duration", "UtcTime", "MonitoringMode"],
     "service_types::impls": ["RequestHeader", "ResponseHeader"],
-    "service_types::enums": ["TimestampsToReturn", "FilterOperator", "BrowseDirection", "NodeClass", "SecurityTokenRequestType", "ApplicationType", "UserTokenType", "DataChangeTrigger"],
+    "service_types::enums": ["TimestampsToReturn", "FilterOperator", "BrowseDirection", "NodeClass", "SecurityTokenRequestType", "ApplicationType", "UserTokenType", "DataChangeTrigger", "HistoryUpdateType", "PerformUpdateType"],
     "node_id": ["NodeId", "ExpandedNodeId"],
     "data_value": ["DataValue"],
     "date_time": ["DateTime"],
     "status_codes": ["StatusCode"]
 };
 
-let serde_supported_types = ["ReadValueId", "DataChangeFilter", "MonitoredItemNotification", "ServerDiagnosticsSummaryDataType"];
+let serde_supported_types = ["ReadValueId", "DataChangeFilter", "MonitoredItemNotification", "ServerDiagnosticsSummaryDataType",
+    "EventFilter", "SimpleAttributeOperand", "ContentFilter", "ContentFilterElement", "EventFieldList"];
 
 // Contains a flattened reverse lookup of the import map
 let basic_types_reverse_import_map = {};
@@ -99,16 +100,20 @@ fs.readFile(types_xml, function (err, data) {
                 let fields_to_add = [];
                 let fields_to_hide = [];
                 _.each(structured_type_element["opc:Field"], function (field) {
-                    // Convert field name to snake case
-                    let field_name = convertFieldName(field["$"]["Name"]);
+                    // Convert field name to snake case, the JSON encoding keeps the original name
+                    let json_name = field["$"]["Name"];
+                    let field_name = convertFieldName(json_name);
 
-                    // Strip namespace off the type
-                    let type = massageTypeName(field["$"]["TypeName"].split(":")[1]);
+                    // Strip namespace off the type, the XML encoding names array elements after the original type
+                    let xml_type = field["$"]["TypeName"].split(":")[1];
+                    let type = massageTypeName(xml_type);
 
                     // Look for arrays
                     if (_.has(field["$"], "LengthField")) {
                         fields_to_add.push({
                             name: field_name,
+                            json_name: json_name,
+                            xml_type: xml_type,
                             type: `Option<Vec<${type}>>`,
                             contained_type: type,
                             inner_type: type,
@@ -119,6 +124,8 @@ fs.readFile(types_xml, function (err, data) {
                     else {
                         fields_to_add.push({
                             name: field_name,
+                            json_name: json_name,
+                            xml_type: xml_type,
                             type: type,
                             contained_type: type
                         })
@@ -159,6 +166,14 @@ function generate_types_mod(structured_types) {
     let contents = `// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
 // DO NOT EDIT THIS FILE
 
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    extension_object::ExtensionObject,
+    node_ids::{DataTypeId, ObjectId},
+};
+
 // The mods below are handwritten
 mod enums;
 mod impls;
@@ -183,13 +198,83 @@ pub use self::impls::*;
 `
     });
 
+    // Lookups between the binary and JSON bodies of extension objects of the types
+    let message_types = _.filter(structured_types, has_message_info);
+
+    contents += `
+/// Decodes the binary body of an extension object of a generated type and encodes it as JSON.
+/// The data type of the body is returned with it. Returns \`None\` if the object id is not the
+/// binary encoding of a generated type.
+pub(crate) fn binary_body_to_json(object_id: ObjectId, extension_object: &ExtensionObject, decoding_limits: &DecodingLimits, context: &JsonContext) -> Option<EncodingResult<(DataTypeId, JsonValue)>> {
+    match object_id {
+`;
+    _.each(message_types, function (structured_type) {
+        let name = structured_type.name;
+        contents += `        ObjectId::${name}_Encoding_DefaultBinary => Some(extension_object.decode_inner::<${name}>(decoding_limits).map(|v| (DataTypeId::${name}, v.encode_json(context)))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Decodes the JSON body of an extension object of a generated type and returns the extension
+/// object with the binary body. Returns \`None\` if the data type is not a generated type.
+pub(crate) fn json_body_to_binary(data_type_id: DataTypeId, body: &JsonValue, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    match data_type_id {
+`;
+    _.each(message_types, function (structured_type) {
+        let name = structured_type.name;
+        contents += `        DataTypeId::${name} => Some(${name}::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::${name}_Encoding_DefaultBinary, &v))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Decodes the binary body of an extension object of a generated type and encodes it as XML.
+/// The id of the XML encoding of the type is returned with it. Returns \`None\` if the object id
+/// is not the binary encoding of a generated type.
+pub(crate) fn binary_body_to_xml(object_id: ObjectId, extension_object: &ExtensionObject, decoding_limits: &DecodingLimits) -> Option<EncodingResult<(ObjectId, XmlNode)>> {
+    match object_id {
+`;
+    _.each(message_types, function (structured_type) {
+        let name = structured_type.name;
+        contents += `        ObjectId::${name}_Encoding_DefaultBinary => Some(extension_object.decode_inner::<${name}>(decoding_limits).map(|v| (ObjectId::${name}_Encoding_DefaultXml, v.encode_xml("${name}").unwrap_or_else(|| XmlNode::new("${name}"))))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+
+/// Decodes the XML body of an extension object of a generated type and returns the extension
+/// object with the binary body. Returns \`None\` if the object id is not the XML encoding of a
+/// generated type.
+pub(crate) fn xml_body_to_binary(object_id: ObjectId, body: &XmlNode, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    match object_id {
+`;
+    _.each(message_types, function (structured_type) {
+        let name = structured_type.name;
+        contents += `        ObjectId::${name}_Encoding_DefaultXml => Some(${name}::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::${name}_Encoding_DefaultBinary, &v))),
+`;
+    });
+    contents += `        _ => None
+    }
+}
+`;
+
     settings.write_to_file(file_path, contents);
 }
 
+function has_message_info(structured_type) {
+    return _.has(structured_type, "base_type") && structured_type.base_type === "ua:ExtensionObject";
+}
+
 function generate_type_imports(structured_types, fields_to_add, fields_to_hide, has_message_info) {
     let imports = `#[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
 `;
 
@@ -253,7 +338,7 @@ function generate_structured_type_file(structured_types, structured_type) {
     let file_name = _.snakeCase(structured_type.name) + ".rs";
     let file_path = `${settings.rs_types_dir}/${file_name}`;
 
-    let has_message_info = _.has(structured_type, "base_type") && structured_type.base_type === "ua:ExtensionObject";
+    let message_info = has_message_info(structured_type);
 
     console.log("Creating structured type file - " + file_path);
 
@@ -263,7 +348,7 @@ function generate_structured_type_file(structured_types, structured_type) {
 use std::io::{Read, Write};
 
 `;
-    contents += generate_type_imports(structured_types, structured_type.fields_to_add, structured_type.fields_to_hide, has_message_info);
+    contents += generate_type_imports(structured_types, structured_type.fields_to_add, structured_type.fields_to_hide, message_info);
     contents += "\n";
 
     if (_.has(structured_type, "documentation")) {
@@ -288,7 +373,7 @@ pub struct ${structured_type.name} {
 
 `;
 
-    if (has_message_info) {
+    if (message_info) {
         contents += `impl MessageInfo for ${structured_type.name} {
     fn object_id(&self) -> ObjectId {
         ObjectId::${structured_type.name}_Encoding_DefaultBinary
@@ -376,6 +461,125 @@ pub struct ${structured_type.name} {
     contents += `        })
     }
 }
+
+impl JsonEncoder for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+`;
+
+    let fields_to_encode = _.filter(structured_type.fields_to_add, function (field) {
+        return !_.includes(structured_type.fields_to_hide, field.name);
+    });
+
+    if (fields_to_encode.length > 0) {
+        contents += `        let mut fields = JsonObject::new();
+`;
+        _.each(fields_to_encode, function (field) {
+            if (_.has(field, 'is_array')) {
+                contents += `        insert_json_field(&mut fields, "${field.json_name}", encode_json_array(&self.${field.name}, context));
+`;
+            }
+            else {
+                contents += `        insert_json_field(&mut fields, "${field.json_name}", self.${field.name}.encode_json(context));
+`;
+            }
+        });
+        contents += `        JsonValue::Object(fields)
+`;
+    }
+    else {
+        contents += `        JsonValue::Object(JsonObject::new())
+`;
+    }
+
+    contents += `    }
+}
+
+impl JsonDecoder for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+`;
+
+    _.each(fields_to_encode, function (field) {
+        if (_.has(field, 'is_array')) {
+            contents += `        let ${field.name}: ${field.type} = decode_json_array(json_field(fields, "${field.json_name}"), decoding_limits)?;
+`;
+        }
+        else {
+            contents += `        let ${field.name} = ${field.type}::decode_json(json_field(fields, "${field.json_name}"), decoding_limits)?;
+`;
+        }
+    });
+
+    contents += `        Ok(${structured_type.name} {
+`;
+
+    _.each(fields_to_encode, function (field) {
+        contents += `            ${field.name},
+`;
+    });
+
+    contents += `        })
+    }
+}
+
+impl XmlEncoder for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+`;
+
+    if (fields_to_encode.length > 0) {
+        contents += `        let mut element = XmlNode::new(name);
+`;
+        _.each(fields_to_encode, function (field) {
+            if (_.has(field, 'is_array')) {
+                contents += `        element.add_child(encode_xml_array("${field.json_name}", "${field.xml_type}", &self.${field.name}));
+`;
+            }
+            else {
+                contents += `        element.add_child(self.${field.name}.encode_xml("${field.json_name}"));
+`;
+            }
+        });
+        contents += `        Some(element)
+`;
+    }
+    else {
+        contents += `        Some(XmlNode::new(name))
+`;
+    }
+
+    contents += `    }
+}
+
+impl XmlDecoder for ${structured_type.name} {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+`;
+
+    _.each(fields_to_encode, function (field) {
+        if (_.has(field, 'is_array')) {
+            contents += `        let ${field.name}: ${field.type} = decode_xml_array(xml_child(element, "${field.json_name}"), decoding_limits)?;
+`;
+        }
+        else {
+            contents += `        let ${field.name} = ${field.type}::decode_xml(xml_child(element, "${field.json_name}"), decoding_limits)?;
+`;
+        }
+    });
+
+    contents += `        Ok(${structured_type.name} {
+`;
+
+    _.each(fields_to_encode, function (field) {
+        contents += `            ${field.name},
+`;
+    });
+
+    contents += `        })
+    }
+}
 `;
 
     settings.write_to_file(file_path, contents);
@@ -0,0 +1,798 @@
+//! Derive macros for custom OPC UA types, i.e. structures and enumerations of a server's or
+//! companion specification's own namespace rather than the generated types of `opcua-types`.
+//!
+//! `#[derive(UaEncodable)]` implements the binary, JSON and XML encodings of `opcua-types` for
+//!
+//! 1. A struct with named fields, which is a structure. Fields marked `#[opcua(optional)]` must
+//!    be an `Option` and make it a structure with optional fields, which is preceded by an
+//!    encoding mask of the fields that are present. A field of type `Option<Vec<T>>` is an array.
+//! 2. An enum whose variants each hold one value, which is a union. The variants are the fields of
+//!    the union and their switch field values are numbered from 1 in order of declaration. A unit
+//!    variant, if there is one, is the null union whose switch field is 0.
+//! 3. An enum of unit variants, which is an enumeration encoded as an Int32 of its discriminants.
+//!
+//! A structure or union identifies its data type and encodings with node ids in the
+//! `data_type_id` and `binary_encoding_id` attributes, and optionally `xml_encoding_id` and
+//! `json_encoding_id`. With them it implements `EncodableType` and converts to and from a
+//! `Variant` holding an `ExtensionObject`. An enumeration converts to and from an Int32 `Variant`.
+//!
+//! Fields and variants are encoded with their names in Pascal case, unless renamed with
+//! `#[opcua(rename = "...")]`. The code that is generated refers to `opcua_types`, which must be
+//! a dependency of the crate that uses the derive.
+//!
+//! ```
+//! use std::convert::TryFrom;
+//! use opcua_types::{UAString, Variant};
+//! use opcua_types_derive::UaEncodable;
+//!
+//! #[derive(Debug, Clone, PartialEq, UaEncodable)]
+//! #[opcua(data_type_id = "ns=2;i=3001", binary_encoding_id = "ns=2;i=5001")]
+//! pub struct Measurement {
+//!     pub name: UAString,
+//!     pub value: f64,
+//!     #[opcua(optional)]
+//!     pub unit: Option<UAString>,
+//! }
+//!
+//! let measurement = Measurement { name: "Temperature".into(), value: 21.5, unit: None };
+//! let variant = Variant::from(measurement.clone());
+//! assert_eq!(Measurement::try_from(&variant).unwrap(), measurement);
+//! ```
+//!
+//! `#[derive(UaObjectType)]`, which models an object type of a server by a struct, is in the
+//! `opcua-server-derive` crate.
+
+#![recursion_limit = "256"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Fields,
+    GenericArgument, Ident, Lit, LitStr, Meta, NestedMeta, PathArguments, Result, Type,
+};
+
+/// Derives `BinaryEncoder`, `JsonEncoder`, `JsonDecoder`, `XmlEncoder` and `XmlDecoder` for a
+/// structure, union or enumeration, and `EncodableType` and conversions with `Variant` for those
+/// with node ids. See the crate documentation for the attributes.
+#[proc_macro_derive(UaEncodable, attributes(opcua))]
+pub fn derive_ua_encodable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = if !input.generics.params.is_empty() {
+        Err(Error::new_spanned(&input.generics, "UaEncodable cannot be derived for a generic type"))
+    } else {
+        match input.data {
+            Data::Struct(ref data) => derive_structure(&input, data),
+            Data::Enum(ref data) => if data.variants.iter().all(|variant| variant.fields.iter().next().is_none()) {
+                derive_enumeration(&input, data)
+            } else {
+                derive_union(&input, data)
+            },
+            Data::Union(_) => Err(Error::new_spanned(&input.ident, "UaEncodable cannot be derived for a Rust union, use an enum")),
+        }
+    };
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// The node ids of the data type and encodings given to a structure or union
+#[derive(Default)]
+struct TypeIds {
+    data_type_id: Option<TokenStream2>,
+    binary_encoding_id: Option<TokenStream2>,
+    xml_encoding_id: Option<TokenStream2>,
+    json_encoding_id: Option<TokenStream2>,
+}
+
+/// The options of a field of a structure or a variant of a union
+#[derive(Default)]
+struct FieldOptions {
+    rename: Option<String>,
+    optional: bool,
+}
+
+/// A field of a structure or the value of a variant of a union and how it is encoded
+struct Field<'a> {
+    /// The name of the field in the JSON and XML encodings
+    name: String,
+    ty: &'a Type,
+    /// The type of the elements if the field is an array, i.e. an `Option<Vec<T>>`
+    array_element: Option<&'a Type>,
+    /// The type held by an optional field if it is not an array
+    optional_value: Option<&'a Type>,
+    optional: bool,
+}
+
+impl<'a> Field<'a> {
+    fn new(name: String, ty: &'a Type, options: &FieldOptions) -> Result<Field<'a>> {
+        let array_element = option_inner(ty).and_then(|ty| generic_inner(ty, "Vec"));
+        let optional_value = if !options.optional {
+            None
+        } else if let Some(value) = option_inner(ty) {
+            if array_element.is_some() { None } else { Some(value) }
+        } else {
+            return Err(Error::new_spanned(ty, "An optional field must be an Option"));
+        };
+        Ok(Field {
+            name: options.rename.clone().unwrap_or(name),
+            ty,
+            array_element,
+            optional_value,
+            optional: options.optional,
+        })
+    }
+
+    /// The size of the binary encoding of the field's value, which is a reference
+    fn byte_len(&self, value: &TokenStream2) -> TokenStream2 {
+        let ty = self.ty;
+        if self.array_element.is_some() && self.optional {
+            quote!(if (#value).is_some() { ::opcua_types::byte_len_array(#value) } else { 0 })
+        } else if self.array_element.is_some() {
+            quote!(::opcua_types::byte_len_array(#value))
+        } else if let Some(inner) = self.optional_value {
+            quote!((#value).as_ref().map_or(0, |value| <#inner as ::opcua_types::BinaryEncoder<#inner>>::byte_len(value)))
+        } else {
+            quote!(<#ty as ::opcua_types::BinaryEncoder<#ty>>::byte_len(#value))
+        }
+    }
+
+    /// Writes the binary encoding of the field's value, which is a reference, returning its size
+    fn encode(&self, value: &TokenStream2) -> TokenStream2 {
+        let ty = self.ty;
+        if self.array_element.is_some() && self.optional {
+            quote!(if (#value).is_some() { ::opcua_types::write_array(stream, #value)? } else { 0 })
+        } else if self.array_element.is_some() {
+            quote!(::opcua_types::write_array(stream, #value)?)
+        } else if let Some(inner) = self.optional_value {
+            quote!(if let Some(ref value) = *#value { <#inner as ::opcua_types::BinaryEncoder<#inner>>::encode(value, stream)? } else { 0 })
+        } else {
+            quote!(<#ty as ::opcua_types::BinaryEncoder<#ty>>::encode(#value, stream)?)
+        }
+    }
+
+    /// Reads the field's value from its binary encoding. An optional field is only present if its
+    /// bit is set in the encoding mask.
+    fn decode(&self, present: Option<TokenStream2>) -> TokenStream2 {
+        let ty = self.ty;
+        let value = if self.array_element.is_some() {
+            quote!(::opcua_types::read_array(stream, decoding_limits)?)
+        } else if let Some(inner) = self.optional_value {
+            quote!(Some(<#inner as ::opcua_types::BinaryEncoder<#inner>>::decode(stream, decoding_limits)?))
+        } else {
+            quote!(<#ty as ::opcua_types::BinaryEncoder<#ty>>::decode(stream, decoding_limits)?)
+        };
+        if let Some(present) = present {
+            quote!(if #present { #value } else { None })
+        } else {
+            value
+        }
+    }
+
+    /// The JSON encoding of the field's value, which is a reference. An absent optional value
+    /// is null.
+    fn encode_json(&self, value: &TokenStream2) -> TokenStream2 {
+        if self.array_element.is_some() {
+            quote!(::opcua_types::encode_json_array(#value, context))
+        } else if self.optional_value.is_some() {
+            quote!((#value).as_ref().map_or(::opcua_types::JsonValue::Null, |value| ::opcua_types::JsonEncoder::encode_json(value, context)))
+        } else {
+            quote!(::opcua_types::JsonEncoder::encode_json(#value, context))
+        }
+    }
+
+    /// Decodes the field's value from a JSON value
+    fn decode_json(&self, value: TokenStream2) -> TokenStream2 {
+        let ty = self.ty;
+        if self.array_element.is_some() {
+            quote!(::opcua_types::decode_json_array(#value, decoding_limits)?)
+        } else if self.optional_value.is_some() {
+            quote!(::opcua_types::decode_json_option(#value, decoding_limits)?)
+        } else {
+            quote!(<#ty as ::opcua_types::JsonDecoder>::decode_json(#value, decoding_limits)?)
+        }
+    }
+
+    /// The XML element of the field's value, which is a reference
+    fn encode_xml(&self, value: &TokenStream2) -> TokenStream2 {
+        let name = &self.name;
+        if let Some(element) = self.array_element {
+            let element_name = xml_element_name(element);
+            quote!(::opcua_types::encode_xml_array(#name, #element_name, #value))
+        } else if self.optional_value.is_some() {
+            quote!((#value).as_ref().and_then(|value| ::opcua_types::XmlEncoder::encode_xml(value, #name)))
+        } else {
+            quote!(::opcua_types::XmlEncoder::encode_xml(#value, #name))
+        }
+    }
+
+    /// Decodes the field's value from its child of the element
+    fn decode_xml(&self) -> TokenStream2 {
+        let (ty, name) = (self.ty, &self.name);
+        let child = quote!(::opcua_types::xml_child(element, #name));
+        if self.array_element.is_some() {
+            quote!(::opcua_types::decode_xml_array(#child, decoding_limits)?)
+        } else if self.optional_value.is_some() {
+            quote!(::opcua_types::decode_xml_option(#child, decoding_limits)?)
+        } else {
+            quote!(<#ty as ::opcua_types::XmlDecoder>::decode_xml(#child, decoding_limits)?)
+        }
+    }
+}
+
+fn derive_structure(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_ids = type_ids(&input.attrs)?;
+    let named = match data.fields {
+        Fields::Named(ref fields) => &fields.named,
+        _ => return Err(Error::new_spanned(ident, "UaEncodable can only be derived for a struct with named fields")),
+    };
+    let mut idents = Vec::with_capacity(named.len());
+    let mut fields = Vec::with_capacity(named.len());
+    for field in named {
+        let field_ident = field.ident.as_ref().unwrap();
+        let options = field_options(&field.attrs)?;
+        fields.push(Field::new(pascal_case(&field_ident.to_string()), &field.ty, &options)?);
+        idents.push(field_ident);
+    }
+    let values = idents.iter().map(|ident| quote!(&self.#ident)).collect::<Vec<_>>();
+
+    // Optional fields are preceded by a mask with a bit for each field, in order, that is present
+    let optional = fields.iter().zip(idents.iter()).filter(|(field, _)| field.optional).map(|(_, ident)| *ident).collect::<Vec<_>>();
+    if optional.len() > 32 {
+        return Err(Error::new_spanned(ident, "A structure cannot have more than 32 optional fields"));
+    }
+    let has_mask = !optional.is_empty();
+    let bits = (0..optional.len() as u32).map(|bit| 1u32 << bit).collect::<Vec<_>>();
+    let mut bit = 0;
+    let present = fields.iter().map(|field| if field.optional {
+        let mask = 1u32 << bit;
+        bit += 1;
+        Some(quote!(encoding_mask & #mask != 0))
+    } else {
+        None
+    }).collect::<Vec<_>>();
+
+    let byte_len = fields.iter().zip(values.iter()).map(|(field, value)| field.byte_len(value));
+    let encode = fields.iter().zip(values.iter()).map(|(field, value)| field.encode(value));
+    let decode = fields.iter().zip(present).map(|(field, present)| field.decode(present));
+    let (mask_len, encode_mask, decode_mask) = if has_mask {
+        (quote!(size += 4;),
+         quote! {
+             let mut encoding_mask = 0u32;
+             #(if self.#optional.is_some() { encoding_mask |= #bits; })*
+             size += ::opcua_types::write_u32(stream, encoding_mask)?;
+         },
+         quote!(let encoding_mask = ::opcua_types::read_u32(stream)?;))
+    } else {
+        (quote!(), quote!(), quote!())
+    };
+
+    let names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+    let encode_json = fields.iter().zip(values.iter()).map(|(field, value)| field.encode_json(value));
+    let decode_json = fields.iter().zip(names.iter()).map(|(field, name)| field.decode_json(quote!(::opcua_types::json_field(fields, #name))));
+    let encode_xml = fields.iter().zip(values.iter()).map(|(field, value)| field.encode_xml(value));
+    let decode_xml = fields.iter().map(|field| field.decode_xml());
+    let (idents, names) = (&idents, &names);
+    let (idents2, idents3, idents4) = (idents, idents, idents);
+
+    let encodable_type = encodable_type(ident, &type_ids)?;
+    Ok(quote! {
+        impl ::opcua_types::BinaryEncoder<#ident> for #ident {
+            fn byte_len(&self) -> usize {
+                let mut size = 0;
+                #mask_len
+                #(size += #byte_len;)*
+                size
+            }
+
+            #[allow(unused_variables)]
+            fn encode<S: ::std::io::Write>(&self, stream: &mut S) -> ::opcua_types::EncodingResult<usize> {
+                let mut size = 0;
+                #encode_mask
+                #(size += #encode;)*
+                Ok(size)
+            }
+
+            #[allow(unused_variables)]
+            fn decode<S: ::std::io::Read>(stream: &mut S, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                #decode_mask
+                #(let #idents = #decode;)*
+                Ok(#ident {
+                    #(#idents2,)*
+                })
+            }
+        }
+
+        impl ::opcua_types::JsonEncoder for #ident {
+            #[allow(unused_variables)]
+            fn encode_json(&self, context: &::opcua_types::JsonContext) -> ::opcua_types::JsonValue {
+                let mut fields = ::opcua_types::JsonObject::new();
+                #(::opcua_types::insert_json_field(&mut fields, #names, #encode_json);)*
+                ::opcua_types::JsonValue::Object(fields)
+            }
+        }
+
+        impl ::opcua_types::JsonDecoder for #ident {
+            #[allow(unused_variables)]
+            fn decode_json(value: &::opcua_types::JsonValue, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                let fields = ::opcua_types::json_object(value)?;
+                #(let #idents3 = #decode_json;)*
+                Ok(#ident {
+                    #(#idents4,)*
+                })
+            }
+        }
+
+        impl ::opcua_types::XmlEncoder for #ident {
+            fn encode_xml(&self, name: &str) -> Option<::opcua_types::XmlNode> {
+                let mut element = ::opcua_types::XmlNode::new(name);
+                #(element.add_child(#encode_xml);)*
+                Some(element)
+            }
+        }
+
+        impl ::opcua_types::XmlDecoder for #ident {
+            #[allow(unused_variables)]
+            fn decode_xml(element: Option<&::opcua_types::XmlNode>, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                #(let #idents2 = #decode_xml;)*
+                Ok(#ident {
+                    #(#idents3,)*
+                })
+            }
+        }
+
+        #encodable_type
+    })
+}
+
+fn derive_union(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_ids = type_ids(&input.attrs)?;
+
+    // The null union, if there is one, is the unit variant
+    let mut null = None;
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut fields = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let options = field_options(&variant.attrs)?;
+        if options.optional {
+            return Err(Error::new_spanned(variant, "A variant of a union cannot be optional"));
+        }
+        match variant.fields {
+            Fields::Unit if null.is_none() => null = Some(&variant.ident),
+            Fields::Unnamed(ref value) if value.unnamed.len() == 1 => {
+                fields.push(Field::new(variant.ident.to_string(), &value.unnamed[0].ty, &options)?);
+                let variant = &variant.ident;
+                variants.push(quote!(#ident::#variant));
+            }
+            _ => return Err(Error::new_spanned(variant, "A variant of a union must hold one value, or be the only unit variant which is the null union")),
+        }
+    }
+    let switch_fields = (1..=variants.len() as u32).collect::<Vec<_>>();
+    let value = quote!(value);
+
+    let byte_len = fields.iter().map(|field| field.byte_len(&value));
+    let encode = fields.iter().map(|field| field.encode(&value));
+    let decode = fields.iter().map(|field| field.decode(None));
+    let encode_json = fields.iter().map(|field| field.encode_json(&value));
+    let decode_json = fields.iter().map(|field| field.decode_json(quote!(value)));
+    let encode_xml = fields.iter().map(|field| field.encode_xml(&value));
+    let decode_xml = fields.iter().map(|field| field.decode_xml());
+    let (variants, switch_fields) = (&variants, &switch_fields);
+
+    let (null_byte_len, null_encode, null_decode, null_json, null_decode_json, null_xml) = if let Some(null) = null {
+        (quote!(#ident::#null => 4,),
+         quote!(#ident::#null => ::opcua_types::write_u32(stream, 0u32),),
+         quote!(0 => Ok(#ident::#null),),
+         quote!(#ident::#null => ::opcua_types::JsonValue::Null,),
+         quote!(0 => Ok(#ident::#null),),
+         quote! {
+             #ident::#null => {
+                 element.add_child(::opcua_types::XmlEncoder::encode_xml(&0u32, "SwitchField"));
+             }
+         })
+    } else {
+        (quote!(), quote!(), quote!(), quote!(), quote!(), quote!())
+    };
+
+    let encodable_type = encodable_type(ident, &type_ids)?;
+    Ok(quote! {
+        impl ::opcua_types::BinaryEncoder<#ident> for #ident {
+            fn byte_len(&self) -> usize {
+                match *self {
+                    #null_byte_len
+                    #(#variants(ref value) => 4 + #byte_len,)*
+                }
+            }
+
+            fn encode<S: ::std::io::Write>(&self, stream: &mut S) -> ::opcua_types::EncodingResult<usize> {
+                match *self {
+                    #null_encode
+                    #(#variants(ref value) => {
+                        let mut size = ::opcua_types::write_u32(stream, #switch_fields)?;
+                        size += #encode;
+                        Ok(size)
+                    })*
+                }
+            }
+
+            fn decode<S: ::std::io::Read>(stream: &mut S, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                match ::opcua_types::read_u32(stream)? {
+                    #null_decode
+                    #(#switch_fields => Ok(#variants(#decode)),)*
+                    _ => Err(::opcua_types::status_code::StatusCode::BadDecodingError),
+                }
+            }
+        }
+
+        impl ::opcua_types::JsonEncoder for #ident {
+            fn encode_json(&self, context: &::opcua_types::JsonContext) -> ::opcua_types::JsonValue {
+                match *self {
+                    #null_json
+                    #(#variants(ref value) => ::opcua_types::encode_json_union(#switch_fields, #encode_json, context),)*
+                }
+            }
+        }
+
+        impl ::opcua_types::JsonDecoder for #ident {
+            fn decode_json(value: &::opcua_types::JsonValue, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                let fields = ::opcua_types::json_object(value)?;
+                let value = ::opcua_types::json_field(fields, "Value");
+                match <u32 as ::opcua_types::JsonDecoder>::decode_json(::opcua_types::json_field(fields, "SwitchField"), decoding_limits)? {
+                    #null_decode_json
+                    #(#switch_fields => Ok(#variants(#decode_json)),)*
+                    _ => Err(::opcua_types::status_code::StatusCode::BadDecodingError),
+                }
+            }
+        }
+
+        impl ::opcua_types::XmlEncoder for #ident {
+            fn encode_xml(&self, name: &str) -> Option<::opcua_types::XmlNode> {
+                let mut element = ::opcua_types::XmlNode::new(name);
+                match *self {
+                    #null_xml
+                    #(#variants(ref value) => {
+                        element.add_child(::opcua_types::XmlEncoder::encode_xml(&#switch_fields, "SwitchField"));
+                        element.add_child(#encode_xml);
+                    })*
+                }
+                Some(element)
+            }
+        }
+
+        impl ::opcua_types::XmlDecoder for #ident {
+            fn decode_xml(element: Option<&::opcua_types::XmlNode>, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                match <u32 as ::opcua_types::XmlDecoder>::decode_xml(::opcua_types::xml_child(element, "SwitchField"), decoding_limits)? {
+                    #null_decode
+                    #(#switch_fields => Ok(#variants(#decode_xml)),)*
+                    _ => Err(::opcua_types::status_code::StatusCode::BadDecodingError),
+                }
+            }
+        }
+
+        #encodable_type
+    })
+}
+
+fn derive_enumeration(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let type_ids = type_ids(&input.attrs)?;
+    if type_ids.data_type_id.is_some() || type_ids.binary_encoding_id.is_some() || type_ids.xml_encoding_id.is_some() || type_ids.json_encoding_id.is_some() {
+        return Err(Error::new_spanned(ident, "An enumeration is encoded as an Int32 and has no encodings"));
+    }
+    let mut to_i32 = Vec::with_capacity(data.variants.len());
+    let mut to_name = Vec::with_capacity(data.variants.len());
+    let mut from_i32 = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        let name = field_options(&variant.attrs)?.rename.unwrap_or_else(|| variant.ident.to_string());
+        let variant = &variant.ident;
+        to_i32.push(quote!(#ident::#variant => #ident::#variant as i32,));
+        to_name.push(quote!(#ident::#variant => (#name, #ident::#variant as i32),));
+        // Each variant is matched by its discriminant, so the enum does not have to be Copy
+        from_i32.push(quote!(value if value == #ident::#variant as i32 => Ok(#ident::#variant),));
+    }
+    let (to_i32, to_name) = (&to_i32, &to_name);
+    let from_i32 = quote! {
+        match value {
+            #(#from_i32)*
+            _ => Err(::opcua_types::status_code::StatusCode::BadDecodingError),
+        }
+    };
+    Ok(quote! {
+        impl ::opcua_types::BinaryEncoder<#ident> for #ident {
+            fn byte_len(&self) -> usize {
+                4
+            }
+
+            fn encode<S: ::std::io::Write>(&self, stream: &mut S) -> ::opcua_types::EncodingResult<usize> {
+                // All enums are Int32
+                let value = match *self {
+                    #(#to_i32)*
+                };
+                ::opcua_types::write_i32(stream, value)
+            }
+
+            fn decode<S: ::std::io::Read>(stream: &mut S, _: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                // All enums are Int32
+                let value = ::opcua_types::read_i32(stream)?;
+                #from_i32
+            }
+        }
+
+        impl ::opcua_types::JsonEncoder for #ident {
+            fn encode_json(&self, context: &::opcua_types::JsonContext) -> ::opcua_types::JsonValue {
+                let (name, value) = match *self {
+                    #(#to_name)*
+                };
+                if context.reversible {
+                    ::opcua_types::JsonValue::from(value)
+                } else {
+                    ::opcua_types::JsonValue::from(format!("{}_{}", name, value))
+                }
+            }
+        }
+
+        impl ::opcua_types::JsonDecoder for #ident {
+            fn decode_json(value: &::opcua_types::JsonValue, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                ::opcua_types::decode_json_enum(value, decoding_limits)
+            }
+        }
+
+        impl ::opcua_types::XmlEncoder for #ident {
+            fn encode_xml(&self, element_name: &str) -> Option<::opcua_types::XmlNode> {
+                let (name, value) = match *self {
+                    #(#to_name)*
+                };
+                Some(::opcua_types::XmlNode::new_text(element_name, format!("{}_{}", name, value)))
+            }
+        }
+
+        impl ::opcua_types::XmlDecoder for #ident {
+            fn decode_xml(element: Option<&::opcua_types::XmlNode>, decoding_limits: &::opcua_types::DecodingLimits) -> ::opcua_types::EncodingResult<Self> {
+                ::opcua_types::decode_xml_enum(element, decoding_limits)
+            }
+        }
+
+        impl From<#ident> for ::opcua_types::Variant {
+            fn from(value: #ident) -> Self {
+                let value = match value {
+                    #(#to_i32)*
+                };
+                ::opcua_types::Variant::Int32(value)
+            }
+        }
+
+        impl<'a> ::std::convert::TryFrom<&'a ::opcua_types::Variant> for #ident {
+            type Error = ::opcua_types::status_code::StatusCode;
+
+            fn try_from(value: &'a ::opcua_types::Variant) -> Result<Self, Self::Error> {
+                if let ::opcua_types::Variant::Int32(value) = *value {
+                    #from_i32
+                } else {
+                    Err(::opcua_types::status_code::StatusCode::BadTypeMismatch)
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<::opcua_types::Variant> for #ident {
+            type Error = ::opcua_types::status_code::StatusCode;
+
+            fn try_from(value: ::opcua_types::Variant) -> Result<Self, Self::Error> {
+                <#ident as ::std::convert::TryFrom<&::opcua_types::Variant>>::try_from(&value)
+            }
+        }
+    })
+}
+
+/// Implements `EncodableType` and conversions with `Variant` and `ExtensionObject` for a type
+/// with node ids. A type without them can still be encoded, e.g. as the field of another type.
+fn encodable_type(ident: &Ident, type_ids: &TypeIds) -> Result<TokenStream2> {
+    let (data_type_id, binary_encoding_id) = match (&type_ids.data_type_id, &type_ids.binary_encoding_id) {
+        (Some(data_type_id), Some(binary_encoding_id)) => (data_type_id, binary_encoding_id),
+        (None, None) if type_ids.xml_encoding_id.is_none() && type_ids.json_encoding_id.is_none() => return Ok(quote!()),
+        _ => return Err(Error::new_spanned(ident, "A type with node ids needs at least a data_type_id and a binary_encoding_id")),
+    };
+    let xml_encoding_id = type_ids.xml_encoding_id.as_ref().map(|id| quote! {
+        fn xml_encoding_id() -> Option<::opcua_types::NodeId> {
+            Some(#id)
+        }
+    });
+    let json_encoding_id = type_ids.json_encoding_id.as_ref().map(|id| quote! {
+        fn json_encoding_id() -> Option<::opcua_types::NodeId> {
+            Some(#id)
+        }
+    });
+    Ok(quote! {
+        impl ::opcua_types::EncodableType for #ident {
+            fn data_type_id() -> ::opcua_types::NodeId {
+                #data_type_id
+            }
+
+            fn binary_encoding_id() -> ::opcua_types::NodeId {
+                #binary_encoding_id
+            }
+
+            #xml_encoding_id
+            #json_encoding_id
+        }
+
+        impl From<#ident> for ::opcua_types::ExtensionObject {
+            fn from(value: #ident) -> Self {
+                ::opcua_types::ExtensionObject::from_type(&value)
+            }
+        }
+
+        impl From<#ident> for ::opcua_types::Variant {
+            fn from(value: #ident) -> Self {
+                ::opcua_types::Variant::from(::opcua_types::ExtensionObject::from_type(&value))
+            }
+        }
+
+        impl<'a> ::std::convert::TryFrom<&'a ::opcua_types::ExtensionObject> for #ident {
+            type Error = ::opcua_types::status_code::StatusCode;
+
+            fn try_from(value: &'a ::opcua_types::ExtensionObject) -> Result<Self, Self::Error> {
+                value.decode_type(&::opcua_types::DecodingLimits::default())
+            }
+        }
+
+        impl<'a> ::std::convert::TryFrom<&'a ::opcua_types::Variant> for #ident {
+            type Error = ::opcua_types::status_code::StatusCode;
+
+            fn try_from(value: &'a ::opcua_types::Variant) -> Result<Self, Self::Error> {
+                if let ::opcua_types::Variant::ExtensionObject(ref value) = *value {
+                    value.decode_type(&::opcua_types::DecodingLimits::default())
+                } else {
+                    Err(::opcua_types::status_code::StatusCode::BadTypeMismatch)
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<::opcua_types::Variant> for #ident {
+            type Error = ::opcua_types::status_code::StatusCode;
+
+            fn try_from(value: ::opcua_types::Variant) -> Result<Self, Self::Error> {
+                <#ident as ::std::convert::TryFrom<&::opcua_types::Variant>>::try_from(&value)
+            }
+        }
+    })
+}
+
+/// Returns the `#[opcua(...)]` items of the attributes
+fn opcua_attributes(attrs: &[Attribute]) -> Result<Vec<Meta>> {
+    let mut items = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("opcua")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(meta) => items.push(meta),
+                    NestedMeta::Literal(lit) => return Err(Error::new_spanned(lit, "Expected an opcua attribute item")),
+                }
+            },
+            meta => return Err(Error::new_spanned(meta, "Expected #[opcua(...)]")),
+        }
+    }
+    Ok(items)
+}
+
+fn type_ids(attrs: &[Attribute]) -> Result<TypeIds> {
+    let mut type_ids = TypeIds::default();
+    for meta in opcua_attributes(attrs)? {
+        let (id, value) = match meta {
+            Meta::NameValue(ref value) => match value.lit {
+                Lit::Str(ref lit) => (&value.ident, node_id(lit)?),
+                _ => return Err(Error::new_spanned(&value.lit, "Expected a node id string")),
+            },
+            _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of a type")),
+        };
+        let type_id = if id == "data_type_id" {
+            &mut type_ids.data_type_id
+        } else if id == "binary_encoding_id" {
+            &mut type_ids.binary_encoding_id
+        } else if id == "xml_encoding_id" {
+            &mut type_ids.xml_encoding_id
+        } else if id == "json_encoding_id" {
+            &mut type_ids.json_encoding_id
+        } else {
+            return Err(Error::new_spanned(id, "Unknown opcua attribute of a type"));
+        };
+        *type_id = Some(value);
+    }
+    Ok(type_ids)
+}
+
+fn field_options(attrs: &[Attribute]) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for meta in opcua_attributes(attrs)? {
+        match meta {
+            Meta::Word(ref ident) if ident == "optional" => options.optional = true,
+            Meta::NameValue(ref value) if value.ident == "rename" => match value.lit {
+                Lit::Str(ref lit) => options.rename = Some(lit.value()),
+                _ => return Err(Error::new_spanned(&value.lit, "Expected a name string")),
+            },
+            _ => return Err(Error::new_spanned(meta, "Unknown opcua attribute of a field")),
+        }
+    }
+    Ok(options)
+}
+
+/// Parses a numeric or string node id, e.g. "ns=2;i=5001" or "ns=2;s=Measurement", into the
+/// expression that creates it. The namespace is 0 if it is omitted.
+fn node_id(lit: &LitStr) -> Result<TokenStream2> {
+    let value = lit.value();
+    let (namespace, identifier) = if let Some(rest) = value.strip_prefix("ns=") {
+        let pos = rest.find(';').ok_or_else(|| Error::new_spanned(lit, "Expected a node id such as \"ns=2;i=5001\""))?;
+        let namespace = rest[..pos].parse::<u16>().map_err(|_| Error::new_spanned(lit, "Invalid namespace index"))?;
+        (namespace, &rest[pos + 1..])
+    } else {
+        (0, &value[..])
+    };
+    if let Some(identifier) = identifier.strip_prefix("i=") {
+        let identifier = identifier.parse::<u32>().map_err(|_| Error::new_spanned(lit, "Invalid numeric identifier"))?;
+        Ok(quote!(::opcua_types::NodeId::new(#namespace, #identifier)))
+    } else if let Some(identifier) = identifier.strip_prefix("s=") {
+        Ok(quote!(::opcua_types::NodeId::new(#namespace, #identifier)))
+    } else {
+        Err(Error::new_spanned(lit, "Expected a numeric or string node id such as \"ns=2;i=5001\""))
+    }
+}
+
+/// Returns the type held by an `Option`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Option")
+}
+
+/// Returns the type argument of a generic type with the name, e.g. `T` of `Vec<T>`
+fn generic_inner<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    if let Type::Path(ref path) = *ty {
+        let segment = path.path.segments.iter().last()?;
+        if segment.ident != name {
+            return None;
+        }
+        if let PathArguments::AngleBracketed(ref arguments) = segment.arguments {
+            if let Some(GenericArgument::Type(ref ty)) = arguments.args.iter().next() {
+                return Some(ty);
+            }
+        }
+    }
+    None
+}
+
+/// Returns the name of the elements of an array of the type in the XML encoding, which is the
+/// name of the built-in type or else the name of the Rust type
+fn xml_element_name(ty: &Type) -> String {
+    let name = if let Type::Path(ref path) = *ty {
+        path.path.segments.iter().last().map(|segment| segment.ident.to_string()).unwrap_or_default()
+    } else {
+        String::new()
+    };
+    let built_in = match name.as_ref() {
+        "bool" => "Boolean",
+        "i8" => "SByte",
+        "u8" => "Byte",
+        "i16" => "Int16",
+        "u16" => "UInt16",
+        "i32" => "Int32",
+        "u32" => "UInt32",
+        "i64" => "Int64",
+        "u64" => "UInt64",
+        "f32" => "Float",
+        "f64" => "Double",
+        "UAString" => "String",
+        _ => return name,
+    };
+    built_in.to_string()
+}
+
+/// Converts a snake case field name to the Pascal case of OPC UA, e.g. `node_id` to `NodeId`
+fn pascal_case(name: &str) -> String {
+    name.split('_').filter(|part| !part.is_empty()).map(|part| {
+        let mut chars = part.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    }).collect()
+}
+
@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::io::Cursor;
+
+use serde_json::json;
+
+use opcua_types::{*, status_code::StatusCode};
+use opcua_types_derive::UaEncodable;
+
+#[derive(Debug, Clone, PartialEq, UaEncodable)]
+#[opcua(data_type_id = "ns=2;i=3001", binary_encoding_id = "ns=2;i=5001", xml_encoding_id = "ns=2;i=5002")]
+struct Measurement {
+    node_id: NodeId,
+    value: f64,
+    #[opcua(rename = "EngineeringUnits")]
+    units: UAString,
+    samples: Option<Vec<i32>>,
+}
+
+#[derive(Debug, Clone, PartialEq, UaEncodable)]
+#[opcua(data_type_id = "ns=2;s=Settings", binary_encoding_id = "ns=2;s=Settings_Binary")]
+struct Settings {
+    name: UAString,
+    #[opcua(optional)]
+    limit: Option<u32>,
+    #[opcua(optional)]
+    tags: Option<Vec<UAString>>,
+    #[opcua(optional)]
+    mode: Option<Mode>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, UaEncodable)]
+enum Mode {
+    Off = 0,
+    Manual = 1,
+    Automatic = 4,
+}
+
+#[derive(Debug, Clone, PartialEq, UaEncodable)]
+#[opcua(data_type_id = "ns=2;i=3002", binary_encoding_id = "ns=2;i=5003")]
+enum Setpoint {
+    Null,
+    Value(f64),
+    Named(UAString),
+    Measurement(Measurement),
+}
+
+/// A structure without node ids, which is only encoded as a field of other structures
+#[derive(Debug, Clone, PartialEq, UaEncodable)]
+struct Range {
+    low: f64,
+    high: f64,
+}
+
+fn measurement() -> Measurement {
+    Measurement {
+        node_id: NodeId::new(2, "Pump"),
+        value: 12.5,
+        units: UAString::from("bar"),
+        samples: Some(vec![1, 2, 3]),
+    }
+}
+
+fn binary_round_trip<T>(value: T) -> Vec<u8> where T: BinaryEncoder<T> + Debug + PartialEq {
+    let mut stream = Cursor::new(Vec::new());
+    let size = value.encode(&mut stream).unwrap();
+    assert_eq!(size, value.byte_len());
+    let bytes = stream.into_inner();
+    assert_eq!(bytes.len(), size);
+    let decoded = T::decode(&mut Cursor::new(bytes.clone()), &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, value);
+    bytes
+}
+
+fn json_round_trip<T>(value: T, expected_json: JsonValue) where T: JsonEncoder + JsonDecoder + Debug + PartialEq {
+    let json = value.encode_json(&JsonContext::reversible());
+    assert_eq!(json, expected_json);
+    assert_eq!(T::decode_json(&json, &DecodingLimits::default()).unwrap(), value);
+}
+
+fn xml_round_trip<T>(value: T, expected_xml: &str) where T: XmlEncoder + XmlDecoder + Debug + PartialEq {
+    let xml = value.to_xml_string("Value");
+    assert_eq!(xml, expected_xml);
+    assert_eq!(T::from_xml_str(&xml, &DecodingLimits::default()).unwrap(), value);
+}
+
+#[test]
+fn structure() {
+    let bytes = binary_round_trip(measurement());
+    // The fields are encoded in order with nothing before them
+    assert_eq!(&bytes[..7], &[0x03, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00]);
+
+    json_round_trip(measurement(), json!({
+        "NodeId": {"Id": "Pump", "IdType": 1, "Namespace": 2},
+        "Value": 12.5,
+        "EngineeringUnits": "bar",
+        "Samples": [1, 2, 3],
+    }));
+    xml_round_trip(Range { low: -1.0, high: 1.0 }, "<Value><Low>-1</Low><High>1</High></Value>");
+
+    let range = Range { low: 0.0, high: 100.0 };
+    binary_round_trip(range.clone());
+    assert_eq!(range.byte_len(), 16);
+}
+
+#[test]
+fn structure_with_optional_fields() {
+    let settings = Settings {
+        name: UAString::from("Pump"),
+        limit: None,
+        tags: None,
+        mode: None,
+    };
+    let bytes = binary_round_trip(settings.clone());
+    // The encoding mask has no bits set and absent fields take no space
+    assert_eq!(&bytes[..4], &[0, 0, 0, 0]);
+    assert_eq!(bytes.len(), 4 + 4 + 4);
+    json_round_trip(settings.clone(), json!({"Name": "Pump"}));
+    xml_round_trip(settings, "<Value><Name>Pump</Name></Value>");
+
+    let settings = Settings {
+        name: UAString::from("Pump"),
+        limit: Some(10),
+        tags: Some(vec![UAString::from("a")]),
+        mode: Some(Mode::Automatic),
+    };
+    let bytes = binary_round_trip(settings.clone());
+    assert_eq!(&bytes[..4], &[0x07, 0, 0, 0]);
+    json_round_trip(settings.clone(), json!({"Name": "Pump", "Limit": 10, "Tags": ["a"], "Mode": 4}));
+    xml_round_trip(settings, "<Value><Name>Pump</Name><Limit>10</Limit><Tags><String>a</String></Tags><Mode>Automatic_4</Mode></Value>");
+
+    // Only the second optional field
+    let settings = Settings {
+        name: UAString::null(),
+        limit: None,
+        tags: Some(Vec::new()),
+        mode: None,
+    };
+    let bytes = binary_round_trip(settings);
+    assert_eq!(&bytes[..4], &[0x02, 0, 0, 0]);
+}
+
+#[test]
+fn union() {
+    let bytes = binary_round_trip(Setpoint::Null);
+    assert_eq!(bytes, vec![0, 0, 0, 0]);
+    let bytes = binary_round_trip(Setpoint::Named(UAString::from("max")));
+    assert_eq!(&bytes[..4], &[2, 0, 0, 0]);
+    binary_round_trip(Setpoint::Value(1.5));
+    binary_round_trip(Setpoint::Measurement(measurement()));
+
+    json_round_trip(Setpoint::Null, JsonValue::Null);
+    json_round_trip(Setpoint::Value(1.5), json!({"SwitchField": 1, "Value": 1.5}));
+    assert_eq!(Setpoint::Value(1.5).encode_json(&JsonContext::non_reversible(Vec::new(), Vec::new())), json!(1.5));
+
+    xml_round_trip(Setpoint::Null, "<Value><SwitchField>0</SwitchField></Value>");
+    xml_round_trip(Setpoint::Named(UAString::from("max")), "<Value><SwitchField>2</SwitchField><Named>max</Named></Value>");
+
+    // A switch field with no field is invalid
+    let mut stream = Cursor::new(vec![5u8, 0, 0, 0]);
+    assert_eq!(Setpoint::decode(&mut stream, &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn enumeration() {
+    let bytes = binary_round_trip(Mode::Automatic);
+    assert_eq!(bytes, vec![4, 0, 0, 0]);
+    let mut stream = Cursor::new(vec![2u8, 0, 0, 0]);
+    assert_eq!(Mode::decode(&mut stream, &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+
+    json_round_trip(Mode::Manual, json!(1));
+    assert_eq!(Mode::Manual.encode_json(&JsonContext::non_reversible(Vec::new(), Vec::new())), json!("Manual_1"));
+    xml_round_trip(Mode::Off, "<Value>Off_0</Value>");
+
+    let variant = Variant::from(Mode::Automatic);
+    assert_eq!(variant, Variant::Int32(4));
+    assert_eq!(Mode::try_from(&variant).unwrap(), Mode::Automatic);
+    assert_eq!(Mode::try_from(Variant::Int32(3)).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(Mode::try_from(Variant::from("Off")).unwrap_err(), StatusCode::BadTypeMismatch);
+}
+
+#[test]
+fn encodable_type() {
+    assert_eq!(Measurement::data_type_id(), NodeId::new(2, 3001u32));
+    assert_eq!(Measurement::binary_encoding_id(), NodeId::new(2, 5001u32));
+    assert_eq!(Measurement::xml_encoding_id(), Some(NodeId::new(2, 5002u32)));
+    assert_eq!(Measurement::json_encoding_id(), None);
+    assert_eq!(Settings::binary_encoding_id(), NodeId::new(2, "Settings_Binary"));
+
+    // Values are held by variants as extension objects of the binary encoding
+    let variant = Variant::from(measurement());
+    if let Variant::ExtensionObject(ref extension_object) = variant {
+        assert_eq!(extension_object.node_id, NodeId::new(2, 5001u32));
+        assert_eq!(Measurement::try_from(extension_object.as_ref()).unwrap(), measurement());
+    } else {
+        panic!("Expected an extension object");
+    }
+    assert_eq!(Measurement::try_from(&variant).unwrap(), measurement());
+    assert_eq!(Setpoint::try_from(Variant::from(Setpoint::Value(2.0))).unwrap(), Setpoint::Value(2.0));
+    let extension_object = ExtensionObject::from(Setpoint::Null);
+    assert_eq!(extension_object.decode_type::<Setpoint>(&DecodingLimits::default()).unwrap(), Setpoint::Null);
+
+    // The wrong type
+    assert_eq!(Setpoint::try_from(&variant).unwrap_err(), StatusCode::BadTypeMismatch);
+    assert_eq!(Measurement::try_from(Variant::from(1.0f64)).unwrap_err(), StatusCode::BadTypeMismatch);
+}
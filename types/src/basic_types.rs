@@ -5,7 +5,7 @@ use std::{
     io::{Read, Write},
 };
 
-use crate::{encoding::*, string::*};
+use crate::{encoding::*, json::*, xml::*, string::*};
 
 // OPC UA Part 6 - Mappings 1.03 Specification
 
@@ -226,6 +226,57 @@ impl BinaryEncoder<QualifiedName> for QualifiedName {
     }
 }
 
+impl JsonEncoder for QualifiedName {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            let mut fields = JsonObject::new();
+            insert_json_field(&mut fields, "Name", self.name.encode_json(context));
+            if self.namespace_index != 0 {
+                fields.insert("Uri".to_string(), context.encode_namespace(self.namespace_index));
+            }
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for QualifiedName {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(QualifiedName {
+            namespace_index: u16::decode_json(json_field(fields, "Uri"), decoding_limits)?,
+            name: UAString::decode_json(json_field(fields, "Name"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for QualifiedName {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        if self.is_null() {
+            None
+        } else {
+            let mut element = XmlNode::new(name);
+            element.add_child(self.namespace_index.encode_xml("NamespaceIndex"));
+            element.add_child(self.name.encode_xml("Name"));
+            Some(element)
+        }
+    }
+}
+
+impl XmlDecoder for QualifiedName {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            Ok(QualifiedName {
+                namespace_index: u16::decode_xml(element.child("NamespaceIndex"), decoding_limits)?,
+                name: UAString::decode_xml(element.child("Name"), decoding_limits)?,
+            })
+        } else {
+            Ok(QualifiedName::null())
+        }
+    }
+}
+
 impl QualifiedName {
     pub fn new(namespace_index: u16, name: &str) -> QualifiedName {
         QualifiedName {
@@ -315,6 +366,58 @@ impl BinaryEncoder<LocalizedText> for LocalizedText {
     }
 }
 
+impl JsonEncoder for LocalizedText {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if !context.reversible {
+            // The non-reversible form is just the text
+            self.text.encode_json(context)
+        } else if self.locale.is_null() && self.text.is_null() {
+            JsonValue::Null
+        } else {
+            let mut fields = JsonObject::new();
+            insert_json_field(&mut fields, "Locale", self.locale.encode_json(context));
+            insert_json_field(&mut fields, "Text", self.text.encode_json(context));
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for LocalizedText {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(LocalizedText {
+            locale: UAString::decode_json(json_field(fields, "Locale"), decoding_limits)?,
+            text: UAString::decode_json(json_field(fields, "Text"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for LocalizedText {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        if self.locale.is_null() && self.text.is_null() {
+            None
+        } else {
+            let mut element = XmlNode::new(name);
+            element.add_child(self.locale.encode_xml("Locale"));
+            element.add_child(self.text.encode_xml("Text"));
+            Some(element)
+        }
+    }
+}
+
+impl XmlDecoder for LocalizedText {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            Ok(LocalizedText {
+                locale: UAString::decode_xml(element.child("Locale"), decoding_limits)?,
+                text: UAString::decode_xml(element.child("Text"), decoding_limits)?,
+            })
+        } else {
+            Ok(LocalizedText::null())
+        }
+    }
+}
+
 impl LocalizedText {
     pub fn new(locale: &str, text: &str) -> LocalizedText {
         LocalizedText {
@@ -6,6 +6,8 @@ use base64;
 
 use crate::{
     encoding::{write_i32, BinaryEncoder, EncodingResult, DecodingLimits, process_encode_io_result, process_decode_io_result},
+    json::{JsonEncoder, JsonDecoder, JsonContext, JsonValue, json_str},
+    xml::{XmlEncoder, XmlDecoder, XmlNode},
     status_codes::StatusCode
 };
 
@@ -64,6 +66,70 @@ impl BinaryEncoder<ByteString> for ByteString {
     }
 }
 
+impl JsonEncoder for ByteString {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        // Byte strings are Base64 encoded strings
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            JsonValue::from(self.as_base64())
+        }
+    }
+}
+
+impl JsonDecoder for ByteString {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = json_str(value)? {
+            let value = ByteString::from_base64(value).ok_or_else(|| {
+                error!("Byte string is not valid Base64");
+                StatusCode::BadDecodingError
+            })?;
+            let len = value.as_ref().len();
+            if len > decoding_limits.max_byte_string_length as usize {
+                error!("Byte string length {} exceeds decoding limit {}", len, decoding_limits.max_byte_string_length);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                Ok(value)
+            }
+        } else {
+            Ok(ByteString::null())
+        }
+    }
+}
+
+impl XmlEncoder for ByteString {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        // Byte strings are Base64 encoded text
+        if self.is_null() {
+            None
+        } else {
+            Some(XmlNode::new_text(name, self.as_base64()))
+        }
+    }
+}
+
+impl XmlDecoder for ByteString {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            // Base64 text may be broken over lines
+            let text = element.text.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+            let value = ByteString::from_base64(&text).ok_or_else(|| {
+                error!("Byte string is not valid Base64");
+                StatusCode::BadDecodingError
+            })?;
+            let len = value.as_ref().len();
+            if len > decoding_limits.max_byte_string_length as usize {
+                error!("Byte string length {} exceeds decoding limit {}", len, decoding_limits.max_byte_string_length);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                Ok(value)
+            }
+        } else {
+            Ok(ByteString::null())
+        }
+    }
+}
+
 impl<'a, T> From<&'a T> for ByteString where T: AsRef<[u8]> + ?Sized {
     fn from(value: &'a T) -> Self {
         Self::from(value.as_ref().to_vec())
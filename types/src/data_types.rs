@@ -134,3 +134,8 @@ impl BinaryEncoder<MonitoringMode> for MonitoringMode {
         }
     }
 }
+
+json_enum_impl!(MessageSecurityMode);
+json_enum_impl!(MonitoringMode);
+xml_enum_impl!(MessageSecurityMode);
+xml_enum_impl!(MonitoringMode);
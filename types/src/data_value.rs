@@ -4,6 +4,8 @@ use std::io::{Read, Write};
 
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     date_time::*,
     variant::Variant,
     status_codes::StatusCode,
@@ -156,6 +158,79 @@ impl BinaryEncoder<DataValue> for DataValue {
     }
 }
 
+impl JsonEncoder for DataValue {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_option(&mut fields, "Value", &self.value, context);
+        // A Good status is omitted. The reversible form keeps every bit of the status.
+        if let Some(status) = self.status {
+            if status != 0 {
+                let status = if context.reversible {
+                    JsonValue::from(status)
+                } else {
+                    StatusCode::from_bits_truncate(status).encode_json(context)
+                };
+                fields.insert("Status".to_string(), status);
+            }
+        }
+        insert_json_option(&mut fields, "SourceTimestamp", &self.source_timestamp, context);
+        insert_json_option(&mut fields, "SourcePicoseconds", &self.source_picoseconds, context);
+        insert_json_option(&mut fields, "ServerTimestamp", &self.server_timestamp, context);
+        insert_json_option(&mut fields, "ServerPicoseconds", &self.server_picoseconds, context);
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DataValue {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(DataValue {
+            value: decode_json_option(json_field(fields, "Value"), decoding_limits)?,
+            status: decode_json_option(json_field(fields, "Status"), decoding_limits)?,
+            source_timestamp: decode_json_option(json_field(fields, "SourceTimestamp"), decoding_limits)?,
+            source_picoseconds: decode_json_option(json_field(fields, "SourcePicoseconds"), decoding_limits)?,
+            server_timestamp: decode_json_option(json_field(fields, "ServerTimestamp"), decoding_limits)?,
+            server_picoseconds: decode_json_option(json_field(fields, "ServerPicoseconds"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for DataValue {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.value.as_ref().and_then(|v| v.encode_xml("Value")));
+        // A Good status is omitted
+        if let Some(status) = self.status {
+            if status != 0 {
+                element.add_child(StatusCode::from_bits_truncate(status).encode_xml("StatusCode"));
+            }
+        }
+        element.add_child(self.source_timestamp.as_ref().and_then(|v| v.encode_xml("SourceTimestamp")));
+        element.add_child(self.source_picoseconds.and_then(|v| v.encode_xml("SourcePicoseconds")));
+        element.add_child(self.server_timestamp.as_ref().and_then(|v| v.encode_xml("ServerTimestamp")));
+        element.add_child(self.server_picoseconds.and_then(|v| v.encode_xml("ServerPicoseconds")));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DataValue {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            let status: Option<StatusCode> = decode_xml_option(element.child("StatusCode"), decoding_limits)?;
+            Ok(DataValue {
+                value: decode_xml_option(element.child("Value"), decoding_limits)?,
+                status: status.map(|status| status.bits()),
+                source_timestamp: decode_xml_option(element.child("SourceTimestamp"), decoding_limits)?,
+                source_picoseconds: decode_xml_option(element.child("SourcePicoseconds"), decoding_limits)?,
+                server_timestamp: decode_xml_option(element.child("ServerTimestamp"), decoding_limits)?,
+                server_picoseconds: decode_xml_option(element.child("ServerPicoseconds"), decoding_limits)?,
+            })
+        } else {
+            Ok(DataValue::null())
+        }
+    }
+}
+
 impl From<Variant> for DataValue {
     fn from(v: Variant) -> Self {
         DataValue::new(v)
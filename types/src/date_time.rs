@@ -2,10 +2,15 @@
 
 use std::io::{Read, Write};
 
-use chrono::{self, Utc, TimeZone, Datelike, Timelike};
+use chrono::{self, Utc, TimeZone, Datelike, Timelike, SecondsFormat};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::encoding::*;
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    status_codes::StatusCode,
+};
 
 const NANOS_PER_SECOND: i64 = 1_000_000_000;
 const NANOS_PER_TICK: i64 = 100;
@@ -61,6 +66,40 @@ impl BinaryEncoder<DateTime> for DateTime {
     }
 }
 
+/// DateTime encoded as an ISO 8601 / RFC 3339 string in UTC, e.g. "2018-11-05T08:21:44.123Z"
+impl JsonEncoder for DateTime {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        JsonValue::from(self.as_chrono().to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl JsonDecoder for DateTime {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = json_str(value)? {
+            Self::parse_iso8601(value)
+        } else {
+            Ok(DateTime::default())
+        }
+    }
+}
+
+/// DateTime encoded as an XML schema dateTime in UTC
+impl XmlEncoder for DateTime {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        Some(XmlNode::new_text(name, self.as_chrono().to_rfc3339_opts(SecondsFormat::AutoSi, true)))
+    }
+}
+
+impl XmlDecoder for DateTime {
+    fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = xml_text(element) {
+            Self::parse_iso8601(value)
+        } else {
+            Ok(DateTime::default())
+        }
+    }
+}
+
 impl Default for DateTime {
     fn default() -> Self {
         DateTime::epoch()
@@ -216,6 +255,22 @@ impl DateTime {
         self.date_time
     }
 
+    /// Parses an ISO 8601 / RFC 3339 date time. Values outside of the OPC UA range are clamped to
+    /// it.
+    fn parse_iso8601(value: &str) -> EncodingResult<DateTime> {
+        let date_time = chrono::DateTime::parse_from_rfc3339(value).map_err(|_| {
+            error!("Date time {} is not in ISO 8601 format", value);
+            StatusCode::BadDecodingError
+        })?.with_timezone(&Utc);
+        if date_time < Self::epoch_chrono() {
+            Ok(Self::epoch())
+        } else if date_time > Self::endtimes_chrono() {
+            Ok(Self::endtimes())
+        } else {
+            Ok(DateTime::from(date_time))
+        }
+    }
+
     /// The OPC UA epoch - Jan 1 1601 00:00:00
     fn epoch_chrono() -> UtcDateTime {
         Utc.ymd(MIN_YEAR as i32, 1, 1).and_hms(0, 0, 0)
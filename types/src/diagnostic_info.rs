@@ -4,6 +4,8 @@ use std::io::{Read, Write};
 
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     status_codes::StatusCode,
     string::UAString,
 };
@@ -169,6 +171,75 @@ impl BinaryEncoder<DiagnosticInfo> for DiagnosticInfo {
     }
 }
 
+impl JsonEncoder for DiagnosticInfo {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_option(&mut fields, "SymbolicId", &self.symbolic_id, context);
+        insert_json_option(&mut fields, "NamespaceUri", &self.namespace_uri, context);
+        insert_json_option(&mut fields, "Locale", &self.locale, context);
+        insert_json_option(&mut fields, "LocalizedText", &self.localized_text, context);
+        insert_json_option(&mut fields, "AdditionalInfo", &self.additional_info, context);
+        insert_json_option(&mut fields, "InnerStatusCode", &self.inner_status_code, context);
+        insert_json_option(&mut fields, "InnerDiagnosticInfo", &self.inner_diagnostic_info, context);
+        if fields.is_empty() {
+            JsonValue::Null
+        } else {
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for DiagnosticInfo {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(DiagnosticInfo {
+            symbolic_id: decode_json_option(json_field(fields, "SymbolicId"), decoding_limits)?,
+            namespace_uri: decode_json_option(json_field(fields, "NamespaceUri"), decoding_limits)?,
+            locale: decode_json_option(json_field(fields, "Locale"), decoding_limits)?,
+            localized_text: decode_json_option(json_field(fields, "LocalizedText"), decoding_limits)?,
+            additional_info: decode_json_option(json_field(fields, "AdditionalInfo"), decoding_limits)?,
+            inner_status_code: decode_json_option(json_field(fields, "InnerStatusCode"), decoding_limits)?,
+            inner_diagnostic_info: decode_json_option(json_field(fields, "InnerDiagnosticInfo"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for DiagnosticInfo {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.symbolic_id.and_then(|v| v.encode_xml("SymbolicId")));
+        element.add_child(self.namespace_uri.and_then(|v| v.encode_xml("NamespaceUri")));
+        element.add_child(self.locale.and_then(|v| v.encode_xml("Locale")));
+        element.add_child(self.localized_text.and_then(|v| v.encode_xml("LocalizedText")));
+        element.add_child(self.additional_info.as_ref().and_then(|v| v.encode_xml("AdditionalInfo")));
+        element.add_child(self.inner_status_code.and_then(|v| v.encode_xml("InnerStatusCode")));
+        element.add_child(self.inner_diagnostic_info.as_ref().and_then(|v| v.encode_xml("InnerDiagnosticInfo")));
+        if element.children.is_empty() {
+            None
+        } else {
+            Some(element)
+        }
+    }
+}
+
+impl XmlDecoder for DiagnosticInfo {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            Ok(DiagnosticInfo {
+                symbolic_id: decode_xml_option(element.child("SymbolicId"), decoding_limits)?,
+                namespace_uri: decode_xml_option(element.child("NamespaceUri"), decoding_limits)?,
+                locale: decode_xml_option(element.child("Locale"), decoding_limits)?,
+                localized_text: decode_xml_option(element.child("LocalizedText"), decoding_limits)?,
+                additional_info: decode_xml_option(element.child("AdditionalInfo"), decoding_limits)?,
+                inner_status_code: decode_xml_option(element.child("InnerStatusCode"), decoding_limits)?,
+                inner_diagnostic_info: decode_xml_option(element.child("InnerDiagnosticInfo"), decoding_limits)?,
+            })
+        } else {
+            Ok(DiagnosticInfo::default())
+        }
+    }
+}
+
 impl Default for DiagnosticInfo {
     fn default() -> Self {
         DiagnosticInfo {
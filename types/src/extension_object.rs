@@ -4,9 +4,13 @@ use std::io::{Read, Write, Cursor};
 
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     string::XmlElement,
+    basic_types::QualifiedName,
     node_id::NodeId,
     byte_string::ByteString,
+    service_types,
     status_codes::StatusCode,
 };
 
@@ -21,6 +25,62 @@ pub enum ExtensionObjectEncoding {
     XmlElement(XmlElement),
 }
 
+/// The encodings that a client may ask the value of a structure to be returned with, identified
+/// by the browse names of the encoding objects of its data type.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Serialize, Deserialize)]
+pub enum DataEncoding {
+    /// The "Default Binary" encoding
+    Binary,
+    /// The "Default XML" encoding
+    Xml,
+    /// The "Default JSON" encoding
+    Json,
+}
+
+impl DataEncoding {
+    /// Returns the encoding with the browse name, or `None` for a null or empty browse name which
+    /// means the value should be returned as it is. Any other browse name is invalid.
+    pub fn from_browse_name(browse_name: &QualifiedName) -> Result<Option<DataEncoding>, StatusCode> {
+        if browse_name.namespace_index == 0 && browse_name.name.is_empty() {
+            Ok(None)
+        } else if browse_name.namespace_index != 0 {
+            Err(StatusCode::BadDataEncodingInvalid)
+        } else {
+            match browse_name.name.as_ref() {
+                "Default Binary" => Ok(Some(DataEncoding::Binary)),
+                "Default XML" => Ok(Some(DataEncoding::Xml)),
+                "Default JSON" => Ok(Some(DataEncoding::Json)),
+                _ => Err(StatusCode::BadDataEncodingInvalid)
+            }
+        }
+    }
+
+    /// Returns the browse name of the encoding objects for this encoding
+    pub fn browse_name(&self) -> QualifiedName {
+        let name = match *self {
+            DataEncoding::Binary => "Default Binary",
+            DataEncoding::Xml => "Default XML",
+            DataEncoding::Json => "Default JSON",
+        };
+        QualifiedName::new(0, name)
+    }
+}
+
+/// Implemented by structures of a data type with encodings of its own, i.e. custom structures
+/// rather than the generated types of namespace 0. The node ids identify the structure when it is
+/// held by an extension object. `#[derive(UaEncodable)]` in `opcua-types-derive` implements this
+/// from the node ids given to the derive.
+pub trait EncodableType: BinaryEncoder<Self> + Sized {
+    /// The node id of the data type of the structure
+    fn data_type_id() -> NodeId;
+    /// The node id of the "Default Binary" encoding of the structure
+    fn binary_encoding_id() -> NodeId;
+    /// The node id of the "Default XML" encoding of the structure, if it has one
+    fn xml_encoding_id() -> Option<NodeId> { None }
+    /// The node id of the "Default JSON" encoding of the structure, if it has one
+    fn json_encoding_id() -> Option<NodeId> { None }
+}
+
 /// An extension object holds a serialized object identified by its node id.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct ExtensionObject {
@@ -100,6 +160,144 @@ impl BinaryEncoder<ExtensionObject> for ExtensionObject {
     }
 }
 
+/// The body of an object of a generated type is encoded as a JSON object and its type id is the
+/// data type. Other binary or XML bodies are encoded as a Base64 or XML string with their encoding.
+/// The non-reversible form is just the body.
+impl JsonEncoder for ExtensionObject {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() && self.is_empty() {
+            return JsonValue::Null;
+        }
+        let (type_id, encoding, body) = match self.body {
+            ExtensionObjectEncoding::None => (self.node_id.clone(), 0u8, JsonValue::Null),
+            ExtensionObjectEncoding::ByteString(ref value) => {
+                let json_body = self.node_id.as_object_id().ok().and_then(|object_id| {
+                    service_types::binary_body_to_json(object_id, self, &DecodingLimits::default(), context)
+                });
+                match json_body {
+                    Some(Ok((data_type_id, body))) => (data_type_id.into(), 0u8, body),
+                    _ => (self.node_id.clone(), 1u8, value.encode_json(context))
+                }
+            }
+            ExtensionObjectEncoding::XmlElement(ref value) => (self.node_id.clone(), 2u8, value.encode_json(context)),
+        };
+        if context.reversible {
+            let mut fields = JsonObject::new();
+            insert_json_field(&mut fields, "TypeId", type_id.encode_json(context));
+            if encoding != 0 {
+                fields.insert("Encoding".to_string(), JsonValue::from(encoding));
+            }
+            insert_json_field(&mut fields, "Body", body);
+            JsonValue::Object(fields)
+        } else {
+            body
+        }
+    }
+}
+
+impl JsonDecoder for ExtensionObject {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "TypeId"), decoding_limits)?;
+        let body = json_field(fields, "Body");
+        match u8::decode_json(json_field(fields, "Encoding"), decoding_limits)? {
+            _ if body.is_null() => Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::None,
+            }),
+            0 => {
+                // A JSON body can only be decoded for a generated type
+                let data_type_id = node_id.as_data_type_id().map_err(|_| {
+                    error!("Extension object type {} is not a known data type", node_id);
+                    StatusCode::BadDecodingError
+                })?;
+                service_types::json_body_to_binary(data_type_id, body, decoding_limits).unwrap_or_else(|| {
+                    error!("Extension object type {} has no JSON decoder", node_id);
+                    Err(StatusCode::BadDecodingError)
+                })
+            }
+            1 => Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::ByteString(ByteString::decode_json(body, decoding_limits)?),
+            }),
+            2 => Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::XmlElement(XmlElement::decode_json(body, decoding_limits)?),
+            }),
+            encoding => {
+                error!("Extension object has an invalid encoding {}", encoding);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+/// The body of an object of a generated type is encoded as the XML element of the structure and
+/// its type id is the XML encoding of the data type. Other binary bodies are encoded as a
+/// `ByteString` element and XML bodies are held as they are.
+impl XmlEncoder for ExtensionObject {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        if self.is_null() && self.is_empty() {
+            return None;
+        }
+        let xml_object = self.to_xml_encoding(&DecodingLimits::default()).ok();
+        let extension_object = xml_object.as_ref().unwrap_or(self);
+        let mut element = XmlNode::new(name);
+        element.add_child(extension_object.node_id.encode_xml("TypeId"));
+        match extension_object.body {
+            ExtensionObjectEncoding::None => {}
+            ExtensionObjectEncoding::ByteString(ref value) => {
+                let mut body = XmlNode::new("Body");
+                body.add_child(value.encode_xml("ByteString"));
+                element.add_child(body);
+            }
+            ExtensionObjectEncoding::XmlElement(ref value) => {
+                let mut body = XmlNode::new("Body");
+                if let Some(ref value) = value.value {
+                    match XmlNode::parse(value) {
+                        Ok(xml) => body.add_child(xml),
+                        Err(_) => {
+                            error!("Extension object body is not XML and will be encoded as text");
+                            body.text = value.clone();
+                        }
+                    }
+                }
+                element.add_child(body);
+            }
+        }
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ExtensionObject {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "TypeId"), decoding_limits)?;
+        let body = if let Some(body) = xml_child(element, "Body").and_then(|body| body.children.first()) {
+            body
+        } else {
+            return Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::None,
+            });
+        };
+        if body.local_name() == "ByteString" {
+            Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::ByteString(ByteString::decode_xml(Some(body), decoding_limits)?),
+            })
+        } else if let Some(extension_object) = node_id.as_object_id().ok()
+            .and_then(|object_id| service_types::xml_body_to_binary(object_id, body, decoding_limits)) {
+            // The body of a generated type is decoded into its binary encoding
+            extension_object
+        } else {
+            Ok(ExtensionObject {
+                node_id,
+                body: ExtensionObjectEncoding::XmlElement(XmlElement::from(body.to_xml_string())),
+            })
+        }
+    }
+}
+
 impl ExtensionObject {
     /// Creates a null extension object, i.e. one with no value or payload
     pub fn null() -> ExtensionObject {
@@ -148,4 +346,57 @@ impl ExtensionObject {
         }
         Err(StatusCode::BadDecodingError)
     }
+
+    /// Creates an extension object holding the binary encoding of a structure of an encodable type
+    pub fn from_type<T>(value: &T) -> ExtensionObject where T: EncodableType {
+        ExtensionObject::from_encodable(T::binary_encoding_id(), value)
+    }
+
+    /// Decodes the structure of an encodable type from the extension object. The node id must be
+    /// the binary encoding of the type, otherwise the object holds some other type and it is a
+    /// `BadTypeMismatch`.
+    pub fn decode_type<T>(&self, decoding_limits: &DecodingLimits) -> EncodingResult<T> where T: EncodableType {
+        if self.node_id != T::binary_encoding_id() {
+            error!("Extension object type {} is not the binary encoding {} of the expected type", self.node_id, T::binary_encoding_id());
+            Err(StatusCode::BadTypeMismatch)
+        } else {
+            self.decode_inner(decoding_limits)
+        }
+    }
+
+    /// Returns the extension object with its body in the XML encoding. A binary body of a
+    /// generated type is converted to the XML encoding of its data type, while an XML body is
+    /// returned as it is. Any other body cannot be converted and is a `BadDataEncodingUnsupported`.
+    pub fn to_xml_encoding(&self, decoding_limits: &DecodingLimits) -> EncodingResult<ExtensionObject> {
+        match self.body {
+            ExtensionObjectEncoding::XmlElement(_) => Ok(self.clone()),
+            ExtensionObjectEncoding::ByteString(_) => {
+                let object_id = self.node_id.as_object_id().map_err(|_| StatusCode::BadDataEncodingUnsupported)?;
+                let (object_id, mut body) = service_types::binary_body_to_xml(object_id, self, decoding_limits)
+                    .ok_or(StatusCode::BadDataEncodingUnsupported)??;
+                body.set_attribute("xmlns", TYPES_XML_NAMESPACE);
+                Ok(ExtensionObject {
+                    node_id: object_id.into(),
+                    body: ExtensionObjectEncoding::XmlElement(XmlElement::from(body.to_xml_string())),
+                })
+            }
+            ExtensionObjectEncoding::None => Err(StatusCode::BadDataEncodingUnsupported),
+        }
+    }
+
+    /// Returns the extension object with its body in the binary encoding. An XML body of a
+    /// generated type is converted to the binary encoding of its data type, while a binary body is
+    /// returned as it is. Any other body cannot be converted and is a `BadDataEncodingUnsupported`.
+    pub fn to_binary_encoding(&self, decoding_limits: &DecodingLimits) -> EncodingResult<ExtensionObject> {
+        match self.body {
+            ExtensionObjectEncoding::ByteString(_) => Ok(self.clone()),
+            ExtensionObjectEncoding::XmlElement(ref value) => {
+                let object_id = self.node_id.as_object_id().map_err(|_| StatusCode::BadDataEncodingUnsupported)?;
+                let body = XmlNode::parse(value.as_ref())?;
+                service_types::xml_body_to_binary(object_id, &body, decoding_limits)
+                    .ok_or(StatusCode::BadDataEncodingUnsupported)?
+            }
+            ExtensionObjectEncoding::None => Err(StatusCode::BadDataEncodingUnsupported),
+        }
+    }
 }
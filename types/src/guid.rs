@@ -9,7 +9,12 @@ use std::{
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
-use crate::encoding::*;
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    status_codes::StatusCode,
+};
 
 /// A Guid is a 16 byte Globally Unique Identifier.
 #[derive(Eq, PartialEq, Clone, Hash)]
@@ -76,6 +81,42 @@ impl BinaryEncoder<Guid> for Guid {
     }
 }
 
+impl JsonEncoder for Guid {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        JsonValue::from(self.to_string())
+    }
+}
+
+impl JsonDecoder for Guid {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = json_str(value)? {
+            Guid::from_str(value).map_err(|_| StatusCode::BadDecodingError)
+        } else {
+            Ok(Guid::null())
+        }
+    }
+}
+
+/// Guid encoded as an element holding the string of the guid, e.g.
+/// `<Guid><String>72962b91-fa75-4ae6-8d28-b404dc7daf63</String></Guid>`
+impl XmlEncoder for Guid {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(XmlNode::new_text("String", self.to_string()));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for Guid {
+    fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = xml_text(xml_child(element, "String")) {
+            Guid::from_str(value).map_err(|_| StatusCode::BadDecodingError)
+        } else {
+            Ok(Guid::null())
+        }
+    }
+}
+
 impl FromStr for Guid {
     type Err = ();
 
@@ -0,0 +1,374 @@
+//! Contains the `JsonEncoder` and `JsonDecoder` traits for the OPC UA JSON encoding described in
+//! Part 6 and helpers for encoding and decoding scalar values, arrays and structures.
+//!
+//! Values are encoded to and decoded from a `serde_json::Value`. The encoding has a reversible
+//! form that can be decoded back into the value, and a non-reversible form that is simpler for
+//! consumers that only need to read it. Only the reversible form can be decoded.
+
+use std::{
+    fmt::Debug,
+    io::Cursor,
+    str::FromStr,
+};
+
+use crate::{
+    encoding::*,
+    status_codes::StatusCode,
+};
+
+pub use serde_json::Value as JsonValue;
+
+/// A JSON object, i.e. a map of field names to values
+pub type JsonObject = serde_json::Map<String, JsonValue>;
+
+/// The value of a field that is absent from a JSON object
+static JSON_NULL: JsonValue = JsonValue::Null;
+
+lazy_static! {
+    static ref EMPTY_JSON_OBJECT: JsonObject = JsonObject::new();
+}
+
+/// Says which form of the JSON encoding values are encoded with. The non-reversible form encodes
+/// namespace and server indices as URIs where they are known, so it holds the tables to look them
+/// up in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonContext {
+    /// True for the reversible form, false for the non-reversible form
+    pub reversible: bool,
+    /// The namespace URIs, by namespace index
+    pub namespace_uris: Vec<String>,
+    /// The server URIs, by server index
+    pub server_uris: Vec<String>,
+}
+
+impl JsonContext {
+    /// Creates a context for the reversible form
+    pub fn reversible() -> JsonContext {
+        JsonContext {
+            reversible: true,
+            namespace_uris: Vec::new(),
+            server_uris: Vec::new(),
+        }
+    }
+
+    /// Creates a context for the non-reversible form that looks the URIs of namespace and server
+    /// indices up in the tables
+    pub fn non_reversible(namespace_uris: Vec<String>, server_uris: Vec<String>) -> JsonContext {
+        JsonContext {
+            reversible: false,
+            namespace_uris,
+            server_uris,
+        }
+    }
+
+    /// Encodes a namespace index. The non-reversible form uses the namespace's URI for indices
+    /// above 1 that are in the namespace table.
+    pub fn encode_namespace(&self, namespace: u16) -> JsonValue {
+        if !self.reversible && namespace > 1 {
+            if let Some(namespace_uri) = self.namespace_uris.get(namespace as usize) {
+                return JsonValue::from(namespace_uri.as_str());
+            }
+        }
+        JsonValue::from(namespace)
+    }
+
+    /// Encodes a server index. The non-reversible form uses the server's URI for indices that are
+    /// in the server table.
+    pub fn encode_server_index(&self, server_index: u32) -> JsonValue {
+        if !self.reversible {
+            if let Some(server_uri) = self.server_uris.get(server_index as usize) {
+                return JsonValue::from(server_uri.as_str());
+            }
+        }
+        JsonValue::from(server_index)
+    }
+}
+
+/// OPC UA JSON encoding interface. Anything that encodes to JSON must implement this.
+pub trait JsonEncoder {
+    /// Encodes the instance as a JSON value in the form the context asks for. A null value, which
+    /// is omitted from structures, is encoded as `JsonValue::Null`.
+    fn encode_json(&self, context: &JsonContext) -> JsonValue;
+    /// Convenience method for encoding straight into a JSON string
+    fn to_json_string(&self, context: &JsonContext) -> String {
+        self.encode_json(context).to_string()
+    }
+}
+
+/// OPC UA JSON decoding interface. Anything that decodes from the reversible JSON form must
+/// implement this.
+pub trait JsonDecoder: Sized {
+    /// Decodes an instance from a JSON value. `JsonValue::Null` decodes to the null or default
+    /// value of the type. The decoding limits restrict the length of strings, byte strings and
+    /// arrays, and exceeding them or any malformed value is a `BadDecodingError`.
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self>;
+    /// Convenience method for decoding from a JSON string
+    fn from_json_str(value: &str, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = serde_json::from_str::<JsonValue>(value).map_err(|err| {
+            error!("Cannot parse JSON, {}", err);
+            StatusCode::BadDecodingError
+        })?;
+        Self::decode_json(&value, decoding_limits)
+    }
+}
+
+/// Inserts a field into a JSON object unless its value is null, because null fields are omitted
+pub fn insert_json_field(fields: &mut JsonObject, name: &str, value: JsonValue) {
+    if !value.is_null() {
+        fields.insert(name.to_string(), value);
+    }
+}
+
+/// Inserts an optional field into a JSON object, omitting it when it is `None` or null
+pub fn insert_json_option<T: JsonEncoder>(fields: &mut JsonObject, name: &str, value: &Option<T>, context: &JsonContext) {
+    if let Some(ref value) = value {
+        insert_json_field(fields, name, value.encode_json(context));
+    }
+}
+
+/// Decodes an optional field, which is `None` when the field is absent
+pub fn decode_json_option<T: JsonDecoder>(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Option<T>> {
+    if value.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(T::decode_json(value, decoding_limits)?))
+    }
+}
+
+/// Encodes the field of a union that the switch field selects. The reversible form is an object
+/// holding the switch field and the value, while the non-reversible form is just the value.
+pub fn encode_json_union(switch_field: u32, value: JsonValue, context: &JsonContext) -> JsonValue {
+    if context.reversible {
+        let mut fields = JsonObject::new();
+        fields.insert("SwitchField".to_string(), JsonValue::from(switch_field));
+        insert_json_field(&mut fields, "Value", value);
+        JsonValue::Object(fields)
+    } else {
+        value
+    }
+}
+
+/// Returns the fields of a JSON object. A null value has no fields.
+pub fn json_object(value: &JsonValue) -> EncodingResult<&JsonObject> {
+    match *value {
+        JsonValue::Object(ref fields) => Ok(fields),
+        JsonValue::Null => Ok(&EMPTY_JSON_OBJECT),
+        _ => {
+            error!("Expected a JSON object but got {}", value);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+/// Returns the value of a field of a JSON object, which is null if the field is absent
+pub fn json_field<'a>(fields: &'a JsonObject, name: &str) -> &'a JsonValue {
+    fields.get(name).unwrap_or(&JSON_NULL)
+}
+
+/// Encodes an array of the encoded type, preserving the distinction between a null array and an
+/// empty array
+pub fn encode_json_array<T: JsonEncoder>(values: &Option<Vec<T>>, context: &JsonContext) -> JsonValue {
+    if let Some(ref values) = values {
+        JsonValue::Array(values.iter().map(|value| value.encode_json(context)).collect())
+    } else {
+        JsonValue::Null
+    }
+}
+
+/// Decodes an array of the encoded type, preserving the distinction between a null array and an
+/// empty array
+pub fn decode_json_array<T: JsonDecoder>(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Option<Vec<T>>> {
+    match *value {
+        JsonValue::Null => Ok(None),
+        JsonValue::Array(ref values) => {
+            if values.len() > decoding_limits.max_array_length as usize {
+                error!("Array length {} exceeds decoding limit {}", values.len(), decoding_limits.max_array_length);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                let values = values.iter()
+                    .map(|value| T::decode_json(value, decoding_limits))
+                    .collect::<EncodingResult<Vec<T>>>()?;
+                Ok(Some(values))
+            }
+        }
+        _ => {
+            error!("Expected a JSON array but got {}", value);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+/// Returns the string of a JSON string, or `None` for null
+pub fn json_str(value: &JsonValue) -> EncodingResult<Option<&str>> {
+    match *value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(ref value) => Ok(Some(value)),
+        _ => {
+            error!("Expected a JSON string but got {}", value);
+            Err(StatusCode::BadDecodingError)
+        }
+    }
+}
+
+/// Encodes an enumeration, which is its value in the reversible form and its name and value
+/// joined by an underscore in the non-reversible form, e.g. "Server_2"
+pub fn encode_json_enum<T: Debug>(value: &T, number: i32, context: &JsonContext) -> JsonValue {
+    if context.reversible {
+        JsonValue::from(number)
+    } else {
+        JsonValue::from(format!("{:?}_{}", value, number))
+    }
+}
+
+/// Decodes an enumeration from its value. Enumerations are Int32s so the value is decoded by the
+/// enumeration's binary decoder, which checks that it is one of the enumeration's values.
+pub fn decode_json_enum<T: BinaryEncoder<T>>(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<T> {
+    let value = i32::decode_json(value, decoding_limits)?;
+    let mut stream = Cursor::new(Vec::with_capacity(4));
+    write_i32(&mut stream, value)?;
+    stream.set_position(0);
+    T::decode(&mut stream, decoding_limits)
+}
+
+impl<T: JsonEncoder> JsonEncoder for Box<T> {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        self.as_ref().encode_json(context)
+    }
+}
+
+impl<T: JsonDecoder> JsonDecoder for Box<T> {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(Box::new(T::decode_json(value, decoding_limits)?))
+    }
+}
+
+/// Implements `JsonEncoder` and `JsonDecoder` for an enumeration that has a binary encoder
+macro_rules! json_enum_impl {
+    ($t: ty) => {
+        impl $crate::json::JsonEncoder for $t {
+            fn encode_json(&self, context: &$crate::json::JsonContext) -> $crate::json::JsonValue {
+                $crate::json::encode_json_enum(self, *self as i32, context)
+            }
+        }
+
+        impl $crate::json::JsonDecoder for $t {
+            fn decode_json(value: &$crate::json::JsonValue, decoding_limits: &$crate::encoding::DecodingLimits) -> $crate::encoding::EncodingResult<Self> {
+                $crate::json::decode_json_enum(value, decoding_limits)
+            }
+        }
+    }
+}
+
+/// Decodes a number from a JSON number or a string holding one. Null is the number's default.
+fn decode_json_number<T: FromStr + Default>(value: &JsonValue) -> EncodingResult<T> {
+    let result = match *value {
+        JsonValue::Null => return Ok(T::default()),
+        JsonValue::Number(ref value) => value.to_string().parse::<T>(),
+        JsonValue::String(ref value) => value.parse::<T>(),
+        _ => {
+            error!("Expected a JSON number but got {}", value);
+            return Err(StatusCode::BadDecodingError);
+        }
+    };
+    result.map_err(|_| {
+        error!("JSON value {} is not a number in range of its type", value);
+        StatusCode::BadDecodingError
+    })
+}
+
+impl JsonEncoder for bool {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        JsonValue::from(*self)
+    }
+}
+
+impl JsonDecoder for bool {
+    fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+        match *value {
+            JsonValue::Null => Ok(false),
+            JsonValue::Bool(value) => Ok(value),
+            _ => {
+                error!("Expected a JSON boolean but got {}", value);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+/// Integers of up to 32 bits are JSON numbers
+macro_rules! json_number_impl {
+    ($t: ty) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                JsonValue::from(*self)
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                decode_json_number(value)
+            }
+        }
+    }
+}
+
+json_number_impl!(i8);
+json_number_impl!(u8);
+json_number_impl!(i16);
+json_number_impl!(u16);
+json_number_impl!(i32);
+json_number_impl!(u32);
+
+/// 64-bit integers are JSON strings because not every JSON parser can hold them in a number
+macro_rules! json_string_number_impl {
+    ($t: ty) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                JsonValue::from(self.to_string())
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                decode_json_number(value)
+            }
+        }
+    }
+}
+
+json_string_number_impl!(i64);
+json_string_number_impl!(u64);
+
+/// Floating point values are JSON numbers, except for NaN and the infinities which have no JSON
+/// number and are the strings "NaN", "Infinity" and "-Infinity"
+macro_rules! json_float_impl {
+    ($t: ty) => {
+        impl JsonEncoder for $t {
+            fn encode_json(&self, _: &JsonContext) -> JsonValue {
+                if self.is_nan() {
+                    JsonValue::from("NaN")
+                } else if self.is_infinite() {
+                    JsonValue::from(if self.is_sign_positive() { "Infinity" } else { "-Infinity" })
+                } else {
+                    // Going through the shortest string of the value keeps an f32 from gaining
+                    // digits as an f64
+                    JsonValue::from(self.to_string().parse::<f64>().unwrap())
+                }
+            }
+        }
+
+        impl JsonDecoder for $t {
+            fn decode_json(value: &JsonValue, _: &DecodingLimits) -> EncodingResult<Self> {
+                match value.as_str() {
+                    Some("NaN") => Ok(<$t>::NAN),
+                    Some("Infinity") => Ok(<$t>::INFINITY),
+                    Some("-Infinity") => Ok(<$t>::NEG_INFINITY),
+                    _ => decode_json_number(value)
+                }
+            }
+        }
+    }
+}
+
+json_float_impl!(f32);
+json_float_impl!(f64);
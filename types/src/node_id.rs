@@ -11,7 +11,9 @@ use crate::{
     byte_string::ByteString,
     encoding::*,
     guid::Guid,
-    node_ids::{ObjectId, ReferenceTypeId},
+    json::*,
+    xml::*,
+    node_ids::{DataTypeId, ObjectId, ReferenceTypeId},
     status_codes::StatusCode,
     string::*,
 };
@@ -229,6 +231,60 @@ impl BinaryEncoder<NodeId> for NodeId {
     }
 }
 
+impl JsonEncoder for NodeId {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() {
+            JsonValue::Null
+        } else {
+            let mut fields = JsonObject::new();
+            self.encode_json_fields(&mut fields, context);
+            if self.namespace != 0 {
+                fields.insert("Namespace".to_string(), context.encode_namespace(self.namespace));
+            }
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for NodeId {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if value.is_null() {
+            Ok(NodeId::null())
+        } else {
+            let fields = json_object(value)?;
+            let namespace = u16::decode_json(json_field(fields, "Namespace"), decoding_limits)?;
+            Self::decode_json_fields(fields, namespace, decoding_limits)
+        }
+    }
+}
+
+/// NodeId encoded as an element holding its string form, e.g.
+/// `<NodeId><Identifier>ns=1;s=Hello</Identifier></NodeId>`
+impl XmlEncoder for NodeId {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        if self.is_null() {
+            None
+        } else {
+            let mut element = XmlNode::new(name);
+            element.add_child(XmlNode::new_text("Identifier", self.to_string()));
+            Some(element)
+        }
+    }
+}
+
+impl XmlDecoder for NodeId {
+    fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(identifier) = xml_text(xml_child(element, "Identifier")) {
+            NodeId::from_str(identifier).map_err(|_| {
+                error!("Node id {} is invalid", identifier);
+                StatusCode::BadDecodingError
+            })
+        } else {
+            Ok(NodeId::null())
+        }
+    }
+}
+
 impl FromStr for NodeId {
     type Err = StatusCode;
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -369,6 +425,37 @@ impl NodeId {
         NodeId::new(0, 0u32)
     }
 
+    /// Inserts the IdType and Id fields of the JSON encoding, which are shared with
+    /// `ExpandedNodeId`. The IdType is omitted for a numeric identifier.
+    fn encode_json_fields(&self, fields: &mut JsonObject, context: &JsonContext) {
+        let (id_type, id) = match self.identifier {
+            Identifier::Numeric(ref value) => (0u8, value.encode_json(context)),
+            Identifier::String(ref value) => (1u8, value.encode_json(context)),
+            Identifier::Guid(ref value) => (2u8, value.encode_json(context)),
+            Identifier::ByteString(ref value) => (3u8, value.encode_json(context)),
+        };
+        if id_type != 0 {
+            fields.insert("IdType".to_string(), JsonValue::from(id_type));
+        }
+        insert_json_field(fields, "Id", id);
+    }
+
+    /// Decodes the IdType and Id fields of the JSON encoding
+    fn decode_json_fields(fields: &JsonObject, namespace: u16, decoding_limits: &DecodingLimits) -> EncodingResult<NodeId> {
+        let id = json_field(fields, "Id");
+        let identifier = match u8::decode_json(json_field(fields, "IdType"), decoding_limits)? {
+            0 => Identifier::Numeric(u32::decode_json(id, decoding_limits)?),
+            1 => Identifier::String(UAString::decode_json(id, decoding_limits)?),
+            2 => Identifier::Guid(Guid::decode_json(id, decoding_limits)?),
+            3 => Identifier::ByteString(ByteString::decode_json(id, decoding_limits)?),
+            id_type => {
+                error!("Node id has an invalid IdType {}", id_type);
+                return Err(StatusCode::BadDecodingError);
+            }
+        };
+        Ok(NodeId { namespace, identifier })
+    }
+
     // Creates a numeric node id with an id incrementing up from 1000
     pub fn next_numeric() -> NodeId {
         NodeId::new(1, NEXT_NODE_ID_NUMERIC.fetch_add(1, Ordering::SeqCst) as u32)
@@ -389,6 +476,14 @@ impl NodeId {
         }
     }
 
+    /// Extracts a DataTypeId from a node id, providing the node id holds a data type id
+    pub fn as_data_type_id(&self) -> std::result::Result<DataTypeId, ()> {
+        match self.identifier {
+            Identifier::Numeric(id) if self.namespace == 0 => DataTypeId::from_u32(id),
+            _ => Err(())
+        }
+    }
+
     /// Test if the node id is numeric
     pub fn is_numeric(&self) -> bool {
         match self.identifier {
@@ -548,6 +643,101 @@ impl BinaryEncoder<ExpandedNodeId> for ExpandedNodeId {
     }
 }
 
+impl JsonEncoder for ExpandedNodeId {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if self.is_null() && self.namespace_uri.is_null() && self.server_index == 0 {
+            JsonValue::Null
+        } else {
+            let mut fields = JsonObject::new();
+            self.node_id.encode_json_fields(&mut fields, context);
+            // The namespace is the URI when there is one, otherwise the index
+            if !self.namespace_uri.is_null() {
+                insert_json_field(&mut fields, "Namespace", self.namespace_uri.encode_json(context));
+            } else if self.node_id.namespace != 0 {
+                fields.insert("Namespace".to_string(), context.encode_namespace(self.node_id.namespace));
+            }
+            if self.server_index != 0 {
+                fields.insert("ServerUri".to_string(), context.encode_server_index(self.server_index));
+            }
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for ExpandedNodeId {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if value.is_null() {
+            Ok(ExpandedNodeId::null())
+        } else {
+            let fields = json_object(value)?;
+            let namespace = json_field(fields, "Namespace");
+            let (namespace, namespace_uri) = if namespace.is_string() {
+                (0, UAString::decode_json(namespace, decoding_limits)?)
+            } else {
+                (u16::decode_json(namespace, decoding_limits)?, UAString::null())
+            };
+            Ok(ExpandedNodeId {
+                node_id: NodeId::decode_json_fields(fields, namespace, decoding_limits)?,
+                namespace_uri,
+                server_index: u32::decode_json(json_field(fields, "ServerUri"), decoding_limits)?,
+            })
+        }
+    }
+}
+
+/// ExpandedNodeId encoded as an element holding its string form, which is the string form of the
+/// node id preceded by the server index and namespace URI when there are any, e.g.
+/// `<ExpandedNodeId><Identifier>svr=1;nsu=urn:x;s=Hello</Identifier></ExpandedNodeId>`
+impl XmlEncoder for ExpandedNodeId {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        if self.is_null() && self.namespace_uri.is_null() && self.server_index == 0 {
+            None
+        } else {
+            let mut identifier = String::new();
+            if self.server_index != 0 {
+                identifier.push_str(&format!("svr={};", self.server_index));
+            }
+            if !self.namespace_uri.is_null() {
+                // The namespace URI replaces the index
+                let node_id = NodeId { namespace: 0, identifier: self.node_id.identifier.clone() };
+                identifier.push_str(&format!("nsu={};{}", self.namespace_uri, node_id));
+            } else {
+                identifier.push_str(&self.node_id.to_string());
+            }
+            let mut element = XmlNode::new(name);
+            element.add_child(XmlNode::new_text("Identifier", identifier));
+            Some(element)
+        }
+    }
+}
+
+impl XmlDecoder for ExpandedNodeId {
+    fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(identifier) = xml_text(xml_child(element, "Identifier")) {
+            let mut rest = identifier;
+            let mut server_index = 0;
+            let mut namespace_uri = UAString::null();
+            if rest.starts_with("svr=") {
+                let end = rest.find(';').ok_or(StatusCode::BadDecodingError)?;
+                server_index = rest[4..end].parse::<u32>().map_err(|_| StatusCode::BadDecodingError)?;
+                rest = &rest[end + 1..];
+            }
+            if rest.starts_with("nsu=") {
+                let end = rest.find(';').ok_or(StatusCode::BadDecodingError)?;
+                namespace_uri = UAString::from(&rest[4..end]);
+                rest = &rest[end + 1..];
+            }
+            let node_id = NodeId::from_str(rest).map_err(|_| {
+                error!("Expanded node id {} is invalid", identifier);
+                StatusCode::BadDecodingError
+            })?;
+            Ok(ExpandedNodeId { node_id, namespace_uri, server_index })
+        } else {
+            Ok(ExpandedNodeId::null())
+        }
+    }
+}
+
 impl<'a> Into<ExpandedNodeId> for &'a NodeId {
     fn into(self) -> ExpandedNodeId {
         self.clone().into()
@@ -1103,202 +1103,399 @@ pub enum ObjectId {
     ReferenceTypesFolder = 91,
     XmlSchema_TypeSystem = 92,
     OPCBinarySchema_TypeSystem = 93,
+    Node_Encoding_DefaultXml = 259,
     Node_Encoding_DefaultBinary = 260,
+    ObjectNode_Encoding_DefaultXml = 262,
     ObjectNode_Encoding_DefaultBinary = 263,
+    ObjectTypeNode_Encoding_DefaultXml = 265,
     ObjectTypeNode_Encoding_DefaultBinary = 266,
+    VariableNode_Encoding_DefaultXml = 268,
     VariableNode_Encoding_DefaultBinary = 269,
+    VariableTypeNode_Encoding_DefaultXml = 271,
     VariableTypeNode_Encoding_DefaultBinary = 272,
+    ReferenceTypeNode_Encoding_DefaultXml = 274,
     ReferenceTypeNode_Encoding_DefaultBinary = 275,
+    MethodNode_Encoding_DefaultXml = 277,
     MethodNode_Encoding_DefaultBinary = 278,
+    ViewNode_Encoding_DefaultXml = 280,
     ViewNode_Encoding_DefaultBinary = 281,
+    DataTypeNode_Encoding_DefaultXml = 283,
     DataTypeNode_Encoding_DefaultBinary = 284,
+    ReferenceNode_Encoding_DefaultXml = 286,
     ReferenceNode_Encoding_DefaultBinary = 287,
+    Argument_Encoding_DefaultXml = 297,
     Argument_Encoding_DefaultBinary = 298,
+    StatusResult_Encoding_DefaultXml = 300,
     StatusResult_Encoding_DefaultBinary = 301,
+    UserTokenPolicy_Encoding_DefaultXml = 305,
     UserTokenPolicy_Encoding_DefaultBinary = 306,
+    ApplicationDescription_Encoding_DefaultXml = 309,
     ApplicationDescription_Encoding_DefaultBinary = 310,
+    EndpointDescription_Encoding_DefaultXml = 313,
     EndpointDescription_Encoding_DefaultBinary = 314,
+    UserIdentityToken_Encoding_DefaultXml = 317,
     UserIdentityToken_Encoding_DefaultBinary = 318,
+    AnonymousIdentityToken_Encoding_DefaultXml = 320,
     AnonymousIdentityToken_Encoding_DefaultBinary = 321,
+    UserNameIdentityToken_Encoding_DefaultXml = 323,
     UserNameIdentityToken_Encoding_DefaultBinary = 324,
+    X509IdentityToken_Encoding_DefaultXml = 326,
     X509IdentityToken_Encoding_DefaultBinary = 327,
+    EndpointConfiguration_Encoding_DefaultXml = 332,
     EndpointConfiguration_Encoding_DefaultBinary = 333,
+    BuildInfo_Encoding_DefaultXml = 339,
     BuildInfo_Encoding_DefaultBinary = 340,
+    SignedSoftwareCertificate_Encoding_DefaultXml = 345,
     SignedSoftwareCertificate_Encoding_DefaultBinary = 346,
+    NodeAttributes_Encoding_DefaultXml = 350,
     NodeAttributes_Encoding_DefaultBinary = 351,
+    ObjectAttributes_Encoding_DefaultXml = 353,
     ObjectAttributes_Encoding_DefaultBinary = 354,
+    VariableAttributes_Encoding_DefaultXml = 356,
     VariableAttributes_Encoding_DefaultBinary = 357,
+    MethodAttributes_Encoding_DefaultXml = 359,
     MethodAttributes_Encoding_DefaultBinary = 360,
+    ObjectTypeAttributes_Encoding_DefaultXml = 362,
     ObjectTypeAttributes_Encoding_DefaultBinary = 363,
+    VariableTypeAttributes_Encoding_DefaultXml = 365,
     VariableTypeAttributes_Encoding_DefaultBinary = 366,
+    ReferenceTypeAttributes_Encoding_DefaultXml = 368,
     ReferenceTypeAttributes_Encoding_DefaultBinary = 369,
+    DataTypeAttributes_Encoding_DefaultXml = 371,
     DataTypeAttributes_Encoding_DefaultBinary = 372,
+    ViewAttributes_Encoding_DefaultXml = 374,
     ViewAttributes_Encoding_DefaultBinary = 375,
+    AddNodesItem_Encoding_DefaultXml = 377,
     AddNodesItem_Encoding_DefaultBinary = 378,
+    AddReferencesItem_Encoding_DefaultXml = 380,
     AddReferencesItem_Encoding_DefaultBinary = 381,
+    DeleteNodesItem_Encoding_DefaultXml = 383,
     DeleteNodesItem_Encoding_DefaultBinary = 384,
+    DeleteReferencesItem_Encoding_DefaultXml = 386,
     DeleteReferencesItem_Encoding_DefaultBinary = 387,
+    RequestHeader_Encoding_DefaultXml = 390,
     RequestHeader_Encoding_DefaultBinary = 391,
+    ResponseHeader_Encoding_DefaultXml = 393,
     ResponseHeader_Encoding_DefaultBinary = 394,
+    ServiceFault_Encoding_DefaultXml = 396,
     ServiceFault_Encoding_DefaultBinary = 397,
+    FindServersRequest_Encoding_DefaultXml = 421,
     FindServersRequest_Encoding_DefaultBinary = 422,
+    FindServersResponse_Encoding_DefaultXml = 424,
     FindServersResponse_Encoding_DefaultBinary = 425,
+    GetEndpointsRequest_Encoding_DefaultXml = 427,
     GetEndpointsRequest_Encoding_DefaultBinary = 428,
+    GetEndpointsResponse_Encoding_DefaultXml = 430,
     GetEndpointsResponse_Encoding_DefaultBinary = 431,
+    RegisteredServer_Encoding_DefaultXml = 433,
     RegisteredServer_Encoding_DefaultBinary = 434,
+    RegisterServerRequest_Encoding_DefaultXml = 436,
     RegisterServerRequest_Encoding_DefaultBinary = 437,
+    RegisterServerResponse_Encoding_DefaultXml = 439,
     RegisterServerResponse_Encoding_DefaultBinary = 440,
+    ChannelSecurityToken_Encoding_DefaultXml = 442,
     ChannelSecurityToken_Encoding_DefaultBinary = 443,
+    OpenSecureChannelRequest_Encoding_DefaultXml = 445,
     OpenSecureChannelRequest_Encoding_DefaultBinary = 446,
+    OpenSecureChannelResponse_Encoding_DefaultXml = 448,
     OpenSecureChannelResponse_Encoding_DefaultBinary = 449,
+    CloseSecureChannelRequest_Encoding_DefaultXml = 451,
     CloseSecureChannelRequest_Encoding_DefaultBinary = 452,
+    CloseSecureChannelResponse_Encoding_DefaultXml = 454,
     CloseSecureChannelResponse_Encoding_DefaultBinary = 455,
+    SignatureData_Encoding_DefaultXml = 457,
     SignatureData_Encoding_DefaultBinary = 458,
+    CreateSessionRequest_Encoding_DefaultXml = 460,
     CreateSessionRequest_Encoding_DefaultBinary = 461,
+    CreateSessionResponse_Encoding_DefaultXml = 463,
     CreateSessionResponse_Encoding_DefaultBinary = 464,
+    ActivateSessionRequest_Encoding_DefaultXml = 466,
     ActivateSessionRequest_Encoding_DefaultBinary = 467,
+    ActivateSessionResponse_Encoding_DefaultXml = 469,
     ActivateSessionResponse_Encoding_DefaultBinary = 470,
+    CloseSessionRequest_Encoding_DefaultXml = 472,
     CloseSessionRequest_Encoding_DefaultBinary = 473,
+    CloseSessionResponse_Encoding_DefaultXml = 475,
     CloseSessionResponse_Encoding_DefaultBinary = 476,
+    CancelRequest_Encoding_DefaultXml = 478,
     CancelRequest_Encoding_DefaultBinary = 479,
+    CancelResponse_Encoding_DefaultXml = 481,
     CancelResponse_Encoding_DefaultBinary = 482,
+    AddNodesResult_Encoding_DefaultXml = 484,
     AddNodesResult_Encoding_DefaultBinary = 485,
+    AddNodesRequest_Encoding_DefaultXml = 487,
     AddNodesRequest_Encoding_DefaultBinary = 488,
+    AddNodesResponse_Encoding_DefaultXml = 490,
     AddNodesResponse_Encoding_DefaultBinary = 491,
+    AddReferencesRequest_Encoding_DefaultXml = 493,
     AddReferencesRequest_Encoding_DefaultBinary = 494,
+    AddReferencesResponse_Encoding_DefaultXml = 496,
     AddReferencesResponse_Encoding_DefaultBinary = 497,
+    DeleteNodesRequest_Encoding_DefaultXml = 499,
     DeleteNodesRequest_Encoding_DefaultBinary = 500,
+    DeleteNodesResponse_Encoding_DefaultXml = 502,
     DeleteNodesResponse_Encoding_DefaultBinary = 503,
+    DeleteReferencesRequest_Encoding_DefaultXml = 505,
     DeleteReferencesRequest_Encoding_DefaultBinary = 506,
+    DeleteReferencesResponse_Encoding_DefaultXml = 508,
     DeleteReferencesResponse_Encoding_DefaultBinary = 509,
+    ViewDescription_Encoding_DefaultXml = 512,
     ViewDescription_Encoding_DefaultBinary = 513,
+    BrowseDescription_Encoding_DefaultXml = 515,
     BrowseDescription_Encoding_DefaultBinary = 516,
+    ReferenceDescription_Encoding_DefaultXml = 519,
     ReferenceDescription_Encoding_DefaultBinary = 520,
+    BrowseResult_Encoding_DefaultXml = 523,
     BrowseResult_Encoding_DefaultBinary = 524,
+    BrowseRequest_Encoding_DefaultXml = 526,
     BrowseRequest_Encoding_DefaultBinary = 527,
+    BrowseResponse_Encoding_DefaultXml = 529,
     BrowseResponse_Encoding_DefaultBinary = 530,
+    BrowseNextRequest_Encoding_DefaultXml = 532,
     BrowseNextRequest_Encoding_DefaultBinary = 533,
+    BrowseNextResponse_Encoding_DefaultXml = 535,
     BrowseNextResponse_Encoding_DefaultBinary = 536,
+    RelativePathElement_Encoding_DefaultXml = 538,
     RelativePathElement_Encoding_DefaultBinary = 539,
+    RelativePath_Encoding_DefaultXml = 541,
     RelativePath_Encoding_DefaultBinary = 542,
+    BrowsePath_Encoding_DefaultXml = 544,
     BrowsePath_Encoding_DefaultBinary = 545,
+    BrowsePathTarget_Encoding_DefaultXml = 547,
     BrowsePathTarget_Encoding_DefaultBinary = 548,
+    BrowsePathResult_Encoding_DefaultXml = 550,
     BrowsePathResult_Encoding_DefaultBinary = 551,
+    TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml = 553,
     TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary = 554,
+    TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml = 556,
     TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary = 557,
+    RegisterNodesRequest_Encoding_DefaultXml = 559,
     RegisterNodesRequest_Encoding_DefaultBinary = 560,
+    RegisterNodesResponse_Encoding_DefaultXml = 562,
     RegisterNodesResponse_Encoding_DefaultBinary = 563,
+    UnregisterNodesRequest_Encoding_DefaultXml = 565,
     UnregisterNodesRequest_Encoding_DefaultBinary = 566,
+    UnregisterNodesResponse_Encoding_DefaultXml = 568,
     UnregisterNodesResponse_Encoding_DefaultBinary = 569,
+    QueryDataDescription_Encoding_DefaultXml = 571,
     QueryDataDescription_Encoding_DefaultBinary = 572,
+    NodeTypeDescription_Encoding_DefaultXml = 574,
     NodeTypeDescription_Encoding_DefaultBinary = 575,
+    QueryDataSet_Encoding_DefaultXml = 578,
     QueryDataSet_Encoding_DefaultBinary = 579,
+    NodeReference_Encoding_DefaultXml = 581,
     NodeReference_Encoding_DefaultBinary = 582,
+    ContentFilterElement_Encoding_DefaultXml = 584,
     ContentFilterElement_Encoding_DefaultBinary = 585,
+    ContentFilter_Encoding_DefaultXml = 587,
     ContentFilter_Encoding_DefaultBinary = 588,
+    FilterOperand_Encoding_DefaultXml = 590,
     FilterOperand_Encoding_DefaultBinary = 591,
+    ElementOperand_Encoding_DefaultXml = 593,
     ElementOperand_Encoding_DefaultBinary = 594,
+    LiteralOperand_Encoding_DefaultXml = 596,
     LiteralOperand_Encoding_DefaultBinary = 597,
+    AttributeOperand_Encoding_DefaultXml = 599,
     AttributeOperand_Encoding_DefaultBinary = 600,
+    SimpleAttributeOperand_Encoding_DefaultXml = 602,
     SimpleAttributeOperand_Encoding_DefaultBinary = 603,
+    ContentFilterElementResult_Encoding_DefaultXml = 605,
     ContentFilterElementResult_Encoding_DefaultBinary = 606,
+    ContentFilterResult_Encoding_DefaultXml = 608,
     ContentFilterResult_Encoding_DefaultBinary = 609,
+    ParsingResult_Encoding_DefaultXml = 611,
     ParsingResult_Encoding_DefaultBinary = 612,
+    QueryFirstRequest_Encoding_DefaultXml = 614,
     QueryFirstRequest_Encoding_DefaultBinary = 615,
+    QueryFirstResponse_Encoding_DefaultXml = 617,
     QueryFirstResponse_Encoding_DefaultBinary = 618,
+    QueryNextRequest_Encoding_DefaultXml = 620,
     QueryNextRequest_Encoding_DefaultBinary = 621,
+    QueryNextResponse_Encoding_DefaultXml = 623,
     QueryNextResponse_Encoding_DefaultBinary = 624,
+    ReadValueId_Encoding_DefaultXml = 627,
     ReadValueId_Encoding_DefaultBinary = 628,
+    ReadRequest_Encoding_DefaultXml = 630,
     ReadRequest_Encoding_DefaultBinary = 631,
+    ReadResponse_Encoding_DefaultXml = 633,
     ReadResponse_Encoding_DefaultBinary = 634,
+    HistoryReadValueId_Encoding_DefaultXml = 636,
     HistoryReadValueId_Encoding_DefaultBinary = 637,
+    HistoryReadResult_Encoding_DefaultXml = 639,
     HistoryReadResult_Encoding_DefaultBinary = 640,
+    HistoryReadDetails_Encoding_DefaultXml = 642,
     HistoryReadDetails_Encoding_DefaultBinary = 643,
+    ReadEventDetails_Encoding_DefaultXml = 645,
     ReadEventDetails_Encoding_DefaultBinary = 646,
+    ReadRawModifiedDetails_Encoding_DefaultXml = 648,
     ReadRawModifiedDetails_Encoding_DefaultBinary = 649,
+    ReadProcessedDetails_Encoding_DefaultXml = 651,
     ReadProcessedDetails_Encoding_DefaultBinary = 652,
+    ReadAtTimeDetails_Encoding_DefaultXml = 654,
     ReadAtTimeDetails_Encoding_DefaultBinary = 655,
+    HistoryData_Encoding_DefaultXml = 657,
     HistoryData_Encoding_DefaultBinary = 658,
+    HistoryEvent_Encoding_DefaultXml = 660,
     HistoryEvent_Encoding_DefaultBinary = 661,
+    HistoryReadRequest_Encoding_DefaultXml = 663,
     HistoryReadRequest_Encoding_DefaultBinary = 664,
+    HistoryReadResponse_Encoding_DefaultXml = 666,
     HistoryReadResponse_Encoding_DefaultBinary = 667,
+    WriteValue_Encoding_DefaultXml = 669,
     WriteValue_Encoding_DefaultBinary = 670,
+    WriteRequest_Encoding_DefaultXml = 672,
     WriteRequest_Encoding_DefaultBinary = 673,
+    WriteResponse_Encoding_DefaultXml = 675,
     WriteResponse_Encoding_DefaultBinary = 676,
+    HistoryUpdateDetails_Encoding_DefaultXml = 678,
     HistoryUpdateDetails_Encoding_DefaultBinary = 679,
+    UpdateDataDetails_Encoding_DefaultXml = 681,
     UpdateDataDetails_Encoding_DefaultBinary = 682,
+    UpdateEventDetails_Encoding_DefaultXml = 684,
     UpdateEventDetails_Encoding_DefaultBinary = 685,
+    DeleteRawModifiedDetails_Encoding_DefaultXml = 687,
     DeleteRawModifiedDetails_Encoding_DefaultBinary = 688,
+    DeleteAtTimeDetails_Encoding_DefaultXml = 690,
     DeleteAtTimeDetails_Encoding_DefaultBinary = 691,
+    DeleteEventDetails_Encoding_DefaultXml = 693,
     DeleteEventDetails_Encoding_DefaultBinary = 694,
+    HistoryUpdateResult_Encoding_DefaultXml = 696,
     HistoryUpdateResult_Encoding_DefaultBinary = 697,
+    HistoryUpdateRequest_Encoding_DefaultXml = 699,
     HistoryUpdateRequest_Encoding_DefaultBinary = 700,
+    HistoryUpdateResponse_Encoding_DefaultXml = 702,
     HistoryUpdateResponse_Encoding_DefaultBinary = 703,
+    CallMethodRequest_Encoding_DefaultXml = 705,
     CallMethodRequest_Encoding_DefaultBinary = 706,
+    CallMethodResult_Encoding_DefaultXml = 708,
     CallMethodResult_Encoding_DefaultBinary = 709,
+    CallRequest_Encoding_DefaultXml = 711,
     CallRequest_Encoding_DefaultBinary = 712,
+    CallResponse_Encoding_DefaultXml = 714,
     CallResponse_Encoding_DefaultBinary = 715,
+    MonitoringFilter_Encoding_DefaultXml = 720,
     MonitoringFilter_Encoding_DefaultBinary = 721,
+    DataChangeFilter_Encoding_DefaultXml = 723,
     DataChangeFilter_Encoding_DefaultBinary = 724,
+    EventFilter_Encoding_DefaultXml = 726,
     EventFilter_Encoding_DefaultBinary = 727,
+    AggregateFilter_Encoding_DefaultXml = 729,
     AggregateFilter_Encoding_DefaultBinary = 730,
+    MonitoringFilterResult_Encoding_DefaultXml = 732,
     MonitoringFilterResult_Encoding_DefaultBinary = 733,
+    EventFilterResult_Encoding_DefaultXml = 735,
     EventFilterResult_Encoding_DefaultBinary = 736,
+    AggregateFilterResult_Encoding_DefaultXml = 738,
     AggregateFilterResult_Encoding_DefaultBinary = 739,
+    MonitoringParameters_Encoding_DefaultXml = 741,
     MonitoringParameters_Encoding_DefaultBinary = 742,
+    MonitoredItemCreateRequest_Encoding_DefaultXml = 744,
     MonitoredItemCreateRequest_Encoding_DefaultBinary = 745,
+    MonitoredItemCreateResult_Encoding_DefaultXml = 747,
     MonitoredItemCreateResult_Encoding_DefaultBinary = 748,
+    CreateMonitoredItemsRequest_Encoding_DefaultXml = 750,
     CreateMonitoredItemsRequest_Encoding_DefaultBinary = 751,
+    CreateMonitoredItemsResponse_Encoding_DefaultXml = 753,
     CreateMonitoredItemsResponse_Encoding_DefaultBinary = 754,
+    MonitoredItemModifyRequest_Encoding_DefaultXml = 756,
     MonitoredItemModifyRequest_Encoding_DefaultBinary = 757,
+    MonitoredItemModifyResult_Encoding_DefaultXml = 759,
     MonitoredItemModifyResult_Encoding_DefaultBinary = 760,
+    ModifyMonitoredItemsRequest_Encoding_DefaultXml = 762,
     ModifyMonitoredItemsRequest_Encoding_DefaultBinary = 763,
+    ModifyMonitoredItemsResponse_Encoding_DefaultXml = 765,
     ModifyMonitoredItemsResponse_Encoding_DefaultBinary = 766,
+    SetMonitoringModeRequest_Encoding_DefaultXml = 768,
     SetMonitoringModeRequest_Encoding_DefaultBinary = 769,
+    SetMonitoringModeResponse_Encoding_DefaultXml = 771,
     SetMonitoringModeResponse_Encoding_DefaultBinary = 772,
+    SetTriggeringRequest_Encoding_DefaultXml = 774,
     SetTriggeringRequest_Encoding_DefaultBinary = 775,
+    SetTriggeringResponse_Encoding_DefaultXml = 777,
     SetTriggeringResponse_Encoding_DefaultBinary = 778,
+    DeleteMonitoredItemsRequest_Encoding_DefaultXml = 780,
     DeleteMonitoredItemsRequest_Encoding_DefaultBinary = 781,
+    DeleteMonitoredItemsResponse_Encoding_DefaultXml = 783,
     DeleteMonitoredItemsResponse_Encoding_DefaultBinary = 784,
+    CreateSubscriptionRequest_Encoding_DefaultXml = 786,
     CreateSubscriptionRequest_Encoding_DefaultBinary = 787,
+    CreateSubscriptionResponse_Encoding_DefaultXml = 789,
     CreateSubscriptionResponse_Encoding_DefaultBinary = 790,
+    ModifySubscriptionRequest_Encoding_DefaultXml = 792,
     ModifySubscriptionRequest_Encoding_DefaultBinary = 793,
+    ModifySubscriptionResponse_Encoding_DefaultXml = 795,
     ModifySubscriptionResponse_Encoding_DefaultBinary = 796,
+    SetPublishingModeRequest_Encoding_DefaultXml = 798,
     SetPublishingModeRequest_Encoding_DefaultBinary = 799,
+    SetPublishingModeResponse_Encoding_DefaultXml = 801,
     SetPublishingModeResponse_Encoding_DefaultBinary = 802,
+    NotificationMessage_Encoding_DefaultXml = 804,
     NotificationMessage_Encoding_DefaultBinary = 805,
+    MonitoredItemNotification_Encoding_DefaultXml = 807,
     MonitoredItemNotification_Encoding_DefaultBinary = 808,
+    DataChangeNotification_Encoding_DefaultXml = 810,
     DataChangeNotification_Encoding_DefaultBinary = 811,
+    StatusChangeNotification_Encoding_DefaultXml = 819,
     StatusChangeNotification_Encoding_DefaultBinary = 820,
+    SubscriptionAcknowledgement_Encoding_DefaultXml = 822,
     SubscriptionAcknowledgement_Encoding_DefaultBinary = 823,
+    PublishRequest_Encoding_DefaultXml = 825,
     PublishRequest_Encoding_DefaultBinary = 826,
+    PublishResponse_Encoding_DefaultXml = 828,
     PublishResponse_Encoding_DefaultBinary = 829,
+    RepublishRequest_Encoding_DefaultXml = 831,
     RepublishRequest_Encoding_DefaultBinary = 832,
+    RepublishResponse_Encoding_DefaultXml = 834,
     RepublishResponse_Encoding_DefaultBinary = 835,
+    TransferResult_Encoding_DefaultXml = 837,
     TransferResult_Encoding_DefaultBinary = 838,
+    TransferSubscriptionsRequest_Encoding_DefaultXml = 840,
     TransferSubscriptionsRequest_Encoding_DefaultBinary = 841,
+    TransferSubscriptionsResponse_Encoding_DefaultXml = 843,
     TransferSubscriptionsResponse_Encoding_DefaultBinary = 844,
+    DeleteSubscriptionsRequest_Encoding_DefaultXml = 846,
     DeleteSubscriptionsRequest_Encoding_DefaultBinary = 847,
+    DeleteSubscriptionsResponse_Encoding_DefaultXml = 849,
     DeleteSubscriptionsResponse_Encoding_DefaultBinary = 850,
+    RedundantServerDataType_Encoding_DefaultXml = 854,
     RedundantServerDataType_Encoding_DefaultBinary = 855,
+    SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml = 857,
     SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary = 858,
+    ServerDiagnosticsSummaryDataType_Encoding_DefaultXml = 860,
     ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary = 861,
+    ServerStatusDataType_Encoding_DefaultXml = 863,
     ServerStatusDataType_Encoding_DefaultBinary = 864,
+    SessionDiagnosticsDataType_Encoding_DefaultXml = 866,
     SessionDiagnosticsDataType_Encoding_DefaultBinary = 867,
+    SessionSecurityDiagnosticsDataType_Encoding_DefaultXml = 869,
     SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary = 870,
+    ServiceCounterDataType_Encoding_DefaultXml = 872,
     ServiceCounterDataType_Encoding_DefaultBinary = 873,
+    SubscriptionDiagnosticsDataType_Encoding_DefaultXml = 875,
     SubscriptionDiagnosticsDataType_Encoding_DefaultBinary = 876,
+    ModelChangeStructureDataType_Encoding_DefaultXml = 878,
     ModelChangeStructureDataType_Encoding_DefaultBinary = 879,
+    Range_Encoding_DefaultXml = 885,
     Range_Encoding_DefaultBinary = 886,
+    EUInformation_Encoding_DefaultXml = 888,
     EUInformation_Encoding_DefaultBinary = 889,
+    Annotation_Encoding_DefaultXml = 892,
     Annotation_Encoding_DefaultBinary = 893,
+    ProgramDiagnosticDataType_Encoding_DefaultXml = 895,
     ProgramDiagnosticDataType_Encoding_DefaultBinary = 896,
+    SemanticChangeStructureDataType_Encoding_DefaultXml = 898,
     SemanticChangeStructureDataType_Encoding_DefaultBinary = 899,
+    EventNotificationList_Encoding_DefaultXml = 915,
     EventNotificationList_Encoding_DefaultBinary = 916,
+    EventFieldList_Encoding_DefaultXml = 918,
     EventFieldList_Encoding_DefaultBinary = 919,
+    HistoryEventFieldList_Encoding_DefaultXml = 921,
     HistoryEventFieldList_Encoding_DefaultBinary = 922,
+    IssuedIdentityToken_Encoding_DefaultXml = 939,
     IssuedIdentityToken_Encoding_DefaultBinary = 940,
+    NotificationData_Encoding_DefaultXml = 946,
     NotificationData_Encoding_DefaultBinary = 947,
+    AggregateConfiguration_Encoding_DefaultXml = 949,
     AggregateConfiguration_Encoding_DefaultBinary = 950,
     ServerType_ServerCapabilities = 2009,
     ServerType_ServerDiagnostics = 2010,
@@ -1365,7 +1562,9 @@ pub enum ObjectId {
     ServerType_ServerDiagnostics_SessionsDiagnosticsSummary = 3111,
     Server_ServerDiagnostics_SessionsDiagnosticsSummary = 3706,
     ProgramStateMachineType_FinalResultData = 3850,
+    EnumValueType_Encoding_DefaultXml = 7616,
     EnumValueType_Encoding_DefaultBinary = 8251,
+    TimeZoneDataType_Encoding_DefaultXml = 8913,
     TimeZoneDataType_Encoding_DefaultBinary = 8917,
     AlarmConditionType_ShelvingState = 9178,
     LimitAlarmType_ShelvingState = 9279,
@@ -1397,6 +1596,8 @@ pub enum ObjectId {
     HistoryServerCapabilities_AggregateFunctions = 11201,
     HAConfiguration = 11202,
     HAConfiguration_AggregateConfiguration = 11203,
+    ModificationInfo_Encoding_DefaultXml = 11218,
+    HistoryModifiedData_Encoding_DefaultXml = 11219,
     ModificationInfo_Encoding_DefaultBinary = 11226,
     HistoryModifiedData_Encoding_DefaultBinary = 11227,
     AggregateFunction_TimeAverage2 = 11285,
@@ -1404,6 +1605,7 @@ pub enum ObjectId {
     AggregateFunction_Maximum2 = 11287,
     AggregateFunction_Range2 = 11288,
     AggregateFunction_WorstQuality2 = 11292,
+    UpdateStructureDataDetails_Encoding_DefaultXml = 11296,
     UpdateStructureDataDetails_Encoding_DefaultBinary = 11300,
     AggregateFunction_Total2 = 11304,
     AggregateFunction_MinimumActualTime2 = 11305,
@@ -1433,15 +1635,28 @@ pub enum ObjectId {
     SystemOffNormalAlarmType_ShelvingState = 11831,
     HistoricalDataConfigurationType_AggregateFunctions = 11876,
     HAConfiguration_AggregateFunctions = 11877,
+    InstanceNode_Encoding_DefaultXml = 11887,
+    TypeNode_Encoding_DefaultXml = 11888,
     InstanceNode_Encoding_DefaultBinary = 11889,
     TypeNode_Encoding_DefaultBinary = 11890,
+    EndpointUrlListDataType_Encoding_DefaultXml = 11949,
+    NetworkGroupDataType_Encoding_DefaultXml = 11950,
     EndpointUrlListDataType_Encoding_DefaultBinary = 11957,
     NetworkGroupDataType_Encoding_DefaultBinary = 11958,
+    AxisInformation_Encoding_DefaultXml = 12081,
+    XVType_Encoding_DefaultXml = 12082,
     AxisInformation_Encoding_DefaultBinary = 12089,
     XVType_Encoding_DefaultBinary = 12090,
     SessionsDiagnosticsSummaryType_ClientName_Placeholder = 12097,
+    ComplexNumberType_Encoding_DefaultXml = 12173,
+    DoubleComplexNumberType_Encoding_DefaultXml = 12174,
     ComplexNumberType_Encoding_DefaultBinary = 12181,
     DoubleComplexNumberType_Encoding_DefaultBinary = 12182,
+    ServerOnNetwork_Encoding_DefaultXml = 12195,
+    FindServersOnNetworkRequest_Encoding_DefaultXml = 12196,
+    FindServersOnNetworkResponse_Encoding_DefaultXml = 12197,
+    RegisterServer2Request_Encoding_DefaultXml = 12199,
+    RegisterServer2Response_Encoding_DefaultXml = 12200,
     ServerOnNetwork_Encoding_DefaultBinary = 12207,
     FindServersOnNetworkRequest_Encoding_DefaultBinary = 12208,
     FindServersOnNetworkResponse_Encoding_DefaultBinary = 12209,
@@ -1449,9 +1664,14 @@ pub enum ObjectId {
     RegisterServer2Response_Encoding_DefaultBinary = 12212,
     ServerConfiguration = 12637,
     ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList = 12642,
+    TrustListDataType_Encoding_DefaultXml = 12676,
     TrustListDataType_Encoding_DefaultBinary = 12680,
+    OptionSet_Encoding_DefaultXml = 12757,
+    Union_Encoding_DefaultXml = 12758,
     OptionSet_Encoding_DefaultBinary = 12765,
     Union_Encoding_DefaultBinary = 12766,
+    DiscoveryConfiguration_Encoding_DefaultXml = 12892,
+    MdnsDiscoveryConfiguration_Encoding_DefaultXml = 12893,
     DiscoveryConfiguration_Encoding_DefaultBinary = 12900,
     MdnsDiscoveryConfiguration_Encoding_DefaultBinary = 12901,
     CertificateExpirationAlarmType_ShelvingState = 13305,
@@ -1516,202 +1736,399 @@ impl ObjectId {
             91 => Ok(ObjectId::ReferenceTypesFolder),
             92 => Ok(ObjectId::XmlSchema_TypeSystem),
             93 => Ok(ObjectId::OPCBinarySchema_TypeSystem),
+            259 => Ok(ObjectId::Node_Encoding_DefaultXml),
             260 => Ok(ObjectId::Node_Encoding_DefaultBinary),
+            262 => Ok(ObjectId::ObjectNode_Encoding_DefaultXml),
             263 => Ok(ObjectId::ObjectNode_Encoding_DefaultBinary),
+            265 => Ok(ObjectId::ObjectTypeNode_Encoding_DefaultXml),
             266 => Ok(ObjectId::ObjectTypeNode_Encoding_DefaultBinary),
+            268 => Ok(ObjectId::VariableNode_Encoding_DefaultXml),
             269 => Ok(ObjectId::VariableNode_Encoding_DefaultBinary),
+            271 => Ok(ObjectId::VariableTypeNode_Encoding_DefaultXml),
             272 => Ok(ObjectId::VariableTypeNode_Encoding_DefaultBinary),
+            274 => Ok(ObjectId::ReferenceTypeNode_Encoding_DefaultXml),
             275 => Ok(ObjectId::ReferenceTypeNode_Encoding_DefaultBinary),
+            277 => Ok(ObjectId::MethodNode_Encoding_DefaultXml),
             278 => Ok(ObjectId::MethodNode_Encoding_DefaultBinary),
+            280 => Ok(ObjectId::ViewNode_Encoding_DefaultXml),
             281 => Ok(ObjectId::ViewNode_Encoding_DefaultBinary),
+            283 => Ok(ObjectId::DataTypeNode_Encoding_DefaultXml),
             284 => Ok(ObjectId::DataTypeNode_Encoding_DefaultBinary),
+            286 => Ok(ObjectId::ReferenceNode_Encoding_DefaultXml),
             287 => Ok(ObjectId::ReferenceNode_Encoding_DefaultBinary),
+            297 => Ok(ObjectId::Argument_Encoding_DefaultXml),
             298 => Ok(ObjectId::Argument_Encoding_DefaultBinary),
+            300 => Ok(ObjectId::StatusResult_Encoding_DefaultXml),
             301 => Ok(ObjectId::StatusResult_Encoding_DefaultBinary),
+            305 => Ok(ObjectId::UserTokenPolicy_Encoding_DefaultXml),
             306 => Ok(ObjectId::UserTokenPolicy_Encoding_DefaultBinary),
+            309 => Ok(ObjectId::ApplicationDescription_Encoding_DefaultXml),
             310 => Ok(ObjectId::ApplicationDescription_Encoding_DefaultBinary),
+            313 => Ok(ObjectId::EndpointDescription_Encoding_DefaultXml),
             314 => Ok(ObjectId::EndpointDescription_Encoding_DefaultBinary),
+            317 => Ok(ObjectId::UserIdentityToken_Encoding_DefaultXml),
             318 => Ok(ObjectId::UserIdentityToken_Encoding_DefaultBinary),
+            320 => Ok(ObjectId::AnonymousIdentityToken_Encoding_DefaultXml),
             321 => Ok(ObjectId::AnonymousIdentityToken_Encoding_DefaultBinary),
+            323 => Ok(ObjectId::UserNameIdentityToken_Encoding_DefaultXml),
             324 => Ok(ObjectId::UserNameIdentityToken_Encoding_DefaultBinary),
+            326 => Ok(ObjectId::X509IdentityToken_Encoding_DefaultXml),
             327 => Ok(ObjectId::X509IdentityToken_Encoding_DefaultBinary),
+            332 => Ok(ObjectId::EndpointConfiguration_Encoding_DefaultXml),
             333 => Ok(ObjectId::EndpointConfiguration_Encoding_DefaultBinary),
+            339 => Ok(ObjectId::BuildInfo_Encoding_DefaultXml),
             340 => Ok(ObjectId::BuildInfo_Encoding_DefaultBinary),
+            345 => Ok(ObjectId::SignedSoftwareCertificate_Encoding_DefaultXml),
             346 => Ok(ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary),
+            350 => Ok(ObjectId::NodeAttributes_Encoding_DefaultXml),
             351 => Ok(ObjectId::NodeAttributes_Encoding_DefaultBinary),
+            353 => Ok(ObjectId::ObjectAttributes_Encoding_DefaultXml),
             354 => Ok(ObjectId::ObjectAttributes_Encoding_DefaultBinary),
+            356 => Ok(ObjectId::VariableAttributes_Encoding_DefaultXml),
             357 => Ok(ObjectId::VariableAttributes_Encoding_DefaultBinary),
+            359 => Ok(ObjectId::MethodAttributes_Encoding_DefaultXml),
             360 => Ok(ObjectId::MethodAttributes_Encoding_DefaultBinary),
+            362 => Ok(ObjectId::ObjectTypeAttributes_Encoding_DefaultXml),
             363 => Ok(ObjectId::ObjectTypeAttributes_Encoding_DefaultBinary),
+            365 => Ok(ObjectId::VariableTypeAttributes_Encoding_DefaultXml),
             366 => Ok(ObjectId::VariableTypeAttributes_Encoding_DefaultBinary),
+            368 => Ok(ObjectId::ReferenceTypeAttributes_Encoding_DefaultXml),
             369 => Ok(ObjectId::ReferenceTypeAttributes_Encoding_DefaultBinary),
+            371 => Ok(ObjectId::DataTypeAttributes_Encoding_DefaultXml),
             372 => Ok(ObjectId::DataTypeAttributes_Encoding_DefaultBinary),
+            374 => Ok(ObjectId::ViewAttributes_Encoding_DefaultXml),
             375 => Ok(ObjectId::ViewAttributes_Encoding_DefaultBinary),
+            377 => Ok(ObjectId::AddNodesItem_Encoding_DefaultXml),
             378 => Ok(ObjectId::AddNodesItem_Encoding_DefaultBinary),
+            380 => Ok(ObjectId::AddReferencesItem_Encoding_DefaultXml),
             381 => Ok(ObjectId::AddReferencesItem_Encoding_DefaultBinary),
+            383 => Ok(ObjectId::DeleteNodesItem_Encoding_DefaultXml),
             384 => Ok(ObjectId::DeleteNodesItem_Encoding_DefaultBinary),
+            386 => Ok(ObjectId::DeleteReferencesItem_Encoding_DefaultXml),
             387 => Ok(ObjectId::DeleteReferencesItem_Encoding_DefaultBinary),
+            390 => Ok(ObjectId::RequestHeader_Encoding_DefaultXml),
             391 => Ok(ObjectId::RequestHeader_Encoding_DefaultBinary),
+            393 => Ok(ObjectId::ResponseHeader_Encoding_DefaultXml),
             394 => Ok(ObjectId::ResponseHeader_Encoding_DefaultBinary),
+            396 => Ok(ObjectId::ServiceFault_Encoding_DefaultXml),
             397 => Ok(ObjectId::ServiceFault_Encoding_DefaultBinary),
+            421 => Ok(ObjectId::FindServersRequest_Encoding_DefaultXml),
             422 => Ok(ObjectId::FindServersRequest_Encoding_DefaultBinary),
+            424 => Ok(ObjectId::FindServersResponse_Encoding_DefaultXml),
             425 => Ok(ObjectId::FindServersResponse_Encoding_DefaultBinary),
+            427 => Ok(ObjectId::GetEndpointsRequest_Encoding_DefaultXml),
             428 => Ok(ObjectId::GetEndpointsRequest_Encoding_DefaultBinary),
+            430 => Ok(ObjectId::GetEndpointsResponse_Encoding_DefaultXml),
             431 => Ok(ObjectId::GetEndpointsResponse_Encoding_DefaultBinary),
+            433 => Ok(ObjectId::RegisteredServer_Encoding_DefaultXml),
             434 => Ok(ObjectId::RegisteredServer_Encoding_DefaultBinary),
+            436 => Ok(ObjectId::RegisterServerRequest_Encoding_DefaultXml),
             437 => Ok(ObjectId::RegisterServerRequest_Encoding_DefaultBinary),
+            439 => Ok(ObjectId::RegisterServerResponse_Encoding_DefaultXml),
             440 => Ok(ObjectId::RegisterServerResponse_Encoding_DefaultBinary),
+            442 => Ok(ObjectId::ChannelSecurityToken_Encoding_DefaultXml),
             443 => Ok(ObjectId::ChannelSecurityToken_Encoding_DefaultBinary),
+            445 => Ok(ObjectId::OpenSecureChannelRequest_Encoding_DefaultXml),
             446 => Ok(ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary),
+            448 => Ok(ObjectId::OpenSecureChannelResponse_Encoding_DefaultXml),
             449 => Ok(ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary),
+            451 => Ok(ObjectId::CloseSecureChannelRequest_Encoding_DefaultXml),
             452 => Ok(ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary),
+            454 => Ok(ObjectId::CloseSecureChannelResponse_Encoding_DefaultXml),
             455 => Ok(ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary),
+            457 => Ok(ObjectId::SignatureData_Encoding_DefaultXml),
             458 => Ok(ObjectId::SignatureData_Encoding_DefaultBinary),
+            460 => Ok(ObjectId::CreateSessionRequest_Encoding_DefaultXml),
             461 => Ok(ObjectId::CreateSessionRequest_Encoding_DefaultBinary),
+            463 => Ok(ObjectId::CreateSessionResponse_Encoding_DefaultXml),
             464 => Ok(ObjectId::CreateSessionResponse_Encoding_DefaultBinary),
+            466 => Ok(ObjectId::ActivateSessionRequest_Encoding_DefaultXml),
             467 => Ok(ObjectId::ActivateSessionRequest_Encoding_DefaultBinary),
+            469 => Ok(ObjectId::ActivateSessionResponse_Encoding_DefaultXml),
             470 => Ok(ObjectId::ActivateSessionResponse_Encoding_DefaultBinary),
+            472 => Ok(ObjectId::CloseSessionRequest_Encoding_DefaultXml),
             473 => Ok(ObjectId::CloseSessionRequest_Encoding_DefaultBinary),
+            475 => Ok(ObjectId::CloseSessionResponse_Encoding_DefaultXml),
             476 => Ok(ObjectId::CloseSessionResponse_Encoding_DefaultBinary),
+            478 => Ok(ObjectId::CancelRequest_Encoding_DefaultXml),
             479 => Ok(ObjectId::CancelRequest_Encoding_DefaultBinary),
+            481 => Ok(ObjectId::CancelResponse_Encoding_DefaultXml),
             482 => Ok(ObjectId::CancelResponse_Encoding_DefaultBinary),
+            484 => Ok(ObjectId::AddNodesResult_Encoding_DefaultXml),
             485 => Ok(ObjectId::AddNodesResult_Encoding_DefaultBinary),
+            487 => Ok(ObjectId::AddNodesRequest_Encoding_DefaultXml),
             488 => Ok(ObjectId::AddNodesRequest_Encoding_DefaultBinary),
+            490 => Ok(ObjectId::AddNodesResponse_Encoding_DefaultXml),
             491 => Ok(ObjectId::AddNodesResponse_Encoding_DefaultBinary),
+            493 => Ok(ObjectId::AddReferencesRequest_Encoding_DefaultXml),
             494 => Ok(ObjectId::AddReferencesRequest_Encoding_DefaultBinary),
+            496 => Ok(ObjectId::AddReferencesResponse_Encoding_DefaultXml),
             497 => Ok(ObjectId::AddReferencesResponse_Encoding_DefaultBinary),
+            499 => Ok(ObjectId::DeleteNodesRequest_Encoding_DefaultXml),
             500 => Ok(ObjectId::DeleteNodesRequest_Encoding_DefaultBinary),
+            502 => Ok(ObjectId::DeleteNodesResponse_Encoding_DefaultXml),
             503 => Ok(ObjectId::DeleteNodesResponse_Encoding_DefaultBinary),
+            505 => Ok(ObjectId::DeleteReferencesRequest_Encoding_DefaultXml),
             506 => Ok(ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary),
+            508 => Ok(ObjectId::DeleteReferencesResponse_Encoding_DefaultXml),
             509 => Ok(ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary),
+            512 => Ok(ObjectId::ViewDescription_Encoding_DefaultXml),
             513 => Ok(ObjectId::ViewDescription_Encoding_DefaultBinary),
+            515 => Ok(ObjectId::BrowseDescription_Encoding_DefaultXml),
             516 => Ok(ObjectId::BrowseDescription_Encoding_DefaultBinary),
+            519 => Ok(ObjectId::ReferenceDescription_Encoding_DefaultXml),
             520 => Ok(ObjectId::ReferenceDescription_Encoding_DefaultBinary),
+            523 => Ok(ObjectId::BrowseResult_Encoding_DefaultXml),
             524 => Ok(ObjectId::BrowseResult_Encoding_DefaultBinary),
+            526 => Ok(ObjectId::BrowseRequest_Encoding_DefaultXml),
             527 => Ok(ObjectId::BrowseRequest_Encoding_DefaultBinary),
+            529 => Ok(ObjectId::BrowseResponse_Encoding_DefaultXml),
             530 => Ok(ObjectId::BrowseResponse_Encoding_DefaultBinary),
+            532 => Ok(ObjectId::BrowseNextRequest_Encoding_DefaultXml),
             533 => Ok(ObjectId::BrowseNextRequest_Encoding_DefaultBinary),
+            535 => Ok(ObjectId::BrowseNextResponse_Encoding_DefaultXml),
             536 => Ok(ObjectId::BrowseNextResponse_Encoding_DefaultBinary),
+            538 => Ok(ObjectId::RelativePathElement_Encoding_DefaultXml),
             539 => Ok(ObjectId::RelativePathElement_Encoding_DefaultBinary),
+            541 => Ok(ObjectId::RelativePath_Encoding_DefaultXml),
             542 => Ok(ObjectId::RelativePath_Encoding_DefaultBinary),
+            544 => Ok(ObjectId::BrowsePath_Encoding_DefaultXml),
             545 => Ok(ObjectId::BrowsePath_Encoding_DefaultBinary),
+            547 => Ok(ObjectId::BrowsePathTarget_Encoding_DefaultXml),
             548 => Ok(ObjectId::BrowsePathTarget_Encoding_DefaultBinary),
+            550 => Ok(ObjectId::BrowsePathResult_Encoding_DefaultXml),
             551 => Ok(ObjectId::BrowsePathResult_Encoding_DefaultBinary),
+            553 => Ok(ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml),
             554 => Ok(ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary),
+            556 => Ok(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml),
             557 => Ok(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary),
+            559 => Ok(ObjectId::RegisterNodesRequest_Encoding_DefaultXml),
             560 => Ok(ObjectId::RegisterNodesRequest_Encoding_DefaultBinary),
+            562 => Ok(ObjectId::RegisterNodesResponse_Encoding_DefaultXml),
             563 => Ok(ObjectId::RegisterNodesResponse_Encoding_DefaultBinary),
+            565 => Ok(ObjectId::UnregisterNodesRequest_Encoding_DefaultXml),
             566 => Ok(ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary),
+            568 => Ok(ObjectId::UnregisterNodesResponse_Encoding_DefaultXml),
             569 => Ok(ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary),
+            571 => Ok(ObjectId::QueryDataDescription_Encoding_DefaultXml),
             572 => Ok(ObjectId::QueryDataDescription_Encoding_DefaultBinary),
+            574 => Ok(ObjectId::NodeTypeDescription_Encoding_DefaultXml),
             575 => Ok(ObjectId::NodeTypeDescription_Encoding_DefaultBinary),
+            578 => Ok(ObjectId::QueryDataSet_Encoding_DefaultXml),
             579 => Ok(ObjectId::QueryDataSet_Encoding_DefaultBinary),
+            581 => Ok(ObjectId::NodeReference_Encoding_DefaultXml),
             582 => Ok(ObjectId::NodeReference_Encoding_DefaultBinary),
+            584 => Ok(ObjectId::ContentFilterElement_Encoding_DefaultXml),
             585 => Ok(ObjectId::ContentFilterElement_Encoding_DefaultBinary),
+            587 => Ok(ObjectId::ContentFilter_Encoding_DefaultXml),
             588 => Ok(ObjectId::ContentFilter_Encoding_DefaultBinary),
+            590 => Ok(ObjectId::FilterOperand_Encoding_DefaultXml),
             591 => Ok(ObjectId::FilterOperand_Encoding_DefaultBinary),
+            593 => Ok(ObjectId::ElementOperand_Encoding_DefaultXml),
             594 => Ok(ObjectId::ElementOperand_Encoding_DefaultBinary),
+            596 => Ok(ObjectId::LiteralOperand_Encoding_DefaultXml),
             597 => Ok(ObjectId::LiteralOperand_Encoding_DefaultBinary),
+            599 => Ok(ObjectId::AttributeOperand_Encoding_DefaultXml),
             600 => Ok(ObjectId::AttributeOperand_Encoding_DefaultBinary),
+            602 => Ok(ObjectId::SimpleAttributeOperand_Encoding_DefaultXml),
             603 => Ok(ObjectId::SimpleAttributeOperand_Encoding_DefaultBinary),
+            605 => Ok(ObjectId::ContentFilterElementResult_Encoding_DefaultXml),
             606 => Ok(ObjectId::ContentFilterElementResult_Encoding_DefaultBinary),
+            608 => Ok(ObjectId::ContentFilterResult_Encoding_DefaultXml),
             609 => Ok(ObjectId::ContentFilterResult_Encoding_DefaultBinary),
+            611 => Ok(ObjectId::ParsingResult_Encoding_DefaultXml),
             612 => Ok(ObjectId::ParsingResult_Encoding_DefaultBinary),
+            614 => Ok(ObjectId::QueryFirstRequest_Encoding_DefaultXml),
             615 => Ok(ObjectId::QueryFirstRequest_Encoding_DefaultBinary),
+            617 => Ok(ObjectId::QueryFirstResponse_Encoding_DefaultXml),
             618 => Ok(ObjectId::QueryFirstResponse_Encoding_DefaultBinary),
+            620 => Ok(ObjectId::QueryNextRequest_Encoding_DefaultXml),
             621 => Ok(ObjectId::QueryNextRequest_Encoding_DefaultBinary),
+            623 => Ok(ObjectId::QueryNextResponse_Encoding_DefaultXml),
             624 => Ok(ObjectId::QueryNextResponse_Encoding_DefaultBinary),
+            627 => Ok(ObjectId::ReadValueId_Encoding_DefaultXml),
             628 => Ok(ObjectId::ReadValueId_Encoding_DefaultBinary),
+            630 => Ok(ObjectId::ReadRequest_Encoding_DefaultXml),
             631 => Ok(ObjectId::ReadRequest_Encoding_DefaultBinary),
+            633 => Ok(ObjectId::ReadResponse_Encoding_DefaultXml),
             634 => Ok(ObjectId::ReadResponse_Encoding_DefaultBinary),
+            636 => Ok(ObjectId::HistoryReadValueId_Encoding_DefaultXml),
             637 => Ok(ObjectId::HistoryReadValueId_Encoding_DefaultBinary),
+            639 => Ok(ObjectId::HistoryReadResult_Encoding_DefaultXml),
             640 => Ok(ObjectId::HistoryReadResult_Encoding_DefaultBinary),
+            642 => Ok(ObjectId::HistoryReadDetails_Encoding_DefaultXml),
             643 => Ok(ObjectId::HistoryReadDetails_Encoding_DefaultBinary),
+            645 => Ok(ObjectId::ReadEventDetails_Encoding_DefaultXml),
             646 => Ok(ObjectId::ReadEventDetails_Encoding_DefaultBinary),
+            648 => Ok(ObjectId::ReadRawModifiedDetails_Encoding_DefaultXml),
             649 => Ok(ObjectId::ReadRawModifiedDetails_Encoding_DefaultBinary),
+            651 => Ok(ObjectId::ReadProcessedDetails_Encoding_DefaultXml),
             652 => Ok(ObjectId::ReadProcessedDetails_Encoding_DefaultBinary),
+            654 => Ok(ObjectId::ReadAtTimeDetails_Encoding_DefaultXml),
             655 => Ok(ObjectId::ReadAtTimeDetails_Encoding_DefaultBinary),
+            657 => Ok(ObjectId::HistoryData_Encoding_DefaultXml),
             658 => Ok(ObjectId::HistoryData_Encoding_DefaultBinary),
+            660 => Ok(ObjectId::HistoryEvent_Encoding_DefaultXml),
             661 => Ok(ObjectId::HistoryEvent_Encoding_DefaultBinary),
+            663 => Ok(ObjectId::HistoryReadRequest_Encoding_DefaultXml),
             664 => Ok(ObjectId::HistoryReadRequest_Encoding_DefaultBinary),
+            666 => Ok(ObjectId::HistoryReadResponse_Encoding_DefaultXml),
             667 => Ok(ObjectId::HistoryReadResponse_Encoding_DefaultBinary),
+            669 => Ok(ObjectId::WriteValue_Encoding_DefaultXml),
             670 => Ok(ObjectId::WriteValue_Encoding_DefaultBinary),
+            672 => Ok(ObjectId::WriteRequest_Encoding_DefaultXml),
             673 => Ok(ObjectId::WriteRequest_Encoding_DefaultBinary),
+            675 => Ok(ObjectId::WriteResponse_Encoding_DefaultXml),
             676 => Ok(ObjectId::WriteResponse_Encoding_DefaultBinary),
+            678 => Ok(ObjectId::HistoryUpdateDetails_Encoding_DefaultXml),
             679 => Ok(ObjectId::HistoryUpdateDetails_Encoding_DefaultBinary),
+            681 => Ok(ObjectId::UpdateDataDetails_Encoding_DefaultXml),
             682 => Ok(ObjectId::UpdateDataDetails_Encoding_DefaultBinary),
+            684 => Ok(ObjectId::UpdateEventDetails_Encoding_DefaultXml),
             685 => Ok(ObjectId::UpdateEventDetails_Encoding_DefaultBinary),
+            687 => Ok(ObjectId::DeleteRawModifiedDetails_Encoding_DefaultXml),
             688 => Ok(ObjectId::DeleteRawModifiedDetails_Encoding_DefaultBinary),
+            690 => Ok(ObjectId::DeleteAtTimeDetails_Encoding_DefaultXml),
             691 => Ok(ObjectId::DeleteAtTimeDetails_Encoding_DefaultBinary),
+            693 => Ok(ObjectId::DeleteEventDetails_Encoding_DefaultXml),
             694 => Ok(ObjectId::DeleteEventDetails_Encoding_DefaultBinary),
+            696 => Ok(ObjectId::HistoryUpdateResult_Encoding_DefaultXml),
             697 => Ok(ObjectId::HistoryUpdateResult_Encoding_DefaultBinary),
+            699 => Ok(ObjectId::HistoryUpdateRequest_Encoding_DefaultXml),
             700 => Ok(ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary),
+            702 => Ok(ObjectId::HistoryUpdateResponse_Encoding_DefaultXml),
             703 => Ok(ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary),
+            705 => Ok(ObjectId::CallMethodRequest_Encoding_DefaultXml),
             706 => Ok(ObjectId::CallMethodRequest_Encoding_DefaultBinary),
+            708 => Ok(ObjectId::CallMethodResult_Encoding_DefaultXml),
             709 => Ok(ObjectId::CallMethodResult_Encoding_DefaultBinary),
+            711 => Ok(ObjectId::CallRequest_Encoding_DefaultXml),
             712 => Ok(ObjectId::CallRequest_Encoding_DefaultBinary),
+            714 => Ok(ObjectId::CallResponse_Encoding_DefaultXml),
             715 => Ok(ObjectId::CallResponse_Encoding_DefaultBinary),
+            720 => Ok(ObjectId::MonitoringFilter_Encoding_DefaultXml),
             721 => Ok(ObjectId::MonitoringFilter_Encoding_DefaultBinary),
+            723 => Ok(ObjectId::DataChangeFilter_Encoding_DefaultXml),
             724 => Ok(ObjectId::DataChangeFilter_Encoding_DefaultBinary),
+            726 => Ok(ObjectId::EventFilter_Encoding_DefaultXml),
             727 => Ok(ObjectId::EventFilter_Encoding_DefaultBinary),
+            729 => Ok(ObjectId::AggregateFilter_Encoding_DefaultXml),
             730 => Ok(ObjectId::AggregateFilter_Encoding_DefaultBinary),
+            732 => Ok(ObjectId::MonitoringFilterResult_Encoding_DefaultXml),
             733 => Ok(ObjectId::MonitoringFilterResult_Encoding_DefaultBinary),
+            735 => Ok(ObjectId::EventFilterResult_Encoding_DefaultXml),
             736 => Ok(ObjectId::EventFilterResult_Encoding_DefaultBinary),
+            738 => Ok(ObjectId::AggregateFilterResult_Encoding_DefaultXml),
             739 => Ok(ObjectId::AggregateFilterResult_Encoding_DefaultBinary),
+            741 => Ok(ObjectId::MonitoringParameters_Encoding_DefaultXml),
             742 => Ok(ObjectId::MonitoringParameters_Encoding_DefaultBinary),
+            744 => Ok(ObjectId::MonitoredItemCreateRequest_Encoding_DefaultXml),
             745 => Ok(ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary),
+            747 => Ok(ObjectId::MonitoredItemCreateResult_Encoding_DefaultXml),
             748 => Ok(ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary),
+            750 => Ok(ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultXml),
             751 => Ok(ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary),
+            753 => Ok(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultXml),
             754 => Ok(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary),
+            756 => Ok(ObjectId::MonitoredItemModifyRequest_Encoding_DefaultXml),
             757 => Ok(ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary),
+            759 => Ok(ObjectId::MonitoredItemModifyResult_Encoding_DefaultXml),
             760 => Ok(ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary),
+            762 => Ok(ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultXml),
             763 => Ok(ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary),
+            765 => Ok(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultXml),
             766 => Ok(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary),
+            768 => Ok(ObjectId::SetMonitoringModeRequest_Encoding_DefaultXml),
             769 => Ok(ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary),
+            771 => Ok(ObjectId::SetMonitoringModeResponse_Encoding_DefaultXml),
             772 => Ok(ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary),
+            774 => Ok(ObjectId::SetTriggeringRequest_Encoding_DefaultXml),
             775 => Ok(ObjectId::SetTriggeringRequest_Encoding_DefaultBinary),
+            777 => Ok(ObjectId::SetTriggeringResponse_Encoding_DefaultXml),
             778 => Ok(ObjectId::SetTriggeringResponse_Encoding_DefaultBinary),
+            780 => Ok(ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultXml),
             781 => Ok(ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary),
+            783 => Ok(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultXml),
             784 => Ok(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary),
+            786 => Ok(ObjectId::CreateSubscriptionRequest_Encoding_DefaultXml),
             787 => Ok(ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary),
+            789 => Ok(ObjectId::CreateSubscriptionResponse_Encoding_DefaultXml),
             790 => Ok(ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary),
+            792 => Ok(ObjectId::ModifySubscriptionRequest_Encoding_DefaultXml),
             793 => Ok(ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary),
+            795 => Ok(ObjectId::ModifySubscriptionResponse_Encoding_DefaultXml),
             796 => Ok(ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary),
+            798 => Ok(ObjectId::SetPublishingModeRequest_Encoding_DefaultXml),
             799 => Ok(ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary),
+            801 => Ok(ObjectId::SetPublishingModeResponse_Encoding_DefaultXml),
             802 => Ok(ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary),
+            804 => Ok(ObjectId::NotificationMessage_Encoding_DefaultXml),
             805 => Ok(ObjectId::NotificationMessage_Encoding_DefaultBinary),
+            807 => Ok(ObjectId::MonitoredItemNotification_Encoding_DefaultXml),
             808 => Ok(ObjectId::MonitoredItemNotification_Encoding_DefaultBinary),
+            810 => Ok(ObjectId::DataChangeNotification_Encoding_DefaultXml),
             811 => Ok(ObjectId::DataChangeNotification_Encoding_DefaultBinary),
+            819 => Ok(ObjectId::StatusChangeNotification_Encoding_DefaultXml),
             820 => Ok(ObjectId::StatusChangeNotification_Encoding_DefaultBinary),
+            822 => Ok(ObjectId::SubscriptionAcknowledgement_Encoding_DefaultXml),
             823 => Ok(ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary),
+            825 => Ok(ObjectId::PublishRequest_Encoding_DefaultXml),
             826 => Ok(ObjectId::PublishRequest_Encoding_DefaultBinary),
+            828 => Ok(ObjectId::PublishResponse_Encoding_DefaultXml),
             829 => Ok(ObjectId::PublishResponse_Encoding_DefaultBinary),
+            831 => Ok(ObjectId::RepublishRequest_Encoding_DefaultXml),
             832 => Ok(ObjectId::RepublishRequest_Encoding_DefaultBinary),
+            834 => Ok(ObjectId::RepublishResponse_Encoding_DefaultXml),
             835 => Ok(ObjectId::RepublishResponse_Encoding_DefaultBinary),
+            837 => Ok(ObjectId::TransferResult_Encoding_DefaultXml),
             838 => Ok(ObjectId::TransferResult_Encoding_DefaultBinary),
+            840 => Ok(ObjectId::TransferSubscriptionsRequest_Encoding_DefaultXml),
             841 => Ok(ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary),
+            843 => Ok(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultXml),
             844 => Ok(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary),
+            846 => Ok(ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultXml),
             847 => Ok(ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary),
+            849 => Ok(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultXml),
             850 => Ok(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary),
+            854 => Ok(ObjectId::RedundantServerDataType_Encoding_DefaultXml),
             855 => Ok(ObjectId::RedundantServerDataType_Encoding_DefaultBinary),
+            857 => Ok(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml),
             858 => Ok(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary),
+            860 => Ok(ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultXml),
             861 => Ok(ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary),
+            863 => Ok(ObjectId::ServerStatusDataType_Encoding_DefaultXml),
             864 => Ok(ObjectId::ServerStatusDataType_Encoding_DefaultBinary),
+            866 => Ok(ObjectId::SessionDiagnosticsDataType_Encoding_DefaultXml),
             867 => Ok(ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary),
+            869 => Ok(ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultXml),
             870 => Ok(ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary),
+            872 => Ok(ObjectId::ServiceCounterDataType_Encoding_DefaultXml),
             873 => Ok(ObjectId::ServiceCounterDataType_Encoding_DefaultBinary),
+            875 => Ok(ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultXml),
             876 => Ok(ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary),
+            878 => Ok(ObjectId::ModelChangeStructureDataType_Encoding_DefaultXml),
             879 => Ok(ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary),
+            885 => Ok(ObjectId::Range_Encoding_DefaultXml),
             886 => Ok(ObjectId::Range_Encoding_DefaultBinary),
+            888 => Ok(ObjectId::EUInformation_Encoding_DefaultXml),
             889 => Ok(ObjectId::EUInformation_Encoding_DefaultBinary),
+            892 => Ok(ObjectId::Annotation_Encoding_DefaultXml),
             893 => Ok(ObjectId::Annotation_Encoding_DefaultBinary),
+            895 => Ok(ObjectId::ProgramDiagnosticDataType_Encoding_DefaultXml),
             896 => Ok(ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary),
+            898 => Ok(ObjectId::SemanticChangeStructureDataType_Encoding_DefaultXml),
             899 => Ok(ObjectId::SemanticChangeStructureDataType_Encoding_DefaultBinary),
+            915 => Ok(ObjectId::EventNotificationList_Encoding_DefaultXml),
             916 => Ok(ObjectId::EventNotificationList_Encoding_DefaultBinary),
+            918 => Ok(ObjectId::EventFieldList_Encoding_DefaultXml),
             919 => Ok(ObjectId::EventFieldList_Encoding_DefaultBinary),
+            921 => Ok(ObjectId::HistoryEventFieldList_Encoding_DefaultXml),
             922 => Ok(ObjectId::HistoryEventFieldList_Encoding_DefaultBinary),
+            939 => Ok(ObjectId::IssuedIdentityToken_Encoding_DefaultXml),
             940 => Ok(ObjectId::IssuedIdentityToken_Encoding_DefaultBinary),
+            946 => Ok(ObjectId::NotificationData_Encoding_DefaultXml),
             947 => Ok(ObjectId::NotificationData_Encoding_DefaultBinary),
+            949 => Ok(ObjectId::AggregateConfiguration_Encoding_DefaultXml),
             950 => Ok(ObjectId::AggregateConfiguration_Encoding_DefaultBinary),
             2009 => Ok(ObjectId::ServerType_ServerCapabilities),
             2010 => Ok(ObjectId::ServerType_ServerDiagnostics),
@@ -1778,7 +2195,9 @@ impl ObjectId {
             3111 => Ok(ObjectId::ServerType_ServerDiagnostics_SessionsDiagnosticsSummary),
             3706 => Ok(ObjectId::Server_ServerDiagnostics_SessionsDiagnosticsSummary),
             3850 => Ok(ObjectId::ProgramStateMachineType_FinalResultData),
+            7616 => Ok(ObjectId::EnumValueType_Encoding_DefaultXml),
             8251 => Ok(ObjectId::EnumValueType_Encoding_DefaultBinary),
+            8913 => Ok(ObjectId::TimeZoneDataType_Encoding_DefaultXml),
             8917 => Ok(ObjectId::TimeZoneDataType_Encoding_DefaultBinary),
             9178 => Ok(ObjectId::AlarmConditionType_ShelvingState),
             9279 => Ok(ObjectId::LimitAlarmType_ShelvingState),
@@ -1810,6 +2229,8 @@ impl ObjectId {
             11201 => Ok(ObjectId::HistoryServerCapabilities_AggregateFunctions),
             11202 => Ok(ObjectId::HAConfiguration),
             11203 => Ok(ObjectId::HAConfiguration_AggregateConfiguration),
+            11218 => Ok(ObjectId::ModificationInfo_Encoding_DefaultXml),
+            11219 => Ok(ObjectId::HistoryModifiedData_Encoding_DefaultXml),
             11226 => Ok(ObjectId::ModificationInfo_Encoding_DefaultBinary),
             11227 => Ok(ObjectId::HistoryModifiedData_Encoding_DefaultBinary),
             11285 => Ok(ObjectId::AggregateFunction_TimeAverage2),
@@ -1817,6 +2238,7 @@ impl ObjectId {
             11287 => Ok(ObjectId::AggregateFunction_Maximum2),
             11288 => Ok(ObjectId::AggregateFunction_Range2),
             11292 => Ok(ObjectId::AggregateFunction_WorstQuality2),
+            11296 => Ok(ObjectId::UpdateStructureDataDetails_Encoding_DefaultXml),
             11300 => Ok(ObjectId::UpdateStructureDataDetails_Encoding_DefaultBinary),
             11304 => Ok(ObjectId::AggregateFunction_Total2),
             11305 => Ok(ObjectId::AggregateFunction_MinimumActualTime2),
@@ -1846,15 +2268,28 @@ impl ObjectId {
             11831 => Ok(ObjectId::SystemOffNormalAlarmType_ShelvingState),
             11876 => Ok(ObjectId::HistoricalDataConfigurationType_AggregateFunctions),
             11877 => Ok(ObjectId::HAConfiguration_AggregateFunctions),
+            11887 => Ok(ObjectId::InstanceNode_Encoding_DefaultXml),
+            11888 => Ok(ObjectId::TypeNode_Encoding_DefaultXml),
             11889 => Ok(ObjectId::InstanceNode_Encoding_DefaultBinary),
             11890 => Ok(ObjectId::TypeNode_Encoding_DefaultBinary),
+            11949 => Ok(ObjectId::EndpointUrlListDataType_Encoding_DefaultXml),
+            11950 => Ok(ObjectId::NetworkGroupDataType_Encoding_DefaultXml),
             11957 => Ok(ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary),
             11958 => Ok(ObjectId::NetworkGroupDataType_Encoding_DefaultBinary),
+            12081 => Ok(ObjectId::AxisInformation_Encoding_DefaultXml),
+            12082 => Ok(ObjectId::XVType_Encoding_DefaultXml),
             12089 => Ok(ObjectId::AxisInformation_Encoding_DefaultBinary),
             12090 => Ok(ObjectId::XVType_Encoding_DefaultBinary),
             12097 => Ok(ObjectId::SessionsDiagnosticsSummaryType_ClientName_Placeholder),
+            12173 => Ok(ObjectId::ComplexNumberType_Encoding_DefaultXml),
+            12174 => Ok(ObjectId::DoubleComplexNumberType_Encoding_DefaultXml),
             12181 => Ok(ObjectId::ComplexNumberType_Encoding_DefaultBinary),
             12182 => Ok(ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary),
+            12195 => Ok(ObjectId::ServerOnNetwork_Encoding_DefaultXml),
+            12196 => Ok(ObjectId::FindServersOnNetworkRequest_Encoding_DefaultXml),
+            12197 => Ok(ObjectId::FindServersOnNetworkResponse_Encoding_DefaultXml),
+            12199 => Ok(ObjectId::RegisterServer2Request_Encoding_DefaultXml),
+            12200 => Ok(ObjectId::RegisterServer2Response_Encoding_DefaultXml),
             12207 => Ok(ObjectId::ServerOnNetwork_Encoding_DefaultBinary),
             12208 => Ok(ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary),
             12209 => Ok(ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary),
@@ -1862,9 +2297,14 @@ impl ObjectId {
             12212 => Ok(ObjectId::RegisterServer2Response_Encoding_DefaultBinary),
             12637 => Ok(ObjectId::ServerConfiguration),
             12642 => Ok(ObjectId::ServerConfiguration_CertificateGroups_DefaultApplicationGroup_TrustList),
+            12676 => Ok(ObjectId::TrustListDataType_Encoding_DefaultXml),
             12680 => Ok(ObjectId::TrustListDataType_Encoding_DefaultBinary),
+            12757 => Ok(ObjectId::OptionSet_Encoding_DefaultXml),
+            12758 => Ok(ObjectId::Union_Encoding_DefaultXml),
             12765 => Ok(ObjectId::OptionSet_Encoding_DefaultBinary),
             12766 => Ok(ObjectId::Union_Encoding_DefaultBinary),
+            12892 => Ok(ObjectId::DiscoveryConfiguration_Encoding_DefaultXml),
+            12893 => Ok(ObjectId::MdnsDiscoveryConfiguration_Encoding_DefaultXml),
             12900 => Ok(ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary),
             12901 => Ok(ObjectId::MdnsDiscoveryConfiguration_Encoding_DefaultBinary),
             13305 => Ok(ObjectId::CertificateExpirationAlarmType_ShelvingState),
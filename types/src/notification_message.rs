@@ -4,10 +4,38 @@ use crate::{
     encoding::DecodingLimits,
     extension_object::ExtensionObject,
     node_ids::ObjectId,
-    service_types::{NotificationMessage, MonitoredItemNotification, DataChangeNotification},
+    service_types::{NotificationMessage, MonitoredItemNotification, DataChangeNotification, EventFieldList, EventNotificationList, StatusChangeNotification},
+    status_code::StatusCode,
+    diagnostic_info::DiagnosticInfo,
 };
 
 impl NotificationMessage {
+    /// Creates a notification message holding data changes and / or events. A notification is
+    /// only added for each kind that is not empty.
+    pub fn new(sequence_number: u32, publish_time: DateTime, monitored_items: Vec<MonitoredItemNotification>, events: Vec<EventFieldList>) -> NotificationMessage {
+        let mut notification_data = Vec::with_capacity(2);
+        if !monitored_items.is_empty() {
+            let data_change_notification = DataChangeNotification {
+                monitored_items: Some(monitored_items),
+                diagnostic_infos: None,
+            };
+            trace!("data change notification = {:?}", data_change_notification);
+            notification_data.push(ExtensionObject::from_encodable(ObjectId::DataChangeNotification_Encoding_DefaultBinary, &data_change_notification));
+        }
+        if !events.is_empty() {
+            let event_notification_list = EventNotificationList {
+                events: Some(events),
+            };
+            trace!("event notification list = {:?}", event_notification_list);
+            notification_data.push(ExtensionObject::from_encodable(ObjectId::EventNotificationList_Encoding_DefaultBinary, &event_notification_list));
+        }
+        NotificationMessage {
+            sequence_number,
+            publish_time,
+            notification_data: Some(notification_data),
+        }
+    }
+
     pub fn data_change(sequence_number: u32, publish_time: DateTime, monitored_items: Vec<MonitoredItemNotification>) -> NotificationMessage {
         let data_change_notification = DataChangeNotification {
             monitored_items: Some(monitored_items),
@@ -33,6 +61,21 @@ impl NotificationMessage {
         }
     }
 
+    /// Creates a notification message holding a change to the status of the subscription, e.g.
+    /// that it was transferred to another session
+    pub fn status_change(sequence_number: u32, publish_time: DateTime, status: StatusCode) -> NotificationMessage {
+        let status_change_notification = StatusChangeNotification {
+            status,
+            diagnostic_info: DiagnosticInfo::default(),
+        };
+        let notification_data = ExtensionObject::from_encodable(ObjectId::StatusChangeNotification_Encoding_DefaultBinary, &status_change_notification);
+        NotificationMessage {
+            sequence_number,
+            publish_time,
+            notification_data: Some(vec![notification_data]),
+        }
+    }
+
     pub fn data_change_notifications(&self, decoding_limits: &DecodingLimits) -> Vec<DataChangeNotification> {
         let mut result = Vec::with_capacity(10);
         if let Some(ref notification_data) = self.notification_data {
@@ -48,4 +91,19 @@ impl NotificationMessage {
         }
         result
     }
+
+    pub fn event_notifications(&self, decoding_limits: &DecodingLimits) -> Vec<EventNotificationList> {
+        let mut result = Vec::with_capacity(10);
+        if let Some(ref notification_data) = self.notification_data {
+            for n in notification_data {
+                if n.node_id != ObjectId::EventNotificationList_Encoding_DefaultBinary.into() {
+                    continue;
+                }
+                if let Ok(notification) = n.decode_inner::<EventNotificationList>(decoding_limits) {
+                    result.push(notification);
+                }
+            }
+        }
+        result
+    }
 }
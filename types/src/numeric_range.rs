@@ -32,7 +32,7 @@ use crate::variant::Variant;
 ///
 /// All indexes start with `0`. The maximum value for any index is one less than the length of the
 /// dimension.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum NumericRange {
     /// A single index
     Index(u32),
@@ -102,6 +102,21 @@ fn invalid_numeric_ranges() {
     }
 }
 
+#[test]
+fn numeric_range_is_valid_for_array() {
+    let array = Variant::from(vec![1i32, 2, 3]);
+    assert!(NumericRange::Index(2).is_valid_for_array(&array));
+    assert!(NumericRange::Range(1, 5).is_valid_for_array(&array));
+    assert!(!NumericRange::Index(3).is_valid_for_array(&array));
+    assert!(!NumericRange::MultipleRanges(vec![NumericRange::Index(0), NumericRange::Index(0)]).is_valid_for_array(&array));
+
+    let array = Variant::new_multi_dimension_array(vec![Variant::from(1), Variant::from(2)], vec![1, 2]);
+    assert!(NumericRange::MultipleRanges(vec![NumericRange::Index(0), NumericRange::Range(0, 1)]).is_valid_for_array(&array));
+    assert!(!NumericRange::MultipleRanges(vec![NumericRange::Index(1), NumericRange::Index(0)]).is_valid_for_array(&array));
+    assert!(!NumericRange::Index(0).is_valid_for_array(&array));
+    assert!(!NumericRange::Index(0).is_valid_for_array(&Variant::from(1)));
+}
+
 const MAX_INDICES: usize = 10;
 
 impl FromStr for NumericRange {
@@ -225,21 +240,31 @@ impl NumericRange {
         }
     }
 
-    // This version should test the range against the supplied array
+    /// Returns the lower and upper bounds of each dimension of the range. The bounds of an index
+    /// are both the index.
+    pub fn dimensions(&self) -> Vec<(u32, u32)> {
+        match *self {
+            NumericRange::Index(idx) => vec![(idx, idx)],
+            NumericRange::Range(min, max) => vec![(min, max)],
+            NumericRange::MultipleRanges(ref ranges) => ranges.iter().flat_map(|r| r.dimensions()).collect(),
+        }
+    }
+
+    /// Tests if the range is valid for the supplied array, i.e. that it has a dimension for each
+    /// dimension of the array and that the lower bound of each lies inside that dimension.
     pub fn is_valid_for_array(&self, array: &Variant) -> bool {
-        match *array {
-            Variant::Array(_) => {
-                // Only accept range / index numeric ranges. Members of range have to be inside array
-                unimplemented!();
-            }
-            Variant::MultiDimensionArray(_) => {
-                // Only accept multi dimension numeric range with same # of dimensions as the array
-                // ensure that each range / index is inside that array dimension
-                unimplemented!();
-            }
+        if !self.is_valid() {
+            return false;
+        }
+        let array_dimensions = match *array {
+            Variant::Array(ref values) => vec![values.len()],
+            Variant::MultiDimensionArray(ref mda) => mda.dimensions.iter().map(|d| *d as usize).collect(),
             _ => {
-                panic!("The array is not an array")
+                return false;
             }
-        }
+        };
+        let dimensions = self.dimensions();
+        dimensions.len() == array_dimensions.len() &&
+            dimensions.iter().zip(array_dimensions.iter()).all(|((min, _), len)| (*min as usize) < *len)
     }
 }
@@ -1,6 +1,9 @@
 //! Contains functions used for making relative paths from / to strings, as per OPC UA Part 4, Appendix A
 
+use std::{iter::Peekable, str::Chars};
+
 use crate::{
+    basic_types::QualifiedName,
     node_ids::*,
     node_id::{Identifier, NodeId},
     service_types::{RelativePath, RelativePathElement},
@@ -26,52 +29,63 @@ pub(crate) fn unescape_browse_name(name: &str) -> String {
     result
 }
 
+/// The standard reference types that a relative path can name, with their browse names
+const REFERENCE_TYPES: &[(ReferenceTypeId, &str)] = &[
+    (ReferenceTypeId::References, "References"),
+    (ReferenceTypeId::NonHierarchicalReferences, "NonHierarchicalReferences"),
+    (ReferenceTypeId::HierarchicalReferences, "HierarchicalReferences"),
+    (ReferenceTypeId::HasChild, "HasChild"),
+    (ReferenceTypeId::Organizes, "Organizes"),
+    (ReferenceTypeId::HasEventSource, "HasEventSource"),
+    (ReferenceTypeId::HasModellingRule, "HasModellingRule"),
+    (ReferenceTypeId::HasEncoding, "HasEncoding"),
+    (ReferenceTypeId::HasDescription, "HasDescription"),
+    (ReferenceTypeId::HasTypeDefinition, "HasTypeDefinition"),
+    (ReferenceTypeId::GeneratesEvent, "GeneratesEvent"),
+    (ReferenceTypeId::Aggregates, "Aggregates"),
+    (ReferenceTypeId::HasSubtype, "HasSubtype"),
+    (ReferenceTypeId::HasProperty, "HasProperty"),
+    (ReferenceTypeId::HasComponent, "HasComponent"),
+    (ReferenceTypeId::HasNotifier, "HasNotifier"),
+    (ReferenceTypeId::HasOrderedComponent, "HasOrderedComponent"),
+    (ReferenceTypeId::FromState, "FromState"),
+    (ReferenceTypeId::ToState, "ToState"),
+    (ReferenceTypeId::HasCause, "HasCause"),
+    (ReferenceTypeId::HasEffect, "HasEffect"),
+    (ReferenceTypeId::HasHistoricalConfiguration, "HasHistoricalConfiguration"),
+    (ReferenceTypeId::HasSubStateMachine, "HasSubStateMachine"),
+    (ReferenceTypeId::AlwaysGeneratesEvent, "AlwaysGeneratesEvent"),
+    (ReferenceTypeId::HasTrueSubState, "HasTrueSubState"),
+    (ReferenceTypeId::HasFalseSubState, "HasFalseSubState"),
+    (ReferenceTypeId::HasCondition, "HasCondition"),
+];
+
 fn reference_type_browse_name(node_id: &NodeId) -> Result<String, ()> {
     match &node_id.identifier {
         Identifier::String(browse_name) => Ok(browse_name.to_string()),
-        Identifier::Numeric(id) => {
-            if node_id.namespace == 0 {
-                let id = *id;
-                // This syntax for matching a number to an enum is just the worst
-                let browse_name = match id {
-                    id if id == ReferenceTypeId::References as u32 => "References",
-                    id if id == ReferenceTypeId::NonHierarchicalReferences as u32 => "NonHierarchicalReferences",
-                    id if id == ReferenceTypeId::HierarchicalReferences as u32 => "HierarchicalReferences",
-                    id if id == ReferenceTypeId::HasChild as u32 => "HasChild",
-                    id if id == ReferenceTypeId::Organizes as u32 => "Organizes",
-                    id if id == ReferenceTypeId::HasEventSource as u32 => "HasEventSource",
-                    id if id == ReferenceTypeId::HasModellingRule as u32 => "HasModellingRule",
-                    id if id == ReferenceTypeId::HasEncoding as u32 => "HasEncoding",
-                    id if id == ReferenceTypeId::HasDescription as u32 => "HasDescription",
-                    id if id == ReferenceTypeId::HasTypeDefinition as u32 => "HasTypeDefinition",
-                    id if id == ReferenceTypeId::GeneratesEvent as u32 => "GeneratesEvent",
-                    id if id == ReferenceTypeId::Aggregates as u32 => "Aggregates",
-                    id if id == ReferenceTypeId::HasSubtype as u32 => "HasSubtype",
-                    id if id == ReferenceTypeId::HasProperty as u32 => "HasProperty",
-                    id if id == ReferenceTypeId::HasComponent as u32 => "HasComponent",
-                    id if id == ReferenceTypeId::HasNotifier as u32 => "HasNotifier",
-                    id if id == ReferenceTypeId::HasOrderedComponent as u32 => "HasOrderedComponent",
-                    id if id == ReferenceTypeId::FromState as u32 => "FromState",
-                    id if id == ReferenceTypeId::ToState as u32 => "ToState",
-                    id if id == ReferenceTypeId::HasCause as u32 => "HasCause",
-                    id if id == ReferenceTypeId::HasEffect as u32 => "HasEffect",
-                    id if id == ReferenceTypeId::HasHistoricalConfiguration as u32 => "HasHistoricalConfiguration",
-                    id if id == ReferenceTypeId::HasSubStateMachine as u32 => "HasSubStateMachine",
-                    id if id == ReferenceTypeId::AlwaysGeneratesEvent as u32 => "AlwaysGeneratesEvent",
-                    id if id == ReferenceTypeId::HasTrueSubState as u32 => "HasTrueSubState",
-                    id if id == ReferenceTypeId::HasFalseSubState as u32 => "HasFalseSubState",
-                    id if id == ReferenceTypeId::HasCondition as u32 => "HasCondition",
-                    _ => return Err(())
-                };
-                Ok(browse_name.to_string())
-            } else {
-                Err(())
-            }
+        Identifier::Numeric(id) if node_id.namespace == 0 => {
+            REFERENCE_TYPES.iter()
+                .find(|(reference_type_id, _)| *reference_type_id as u32 == *id)
+                .map(|(_, browse_name)| browse_name.to_string())
+                .ok_or(())
         }
         _ => Err(())
     }
 }
 
+/// The inverse of `reference_type_browse_name`, i.e. a standard reference type from its browse
+/// name, or else a reference type whose string identifier is its browse name.
+fn reference_type_id(namespace: u16, browse_name: &str) -> Result<NodeId, ()> {
+    if namespace == 0 {
+        REFERENCE_TYPES.iter()
+            .find(|(_, name)| *name == browse_name)
+            .map(|(reference_type_id, _)| (*reference_type_id).into())
+            .ok_or(())
+    } else {
+        Ok(NodeId::new(namespace, browse_name.to_string()))
+    }
+}
+
 /// Constructs a string representation of the reference type in the relative path.
 /// This code assumes that the reference type's node id has a string identifier and that
 /// the string identifier is the same as the browse name.
@@ -79,7 +93,7 @@ pub(crate) fn relative_path_reference_type(e: &RelativePathElement) -> Result<St
     let browse_name = reference_type_browse_name(&e.reference_type_id)?;
     let mut result = String::with_capacity(1024);
     // Common references will come out as '/' or '.'
-    if !e.include_subtypes && !e.is_inverse {
+    if e.include_subtypes && !e.is_inverse {
         if e.reference_type_id == ReferenceTypeId::HierarchicalReferences.into() {
             result.push('/');
         } else if e.reference_type_id == ReferenceTypeId::Aggregates.into() {
@@ -133,75 +147,76 @@ pub fn from_relative_path(path: &RelativePath) -> Result<String, ()> {
     Ok(result)
 }
 
-pub fn make_relative_path(path: &str) -> Result<RelativePath, ()> {
-    // Tokenize the buffer
-    let mut token = String::with_capacity(path.len());
-
-    // Break the string into segments
-    let mut escaped_char = false;
-    let mut reference_type = ReferenceTypeId::HierarchicalReferences;
-
-    let mut include_subtypes = true;
-    let mut is_inverse = false;
-
-    path.chars().for_each(|c| {
-        // Parse the
+/// Reads a browse name of a relative path, i.e. an optional namespace index and ':' followed by
+/// the name, up to the next unescaped '/', '.', '<' or '>'. The namespace index is 0 if omitted.
+fn read_browse_name(chars: &mut Peekable<Chars<'_>>) -> Result<(u16, String), ()> {
+    let mut namespace = None;
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
         match c {
+            '/' | '.' | '<' | '>' => break,
             '&' => {
-                // The next character is escaped and part of the token
-                escaped_char = true;
-            }
-            '/' => {
-                // Follow any subtype of HierarchicalReferences
-                if !token.is_empty() {}
-                reference_type = ReferenceTypeId::HierarchicalReferences;
-            }
-            '.' => {
-                // Follow any subtype of Aggregates
-                if !token.is_empty() {}
-                reference_type = ReferenceTypeId::Aggregates;
-            }
-            '>' => {
-                // Process the token as a reference type
-                // TODO this code needs to look up the reference type from the namespace:browsename
-                reference_type = match token.as_ref() {
-                    "References" => ReferenceTypeId::References,
-                    "NonHierarchicalReferences" => ReferenceTypeId::NonHierarchicalReferences,
-                    "HierarchicalReferences" => ReferenceTypeId::HierarchicalReferences,
-                    "HasChild" => ReferenceTypeId::HasChild,
-                    "Organizes" => ReferenceTypeId::Organizes,
-                    "HasEventSource" => ReferenceTypeId::HasEventSource,
-                    "HasModellingRule" => ReferenceTypeId::HasModellingRule,
-                    "HasEncoding" => ReferenceTypeId::HasEncoding,
-                    "HasDescription" => ReferenceTypeId::HasDescription,
-                    "HasTypeDefinition" => ReferenceTypeId::HasTypeDefinition,
-                    "GeneratesEvent" => ReferenceTypeId::GeneratesEvent,
-                    "Aggregates" => ReferenceTypeId::Aggregates,
-                    "HasSubtype" => ReferenceTypeId::HasSubtype,
-                    "HasProperty" => ReferenceTypeId::HasProperty,
-                    "HasComponent" => ReferenceTypeId::HasComponent,
-                    "HasNotifier" => ReferenceTypeId::HasNotifier,
-                    "HasOrderedComponent" => ReferenceTypeId::HasOrderedComponent,
-                    "FromState" => ReferenceTypeId::FromState,
-                    "ToState" => ReferenceTypeId::ToState,
-                    "HasCause" => ReferenceTypeId::HasCause,
-                    "HasEffect" => ReferenceTypeId::HasEffect,
-                    "HasHistoricalConfiguration" => ReferenceTypeId::HasHistoricalConfiguration,
-                    "HasSubStateMachine" => ReferenceTypeId::HasSubStateMachine,
-                    "AlwaysGeneratesEvent" => ReferenceTypeId::AlwaysGeneratesEvent,
-                    "HasTrueSubState" => ReferenceTypeId::HasTrueSubState,
-                    "HasFalseSubState" => ReferenceTypeId::HasFalseSubState,
-                    "HasCondition" => ReferenceTypeId::HasCondition,
-                    _ => ReferenceTypeId::References,
-                };
+                chars.next();
+                match chars.next() {
+                    Some(c) if BROWSE_NAME_RESERVED_CHARS.contains(c) => name.push(c),
+                    _ => return Err(())
+                }
+                continue;
             }
-            '<' => {}
-            c => {
-                token.push(c)
+            ':' if namespace.is_none() && !name.is_empty() => {
+                namespace = Some(name.parse::<u16>().map_err(|_| ())?);
+                name.clear();
             }
+            '#' | '!' | ':' => return Err(()),
+            c => name.push(c),
         }
-    });
-    Err(())
+        chars.next();
+    }
+    Ok((namespace.unwrap_or(0), name))
+}
+
+/// Makes a relative path from its string form, as per OPC UA Part 4, Appendix A, e.g.
+/// "/2:Block&.Output" or "<!HasChild>Objects". '/' follows HierarchicalReferences and '.'
+/// follows Aggregates forward, including subtypes. Only the last browse name may be empty, which
+/// matches any target. An error is returned if the string is malformed or names a reference
+/// type that is not known.
+pub fn make_relative_path(path: &str) -> Result<RelativePath, ()> {
+    let mut elements = Vec::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        let (reference_type_id, is_inverse, include_subtypes) = match c {
+            '/' => (ReferenceTypeId::HierarchicalReferences.into(), false, true),
+            '.' => (ReferenceTypeId::Aggregates.into(), false, true),
+            '<' => {
+                let include_subtypes = chars.peek() != Some(&'#');
+                if !include_subtypes {
+                    chars.next();
+                }
+                let is_inverse = chars.peek() == Some(&'!');
+                if is_inverse {
+                    chars.next();
+                }
+                let (namespace, browse_name) = read_browse_name(&mut chars)?;
+                if chars.next() != Some('>') {
+                    return Err(());
+                }
+                (reference_type_id(namespace, &browse_name)?, is_inverse, include_subtypes)
+            }
+            _ => return Err(())
+        };
+        let (namespace, browse_name) = read_browse_name(&mut chars)?;
+        elements.push(RelativePathElement {
+            reference_type_id,
+            is_inverse,
+            include_subtypes,
+            target_name: QualifiedName::new(namespace, &browse_name),
+        });
+    }
+    if elements.iter().rev().skip(1).any(|e| e.target_name.name.is_empty()) {
+        Err(())
+    } else {
+        Ok(RelativePath { elements: Some(elements) })
+    }
 }
 
 /// Test that escaping of browse names works as expected in each direction
@@ -229,19 +244,17 @@ fn test_escape_browse_name() {
 /// and a RelativePathElement type
 #[test]
 fn test_relative_path_element() {
-    use crate::basic_types::QualifiedName;
-
     [
         (RelativePathElement {
             reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
             is_inverse: false,
-            include_subtypes: false,
+            include_subtypes: true,
             target_name: QualifiedName::new(0, "foo"),
         }, "/0:foo"),
         (RelativePathElement {
             reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
             is_inverse: false,
-            include_subtypes: false,
+            include_subtypes: true,
             target_name: QualifiedName::new(0, ".foo"),
         }, "/0:&.foo"),
         (RelativePathElement {
@@ -259,7 +272,7 @@ fn test_relative_path_element() {
         (RelativePathElement {
             reference_type_id: ReferenceTypeId::Aggregates.into(),
             is_inverse: false,
-            include_subtypes: false,
+            include_subtypes: true,
             target_name: QualifiedName::new(0, "foo"),
         }, ".0:foo"),
         (RelativePathElement {
@@ -273,7 +286,8 @@ fn test_relative_path_element() {
         let expected = n.1.to_string();
         let actual = from_relative_path_element(element, true).unwrap();
         assert_eq!(expected, actual);
-        // TODO convert path string back to relative path element, expect it to equal element
+        let relative_path = make_relative_path(&actual).unwrap();
+        assert_eq!(relative_path.elements.unwrap(), vec![element.clone()]);
     });
 }
 
@@ -281,15 +295,13 @@ fn test_relative_path_element() {
 /// and a RelativePath type.
 #[test]
 fn test_relative_path() {
-    use crate::basic_types::QualifiedName;
-
     // Samples are from OPC UA Part 4 Appendix A
     let mut tests = vec![
         (vec![
             RelativePathElement {
                 reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
                 is_inverse: false,
-                include_subtypes: false,
+                include_subtypes: true,
                 target_name: QualifiedName::new(2, "Block.Output"),
             }
         ], "/2:Block&.Output"),
@@ -297,13 +309,13 @@ fn test_relative_path() {
             RelativePathElement {
                 reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
                 is_inverse: false,
-                include_subtypes: false,
+                include_subtypes: true,
                 target_name: QualifiedName::new(3, "Truck"),
             },
             RelativePathElement {
                 reference_type_id: ReferenceTypeId::Aggregates.into(),
                 is_inverse: false,
-                include_subtypes: false,
+                include_subtypes: true,
                 target_name: QualifiedName::new(0, "NodeVersion"),
             }],
          "/3:Truck.0:NodeVersion"),
@@ -316,6 +328,41 @@ fn test_relative_path() {
         let expected = n.1.to_string();
         let actual = from_relative_path(&relative_path).unwrap();
         assert_eq!(expected, actual);
-        // TODO convert path string back to relative path, expect it to equal element
+        assert_eq!(make_relative_path(&actual).unwrap(), relative_path);
+    });
+}
+
+/// Test that a relative path can be made from strings that omit namespaces, name custom reference
+/// types or end in an empty browse name, and that malformed strings are rejected
+#[test]
+fn test_make_relative_path() {
+    let relative_path = make_relative_path("/Objects<!Organizes>Root<#2:MyReference>").unwrap();
+    assert_eq!(relative_path.elements.unwrap(), vec![
+        RelativePathElement {
+            reference_type_id: ReferenceTypeId::HierarchicalReferences.into(),
+            is_inverse: false,
+            include_subtypes: true,
+            target_name: QualifiedName::new(0, "Objects"),
+        },
+        RelativePathElement {
+            reference_type_id: ReferenceTypeId::Organizes.into(),
+            is_inverse: true,
+            include_subtypes: true,
+            target_name: QualifiedName::new(0, "Root"),
+        },
+        RelativePathElement {
+            reference_type_id: NodeId::new(2, "MyReference"),
+            is_inverse: false,
+            include_subtypes: false,
+            target_name: QualifiedName::new(0, ""),
+        },
+    ]);
+
+    assert!(make_relative_path("").unwrap().elements.unwrap().is_empty());
+    [
+        "Objects", "//Objects", "/1:2:Objects", "<Organizes", "<Unknown>Objects", "/x:Objects",
+        "/Block.Output:1", "/Pump&Motor", "/Pump#1",
+    ].iter().for_each(|path| {
+        assert!(make_relative_path(path).is_err(), "{} should not be a relative path", path);
     });
-}
\ No newline at end of file
+}
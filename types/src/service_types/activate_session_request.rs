@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -75,3 +77,72 @@ impl BinaryEncoder<ActivateSessionRequest> for ActivateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ClientSignature", self.client_signature.encode_json(context));
+        insert_json_field(&mut fields, "ClientSoftwareCertificates", encode_json_array(&self.client_software_certificates, context));
+        insert_json_field(&mut fields, "LocaleIds", encode_json_array(&self.locale_ids, context));
+        insert_json_field(&mut fields, "UserIdentityToken", self.user_identity_token.encode_json(context));
+        insert_json_field(&mut fields, "UserTokenSignature", self.user_token_signature.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let client_signature = SignatureData::decode_json(json_field(fields, "ClientSignature"), decoding_limits)?;
+        let client_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_json_array(json_field(fields, "ClientSoftwareCertificates"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_json_array(json_field(fields, "LocaleIds"), decoding_limits)?;
+        let user_identity_token = ExtensionObject::decode_json(json_field(fields, "UserIdentityToken"), decoding_limits)?;
+        let user_token_signature = SignatureData::decode_json(json_field(fields, "UserTokenSignature"), decoding_limits)?;
+        Ok(ActivateSessionRequest {
+            request_header,
+            client_signature,
+            client_software_certificates,
+            locale_ids,
+            user_identity_token,
+            user_token_signature,
+        })
+    }
+}
+
+impl XmlEncoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.client_signature.encode_xml("ClientSignature"));
+        element.add_child(encode_xml_array("ClientSoftwareCertificates", "SignedSoftwareCertificate", &self.client_software_certificates));
+        element.add_child(encode_xml_array("LocaleIds", "String", &self.locale_ids));
+        element.add_child(self.user_identity_token.encode_xml("UserIdentityToken"));
+        element.add_child(self.user_token_signature.encode_xml("UserTokenSignature"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ActivateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let client_signature = SignatureData::decode_xml(xml_child(element, "ClientSignature"), decoding_limits)?;
+        let client_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_xml_array(xml_child(element, "ClientSoftwareCertificates"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "LocaleIds"), decoding_limits)?;
+        let user_identity_token = ExtensionObject::decode_xml(xml_child(element, "UserIdentityToken"), decoding_limits)?;
+        let user_token_signature = SignatureData::decode_xml(xml_child(element, "UserTokenSignature"), decoding_limits)?;
+        Ok(ActivateSessionRequest {
+            request_header,
+            client_signature,
+            client_software_certificates,
+            locale_ids,
+            user_identity_token,
+            user_token_signature,
+        })
+    }
+}
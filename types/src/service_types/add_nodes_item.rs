@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -80,3 +82,78 @@ impl BinaryEncoder<AddNodesItem> for AddNodesItem {
         })
     }
 }
+
+impl JsonEncoder for AddNodesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ParentNodeId", self.parent_node_id.encode_json(context));
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "RequestedNewNodeId", self.requested_new_node_id.encode_json(context));
+        insert_json_field(&mut fields, "BrowseName", self.browse_name.encode_json(context));
+        insert_json_field(&mut fields, "NodeClass", self.node_class.encode_json(context));
+        insert_json_field(&mut fields, "NodeAttributes", self.node_attributes.encode_json(context));
+        insert_json_field(&mut fields, "TypeDefinition", self.type_definition.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AddNodesItem {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let parent_node_id = ExpandedNodeId::decode_json(json_field(fields, "ParentNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let requested_new_node_id = ExpandedNodeId::decode_json(json_field(fields, "RequestedNewNodeId"), decoding_limits)?;
+        let browse_name = QualifiedName::decode_json(json_field(fields, "BrowseName"), decoding_limits)?;
+        let node_class = NodeClass::decode_json(json_field(fields, "NodeClass"), decoding_limits)?;
+        let node_attributes = ExtensionObject::decode_json(json_field(fields, "NodeAttributes"), decoding_limits)?;
+        let type_definition = ExpandedNodeId::decode_json(json_field(fields, "TypeDefinition"), decoding_limits)?;
+        Ok(AddNodesItem {
+            parent_node_id,
+            reference_type_id,
+            requested_new_node_id,
+            browse_name,
+            node_class,
+            node_attributes,
+            type_definition,
+        })
+    }
+}
+
+impl XmlEncoder for AddNodesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.parent_node_id.encode_xml("ParentNodeId"));
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.requested_new_node_id.encode_xml("RequestedNewNodeId"));
+        element.add_child(self.browse_name.encode_xml("BrowseName"));
+        element.add_child(self.node_class.encode_xml("NodeClass"));
+        element.add_child(self.node_attributes.encode_xml("NodeAttributes"));
+        element.add_child(self.type_definition.encode_xml("TypeDefinition"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AddNodesItem {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let parent_node_id = ExpandedNodeId::decode_xml(xml_child(element, "ParentNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let requested_new_node_id = ExpandedNodeId::decode_xml(xml_child(element, "RequestedNewNodeId"), decoding_limits)?;
+        let browse_name = QualifiedName::decode_xml(xml_child(element, "BrowseName"), decoding_limits)?;
+        let node_class = NodeClass::decode_xml(xml_child(element, "NodeClass"), decoding_limits)?;
+        let node_attributes = ExtensionObject::decode_xml(xml_child(element, "NodeAttributes"), decoding_limits)?;
+        let type_definition = ExpandedNodeId::decode_xml(xml_child(element, "TypeDefinition"), decoding_limits)?;
+        Ok(AddNodesItem {
+            parent_node_id,
+            reference_type_id,
+            requested_new_node_id,
+            browse_name,
+            node_class,
+            node_attributes,
+            type_definition,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<AddNodesRequest> for AddNodesRequest {
         })
     }
 }
+
+impl JsonEncoder for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "NodesToAdd", encode_json_array(&self.nodes_to_add, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let nodes_to_add: Option<Vec<AddNodesItem>> = decode_json_array(json_field(fields, "NodesToAdd"), decoding_limits)?;
+        Ok(AddNodesRequest {
+            request_header,
+            nodes_to_add,
+        })
+    }
+}
+
+impl XmlEncoder for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("NodesToAdd", "AddNodesItem", &self.nodes_to_add));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AddNodesRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let nodes_to_add: Option<Vec<AddNodesItem>> = decode_xml_array(xml_child(element, "NodesToAdd"), decoding_limits)?;
+        Ok(AddNodesRequest {
+            request_header,
+            nodes_to_add,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<AddNodesResult> for AddNodesResult {
         })
     }
 }
+
+impl JsonEncoder for AddNodesResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "AddedNodeId", self.added_node_id.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AddNodesResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let added_node_id = NodeId::decode_json(json_field(fields, "AddedNodeId"), decoding_limits)?;
+        Ok(AddNodesResult {
+            status_code,
+            added_node_id,
+        })
+    }
+}
+
+impl XmlEncoder for AddNodesResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(self.added_node_id.encode_xml("AddedNodeId"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AddNodesResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let added_node_id = NodeId::decode_xml(xml_child(element, "AddedNodeId"), decoding_limits)?;
+        Ok(AddNodesResult {
+            status_code,
+            added_node_id,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -74,3 +76,72 @@ impl BinaryEncoder<AddReferencesItem> for AddReferencesItem {
         })
     }
 }
+
+impl JsonEncoder for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SourceNodeId", self.source_node_id.encode_json(context));
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IsForward", self.is_forward.encode_json(context));
+        insert_json_field(&mut fields, "TargetServerUri", self.target_server_uri.encode_json(context));
+        insert_json_field(&mut fields, "TargetNodeId", self.target_node_id.encode_json(context));
+        insert_json_field(&mut fields, "TargetNodeClass", self.target_node_class.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let source_node_id = NodeId::decode_json(json_field(fields, "SourceNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_json(json_field(fields, "IsForward"), decoding_limits)?;
+        let target_server_uri = UAString::decode_json(json_field(fields, "TargetServerUri"), decoding_limits)?;
+        let target_node_id = ExpandedNodeId::decode_json(json_field(fields, "TargetNodeId"), decoding_limits)?;
+        let target_node_class = NodeClass::decode_json(json_field(fields, "TargetNodeClass"), decoding_limits)?;
+        Ok(AddReferencesItem {
+            source_node_id,
+            reference_type_id,
+            is_forward,
+            target_server_uri,
+            target_node_id,
+            target_node_class,
+        })
+    }
+}
+
+impl XmlEncoder for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.source_node_id.encode_xml("SourceNodeId"));
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.is_forward.encode_xml("IsForward"));
+        element.add_child(self.target_server_uri.encode_xml("TargetServerUri"));
+        element.add_child(self.target_node_id.encode_xml("TargetNodeId"));
+        element.add_child(self.target_node_class.encode_xml("TargetNodeClass"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AddReferencesItem {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let source_node_id = NodeId::decode_xml(xml_child(element, "SourceNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_xml(xml_child(element, "IsForward"), decoding_limits)?;
+        let target_server_uri = UAString::decode_xml(xml_child(element, "TargetServerUri"), decoding_limits)?;
+        let target_node_id = ExpandedNodeId::decode_xml(xml_child(element, "TargetNodeId"), decoding_limits)?;
+        let target_node_class = NodeClass::decode_xml(xml_child(element, "TargetNodeClass"), decoding_limits)?;
+        Ok(AddReferencesItem {
+            source_node_id,
+            reference_type_id,
+            is_forward,
+            target_server_uri,
+            target_node_id,
+            target_node_class,
+        })
+    }
+}
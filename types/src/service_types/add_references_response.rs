@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -58,3 +60,54 @@ impl BinaryEncoder<AddReferencesResponse> for AddReferencesResponse {
         })
     }
 }
+
+impl JsonEncoder for AddReferencesResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "Results", encode_json_array(&self.results, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AddReferencesResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        Ok(AddReferencesResponse {
+            response_header,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for AddReferencesResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("Results", "StatusCode", &self.results));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AddReferencesResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        Ok(AddReferencesResponse {
+            response_header,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
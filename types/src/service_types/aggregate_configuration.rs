@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -64,3 +66,66 @@ impl BinaryEncoder<AggregateConfiguration> for AggregateConfiguration {
         })
     }
 }
+
+impl JsonEncoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "UseServerCapabilitiesDefaults", self.use_server_capabilities_defaults.encode_json(context));
+        insert_json_field(&mut fields, "TreatUncertainAsBad", self.treat_uncertain_as_bad.encode_json(context));
+        insert_json_field(&mut fields, "PercentDataBad", self.percent_data_bad.encode_json(context));
+        insert_json_field(&mut fields, "PercentDataGood", self.percent_data_good.encode_json(context));
+        insert_json_field(&mut fields, "UseSlopedExtrapolation", self.use_sloped_extrapolation.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let use_server_capabilities_defaults = bool::decode_json(json_field(fields, "UseServerCapabilitiesDefaults"), decoding_limits)?;
+        let treat_uncertain_as_bad = bool::decode_json(json_field(fields, "TreatUncertainAsBad"), decoding_limits)?;
+        let percent_data_bad = u8::decode_json(json_field(fields, "PercentDataBad"), decoding_limits)?;
+        let percent_data_good = u8::decode_json(json_field(fields, "PercentDataGood"), decoding_limits)?;
+        let use_sloped_extrapolation = bool::decode_json(json_field(fields, "UseSlopedExtrapolation"), decoding_limits)?;
+        Ok(AggregateConfiguration {
+            use_server_capabilities_defaults,
+            treat_uncertain_as_bad,
+            percent_data_bad,
+            percent_data_good,
+            use_sloped_extrapolation,
+        })
+    }
+}
+
+impl XmlEncoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.use_server_capabilities_defaults.encode_xml("UseServerCapabilitiesDefaults"));
+        element.add_child(self.treat_uncertain_as_bad.encode_xml("TreatUncertainAsBad"));
+        element.add_child(self.percent_data_bad.encode_xml("PercentDataBad"));
+        element.add_child(self.percent_data_good.encode_xml("PercentDataGood"));
+        element.add_child(self.use_sloped_extrapolation.encode_xml("UseSlopedExtrapolation"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AggregateConfiguration {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let use_server_capabilities_defaults = bool::decode_xml(xml_child(element, "UseServerCapabilitiesDefaults"), decoding_limits)?;
+        let treat_uncertain_as_bad = bool::decode_xml(xml_child(element, "TreatUncertainAsBad"), decoding_limits)?;
+        let percent_data_bad = u8::decode_xml(xml_child(element, "PercentDataBad"), decoding_limits)?;
+        let percent_data_good = u8::decode_xml(xml_child(element, "PercentDataGood"), decoding_limits)?;
+        let use_sloped_extrapolation = bool::decode_xml(xml_child(element, "UseSlopedExtrapolation"), decoding_limits)?;
+        Ok(AggregateConfiguration {
+            use_server_capabilities_defaults,
+            treat_uncertain_as_bad,
+            percent_data_bad,
+            percent_data_good,
+            use_sloped_extrapolation,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     date_time::DateTime,
     node_id::NodeId,
@@ -54,3 +56,60 @@ impl BinaryEncoder<AggregateFilter> for AggregateFilter {
         })
     }
 }
+
+impl JsonEncoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StartTime", self.start_time.encode_json(context));
+        insert_json_field(&mut fields, "AggregateType", self.aggregate_type.encode_json(context));
+        insert_json_field(&mut fields, "ProcessingInterval", self.processing_interval.encode_json(context));
+        insert_json_field(&mut fields, "AggregateConfiguration", self.aggregate_configuration.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let start_time = DateTime::decode_json(json_field(fields, "StartTime"), decoding_limits)?;
+        let aggregate_type = NodeId::decode_json(json_field(fields, "AggregateType"), decoding_limits)?;
+        let processing_interval = f64::decode_json(json_field(fields, "ProcessingInterval"), decoding_limits)?;
+        let aggregate_configuration = AggregateConfiguration::decode_json(json_field(fields, "AggregateConfiguration"), decoding_limits)?;
+        Ok(AggregateFilter {
+            start_time,
+            aggregate_type,
+            processing_interval,
+            aggregate_configuration,
+        })
+    }
+}
+
+impl XmlEncoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.start_time.encode_xml("StartTime"));
+        element.add_child(self.aggregate_type.encode_xml("AggregateType"));
+        element.add_child(self.processing_interval.encode_xml("ProcessingInterval"));
+        element.add_child(self.aggregate_configuration.encode_xml("AggregateConfiguration"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AggregateFilter {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let start_time = DateTime::decode_xml(xml_child(element, "StartTime"), decoding_limits)?;
+        let aggregate_type = NodeId::decode_xml(xml_child(element, "AggregateType"), decoding_limits)?;
+        let processing_interval = f64::decode_xml(xml_child(element, "ProcessingInterval"), decoding_limits)?;
+        let aggregate_configuration = AggregateConfiguration::decode_xml(xml_child(element, "AggregateConfiguration"), decoding_limits)?;
+        Ok(AggregateFilter {
+            start_time,
+            aggregate_type,
+            processing_interval,
+            aggregate_configuration,
+        })
+    }
+}
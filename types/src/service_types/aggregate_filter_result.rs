@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     date_time::DateTime,
     service_types::AggregateConfiguration,
@@ -48,3 +50,54 @@ impl BinaryEncoder<AggregateFilterResult> for AggregateFilterResult {
         })
     }
 }
+
+impl JsonEncoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RevisedStartTime", self.revised_start_time.encode_json(context));
+        insert_json_field(&mut fields, "RevisedProcessingInterval", self.revised_processing_interval.encode_json(context));
+        insert_json_field(&mut fields, "RevisedAggregateConfiguration", self.revised_aggregate_configuration.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let revised_start_time = DateTime::decode_json(json_field(fields, "RevisedStartTime"), decoding_limits)?;
+        let revised_processing_interval = f64::decode_json(json_field(fields, "RevisedProcessingInterval"), decoding_limits)?;
+        let revised_aggregate_configuration = AggregateConfiguration::decode_json(json_field(fields, "RevisedAggregateConfiguration"), decoding_limits)?;
+        Ok(AggregateFilterResult {
+            revised_start_time,
+            revised_processing_interval,
+            revised_aggregate_configuration,
+        })
+    }
+}
+
+impl XmlEncoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.revised_start_time.encode_xml("RevisedStartTime"));
+        element.add_child(self.revised_processing_interval.encode_xml("RevisedProcessingInterval"));
+        element.add_child(self.revised_aggregate_configuration.encode_xml("RevisedAggregateConfiguration"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AggregateFilterResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let revised_start_time = DateTime::decode_xml(xml_child(element, "RevisedStartTime"), decoding_limits)?;
+        let revised_processing_interval = f64::decode_xml(xml_child(element, "RevisedProcessingInterval"), decoding_limits)?;
+        let revised_aggregate_configuration = AggregateConfiguration::decode_xml(xml_child(element, "RevisedAggregateConfiguration"), decoding_limits)?;
+        Ok(AggregateFilterResult {
+            revised_start_time,
+            revised_processing_interval,
+            revised_aggregate_configuration,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<Annotation> for Annotation {
         })
     }
 }
+
+impl JsonEncoder for Annotation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Message", self.message.encode_json(context));
+        insert_json_field(&mut fields, "UserName", self.user_name.encode_json(context));
+        insert_json_field(&mut fields, "AnnotationTime", self.annotation_time.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for Annotation {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let message = UAString::decode_json(json_field(fields, "Message"), decoding_limits)?;
+        let user_name = UAString::decode_json(json_field(fields, "UserName"), decoding_limits)?;
+        let annotation_time = DateTime::decode_json(json_field(fields, "AnnotationTime"), decoding_limits)?;
+        Ok(Annotation {
+            message,
+            user_name,
+            annotation_time,
+        })
+    }
+}
+
+impl XmlEncoder for Annotation {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.message.encode_xml("Message"));
+        element.add_child(self.user_name.encode_xml("UserName"));
+        element.add_child(self.annotation_time.encode_xml("AnnotationTime"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for Annotation {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let message = UAString::decode_xml(xml_child(element, "Message"), decoding_limits)?;
+        let user_name = UAString::decode_xml(xml_child(element, "UserName"), decoding_limits)?;
+        let annotation_time = DateTime::decode_xml(xml_child(element, "AnnotationTime"), decoding_limits)?;
+        Ok(Annotation {
+            message,
+            user_name,
+            annotation_time,
+        })
+    }
+}
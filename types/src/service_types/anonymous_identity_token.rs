@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     string::UAString,
 };
@@ -38,3 +40,42 @@ impl BinaryEncoder<AnonymousIdentityToken> for AnonymousIdentityToken {
         })
     }
 }
+
+impl JsonEncoder for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "PolicyId", self.policy_id.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let policy_id = UAString::decode_json(json_field(fields, "PolicyId"), decoding_limits)?;
+        Ok(AnonymousIdentityToken {
+            policy_id,
+        })
+    }
+}
+
+impl XmlEncoder for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.policy_id.encode_xml("PolicyId"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AnonymousIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let policy_id = UAString::decode_xml(xml_child(element, "PolicyId"), decoding_limits)?;
+        Ok(AnonymousIdentityToken {
+            policy_id,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -78,3 +80,78 @@ impl BinaryEncoder<ApplicationDescription> for ApplicationDescription {
         })
     }
 }
+
+impl JsonEncoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ApplicationUri", self.application_uri.encode_json(context));
+        insert_json_field(&mut fields, "ProductUri", self.product_uri.encode_json(context));
+        insert_json_field(&mut fields, "ApplicationName", self.application_name.encode_json(context));
+        insert_json_field(&mut fields, "ApplicationType", self.application_type.encode_json(context));
+        insert_json_field(&mut fields, "GatewayServerUri", self.gateway_server_uri.encode_json(context));
+        insert_json_field(&mut fields, "DiscoveryProfileUri", self.discovery_profile_uri.encode_json(context));
+        insert_json_field(&mut fields, "DiscoveryUrls", encode_json_array(&self.discovery_urls, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let application_uri = UAString::decode_json(json_field(fields, "ApplicationUri"), decoding_limits)?;
+        let product_uri = UAString::decode_json(json_field(fields, "ProductUri"), decoding_limits)?;
+        let application_name = LocalizedText::decode_json(json_field(fields, "ApplicationName"), decoding_limits)?;
+        let application_type = ApplicationType::decode_json(json_field(fields, "ApplicationType"), decoding_limits)?;
+        let gateway_server_uri = UAString::decode_json(json_field(fields, "GatewayServerUri"), decoding_limits)?;
+        let discovery_profile_uri = UAString::decode_json(json_field(fields, "DiscoveryProfileUri"), decoding_limits)?;
+        let discovery_urls: Option<Vec<UAString>> = decode_json_array(json_field(fields, "DiscoveryUrls"), decoding_limits)?;
+        Ok(ApplicationDescription {
+            application_uri,
+            product_uri,
+            application_name,
+            application_type,
+            gateway_server_uri,
+            discovery_profile_uri,
+            discovery_urls,
+        })
+    }
+}
+
+impl XmlEncoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.application_uri.encode_xml("ApplicationUri"));
+        element.add_child(self.product_uri.encode_xml("ProductUri"));
+        element.add_child(self.application_name.encode_xml("ApplicationName"));
+        element.add_child(self.application_type.encode_xml("ApplicationType"));
+        element.add_child(self.gateway_server_uri.encode_xml("GatewayServerUri"));
+        element.add_child(self.discovery_profile_uri.encode_xml("DiscoveryProfileUri"));
+        element.add_child(encode_xml_array("DiscoveryUrls", "String", &self.discovery_urls));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ApplicationDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let application_uri = UAString::decode_xml(xml_child(element, "ApplicationUri"), decoding_limits)?;
+        let product_uri = UAString::decode_xml(xml_child(element, "ProductUri"), decoding_limits)?;
+        let application_name = LocalizedText::decode_xml(xml_child(element, "ApplicationName"), decoding_limits)?;
+        let application_type = ApplicationType::decode_xml(xml_child(element, "ApplicationType"), decoding_limits)?;
+        let gateway_server_uri = UAString::decode_xml(xml_child(element, "GatewayServerUri"), decoding_limits)?;
+        let discovery_profile_uri = UAString::decode_xml(xml_child(element, "DiscoveryProfileUri"), decoding_limits)?;
+        let discovery_urls: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "DiscoveryUrls"), decoding_limits)?;
+        Ok(ApplicationDescription {
+            application_uri,
+            product_uri,
+            application_name,
+            application_type,
+            gateway_server_uri,
+            discovery_profile_uri,
+            discovery_urls,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -68,3 +70,66 @@ impl BinaryEncoder<Argument> for Argument {
         })
     }
 }
+
+impl JsonEncoder for Argument {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Name", self.name.encode_json(context));
+        insert_json_field(&mut fields, "DataType", self.data_type.encode_json(context));
+        insert_json_field(&mut fields, "ValueRank", self.value_rank.encode_json(context));
+        insert_json_field(&mut fields, "ArrayDimensions", encode_json_array(&self.array_dimensions, context));
+        insert_json_field(&mut fields, "Description", self.description.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for Argument {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let name = UAString::decode_json(json_field(fields, "Name"), decoding_limits)?;
+        let data_type = NodeId::decode_json(json_field(fields, "DataType"), decoding_limits)?;
+        let value_rank = i32::decode_json(json_field(fields, "ValueRank"), decoding_limits)?;
+        let array_dimensions: Option<Vec<u32>> = decode_json_array(json_field(fields, "ArrayDimensions"), decoding_limits)?;
+        let description = LocalizedText::decode_json(json_field(fields, "Description"), decoding_limits)?;
+        Ok(Argument {
+            name,
+            data_type,
+            value_rank,
+            array_dimensions,
+            description,
+        })
+    }
+}
+
+impl XmlEncoder for Argument {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.name.encode_xml("Name"));
+        element.add_child(self.data_type.encode_xml("DataType"));
+        element.add_child(self.value_rank.encode_xml("ValueRank"));
+        element.add_child(encode_xml_array("ArrayDimensions", "UInt32", &self.array_dimensions));
+        element.add_child(self.description.encode_xml("Description"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for Argument {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let name = UAString::decode_xml(xml_child(element, "Name"), decoding_limits)?;
+        let data_type = NodeId::decode_xml(xml_child(element, "DataType"), decoding_limits)?;
+        let value_rank = i32::decode_xml(xml_child(element, "ValueRank"), decoding_limits)?;
+        let array_dimensions: Option<Vec<u32>> = decode_xml_array(xml_child(element, "ArrayDimensions"), decoding_limits)?;
+        let description = LocalizedText::decode_xml(xml_child(element, "Description"), decoding_limits)?;
+        Ok(Argument {
+            name,
+            data_type,
+            value_rank,
+            array_dimensions,
+            description,
+        })
+    }
+}
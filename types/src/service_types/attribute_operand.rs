@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     node_id::NodeId,
     string::UAString,
@@ -59,3 +61,66 @@ impl BinaryEncoder<AttributeOperand> for AttributeOperand {
         })
     }
 }
+
+impl JsonEncoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "Alias", self.alias.encode_json(context));
+        insert_json_field(&mut fields, "BrowsePath", self.browse_path.encode_json(context));
+        insert_json_field(&mut fields, "AttributeId", self.attribute_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let alias = UAString::decode_json(json_field(fields, "Alias"), decoding_limits)?;
+        let browse_path = RelativePath::decode_json(json_field(fields, "BrowsePath"), decoding_limits)?;
+        let attribute_id = u32::decode_json(json_field(fields, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        Ok(AttributeOperand {
+            node_id,
+            alias,
+            browse_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
+
+impl XmlEncoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.alias.encode_xml("Alias"));
+        element.add_child(self.browse_path.encode_xml("BrowsePath"));
+        element.add_child(self.attribute_id.encode_xml("AttributeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for AttributeOperand {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let alias = UAString::decode_xml(xml_child(element, "Alias"), decoding_limits)?;
+        let browse_path = RelativePath::decode_xml(xml_child(element, "BrowsePath"), decoding_limits)?;
+        let attribute_id = u32::decode_xml(xml_child(element, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        Ok(AttributeOperand {
+            node_id,
+            alias,
+            browse_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -72,3 +74,72 @@ impl BinaryEncoder<BrowseDescription> for BrowseDescription {
         })
     }
 }
+
+impl JsonEncoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "BrowseDirection", self.browse_direction.encode_json(context));
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IncludeSubtypes", self.include_subtypes.encode_json(context));
+        insert_json_field(&mut fields, "NodeClassMask", self.node_class_mask.encode_json(context));
+        insert_json_field(&mut fields, "ResultMask", self.result_mask.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let browse_direction = BrowseDirection::decode_json(json_field(fields, "BrowseDirection"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let include_subtypes = bool::decode_json(json_field(fields, "IncludeSubtypes"), decoding_limits)?;
+        let node_class_mask = u32::decode_json(json_field(fields, "NodeClassMask"), decoding_limits)?;
+        let result_mask = u32::decode_json(json_field(fields, "ResultMask"), decoding_limits)?;
+        Ok(BrowseDescription {
+            node_id,
+            browse_direction,
+            reference_type_id,
+            include_subtypes,
+            node_class_mask,
+            result_mask,
+        })
+    }
+}
+
+impl XmlEncoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.browse_direction.encode_xml("BrowseDirection"));
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.include_subtypes.encode_xml("IncludeSubtypes"));
+        element.add_child(self.node_class_mask.encode_xml("NodeClassMask"));
+        element.add_child(self.result_mask.encode_xml("ResultMask"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowseDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let browse_direction = BrowseDirection::decode_xml(xml_child(element, "BrowseDirection"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let include_subtypes = bool::decode_xml(xml_child(element, "IncludeSubtypes"), decoding_limits)?;
+        let node_class_mask = u32::decode_xml(xml_child(element, "NodeClassMask"), decoding_limits)?;
+        let result_mask = u32::decode_xml(xml_child(element, "ResultMask"), decoding_limits)?;
+        Ok(BrowseDescription {
+            node_id,
+            browse_direction,
+            reference_type_id,
+            include_subtypes,
+            node_class_mask,
+            result_mask,
+        })
+    }
+}
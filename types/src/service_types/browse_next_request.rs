@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +59,54 @@ impl BinaryEncoder<BrowseNextRequest> for BrowseNextRequest {
         })
     }
 }
+
+impl JsonEncoder for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ReleaseContinuationPoints", self.release_continuation_points.encode_json(context));
+        insert_json_field(&mut fields, "ContinuationPoints", encode_json_array(&self.continuation_points, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let release_continuation_points = bool::decode_json(json_field(fields, "ReleaseContinuationPoints"), decoding_limits)?;
+        let continuation_points: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "ContinuationPoints"), decoding_limits)?;
+        Ok(BrowseNextRequest {
+            request_header,
+            release_continuation_points,
+            continuation_points,
+        })
+    }
+}
+
+impl XmlEncoder for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.release_continuation_points.encode_xml("ReleaseContinuationPoints"));
+        element.add_child(encode_xml_array("ContinuationPoints", "ByteString", &self.continuation_points));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowseNextRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let release_continuation_points = bool::decode_xml(xml_child(element, "ReleaseContinuationPoints"), decoding_limits)?;
+        let continuation_points: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "ContinuationPoints"), decoding_limits)?;
+        Ok(BrowseNextRequest {
+            request_header,
+            release_continuation_points,
+            continuation_points,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<BrowsePath> for BrowsePath {
         })
     }
 }
+
+impl JsonEncoder for BrowsePath {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StartingNode", self.starting_node.encode_json(context));
+        insert_json_field(&mut fields, "RelativePath", self.relative_path.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowsePath {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let starting_node = NodeId::decode_json(json_field(fields, "StartingNode"), decoding_limits)?;
+        let relative_path = RelativePath::decode_json(json_field(fields, "RelativePath"), decoding_limits)?;
+        Ok(BrowsePath {
+            starting_node,
+            relative_path,
+        })
+    }
+}
+
+impl XmlEncoder for BrowsePath {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.starting_node.encode_xml("StartingNode"));
+        element.add_child(self.relative_path.encode_xml("RelativePath"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowsePath {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let starting_node = NodeId::decode_xml(xml_child(element, "StartingNode"), decoding_limits)?;
+        let relative_path = RelativePath::decode_xml(xml_child(element, "RelativePath"), decoding_limits)?;
+        Ok(BrowsePath {
+            starting_node,
+            relative_path,
+        })
+    }
+}
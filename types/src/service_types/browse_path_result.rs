@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<BrowsePathResult> for BrowsePathResult {
         })
     }
 }
+
+impl JsonEncoder for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "Targets", encode_json_array(&self.targets, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let targets: Option<Vec<BrowsePathTarget>> = decode_json_array(json_field(fields, "Targets"), decoding_limits)?;
+        Ok(BrowsePathResult {
+            status_code,
+            targets,
+        })
+    }
+}
+
+impl XmlEncoder for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("Targets", "BrowsePathTarget", &self.targets));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowsePathResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let targets: Option<Vec<BrowsePathTarget>> = decode_xml_array(xml_child(element, "Targets"), decoding_limits)?;
+        Ok(BrowsePathResult {
+            status_code,
+            targets,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<BrowsePathTarget> for BrowsePathTarget {
         })
     }
 }
+
+impl JsonEncoder for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "TargetId", self.target_id.encode_json(context));
+        insert_json_field(&mut fields, "RemainingPathIndex", self.remaining_path_index.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let target_id = ExpandedNodeId::decode_json(json_field(fields, "TargetId"), decoding_limits)?;
+        let remaining_path_index = u32::decode_json(json_field(fields, "RemainingPathIndex"), decoding_limits)?;
+        Ok(BrowsePathTarget {
+            target_id,
+            remaining_path_index,
+        })
+    }
+}
+
+impl XmlEncoder for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.target_id.encode_xml("TargetId"));
+        element.add_child(self.remaining_path_index.encode_xml("RemainingPathIndex"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowsePathTarget {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let target_id = ExpandedNodeId::decode_xml(xml_child(element, "TargetId"), decoding_limits)?;
+        let remaining_path_index = u32::decode_xml(xml_child(element, "RemainingPathIndex"), decoding_limits)?;
+        Ok(BrowsePathTarget {
+            target_id,
+            remaining_path_index,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +65,60 @@ impl BinaryEncoder<BrowseRequest> for BrowseRequest {
         })
     }
 }
+
+impl JsonEncoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "View", self.view.encode_json(context));
+        insert_json_field(&mut fields, "RequestedMaxReferencesPerNode", self.requested_max_references_per_node.encode_json(context));
+        insert_json_field(&mut fields, "NodesToBrowse", encode_json_array(&self.nodes_to_browse, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let view = ViewDescription::decode_json(json_field(fields, "View"), decoding_limits)?;
+        let requested_max_references_per_node = u32::decode_json(json_field(fields, "RequestedMaxReferencesPerNode"), decoding_limits)?;
+        let nodes_to_browse: Option<Vec<BrowseDescription>> = decode_json_array(json_field(fields, "NodesToBrowse"), decoding_limits)?;
+        Ok(BrowseRequest {
+            request_header,
+            view,
+            requested_max_references_per_node,
+            nodes_to_browse,
+        })
+    }
+}
+
+impl XmlEncoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.view.encode_xml("View"));
+        element.add_child(self.requested_max_references_per_node.encode_xml("RequestedMaxReferencesPerNode"));
+        element.add_child(encode_xml_array("NodesToBrowse", "BrowseDescription", &self.nodes_to_browse));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowseRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let view = ViewDescription::decode_xml(xml_child(element, "View"), decoding_limits)?;
+        let requested_max_references_per_node = u32::decode_xml(xml_child(element, "RequestedMaxReferencesPerNode"), decoding_limits)?;
+        let nodes_to_browse: Option<Vec<BrowseDescription>> = decode_xml_array(xml_child(element, "NodesToBrowse"), decoding_limits)?;
+        Ok(BrowseRequest {
+            request_header,
+            view,
+            requested_max_references_per_node,
+            nodes_to_browse,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -58,3 +60,54 @@ impl BinaryEncoder<BrowseResult> for BrowseResult {
         })
     }
 }
+
+impl JsonEncoder for BrowseResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "ContinuationPoint", self.continuation_point.encode_json(context));
+        insert_json_field(&mut fields, "References", encode_json_array(&self.references, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BrowseResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let continuation_point = ByteString::decode_json(json_field(fields, "ContinuationPoint"), decoding_limits)?;
+        let references: Option<Vec<ReferenceDescription>> = decode_json_array(json_field(fields, "References"), decoding_limits)?;
+        Ok(BrowseResult {
+            status_code,
+            continuation_point,
+            references,
+        })
+    }
+}
+
+impl XmlEncoder for BrowseResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(self.continuation_point.encode_xml("ContinuationPoint"));
+        element.add_child(encode_xml_array("References", "ReferenceDescription", &self.references));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BrowseResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let continuation_point = ByteString::decode_xml(xml_child(element, "ContinuationPoint"), decoding_limits)?;
+        let references: Option<Vec<ReferenceDescription>> = decode_xml_array(xml_child(element, "References"), decoding_limits)?;
+        Ok(BrowseResult {
+            status_code,
+            continuation_point,
+            references,
+        })
+    }
+}
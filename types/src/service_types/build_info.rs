@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -71,3 +73,72 @@ impl BinaryEncoder<BuildInfo> for BuildInfo {
         })
     }
 }
+
+impl JsonEncoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ProductUri", self.product_uri.encode_json(context));
+        insert_json_field(&mut fields, "ManufacturerName", self.manufacturer_name.encode_json(context));
+        insert_json_field(&mut fields, "ProductName", self.product_name.encode_json(context));
+        insert_json_field(&mut fields, "SoftwareVersion", self.software_version.encode_json(context));
+        insert_json_field(&mut fields, "BuildNumber", self.build_number.encode_json(context));
+        insert_json_field(&mut fields, "BuildDate", self.build_date.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let product_uri = UAString::decode_json(json_field(fields, "ProductUri"), decoding_limits)?;
+        let manufacturer_name = UAString::decode_json(json_field(fields, "ManufacturerName"), decoding_limits)?;
+        let product_name = UAString::decode_json(json_field(fields, "ProductName"), decoding_limits)?;
+        let software_version = UAString::decode_json(json_field(fields, "SoftwareVersion"), decoding_limits)?;
+        let build_number = UAString::decode_json(json_field(fields, "BuildNumber"), decoding_limits)?;
+        let build_date = DateTime::decode_json(json_field(fields, "BuildDate"), decoding_limits)?;
+        Ok(BuildInfo {
+            product_uri,
+            manufacturer_name,
+            product_name,
+            software_version,
+            build_number,
+            build_date,
+        })
+    }
+}
+
+impl XmlEncoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.product_uri.encode_xml("ProductUri"));
+        element.add_child(self.manufacturer_name.encode_xml("ManufacturerName"));
+        element.add_child(self.product_name.encode_xml("ProductName"));
+        element.add_child(self.software_version.encode_xml("SoftwareVersion"));
+        element.add_child(self.build_number.encode_xml("BuildNumber"));
+        element.add_child(self.build_date.encode_xml("BuildDate"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for BuildInfo {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let product_uri = UAString::decode_xml(xml_child(element, "ProductUri"), decoding_limits)?;
+        let manufacturer_name = UAString::decode_xml(xml_child(element, "ManufacturerName"), decoding_limits)?;
+        let product_name = UAString::decode_xml(xml_child(element, "ProductName"), decoding_limits)?;
+        let software_version = UAString::decode_xml(xml_child(element, "SoftwareVersion"), decoding_limits)?;
+        let build_number = UAString::decode_xml(xml_child(element, "BuildNumber"), decoding_limits)?;
+        let build_date = DateTime::decode_xml(xml_child(element, "BuildDate"), decoding_limits)?;
+        Ok(BuildInfo {
+            product_uri,
+            manufacturer_name,
+            product_name,
+            software_version,
+            build_number,
+            build_date,
+        })
+    }
+}
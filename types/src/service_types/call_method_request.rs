@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<CallMethodRequest> for CallMethodRequest {
         })
     }
 }
+
+impl JsonEncoder for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ObjectId", self.object_id.encode_json(context));
+        insert_json_field(&mut fields, "MethodId", self.method_id.encode_json(context));
+        insert_json_field(&mut fields, "InputArguments", encode_json_array(&self.input_arguments, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let object_id = NodeId::decode_json(json_field(fields, "ObjectId"), decoding_limits)?;
+        let method_id = NodeId::decode_json(json_field(fields, "MethodId"), decoding_limits)?;
+        let input_arguments: Option<Vec<Variant>> = decode_json_array(json_field(fields, "InputArguments"), decoding_limits)?;
+        Ok(CallMethodRequest {
+            object_id,
+            method_id,
+            input_arguments,
+        })
+    }
+}
+
+impl XmlEncoder for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.object_id.encode_xml("ObjectId"));
+        element.add_child(self.method_id.encode_xml("MethodId"));
+        element.add_child(encode_xml_array("InputArguments", "Variant", &self.input_arguments));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CallMethodRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let object_id = NodeId::decode_xml(xml_child(element, "ObjectId"), decoding_limits)?;
+        let method_id = NodeId::decode_xml(xml_child(element, "MethodId"), decoding_limits)?;
+        let input_arguments: Option<Vec<Variant>> = decode_xml_array(xml_child(element, "InputArguments"), decoding_limits)?;
+        Ok(CallMethodRequest {
+            object_id,
+            method_id,
+            input_arguments,
+        })
+    }
+}
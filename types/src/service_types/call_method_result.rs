@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<CallMethodResult> for CallMethodResult {
         })
     }
 }
+
+impl JsonEncoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "InputArgumentResults", encode_json_array(&self.input_argument_results, context));
+        insert_json_field(&mut fields, "InputArgumentDiagnosticInfos", encode_json_array(&self.input_argument_diagnostic_infos, context));
+        insert_json_field(&mut fields, "OutputArguments", encode_json_array(&self.output_arguments, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let input_argument_results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "InputArgumentResults"), decoding_limits)?;
+        let input_argument_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "InputArgumentDiagnosticInfos"), decoding_limits)?;
+        let output_arguments: Option<Vec<Variant>> = decode_json_array(json_field(fields, "OutputArguments"), decoding_limits)?;
+        Ok(CallMethodResult {
+            status_code,
+            input_argument_results,
+            input_argument_diagnostic_infos,
+            output_arguments,
+        })
+    }
+}
+
+impl XmlEncoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("InputArgumentResults", "StatusCode", &self.input_argument_results));
+        element.add_child(encode_xml_array("InputArgumentDiagnosticInfos", "DiagnosticInfo", &self.input_argument_diagnostic_infos));
+        element.add_child(encode_xml_array("OutputArguments", "Variant", &self.output_arguments));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CallMethodResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let input_argument_results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "InputArgumentResults"), decoding_limits)?;
+        let input_argument_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "InputArgumentDiagnosticInfos"), decoding_limits)?;
+        let output_arguments: Option<Vec<Variant>> = decode_xml_array(xml_child(element, "OutputArguments"), decoding_limits)?;
+        Ok(CallMethodResult {
+            status_code,
+            input_argument_results,
+            input_argument_diagnostic_infos,
+            output_arguments,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<CallRequest> for CallRequest {
         })
     }
 }
+
+impl JsonEncoder for CallRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "MethodsToCall", encode_json_array(&self.methods_to_call, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CallRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let methods_to_call: Option<Vec<CallMethodRequest>> = decode_json_array(json_field(fields, "MethodsToCall"), decoding_limits)?;
+        Ok(CallRequest {
+            request_header,
+            methods_to_call,
+        })
+    }
+}
+
+impl XmlEncoder for CallRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("MethodsToCall", "CallMethodRequest", &self.methods_to_call));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CallRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let methods_to_call: Option<Vec<CallMethodRequest>> = decode_xml_array(xml_child(element, "MethodsToCall"), decoding_limits)?;
+        Ok(CallRequest {
+            request_header,
+            methods_to_call,
+        })
+    }
+}
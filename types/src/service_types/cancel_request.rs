@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<CancelRequest> for CancelRequest {
         })
     }
 }
+
+impl JsonEncoder for CancelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "RequestHandle", self.request_handle.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CancelRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let request_handle = u32::decode_json(json_field(fields, "RequestHandle"), decoding_limits)?;
+        Ok(CancelRequest {
+            request_header,
+            request_handle,
+        })
+    }
+}
+
+impl XmlEncoder for CancelRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.request_handle.encode_xml("RequestHandle"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CancelRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let request_handle = u32::decode_xml(xml_child(element, "RequestHandle"), decoding_limits)?;
+        Ok(CancelRequest {
+            request_header,
+            request_handle,
+        })
+    }
+}
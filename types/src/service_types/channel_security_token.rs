@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +63,60 @@ impl BinaryEncoder<ChannelSecurityToken> for ChannelSecurityToken {
         })
     }
 }
+
+impl JsonEncoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ChannelId", self.channel_id.encode_json(context));
+        insert_json_field(&mut fields, "TokenId", self.token_id.encode_json(context));
+        insert_json_field(&mut fields, "CreatedAt", self.created_at.encode_json(context));
+        insert_json_field(&mut fields, "RevisedLifetime", self.revised_lifetime.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let channel_id = u32::decode_json(json_field(fields, "ChannelId"), decoding_limits)?;
+        let token_id = u32::decode_json(json_field(fields, "TokenId"), decoding_limits)?;
+        let created_at = DateTime::decode_json(json_field(fields, "CreatedAt"), decoding_limits)?;
+        let revised_lifetime = u32::decode_json(json_field(fields, "RevisedLifetime"), decoding_limits)?;
+        Ok(ChannelSecurityToken {
+            channel_id,
+            token_id,
+            created_at,
+            revised_lifetime,
+        })
+    }
+}
+
+impl XmlEncoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.channel_id.encode_xml("ChannelId"));
+        element.add_child(self.token_id.encode_xml("TokenId"));
+        element.add_child(self.created_at.encode_xml("CreatedAt"));
+        element.add_child(self.revised_lifetime.encode_xml("RevisedLifetime"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ChannelSecurityToken {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let channel_id = u32::decode_xml(xml_child(element, "ChannelId"), decoding_limits)?;
+        let token_id = u32::decode_xml(xml_child(element, "TokenId"), decoding_limits)?;
+        let created_at = DateTime::decode_xml(xml_child(element, "CreatedAt"), decoding_limits)?;
+        let revised_lifetime = u32::decode_xml(xml_child(element, "RevisedLifetime"), decoding_limits)?;
+        Ok(ChannelSecurityToken {
+            channel_id,
+            token_id,
+            created_at,
+            revised_lifetime,
+        })
+    }
+}
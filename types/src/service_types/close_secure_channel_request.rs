@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -46,3 +48,42 @@ impl BinaryEncoder<CloseSecureChannelRequest> for CloseSecureChannelRequest {
         })
     }
 }
+
+impl JsonEncoder for CloseSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CloseSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        Ok(CloseSecureChannelRequest {
+            request_header,
+        })
+    }
+}
+
+impl XmlEncoder for CloseSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CloseSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        Ok(CloseSecureChannelRequest {
+            request_header,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -46,3 +48,42 @@ impl BinaryEncoder<CloseSecureChannelResponse> for CloseSecureChannelResponse {
         })
     }
 }
+
+impl JsonEncoder for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        Ok(CloseSecureChannelResponse {
+            response_header,
+        })
+    }
+}
+
+impl XmlEncoder for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CloseSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        Ok(CloseSecureChannelResponse {
+            response_header,
+        })
+    }
+}
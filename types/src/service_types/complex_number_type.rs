@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<ComplexNumberType> for ComplexNumberType {
         })
     }
 }
+
+impl JsonEncoder for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Real", self.real.encode_json(context));
+        insert_json_field(&mut fields, "Imaginary", self.imaginary.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let real = f32::decode_json(json_field(fields, "Real"), decoding_limits)?;
+        let imaginary = f32::decode_json(json_field(fields, "Imaginary"), decoding_limits)?;
+        Ok(ComplexNumberType {
+            real,
+            imaginary,
+        })
+    }
+}
+
+impl XmlEncoder for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.real.encode_xml("Real"));
+        element.add_child(self.imaginary.encode_xml("Imaginary"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ComplexNumberType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let real = f32::decode_xml(xml_child(element, "Real"), decoding_limits)?;
+        let imaginary = f32::decode_xml(xml_child(element, "Imaginary"), decoding_limits)?;
+        Ok(ComplexNumberType {
+            real,
+            imaginary,
+        })
+    }
+}
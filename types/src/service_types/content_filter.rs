@@ -6,13 +6,15 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
     service_types::ContentFilterElement,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ContentFilter {
     pub elements: Option<Vec<ContentFilterElement>>,
 }
@@ -45,3 +47,42 @@ impl BinaryEncoder<ContentFilter> for ContentFilter {
         })
     }
 }
+
+impl JsonEncoder for ContentFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Elements", encode_json_array(&self.elements, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ContentFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let elements: Option<Vec<ContentFilterElement>> = decode_json_array(json_field(fields, "Elements"), decoding_limits)?;
+        Ok(ContentFilter {
+            elements,
+        })
+    }
+}
+
+impl XmlEncoder for ContentFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("Elements", "ContentFilterElement", &self.elements));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ContentFilter {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let elements: Option<Vec<ContentFilterElement>> = decode_xml_array(xml_child(element, "Elements"), decoding_limits)?;
+        Ok(ContentFilter {
+            elements,
+        })
+    }
+}
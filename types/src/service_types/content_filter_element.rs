@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -13,7 +15,7 @@ use crate::{
     extension_object::ExtensionObject,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ContentFilterElement {
     pub filter_operator: FilterOperator,
     pub filter_operands: Option<Vec<ExtensionObject>>,
@@ -51,3 +53,48 @@ impl BinaryEncoder<ContentFilterElement> for ContentFilterElement {
         })
     }
 }
+
+impl JsonEncoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "FilterOperator", self.filter_operator.encode_json(context));
+        insert_json_field(&mut fields, "FilterOperands", encode_json_array(&self.filter_operands, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let filter_operator = FilterOperator::decode_json(json_field(fields, "FilterOperator"), decoding_limits)?;
+        let filter_operands: Option<Vec<ExtensionObject>> = decode_json_array(json_field(fields, "FilterOperands"), decoding_limits)?;
+        Ok(ContentFilterElement {
+            filter_operator,
+            filter_operands,
+        })
+    }
+}
+
+impl XmlEncoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.filter_operator.encode_xml("FilterOperator"));
+        element.add_child(encode_xml_array("FilterOperands", "ExtensionObject", &self.filter_operands));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ContentFilterElement {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let filter_operator = FilterOperator::decode_xml(xml_child(element, "FilterOperator"), decoding_limits)?;
+        let filter_operands: Option<Vec<ExtensionObject>> = decode_xml_array(xml_child(element, "FilterOperands"), decoding_limits)?;
+        Ok(ContentFilterElement {
+            filter_operator,
+            filter_operands,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<ContentFilterElementResult> for ContentFilterElementResult {
         })
     }
 }
+
+impl JsonEncoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "OperandStatusCodes", encode_json_array(&self.operand_status_codes, context));
+        insert_json_field(&mut fields, "OperandDiagnosticInfos", encode_json_array(&self.operand_diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let operand_status_codes: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "OperandStatusCodes"), decoding_limits)?;
+        let operand_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "OperandDiagnosticInfos"), decoding_limits)?;
+        Ok(ContentFilterElementResult {
+            status_code,
+            operand_status_codes,
+            operand_diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("OperandStatusCodes", "StatusCode", &self.operand_status_codes));
+        element.add_child(encode_xml_array("OperandDiagnosticInfos", "DiagnosticInfo", &self.operand_diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ContentFilterElementResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let operand_status_codes: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "OperandStatusCodes"), decoding_limits)?;
+        let operand_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "OperandDiagnosticInfos"), decoding_limits)?;
+        Ok(ContentFilterElementResult {
+            status_code,
+            operand_status_codes,
+            operand_diagnostic_infos,
+        })
+    }
+}
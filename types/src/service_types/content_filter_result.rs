@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<ContentFilterResult> for ContentFilterResult {
         })
     }
 }
+
+impl JsonEncoder for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ElementResults", encode_json_array(&self.element_results, context));
+        insert_json_field(&mut fields, "ElementDiagnosticInfos", encode_json_array(&self.element_diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let element_results: Option<Vec<ContentFilterElementResult>> = decode_json_array(json_field(fields, "ElementResults"), decoding_limits)?;
+        let element_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "ElementDiagnosticInfos"), decoding_limits)?;
+        Ok(ContentFilterResult {
+            element_results,
+            element_diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("ElementResults", "ContentFilterElementResult", &self.element_results));
+        element.add_child(encode_xml_array("ElementDiagnosticInfos", "DiagnosticInfo", &self.element_diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ContentFilterResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let element_results: Option<Vec<ContentFilterElementResult>> = decode_xml_array(xml_child(element, "ElementResults"), decoding_limits)?;
+        let element_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "ElementDiagnosticInfos"), decoding_limits)?;
+        Ok(ContentFilterResult {
+            element_results,
+            element_diagnostic_infos,
+        })
+    }
+}
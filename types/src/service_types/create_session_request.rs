@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -89,3 +91,90 @@ impl BinaryEncoder<CreateSessionRequest> for CreateSessionRequest {
         })
     }
 }
+
+impl JsonEncoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ClientDescription", self.client_description.encode_json(context));
+        insert_json_field(&mut fields, "ServerUri", self.server_uri.encode_json(context));
+        insert_json_field(&mut fields, "EndpointUrl", self.endpoint_url.encode_json(context));
+        insert_json_field(&mut fields, "SessionName", self.session_name.encode_json(context));
+        insert_json_field(&mut fields, "ClientNonce", self.client_nonce.encode_json(context));
+        insert_json_field(&mut fields, "ClientCertificate", self.client_certificate.encode_json(context));
+        insert_json_field(&mut fields, "RequestedSessionTimeout", self.requested_session_timeout.encode_json(context));
+        insert_json_field(&mut fields, "MaxResponseMessageSize", self.max_response_message_size.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let client_description = ApplicationDescription::decode_json(json_field(fields, "ClientDescription"), decoding_limits)?;
+        let server_uri = UAString::decode_json(json_field(fields, "ServerUri"), decoding_limits)?;
+        let endpoint_url = UAString::decode_json(json_field(fields, "EndpointUrl"), decoding_limits)?;
+        let session_name = UAString::decode_json(json_field(fields, "SessionName"), decoding_limits)?;
+        let client_nonce = ByteString::decode_json(json_field(fields, "ClientNonce"), decoding_limits)?;
+        let client_certificate = ByteString::decode_json(json_field(fields, "ClientCertificate"), decoding_limits)?;
+        let requested_session_timeout = f64::decode_json(json_field(fields, "RequestedSessionTimeout"), decoding_limits)?;
+        let max_response_message_size = u32::decode_json(json_field(fields, "MaxResponseMessageSize"), decoding_limits)?;
+        Ok(CreateSessionRequest {
+            request_header,
+            client_description,
+            server_uri,
+            endpoint_url,
+            session_name,
+            client_nonce,
+            client_certificate,
+            requested_session_timeout,
+            max_response_message_size,
+        })
+    }
+}
+
+impl XmlEncoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.client_description.encode_xml("ClientDescription"));
+        element.add_child(self.server_uri.encode_xml("ServerUri"));
+        element.add_child(self.endpoint_url.encode_xml("EndpointUrl"));
+        element.add_child(self.session_name.encode_xml("SessionName"));
+        element.add_child(self.client_nonce.encode_xml("ClientNonce"));
+        element.add_child(self.client_certificate.encode_xml("ClientCertificate"));
+        element.add_child(self.requested_session_timeout.encode_xml("RequestedSessionTimeout"));
+        element.add_child(self.max_response_message_size.encode_xml("MaxResponseMessageSize"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CreateSessionRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let client_description = ApplicationDescription::decode_xml(xml_child(element, "ClientDescription"), decoding_limits)?;
+        let server_uri = UAString::decode_xml(xml_child(element, "ServerUri"), decoding_limits)?;
+        let endpoint_url = UAString::decode_xml(xml_child(element, "EndpointUrl"), decoding_limits)?;
+        let session_name = UAString::decode_xml(xml_child(element, "SessionName"), decoding_limits)?;
+        let client_nonce = ByteString::decode_xml(xml_child(element, "ClientNonce"), decoding_limits)?;
+        let client_certificate = ByteString::decode_xml(xml_child(element, "ClientCertificate"), decoding_limits)?;
+        let requested_session_timeout = f64::decode_xml(xml_child(element, "RequestedSessionTimeout"), decoding_limits)?;
+        let max_response_message_size = u32::decode_xml(xml_child(element, "MaxResponseMessageSize"), decoding_limits)?;
+        Ok(CreateSessionRequest {
+            request_header,
+            client_description,
+            server_uri,
+            endpoint_url,
+            session_name,
+            client_nonce,
+            client_certificate,
+            requested_session_timeout,
+            max_response_message_size,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -96,3 +98,96 @@ impl BinaryEncoder<CreateSessionResponse> for CreateSessionResponse {
         })
     }
 }
+
+impl JsonEncoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "SessionId", self.session_id.encode_json(context));
+        insert_json_field(&mut fields, "AuthenticationToken", self.authentication_token.encode_json(context));
+        insert_json_field(&mut fields, "RevisedSessionTimeout", self.revised_session_timeout.encode_json(context));
+        insert_json_field(&mut fields, "ServerNonce", self.server_nonce.encode_json(context));
+        insert_json_field(&mut fields, "ServerCertificate", self.server_certificate.encode_json(context));
+        insert_json_field(&mut fields, "ServerEndpoints", encode_json_array(&self.server_endpoints, context));
+        insert_json_field(&mut fields, "ServerSoftwareCertificates", encode_json_array(&self.server_software_certificates, context));
+        insert_json_field(&mut fields, "ServerSignature", self.server_signature.encode_json(context));
+        insert_json_field(&mut fields, "MaxRequestMessageSize", self.max_request_message_size.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let session_id = NodeId::decode_json(json_field(fields, "SessionId"), decoding_limits)?;
+        let authentication_token = NodeId::decode_json(json_field(fields, "AuthenticationToken"), decoding_limits)?;
+        let revised_session_timeout = f64::decode_json(json_field(fields, "RevisedSessionTimeout"), decoding_limits)?;
+        let server_nonce = ByteString::decode_json(json_field(fields, "ServerNonce"), decoding_limits)?;
+        let server_certificate = ByteString::decode_json(json_field(fields, "ServerCertificate"), decoding_limits)?;
+        let server_endpoints: Option<Vec<EndpointDescription>> = decode_json_array(json_field(fields, "ServerEndpoints"), decoding_limits)?;
+        let server_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_json_array(json_field(fields, "ServerSoftwareCertificates"), decoding_limits)?;
+        let server_signature = SignatureData::decode_json(json_field(fields, "ServerSignature"), decoding_limits)?;
+        let max_request_message_size = u32::decode_json(json_field(fields, "MaxRequestMessageSize"), decoding_limits)?;
+        Ok(CreateSessionResponse {
+            response_header,
+            session_id,
+            authentication_token,
+            revised_session_timeout,
+            server_nonce,
+            server_certificate,
+            server_endpoints,
+            server_software_certificates,
+            server_signature,
+            max_request_message_size,
+        })
+    }
+}
+
+impl XmlEncoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(self.session_id.encode_xml("SessionId"));
+        element.add_child(self.authentication_token.encode_xml("AuthenticationToken"));
+        element.add_child(self.revised_session_timeout.encode_xml("RevisedSessionTimeout"));
+        element.add_child(self.server_nonce.encode_xml("ServerNonce"));
+        element.add_child(self.server_certificate.encode_xml("ServerCertificate"));
+        element.add_child(encode_xml_array("ServerEndpoints", "EndpointDescription", &self.server_endpoints));
+        element.add_child(encode_xml_array("ServerSoftwareCertificates", "SignedSoftwareCertificate", &self.server_software_certificates));
+        element.add_child(self.server_signature.encode_xml("ServerSignature"));
+        element.add_child(self.max_request_message_size.encode_xml("MaxRequestMessageSize"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CreateSessionResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let session_id = NodeId::decode_xml(xml_child(element, "SessionId"), decoding_limits)?;
+        let authentication_token = NodeId::decode_xml(xml_child(element, "AuthenticationToken"), decoding_limits)?;
+        let revised_session_timeout = f64::decode_xml(xml_child(element, "RevisedSessionTimeout"), decoding_limits)?;
+        let server_nonce = ByteString::decode_xml(xml_child(element, "ServerNonce"), decoding_limits)?;
+        let server_certificate = ByteString::decode_xml(xml_child(element, "ServerCertificate"), decoding_limits)?;
+        let server_endpoints: Option<Vec<EndpointDescription>> = decode_xml_array(xml_child(element, "ServerEndpoints"), decoding_limits)?;
+        let server_software_certificates: Option<Vec<SignedSoftwareCertificate>> = decode_xml_array(xml_child(element, "ServerSoftwareCertificates"), decoding_limits)?;
+        let server_signature = SignatureData::decode_xml(xml_child(element, "ServerSignature"), decoding_limits)?;
+        let max_request_message_size = u32::decode_xml(xml_child(element, "MaxRequestMessageSize"), decoding_limits)?;
+        Ok(CreateSessionResponse {
+            response_header,
+            session_id,
+            authentication_token,
+            revised_session_timeout,
+            server_nonce,
+            server_certificate,
+            server_endpoints,
+            server_software_certificates,
+            server_signature,
+            max_request_message_size,
+        })
+    }
+}
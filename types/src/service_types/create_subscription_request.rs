@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -75,3 +77,78 @@ impl BinaryEncoder<CreateSubscriptionRequest> for CreateSubscriptionRequest {
         })
     }
 }
+
+impl JsonEncoder for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "RequestedPublishingInterval", self.requested_publishing_interval.encode_json(context));
+        insert_json_field(&mut fields, "RequestedLifetimeCount", self.requested_lifetime_count.encode_json(context));
+        insert_json_field(&mut fields, "RequestedMaxKeepAliveCount", self.requested_max_keep_alive_count.encode_json(context));
+        insert_json_field(&mut fields, "MaxNotificationsPerPublish", self.max_notifications_per_publish.encode_json(context));
+        insert_json_field(&mut fields, "PublishingEnabled", self.publishing_enabled.encode_json(context));
+        insert_json_field(&mut fields, "Priority", self.priority.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let requested_publishing_interval = f64::decode_json(json_field(fields, "RequestedPublishingInterval"), decoding_limits)?;
+        let requested_lifetime_count = u32::decode_json(json_field(fields, "RequestedLifetimeCount"), decoding_limits)?;
+        let requested_max_keep_alive_count = u32::decode_json(json_field(fields, "RequestedMaxKeepAliveCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_json(json_field(fields, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let publishing_enabled = bool::decode_json(json_field(fields, "PublishingEnabled"), decoding_limits)?;
+        let priority = u8::decode_json(json_field(fields, "Priority"), decoding_limits)?;
+        Ok(CreateSubscriptionRequest {
+            request_header,
+            requested_publishing_interval,
+            requested_lifetime_count,
+            requested_max_keep_alive_count,
+            max_notifications_per_publish,
+            publishing_enabled,
+            priority,
+        })
+    }
+}
+
+impl XmlEncoder for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.requested_publishing_interval.encode_xml("RequestedPublishingInterval"));
+        element.add_child(self.requested_lifetime_count.encode_xml("RequestedLifetimeCount"));
+        element.add_child(self.requested_max_keep_alive_count.encode_xml("RequestedMaxKeepAliveCount"));
+        element.add_child(self.max_notifications_per_publish.encode_xml("MaxNotificationsPerPublish"));
+        element.add_child(self.publishing_enabled.encode_xml("PublishingEnabled"));
+        element.add_child(self.priority.encode_xml("Priority"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for CreateSubscriptionRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let requested_publishing_interval = f64::decode_xml(xml_child(element, "RequestedPublishingInterval"), decoding_limits)?;
+        let requested_lifetime_count = u32::decode_xml(xml_child(element, "RequestedLifetimeCount"), decoding_limits)?;
+        let requested_max_keep_alive_count = u32::decode_xml(xml_child(element, "RequestedMaxKeepAliveCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_xml(xml_child(element, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let publishing_enabled = bool::decode_xml(xml_child(element, "PublishingEnabled"), decoding_limits)?;
+        let priority = u8::decode_xml(xml_child(element, "Priority"), decoding_limits)?;
+        Ok(CreateSubscriptionRequest {
+            request_header,
+            requested_publishing_interval,
+            requested_lifetime_count,
+            requested_max_keep_alive_count,
+            max_notifications_per_publish,
+            publishing_enabled,
+            priority,
+        })
+    }
+}
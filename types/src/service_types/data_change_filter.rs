@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::enums::DataChangeTrigger,
 };
@@ -47,3 +49,54 @@ impl BinaryEncoder<DataChangeFilter> for DataChangeFilter {
         })
     }
 }
+
+impl JsonEncoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Trigger", self.trigger.encode_json(context));
+        insert_json_field(&mut fields, "DeadbandType", self.deadband_type.encode_json(context));
+        insert_json_field(&mut fields, "DeadbandValue", self.deadband_value.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let trigger = DataChangeTrigger::decode_json(json_field(fields, "Trigger"), decoding_limits)?;
+        let deadband_type = u32::decode_json(json_field(fields, "DeadbandType"), decoding_limits)?;
+        let deadband_value = f64::decode_json(json_field(fields, "DeadbandValue"), decoding_limits)?;
+        Ok(DataChangeFilter {
+            trigger,
+            deadband_type,
+            deadband_value,
+        })
+    }
+}
+
+impl XmlEncoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.trigger.encode_xml("Trigger"));
+        element.add_child(self.deadband_type.encode_xml("DeadbandType"));
+        element.add_child(self.deadband_value.encode_xml("DeadbandValue"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DataChangeFilter {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let trigger = DataChangeTrigger::decode_xml(xml_child(element, "Trigger"), decoding_limits)?;
+        let deadband_type = u32::decode_xml(xml_child(element, "DeadbandType"), decoding_limits)?;
+        let deadband_value = f64::decode_xml(xml_child(element, "DeadbandValue"), decoding_limits)?;
+        Ok(DataChangeFilter {
+            trigger,
+            deadband_type,
+            deadband_value,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     diagnostic_info::DiagnosticInfo,
     service_types::MonitoredItemNotification,
@@ -43,3 +45,48 @@ impl BinaryEncoder<DataChangeNotification> for DataChangeNotification {
         })
     }
 }
+
+impl JsonEncoder for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "MonitoredItems", encode_json_array(&self.monitored_items, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let monitored_items: Option<Vec<MonitoredItemNotification>> = decode_json_array(json_field(fields, "MonitoredItems"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        Ok(DataChangeNotification {
+            monitored_items,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("MonitoredItems", "MonitoredItemNotification", &self.monitored_items));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DataChangeNotification {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let monitored_items: Option<Vec<MonitoredItemNotification>> = decode_xml_array(xml_child(element, "MonitoredItems"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        Ok(DataChangeNotification {
+            monitored_items,
+            diagnostic_infos,
+        })
+    }
+}
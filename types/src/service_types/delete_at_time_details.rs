@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     node_id::NodeId,
     date_time::DateTime,
@@ -43,3 +45,48 @@ impl BinaryEncoder<DeleteAtTimeDetails> for DeleteAtTimeDetails {
         })
     }
 }
+
+impl JsonEncoder for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "ReqTimes", encode_json_array(&self.req_times, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let req_times: Option<Vec<DateTime>> = decode_json_array(json_field(fields, "ReqTimes"), decoding_limits)?;
+        Ok(DeleteAtTimeDetails {
+            node_id,
+            req_times,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(encode_xml_array("ReqTimes", "DateTime", &self.req_times));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteAtTimeDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let req_times: Option<Vec<DateTime>> = decode_xml_array(xml_child(element, "ReqTimes"), decoding_limits)?;
+        Ok(DeleteAtTimeDetails {
+            node_id,
+            req_times,
+        })
+    }
+}
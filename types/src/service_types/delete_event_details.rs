@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     node_id::NodeId,
     byte_string::ByteString,
@@ -43,3 +45,48 @@ impl BinaryEncoder<DeleteEventDetails> for DeleteEventDetails {
         })
     }
 }
+
+impl JsonEncoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "EventIds", encode_json_array(&self.event_ids, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let event_ids: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "EventIds"), decoding_limits)?;
+        Ok(DeleteEventDetails {
+            node_id,
+            event_ids,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(encode_xml_array("EventIds", "ByteString", &self.event_ids));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteEventDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let event_ids: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "EventIds"), decoding_limits)?;
+        Ok(DeleteEventDetails {
+            node_id,
+            event_ids,
+        })
+    }
+}
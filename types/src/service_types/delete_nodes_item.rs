@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<DeleteNodesItem> for DeleteNodesItem {
         })
     }
 }
+
+impl JsonEncoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "DeleteTargetReferences", self.delete_target_references.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let delete_target_references = bool::decode_json(json_field(fields, "DeleteTargetReferences"), decoding_limits)?;
+        Ok(DeleteNodesItem {
+            node_id,
+            delete_target_references,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.delete_target_references.encode_xml("DeleteTargetReferences"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteNodesItem {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let delete_target_references = bool::decode_xml(xml_child(element, "DeleteTargetReferences"), decoding_limits)?;
+        Ok(DeleteNodesItem {
+            node_id,
+            delete_target_references,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     node_id::NodeId,
     date_time::DateTime,
@@ -53,3 +55,60 @@ impl BinaryEncoder<DeleteRawModifiedDetails> for DeleteRawModifiedDetails {
         })
     }
 }
+
+impl JsonEncoder for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "IsDeleteModified", self.is_delete_modified.encode_json(context));
+        insert_json_field(&mut fields, "StartTime", self.start_time.encode_json(context));
+        insert_json_field(&mut fields, "EndTime", self.end_time.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let is_delete_modified = bool::decode_json(json_field(fields, "IsDeleteModified"), decoding_limits)?;
+        let start_time = DateTime::decode_json(json_field(fields, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_json(json_field(fields, "EndTime"), decoding_limits)?;
+        Ok(DeleteRawModifiedDetails {
+            node_id,
+            is_delete_modified,
+            start_time,
+            end_time,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.is_delete_modified.encode_xml("IsDeleteModified"));
+        element.add_child(self.start_time.encode_xml("StartTime"));
+        element.add_child(self.end_time.encode_xml("EndTime"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let is_delete_modified = bool::decode_xml(xml_child(element, "IsDeleteModified"), decoding_limits)?;
+        let start_time = DateTime::decode_xml(xml_child(element, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_xml(xml_child(element, "EndTime"), decoding_limits)?;
+        Ok(DeleteRawModifiedDetails {
+            node_id,
+            is_delete_modified,
+            start_time,
+            end_time,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +69,66 @@ impl BinaryEncoder<DeleteReferencesItem> for DeleteReferencesItem {
         })
     }
 }
+
+impl JsonEncoder for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SourceNodeId", self.source_node_id.encode_json(context));
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IsForward", self.is_forward.encode_json(context));
+        insert_json_field(&mut fields, "TargetNodeId", self.target_node_id.encode_json(context));
+        insert_json_field(&mut fields, "DeleteBidirectional", self.delete_bidirectional.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let source_node_id = NodeId::decode_json(json_field(fields, "SourceNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_json(json_field(fields, "IsForward"), decoding_limits)?;
+        let target_node_id = ExpandedNodeId::decode_json(json_field(fields, "TargetNodeId"), decoding_limits)?;
+        let delete_bidirectional = bool::decode_json(json_field(fields, "DeleteBidirectional"), decoding_limits)?;
+        Ok(DeleteReferencesItem {
+            source_node_id,
+            reference_type_id,
+            is_forward,
+            target_node_id,
+            delete_bidirectional,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.source_node_id.encode_xml("SourceNodeId"));
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.is_forward.encode_xml("IsForward"));
+        element.add_child(self.target_node_id.encode_xml("TargetNodeId"));
+        element.add_child(self.delete_bidirectional.encode_xml("DeleteBidirectional"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteReferencesItem {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let source_node_id = NodeId::decode_xml(xml_child(element, "SourceNodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_xml(xml_child(element, "IsForward"), decoding_limits)?;
+        let target_node_id = ExpandedNodeId::decode_xml(xml_child(element, "TargetNodeId"), decoding_limits)?;
+        let delete_bidirectional = bool::decode_xml(xml_child(element, "DeleteBidirectional"), decoding_limits)?;
+        Ok(DeleteReferencesItem {
+            source_node_id,
+            reference_type_id,
+            is_forward,
+            target_node_id,
+            delete_bidirectional,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<DeleteReferencesRequest> for DeleteReferencesRequest {
         })
     }
 }
+
+impl JsonEncoder for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ReferencesToDelete", encode_json_array(&self.references_to_delete, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let references_to_delete: Option<Vec<DeleteReferencesItem>> = decode_json_array(json_field(fields, "ReferencesToDelete"), decoding_limits)?;
+        Ok(DeleteReferencesRequest {
+            request_header,
+            references_to_delete,
+        })
+    }
+}
+
+impl XmlEncoder for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("ReferencesToDelete", "DeleteReferencesItem", &self.references_to_delete));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for DeleteReferencesRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let references_to_delete: Option<Vec<DeleteReferencesItem>> = decode_xml_array(xml_child(element, "ReferencesToDelete"), decoding_limits)?;
+        Ok(DeleteReferencesRequest {
+            request_header,
+            references_to_delete,
+        })
+    }
+}
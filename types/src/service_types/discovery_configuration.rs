@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -38,3 +40,34 @@ impl BinaryEncoder<DiscoveryConfiguration> for DiscoveryConfiguration {
         })
     }
 }
+
+impl JsonEncoder for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        JsonValue::Object(JsonObject::new())
+    }
+}
+
+impl JsonDecoder for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(DiscoveryConfiguration {
+        })
+    }
+}
+
+impl XmlEncoder for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        Some(XmlNode::new(name))
+    }
+}
+
+impl XmlDecoder for DiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(DiscoveryConfiguration {
+        })
+    }
+}
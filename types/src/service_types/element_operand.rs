@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
 };
 
@@ -36,3 +38,42 @@ impl BinaryEncoder<ElementOperand> for ElementOperand {
         })
     }
 }
+
+impl JsonEncoder for ElementOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Index", self.index.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ElementOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let index = u32::decode_json(json_field(fields, "Index"), decoding_limits)?;
+        Ok(ElementOperand {
+            index,
+        })
+    }
+}
+
+impl XmlEncoder for ElementOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.index.encode_xml("Index"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ElementOperand {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let index = u32::decode_xml(xml_child(element, "Index"), decoding_limits)?;
+        Ok(ElementOperand {
+            index,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -84,3 +86,90 @@ impl BinaryEncoder<EndpointConfiguration> for EndpointConfiguration {
         })
     }
 }
+
+impl JsonEncoder for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "OperationTimeout", self.operation_timeout.encode_json(context));
+        insert_json_field(&mut fields, "UseBinaryEncoding", self.use_binary_encoding.encode_json(context));
+        insert_json_field(&mut fields, "MaxStringLength", self.max_string_length.encode_json(context));
+        insert_json_field(&mut fields, "MaxByteStringLength", self.max_byte_string_length.encode_json(context));
+        insert_json_field(&mut fields, "MaxArrayLength", self.max_array_length.encode_json(context));
+        insert_json_field(&mut fields, "MaxMessageSize", self.max_message_size.encode_json(context));
+        insert_json_field(&mut fields, "MaxBufferSize", self.max_buffer_size.encode_json(context));
+        insert_json_field(&mut fields, "ChannelLifetime", self.channel_lifetime.encode_json(context));
+        insert_json_field(&mut fields, "SecurityTokenLifetime", self.security_token_lifetime.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let operation_timeout = i32::decode_json(json_field(fields, "OperationTimeout"), decoding_limits)?;
+        let use_binary_encoding = bool::decode_json(json_field(fields, "UseBinaryEncoding"), decoding_limits)?;
+        let max_string_length = i32::decode_json(json_field(fields, "MaxStringLength"), decoding_limits)?;
+        let max_byte_string_length = i32::decode_json(json_field(fields, "MaxByteStringLength"), decoding_limits)?;
+        let max_array_length = i32::decode_json(json_field(fields, "MaxArrayLength"), decoding_limits)?;
+        let max_message_size = i32::decode_json(json_field(fields, "MaxMessageSize"), decoding_limits)?;
+        let max_buffer_size = i32::decode_json(json_field(fields, "MaxBufferSize"), decoding_limits)?;
+        let channel_lifetime = i32::decode_json(json_field(fields, "ChannelLifetime"), decoding_limits)?;
+        let security_token_lifetime = i32::decode_json(json_field(fields, "SecurityTokenLifetime"), decoding_limits)?;
+        Ok(EndpointConfiguration {
+            operation_timeout,
+            use_binary_encoding,
+            max_string_length,
+            max_byte_string_length,
+            max_array_length,
+            max_message_size,
+            max_buffer_size,
+            channel_lifetime,
+            security_token_lifetime,
+        })
+    }
+}
+
+impl XmlEncoder for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.operation_timeout.encode_xml("OperationTimeout"));
+        element.add_child(self.use_binary_encoding.encode_xml("UseBinaryEncoding"));
+        element.add_child(self.max_string_length.encode_xml("MaxStringLength"));
+        element.add_child(self.max_byte_string_length.encode_xml("MaxByteStringLength"));
+        element.add_child(self.max_array_length.encode_xml("MaxArrayLength"));
+        element.add_child(self.max_message_size.encode_xml("MaxMessageSize"));
+        element.add_child(self.max_buffer_size.encode_xml("MaxBufferSize"));
+        element.add_child(self.channel_lifetime.encode_xml("ChannelLifetime"));
+        element.add_child(self.security_token_lifetime.encode_xml("SecurityTokenLifetime"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EndpointConfiguration {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let operation_timeout = i32::decode_xml(xml_child(element, "OperationTimeout"), decoding_limits)?;
+        let use_binary_encoding = bool::decode_xml(xml_child(element, "UseBinaryEncoding"), decoding_limits)?;
+        let max_string_length = i32::decode_xml(xml_child(element, "MaxStringLength"), decoding_limits)?;
+        let max_byte_string_length = i32::decode_xml(xml_child(element, "MaxByteStringLength"), decoding_limits)?;
+        let max_array_length = i32::decode_xml(xml_child(element, "MaxArrayLength"), decoding_limits)?;
+        let max_message_size = i32::decode_xml(xml_child(element, "MaxMessageSize"), decoding_limits)?;
+        let max_buffer_size = i32::decode_xml(xml_child(element, "MaxBufferSize"), decoding_limits)?;
+        let channel_lifetime = i32::decode_xml(xml_child(element, "ChannelLifetime"), decoding_limits)?;
+        let security_token_lifetime = i32::decode_xml(xml_child(element, "SecurityTokenLifetime"), decoding_limits)?;
+        Ok(EndpointConfiguration {
+            operation_timeout,
+            use_binary_encoding,
+            max_string_length,
+            max_byte_string_length,
+            max_array_length,
+            max_message_size,
+            max_buffer_size,
+            channel_lifetime,
+            security_token_lifetime,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -85,3 +87,84 @@ impl BinaryEncoder<EndpointDescription> for EndpointDescription {
         })
     }
 }
+
+impl JsonEncoder for EndpointDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "EndpointUrl", self.endpoint_url.encode_json(context));
+        insert_json_field(&mut fields, "Server", self.server.encode_json(context));
+        insert_json_field(&mut fields, "ServerCertificate", self.server_certificate.encode_json(context));
+        insert_json_field(&mut fields, "SecurityMode", self.security_mode.encode_json(context));
+        insert_json_field(&mut fields, "SecurityPolicyUri", self.security_policy_uri.encode_json(context));
+        insert_json_field(&mut fields, "UserIdentityTokens", encode_json_array(&self.user_identity_tokens, context));
+        insert_json_field(&mut fields, "TransportProfileUri", self.transport_profile_uri.encode_json(context));
+        insert_json_field(&mut fields, "SecurityLevel", self.security_level.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EndpointDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let endpoint_url = UAString::decode_json(json_field(fields, "EndpointUrl"), decoding_limits)?;
+        let server = ApplicationDescription::decode_json(json_field(fields, "Server"), decoding_limits)?;
+        let server_certificate = ByteString::decode_json(json_field(fields, "ServerCertificate"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_json(json_field(fields, "SecurityMode"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_json(json_field(fields, "SecurityPolicyUri"), decoding_limits)?;
+        let user_identity_tokens: Option<Vec<UserTokenPolicy>> = decode_json_array(json_field(fields, "UserIdentityTokens"), decoding_limits)?;
+        let transport_profile_uri = UAString::decode_json(json_field(fields, "TransportProfileUri"), decoding_limits)?;
+        let security_level = u8::decode_json(json_field(fields, "SecurityLevel"), decoding_limits)?;
+        Ok(EndpointDescription {
+            endpoint_url,
+            server,
+            server_certificate,
+            security_mode,
+            security_policy_uri,
+            user_identity_tokens,
+            transport_profile_uri,
+            security_level,
+        })
+    }
+}
+
+impl XmlEncoder for EndpointDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.endpoint_url.encode_xml("EndpointUrl"));
+        element.add_child(self.server.encode_xml("Server"));
+        element.add_child(self.server_certificate.encode_xml("ServerCertificate"));
+        element.add_child(self.security_mode.encode_xml("SecurityMode"));
+        element.add_child(self.security_policy_uri.encode_xml("SecurityPolicyUri"));
+        element.add_child(encode_xml_array("UserIdentityTokens", "UserTokenPolicy", &self.user_identity_tokens));
+        element.add_child(self.transport_profile_uri.encode_xml("TransportProfileUri"));
+        element.add_child(self.security_level.encode_xml("SecurityLevel"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EndpointDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let endpoint_url = UAString::decode_xml(xml_child(element, "EndpointUrl"), decoding_limits)?;
+        let server = ApplicationDescription::decode_xml(xml_child(element, "Server"), decoding_limits)?;
+        let server_certificate = ByteString::decode_xml(xml_child(element, "ServerCertificate"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_xml(xml_child(element, "SecurityMode"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_xml(xml_child(element, "SecurityPolicyUri"), decoding_limits)?;
+        let user_identity_tokens: Option<Vec<UserTokenPolicy>> = decode_xml_array(xml_child(element, "UserIdentityTokens"), decoding_limits)?;
+        let transport_profile_uri = UAString::decode_xml(xml_child(element, "TransportProfileUri"), decoding_limits)?;
+        let security_level = u8::decode_xml(xml_child(element, "SecurityLevel"), decoding_limits)?;
+        Ok(EndpointDescription {
+            endpoint_url,
+            server,
+            server_certificate,
+            security_mode,
+            security_policy_uri,
+            user_identity_tokens,
+            transport_profile_uri,
+            security_level,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -45,3 +47,42 @@ impl BinaryEncoder<EndpointUrlListDataType> for EndpointUrlListDataType {
         })
     }
 }
+
+impl JsonEncoder for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "EndpointUrlList", encode_json_array(&self.endpoint_url_list, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let endpoint_url_list: Option<Vec<UAString>> = decode_json_array(json_field(fields, "EndpointUrlList"), decoding_limits)?;
+        Ok(EndpointUrlListDataType {
+            endpoint_url_list,
+        })
+    }
+}
+
+impl XmlEncoder for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("EndpointUrlList", "String", &self.endpoint_url_list));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EndpointUrlListDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let endpoint_url_list: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "EndpointUrlList"), decoding_limits)?;
+        Ok(EndpointUrlListDataType {
+            endpoint_url_list,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<EnumValueType> for EnumValueType {
         })
     }
 }
+
+impl JsonEncoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        insert_json_field(&mut fields, "DisplayName", self.display_name.encode_json(context));
+        insert_json_field(&mut fields, "Description", self.description.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let value = i64::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        let display_name = LocalizedText::decode_json(json_field(fields, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_json(json_field(fields, "Description"), decoding_limits)?;
+        Ok(EnumValueType {
+            value,
+            display_name,
+            description,
+        })
+    }
+}
+
+impl XmlEncoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.value.encode_xml("Value"));
+        element.add_child(self.display_name.encode_xml("DisplayName"));
+        element.add_child(self.description.encode_xml("Description"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EnumValueType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = i64::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        let display_name = LocalizedText::decode_xml(xml_child(element, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_xml(xml_child(element, "Description"), decoding_limits)?;
+        Ok(EnumValueType {
+            value,
+            display_name,
+            description,
+        })
+    }
+}
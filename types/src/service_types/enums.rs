@@ -197,7 +197,7 @@ impl BinaryEncoder<DataChangeTrigger> for DataChangeTrigger {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
 pub enum FilterOperator {
     Equals = 0,
     IsNull = 1,
@@ -212,6 +212,9 @@ pub enum FilterOperator {
     And = 10,
     Or = 11,
     Cast = 12,
+    InView = 13,
+    OfType = 14,
+    RelatedTo = 15,
     BitwiseAnd = 16,
     BitwiseOr = 17,
 }
@@ -243,6 +246,9 @@ impl BinaryEncoder<FilterOperator> for FilterOperator {
             10 => Ok(FilterOperator::And),
             11 => Ok(FilterOperator::Or),
             12 => Ok(FilterOperator::Cast),
+            13 => Ok(FilterOperator::InView),
+            14 => Ok(FilterOperator::OfType),
+            15 => Ok(FilterOperator::RelatedTo),
             16 => Ok(FilterOperator::BitwiseAnd),
             17 => Ok(FilterOperator::BitwiseOr),
             _ => {
@@ -357,3 +363,94 @@ impl BinaryEncoder<ServerState> for ServerState {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HistoryUpdateType {
+    Insert = 1,
+    Replace = 2,
+    Update = 3,
+    Delete = 4,
+}
+
+impl BinaryEncoder<HistoryUpdateType> for HistoryUpdateType {
+    fn byte_len(&self) -> usize {
+        4
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        // All enums are Int32
+        write_i32(stream, *self as i32)
+    }
+
+    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
+        // All enums are Int32
+        let value = read_i32(stream)?;
+        match value {
+            1 => Ok(HistoryUpdateType::Insert),
+            2 => Ok(HistoryUpdateType::Replace),
+            3 => Ok(HistoryUpdateType::Update),
+            4 => Ok(HistoryUpdateType::Delete),
+            _ => {
+                error!("Don't know what history update type {} is", value);
+                Err(StatusCode::BadUnexpectedError)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PerformUpdateType {
+    Insert = 1,
+    Replace = 2,
+    Update = 3,
+    Remove = 4,
+}
+
+impl BinaryEncoder<PerformUpdateType> for PerformUpdateType {
+    fn byte_len(&self) -> usize {
+        4
+    }
+
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        // All enums are Int32
+        write_i32(stream, *self as i32)
+    }
+
+    fn decode<S: Read>(stream: &mut S, _: &DecodingLimits) -> EncodingResult<Self> {
+        // All enums are Int32
+        let value = read_i32(stream)?;
+        match value {
+            1 => Ok(PerformUpdateType::Insert),
+            2 => Ok(PerformUpdateType::Replace),
+            3 => Ok(PerformUpdateType::Update),
+            4 => Ok(PerformUpdateType::Remove),
+            _ => {
+                error!("Don't know what perform update type {} is", value);
+                Err(StatusCode::BadUnexpectedError)
+            }
+        }
+    }
+}
+
+json_enum_impl!(UserTokenType);
+json_enum_impl!(ApplicationType);
+json_enum_impl!(TimestampsToReturn);
+json_enum_impl!(NodeClass);
+json_enum_impl!(DataChangeTrigger);
+json_enum_impl!(FilterOperator);
+json_enum_impl!(BrowseDirection);
+json_enum_impl!(SecurityTokenRequestType);
+json_enum_impl!(ServerState);
+json_enum_impl!(HistoryUpdateType);
+json_enum_impl!(PerformUpdateType);
+xml_enum_impl!(UserTokenType);
+xml_enum_impl!(ApplicationType);
+xml_enum_impl!(TimestampsToReturn);
+xml_enum_impl!(NodeClass);
+xml_enum_impl!(DataChangeTrigger);
+xml_enum_impl!(FilterOperator);
+xml_enum_impl!(BrowseDirection);
+xml_enum_impl!(SecurityTokenRequestType);
+xml_enum_impl!(ServerState);
+xml_enum_impl!(HistoryUpdateType);
+xml_enum_impl!(PerformUpdateType);
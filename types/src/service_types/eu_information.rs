@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +63,60 @@ impl BinaryEncoder<EUInformation> for EUInformation {
         })
     }
 }
+
+impl JsonEncoder for EUInformation {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NamespaceUri", self.namespace_uri.encode_json(context));
+        insert_json_field(&mut fields, "UnitId", self.unit_id.encode_json(context));
+        insert_json_field(&mut fields, "DisplayName", self.display_name.encode_json(context));
+        insert_json_field(&mut fields, "Description", self.description.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EUInformation {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let namespace_uri = UAString::decode_json(json_field(fields, "NamespaceUri"), decoding_limits)?;
+        let unit_id = i32::decode_json(json_field(fields, "UnitId"), decoding_limits)?;
+        let display_name = LocalizedText::decode_json(json_field(fields, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_json(json_field(fields, "Description"), decoding_limits)?;
+        Ok(EUInformation {
+            namespace_uri,
+            unit_id,
+            display_name,
+            description,
+        })
+    }
+}
+
+impl XmlEncoder for EUInformation {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.namespace_uri.encode_xml("NamespaceUri"));
+        element.add_child(self.unit_id.encode_xml("UnitId"));
+        element.add_child(self.display_name.encode_xml("DisplayName"));
+        element.add_child(self.description.encode_xml("Description"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EUInformation {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let namespace_uri = UAString::decode_xml(xml_child(element, "NamespaceUri"), decoding_limits)?;
+        let unit_id = i32::decode_xml(xml_child(element, "UnitId"), decoding_limits)?;
+        let display_name = LocalizedText::decode_xml(xml_child(element, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_xml(xml_child(element, "Description"), decoding_limits)?;
+        Ok(EUInformation {
+            namespace_uri,
+            unit_id,
+            display_name,
+            description,
+        })
+    }
+}
@@ -6,13 +6,15 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
     variant::Variant,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EventFieldList {
     pub client_handle: u32,
     pub event_fields: Option<Vec<Variant>>,
@@ -50,3 +52,48 @@ impl BinaryEncoder<EventFieldList> for EventFieldList {
         })
     }
 }
+
+impl JsonEncoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ClientHandle", self.client_handle.encode_json(context));
+        insert_json_field(&mut fields, "EventFields", encode_json_array(&self.event_fields, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let client_handle = u32::decode_json(json_field(fields, "ClientHandle"), decoding_limits)?;
+        let event_fields: Option<Vec<Variant>> = decode_json_array(json_field(fields, "EventFields"), decoding_limits)?;
+        Ok(EventFieldList {
+            client_handle,
+            event_fields,
+        })
+    }
+}
+
+impl XmlEncoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.client_handle.encode_xml("ClientHandle"));
+        element.add_child(encode_xml_array("EventFields", "Variant", &self.event_fields));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EventFieldList {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let client_handle = u32::decode_xml(xml_child(element, "ClientHandle"), decoding_limits)?;
+        let event_fields: Option<Vec<Variant>> = decode_xml_array(xml_child(element, "EventFields"), decoding_limits)?;
+        Ok(EventFieldList {
+            client_handle,
+            event_fields,
+        })
+    }
+}
@@ -6,12 +6,14 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::SimpleAttributeOperand,
     service_types::ContentFilter,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EventFilter {
     pub select_clauses: Option<Vec<SimpleAttributeOperand>>,
     pub where_clause: ContentFilter,
@@ -43,3 +45,48 @@ impl BinaryEncoder<EventFilter> for EventFilter {
         })
     }
 }
+
+impl JsonEncoder for EventFilter {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SelectClauses", encode_json_array(&self.select_clauses, context));
+        insert_json_field(&mut fields, "WhereClause", self.where_clause.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EventFilter {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let select_clauses: Option<Vec<SimpleAttributeOperand>> = decode_json_array(json_field(fields, "SelectClauses"), decoding_limits)?;
+        let where_clause = ContentFilter::decode_json(json_field(fields, "WhereClause"), decoding_limits)?;
+        Ok(EventFilter {
+            select_clauses,
+            where_clause,
+        })
+    }
+}
+
+impl XmlEncoder for EventFilter {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("SelectClauses", "SimpleAttributeOperand", &self.select_clauses));
+        element.add_child(self.where_clause.encode_xml("WhereClause"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EventFilter {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let select_clauses: Option<Vec<SimpleAttributeOperand>> = decode_xml_array(xml_child(element, "SelectClauses"), decoding_limits)?;
+        let where_clause = ContentFilter::decode_xml(xml_child(element, "WhereClause"), decoding_limits)?;
+        Ok(EventFilter {
+            select_clauses,
+            where_clause,
+        })
+    }
+}
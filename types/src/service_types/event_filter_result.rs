@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     status_codes::StatusCode,
     diagnostic_info::DiagnosticInfo,
@@ -49,3 +51,54 @@ impl BinaryEncoder<EventFilterResult> for EventFilterResult {
         })
     }
 }
+
+impl JsonEncoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SelectClauseResults", encode_json_array(&self.select_clause_results, context));
+        insert_json_field(&mut fields, "SelectClauseDiagnosticInfos", encode_json_array(&self.select_clause_diagnostic_infos, context));
+        insert_json_field(&mut fields, "WhereClauseResult", self.where_clause_result.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let select_clause_results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "SelectClauseResults"), decoding_limits)?;
+        let select_clause_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "SelectClauseDiagnosticInfos"), decoding_limits)?;
+        let where_clause_result = ContentFilterResult::decode_json(json_field(fields, "WhereClauseResult"), decoding_limits)?;
+        Ok(EventFilterResult {
+            select_clause_results,
+            select_clause_diagnostic_infos,
+            where_clause_result,
+        })
+    }
+}
+
+impl XmlEncoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("SelectClauseResults", "StatusCode", &self.select_clause_results));
+        element.add_child(encode_xml_array("SelectClauseDiagnosticInfos", "DiagnosticInfo", &self.select_clause_diagnostic_infos));
+        element.add_child(self.where_clause_result.encode_xml("WhereClauseResult"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EventFilterResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let select_clause_results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "SelectClauseResults"), decoding_limits)?;
+        let select_clause_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "SelectClauseDiagnosticInfos"), decoding_limits)?;
+        let where_clause_result = ContentFilterResult::decode_xml(xml_child(element, "WhereClauseResult"), decoding_limits)?;
+        Ok(EventFilterResult {
+            select_clause_results,
+            select_clause_diagnostic_infos,
+            where_clause_result,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::EventFieldList,
 };
@@ -37,3 +39,42 @@ impl BinaryEncoder<EventNotificationList> for EventNotificationList {
         })
     }
 }
+
+impl JsonEncoder for EventNotificationList {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Events", encode_json_array(&self.events, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for EventNotificationList {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let events: Option<Vec<EventFieldList>> = decode_json_array(json_field(fields, "Events"), decoding_limits)?;
+        Ok(EventNotificationList {
+            events,
+        })
+    }
+}
+
+impl XmlEncoder for EventNotificationList {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("Events", "EventFieldList", &self.events));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for EventNotificationList {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let events: Option<Vec<EventFieldList>> = decode_xml_array(xml_child(element, "Events"), decoding_limits)?;
+        Ok(EventNotificationList {
+            events,
+        })
+    }
+}
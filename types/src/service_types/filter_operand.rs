@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -37,3 +39,34 @@ impl BinaryEncoder<FilterOperand> for FilterOperand {
         })
     }
 }
+
+impl JsonEncoder for FilterOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        JsonValue::Object(JsonObject::new())
+    }
+}
+
+impl JsonDecoder for FilterOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(FilterOperand {
+        })
+    }
+}
+
+impl XmlEncoder for FilterOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        Some(XmlNode::new(name))
+    }
+}
+
+impl XmlDecoder for FilterOperand {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(FilterOperand {
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +63,60 @@ impl BinaryEncoder<FindServersOnNetworkRequest> for FindServersOnNetworkRequest
         })
     }
 }
+
+impl JsonEncoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "StartingRecordId", self.starting_record_id.encode_json(context));
+        insert_json_field(&mut fields, "MaxRecordsToReturn", self.max_records_to_return.encode_json(context));
+        insert_json_field(&mut fields, "ServerCapabilityFilter", encode_json_array(&self.server_capability_filter, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let starting_record_id = u32::decode_json(json_field(fields, "StartingRecordId"), decoding_limits)?;
+        let max_records_to_return = u32::decode_json(json_field(fields, "MaxRecordsToReturn"), decoding_limits)?;
+        let server_capability_filter: Option<Vec<UAString>> = decode_json_array(json_field(fields, "ServerCapabilityFilter"), decoding_limits)?;
+        Ok(FindServersOnNetworkRequest {
+            request_header,
+            starting_record_id,
+            max_records_to_return,
+            server_capability_filter,
+        })
+    }
+}
+
+impl XmlEncoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.starting_record_id.encode_xml("StartingRecordId"));
+        element.add_child(self.max_records_to_return.encode_xml("MaxRecordsToReturn"));
+        element.add_child(encode_xml_array("ServerCapabilityFilter", "String", &self.server_capability_filter));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for FindServersOnNetworkRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let starting_record_id = u32::decode_xml(xml_child(element, "StartingRecordId"), decoding_limits)?;
+        let max_records_to_return = u32::decode_xml(xml_child(element, "MaxRecordsToReturn"), decoding_limits)?;
+        let server_capability_filter: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "ServerCapabilityFilter"), decoding_limits)?;
+        Ok(FindServersOnNetworkRequest {
+            request_header,
+            starting_record_id,
+            max_records_to_return,
+            server_capability_filter,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<FindServersResponse> for FindServersResponse {
         })
     }
 }
+
+impl JsonEncoder for FindServersResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "Servers", encode_json_array(&self.servers, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for FindServersResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let servers: Option<Vec<ApplicationDescription>> = decode_json_array(json_field(fields, "Servers"), decoding_limits)?;
+        Ok(FindServersResponse {
+            response_header,
+            servers,
+        })
+    }
+}
+
+impl XmlEncoder for FindServersResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("Servers", "ApplicationDescription", &self.servers));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for FindServersResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let servers: Option<Vec<ApplicationDescription>> = decode_xml_array(xml_child(element, "Servers"), decoding_limits)?;
+        Ok(FindServersResponse {
+            response_header,
+            servers,
+        })
+    }
+}
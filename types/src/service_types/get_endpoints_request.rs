@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<GetEndpointsRequest> for GetEndpointsRequest {
         })
     }
 }
+
+impl JsonEncoder for GetEndpointsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "EndpointUrl", self.endpoint_url.encode_json(context));
+        insert_json_field(&mut fields, "LocaleIds", encode_json_array(&self.locale_ids, context));
+        insert_json_field(&mut fields, "ProfileUris", encode_json_array(&self.profile_uris, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for GetEndpointsRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let endpoint_url = UAString::decode_json(json_field(fields, "EndpointUrl"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_json_array(json_field(fields, "LocaleIds"), decoding_limits)?;
+        let profile_uris: Option<Vec<UAString>> = decode_json_array(json_field(fields, "ProfileUris"), decoding_limits)?;
+        Ok(GetEndpointsRequest {
+            request_header,
+            endpoint_url,
+            locale_ids,
+            profile_uris,
+        })
+    }
+}
+
+impl XmlEncoder for GetEndpointsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.endpoint_url.encode_xml("EndpointUrl"));
+        element.add_child(encode_xml_array("LocaleIds", "String", &self.locale_ids));
+        element.add_child(encode_xml_array("ProfileUris", "String", &self.profile_uris));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for GetEndpointsRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let endpoint_url = UAString::decode_xml(xml_child(element, "EndpointUrl"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "LocaleIds"), decoding_limits)?;
+        let profile_uris: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "ProfileUris"), decoding_limits)?;
+        Ok(GetEndpointsRequest {
+            request_header,
+            endpoint_url,
+            locale_ids,
+            profile_uris,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<GetEndpointsResponse> for GetEndpointsResponse {
         })
     }
 }
+
+impl JsonEncoder for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "Endpoints", encode_json_array(&self.endpoints, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let endpoints: Option<Vec<EndpointDescription>> = decode_json_array(json_field(fields, "Endpoints"), decoding_limits)?;
+        Ok(GetEndpointsResponse {
+            response_header,
+            endpoints,
+        })
+    }
+}
+
+impl XmlEncoder for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("Endpoints", "EndpointDescription", &self.endpoints));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for GetEndpointsResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let endpoints: Option<Vec<EndpointDescription>> = decode_xml_array(xml_child(element, "Endpoints"), decoding_limits)?;
+        Ok(GetEndpointsResponse {
+            response_header,
+            endpoints,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    data_value::DataValue,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryData {
+    pub data_values: Option<Vec<DataValue>>,
+}
+
+impl MessageInfo for HistoryData {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryData_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryData> for HistoryData {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += byte_len_array(&self.data_values);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += write_array(stream, &self.data_values)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let data_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryData {
+            data_values,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryData {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "DataValues", encode_json_array(&self.data_values, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryData {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let data_values: Option<Vec<DataValue>> = decode_json_array(json_field(fields, "DataValues"), decoding_limits)?;
+        Ok(HistoryData {
+            data_values,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryData {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("DataValues", "DataValue", &self.data_values));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryData {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let data_values: Option<Vec<DataValue>> = decode_xml_array(xml_child(element, "DataValues"), decoding_limits)?;
+        Ok(HistoryData {
+            data_values,
+        })
+    }
+}
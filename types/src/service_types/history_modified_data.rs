@@ -0,0 +1,92 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    data_value::DataValue,
+    service_types::ModificationInfo,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryModifiedData {
+    pub data_values: Option<Vec<DataValue>>,
+    pub modification_infos: Option<Vec<ModificationInfo>>,
+}
+
+impl BinaryEncoder<HistoryModifiedData> for HistoryModifiedData {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += byte_len_array(&self.data_values);
+        size += byte_len_array(&self.modification_infos);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += write_array(stream, &self.data_values)?;
+        size += write_array(stream, &self.modification_infos)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let data_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        let modification_infos: Option<Vec<ModificationInfo>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryModifiedData {
+            data_values,
+            modification_infos,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryModifiedData {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "DataValues", encode_json_array(&self.data_values, context));
+        insert_json_field(&mut fields, "ModificationInfos", encode_json_array(&self.modification_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryModifiedData {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let data_values: Option<Vec<DataValue>> = decode_json_array(json_field(fields, "DataValues"), decoding_limits)?;
+        let modification_infos: Option<Vec<ModificationInfo>> = decode_json_array(json_field(fields, "ModificationInfos"), decoding_limits)?;
+        Ok(HistoryModifiedData {
+            data_values,
+            modification_infos,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryModifiedData {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(encode_xml_array("DataValues", "DataValue", &self.data_values));
+        element.add_child(encode_xml_array("ModificationInfos", "ModificationInfo", &self.modification_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryModifiedData {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let data_values: Option<Vec<DataValue>> = decode_xml_array(xml_child(element, "DataValues"), decoding_limits)?;
+        let modification_infos: Option<Vec<ModificationInfo>> = decode_xml_array(xml_child(element, "ModificationInfos"), decoding_limits)?;
+        Ok(HistoryModifiedData {
+            data_values,
+            modification_infos,
+        })
+    }
+}
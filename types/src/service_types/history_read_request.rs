@@ -0,0 +1,135 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    service_types::impls::RequestHeader,
+    extension_object::ExtensionObject,
+    service_types::enums::TimestampsToReturn,
+    service_types::HistoryReadValueId,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryReadRequest {
+    pub request_header: RequestHeader,
+    pub history_read_details: ExtensionObject,
+    pub timestamps_to_return: TimestampsToReturn,
+    pub release_continuation_points: bool,
+    pub nodes_to_read: Option<Vec<HistoryReadValueId>>,
+}
+
+impl MessageInfo for HistoryReadRequest {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryReadRequest_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryReadRequest> for HistoryReadRequest {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.request_header.byte_len();
+        size += self.history_read_details.byte_len();
+        size += self.timestamps_to_return.byte_len();
+        size += self.release_continuation_points.byte_len();
+        size += byte_len_array(&self.nodes_to_read);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.request_header.encode(stream)?;
+        size += self.history_read_details.encode(stream)?;
+        size += self.timestamps_to_return.encode(stream)?;
+        size += self.release_continuation_points.encode(stream)?;
+        size += write_array(stream, &self.nodes_to_read)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode(stream, decoding_limits)?;
+        let history_read_details = ExtensionObject::decode(stream, decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode(stream, decoding_limits)?;
+        let release_continuation_points = bool::decode(stream, decoding_limits)?;
+        let nodes_to_read: Option<Vec<HistoryReadValueId>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryReadRequest {
+            request_header,
+            history_read_details,
+            timestamps_to_return,
+            release_continuation_points,
+            nodes_to_read,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryReadRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "HistoryReadDetails", self.history_read_details.encode_json(context));
+        insert_json_field(&mut fields, "TimestampsToReturn", self.timestamps_to_return.encode_json(context));
+        insert_json_field(&mut fields, "ReleaseContinuationPoints", self.release_continuation_points.encode_json(context));
+        insert_json_field(&mut fields, "NodesToRead", encode_json_array(&self.nodes_to_read, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryReadRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let history_read_details = ExtensionObject::decode_json(json_field(fields, "HistoryReadDetails"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_json(json_field(fields, "TimestampsToReturn"), decoding_limits)?;
+        let release_continuation_points = bool::decode_json(json_field(fields, "ReleaseContinuationPoints"), decoding_limits)?;
+        let nodes_to_read: Option<Vec<HistoryReadValueId>> = decode_json_array(json_field(fields, "NodesToRead"), decoding_limits)?;
+        Ok(HistoryReadRequest {
+            request_header,
+            history_read_details,
+            timestamps_to_return,
+            release_continuation_points,
+            nodes_to_read,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryReadRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.history_read_details.encode_xml("HistoryReadDetails"));
+        element.add_child(self.timestamps_to_return.encode_xml("TimestampsToReturn"));
+        element.add_child(self.release_continuation_points.encode_xml("ReleaseContinuationPoints"));
+        element.add_child(encode_xml_array("NodesToRead", "HistoryReadValueId", &self.nodes_to_read));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryReadRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let history_read_details = ExtensionObject::decode_xml(xml_child(element, "HistoryReadDetails"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_xml(xml_child(element, "TimestampsToReturn"), decoding_limits)?;
+        let release_continuation_points = bool::decode_xml(xml_child(element, "ReleaseContinuationPoints"), decoding_limits)?;
+        let nodes_to_read: Option<Vec<HistoryReadValueId>> = decode_xml_array(xml_child(element, "NodesToRead"), decoding_limits)?;
+        Ok(HistoryReadRequest {
+            request_header,
+            history_read_details,
+            timestamps_to_return,
+            release_continuation_points,
+            nodes_to_read,
+        })
+    }
+}
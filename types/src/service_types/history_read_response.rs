@@ -0,0 +1,112 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    service_types::impls::ResponseHeader,
+    diagnostic_info::DiagnosticInfo,
+    service_types::HistoryReadResult,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryReadResponse {
+    pub response_header: ResponseHeader,
+    pub results: Option<Vec<HistoryReadResult>>,
+    pub diagnostic_infos: Option<Vec<DiagnosticInfo>>,
+}
+
+impl MessageInfo for HistoryReadResponse {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryReadResponse_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryReadResponse> for HistoryReadResponse {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.response_header.byte_len();
+        size += byte_len_array(&self.results);
+        size += byte_len_array(&self.diagnostic_infos);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.response_header.encode(stream)?;
+        size += write_array(stream, &self.results)?;
+        size += write_array(stream, &self.diagnostic_infos)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode(stream, decoding_limits)?;
+        let results: Option<Vec<HistoryReadResult>> = read_array(stream, decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryReadResponse {
+            response_header,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryReadResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "Results", encode_json_array(&self.results, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryReadResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let results: Option<Vec<HistoryReadResult>> = decode_json_array(json_field(fields, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        Ok(HistoryReadResponse {
+            response_header,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryReadResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("Results", "HistoryReadResult", &self.results));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryReadResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let results: Option<Vec<HistoryReadResult>> = decode_xml_array(xml_child(element, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        Ok(HistoryReadResponse {
+            response_header,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
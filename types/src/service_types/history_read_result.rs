@@ -0,0 +1,112 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    status_codes::StatusCode,
+    byte_string::ByteString,
+    extension_object::ExtensionObject,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryReadResult {
+    pub status_code: StatusCode,
+    pub continuation_point: ByteString,
+    pub history_data: ExtensionObject,
+}
+
+impl MessageInfo for HistoryReadResult {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryReadResult_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryReadResult> for HistoryReadResult {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.status_code.byte_len();
+        size += self.continuation_point.byte_len();
+        size += self.history_data.byte_len();
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.status_code.encode(stream)?;
+        size += self.continuation_point.encode(stream)?;
+        size += self.history_data.encode(stream)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode(stream, decoding_limits)?;
+        let continuation_point = ByteString::decode(stream, decoding_limits)?;
+        let history_data = ExtensionObject::decode(stream, decoding_limits)?;
+        Ok(HistoryReadResult {
+            status_code,
+            continuation_point,
+            history_data,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "ContinuationPoint", self.continuation_point.encode_json(context));
+        insert_json_field(&mut fields, "HistoryData", self.history_data.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let continuation_point = ByteString::decode_json(json_field(fields, "ContinuationPoint"), decoding_limits)?;
+        let history_data = ExtensionObject::decode_json(json_field(fields, "HistoryData"), decoding_limits)?;
+        Ok(HistoryReadResult {
+            status_code,
+            continuation_point,
+            history_data,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(self.continuation_point.encode_xml("ContinuationPoint"));
+        element.add_child(self.history_data.encode_xml("HistoryData"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryReadResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let continuation_point = ByteString::decode_xml(xml_child(element, "ContinuationPoint"), decoding_limits)?;
+        let history_data = ExtensionObject::decode_xml(xml_child(element, "HistoryData"), decoding_limits)?;
+        Ok(HistoryReadResult {
+            status_code,
+            continuation_point,
+            history_data,
+        })
+    }
+}
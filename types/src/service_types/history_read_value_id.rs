@@ -0,0 +1,124 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    node_id::NodeId,
+    string::UAString,
+    basic_types::QualifiedName,
+    byte_string::ByteString,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryReadValueId {
+    pub node_id: NodeId,
+    pub index_range: UAString,
+    pub data_encoding: QualifiedName,
+    pub continuation_point: ByteString,
+}
+
+impl MessageInfo for HistoryReadValueId {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryReadValueId_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryReadValueId> for HistoryReadValueId {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.node_id.byte_len();
+        size += self.index_range.byte_len();
+        size += self.data_encoding.byte_len();
+        size += self.continuation_point.byte_len();
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.node_id.encode(stream)?;
+        size += self.index_range.encode(stream)?;
+        size += self.data_encoding.encode(stream)?;
+        size += self.continuation_point.encode(stream)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode(stream, decoding_limits)?;
+        let index_range = UAString::decode(stream, decoding_limits)?;
+        let data_encoding = QualifiedName::decode(stream, decoding_limits)?;
+        let continuation_point = ByteString::decode(stream, decoding_limits)?;
+        Ok(HistoryReadValueId {
+            node_id,
+            index_range,
+            data_encoding,
+            continuation_point,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        insert_json_field(&mut fields, "DataEncoding", self.data_encoding.encode_json(context));
+        insert_json_field(&mut fields, "ContinuationPoint", self.continuation_point.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        let data_encoding = QualifiedName::decode_json(json_field(fields, "DataEncoding"), decoding_limits)?;
+        let continuation_point = ByteString::decode_json(json_field(fields, "ContinuationPoint"), decoding_limits)?;
+        Ok(HistoryReadValueId {
+            node_id,
+            index_range,
+            data_encoding,
+            continuation_point,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        element.add_child(self.data_encoding.encode_xml("DataEncoding"));
+        element.add_child(self.continuation_point.encode_xml("ContinuationPoint"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryReadValueId {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        let data_encoding = QualifiedName::decode_xml(xml_child(element, "DataEncoding"), decoding_limits)?;
+        let continuation_point = ByteString::decode_xml(xml_child(element, "ContinuationPoint"), decoding_limits)?;
+        Ok(HistoryReadValueId {
+            node_id,
+            index_range,
+            data_encoding,
+            continuation_point,
+        })
+    }
+}
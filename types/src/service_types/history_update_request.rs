@@ -0,0 +1,100 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    service_types::impls::RequestHeader,
+    extension_object::ExtensionObject,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryUpdateRequest {
+    pub request_header: RequestHeader,
+    pub history_update_details: Option<Vec<ExtensionObject>>,
+}
+
+impl MessageInfo for HistoryUpdateRequest {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryUpdateRequest> for HistoryUpdateRequest {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.request_header.byte_len();
+        size += byte_len_array(&self.history_update_details);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.request_header.encode(stream)?;
+        size += write_array(stream, &self.history_update_details)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode(stream, decoding_limits)?;
+        let history_update_details: Option<Vec<ExtensionObject>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryUpdateRequest {
+            request_header,
+            history_update_details,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryUpdateRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "HistoryUpdateDetails", encode_json_array(&self.history_update_details, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryUpdateRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let history_update_details: Option<Vec<ExtensionObject>> = decode_json_array(json_field(fields, "HistoryUpdateDetails"), decoding_limits)?;
+        Ok(HistoryUpdateRequest {
+            request_header,
+            history_update_details,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryUpdateRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("HistoryUpdateDetails", "ExtensionObject", &self.history_update_details));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryUpdateRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let history_update_details: Option<Vec<ExtensionObject>> = decode_xml_array(xml_child(element, "HistoryUpdateDetails"), decoding_limits)?;
+        Ok(HistoryUpdateRequest {
+            request_header,
+            history_update_details,
+        })
+    }
+}
@@ -0,0 +1,111 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    status_codes::StatusCode,
+    diagnostic_info::DiagnosticInfo,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryUpdateResult {
+    pub status_code: StatusCode,
+    pub operation_results: Option<Vec<StatusCode>>,
+    pub diagnostic_infos: Option<Vec<DiagnosticInfo>>,
+}
+
+impl MessageInfo for HistoryUpdateResult {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::HistoryUpdateResult_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<HistoryUpdateResult> for HistoryUpdateResult {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.status_code.byte_len();
+        size += byte_len_array(&self.operation_results);
+        size += byte_len_array(&self.diagnostic_infos);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.status_code.encode(stream)?;
+        size += write_array(stream, &self.operation_results)?;
+        size += write_array(stream, &self.diagnostic_infos)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode(stream, decoding_limits)?;
+        let operation_results: Option<Vec<StatusCode>> = read_array(stream, decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = read_array(stream, decoding_limits)?;
+        Ok(HistoryUpdateResult {
+            status_code,
+            operation_results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl JsonEncoder for HistoryUpdateResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "OperationResults", encode_json_array(&self.operation_results, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for HistoryUpdateResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let operation_results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "OperationResults"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        Ok(HistoryUpdateResult {
+            status_code,
+            operation_results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for HistoryUpdateResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("OperationResults", "StatusCode", &self.operation_results));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for HistoryUpdateResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let operation_results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "OperationResults"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        Ok(HistoryUpdateResult {
+            status_code,
+            operation_results,
+            diagnostic_infos,
+        })
+    }
+}
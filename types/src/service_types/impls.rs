@@ -10,6 +10,8 @@ use crate::{
     data_value::DataValue,
     date_time::DateTime,
     encoding::*,
+    json::*,
+    xml::*,
     node_id::NodeId,
     node_ids::ObjectId,
     diagnostic_info::{DiagnosticBits, DiagnosticInfo},
@@ -163,6 +165,63 @@ impl BinaryEncoder<RequestHeader> for RequestHeader {
     }
 }
 
+impl JsonEncoder for RequestHeader {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "AuthenticationToken", self.authentication_token.encode_json(context));
+        insert_json_field(&mut fields, "Timestamp", self.timestamp.encode_json(context));
+        insert_json_field(&mut fields, "RequestHandle", self.request_handle.encode_json(context));
+        insert_json_field(&mut fields, "ReturnDiagnostics", self.return_diagnostics.bits().encode_json(context));
+        insert_json_field(&mut fields, "AuditEntryId", self.audit_entry_id.encode_json(context));
+        insert_json_field(&mut fields, "TimeoutHint", self.timeout_hint.encode_json(context));
+        insert_json_field(&mut fields, "AdditionalHeader", self.additional_header.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RequestHeader {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(RequestHeader {
+            authentication_token: NodeId::decode_json(json_field(fields, "AuthenticationToken"), decoding_limits)?,
+            timestamp: UtcTime::decode_json(json_field(fields, "Timestamp"), decoding_limits)?,
+            request_handle: IntegerId::decode_json(json_field(fields, "RequestHandle"), decoding_limits)?,
+            return_diagnostics: DiagnosticBits::from_bits_truncate(u32::decode_json(json_field(fields, "ReturnDiagnostics"), decoding_limits)?),
+            audit_entry_id: UAString::decode_json(json_field(fields, "AuditEntryId"), decoding_limits)?,
+            timeout_hint: u32::decode_json(json_field(fields, "TimeoutHint"), decoding_limits)?,
+            additional_header: ExtensionObject::decode_json(json_field(fields, "AdditionalHeader"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for RequestHeader {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.authentication_token.encode_xml("AuthenticationToken"));
+        element.add_child(self.timestamp.encode_xml("Timestamp"));
+        element.add_child(self.request_handle.encode_xml("RequestHandle"));
+        element.add_child(self.return_diagnostics.bits().encode_xml("ReturnDiagnostics"));
+        element.add_child(self.audit_entry_id.encode_xml("AuditEntryId"));
+        element.add_child(self.timeout_hint.encode_xml("TimeoutHint"));
+        element.add_child(self.additional_header.encode_xml("AdditionalHeader"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RequestHeader {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(RequestHeader {
+            authentication_token: NodeId::decode_xml(xml_child(element, "AuthenticationToken"), decoding_limits)?,
+            timestamp: UtcTime::decode_xml(xml_child(element, "Timestamp"), decoding_limits)?,
+            request_handle: IntegerId::decode_xml(xml_child(element, "RequestHandle"), decoding_limits)?,
+            return_diagnostics: DiagnosticBits::from_bits_truncate(u32::decode_xml(xml_child(element, "ReturnDiagnostics"), decoding_limits)?),
+            audit_entry_id: UAString::decode_xml(xml_child(element, "AuditEntryId"), decoding_limits)?,
+            timeout_hint: u32::decode_xml(xml_child(element, "TimeoutHint"), decoding_limits)?,
+            additional_header: ExtensionObject::decode_xml(xml_child(element, "AdditionalHeader"), decoding_limits)?,
+        })
+    }
+}
+
 impl RequestHeader {
     pub fn new(authentication_token: &NodeId, timestamp: &DateTime, request_handle: IntegerId) -> RequestHeader {
         RequestHeader {
@@ -230,6 +289,59 @@ impl BinaryEncoder<ResponseHeader> for ResponseHeader {
     }
 }
 
+impl JsonEncoder for ResponseHeader {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Timestamp", self.timestamp.encode_json(context));
+        insert_json_field(&mut fields, "RequestHandle", self.request_handle.encode_json(context));
+        insert_json_field(&mut fields, "ServiceResult", self.service_result.encode_json(context));
+        insert_json_field(&mut fields, "ServiceDiagnostics", self.service_diagnostics.encode_json(context));
+        insert_json_field(&mut fields, "StringTable", encode_json_array(&self.string_table, context));
+        insert_json_field(&mut fields, "AdditionalHeader", self.additional_header.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ResponseHeader {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        Ok(ResponseHeader {
+            timestamp: UtcTime::decode_json(json_field(fields, "Timestamp"), decoding_limits)?,
+            request_handle: IntegerId::decode_json(json_field(fields, "RequestHandle"), decoding_limits)?,
+            service_result: StatusCode::decode_json(json_field(fields, "ServiceResult"), decoding_limits)?,
+            service_diagnostics: DiagnosticInfo::decode_json(json_field(fields, "ServiceDiagnostics"), decoding_limits)?,
+            string_table: decode_json_array(json_field(fields, "StringTable"), decoding_limits)?,
+            additional_header: ExtensionObject::decode_json(json_field(fields, "AdditionalHeader"), decoding_limits)?,
+        })
+    }
+}
+
+impl XmlEncoder for ResponseHeader {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.timestamp.encode_xml("Timestamp"));
+        element.add_child(self.request_handle.encode_xml("RequestHandle"));
+        element.add_child(self.service_result.encode_xml("ServiceResult"));
+        element.add_child(self.service_diagnostics.encode_xml("ServiceDiagnostics"));
+        element.add_child(encode_xml_array("StringTable", "String", &self.string_table));
+        element.add_child(self.additional_header.encode_xml("AdditionalHeader"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ResponseHeader {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(ResponseHeader {
+            timestamp: UtcTime::decode_xml(xml_child(element, "Timestamp"), decoding_limits)?,
+            request_handle: IntegerId::decode_xml(xml_child(element, "RequestHandle"), decoding_limits)?,
+            service_result: StatusCode::decode_xml(xml_child(element, "ServiceResult"), decoding_limits)?,
+            service_diagnostics: DiagnosticInfo::decode_xml(xml_child(element, "ServiceDiagnostics"), decoding_limits)?,
+            string_table: decode_xml_array(xml_child(element, "StringTable"), decoding_limits)?,
+            additional_header: ExtensionObject::decode_xml(xml_child(element, "AdditionalHeader"), decoding_limits)?,
+        })
+    }
+}
+
 impl ResponseHeader {
     pub fn new_good(request_header: &RequestHeader) -> ResponseHeader {
         ResponseHeader::new_service_result(request_header, StatusCode::Good)
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     string::UAString,
     byte_string::ByteString,
@@ -49,3 +51,54 @@ impl BinaryEncoder<IssuedIdentityToken> for IssuedIdentityToken {
         })
     }
 }
+
+impl JsonEncoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "PolicyId", self.policy_id.encode_json(context));
+        insert_json_field(&mut fields, "TokenData", self.token_data.encode_json(context));
+        insert_json_field(&mut fields, "EncryptionAlgorithm", self.encryption_algorithm.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let policy_id = UAString::decode_json(json_field(fields, "PolicyId"), decoding_limits)?;
+        let token_data = ByteString::decode_json(json_field(fields, "TokenData"), decoding_limits)?;
+        let encryption_algorithm = UAString::decode_json(json_field(fields, "EncryptionAlgorithm"), decoding_limits)?;
+        Ok(IssuedIdentityToken {
+            policy_id,
+            token_data,
+            encryption_algorithm,
+        })
+    }
+}
+
+impl XmlEncoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.policy_id.encode_xml("PolicyId"));
+        element.add_child(self.token_data.encode_xml("TokenData"));
+        element.add_child(self.encryption_algorithm.encode_xml("EncryptionAlgorithm"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for IssuedIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let policy_id = UAString::decode_xml(xml_child(element, "PolicyId"), decoding_limits)?;
+        let token_data = ByteString::decode_xml(xml_child(element, "TokenData"), decoding_limits)?;
+        let encryption_algorithm = UAString::decode_xml(xml_child(element, "EncryptionAlgorithm"), decoding_limits)?;
+        Ok(IssuedIdentityToken {
+            policy_id,
+            token_data,
+            encryption_algorithm,
+        })
+    }
+}
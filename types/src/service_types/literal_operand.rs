@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     variant::Variant,
 };
@@ -37,3 +39,42 @@ impl BinaryEncoder<LiteralOperand> for LiteralOperand {
         })
     }
 }
+
+impl JsonEncoder for LiteralOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for LiteralOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let value = Variant::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        Ok(LiteralOperand {
+            value,
+        })
+    }
+}
+
+impl XmlEncoder for LiteralOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.value.encode_xml("Value"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for LiteralOperand {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = Variant::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        Ok(LiteralOperand {
+            value,
+        })
+    }
+}
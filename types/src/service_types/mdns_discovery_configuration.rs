@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     string::UAString,
 };
@@ -43,3 +45,48 @@ impl BinaryEncoder<MdnsDiscoveryConfiguration> for MdnsDiscoveryConfiguration {
         })
     }
 }
+
+impl JsonEncoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "MdnsServerName", self.mdns_server_name.encode_json(context));
+        insert_json_field(&mut fields, "ServerCapabilities", encode_json_array(&self.server_capabilities, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let mdns_server_name = UAString::decode_json(json_field(fields, "MdnsServerName"), decoding_limits)?;
+        let server_capabilities: Option<Vec<UAString>> = decode_json_array(json_field(fields, "ServerCapabilities"), decoding_limits)?;
+        Ok(MdnsDiscoveryConfiguration {
+            mdns_server_name,
+            server_capabilities,
+        })
+    }
+}
+
+impl XmlEncoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.mdns_server_name.encode_xml("MdnsServerName"));
+        element.add_child(encode_xml_array("ServerCapabilities", "String", &self.server_capabilities));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MdnsDiscoveryConfiguration {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let mdns_server_name = UAString::decode_xml(xml_child(element, "MdnsServerName"), decoding_limits)?;
+        let server_capabilities: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "ServerCapabilities"), decoding_limits)?;
+        Ok(MdnsDiscoveryConfiguration {
+            mdns_server_name,
+            server_capabilities,
+        })
+    }
+}
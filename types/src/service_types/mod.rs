@@ -1,6 +1,14 @@
 // This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
 // DO NOT EDIT THIS FILE
 
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    extension_object::ExtensionObject,
+    node_ids::{DataTypeId, ObjectId},
+};
+
 // The mods below are handwritten
 mod enums;
 mod impls;
@@ -114,14 +122,26 @@ mod query_next_response;
 mod read_value_id;
 mod read_request;
 mod read_response;
+mod history_read_value_id;
+mod history_read_result;
 mod read_event_details;
 mod read_raw_modified_details;
+mod read_processed_details;
+mod history_data;
+mod modification_info;
+mod history_modified_data;
+mod history_read_request;
+mod history_read_response;
 mod write_value;
 mod write_request;
 mod write_response;
+mod update_data_details;
 mod delete_raw_modified_details;
 mod delete_at_time_details;
 mod delete_event_details;
+mod history_update_result;
+mod history_update_request;
+mod history_update_response;
 mod call_method_request;
 mod call_method_result;
 mod call_request;
@@ -295,14 +315,26 @@ pub use self::query_next_response::*;
 pub use self::read_value_id::*;
 pub use self::read_request::*;
 pub use self::read_response::*;
+pub use self::history_read_value_id::*;
+pub use self::history_read_result::*;
 pub use self::read_event_details::*;
 pub use self::read_raw_modified_details::*;
+pub use self::read_processed_details::*;
+pub use self::history_data::*;
+pub use self::modification_info::*;
+pub use self::history_modified_data::*;
+pub use self::history_read_request::*;
+pub use self::history_read_response::*;
 pub use self::write_value::*;
 pub use self::write_request::*;
 pub use self::write_response::*;
+pub use self::update_data_details::*;
 pub use self::delete_raw_modified_details::*;
 pub use self::delete_at_time_details::*;
 pub use self::delete_event_details::*;
+pub use self::history_update_result::*;
+pub use self::history_update_request::*;
+pub use self::history_update_response::*;
 pub use self::call_method_request::*;
 pub use self::call_method_result::*;
 pub use self::call_request::*;
@@ -371,3 +403,674 @@ pub use self::double_complex_number_type::*;
 pub use self::xv_type::*;
 pub use self::program_diagnostic_data_type::*;
 pub use self::annotation::*;
+
+/// Decodes the binary body of an extension object of a generated type and encodes it as JSON.
+/// The data type of the body is returned with it. Returns `None` if the object id is not the
+/// binary encoding of a generated type.
+pub(crate) fn binary_body_to_json(object_id: ObjectId, extension_object: &ExtensionObject, decoding_limits: &DecodingLimits, context: &JsonContext) -> Option<EncodingResult<(DataTypeId, JsonValue)>> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TrustListDataType>(decoding_limits).map(|v| (DataTypeId::TrustListDataType, v.encode_json(context)))),
+        ObjectId::Argument_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Argument>(decoding_limits).map(|v| (DataTypeId::Argument, v.encode_json(context)))),
+        ObjectId::EnumValueType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EnumValueType>(decoding_limits).map(|v| (DataTypeId::EnumValueType, v.encode_json(context)))),
+        ObjectId::OptionSet_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OptionSet>(decoding_limits).map(|v| (DataTypeId::OptionSet, v.encode_json(context)))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TimeZoneDataType>(decoding_limits).map(|v| (DataTypeId::TimeZoneDataType, v.encode_json(context)))),
+        ObjectId::ApplicationDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ApplicationDescription>(decoding_limits).map(|v| (DataTypeId::ApplicationDescription, v.encode_json(context)))),
+        ObjectId::ServiceFault_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServiceFault>(decoding_limits).map(|v| (DataTypeId::ServiceFault, v.encode_json(context)))),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersRequest>(decoding_limits).map(|v| (DataTypeId::FindServersRequest, v.encode_json(context)))),
+        ObjectId::FindServersResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersResponse>(decoding_limits).map(|v| (DataTypeId::FindServersResponse, v.encode_json(context)))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServerOnNetwork>(decoding_limits).map(|v| (DataTypeId::ServerOnNetwork, v.encode_json(context)))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersOnNetworkRequest>(decoding_limits).map(|v| (DataTypeId::FindServersOnNetworkRequest, v.encode_json(context)))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersOnNetworkResponse>(decoding_limits).map(|v| (DataTypeId::FindServersOnNetworkResponse, v.encode_json(context)))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UserTokenPolicy>(decoding_limits).map(|v| (DataTypeId::UserTokenPolicy, v.encode_json(context)))),
+        ObjectId::EndpointDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointDescription>(decoding_limits).map(|v| (DataTypeId::EndpointDescription, v.encode_json(context)))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<GetEndpointsRequest>(decoding_limits).map(|v| (DataTypeId::GetEndpointsRequest, v.encode_json(context)))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<GetEndpointsResponse>(decoding_limits).map(|v| (DataTypeId::GetEndpointsResponse, v.encode_json(context)))),
+        ObjectId::RegisteredServer_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisteredServer>(decoding_limits).map(|v| (DataTypeId::RegisteredServer, v.encode_json(context)))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterServerRequest>(decoding_limits).map(|v| (DataTypeId::RegisterServerRequest, v.encode_json(context)))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterServerResponse>(decoding_limits).map(|v| (DataTypeId::RegisterServerResponse, v.encode_json(context)))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DiscoveryConfiguration>(decoding_limits).map(|v| (DataTypeId::DiscoveryConfiguration, v.encode_json(context)))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ChannelSecurityToken>(decoding_limits).map(|v| (DataTypeId::ChannelSecurityToken, v.encode_json(context)))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OpenSecureChannelRequest>(decoding_limits).map(|v| (DataTypeId::OpenSecureChannelRequest, v.encode_json(context)))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OpenSecureChannelResponse>(decoding_limits).map(|v| (DataTypeId::OpenSecureChannelResponse, v.encode_json(context)))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSecureChannelRequest>(decoding_limits).map(|v| (DataTypeId::CloseSecureChannelRequest, v.encode_json(context)))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSecureChannelResponse>(decoding_limits).map(|v| (DataTypeId::CloseSecureChannelResponse, v.encode_json(context)))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SignedSoftwareCertificate>(decoding_limits).map(|v| (DataTypeId::SignedSoftwareCertificate, v.encode_json(context)))),
+        ObjectId::SignatureData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SignatureData>(decoding_limits).map(|v| (DataTypeId::SignatureData, v.encode_json(context)))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSessionRequest>(decoding_limits).map(|v| (DataTypeId::CreateSessionRequest, v.encode_json(context)))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSessionResponse>(decoding_limits).map(|v| (DataTypeId::CreateSessionResponse, v.encode_json(context)))),
+        ObjectId::UserIdentityToken_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UserIdentityToken>(decoding_limits).map(|v| (DataTypeId::UserIdentityToken, v.encode_json(context)))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ActivateSessionRequest>(decoding_limits).map(|v| (DataTypeId::ActivateSessionRequest, v.encode_json(context)))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ActivateSessionResponse>(decoding_limits).map(|v| (DataTypeId::ActivateSessionResponse, v.encode_json(context)))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSessionRequest>(decoding_limits).map(|v| (DataTypeId::CloseSessionRequest, v.encode_json(context)))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSessionResponse>(decoding_limits).map(|v| (DataTypeId::CloseSessionResponse, v.encode_json(context)))),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CancelRequest>(decoding_limits).map(|v| (DataTypeId::CancelRequest, v.encode_json(context)))),
+        ObjectId::CancelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CancelResponse>(decoding_limits).map(|v| (DataTypeId::CancelResponse, v.encode_json(context)))),
+        ObjectId::NodeAttributes_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeAttributes>(decoding_limits).map(|v| (DataTypeId::NodeAttributes, v.encode_json(context)))),
+        ObjectId::AddNodesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesItem>(decoding_limits).map(|v| (DataTypeId::AddNodesItem, v.encode_json(context)))),
+        ObjectId::AddNodesResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesResult>(decoding_limits).map(|v| (DataTypeId::AddNodesResult, v.encode_json(context)))),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesRequest>(decoding_limits).map(|v| (DataTypeId::AddNodesRequest, v.encode_json(context)))),
+        ObjectId::AddNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesResponse>(decoding_limits).map(|v| (DataTypeId::AddNodesResponse, v.encode_json(context)))),
+        ObjectId::AddReferencesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesItem>(decoding_limits).map(|v| (DataTypeId::AddReferencesItem, v.encode_json(context)))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesRequest>(decoding_limits).map(|v| (DataTypeId::AddReferencesRequest, v.encode_json(context)))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesResponse>(decoding_limits).map(|v| (DataTypeId::AddReferencesResponse, v.encode_json(context)))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesItem>(decoding_limits).map(|v| (DataTypeId::DeleteNodesItem, v.encode_json(context)))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesRequest>(decoding_limits).map(|v| (DataTypeId::DeleteNodesRequest, v.encode_json(context)))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesResponse>(decoding_limits).map(|v| (DataTypeId::DeleteNodesResponse, v.encode_json(context)))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesItem>(decoding_limits).map(|v| (DataTypeId::DeleteReferencesItem, v.encode_json(context)))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesRequest>(decoding_limits).map(|v| (DataTypeId::DeleteReferencesRequest, v.encode_json(context)))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesResponse>(decoding_limits).map(|v| (DataTypeId::DeleteReferencesResponse, v.encode_json(context)))),
+        ObjectId::ViewDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ViewDescription>(decoding_limits).map(|v| (DataTypeId::ViewDescription, v.encode_json(context)))),
+        ObjectId::BrowseDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseDescription>(decoding_limits).map(|v| (DataTypeId::BrowseDescription, v.encode_json(context)))),
+        ObjectId::ReferenceDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReferenceDescription>(decoding_limits).map(|v| (DataTypeId::ReferenceDescription, v.encode_json(context)))),
+        ObjectId::BrowseResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseResult>(decoding_limits).map(|v| (DataTypeId::BrowseResult, v.encode_json(context)))),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseRequest>(decoding_limits).map(|v| (DataTypeId::BrowseRequest, v.encode_json(context)))),
+        ObjectId::BrowseResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseResponse>(decoding_limits).map(|v| (DataTypeId::BrowseResponse, v.encode_json(context)))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseNextRequest>(decoding_limits).map(|v| (DataTypeId::BrowseNextRequest, v.encode_json(context)))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseNextResponse>(decoding_limits).map(|v| (DataTypeId::BrowseNextResponse, v.encode_json(context)))),
+        ObjectId::RelativePathElement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RelativePathElement>(decoding_limits).map(|v| (DataTypeId::RelativePathElement, v.encode_json(context)))),
+        ObjectId::RelativePath_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RelativePath>(decoding_limits).map(|v| (DataTypeId::RelativePath, v.encode_json(context)))),
+        ObjectId::BrowsePath_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePath>(decoding_limits).map(|v| (DataTypeId::BrowsePath, v.encode_json(context)))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePathTarget>(decoding_limits).map(|v| (DataTypeId::BrowsePathTarget, v.encode_json(context)))),
+        ObjectId::BrowsePathResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePathResult>(decoding_limits).map(|v| (DataTypeId::BrowsePathResult, v.encode_json(context)))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TranslateBrowsePathsToNodeIdsRequest>(decoding_limits).map(|v| (DataTypeId::TranslateBrowsePathsToNodeIdsRequest, v.encode_json(context)))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TranslateBrowsePathsToNodeIdsResponse>(decoding_limits).map(|v| (DataTypeId::TranslateBrowsePathsToNodeIdsResponse, v.encode_json(context)))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterNodesRequest>(decoding_limits).map(|v| (DataTypeId::RegisterNodesRequest, v.encode_json(context)))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterNodesResponse>(decoding_limits).map(|v| (DataTypeId::RegisterNodesResponse, v.encode_json(context)))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UnregisterNodesRequest>(decoding_limits).map(|v| (DataTypeId::UnregisterNodesRequest, v.encode_json(context)))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UnregisterNodesResponse>(decoding_limits).map(|v| (DataTypeId::UnregisterNodesResponse, v.encode_json(context)))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointConfiguration>(decoding_limits).map(|v| (DataTypeId::EndpointConfiguration, v.encode_json(context)))),
+        ObjectId::QueryDataDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryDataDescription>(decoding_limits).map(|v| (DataTypeId::QueryDataDescription, v.encode_json(context)))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeTypeDescription>(decoding_limits).map(|v| (DataTypeId::NodeTypeDescription, v.encode_json(context)))),
+        ObjectId::QueryDataSet_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryDataSet>(decoding_limits).map(|v| (DataTypeId::QueryDataSet, v.encode_json(context)))),
+        ObjectId::NodeReference_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeReference>(decoding_limits).map(|v| (DataTypeId::NodeReference, v.encode_json(context)))),
+        ObjectId::ContentFilterElement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterElement>(decoding_limits).map(|v| (DataTypeId::ContentFilterElement, v.encode_json(context)))),
+        ObjectId::ContentFilter_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilter>(decoding_limits).map(|v| (DataTypeId::ContentFilter, v.encode_json(context)))),
+        ObjectId::FilterOperand_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FilterOperand>(decoding_limits).map(|v| (DataTypeId::FilterOperand, v.encode_json(context)))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterElementResult>(decoding_limits).map(|v| (DataTypeId::ContentFilterElementResult, v.encode_json(context)))),
+        ObjectId::ContentFilterResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterResult>(decoding_limits).map(|v| (DataTypeId::ContentFilterResult, v.encode_json(context)))),
+        ObjectId::ParsingResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ParsingResult>(decoding_limits).map(|v| (DataTypeId::ParsingResult, v.encode_json(context)))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryFirstRequest>(decoding_limits).map(|v| (DataTypeId::QueryFirstRequest, v.encode_json(context)))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryFirstResponse>(decoding_limits).map(|v| (DataTypeId::QueryFirstResponse, v.encode_json(context)))),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryNextRequest>(decoding_limits).map(|v| (DataTypeId::QueryNextRequest, v.encode_json(context)))),
+        ObjectId::QueryNextResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryNextResponse>(decoding_limits).map(|v| (DataTypeId::QueryNextResponse, v.encode_json(context)))),
+        ObjectId::ReadValueId_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadValueId>(decoding_limits).map(|v| (DataTypeId::ReadValueId, v.encode_json(context)))),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadRequest>(decoding_limits).map(|v| (DataTypeId::ReadRequest, v.encode_json(context)))),
+        ObjectId::ReadResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadResponse>(decoding_limits).map(|v| (DataTypeId::ReadResponse, v.encode_json(context)))),
+        ObjectId::HistoryReadValueId_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadValueId>(decoding_limits).map(|v| (DataTypeId::HistoryReadValueId, v.encode_json(context)))),
+        ObjectId::HistoryReadResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadResult>(decoding_limits).map(|v| (DataTypeId::HistoryReadResult, v.encode_json(context)))),
+        ObjectId::HistoryData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryData>(decoding_limits).map(|v| (DataTypeId::HistoryData, v.encode_json(context)))),
+        ObjectId::ModificationInfo_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModificationInfo>(decoding_limits).map(|v| (DataTypeId::ModificationInfo, v.encode_json(context)))),
+        ObjectId::HistoryReadRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadRequest>(decoding_limits).map(|v| (DataTypeId::HistoryReadRequest, v.encode_json(context)))),
+        ObjectId::HistoryReadResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadResponse>(decoding_limits).map(|v| (DataTypeId::HistoryReadResponse, v.encode_json(context)))),
+        ObjectId::WriteValue_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteValue>(decoding_limits).map(|v| (DataTypeId::WriteValue, v.encode_json(context)))),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteRequest>(decoding_limits).map(|v| (DataTypeId::WriteRequest, v.encode_json(context)))),
+        ObjectId::WriteResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteResponse>(decoding_limits).map(|v| (DataTypeId::WriteResponse, v.encode_json(context)))),
+        ObjectId::HistoryUpdateResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateResult>(decoding_limits).map(|v| (DataTypeId::HistoryUpdateResult, v.encode_json(context)))),
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateRequest>(decoding_limits).map(|v| (DataTypeId::HistoryUpdateRequest, v.encode_json(context)))),
+        ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateResponse>(decoding_limits).map(|v| (DataTypeId::HistoryUpdateResponse, v.encode_json(context)))),
+        ObjectId::CallMethodRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallMethodRequest>(decoding_limits).map(|v| (DataTypeId::CallMethodRequest, v.encode_json(context)))),
+        ObjectId::CallMethodResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallMethodResult>(decoding_limits).map(|v| (DataTypeId::CallMethodResult, v.encode_json(context)))),
+        ObjectId::CallRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallRequest>(decoding_limits).map(|v| (DataTypeId::CallRequest, v.encode_json(context)))),
+        ObjectId::CallResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallResponse>(decoding_limits).map(|v| (DataTypeId::CallResponse, v.encode_json(context)))),
+        ObjectId::MonitoringFilter_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringFilter>(decoding_limits).map(|v| (DataTypeId::MonitoringFilter, v.encode_json(context)))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AggregateConfiguration>(decoding_limits).map(|v| (DataTypeId::AggregateConfiguration, v.encode_json(context)))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringFilterResult>(decoding_limits).map(|v| (DataTypeId::MonitoringFilterResult, v.encode_json(context)))),
+        ObjectId::MonitoringParameters_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringParameters>(decoding_limits).map(|v| (DataTypeId::MonitoringParameters, v.encode_json(context)))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemCreateRequest>(decoding_limits).map(|v| (DataTypeId::MonitoredItemCreateRequest, v.encode_json(context)))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemCreateResult>(decoding_limits).map(|v| (DataTypeId::MonitoredItemCreateResult, v.encode_json(context)))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateMonitoredItemsRequest>(decoding_limits).map(|v| (DataTypeId::CreateMonitoredItemsRequest, v.encode_json(context)))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateMonitoredItemsResponse>(decoding_limits).map(|v| (DataTypeId::CreateMonitoredItemsResponse, v.encode_json(context)))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemModifyRequest>(decoding_limits).map(|v| (DataTypeId::MonitoredItemModifyRequest, v.encode_json(context)))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemModifyResult>(decoding_limits).map(|v| (DataTypeId::MonitoredItemModifyResult, v.encode_json(context)))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifyMonitoredItemsRequest>(decoding_limits).map(|v| (DataTypeId::ModifyMonitoredItemsRequest, v.encode_json(context)))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifyMonitoredItemsResponse>(decoding_limits).map(|v| (DataTypeId::ModifyMonitoredItemsResponse, v.encode_json(context)))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetMonitoringModeRequest>(decoding_limits).map(|v| (DataTypeId::SetMonitoringModeRequest, v.encode_json(context)))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetMonitoringModeResponse>(decoding_limits).map(|v| (DataTypeId::SetMonitoringModeResponse, v.encode_json(context)))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetTriggeringRequest>(decoding_limits).map(|v| (DataTypeId::SetTriggeringRequest, v.encode_json(context)))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetTriggeringResponse>(decoding_limits).map(|v| (DataTypeId::SetTriggeringResponse, v.encode_json(context)))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteMonitoredItemsRequest>(decoding_limits).map(|v| (DataTypeId::DeleteMonitoredItemsRequest, v.encode_json(context)))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteMonitoredItemsResponse>(decoding_limits).map(|v| (DataTypeId::DeleteMonitoredItemsResponse, v.encode_json(context)))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSubscriptionRequest>(decoding_limits).map(|v| (DataTypeId::CreateSubscriptionRequest, v.encode_json(context)))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSubscriptionResponse>(decoding_limits).map(|v| (DataTypeId::CreateSubscriptionResponse, v.encode_json(context)))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifySubscriptionRequest>(decoding_limits).map(|v| (DataTypeId::ModifySubscriptionRequest, v.encode_json(context)))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifySubscriptionResponse>(decoding_limits).map(|v| (DataTypeId::ModifySubscriptionResponse, v.encode_json(context)))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetPublishingModeRequest>(decoding_limits).map(|v| (DataTypeId::SetPublishingModeRequest, v.encode_json(context)))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetPublishingModeResponse>(decoding_limits).map(|v| (DataTypeId::SetPublishingModeResponse, v.encode_json(context)))),
+        ObjectId::NotificationMessage_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NotificationMessage>(decoding_limits).map(|v| (DataTypeId::NotificationMessage, v.encode_json(context)))),
+        ObjectId::NotificationData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NotificationData>(decoding_limits).map(|v| (DataTypeId::NotificationData, v.encode_json(context)))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemNotification>(decoding_limits).map(|v| (DataTypeId::MonitoredItemNotification, v.encode_json(context)))),
+        ObjectId::EventFieldList_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EventFieldList>(decoding_limits).map(|v| (DataTypeId::EventFieldList, v.encode_json(context)))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SubscriptionAcknowledgement>(decoding_limits).map(|v| (DataTypeId::SubscriptionAcknowledgement, v.encode_json(context)))),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<PublishRequest>(decoding_limits).map(|v| (DataTypeId::PublishRequest, v.encode_json(context)))),
+        ObjectId::PublishResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<PublishResponse>(decoding_limits).map(|v| (DataTypeId::PublishResponse, v.encode_json(context)))),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RepublishRequest>(decoding_limits).map(|v| (DataTypeId::RepublishRequest, v.encode_json(context)))),
+        ObjectId::RepublishResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RepublishResponse>(decoding_limits).map(|v| (DataTypeId::RepublishResponse, v.encode_json(context)))),
+        ObjectId::TransferResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferResult>(decoding_limits).map(|v| (DataTypeId::TransferResult, v.encode_json(context)))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferSubscriptionsRequest>(decoding_limits).map(|v| (DataTypeId::TransferSubscriptionsRequest, v.encode_json(context)))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferSubscriptionsResponse>(decoding_limits).map(|v| (DataTypeId::TransferSubscriptionsResponse, v.encode_json(context)))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteSubscriptionsRequest>(decoding_limits).map(|v| (DataTypeId::DeleteSubscriptionsRequest, v.encode_json(context)))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteSubscriptionsResponse>(decoding_limits).map(|v| (DataTypeId::DeleteSubscriptionsResponse, v.encode_json(context)))),
+        ObjectId::BuildInfo_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BuildInfo>(decoding_limits).map(|v| (DataTypeId::BuildInfo, v.encode_json(context)))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointUrlListDataType>(decoding_limits).map(|v| (DataTypeId::EndpointUrlListDataType, v.encode_json(context)))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NetworkGroupDataType>(decoding_limits).map(|v| (DataTypeId::NetworkGroupDataType, v.encode_json(context)))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SamplingIntervalDiagnosticsDataType>(decoding_limits).map(|v| (DataTypeId::SamplingIntervalDiagnosticsDataType, v.encode_json(context)))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServerDiagnosticsSummaryDataType>(decoding_limits).map(|v| (DataTypeId::ServerDiagnosticsSummaryDataType, v.encode_json(context)))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SessionDiagnosticsDataType>(decoding_limits).map(|v| (DataTypeId::SessionDiagnosticsDataType, v.encode_json(context)))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SessionSecurityDiagnosticsDataType>(decoding_limits).map(|v| (DataTypeId::SessionSecurityDiagnosticsDataType, v.encode_json(context)))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServiceCounterDataType>(decoding_limits).map(|v| (DataTypeId::ServiceCounterDataType, v.encode_json(context)))),
+        ObjectId::StatusResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<StatusResult>(decoding_limits).map(|v| (DataTypeId::StatusResult, v.encode_json(context)))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SubscriptionDiagnosticsDataType>(decoding_limits).map(|v| (DataTypeId::SubscriptionDiagnosticsDataType, v.encode_json(context)))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModelChangeStructureDataType>(decoding_limits).map(|v| (DataTypeId::ModelChangeStructureDataType, v.encode_json(context)))),
+        ObjectId::Range_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Range>(decoding_limits).map(|v| (DataTypeId::Range, v.encode_json(context)))),
+        ObjectId::EUInformation_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EUInformation>(decoding_limits).map(|v| (DataTypeId::EUInformation, v.encode_json(context)))),
+        ObjectId::ComplexNumberType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ComplexNumberType>(decoding_limits).map(|v| (DataTypeId::ComplexNumberType, v.encode_json(context)))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DoubleComplexNumberType>(decoding_limits).map(|v| (DataTypeId::DoubleComplexNumberType, v.encode_json(context)))),
+        ObjectId::XVType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<XVType>(decoding_limits).map(|v| (DataTypeId::XVType, v.encode_json(context)))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ProgramDiagnosticDataType>(decoding_limits).map(|v| (DataTypeId::ProgramDiagnosticDataType, v.encode_json(context)))),
+        ObjectId::Annotation_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Annotation>(decoding_limits).map(|v| (DataTypeId::Annotation, v.encode_json(context)))),
+        _ => None
+    }
+}
+
+/// Decodes the JSON body of an extension object of a generated type and returns the extension
+/// object with the binary body. Returns `None` if the data type is not a generated type.
+pub(crate) fn json_body_to_binary(data_type_id: DataTypeId, body: &JsonValue, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    match data_type_id {
+        DataTypeId::TrustListDataType => Some(TrustListDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TrustListDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::Argument => Some(Argument::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &v))),
+        DataTypeId::EnumValueType => Some(EnumValueType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EnumValueType_Encoding_DefaultBinary, &v))),
+        DataTypeId::OptionSet => Some(OptionSet::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OptionSet_Encoding_DefaultBinary, &v))),
+        DataTypeId::TimeZoneDataType => Some(TimeZoneDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TimeZoneDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::ApplicationDescription => Some(ApplicationDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ApplicationDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::ServiceFault => Some(ServiceFault::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServiceFault_Encoding_DefaultBinary, &v))),
+        DataTypeId::FindServersRequest => Some(FindServersRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::FindServersResponse => Some(FindServersResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::ServerOnNetwork => Some(ServerOnNetwork::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServerOnNetwork_Encoding_DefaultBinary, &v))),
+        DataTypeId::FindServersOnNetworkRequest => Some(FindServersOnNetworkRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::FindServersOnNetworkResponse => Some(FindServersOnNetworkResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::UserTokenPolicy => Some(UserTokenPolicy::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UserTokenPolicy_Encoding_DefaultBinary, &v))),
+        DataTypeId::EndpointDescription => Some(EndpointDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::GetEndpointsRequest => Some(GetEndpointsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::GetEndpointsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::GetEndpointsResponse => Some(GetEndpointsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::GetEndpointsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::RegisteredServer => Some(RegisteredServer::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisteredServer_Encoding_DefaultBinary, &v))),
+        DataTypeId::RegisterServerRequest => Some(RegisterServerRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterServerRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::RegisterServerResponse => Some(RegisterServerResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterServerResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::DiscoveryConfiguration => Some(DiscoveryConfiguration::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary, &v))),
+        DataTypeId::ChannelSecurityToken => Some(ChannelSecurityToken::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ChannelSecurityToken_Encoding_DefaultBinary, &v))),
+        DataTypeId::OpenSecureChannelRequest => Some(OpenSecureChannelRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::OpenSecureChannelResponse => Some(OpenSecureChannelResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::CloseSecureChannelRequest => Some(CloseSecureChannelRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CloseSecureChannelResponse => Some(CloseSecureChannelResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::SignedSoftwareCertificate => Some(SignedSoftwareCertificate::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary, &v))),
+        DataTypeId::SignatureData => Some(SignatureData::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SignatureData_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateSessionRequest => Some(CreateSessionRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSessionRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateSessionResponse => Some(CreateSessionResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSessionResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::UserIdentityToken => Some(UserIdentityToken::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UserIdentityToken_Encoding_DefaultBinary, &v))),
+        DataTypeId::ActivateSessionRequest => Some(ActivateSessionRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ActivateSessionRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::ActivateSessionResponse => Some(ActivateSessionResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ActivateSessionResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::CloseSessionRequest => Some(CloseSessionRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSessionRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CloseSessionResponse => Some(CloseSessionResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSessionResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::CancelRequest => Some(CancelRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CancelRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CancelResponse => Some(CancelResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CancelResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::NodeAttributes => Some(NodeAttributes::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeAttributes_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddNodesItem => Some(AddNodesItem::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesItem_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddNodesResult => Some(AddNodesResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddNodesRequest => Some(AddNodesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddNodesResponse => Some(AddNodesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddReferencesItem => Some(AddReferencesItem::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesItem_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddReferencesRequest => Some(AddReferencesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::AddReferencesResponse => Some(AddReferencesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteNodesItem => Some(DeleteNodesItem::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesItem_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteNodesRequest => Some(DeleteNodesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteNodesResponse => Some(DeleteNodesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteReferencesItem => Some(DeleteReferencesItem::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesItem_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteReferencesRequest => Some(DeleteReferencesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteReferencesResponse => Some(DeleteReferencesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::ViewDescription => Some(ViewDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ViewDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseDescription => Some(BrowseDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::ReferenceDescription => Some(ReferenceDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReferenceDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseResult => Some(BrowseResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseRequest => Some(BrowseRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseResponse => Some(BrowseResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseNextRequest => Some(BrowseNextRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseNextRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowseNextResponse => Some(BrowseNextResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseNextResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::RelativePathElement => Some(RelativePathElement::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RelativePathElement_Encoding_DefaultBinary, &v))),
+        DataTypeId::RelativePath => Some(RelativePath::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RelativePath_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowsePath => Some(BrowsePath::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePath_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowsePathTarget => Some(BrowsePathTarget::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePathTarget_Encoding_DefaultBinary, &v))),
+        DataTypeId::BrowsePathResult => Some(BrowsePathResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePathResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::TranslateBrowsePathsToNodeIdsRequest => Some(TranslateBrowsePathsToNodeIdsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::TranslateBrowsePathsToNodeIdsResponse => Some(TranslateBrowsePathsToNodeIdsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::RegisterNodesRequest => Some(RegisterNodesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterNodesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::RegisterNodesResponse => Some(RegisterNodesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterNodesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::UnregisterNodesRequest => Some(UnregisterNodesRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::UnregisterNodesResponse => Some(UnregisterNodesResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::EndpointConfiguration => Some(EndpointConfiguration::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointConfiguration_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryDataDescription => Some(QueryDataDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryDataDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::NodeTypeDescription => Some(NodeTypeDescription::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeTypeDescription_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryDataSet => Some(QueryDataSet::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryDataSet_Encoding_DefaultBinary, &v))),
+        DataTypeId::NodeReference => Some(NodeReference::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeReference_Encoding_DefaultBinary, &v))),
+        DataTypeId::ContentFilterElement => Some(ContentFilterElement::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterElement_Encoding_DefaultBinary, &v))),
+        DataTypeId::ContentFilter => Some(ContentFilter::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilter_Encoding_DefaultBinary, &v))),
+        DataTypeId::FilterOperand => Some(FilterOperand::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FilterOperand_Encoding_DefaultBinary, &v))),
+        DataTypeId::ContentFilterElementResult => Some(ContentFilterElementResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterElementResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::ContentFilterResult => Some(ContentFilterResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::ParsingResult => Some(ParsingResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ParsingResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryFirstRequest => Some(QueryFirstRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryFirstRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryFirstResponse => Some(QueryFirstResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryFirstResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryNextRequest => Some(QueryNextRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryNextRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::QueryNextResponse => Some(QueryNextResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryNextResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::ReadValueId => Some(ReadValueId::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadValueId_Encoding_DefaultBinary, &v))),
+        DataTypeId::ReadRequest => Some(ReadRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::ReadResponse => Some(ReadResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryReadValueId => Some(HistoryReadValueId::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadValueId_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryReadResult => Some(HistoryReadResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryData => Some(HistoryData::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryData_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModificationInfo => Some(ModificationInfo::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModificationInfo_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryReadRequest => Some(HistoryReadRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryReadResponse => Some(HistoryReadResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::WriteValue => Some(WriteValue::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteValue_Encoding_DefaultBinary, &v))),
+        DataTypeId::WriteRequest => Some(WriteRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::WriteResponse => Some(WriteResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryUpdateResult => Some(HistoryUpdateResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryUpdateRequest => Some(HistoryUpdateRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::HistoryUpdateResponse => Some(HistoryUpdateResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::CallMethodRequest => Some(CallMethodRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallMethodRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CallMethodResult => Some(CallMethodResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallMethodResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::CallRequest => Some(CallRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CallResponse => Some(CallResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoringFilter => Some(MonitoringFilter::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringFilter_Encoding_DefaultBinary, &v))),
+        DataTypeId::AggregateConfiguration => Some(AggregateConfiguration::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AggregateConfiguration_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoringFilterResult => Some(MonitoringFilterResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringFilterResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoringParameters => Some(MonitoringParameters::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringParameters_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoredItemCreateRequest => Some(MonitoredItemCreateRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoredItemCreateResult => Some(MonitoredItemCreateResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateMonitoredItemsRequest => Some(CreateMonitoredItemsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateMonitoredItemsResponse => Some(CreateMonitoredItemsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoredItemModifyRequest => Some(MonitoredItemModifyRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoredItemModifyResult => Some(MonitoredItemModifyResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModifyMonitoredItemsRequest => Some(ModifyMonitoredItemsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModifyMonitoredItemsResponse => Some(ModifyMonitoredItemsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetMonitoringModeRequest => Some(SetMonitoringModeRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetMonitoringModeResponse => Some(SetMonitoringModeResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetTriggeringRequest => Some(SetTriggeringRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetTriggeringRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetTriggeringResponse => Some(SetTriggeringResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetTriggeringResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteMonitoredItemsRequest => Some(DeleteMonitoredItemsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteMonitoredItemsResponse => Some(DeleteMonitoredItemsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateSubscriptionRequest => Some(CreateSubscriptionRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::CreateSubscriptionResponse => Some(CreateSubscriptionResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModifySubscriptionRequest => Some(ModifySubscriptionRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModifySubscriptionResponse => Some(ModifySubscriptionResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetPublishingModeRequest => Some(SetPublishingModeRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::SetPublishingModeResponse => Some(SetPublishingModeResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::NotificationMessage => Some(NotificationMessage::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NotificationMessage_Encoding_DefaultBinary, &v))),
+        DataTypeId::NotificationData => Some(NotificationData::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NotificationData_Encoding_DefaultBinary, &v))),
+        DataTypeId::MonitoredItemNotification => Some(MonitoredItemNotification::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemNotification_Encoding_DefaultBinary, &v))),
+        DataTypeId::EventFieldList => Some(EventFieldList::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EventFieldList_Encoding_DefaultBinary, &v))),
+        DataTypeId::SubscriptionAcknowledgement => Some(SubscriptionAcknowledgement::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary, &v))),
+        DataTypeId::PublishRequest => Some(PublishRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::PublishRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::PublishResponse => Some(PublishResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::PublishResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::RepublishRequest => Some(RepublishRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RepublishRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::RepublishResponse => Some(RepublishResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RepublishResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::TransferResult => Some(TransferResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::TransferSubscriptionsRequest => Some(TransferSubscriptionsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::TransferSubscriptionsResponse => Some(TransferSubscriptionsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteSubscriptionsRequest => Some(DeleteSubscriptionsRequest::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary, &v))),
+        DataTypeId::DeleteSubscriptionsResponse => Some(DeleteSubscriptionsResponse::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary, &v))),
+        DataTypeId::BuildInfo => Some(BuildInfo::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BuildInfo_Encoding_DefaultBinary, &v))),
+        DataTypeId::EndpointUrlListDataType => Some(EndpointUrlListDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::NetworkGroupDataType => Some(NetworkGroupDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NetworkGroupDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::SamplingIntervalDiagnosticsDataType => Some(SamplingIntervalDiagnosticsDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::ServerDiagnosticsSummaryDataType => Some(ServerDiagnosticsSummaryDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::SessionDiagnosticsDataType => Some(SessionDiagnosticsDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::SessionSecurityDiagnosticsDataType => Some(SessionSecurityDiagnosticsDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::ServiceCounterDataType => Some(ServiceCounterDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServiceCounterDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::StatusResult => Some(StatusResult::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::StatusResult_Encoding_DefaultBinary, &v))),
+        DataTypeId::SubscriptionDiagnosticsDataType => Some(SubscriptionDiagnosticsDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::ModelChangeStructureDataType => Some(ModelChangeStructureDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::Range => Some(Range::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Range_Encoding_DefaultBinary, &v))),
+        DataTypeId::EUInformation => Some(EUInformation::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EUInformation_Encoding_DefaultBinary, &v))),
+        DataTypeId::ComplexNumberType => Some(ComplexNumberType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ComplexNumberType_Encoding_DefaultBinary, &v))),
+        DataTypeId::DoubleComplexNumberType => Some(DoubleComplexNumberType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary, &v))),
+        DataTypeId::XVType => Some(XVType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::XVType_Encoding_DefaultBinary, &v))),
+        DataTypeId::ProgramDiagnosticDataType => Some(ProgramDiagnosticDataType::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary, &v))),
+        DataTypeId::Annotation => Some(Annotation::decode_json(body, decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Annotation_Encoding_DefaultBinary, &v))),
+        _ => None
+    }
+}
+
+/// Decodes the binary body of an extension object of a generated type and encodes it as XML.
+/// The id of the XML encoding of the type is returned with it. Returns `None` if the object id
+/// is not the binary encoding of a generated type.
+pub(crate) fn binary_body_to_xml(object_id: ObjectId, extension_object: &ExtensionObject, decoding_limits: &DecodingLimits) -> Option<EncodingResult<(ObjectId, XmlNode)>> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TrustListDataType>(decoding_limits).map(|v| (ObjectId::TrustListDataType_Encoding_DefaultXml, v.encode_xml("TrustListDataType").unwrap_or_else(|| XmlNode::new("TrustListDataType"))))),
+        ObjectId::Argument_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Argument>(decoding_limits).map(|v| (ObjectId::Argument_Encoding_DefaultXml, v.encode_xml("Argument").unwrap_or_else(|| XmlNode::new("Argument"))))),
+        ObjectId::EnumValueType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EnumValueType>(decoding_limits).map(|v| (ObjectId::EnumValueType_Encoding_DefaultXml, v.encode_xml("EnumValueType").unwrap_or_else(|| XmlNode::new("EnumValueType"))))),
+        ObjectId::OptionSet_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OptionSet>(decoding_limits).map(|v| (ObjectId::OptionSet_Encoding_DefaultXml, v.encode_xml("OptionSet").unwrap_or_else(|| XmlNode::new("OptionSet"))))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TimeZoneDataType>(decoding_limits).map(|v| (ObjectId::TimeZoneDataType_Encoding_DefaultXml, v.encode_xml("TimeZoneDataType").unwrap_or_else(|| XmlNode::new("TimeZoneDataType"))))),
+        ObjectId::ApplicationDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ApplicationDescription>(decoding_limits).map(|v| (ObjectId::ApplicationDescription_Encoding_DefaultXml, v.encode_xml("ApplicationDescription").unwrap_or_else(|| XmlNode::new("ApplicationDescription"))))),
+        ObjectId::ServiceFault_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServiceFault>(decoding_limits).map(|v| (ObjectId::ServiceFault_Encoding_DefaultXml, v.encode_xml("ServiceFault").unwrap_or_else(|| XmlNode::new("ServiceFault"))))),
+        ObjectId::FindServersRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersRequest>(decoding_limits).map(|v| (ObjectId::FindServersRequest_Encoding_DefaultXml, v.encode_xml("FindServersRequest").unwrap_or_else(|| XmlNode::new("FindServersRequest"))))),
+        ObjectId::FindServersResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersResponse>(decoding_limits).map(|v| (ObjectId::FindServersResponse_Encoding_DefaultXml, v.encode_xml("FindServersResponse").unwrap_or_else(|| XmlNode::new("FindServersResponse"))))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServerOnNetwork>(decoding_limits).map(|v| (ObjectId::ServerOnNetwork_Encoding_DefaultXml, v.encode_xml("ServerOnNetwork").unwrap_or_else(|| XmlNode::new("ServerOnNetwork"))))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersOnNetworkRequest>(decoding_limits).map(|v| (ObjectId::FindServersOnNetworkRequest_Encoding_DefaultXml, v.encode_xml("FindServersOnNetworkRequest").unwrap_or_else(|| XmlNode::new("FindServersOnNetworkRequest"))))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FindServersOnNetworkResponse>(decoding_limits).map(|v| (ObjectId::FindServersOnNetworkResponse_Encoding_DefaultXml, v.encode_xml("FindServersOnNetworkResponse").unwrap_or_else(|| XmlNode::new("FindServersOnNetworkResponse"))))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UserTokenPolicy>(decoding_limits).map(|v| (ObjectId::UserTokenPolicy_Encoding_DefaultXml, v.encode_xml("UserTokenPolicy").unwrap_or_else(|| XmlNode::new("UserTokenPolicy"))))),
+        ObjectId::EndpointDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointDescription>(decoding_limits).map(|v| (ObjectId::EndpointDescription_Encoding_DefaultXml, v.encode_xml("EndpointDescription").unwrap_or_else(|| XmlNode::new("EndpointDescription"))))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<GetEndpointsRequest>(decoding_limits).map(|v| (ObjectId::GetEndpointsRequest_Encoding_DefaultXml, v.encode_xml("GetEndpointsRequest").unwrap_or_else(|| XmlNode::new("GetEndpointsRequest"))))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<GetEndpointsResponse>(decoding_limits).map(|v| (ObjectId::GetEndpointsResponse_Encoding_DefaultXml, v.encode_xml("GetEndpointsResponse").unwrap_or_else(|| XmlNode::new("GetEndpointsResponse"))))),
+        ObjectId::RegisteredServer_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisteredServer>(decoding_limits).map(|v| (ObjectId::RegisteredServer_Encoding_DefaultXml, v.encode_xml("RegisteredServer").unwrap_or_else(|| XmlNode::new("RegisteredServer"))))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterServerRequest>(decoding_limits).map(|v| (ObjectId::RegisterServerRequest_Encoding_DefaultXml, v.encode_xml("RegisterServerRequest").unwrap_or_else(|| XmlNode::new("RegisterServerRequest"))))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterServerResponse>(decoding_limits).map(|v| (ObjectId::RegisterServerResponse_Encoding_DefaultXml, v.encode_xml("RegisterServerResponse").unwrap_or_else(|| XmlNode::new("RegisterServerResponse"))))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DiscoveryConfiguration>(decoding_limits).map(|v| (ObjectId::DiscoveryConfiguration_Encoding_DefaultXml, v.encode_xml("DiscoveryConfiguration").unwrap_or_else(|| XmlNode::new("DiscoveryConfiguration"))))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ChannelSecurityToken>(decoding_limits).map(|v| (ObjectId::ChannelSecurityToken_Encoding_DefaultXml, v.encode_xml("ChannelSecurityToken").unwrap_or_else(|| XmlNode::new("ChannelSecurityToken"))))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OpenSecureChannelRequest>(decoding_limits).map(|v| (ObjectId::OpenSecureChannelRequest_Encoding_DefaultXml, v.encode_xml("OpenSecureChannelRequest").unwrap_or_else(|| XmlNode::new("OpenSecureChannelRequest"))))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<OpenSecureChannelResponse>(decoding_limits).map(|v| (ObjectId::OpenSecureChannelResponse_Encoding_DefaultXml, v.encode_xml("OpenSecureChannelResponse").unwrap_or_else(|| XmlNode::new("OpenSecureChannelResponse"))))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSecureChannelRequest>(decoding_limits).map(|v| (ObjectId::CloseSecureChannelRequest_Encoding_DefaultXml, v.encode_xml("CloseSecureChannelRequest").unwrap_or_else(|| XmlNode::new("CloseSecureChannelRequest"))))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSecureChannelResponse>(decoding_limits).map(|v| (ObjectId::CloseSecureChannelResponse_Encoding_DefaultXml, v.encode_xml("CloseSecureChannelResponse").unwrap_or_else(|| XmlNode::new("CloseSecureChannelResponse"))))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SignedSoftwareCertificate>(decoding_limits).map(|v| (ObjectId::SignedSoftwareCertificate_Encoding_DefaultXml, v.encode_xml("SignedSoftwareCertificate").unwrap_or_else(|| XmlNode::new("SignedSoftwareCertificate"))))),
+        ObjectId::SignatureData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SignatureData>(decoding_limits).map(|v| (ObjectId::SignatureData_Encoding_DefaultXml, v.encode_xml("SignatureData").unwrap_or_else(|| XmlNode::new("SignatureData"))))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSessionRequest>(decoding_limits).map(|v| (ObjectId::CreateSessionRequest_Encoding_DefaultXml, v.encode_xml("CreateSessionRequest").unwrap_or_else(|| XmlNode::new("CreateSessionRequest"))))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSessionResponse>(decoding_limits).map(|v| (ObjectId::CreateSessionResponse_Encoding_DefaultXml, v.encode_xml("CreateSessionResponse").unwrap_or_else(|| XmlNode::new("CreateSessionResponse"))))),
+        ObjectId::UserIdentityToken_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UserIdentityToken>(decoding_limits).map(|v| (ObjectId::UserIdentityToken_Encoding_DefaultXml, v.encode_xml("UserIdentityToken").unwrap_or_else(|| XmlNode::new("UserIdentityToken"))))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ActivateSessionRequest>(decoding_limits).map(|v| (ObjectId::ActivateSessionRequest_Encoding_DefaultXml, v.encode_xml("ActivateSessionRequest").unwrap_or_else(|| XmlNode::new("ActivateSessionRequest"))))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ActivateSessionResponse>(decoding_limits).map(|v| (ObjectId::ActivateSessionResponse_Encoding_DefaultXml, v.encode_xml("ActivateSessionResponse").unwrap_or_else(|| XmlNode::new("ActivateSessionResponse"))))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSessionRequest>(decoding_limits).map(|v| (ObjectId::CloseSessionRequest_Encoding_DefaultXml, v.encode_xml("CloseSessionRequest").unwrap_or_else(|| XmlNode::new("CloseSessionRequest"))))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CloseSessionResponse>(decoding_limits).map(|v| (ObjectId::CloseSessionResponse_Encoding_DefaultXml, v.encode_xml("CloseSessionResponse").unwrap_or_else(|| XmlNode::new("CloseSessionResponse"))))),
+        ObjectId::CancelRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CancelRequest>(decoding_limits).map(|v| (ObjectId::CancelRequest_Encoding_DefaultXml, v.encode_xml("CancelRequest").unwrap_or_else(|| XmlNode::new("CancelRequest"))))),
+        ObjectId::CancelResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CancelResponse>(decoding_limits).map(|v| (ObjectId::CancelResponse_Encoding_DefaultXml, v.encode_xml("CancelResponse").unwrap_or_else(|| XmlNode::new("CancelResponse"))))),
+        ObjectId::NodeAttributes_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeAttributes>(decoding_limits).map(|v| (ObjectId::NodeAttributes_Encoding_DefaultXml, v.encode_xml("NodeAttributes").unwrap_or_else(|| XmlNode::new("NodeAttributes"))))),
+        ObjectId::AddNodesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesItem>(decoding_limits).map(|v| (ObjectId::AddNodesItem_Encoding_DefaultXml, v.encode_xml("AddNodesItem").unwrap_or_else(|| XmlNode::new("AddNodesItem"))))),
+        ObjectId::AddNodesResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesResult>(decoding_limits).map(|v| (ObjectId::AddNodesResult_Encoding_DefaultXml, v.encode_xml("AddNodesResult").unwrap_or_else(|| XmlNode::new("AddNodesResult"))))),
+        ObjectId::AddNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesRequest>(decoding_limits).map(|v| (ObjectId::AddNodesRequest_Encoding_DefaultXml, v.encode_xml("AddNodesRequest").unwrap_or_else(|| XmlNode::new("AddNodesRequest"))))),
+        ObjectId::AddNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddNodesResponse>(decoding_limits).map(|v| (ObjectId::AddNodesResponse_Encoding_DefaultXml, v.encode_xml("AddNodesResponse").unwrap_or_else(|| XmlNode::new("AddNodesResponse"))))),
+        ObjectId::AddReferencesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesItem>(decoding_limits).map(|v| (ObjectId::AddReferencesItem_Encoding_DefaultXml, v.encode_xml("AddReferencesItem").unwrap_or_else(|| XmlNode::new("AddReferencesItem"))))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesRequest>(decoding_limits).map(|v| (ObjectId::AddReferencesRequest_Encoding_DefaultXml, v.encode_xml("AddReferencesRequest").unwrap_or_else(|| XmlNode::new("AddReferencesRequest"))))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AddReferencesResponse>(decoding_limits).map(|v| (ObjectId::AddReferencesResponse_Encoding_DefaultXml, v.encode_xml("AddReferencesResponse").unwrap_or_else(|| XmlNode::new("AddReferencesResponse"))))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesItem>(decoding_limits).map(|v| (ObjectId::DeleteNodesItem_Encoding_DefaultXml, v.encode_xml("DeleteNodesItem").unwrap_or_else(|| XmlNode::new("DeleteNodesItem"))))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesRequest>(decoding_limits).map(|v| (ObjectId::DeleteNodesRequest_Encoding_DefaultXml, v.encode_xml("DeleteNodesRequest").unwrap_or_else(|| XmlNode::new("DeleteNodesRequest"))))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteNodesResponse>(decoding_limits).map(|v| (ObjectId::DeleteNodesResponse_Encoding_DefaultXml, v.encode_xml("DeleteNodesResponse").unwrap_or_else(|| XmlNode::new("DeleteNodesResponse"))))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesItem>(decoding_limits).map(|v| (ObjectId::DeleteReferencesItem_Encoding_DefaultXml, v.encode_xml("DeleteReferencesItem").unwrap_or_else(|| XmlNode::new("DeleteReferencesItem"))))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesRequest>(decoding_limits).map(|v| (ObjectId::DeleteReferencesRequest_Encoding_DefaultXml, v.encode_xml("DeleteReferencesRequest").unwrap_or_else(|| XmlNode::new("DeleteReferencesRequest"))))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteReferencesResponse>(decoding_limits).map(|v| (ObjectId::DeleteReferencesResponse_Encoding_DefaultXml, v.encode_xml("DeleteReferencesResponse").unwrap_or_else(|| XmlNode::new("DeleteReferencesResponse"))))),
+        ObjectId::ViewDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ViewDescription>(decoding_limits).map(|v| (ObjectId::ViewDescription_Encoding_DefaultXml, v.encode_xml("ViewDescription").unwrap_or_else(|| XmlNode::new("ViewDescription"))))),
+        ObjectId::BrowseDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseDescription>(decoding_limits).map(|v| (ObjectId::BrowseDescription_Encoding_DefaultXml, v.encode_xml("BrowseDescription").unwrap_or_else(|| XmlNode::new("BrowseDescription"))))),
+        ObjectId::ReferenceDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReferenceDescription>(decoding_limits).map(|v| (ObjectId::ReferenceDescription_Encoding_DefaultXml, v.encode_xml("ReferenceDescription").unwrap_or_else(|| XmlNode::new("ReferenceDescription"))))),
+        ObjectId::BrowseResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseResult>(decoding_limits).map(|v| (ObjectId::BrowseResult_Encoding_DefaultXml, v.encode_xml("BrowseResult").unwrap_or_else(|| XmlNode::new("BrowseResult"))))),
+        ObjectId::BrowseRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseRequest>(decoding_limits).map(|v| (ObjectId::BrowseRequest_Encoding_DefaultXml, v.encode_xml("BrowseRequest").unwrap_or_else(|| XmlNode::new("BrowseRequest"))))),
+        ObjectId::BrowseResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseResponse>(decoding_limits).map(|v| (ObjectId::BrowseResponse_Encoding_DefaultXml, v.encode_xml("BrowseResponse").unwrap_or_else(|| XmlNode::new("BrowseResponse"))))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseNextRequest>(decoding_limits).map(|v| (ObjectId::BrowseNextRequest_Encoding_DefaultXml, v.encode_xml("BrowseNextRequest").unwrap_or_else(|| XmlNode::new("BrowseNextRequest"))))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowseNextResponse>(decoding_limits).map(|v| (ObjectId::BrowseNextResponse_Encoding_DefaultXml, v.encode_xml("BrowseNextResponse").unwrap_or_else(|| XmlNode::new("BrowseNextResponse"))))),
+        ObjectId::RelativePathElement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RelativePathElement>(decoding_limits).map(|v| (ObjectId::RelativePathElement_Encoding_DefaultXml, v.encode_xml("RelativePathElement").unwrap_or_else(|| XmlNode::new("RelativePathElement"))))),
+        ObjectId::RelativePath_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RelativePath>(decoding_limits).map(|v| (ObjectId::RelativePath_Encoding_DefaultXml, v.encode_xml("RelativePath").unwrap_or_else(|| XmlNode::new("RelativePath"))))),
+        ObjectId::BrowsePath_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePath>(decoding_limits).map(|v| (ObjectId::BrowsePath_Encoding_DefaultXml, v.encode_xml("BrowsePath").unwrap_or_else(|| XmlNode::new("BrowsePath"))))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePathTarget>(decoding_limits).map(|v| (ObjectId::BrowsePathTarget_Encoding_DefaultXml, v.encode_xml("BrowsePathTarget").unwrap_or_else(|| XmlNode::new("BrowsePathTarget"))))),
+        ObjectId::BrowsePathResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BrowsePathResult>(decoding_limits).map(|v| (ObjectId::BrowsePathResult_Encoding_DefaultXml, v.encode_xml("BrowsePathResult").unwrap_or_else(|| XmlNode::new("BrowsePathResult"))))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TranslateBrowsePathsToNodeIdsRequest>(decoding_limits).map(|v| (ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml, v.encode_xml("TranslateBrowsePathsToNodeIdsRequest").unwrap_or_else(|| XmlNode::new("TranslateBrowsePathsToNodeIdsRequest"))))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TranslateBrowsePathsToNodeIdsResponse>(decoding_limits).map(|v| (ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml, v.encode_xml("TranslateBrowsePathsToNodeIdsResponse").unwrap_or_else(|| XmlNode::new("TranslateBrowsePathsToNodeIdsResponse"))))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterNodesRequest>(decoding_limits).map(|v| (ObjectId::RegisterNodesRequest_Encoding_DefaultXml, v.encode_xml("RegisterNodesRequest").unwrap_or_else(|| XmlNode::new("RegisterNodesRequest"))))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RegisterNodesResponse>(decoding_limits).map(|v| (ObjectId::RegisterNodesResponse_Encoding_DefaultXml, v.encode_xml("RegisterNodesResponse").unwrap_or_else(|| XmlNode::new("RegisterNodesResponse"))))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UnregisterNodesRequest>(decoding_limits).map(|v| (ObjectId::UnregisterNodesRequest_Encoding_DefaultXml, v.encode_xml("UnregisterNodesRequest").unwrap_or_else(|| XmlNode::new("UnregisterNodesRequest"))))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<UnregisterNodesResponse>(decoding_limits).map(|v| (ObjectId::UnregisterNodesResponse_Encoding_DefaultXml, v.encode_xml("UnregisterNodesResponse").unwrap_or_else(|| XmlNode::new("UnregisterNodesResponse"))))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointConfiguration>(decoding_limits).map(|v| (ObjectId::EndpointConfiguration_Encoding_DefaultXml, v.encode_xml("EndpointConfiguration").unwrap_or_else(|| XmlNode::new("EndpointConfiguration"))))),
+        ObjectId::QueryDataDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryDataDescription>(decoding_limits).map(|v| (ObjectId::QueryDataDescription_Encoding_DefaultXml, v.encode_xml("QueryDataDescription").unwrap_or_else(|| XmlNode::new("QueryDataDescription"))))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeTypeDescription>(decoding_limits).map(|v| (ObjectId::NodeTypeDescription_Encoding_DefaultXml, v.encode_xml("NodeTypeDescription").unwrap_or_else(|| XmlNode::new("NodeTypeDescription"))))),
+        ObjectId::QueryDataSet_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryDataSet>(decoding_limits).map(|v| (ObjectId::QueryDataSet_Encoding_DefaultXml, v.encode_xml("QueryDataSet").unwrap_or_else(|| XmlNode::new("QueryDataSet"))))),
+        ObjectId::NodeReference_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NodeReference>(decoding_limits).map(|v| (ObjectId::NodeReference_Encoding_DefaultXml, v.encode_xml("NodeReference").unwrap_or_else(|| XmlNode::new("NodeReference"))))),
+        ObjectId::ContentFilterElement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterElement>(decoding_limits).map(|v| (ObjectId::ContentFilterElement_Encoding_DefaultXml, v.encode_xml("ContentFilterElement").unwrap_or_else(|| XmlNode::new("ContentFilterElement"))))),
+        ObjectId::ContentFilter_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilter>(decoding_limits).map(|v| (ObjectId::ContentFilter_Encoding_DefaultXml, v.encode_xml("ContentFilter").unwrap_or_else(|| XmlNode::new("ContentFilter"))))),
+        ObjectId::FilterOperand_Encoding_DefaultBinary => Some(extension_object.decode_inner::<FilterOperand>(decoding_limits).map(|v| (ObjectId::FilterOperand_Encoding_DefaultXml, v.encode_xml("FilterOperand").unwrap_or_else(|| XmlNode::new("FilterOperand"))))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterElementResult>(decoding_limits).map(|v| (ObjectId::ContentFilterElementResult_Encoding_DefaultXml, v.encode_xml("ContentFilterElementResult").unwrap_or_else(|| XmlNode::new("ContentFilterElementResult"))))),
+        ObjectId::ContentFilterResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ContentFilterResult>(decoding_limits).map(|v| (ObjectId::ContentFilterResult_Encoding_DefaultXml, v.encode_xml("ContentFilterResult").unwrap_or_else(|| XmlNode::new("ContentFilterResult"))))),
+        ObjectId::ParsingResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ParsingResult>(decoding_limits).map(|v| (ObjectId::ParsingResult_Encoding_DefaultXml, v.encode_xml("ParsingResult").unwrap_or_else(|| XmlNode::new("ParsingResult"))))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryFirstRequest>(decoding_limits).map(|v| (ObjectId::QueryFirstRequest_Encoding_DefaultXml, v.encode_xml("QueryFirstRequest").unwrap_or_else(|| XmlNode::new("QueryFirstRequest"))))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryFirstResponse>(decoding_limits).map(|v| (ObjectId::QueryFirstResponse_Encoding_DefaultXml, v.encode_xml("QueryFirstResponse").unwrap_or_else(|| XmlNode::new("QueryFirstResponse"))))),
+        ObjectId::QueryNextRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryNextRequest>(decoding_limits).map(|v| (ObjectId::QueryNextRequest_Encoding_DefaultXml, v.encode_xml("QueryNextRequest").unwrap_or_else(|| XmlNode::new("QueryNextRequest"))))),
+        ObjectId::QueryNextResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<QueryNextResponse>(decoding_limits).map(|v| (ObjectId::QueryNextResponse_Encoding_DefaultXml, v.encode_xml("QueryNextResponse").unwrap_or_else(|| XmlNode::new("QueryNextResponse"))))),
+        ObjectId::ReadValueId_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadValueId>(decoding_limits).map(|v| (ObjectId::ReadValueId_Encoding_DefaultXml, v.encode_xml("ReadValueId").unwrap_or_else(|| XmlNode::new("ReadValueId"))))),
+        ObjectId::ReadRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadRequest>(decoding_limits).map(|v| (ObjectId::ReadRequest_Encoding_DefaultXml, v.encode_xml("ReadRequest").unwrap_or_else(|| XmlNode::new("ReadRequest"))))),
+        ObjectId::ReadResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ReadResponse>(decoding_limits).map(|v| (ObjectId::ReadResponse_Encoding_DefaultXml, v.encode_xml("ReadResponse").unwrap_or_else(|| XmlNode::new("ReadResponse"))))),
+        ObjectId::HistoryReadValueId_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadValueId>(decoding_limits).map(|v| (ObjectId::HistoryReadValueId_Encoding_DefaultXml, v.encode_xml("HistoryReadValueId").unwrap_or_else(|| XmlNode::new("HistoryReadValueId"))))),
+        ObjectId::HistoryReadResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadResult>(decoding_limits).map(|v| (ObjectId::HistoryReadResult_Encoding_DefaultXml, v.encode_xml("HistoryReadResult").unwrap_or_else(|| XmlNode::new("HistoryReadResult"))))),
+        ObjectId::HistoryData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryData>(decoding_limits).map(|v| (ObjectId::HistoryData_Encoding_DefaultXml, v.encode_xml("HistoryData").unwrap_or_else(|| XmlNode::new("HistoryData"))))),
+        ObjectId::ModificationInfo_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModificationInfo>(decoding_limits).map(|v| (ObjectId::ModificationInfo_Encoding_DefaultXml, v.encode_xml("ModificationInfo").unwrap_or_else(|| XmlNode::new("ModificationInfo"))))),
+        ObjectId::HistoryReadRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadRequest>(decoding_limits).map(|v| (ObjectId::HistoryReadRequest_Encoding_DefaultXml, v.encode_xml("HistoryReadRequest").unwrap_or_else(|| XmlNode::new("HistoryReadRequest"))))),
+        ObjectId::HistoryReadResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryReadResponse>(decoding_limits).map(|v| (ObjectId::HistoryReadResponse_Encoding_DefaultXml, v.encode_xml("HistoryReadResponse").unwrap_or_else(|| XmlNode::new("HistoryReadResponse"))))),
+        ObjectId::WriteValue_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteValue>(decoding_limits).map(|v| (ObjectId::WriteValue_Encoding_DefaultXml, v.encode_xml("WriteValue").unwrap_or_else(|| XmlNode::new("WriteValue"))))),
+        ObjectId::WriteRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteRequest>(decoding_limits).map(|v| (ObjectId::WriteRequest_Encoding_DefaultXml, v.encode_xml("WriteRequest").unwrap_or_else(|| XmlNode::new("WriteRequest"))))),
+        ObjectId::WriteResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<WriteResponse>(decoding_limits).map(|v| (ObjectId::WriteResponse_Encoding_DefaultXml, v.encode_xml("WriteResponse").unwrap_or_else(|| XmlNode::new("WriteResponse"))))),
+        ObjectId::HistoryUpdateResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateResult>(decoding_limits).map(|v| (ObjectId::HistoryUpdateResult_Encoding_DefaultXml, v.encode_xml("HistoryUpdateResult").unwrap_or_else(|| XmlNode::new("HistoryUpdateResult"))))),
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateRequest>(decoding_limits).map(|v| (ObjectId::HistoryUpdateRequest_Encoding_DefaultXml, v.encode_xml("HistoryUpdateRequest").unwrap_or_else(|| XmlNode::new("HistoryUpdateRequest"))))),
+        ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<HistoryUpdateResponse>(decoding_limits).map(|v| (ObjectId::HistoryUpdateResponse_Encoding_DefaultXml, v.encode_xml("HistoryUpdateResponse").unwrap_or_else(|| XmlNode::new("HistoryUpdateResponse"))))),
+        ObjectId::CallMethodRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallMethodRequest>(decoding_limits).map(|v| (ObjectId::CallMethodRequest_Encoding_DefaultXml, v.encode_xml("CallMethodRequest").unwrap_or_else(|| XmlNode::new("CallMethodRequest"))))),
+        ObjectId::CallMethodResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallMethodResult>(decoding_limits).map(|v| (ObjectId::CallMethodResult_Encoding_DefaultXml, v.encode_xml("CallMethodResult").unwrap_or_else(|| XmlNode::new("CallMethodResult"))))),
+        ObjectId::CallRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallRequest>(decoding_limits).map(|v| (ObjectId::CallRequest_Encoding_DefaultXml, v.encode_xml("CallRequest").unwrap_or_else(|| XmlNode::new("CallRequest"))))),
+        ObjectId::CallResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CallResponse>(decoding_limits).map(|v| (ObjectId::CallResponse_Encoding_DefaultXml, v.encode_xml("CallResponse").unwrap_or_else(|| XmlNode::new("CallResponse"))))),
+        ObjectId::MonitoringFilter_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringFilter>(decoding_limits).map(|v| (ObjectId::MonitoringFilter_Encoding_DefaultXml, v.encode_xml("MonitoringFilter").unwrap_or_else(|| XmlNode::new("MonitoringFilter"))))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultBinary => Some(extension_object.decode_inner::<AggregateConfiguration>(decoding_limits).map(|v| (ObjectId::AggregateConfiguration_Encoding_DefaultXml, v.encode_xml("AggregateConfiguration").unwrap_or_else(|| XmlNode::new("AggregateConfiguration"))))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringFilterResult>(decoding_limits).map(|v| (ObjectId::MonitoringFilterResult_Encoding_DefaultXml, v.encode_xml("MonitoringFilterResult").unwrap_or_else(|| XmlNode::new("MonitoringFilterResult"))))),
+        ObjectId::MonitoringParameters_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoringParameters>(decoding_limits).map(|v| (ObjectId::MonitoringParameters_Encoding_DefaultXml, v.encode_xml("MonitoringParameters").unwrap_or_else(|| XmlNode::new("MonitoringParameters"))))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemCreateRequest>(decoding_limits).map(|v| (ObjectId::MonitoredItemCreateRequest_Encoding_DefaultXml, v.encode_xml("MonitoredItemCreateRequest").unwrap_or_else(|| XmlNode::new("MonitoredItemCreateRequest"))))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemCreateResult>(decoding_limits).map(|v| (ObjectId::MonitoredItemCreateResult_Encoding_DefaultXml, v.encode_xml("MonitoredItemCreateResult").unwrap_or_else(|| XmlNode::new("MonitoredItemCreateResult"))))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateMonitoredItemsRequest>(decoding_limits).map(|v| (ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultXml, v.encode_xml("CreateMonitoredItemsRequest").unwrap_or_else(|| XmlNode::new("CreateMonitoredItemsRequest"))))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateMonitoredItemsResponse>(decoding_limits).map(|v| (ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultXml, v.encode_xml("CreateMonitoredItemsResponse").unwrap_or_else(|| XmlNode::new("CreateMonitoredItemsResponse"))))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemModifyRequest>(decoding_limits).map(|v| (ObjectId::MonitoredItemModifyRequest_Encoding_DefaultXml, v.encode_xml("MonitoredItemModifyRequest").unwrap_or_else(|| XmlNode::new("MonitoredItemModifyRequest"))))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemModifyResult>(decoding_limits).map(|v| (ObjectId::MonitoredItemModifyResult_Encoding_DefaultXml, v.encode_xml("MonitoredItemModifyResult").unwrap_or_else(|| XmlNode::new("MonitoredItemModifyResult"))))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifyMonitoredItemsRequest>(decoding_limits).map(|v| (ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultXml, v.encode_xml("ModifyMonitoredItemsRequest").unwrap_or_else(|| XmlNode::new("ModifyMonitoredItemsRequest"))))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifyMonitoredItemsResponse>(decoding_limits).map(|v| (ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultXml, v.encode_xml("ModifyMonitoredItemsResponse").unwrap_or_else(|| XmlNode::new("ModifyMonitoredItemsResponse"))))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetMonitoringModeRequest>(decoding_limits).map(|v| (ObjectId::SetMonitoringModeRequest_Encoding_DefaultXml, v.encode_xml("SetMonitoringModeRequest").unwrap_or_else(|| XmlNode::new("SetMonitoringModeRequest"))))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetMonitoringModeResponse>(decoding_limits).map(|v| (ObjectId::SetMonitoringModeResponse_Encoding_DefaultXml, v.encode_xml("SetMonitoringModeResponse").unwrap_or_else(|| XmlNode::new("SetMonitoringModeResponse"))))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetTriggeringRequest>(decoding_limits).map(|v| (ObjectId::SetTriggeringRequest_Encoding_DefaultXml, v.encode_xml("SetTriggeringRequest").unwrap_or_else(|| XmlNode::new("SetTriggeringRequest"))))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetTriggeringResponse>(decoding_limits).map(|v| (ObjectId::SetTriggeringResponse_Encoding_DefaultXml, v.encode_xml("SetTriggeringResponse").unwrap_or_else(|| XmlNode::new("SetTriggeringResponse"))))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteMonitoredItemsRequest>(decoding_limits).map(|v| (ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultXml, v.encode_xml("DeleteMonitoredItemsRequest").unwrap_or_else(|| XmlNode::new("DeleteMonitoredItemsRequest"))))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteMonitoredItemsResponse>(decoding_limits).map(|v| (ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultXml, v.encode_xml("DeleteMonitoredItemsResponse").unwrap_or_else(|| XmlNode::new("DeleteMonitoredItemsResponse"))))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSubscriptionRequest>(decoding_limits).map(|v| (ObjectId::CreateSubscriptionRequest_Encoding_DefaultXml, v.encode_xml("CreateSubscriptionRequest").unwrap_or_else(|| XmlNode::new("CreateSubscriptionRequest"))))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<CreateSubscriptionResponse>(decoding_limits).map(|v| (ObjectId::CreateSubscriptionResponse_Encoding_DefaultXml, v.encode_xml("CreateSubscriptionResponse").unwrap_or_else(|| XmlNode::new("CreateSubscriptionResponse"))))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifySubscriptionRequest>(decoding_limits).map(|v| (ObjectId::ModifySubscriptionRequest_Encoding_DefaultXml, v.encode_xml("ModifySubscriptionRequest").unwrap_or_else(|| XmlNode::new("ModifySubscriptionRequest"))))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModifySubscriptionResponse>(decoding_limits).map(|v| (ObjectId::ModifySubscriptionResponse_Encoding_DefaultXml, v.encode_xml("ModifySubscriptionResponse").unwrap_or_else(|| XmlNode::new("ModifySubscriptionResponse"))))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetPublishingModeRequest>(decoding_limits).map(|v| (ObjectId::SetPublishingModeRequest_Encoding_DefaultXml, v.encode_xml("SetPublishingModeRequest").unwrap_or_else(|| XmlNode::new("SetPublishingModeRequest"))))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SetPublishingModeResponse>(decoding_limits).map(|v| (ObjectId::SetPublishingModeResponse_Encoding_DefaultXml, v.encode_xml("SetPublishingModeResponse").unwrap_or_else(|| XmlNode::new("SetPublishingModeResponse"))))),
+        ObjectId::NotificationMessage_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NotificationMessage>(decoding_limits).map(|v| (ObjectId::NotificationMessage_Encoding_DefaultXml, v.encode_xml("NotificationMessage").unwrap_or_else(|| XmlNode::new("NotificationMessage"))))),
+        ObjectId::NotificationData_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NotificationData>(decoding_limits).map(|v| (ObjectId::NotificationData_Encoding_DefaultXml, v.encode_xml("NotificationData").unwrap_or_else(|| XmlNode::new("NotificationData"))))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultBinary => Some(extension_object.decode_inner::<MonitoredItemNotification>(decoding_limits).map(|v| (ObjectId::MonitoredItemNotification_Encoding_DefaultXml, v.encode_xml("MonitoredItemNotification").unwrap_or_else(|| XmlNode::new("MonitoredItemNotification"))))),
+        ObjectId::EventFieldList_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EventFieldList>(decoding_limits).map(|v| (ObjectId::EventFieldList_Encoding_DefaultXml, v.encode_xml("EventFieldList").unwrap_or_else(|| XmlNode::new("EventFieldList"))))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SubscriptionAcknowledgement>(decoding_limits).map(|v| (ObjectId::SubscriptionAcknowledgement_Encoding_DefaultXml, v.encode_xml("SubscriptionAcknowledgement").unwrap_or_else(|| XmlNode::new("SubscriptionAcknowledgement"))))),
+        ObjectId::PublishRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<PublishRequest>(decoding_limits).map(|v| (ObjectId::PublishRequest_Encoding_DefaultXml, v.encode_xml("PublishRequest").unwrap_or_else(|| XmlNode::new("PublishRequest"))))),
+        ObjectId::PublishResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<PublishResponse>(decoding_limits).map(|v| (ObjectId::PublishResponse_Encoding_DefaultXml, v.encode_xml("PublishResponse").unwrap_or_else(|| XmlNode::new("PublishResponse"))))),
+        ObjectId::RepublishRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RepublishRequest>(decoding_limits).map(|v| (ObjectId::RepublishRequest_Encoding_DefaultXml, v.encode_xml("RepublishRequest").unwrap_or_else(|| XmlNode::new("RepublishRequest"))))),
+        ObjectId::RepublishResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<RepublishResponse>(decoding_limits).map(|v| (ObjectId::RepublishResponse_Encoding_DefaultXml, v.encode_xml("RepublishResponse").unwrap_or_else(|| XmlNode::new("RepublishResponse"))))),
+        ObjectId::TransferResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferResult>(decoding_limits).map(|v| (ObjectId::TransferResult_Encoding_DefaultXml, v.encode_xml("TransferResult").unwrap_or_else(|| XmlNode::new("TransferResult"))))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferSubscriptionsRequest>(decoding_limits).map(|v| (ObjectId::TransferSubscriptionsRequest_Encoding_DefaultXml, v.encode_xml("TransferSubscriptionsRequest").unwrap_or_else(|| XmlNode::new("TransferSubscriptionsRequest"))))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<TransferSubscriptionsResponse>(decoding_limits).map(|v| (ObjectId::TransferSubscriptionsResponse_Encoding_DefaultXml, v.encode_xml("TransferSubscriptionsResponse").unwrap_or_else(|| XmlNode::new("TransferSubscriptionsResponse"))))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteSubscriptionsRequest>(decoding_limits).map(|v| (ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultXml, v.encode_xml("DeleteSubscriptionsRequest").unwrap_or_else(|| XmlNode::new("DeleteSubscriptionsRequest"))))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DeleteSubscriptionsResponse>(decoding_limits).map(|v| (ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultXml, v.encode_xml("DeleteSubscriptionsResponse").unwrap_or_else(|| XmlNode::new("DeleteSubscriptionsResponse"))))),
+        ObjectId::BuildInfo_Encoding_DefaultBinary => Some(extension_object.decode_inner::<BuildInfo>(decoding_limits).map(|v| (ObjectId::BuildInfo_Encoding_DefaultXml, v.encode_xml("BuildInfo").unwrap_or_else(|| XmlNode::new("BuildInfo"))))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EndpointUrlListDataType>(decoding_limits).map(|v| (ObjectId::EndpointUrlListDataType_Encoding_DefaultXml, v.encode_xml("EndpointUrlListDataType").unwrap_or_else(|| XmlNode::new("EndpointUrlListDataType"))))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<NetworkGroupDataType>(decoding_limits).map(|v| (ObjectId::NetworkGroupDataType_Encoding_DefaultXml, v.encode_xml("NetworkGroupDataType").unwrap_or_else(|| XmlNode::new("NetworkGroupDataType"))))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SamplingIntervalDiagnosticsDataType>(decoding_limits).map(|v| (ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml, v.encode_xml("SamplingIntervalDiagnosticsDataType").unwrap_or_else(|| XmlNode::new("SamplingIntervalDiagnosticsDataType"))))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServerDiagnosticsSummaryDataType>(decoding_limits).map(|v| (ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultXml, v.encode_xml("ServerDiagnosticsSummaryDataType").unwrap_or_else(|| XmlNode::new("ServerDiagnosticsSummaryDataType"))))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SessionDiagnosticsDataType>(decoding_limits).map(|v| (ObjectId::SessionDiagnosticsDataType_Encoding_DefaultXml, v.encode_xml("SessionDiagnosticsDataType").unwrap_or_else(|| XmlNode::new("SessionDiagnosticsDataType"))))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SessionSecurityDiagnosticsDataType>(decoding_limits).map(|v| (ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultXml, v.encode_xml("SessionSecurityDiagnosticsDataType").unwrap_or_else(|| XmlNode::new("SessionSecurityDiagnosticsDataType"))))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ServiceCounterDataType>(decoding_limits).map(|v| (ObjectId::ServiceCounterDataType_Encoding_DefaultXml, v.encode_xml("ServiceCounterDataType").unwrap_or_else(|| XmlNode::new("ServiceCounterDataType"))))),
+        ObjectId::StatusResult_Encoding_DefaultBinary => Some(extension_object.decode_inner::<StatusResult>(decoding_limits).map(|v| (ObjectId::StatusResult_Encoding_DefaultXml, v.encode_xml("StatusResult").unwrap_or_else(|| XmlNode::new("StatusResult"))))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<SubscriptionDiagnosticsDataType>(decoding_limits).map(|v| (ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultXml, v.encode_xml("SubscriptionDiagnosticsDataType").unwrap_or_else(|| XmlNode::new("SubscriptionDiagnosticsDataType"))))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ModelChangeStructureDataType>(decoding_limits).map(|v| (ObjectId::ModelChangeStructureDataType_Encoding_DefaultXml, v.encode_xml("ModelChangeStructureDataType").unwrap_or_else(|| XmlNode::new("ModelChangeStructureDataType"))))),
+        ObjectId::Range_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Range>(decoding_limits).map(|v| (ObjectId::Range_Encoding_DefaultXml, v.encode_xml("Range").unwrap_or_else(|| XmlNode::new("Range"))))),
+        ObjectId::EUInformation_Encoding_DefaultBinary => Some(extension_object.decode_inner::<EUInformation>(decoding_limits).map(|v| (ObjectId::EUInformation_Encoding_DefaultXml, v.encode_xml("EUInformation").unwrap_or_else(|| XmlNode::new("EUInformation"))))),
+        ObjectId::ComplexNumberType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ComplexNumberType>(decoding_limits).map(|v| (ObjectId::ComplexNumberType_Encoding_DefaultXml, v.encode_xml("ComplexNumberType").unwrap_or_else(|| XmlNode::new("ComplexNumberType"))))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<DoubleComplexNumberType>(decoding_limits).map(|v| (ObjectId::DoubleComplexNumberType_Encoding_DefaultXml, v.encode_xml("DoubleComplexNumberType").unwrap_or_else(|| XmlNode::new("DoubleComplexNumberType"))))),
+        ObjectId::XVType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<XVType>(decoding_limits).map(|v| (ObjectId::XVType_Encoding_DefaultXml, v.encode_xml("XVType").unwrap_or_else(|| XmlNode::new("XVType"))))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary => Some(extension_object.decode_inner::<ProgramDiagnosticDataType>(decoding_limits).map(|v| (ObjectId::ProgramDiagnosticDataType_Encoding_DefaultXml, v.encode_xml("ProgramDiagnosticDataType").unwrap_or_else(|| XmlNode::new("ProgramDiagnosticDataType"))))),
+        ObjectId::Annotation_Encoding_DefaultBinary => Some(extension_object.decode_inner::<Annotation>(decoding_limits).map(|v| (ObjectId::Annotation_Encoding_DefaultXml, v.encode_xml("Annotation").unwrap_or_else(|| XmlNode::new("Annotation"))))),
+        _ => None
+    }
+}
+
+/// Decodes the XML body of an extension object of a generated type and returns the extension
+/// object with the binary body. Returns `None` if the object id is not the XML encoding of a
+/// generated type.
+pub(crate) fn xml_body_to_binary(object_id: ObjectId, body: &XmlNode, decoding_limits: &DecodingLimits) -> Option<EncodingResult<ExtensionObject>> {
+    match object_id {
+        ObjectId::TrustListDataType_Encoding_DefaultXml => Some(TrustListDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TrustListDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::Argument_Encoding_DefaultXml => Some(Argument::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &v))),
+        ObjectId::EnumValueType_Encoding_DefaultXml => Some(EnumValueType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EnumValueType_Encoding_DefaultBinary, &v))),
+        ObjectId::OptionSet_Encoding_DefaultXml => Some(OptionSet::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OptionSet_Encoding_DefaultBinary, &v))),
+        ObjectId::TimeZoneDataType_Encoding_DefaultXml => Some(TimeZoneDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TimeZoneDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::ApplicationDescription_Encoding_DefaultXml => Some(ApplicationDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ApplicationDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::ServiceFault_Encoding_DefaultXml => Some(ServiceFault::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServiceFault_Encoding_DefaultBinary, &v))),
+        ObjectId::FindServersRequest_Encoding_DefaultXml => Some(FindServersRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::FindServersResponse_Encoding_DefaultXml => Some(FindServersResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::ServerOnNetwork_Encoding_DefaultXml => Some(ServerOnNetwork::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServerOnNetwork_Encoding_DefaultBinary, &v))),
+        ObjectId::FindServersOnNetworkRequest_Encoding_DefaultXml => Some(FindServersOnNetworkRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersOnNetworkRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::FindServersOnNetworkResponse_Encoding_DefaultXml => Some(FindServersOnNetworkResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FindServersOnNetworkResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::UserTokenPolicy_Encoding_DefaultXml => Some(UserTokenPolicy::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UserTokenPolicy_Encoding_DefaultBinary, &v))),
+        ObjectId::EndpointDescription_Encoding_DefaultXml => Some(EndpointDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::GetEndpointsRequest_Encoding_DefaultXml => Some(GetEndpointsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::GetEndpointsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::GetEndpointsResponse_Encoding_DefaultXml => Some(GetEndpointsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::GetEndpointsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::RegisteredServer_Encoding_DefaultXml => Some(RegisteredServer::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisteredServer_Encoding_DefaultBinary, &v))),
+        ObjectId::RegisterServerRequest_Encoding_DefaultXml => Some(RegisterServerRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterServerRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::RegisterServerResponse_Encoding_DefaultXml => Some(RegisterServerResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterServerResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::DiscoveryConfiguration_Encoding_DefaultXml => Some(DiscoveryConfiguration::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DiscoveryConfiguration_Encoding_DefaultBinary, &v))),
+        ObjectId::ChannelSecurityToken_Encoding_DefaultXml => Some(ChannelSecurityToken::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ChannelSecurityToken_Encoding_DefaultBinary, &v))),
+        ObjectId::OpenSecureChannelRequest_Encoding_DefaultXml => Some(OpenSecureChannelRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OpenSecureChannelRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::OpenSecureChannelResponse_Encoding_DefaultXml => Some(OpenSecureChannelResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::OpenSecureChannelResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::CloseSecureChannelRequest_Encoding_DefaultXml => Some(CloseSecureChannelRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSecureChannelRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CloseSecureChannelResponse_Encoding_DefaultXml => Some(CloseSecureChannelResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSecureChannelResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::SignedSoftwareCertificate_Encoding_DefaultXml => Some(SignedSoftwareCertificate::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SignedSoftwareCertificate_Encoding_DefaultBinary, &v))),
+        ObjectId::SignatureData_Encoding_DefaultXml => Some(SignatureData::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SignatureData_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateSessionRequest_Encoding_DefaultXml => Some(CreateSessionRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSessionRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateSessionResponse_Encoding_DefaultXml => Some(CreateSessionResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSessionResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::UserIdentityToken_Encoding_DefaultXml => Some(UserIdentityToken::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UserIdentityToken_Encoding_DefaultBinary, &v))),
+        ObjectId::ActivateSessionRequest_Encoding_DefaultXml => Some(ActivateSessionRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ActivateSessionRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::ActivateSessionResponse_Encoding_DefaultXml => Some(ActivateSessionResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ActivateSessionResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::CloseSessionRequest_Encoding_DefaultXml => Some(CloseSessionRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSessionRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CloseSessionResponse_Encoding_DefaultXml => Some(CloseSessionResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CloseSessionResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::CancelRequest_Encoding_DefaultXml => Some(CancelRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CancelRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CancelResponse_Encoding_DefaultXml => Some(CancelResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CancelResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::NodeAttributes_Encoding_DefaultXml => Some(NodeAttributes::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeAttributes_Encoding_DefaultBinary, &v))),
+        ObjectId::AddNodesItem_Encoding_DefaultXml => Some(AddNodesItem::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesItem_Encoding_DefaultBinary, &v))),
+        ObjectId::AddNodesResult_Encoding_DefaultXml => Some(AddNodesResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesResult_Encoding_DefaultBinary, &v))),
+        ObjectId::AddNodesRequest_Encoding_DefaultXml => Some(AddNodesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::AddNodesResponse_Encoding_DefaultXml => Some(AddNodesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddNodesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::AddReferencesItem_Encoding_DefaultXml => Some(AddReferencesItem::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesItem_Encoding_DefaultBinary, &v))),
+        ObjectId::AddReferencesRequest_Encoding_DefaultXml => Some(AddReferencesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::AddReferencesResponse_Encoding_DefaultXml => Some(AddReferencesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AddReferencesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteNodesItem_Encoding_DefaultXml => Some(DeleteNodesItem::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesItem_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteNodesRequest_Encoding_DefaultXml => Some(DeleteNodesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteNodesResponse_Encoding_DefaultXml => Some(DeleteNodesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteNodesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteReferencesItem_Encoding_DefaultXml => Some(DeleteReferencesItem::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesItem_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteReferencesRequest_Encoding_DefaultXml => Some(DeleteReferencesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteReferencesResponse_Encoding_DefaultXml => Some(DeleteReferencesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::ViewDescription_Encoding_DefaultXml => Some(ViewDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ViewDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseDescription_Encoding_DefaultXml => Some(BrowseDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::ReferenceDescription_Encoding_DefaultXml => Some(ReferenceDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReferenceDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseResult_Encoding_DefaultXml => Some(BrowseResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseResult_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseRequest_Encoding_DefaultXml => Some(BrowseRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseResponse_Encoding_DefaultXml => Some(BrowseResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseNextRequest_Encoding_DefaultXml => Some(BrowseNextRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseNextRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowseNextResponse_Encoding_DefaultXml => Some(BrowseNextResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowseNextResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::RelativePathElement_Encoding_DefaultXml => Some(RelativePathElement::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RelativePathElement_Encoding_DefaultBinary, &v))),
+        ObjectId::RelativePath_Encoding_DefaultXml => Some(RelativePath::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RelativePath_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowsePath_Encoding_DefaultXml => Some(BrowsePath::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePath_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowsePathTarget_Encoding_DefaultXml => Some(BrowsePathTarget::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePathTarget_Encoding_DefaultBinary, &v))),
+        ObjectId::BrowsePathResult_Encoding_DefaultXml => Some(BrowsePathResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BrowsePathResult_Encoding_DefaultBinary, &v))),
+        ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultXml => Some(TranslateBrowsePathsToNodeIdsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultXml => Some(TranslateBrowsePathsToNodeIdsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::RegisterNodesRequest_Encoding_DefaultXml => Some(RegisterNodesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterNodesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::RegisterNodesResponse_Encoding_DefaultXml => Some(RegisterNodesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RegisterNodesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::UnregisterNodesRequest_Encoding_DefaultXml => Some(UnregisterNodesRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::UnregisterNodesResponse_Encoding_DefaultXml => Some(UnregisterNodesResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::EndpointConfiguration_Encoding_DefaultXml => Some(EndpointConfiguration::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointConfiguration_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryDataDescription_Encoding_DefaultXml => Some(QueryDataDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryDataDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::NodeTypeDescription_Encoding_DefaultXml => Some(NodeTypeDescription::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeTypeDescription_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryDataSet_Encoding_DefaultXml => Some(QueryDataSet::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryDataSet_Encoding_DefaultBinary, &v))),
+        ObjectId::NodeReference_Encoding_DefaultXml => Some(NodeReference::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NodeReference_Encoding_DefaultBinary, &v))),
+        ObjectId::ContentFilterElement_Encoding_DefaultXml => Some(ContentFilterElement::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterElement_Encoding_DefaultBinary, &v))),
+        ObjectId::ContentFilter_Encoding_DefaultXml => Some(ContentFilter::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilter_Encoding_DefaultBinary, &v))),
+        ObjectId::FilterOperand_Encoding_DefaultXml => Some(FilterOperand::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::FilterOperand_Encoding_DefaultBinary, &v))),
+        ObjectId::ContentFilterElementResult_Encoding_DefaultXml => Some(ContentFilterElementResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterElementResult_Encoding_DefaultBinary, &v))),
+        ObjectId::ContentFilterResult_Encoding_DefaultXml => Some(ContentFilterResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ContentFilterResult_Encoding_DefaultBinary, &v))),
+        ObjectId::ParsingResult_Encoding_DefaultXml => Some(ParsingResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ParsingResult_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryFirstRequest_Encoding_DefaultXml => Some(QueryFirstRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryFirstRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryFirstResponse_Encoding_DefaultXml => Some(QueryFirstResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryFirstResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryNextRequest_Encoding_DefaultXml => Some(QueryNextRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryNextRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::QueryNextResponse_Encoding_DefaultXml => Some(QueryNextResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::QueryNextResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::ReadValueId_Encoding_DefaultXml => Some(ReadValueId::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadValueId_Encoding_DefaultBinary, &v))),
+        ObjectId::ReadRequest_Encoding_DefaultXml => Some(ReadRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::ReadResponse_Encoding_DefaultXml => Some(ReadResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ReadResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryReadValueId_Encoding_DefaultXml => Some(HistoryReadValueId::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadValueId_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryReadResult_Encoding_DefaultXml => Some(HistoryReadResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadResult_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryData_Encoding_DefaultXml => Some(HistoryData::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryData_Encoding_DefaultBinary, &v))),
+        ObjectId::ModificationInfo_Encoding_DefaultXml => Some(ModificationInfo::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModificationInfo_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryReadRequest_Encoding_DefaultXml => Some(HistoryReadRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryReadResponse_Encoding_DefaultXml => Some(HistoryReadResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryReadResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::WriteValue_Encoding_DefaultXml => Some(WriteValue::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteValue_Encoding_DefaultBinary, &v))),
+        ObjectId::WriteRequest_Encoding_DefaultXml => Some(WriteRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::WriteResponse_Encoding_DefaultXml => Some(WriteResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::WriteResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryUpdateResult_Encoding_DefaultXml => Some(HistoryUpdateResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateResult_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryUpdateRequest_Encoding_DefaultXml => Some(HistoryUpdateRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::HistoryUpdateResponse_Encoding_DefaultXml => Some(HistoryUpdateResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::CallMethodRequest_Encoding_DefaultXml => Some(CallMethodRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallMethodRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CallMethodResult_Encoding_DefaultXml => Some(CallMethodResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallMethodResult_Encoding_DefaultBinary, &v))),
+        ObjectId::CallRequest_Encoding_DefaultXml => Some(CallRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CallResponse_Encoding_DefaultXml => Some(CallResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CallResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoringFilter_Encoding_DefaultXml => Some(MonitoringFilter::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringFilter_Encoding_DefaultBinary, &v))),
+        ObjectId::AggregateConfiguration_Encoding_DefaultXml => Some(AggregateConfiguration::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::AggregateConfiguration_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoringFilterResult_Encoding_DefaultXml => Some(MonitoringFilterResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringFilterResult_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoringParameters_Encoding_DefaultXml => Some(MonitoringParameters::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoringParameters_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoredItemCreateRequest_Encoding_DefaultXml => Some(MonitoredItemCreateRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemCreateRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoredItemCreateResult_Encoding_DefaultXml => Some(MonitoredItemCreateResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemCreateResult_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultXml => Some(CreateMonitoredItemsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultXml => Some(CreateMonitoredItemsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoredItemModifyRequest_Encoding_DefaultXml => Some(MonitoredItemModifyRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemModifyRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoredItemModifyResult_Encoding_DefaultXml => Some(MonitoredItemModifyResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemModifyResult_Encoding_DefaultBinary, &v))),
+        ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultXml => Some(ModifyMonitoredItemsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifyMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultXml => Some(ModifyMonitoredItemsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifyMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::SetMonitoringModeRequest_Encoding_DefaultXml => Some(SetMonitoringModeRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::SetMonitoringModeResponse_Encoding_DefaultXml => Some(SetMonitoringModeResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::SetTriggeringRequest_Encoding_DefaultXml => Some(SetTriggeringRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetTriggeringRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::SetTriggeringResponse_Encoding_DefaultXml => Some(SetTriggeringResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetTriggeringResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultXml => Some(DeleteMonitoredItemsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteMonitoredItemsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultXml => Some(DeleteMonitoredItemsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateSubscriptionRequest_Encoding_DefaultXml => Some(CreateSubscriptionRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::CreateSubscriptionResponse_Encoding_DefaultXml => Some(CreateSubscriptionResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::CreateSubscriptionResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::ModifySubscriptionRequest_Encoding_DefaultXml => Some(ModifySubscriptionRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifySubscriptionRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::ModifySubscriptionResponse_Encoding_DefaultXml => Some(ModifySubscriptionResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModifySubscriptionResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::SetPublishingModeRequest_Encoding_DefaultXml => Some(SetPublishingModeRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetPublishingModeRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::SetPublishingModeResponse_Encoding_DefaultXml => Some(SetPublishingModeResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SetPublishingModeResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::NotificationMessage_Encoding_DefaultXml => Some(NotificationMessage::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NotificationMessage_Encoding_DefaultBinary, &v))),
+        ObjectId::NotificationData_Encoding_DefaultXml => Some(NotificationData::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NotificationData_Encoding_DefaultBinary, &v))),
+        ObjectId::MonitoredItemNotification_Encoding_DefaultXml => Some(MonitoredItemNotification::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::MonitoredItemNotification_Encoding_DefaultBinary, &v))),
+        ObjectId::EventFieldList_Encoding_DefaultXml => Some(EventFieldList::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EventFieldList_Encoding_DefaultBinary, &v))),
+        ObjectId::SubscriptionAcknowledgement_Encoding_DefaultXml => Some(SubscriptionAcknowledgement::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SubscriptionAcknowledgement_Encoding_DefaultBinary, &v))),
+        ObjectId::PublishRequest_Encoding_DefaultXml => Some(PublishRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::PublishRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::PublishResponse_Encoding_DefaultXml => Some(PublishResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::PublishResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::RepublishRequest_Encoding_DefaultXml => Some(RepublishRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RepublishRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::RepublishResponse_Encoding_DefaultXml => Some(RepublishResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::RepublishResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::TransferResult_Encoding_DefaultXml => Some(TransferResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferResult_Encoding_DefaultBinary, &v))),
+        ObjectId::TransferSubscriptionsRequest_Encoding_DefaultXml => Some(TransferSubscriptionsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::TransferSubscriptionsResponse_Encoding_DefaultXml => Some(TransferSubscriptionsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultXml => Some(DeleteSubscriptionsRequest::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteSubscriptionsRequest_Encoding_DefaultBinary, &v))),
+        ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultXml => Some(DeleteSubscriptionsResponse::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DeleteSubscriptionsResponse_Encoding_DefaultBinary, &v))),
+        ObjectId::BuildInfo_Encoding_DefaultXml => Some(BuildInfo::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::BuildInfo_Encoding_DefaultBinary, &v))),
+        ObjectId::EndpointUrlListDataType_Encoding_DefaultXml => Some(EndpointUrlListDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EndpointUrlListDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::NetworkGroupDataType_Encoding_DefaultXml => Some(NetworkGroupDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::NetworkGroupDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultXml => Some(SamplingIntervalDiagnosticsDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SamplingIntervalDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultXml => Some(ServerDiagnosticsSummaryDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServerDiagnosticsSummaryDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::SessionDiagnosticsDataType_Encoding_DefaultXml => Some(SessionDiagnosticsDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SessionDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultXml => Some(SessionSecurityDiagnosticsDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SessionSecurityDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::ServiceCounterDataType_Encoding_DefaultXml => Some(ServiceCounterDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ServiceCounterDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::StatusResult_Encoding_DefaultXml => Some(StatusResult::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::StatusResult_Encoding_DefaultBinary, &v))),
+        ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultXml => Some(SubscriptionDiagnosticsDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::SubscriptionDiagnosticsDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::ModelChangeStructureDataType_Encoding_DefaultXml => Some(ModelChangeStructureDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ModelChangeStructureDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::Range_Encoding_DefaultXml => Some(Range::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Range_Encoding_DefaultBinary, &v))),
+        ObjectId::EUInformation_Encoding_DefaultXml => Some(EUInformation::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::EUInformation_Encoding_DefaultBinary, &v))),
+        ObjectId::ComplexNumberType_Encoding_DefaultXml => Some(ComplexNumberType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ComplexNumberType_Encoding_DefaultBinary, &v))),
+        ObjectId::DoubleComplexNumberType_Encoding_DefaultXml => Some(DoubleComplexNumberType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::DoubleComplexNumberType_Encoding_DefaultBinary, &v))),
+        ObjectId::XVType_Encoding_DefaultXml => Some(XVType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::XVType_Encoding_DefaultBinary, &v))),
+        ObjectId::ProgramDiagnosticDataType_Encoding_DefaultXml => Some(ProgramDiagnosticDataType::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::ProgramDiagnosticDataType_Encoding_DefaultBinary, &v))),
+        ObjectId::Annotation_Encoding_DefaultXml => Some(Annotation::decode_xml(Some(body), decoding_limits).map(|v| ExtensionObject::from_encodable(ObjectId::Annotation_Encoding_DefaultBinary, &v))),
+        _ => None
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -55,3 +57,54 @@ impl BinaryEncoder<ModelChangeStructureDataType> for ModelChangeStructureDataTyp
         })
     }
 }
+
+impl JsonEncoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Affected", self.affected.encode_json(context));
+        insert_json_field(&mut fields, "AffectedType", self.affected_type.encode_json(context));
+        insert_json_field(&mut fields, "Verb", self.verb.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let affected = NodeId::decode_json(json_field(fields, "Affected"), decoding_limits)?;
+        let affected_type = NodeId::decode_json(json_field(fields, "AffectedType"), decoding_limits)?;
+        let verb = u8::decode_json(json_field(fields, "Verb"), decoding_limits)?;
+        Ok(ModelChangeStructureDataType {
+            affected,
+            affected_type,
+            verb,
+        })
+    }
+}
+
+impl XmlEncoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.affected.encode_xml("Affected"));
+        element.add_child(self.affected_type.encode_xml("AffectedType"));
+        element.add_child(self.verb.encode_xml("Verb"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ModelChangeStructureDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let affected = NodeId::decode_xml(xml_child(element, "Affected"), decoding_limits)?;
+        let affected_type = NodeId::decode_xml(xml_child(element, "AffectedType"), decoding_limits)?;
+        let verb = u8::decode_xml(xml_child(element, "Verb"), decoding_limits)?;
+        Ok(ModelChangeStructureDataType {
+            affected,
+            affected_type,
+            verb,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    service_types::impls::MessageInfo,
+    node_ids::ObjectId,
+    date_time::DateTime,
+    service_types::enums::HistoryUpdateType,
+    string::UAString,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModificationInfo {
+    pub modification_time: DateTime,
+    pub update_type: HistoryUpdateType,
+    pub user_name: UAString,
+}
+
+impl MessageInfo for ModificationInfo {
+    fn object_id(&self) -> ObjectId {
+        ObjectId::ModificationInfo_Encoding_DefaultBinary
+    }
+}
+
+impl BinaryEncoder<ModificationInfo> for ModificationInfo {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.modification_time.byte_len();
+        size += self.update_type.byte_len();
+        size += self.user_name.byte_len();
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.modification_time.encode(stream)?;
+        size += self.update_type.encode(stream)?;
+        size += self.user_name.encode(stream)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let modification_time = DateTime::decode(stream, decoding_limits)?;
+        let update_type = HistoryUpdateType::decode(stream, decoding_limits)?;
+        let user_name = UAString::decode(stream, decoding_limits)?;
+        Ok(ModificationInfo {
+            modification_time,
+            update_type,
+            user_name,
+        })
+    }
+}
+
+impl JsonEncoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ModificationTime", self.modification_time.encode_json(context));
+        insert_json_field(&mut fields, "UpdateType", self.update_type.encode_json(context));
+        insert_json_field(&mut fields, "UserName", self.user_name.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let modification_time = DateTime::decode_json(json_field(fields, "ModificationTime"), decoding_limits)?;
+        let update_type = HistoryUpdateType::decode_json(json_field(fields, "UpdateType"), decoding_limits)?;
+        let user_name = UAString::decode_json(json_field(fields, "UserName"), decoding_limits)?;
+        Ok(ModificationInfo {
+            modification_time,
+            update_type,
+            user_name,
+        })
+    }
+}
+
+impl XmlEncoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.modification_time.encode_xml("ModificationTime"));
+        element.add_child(self.update_type.encode_xml("UpdateType"));
+        element.add_child(self.user_name.encode_xml("UserName"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ModificationInfo {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let modification_time = DateTime::decode_xml(xml_child(element, "ModificationTime"), decoding_limits)?;
+        let update_type = HistoryUpdateType::decode_xml(xml_child(element, "UpdateType"), decoding_limits)?;
+        let user_name = UAString::decode_xml(xml_child(element, "UserName"), decoding_limits)?;
+        Ok(ModificationInfo {
+            modification_time,
+            update_type,
+            user_name,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<ModifyMonitoredItemsRequest> for ModifyMonitoredItemsRequest
         })
     }
 }
+
+impl JsonEncoder for ModifyMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "TimestampsToReturn", self.timestamps_to_return.encode_json(context));
+        insert_json_field(&mut fields, "ItemsToModify", encode_json_array(&self.items_to_modify, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ModifyMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_json(json_field(fields, "TimestampsToReturn"), decoding_limits)?;
+        let items_to_modify: Option<Vec<MonitoredItemModifyRequest>> = decode_json_array(json_field(fields, "ItemsToModify"), decoding_limits)?;
+        Ok(ModifyMonitoredItemsRequest {
+            request_header,
+            subscription_id,
+            timestamps_to_return,
+            items_to_modify,
+        })
+    }
+}
+
+impl XmlEncoder for ModifyMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.timestamps_to_return.encode_xml("TimestampsToReturn"));
+        element.add_child(encode_xml_array("ItemsToModify", "MonitoredItemModifyRequest", &self.items_to_modify));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ModifyMonitoredItemsRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_xml(xml_child(element, "TimestampsToReturn"), decoding_limits)?;
+        let items_to_modify: Option<Vec<MonitoredItemModifyRequest>> = decode_xml_array(xml_child(element, "ItemsToModify"), decoding_limits)?;
+        Ok(ModifyMonitoredItemsRequest {
+            request_header,
+            subscription_id,
+            timestamps_to_return,
+            items_to_modify,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -75,3 +77,78 @@ impl BinaryEncoder<ModifySubscriptionRequest> for ModifySubscriptionRequest {
         })
     }
 }
+
+impl JsonEncoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "RequestedPublishingInterval", self.requested_publishing_interval.encode_json(context));
+        insert_json_field(&mut fields, "RequestedLifetimeCount", self.requested_lifetime_count.encode_json(context));
+        insert_json_field(&mut fields, "RequestedMaxKeepAliveCount", self.requested_max_keep_alive_count.encode_json(context));
+        insert_json_field(&mut fields, "MaxNotificationsPerPublish", self.max_notifications_per_publish.encode_json(context));
+        insert_json_field(&mut fields, "Priority", self.priority.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let requested_publishing_interval = f64::decode_json(json_field(fields, "RequestedPublishingInterval"), decoding_limits)?;
+        let requested_lifetime_count = u32::decode_json(json_field(fields, "RequestedLifetimeCount"), decoding_limits)?;
+        let requested_max_keep_alive_count = u32::decode_json(json_field(fields, "RequestedMaxKeepAliveCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_json(json_field(fields, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let priority = u8::decode_json(json_field(fields, "Priority"), decoding_limits)?;
+        Ok(ModifySubscriptionRequest {
+            request_header,
+            subscription_id,
+            requested_publishing_interval,
+            requested_lifetime_count,
+            requested_max_keep_alive_count,
+            max_notifications_per_publish,
+            priority,
+        })
+    }
+}
+
+impl XmlEncoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.requested_publishing_interval.encode_xml("RequestedPublishingInterval"));
+        element.add_child(self.requested_lifetime_count.encode_xml("RequestedLifetimeCount"));
+        element.add_child(self.requested_max_keep_alive_count.encode_xml("RequestedMaxKeepAliveCount"));
+        element.add_child(self.max_notifications_per_publish.encode_xml("MaxNotificationsPerPublish"));
+        element.add_child(self.priority.encode_xml("Priority"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ModifySubscriptionRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let requested_publishing_interval = f64::decode_xml(xml_child(element, "RequestedPublishingInterval"), decoding_limits)?;
+        let requested_lifetime_count = u32::decode_xml(xml_child(element, "RequestedLifetimeCount"), decoding_limits)?;
+        let requested_max_keep_alive_count = u32::decode_xml(xml_child(element, "RequestedMaxKeepAliveCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_xml(xml_child(element, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let priority = u8::decode_xml(xml_child(element, "Priority"), decoding_limits)?;
+        Ok(ModifySubscriptionRequest {
+            request_header,
+            subscription_id,
+            requested_publishing_interval,
+            requested_lifetime_count,
+            requested_max_keep_alive_count,
+            max_notifications_per_publish,
+            priority,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -60,3 +62,60 @@ impl BinaryEncoder<ModifySubscriptionResponse> for ModifySubscriptionResponse {
         })
     }
 }
+
+impl JsonEncoder for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "RevisedPublishingInterval", self.revised_publishing_interval.encode_json(context));
+        insert_json_field(&mut fields, "RevisedLifetimeCount", self.revised_lifetime_count.encode_json(context));
+        insert_json_field(&mut fields, "RevisedMaxKeepAliveCount", self.revised_max_keep_alive_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let revised_publishing_interval = f64::decode_json(json_field(fields, "RevisedPublishingInterval"), decoding_limits)?;
+        let revised_lifetime_count = u32::decode_json(json_field(fields, "RevisedLifetimeCount"), decoding_limits)?;
+        let revised_max_keep_alive_count = u32::decode_json(json_field(fields, "RevisedMaxKeepAliveCount"), decoding_limits)?;
+        Ok(ModifySubscriptionResponse {
+            response_header,
+            revised_publishing_interval,
+            revised_lifetime_count,
+            revised_max_keep_alive_count,
+        })
+    }
+}
+
+impl XmlEncoder for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(self.revised_publishing_interval.encode_xml("RevisedPublishingInterval"));
+        element.add_child(self.revised_lifetime_count.encode_xml("RevisedLifetimeCount"));
+        element.add_child(self.revised_max_keep_alive_count.encode_xml("RevisedMaxKeepAliveCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ModifySubscriptionResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let revised_publishing_interval = f64::decode_xml(xml_child(element, "RevisedPublishingInterval"), decoding_limits)?;
+        let revised_lifetime_count = u32::decode_xml(xml_child(element, "RevisedLifetimeCount"), decoding_limits)?;
+        let revised_max_keep_alive_count = u32::decode_xml(xml_child(element, "RevisedMaxKeepAliveCount"), decoding_limits)?;
+        Ok(ModifySubscriptionResponse {
+            response_header,
+            revised_publishing_interval,
+            revised_lifetime_count,
+            revised_max_keep_alive_count,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +59,54 @@ impl BinaryEncoder<MonitoredItemCreateRequest> for MonitoredItemCreateRequest {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ItemToMonitor", self.item_to_monitor.encode_json(context));
+        insert_json_field(&mut fields, "MonitoringMode", self.monitoring_mode.encode_json(context));
+        insert_json_field(&mut fields, "RequestedParameters", self.requested_parameters.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let item_to_monitor = ReadValueId::decode_json(json_field(fields, "ItemToMonitor"), decoding_limits)?;
+        let monitoring_mode = MonitoringMode::decode_json(json_field(fields, "MonitoringMode"), decoding_limits)?;
+        let requested_parameters = MonitoringParameters::decode_json(json_field(fields, "RequestedParameters"), decoding_limits)?;
+        Ok(MonitoredItemCreateRequest {
+            item_to_monitor,
+            monitoring_mode,
+            requested_parameters,
+        })
+    }
+}
+
+impl XmlEncoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.item_to_monitor.encode_xml("ItemToMonitor"));
+        element.add_child(self.monitoring_mode.encode_xml("MonitoringMode"));
+        element.add_child(self.requested_parameters.encode_xml("RequestedParameters"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MonitoredItemCreateRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let item_to_monitor = ReadValueId::decode_xml(xml_child(element, "ItemToMonitor"), decoding_limits)?;
+        let monitoring_mode = MonitoringMode::decode_xml(xml_child(element, "MonitoringMode"), decoding_limits)?;
+        let requested_parameters = MonitoringParameters::decode_xml(xml_child(element, "RequestedParameters"), decoding_limits)?;
+        Ok(MonitoredItemCreateRequest {
+            item_to_monitor,
+            monitoring_mode,
+            requested_parameters,
+        })
+    }
+}
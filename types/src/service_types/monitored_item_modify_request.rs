@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -50,3 +52,48 @@ impl BinaryEncoder<MonitoredItemModifyRequest> for MonitoredItemModifyRequest {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "MonitoredItemId", self.monitored_item_id.encode_json(context));
+        insert_json_field(&mut fields, "RequestedParameters", self.requested_parameters.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let monitored_item_id = u32::decode_json(json_field(fields, "MonitoredItemId"), decoding_limits)?;
+        let requested_parameters = MonitoringParameters::decode_json(json_field(fields, "RequestedParameters"), decoding_limits)?;
+        Ok(MonitoredItemModifyRequest {
+            monitored_item_id,
+            requested_parameters,
+        })
+    }
+}
+
+impl XmlEncoder for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.monitored_item_id.encode_xml("MonitoredItemId"));
+        element.add_child(self.requested_parameters.encode_xml("RequestedParameters"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MonitoredItemModifyRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let monitored_item_id = u32::decode_xml(xml_child(element, "MonitoredItemId"), decoding_limits)?;
+        let requested_parameters = MonitoringParameters::decode_xml(xml_child(element, "RequestedParameters"), decoding_limits)?;
+        Ok(MonitoredItemModifyRequest {
+            monitored_item_id,
+            requested_parameters,
+        })
+    }
+}
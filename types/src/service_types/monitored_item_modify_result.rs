@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +63,60 @@ impl BinaryEncoder<MonitoredItemModifyResult> for MonitoredItemModifyResult {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemModifyResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "RevisedSamplingInterval", self.revised_sampling_interval.encode_json(context));
+        insert_json_field(&mut fields, "RevisedQueueSize", self.revised_queue_size.encode_json(context));
+        insert_json_field(&mut fields, "FilterResult", self.filter_result.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MonitoredItemModifyResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let revised_sampling_interval = f64::decode_json(json_field(fields, "RevisedSamplingInterval"), decoding_limits)?;
+        let revised_queue_size = u32::decode_json(json_field(fields, "RevisedQueueSize"), decoding_limits)?;
+        let filter_result = ExtensionObject::decode_json(json_field(fields, "FilterResult"), decoding_limits)?;
+        Ok(MonitoredItemModifyResult {
+            status_code,
+            revised_sampling_interval,
+            revised_queue_size,
+            filter_result,
+        })
+    }
+}
+
+impl XmlEncoder for MonitoredItemModifyResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(self.revised_sampling_interval.encode_xml("RevisedSamplingInterval"));
+        element.add_child(self.revised_queue_size.encode_xml("RevisedQueueSize"));
+        element.add_child(self.filter_result.encode_xml("FilterResult"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MonitoredItemModifyResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let revised_sampling_interval = f64::decode_xml(xml_child(element, "RevisedSamplingInterval"), decoding_limits)?;
+        let revised_queue_size = u32::decode_xml(xml_child(element, "RevisedQueueSize"), decoding_limits)?;
+        let filter_result = ExtensionObject::decode_xml(xml_child(element, "FilterResult"), decoding_limits)?;
+        Ok(MonitoredItemModifyResult {
+            status_code,
+            revised_sampling_interval,
+            revised_queue_size,
+            filter_result,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -50,3 +52,48 @@ impl BinaryEncoder<MonitoredItemNotification> for MonitoredItemNotification {
         })
     }
 }
+
+impl JsonEncoder for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ClientHandle", self.client_handle.encode_json(context));
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let client_handle = u32::decode_json(json_field(fields, "ClientHandle"), decoding_limits)?;
+        let value = DataValue::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        Ok(MonitoredItemNotification {
+            client_handle,
+            value,
+        })
+    }
+}
+
+impl XmlEncoder for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.client_handle.encode_xml("ClientHandle"));
+        element.add_child(self.value.encode_xml("Value"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MonitoredItemNotification {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let client_handle = u32::decode_xml(xml_child(element, "ClientHandle"), decoding_limits)?;
+        let value = DataValue::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        Ok(MonitoredItemNotification {
+            client_handle,
+            value,
+        })
+    }
+}
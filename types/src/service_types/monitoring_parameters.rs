@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -65,3 +67,66 @@ impl BinaryEncoder<MonitoringParameters> for MonitoringParameters {
         })
     }
 }
+
+impl JsonEncoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ClientHandle", self.client_handle.encode_json(context));
+        insert_json_field(&mut fields, "SamplingInterval", self.sampling_interval.encode_json(context));
+        insert_json_field(&mut fields, "Filter", self.filter.encode_json(context));
+        insert_json_field(&mut fields, "QueueSize", self.queue_size.encode_json(context));
+        insert_json_field(&mut fields, "DiscardOldest", self.discard_oldest.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let client_handle = u32::decode_json(json_field(fields, "ClientHandle"), decoding_limits)?;
+        let sampling_interval = f64::decode_json(json_field(fields, "SamplingInterval"), decoding_limits)?;
+        let filter = ExtensionObject::decode_json(json_field(fields, "Filter"), decoding_limits)?;
+        let queue_size = u32::decode_json(json_field(fields, "QueueSize"), decoding_limits)?;
+        let discard_oldest = bool::decode_json(json_field(fields, "DiscardOldest"), decoding_limits)?;
+        Ok(MonitoringParameters {
+            client_handle,
+            sampling_interval,
+            filter,
+            queue_size,
+            discard_oldest,
+        })
+    }
+}
+
+impl XmlEncoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.client_handle.encode_xml("ClientHandle"));
+        element.add_child(self.sampling_interval.encode_xml("SamplingInterval"));
+        element.add_child(self.filter.encode_xml("Filter"));
+        element.add_child(self.queue_size.encode_xml("QueueSize"));
+        element.add_child(self.discard_oldest.encode_xml("DiscardOldest"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for MonitoringParameters {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let client_handle = u32::decode_xml(xml_child(element, "ClientHandle"), decoding_limits)?;
+        let sampling_interval = f64::decode_xml(xml_child(element, "SamplingInterval"), decoding_limits)?;
+        let filter = ExtensionObject::decode_xml(xml_child(element, "Filter"), decoding_limits)?;
+        let queue_size = u32::decode_xml(xml_child(element, "QueueSize"), decoding_limits)?;
+        let discard_oldest = bool::decode_xml(xml_child(element, "DiscardOldest"), decoding_limits)?;
+        Ok(MonitoringParameters {
+            client_handle,
+            sampling_interval,
+            filter,
+            queue_size,
+            discard_oldest,
+        })
+    }
+}
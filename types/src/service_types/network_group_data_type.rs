@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<NetworkGroupDataType> for NetworkGroupDataType {
         })
     }
 }
+
+impl JsonEncoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ServerUri", self.server_uri.encode_json(context));
+        insert_json_field(&mut fields, "NetworkPaths", encode_json_array(&self.network_paths, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let server_uri = UAString::decode_json(json_field(fields, "ServerUri"), decoding_limits)?;
+        let network_paths: Option<Vec<EndpointUrlListDataType>> = decode_json_array(json_field(fields, "NetworkPaths"), decoding_limits)?;
+        Ok(NetworkGroupDataType {
+            server_uri,
+            network_paths,
+        })
+    }
+}
+
+impl XmlEncoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.server_uri.encode_xml("ServerUri"));
+        element.add_child(encode_xml_array("NetworkPaths", "EndpointUrlListDataType", &self.network_paths));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for NetworkGroupDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let server_uri = UAString::decode_xml(xml_child(element, "ServerUri"), decoding_limits)?;
+        let network_paths: Option<Vec<EndpointUrlListDataType>> = decode_xml_array(xml_child(element, "NetworkPaths"), decoding_limits)?;
+        Ok(NetworkGroupDataType {
+            server_uri,
+            network_paths,
+        })
+    }
+}
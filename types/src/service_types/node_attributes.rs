@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -66,3 +68,66 @@ impl BinaryEncoder<NodeAttributes> for NodeAttributes {
         })
     }
 }
+
+impl JsonEncoder for NodeAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SpecifiedAttributes", self.specified_attributes.encode_json(context));
+        insert_json_field(&mut fields, "DisplayName", self.display_name.encode_json(context));
+        insert_json_field(&mut fields, "Description", self.description.encode_json(context));
+        insert_json_field(&mut fields, "WriteMask", self.write_mask.encode_json(context));
+        insert_json_field(&mut fields, "UserWriteMask", self.user_write_mask.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for NodeAttributes {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let specified_attributes = u32::decode_json(json_field(fields, "SpecifiedAttributes"), decoding_limits)?;
+        let display_name = LocalizedText::decode_json(json_field(fields, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_json(json_field(fields, "Description"), decoding_limits)?;
+        let write_mask = u32::decode_json(json_field(fields, "WriteMask"), decoding_limits)?;
+        let user_write_mask = u32::decode_json(json_field(fields, "UserWriteMask"), decoding_limits)?;
+        Ok(NodeAttributes {
+            specified_attributes,
+            display_name,
+            description,
+            write_mask,
+            user_write_mask,
+        })
+    }
+}
+
+impl XmlEncoder for NodeAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.specified_attributes.encode_xml("SpecifiedAttributes"));
+        element.add_child(self.display_name.encode_xml("DisplayName"));
+        element.add_child(self.description.encode_xml("Description"));
+        element.add_child(self.write_mask.encode_xml("WriteMask"));
+        element.add_child(self.user_write_mask.encode_xml("UserWriteMask"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for NodeAttributes {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let specified_attributes = u32::decode_xml(xml_child(element, "SpecifiedAttributes"), decoding_limits)?;
+        let display_name = LocalizedText::decode_xml(xml_child(element, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_xml(xml_child(element, "Description"), decoding_limits)?;
+        let write_mask = u32::decode_xml(xml_child(element, "WriteMask"), decoding_limits)?;
+        let user_write_mask = u32::decode_xml(xml_child(element, "UserWriteMask"), decoding_limits)?;
+        Ok(NodeAttributes {
+            specified_attributes,
+            display_name,
+            description,
+            write_mask,
+            user_write_mask,
+        })
+    }
+}
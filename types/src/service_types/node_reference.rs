@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -60,3 +62,60 @@ impl BinaryEncoder<NodeReference> for NodeReference {
         })
     }
 }
+
+impl JsonEncoder for NodeReference {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IsForward", self.is_forward.encode_json(context));
+        insert_json_field(&mut fields, "ReferencedNodeIds", encode_json_array(&self.referenced_node_ids, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for NodeReference {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_json(json_field(fields, "IsForward"), decoding_limits)?;
+        let referenced_node_ids: Option<Vec<NodeId>> = decode_json_array(json_field(fields, "ReferencedNodeIds"), decoding_limits)?;
+        Ok(NodeReference {
+            node_id,
+            reference_type_id,
+            is_forward,
+            referenced_node_ids,
+        })
+    }
+}
+
+impl XmlEncoder for NodeReference {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.is_forward.encode_xml("IsForward"));
+        element.add_child(encode_xml_array("ReferencedNodeIds", "NodeId", &self.referenced_node_ids));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for NodeReference {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_xml(xml_child(element, "IsForward"), decoding_limits)?;
+        let referenced_node_ids: Option<Vec<NodeId>> = decode_xml_array(xml_child(element, "ReferencedNodeIds"), decoding_limits)?;
+        Ok(NodeReference {
+            node_id,
+            reference_type_id,
+            is_forward,
+            referenced_node_ids,
+        })
+    }
+}
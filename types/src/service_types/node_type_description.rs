@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<NodeTypeDescription> for NodeTypeDescription {
         })
     }
 }
+
+impl JsonEncoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "TypeDefinitionNode", self.type_definition_node.encode_json(context));
+        insert_json_field(&mut fields, "IncludeSubTypes", self.include_sub_types.encode_json(context));
+        insert_json_field(&mut fields, "DataToReturn", encode_json_array(&self.data_to_return, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let type_definition_node = ExpandedNodeId::decode_json(json_field(fields, "TypeDefinitionNode"), decoding_limits)?;
+        let include_sub_types = bool::decode_json(json_field(fields, "IncludeSubTypes"), decoding_limits)?;
+        let data_to_return: Option<Vec<QueryDataDescription>> = decode_json_array(json_field(fields, "DataToReturn"), decoding_limits)?;
+        Ok(NodeTypeDescription {
+            type_definition_node,
+            include_sub_types,
+            data_to_return,
+        })
+    }
+}
+
+impl XmlEncoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.type_definition_node.encode_xml("TypeDefinitionNode"));
+        element.add_child(self.include_sub_types.encode_xml("IncludeSubTypes"));
+        element.add_child(encode_xml_array("DataToReturn", "QueryDataDescription", &self.data_to_return));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for NodeTypeDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let type_definition_node = ExpandedNodeId::decode_xml(xml_child(element, "TypeDefinitionNode"), decoding_limits)?;
+        let include_sub_types = bool::decode_xml(xml_child(element, "IncludeSubTypes"), decoding_limits)?;
+        let data_to_return: Option<Vec<QueryDataDescription>> = decode_xml_array(xml_child(element, "DataToReturn"), decoding_limits)?;
+        Ok(NodeTypeDescription {
+            type_definition_node,
+            include_sub_types,
+            data_to_return,
+        })
+    }
+}
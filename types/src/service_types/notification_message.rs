@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<NotificationMessage> for NotificationMessage {
         })
     }
 }
+
+impl JsonEncoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SequenceNumber", self.sequence_number.encode_json(context));
+        insert_json_field(&mut fields, "PublishTime", self.publish_time.encode_json(context));
+        insert_json_field(&mut fields, "NotificationData", encode_json_array(&self.notification_data, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let sequence_number = u32::decode_json(json_field(fields, "SequenceNumber"), decoding_limits)?;
+        let publish_time = DateTime::decode_json(json_field(fields, "PublishTime"), decoding_limits)?;
+        let notification_data: Option<Vec<ExtensionObject>> = decode_json_array(json_field(fields, "NotificationData"), decoding_limits)?;
+        Ok(NotificationMessage {
+            sequence_number,
+            publish_time,
+            notification_data,
+        })
+    }
+}
+
+impl XmlEncoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.sequence_number.encode_xml("SequenceNumber"));
+        element.add_child(self.publish_time.encode_xml("PublishTime"));
+        element.add_child(encode_xml_array("NotificationData", "ExtensionObject", &self.notification_data));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for NotificationMessage {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let sequence_number = u32::decode_xml(xml_child(element, "SequenceNumber"), decoding_limits)?;
+        let publish_time = DateTime::decode_xml(xml_child(element, "PublishTime"), decoding_limits)?;
+        let notification_data: Option<Vec<ExtensionObject>> = decode_xml_array(xml_child(element, "NotificationData"), decoding_limits)?;
+        Ok(NotificationMessage {
+            sequence_number,
+            publish_time,
+            notification_data,
+        })
+    }
+}
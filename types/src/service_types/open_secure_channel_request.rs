@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -74,3 +76,72 @@ impl BinaryEncoder<OpenSecureChannelRequest> for OpenSecureChannelRequest {
         })
     }
 }
+
+impl JsonEncoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ClientProtocolVersion", self.client_protocol_version.encode_json(context));
+        insert_json_field(&mut fields, "RequestType", self.request_type.encode_json(context));
+        insert_json_field(&mut fields, "SecurityMode", self.security_mode.encode_json(context));
+        insert_json_field(&mut fields, "ClientNonce", self.client_nonce.encode_json(context));
+        insert_json_field(&mut fields, "RequestedLifetime", self.requested_lifetime.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let client_protocol_version = u32::decode_json(json_field(fields, "ClientProtocolVersion"), decoding_limits)?;
+        let request_type = SecurityTokenRequestType::decode_json(json_field(fields, "RequestType"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_json(json_field(fields, "SecurityMode"), decoding_limits)?;
+        let client_nonce = ByteString::decode_json(json_field(fields, "ClientNonce"), decoding_limits)?;
+        let requested_lifetime = u32::decode_json(json_field(fields, "RequestedLifetime"), decoding_limits)?;
+        Ok(OpenSecureChannelRequest {
+            request_header,
+            client_protocol_version,
+            request_type,
+            security_mode,
+            client_nonce,
+            requested_lifetime,
+        })
+    }
+}
+
+impl XmlEncoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.client_protocol_version.encode_xml("ClientProtocolVersion"));
+        element.add_child(self.request_type.encode_xml("RequestType"));
+        element.add_child(self.security_mode.encode_xml("SecurityMode"));
+        element.add_child(self.client_nonce.encode_xml("ClientNonce"));
+        element.add_child(self.requested_lifetime.encode_xml("RequestedLifetime"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for OpenSecureChannelRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let client_protocol_version = u32::decode_xml(xml_child(element, "ClientProtocolVersion"), decoding_limits)?;
+        let request_type = SecurityTokenRequestType::decode_xml(xml_child(element, "RequestType"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_xml(xml_child(element, "SecurityMode"), decoding_limits)?;
+        let client_nonce = ByteString::decode_xml(xml_child(element, "ClientNonce"), decoding_limits)?;
+        let requested_lifetime = u32::decode_xml(xml_child(element, "RequestedLifetime"), decoding_limits)?;
+        Ok(OpenSecureChannelRequest {
+            request_header,
+            client_protocol_version,
+            request_type,
+            security_mode,
+            client_nonce,
+            requested_lifetime,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -63,3 +65,60 @@ impl BinaryEncoder<OpenSecureChannelResponse> for OpenSecureChannelResponse {
         })
     }
 }
+
+impl JsonEncoder for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "ServerProtocolVersion", self.server_protocol_version.encode_json(context));
+        insert_json_field(&mut fields, "SecurityToken", self.security_token.encode_json(context));
+        insert_json_field(&mut fields, "ServerNonce", self.server_nonce.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let server_protocol_version = u32::decode_json(json_field(fields, "ServerProtocolVersion"), decoding_limits)?;
+        let security_token = ChannelSecurityToken::decode_json(json_field(fields, "SecurityToken"), decoding_limits)?;
+        let server_nonce = ByteString::decode_json(json_field(fields, "ServerNonce"), decoding_limits)?;
+        Ok(OpenSecureChannelResponse {
+            response_header,
+            server_protocol_version,
+            security_token,
+            server_nonce,
+        })
+    }
+}
+
+impl XmlEncoder for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(self.server_protocol_version.encode_xml("ServerProtocolVersion"));
+        element.add_child(self.security_token.encode_xml("SecurityToken"));
+        element.add_child(self.server_nonce.encode_xml("ServerNonce"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for OpenSecureChannelResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let server_protocol_version = u32::decode_xml(xml_child(element, "ServerProtocolVersion"), decoding_limits)?;
+        let security_token = ChannelSecurityToken::decode_xml(xml_child(element, "SecurityToken"), decoding_limits)?;
+        let server_nonce = ByteString::decode_xml(xml_child(element, "ServerNonce"), decoding_limits)?;
+        Ok(OpenSecureChannelResponse {
+            response_header,
+            server_protocol_version,
+            security_token,
+            server_nonce,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<OptionSet> for OptionSet {
         })
     }
 }
+
+impl JsonEncoder for OptionSet {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        insert_json_field(&mut fields, "ValidBits", self.valid_bits.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for OptionSet {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let value = ByteString::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        let valid_bits = ByteString::decode_json(json_field(fields, "ValidBits"), decoding_limits)?;
+        Ok(OptionSet {
+            value,
+            valid_bits,
+        })
+    }
+}
+
+impl XmlEncoder for OptionSet {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.value.encode_xml("Value"));
+        element.add_child(self.valid_bits.encode_xml("ValidBits"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for OptionSet {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = ByteString::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        let valid_bits = ByteString::decode_xml(xml_child(element, "ValidBits"), decoding_limits)?;
+        Ok(OptionSet {
+            value,
+            valid_bits,
+        })
+    }
+}
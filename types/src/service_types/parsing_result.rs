@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<ParsingResult> for ParsingResult {
         })
     }
 }
+
+impl JsonEncoder for ParsingResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "DataStatusCodes", encode_json_array(&self.data_status_codes, context));
+        insert_json_field(&mut fields, "DataDiagnosticInfos", encode_json_array(&self.data_diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ParsingResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let data_status_codes: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "DataStatusCodes"), decoding_limits)?;
+        let data_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DataDiagnosticInfos"), decoding_limits)?;
+        Ok(ParsingResult {
+            status_code,
+            data_status_codes,
+            data_diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for ParsingResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("DataStatusCodes", "StatusCode", &self.data_status_codes));
+        element.add_child(encode_xml_array("DataDiagnosticInfos", "DiagnosticInfo", &self.data_diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ParsingResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let data_status_codes: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "DataStatusCodes"), decoding_limits)?;
+        let data_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DataDiagnosticInfos"), decoding_limits)?;
+        Ok(ParsingResult {
+            status_code,
+            data_status_codes,
+            data_diagnostic_infos,
+        })
+    }
+}
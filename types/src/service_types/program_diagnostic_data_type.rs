@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -94,3 +96,96 @@ impl BinaryEncoder<ProgramDiagnosticDataType> for ProgramDiagnosticDataType {
         })
     }
 }
+
+impl JsonEncoder for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "CreateSessionId", self.create_session_id.encode_json(context));
+        insert_json_field(&mut fields, "CreateClientName", self.create_client_name.encode_json(context));
+        insert_json_field(&mut fields, "InvocationCreationTime", self.invocation_creation_time.encode_json(context));
+        insert_json_field(&mut fields, "LastTransitionTime", self.last_transition_time.encode_json(context));
+        insert_json_field(&mut fields, "LastMethodCall", self.last_method_call.encode_json(context));
+        insert_json_field(&mut fields, "LastMethodSessionId", self.last_method_session_id.encode_json(context));
+        insert_json_field(&mut fields, "LastMethodInputArguments", encode_json_array(&self.last_method_input_arguments, context));
+        insert_json_field(&mut fields, "LastMethodOutputArguments", encode_json_array(&self.last_method_output_arguments, context));
+        insert_json_field(&mut fields, "LastMethodCallTime", self.last_method_call_time.encode_json(context));
+        insert_json_field(&mut fields, "LastMethodReturnStatus", self.last_method_return_status.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let create_session_id = NodeId::decode_json(json_field(fields, "CreateSessionId"), decoding_limits)?;
+        let create_client_name = UAString::decode_json(json_field(fields, "CreateClientName"), decoding_limits)?;
+        let invocation_creation_time = DateTime::decode_json(json_field(fields, "InvocationCreationTime"), decoding_limits)?;
+        let last_transition_time = DateTime::decode_json(json_field(fields, "LastTransitionTime"), decoding_limits)?;
+        let last_method_call = UAString::decode_json(json_field(fields, "LastMethodCall"), decoding_limits)?;
+        let last_method_session_id = NodeId::decode_json(json_field(fields, "LastMethodSessionId"), decoding_limits)?;
+        let last_method_input_arguments: Option<Vec<Argument>> = decode_json_array(json_field(fields, "LastMethodInputArguments"), decoding_limits)?;
+        let last_method_output_arguments: Option<Vec<Argument>> = decode_json_array(json_field(fields, "LastMethodOutputArguments"), decoding_limits)?;
+        let last_method_call_time = DateTime::decode_json(json_field(fields, "LastMethodCallTime"), decoding_limits)?;
+        let last_method_return_status = StatusResult::decode_json(json_field(fields, "LastMethodReturnStatus"), decoding_limits)?;
+        Ok(ProgramDiagnosticDataType {
+            create_session_id,
+            create_client_name,
+            invocation_creation_time,
+            last_transition_time,
+            last_method_call,
+            last_method_session_id,
+            last_method_input_arguments,
+            last_method_output_arguments,
+            last_method_call_time,
+            last_method_return_status,
+        })
+    }
+}
+
+impl XmlEncoder for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.create_session_id.encode_xml("CreateSessionId"));
+        element.add_child(self.create_client_name.encode_xml("CreateClientName"));
+        element.add_child(self.invocation_creation_time.encode_xml("InvocationCreationTime"));
+        element.add_child(self.last_transition_time.encode_xml("LastTransitionTime"));
+        element.add_child(self.last_method_call.encode_xml("LastMethodCall"));
+        element.add_child(self.last_method_session_id.encode_xml("LastMethodSessionId"));
+        element.add_child(encode_xml_array("LastMethodInputArguments", "Argument", &self.last_method_input_arguments));
+        element.add_child(encode_xml_array("LastMethodOutputArguments", "Argument", &self.last_method_output_arguments));
+        element.add_child(self.last_method_call_time.encode_xml("LastMethodCallTime"));
+        element.add_child(self.last_method_return_status.encode_xml("LastMethodReturnStatus"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ProgramDiagnosticDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let create_session_id = NodeId::decode_xml(xml_child(element, "CreateSessionId"), decoding_limits)?;
+        let create_client_name = UAString::decode_xml(xml_child(element, "CreateClientName"), decoding_limits)?;
+        let invocation_creation_time = DateTime::decode_xml(xml_child(element, "InvocationCreationTime"), decoding_limits)?;
+        let last_transition_time = DateTime::decode_xml(xml_child(element, "LastTransitionTime"), decoding_limits)?;
+        let last_method_call = UAString::decode_xml(xml_child(element, "LastMethodCall"), decoding_limits)?;
+        let last_method_session_id = NodeId::decode_xml(xml_child(element, "LastMethodSessionId"), decoding_limits)?;
+        let last_method_input_arguments: Option<Vec<Argument>> = decode_xml_array(xml_child(element, "LastMethodInputArguments"), decoding_limits)?;
+        let last_method_output_arguments: Option<Vec<Argument>> = decode_xml_array(xml_child(element, "LastMethodOutputArguments"), decoding_limits)?;
+        let last_method_call_time = DateTime::decode_xml(xml_child(element, "LastMethodCallTime"), decoding_limits)?;
+        let last_method_return_status = StatusResult::decode_xml(xml_child(element, "LastMethodReturnStatus"), decoding_limits)?;
+        Ok(ProgramDiagnosticDataType {
+            create_session_id,
+            create_client_name,
+            invocation_creation_time,
+            last_transition_time,
+            last_method_call,
+            last_method_session_id,
+            last_method_input_arguments,
+            last_method_output_arguments,
+            last_method_call_time,
+            last_method_return_status,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<PublishRequest> for PublishRequest {
         })
     }
 }
+
+impl JsonEncoder for PublishRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionAcknowledgements", encode_json_array(&self.subscription_acknowledgements, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for PublishRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_acknowledgements: Option<Vec<SubscriptionAcknowledgement>> = decode_json_array(json_field(fields, "SubscriptionAcknowledgements"), decoding_limits)?;
+        Ok(PublishRequest {
+            request_header,
+            subscription_acknowledgements,
+        })
+    }
+}
+
+impl XmlEncoder for PublishRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("SubscriptionAcknowledgements", "SubscriptionAcknowledgement", &self.subscription_acknowledgements));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for PublishRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_acknowledgements: Option<Vec<SubscriptionAcknowledgement>> = decode_xml_array(xml_child(element, "SubscriptionAcknowledgements"), decoding_limits)?;
+        Ok(PublishRequest {
+            request_header,
+            subscription_acknowledgements,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -78,3 +80,78 @@ impl BinaryEncoder<PublishResponse> for PublishResponse {
         })
     }
 }
+
+impl JsonEncoder for PublishResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "AvailableSequenceNumbers", encode_json_array(&self.available_sequence_numbers, context));
+        insert_json_field(&mut fields, "MoreNotifications", self.more_notifications.encode_json(context));
+        insert_json_field(&mut fields, "NotificationMessage", self.notification_message.encode_json(context));
+        insert_json_field(&mut fields, "Results", encode_json_array(&self.results, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for PublishResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_json_array(json_field(fields, "AvailableSequenceNumbers"), decoding_limits)?;
+        let more_notifications = bool::decode_json(json_field(fields, "MoreNotifications"), decoding_limits)?;
+        let notification_message = NotificationMessage::decode_json(json_field(fields, "NotificationMessage"), decoding_limits)?;
+        let results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        Ok(PublishResponse {
+            response_header,
+            subscription_id,
+            available_sequence_numbers,
+            more_notifications,
+            notification_message,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for PublishResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(encode_xml_array("AvailableSequenceNumbers", "UInt32", &self.available_sequence_numbers));
+        element.add_child(self.more_notifications.encode_xml("MoreNotifications"));
+        element.add_child(self.notification_message.encode_xml("NotificationMessage"));
+        element.add_child(encode_xml_array("Results", "StatusCode", &self.results));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for PublishResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_xml_array(xml_child(element, "AvailableSequenceNumbers"), decoding_limits)?;
+        let more_notifications = bool::decode_xml(xml_child(element, "MoreNotifications"), decoding_limits)?;
+        let notification_message = NotificationMessage::decode_xml(xml_child(element, "NotificationMessage"), decoding_limits)?;
+        let results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "Results"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        Ok(PublishResponse {
+            response_header,
+            subscription_id,
+            available_sequence_numbers,
+            more_notifications,
+            notification_message,
+            results,
+            diagnostic_infos,
+        })
+    }
+}
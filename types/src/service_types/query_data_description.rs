@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<QueryDataDescription> for QueryDataDescription {
         })
     }
 }
+
+impl JsonEncoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RelativePath", self.relative_path.encode_json(context));
+        insert_json_field(&mut fields, "AttributeId", self.attribute_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let relative_path = RelativePath::decode_json(json_field(fields, "RelativePath"), decoding_limits)?;
+        let attribute_id = u32::decode_json(json_field(fields, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        Ok(QueryDataDescription {
+            relative_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
+
+impl XmlEncoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.relative_path.encode_xml("RelativePath"));
+        element.add_child(self.attribute_id.encode_xml("AttributeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryDataDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let relative_path = RelativePath::decode_xml(xml_child(element, "RelativePath"), decoding_limits)?;
+        let attribute_id = u32::decode_xml(xml_child(element, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        Ok(QueryDataDescription {
+            relative_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<QueryDataSet> for QueryDataSet {
         })
     }
 }
+
+impl JsonEncoder for QueryDataSet {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "TypeDefinitionNode", self.type_definition_node.encode_json(context));
+        insert_json_field(&mut fields, "Values", encode_json_array(&self.values, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryDataSet {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = ExpandedNodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let type_definition_node = ExpandedNodeId::decode_json(json_field(fields, "TypeDefinitionNode"), decoding_limits)?;
+        let values: Option<Vec<Variant>> = decode_json_array(json_field(fields, "Values"), decoding_limits)?;
+        Ok(QueryDataSet {
+            node_id,
+            type_definition_node,
+            values,
+        })
+    }
+}
+
+impl XmlEncoder for QueryDataSet {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.type_definition_node.encode_xml("TypeDefinitionNode"));
+        element.add_child(encode_xml_array("Values", "Variant", &self.values));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryDataSet {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = ExpandedNodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let type_definition_node = ExpandedNodeId::decode_xml(xml_child(element, "TypeDefinitionNode"), decoding_limits)?;
+        let values: Option<Vec<Variant>> = decode_xml_array(xml_child(element, "Values"), decoding_limits)?;
+        Ok(QueryDataSet {
+            node_id,
+            type_definition_node,
+            values,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -73,3 +75,72 @@ impl BinaryEncoder<QueryFirstRequest> for QueryFirstRequest {
         })
     }
 }
+
+impl JsonEncoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "View", self.view.encode_json(context));
+        insert_json_field(&mut fields, "NodeTypes", encode_json_array(&self.node_types, context));
+        insert_json_field(&mut fields, "Filter", self.filter.encode_json(context));
+        insert_json_field(&mut fields, "MaxDataSetsToReturn", self.max_data_sets_to_return.encode_json(context));
+        insert_json_field(&mut fields, "MaxReferencesToReturn", self.max_references_to_return.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let view = ViewDescription::decode_json(json_field(fields, "View"), decoding_limits)?;
+        let node_types: Option<Vec<NodeTypeDescription>> = decode_json_array(json_field(fields, "NodeTypes"), decoding_limits)?;
+        let filter = ContentFilter::decode_json(json_field(fields, "Filter"), decoding_limits)?;
+        let max_data_sets_to_return = u32::decode_json(json_field(fields, "MaxDataSetsToReturn"), decoding_limits)?;
+        let max_references_to_return = u32::decode_json(json_field(fields, "MaxReferencesToReturn"), decoding_limits)?;
+        Ok(QueryFirstRequest {
+            request_header,
+            view,
+            node_types,
+            filter,
+            max_data_sets_to_return,
+            max_references_to_return,
+        })
+    }
+}
+
+impl XmlEncoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.view.encode_xml("View"));
+        element.add_child(encode_xml_array("NodeTypes", "NodeTypeDescription", &self.node_types));
+        element.add_child(self.filter.encode_xml("Filter"));
+        element.add_child(self.max_data_sets_to_return.encode_xml("MaxDataSetsToReturn"));
+        element.add_child(self.max_references_to_return.encode_xml("MaxReferencesToReturn"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryFirstRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let view = ViewDescription::decode_xml(xml_child(element, "View"), decoding_limits)?;
+        let node_types: Option<Vec<NodeTypeDescription>> = decode_xml_array(xml_child(element, "NodeTypes"), decoding_limits)?;
+        let filter = ContentFilter::decode_xml(xml_child(element, "Filter"), decoding_limits)?;
+        let max_data_sets_to_return = u32::decode_xml(xml_child(element, "MaxDataSetsToReturn"), decoding_limits)?;
+        let max_references_to_return = u32::decode_xml(xml_child(element, "MaxReferencesToReturn"), decoding_limits)?;
+        Ok(QueryFirstRequest {
+            request_header,
+            view,
+            node_types,
+            filter,
+            max_data_sets_to_return,
+            max_references_to_return,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -75,3 +77,72 @@ impl BinaryEncoder<QueryFirstResponse> for QueryFirstResponse {
         })
     }
 }
+
+impl JsonEncoder for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "QueryDataSets", encode_json_array(&self.query_data_sets, context));
+        insert_json_field(&mut fields, "ContinuationPoint", self.continuation_point.encode_json(context));
+        insert_json_field(&mut fields, "ParsingResults", encode_json_array(&self.parsing_results, context));
+        insert_json_field(&mut fields, "DiagnosticInfos", encode_json_array(&self.diagnostic_infos, context));
+        insert_json_field(&mut fields, "FilterResult", self.filter_result.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let query_data_sets: Option<Vec<QueryDataSet>> = decode_json_array(json_field(fields, "QueryDataSets"), decoding_limits)?;
+        let continuation_point = ByteString::decode_json(json_field(fields, "ContinuationPoint"), decoding_limits)?;
+        let parsing_results: Option<Vec<ParsingResult>> = decode_json_array(json_field(fields, "ParsingResults"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "DiagnosticInfos"), decoding_limits)?;
+        let filter_result = ContentFilterResult::decode_json(json_field(fields, "FilterResult"), decoding_limits)?;
+        Ok(QueryFirstResponse {
+            response_header,
+            query_data_sets,
+            continuation_point,
+            parsing_results,
+            diagnostic_infos,
+            filter_result,
+        })
+    }
+}
+
+impl XmlEncoder for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("QueryDataSets", "QueryDataSet", &self.query_data_sets));
+        element.add_child(self.continuation_point.encode_xml("ContinuationPoint"));
+        element.add_child(encode_xml_array("ParsingResults", "ParsingResult", &self.parsing_results));
+        element.add_child(encode_xml_array("DiagnosticInfos", "DiagnosticInfo", &self.diagnostic_infos));
+        element.add_child(self.filter_result.encode_xml("FilterResult"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryFirstResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let query_data_sets: Option<Vec<QueryDataSet>> = decode_xml_array(xml_child(element, "QueryDataSets"), decoding_limits)?;
+        let continuation_point = ByteString::decode_xml(xml_child(element, "ContinuationPoint"), decoding_limits)?;
+        let parsing_results: Option<Vec<ParsingResult>> = decode_xml_array(xml_child(element, "ParsingResults"), decoding_limits)?;
+        let diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "DiagnosticInfos"), decoding_limits)?;
+        let filter_result = ContentFilterResult::decode_xml(xml_child(element, "FilterResult"), decoding_limits)?;
+        Ok(QueryFirstResponse {
+            response_header,
+            query_data_sets,
+            continuation_point,
+            parsing_results,
+            diagnostic_infos,
+            filter_result,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -56,3 +58,54 @@ impl BinaryEncoder<QueryNextRequest> for QueryNextRequest {
         })
     }
 }
+
+impl JsonEncoder for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "ReleaseContinuationPoint", self.release_continuation_point.encode_json(context));
+        insert_json_field(&mut fields, "ContinuationPoint", self.continuation_point.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let release_continuation_point = bool::decode_json(json_field(fields, "ReleaseContinuationPoint"), decoding_limits)?;
+        let continuation_point = ByteString::decode_json(json_field(fields, "ContinuationPoint"), decoding_limits)?;
+        Ok(QueryNextRequest {
+            request_header,
+            release_continuation_point,
+            continuation_point,
+        })
+    }
+}
+
+impl XmlEncoder for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.release_continuation_point.encode_xml("ReleaseContinuationPoint"));
+        element.add_child(self.continuation_point.encode_xml("ContinuationPoint"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryNextRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let release_continuation_point = bool::decode_xml(xml_child(element, "ReleaseContinuationPoint"), decoding_limits)?;
+        let continuation_point = ByteString::decode_xml(xml_child(element, "ContinuationPoint"), decoding_limits)?;
+        Ok(QueryNextRequest {
+            request_header,
+            release_continuation_point,
+            continuation_point,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +59,54 @@ impl BinaryEncoder<QueryNextResponse> for QueryNextResponse {
         })
     }
 }
+
+impl JsonEncoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "QueryDataSets", encode_json_array(&self.query_data_sets, context));
+        insert_json_field(&mut fields, "RevisedContinuationPoint", self.revised_continuation_point.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let query_data_sets: Option<Vec<QueryDataSet>> = decode_json_array(json_field(fields, "QueryDataSets"), decoding_limits)?;
+        let revised_continuation_point = ByteString::decode_json(json_field(fields, "RevisedContinuationPoint"), decoding_limits)?;
+        Ok(QueryNextResponse {
+            response_header,
+            query_data_sets,
+            revised_continuation_point,
+        })
+    }
+}
+
+impl XmlEncoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("QueryDataSets", "QueryDataSet", &self.query_data_sets));
+        element.add_child(self.revised_continuation_point.encode_xml("RevisedContinuationPoint"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for QueryNextResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let query_data_sets: Option<Vec<QueryDataSet>> = decode_xml_array(xml_child(element, "QueryDataSets"), decoding_limits)?;
+        let revised_continuation_point = ByteString::decode_xml(xml_child(element, "RevisedContinuationPoint"), decoding_limits)?;
+        Ok(QueryNextResponse {
+            response_header,
+            query_data_sets,
+            revised_continuation_point,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<Range> for Range {
         })
     }
 }
+
+impl JsonEncoder for Range {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Low", self.low.encode_json(context));
+        insert_json_field(&mut fields, "High", self.high.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for Range {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let low = f64::decode_json(json_field(fields, "Low"), decoding_limits)?;
+        let high = f64::decode_json(json_field(fields, "High"), decoding_limits)?;
+        Ok(Range {
+            low,
+            high,
+        })
+    }
+}
+
+impl XmlEncoder for Range {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.low.encode_xml("Low"));
+        element.add_child(self.high.encode_xml("High"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for Range {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let low = f64::decode_xml(xml_child(element, "Low"), decoding_limits)?;
+        let high = f64::decode_xml(xml_child(element, "High"), decoding_limits)?;
+        Ok(Range {
+            low,
+            high,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     date_time::DateTime,
     service_types::EventFilter,
@@ -53,3 +55,60 @@ impl BinaryEncoder<ReadEventDetails> for ReadEventDetails {
         })
     }
 }
+
+impl JsonEncoder for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NumValuesPerNode", self.num_values_per_node.encode_json(context));
+        insert_json_field(&mut fields, "StartTime", self.start_time.encode_json(context));
+        insert_json_field(&mut fields, "EndTime", self.end_time.encode_json(context));
+        insert_json_field(&mut fields, "Filter", self.filter.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let num_values_per_node = u32::decode_json(json_field(fields, "NumValuesPerNode"), decoding_limits)?;
+        let start_time = DateTime::decode_json(json_field(fields, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_json(json_field(fields, "EndTime"), decoding_limits)?;
+        let filter = EventFilter::decode_json(json_field(fields, "Filter"), decoding_limits)?;
+        Ok(ReadEventDetails {
+            num_values_per_node,
+            start_time,
+            end_time,
+            filter,
+        })
+    }
+}
+
+impl XmlEncoder for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.num_values_per_node.encode_xml("NumValuesPerNode"));
+        element.add_child(self.start_time.encode_xml("StartTime"));
+        element.add_child(self.end_time.encode_xml("EndTime"));
+        element.add_child(self.filter.encode_xml("Filter"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReadEventDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let num_values_per_node = u32::decode_xml(xml_child(element, "NumValuesPerNode"), decoding_limits)?;
+        let start_time = DateTime::decode_xml(xml_child(element, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_xml(xml_child(element, "EndTime"), decoding_limits)?;
+        let filter = EventFilter::decode_xml(xml_child(element, "Filter"), decoding_limits)?;
+        Ok(ReadEventDetails {
+            num_values_per_node,
+            start_time,
+            end_time,
+            filter,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    date_time::DateTime,
+    node_id::NodeId,
+    service_types::AggregateConfiguration,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadProcessedDetails {
+    pub start_time: DateTime,
+    pub end_time: DateTime,
+    pub processing_interval: f64,
+    pub aggregate_type: Option<Vec<NodeId>>,
+    pub aggregate_configuration: AggregateConfiguration,
+}
+
+impl BinaryEncoder<ReadProcessedDetails> for ReadProcessedDetails {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.start_time.byte_len();
+        size += self.end_time.byte_len();
+        size += self.processing_interval.byte_len();
+        size += byte_len_array(&self.aggregate_type);
+        size += self.aggregate_configuration.byte_len();
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.start_time.encode(stream)?;
+        size += self.end_time.encode(stream)?;
+        size += self.processing_interval.encode(stream)?;
+        size += write_array(stream, &self.aggregate_type)?;
+        size += self.aggregate_configuration.encode(stream)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let start_time = DateTime::decode(stream, decoding_limits)?;
+        let end_time = DateTime::decode(stream, decoding_limits)?;
+        let processing_interval = f64::decode(stream, decoding_limits)?;
+        let aggregate_type: Option<Vec<NodeId>> = read_array(stream, decoding_limits)?;
+        let aggregate_configuration = AggregateConfiguration::decode(stream, decoding_limits)?;
+        Ok(ReadProcessedDetails {
+            start_time,
+            end_time,
+            processing_interval,
+            aggregate_type,
+            aggregate_configuration,
+        })
+    }
+}
+
+impl JsonEncoder for ReadProcessedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StartTime", self.start_time.encode_json(context));
+        insert_json_field(&mut fields, "EndTime", self.end_time.encode_json(context));
+        insert_json_field(&mut fields, "ProcessingInterval", self.processing_interval.encode_json(context));
+        insert_json_field(&mut fields, "AggregateType", encode_json_array(&self.aggregate_type, context));
+        insert_json_field(&mut fields, "AggregateConfiguration", self.aggregate_configuration.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReadProcessedDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let start_time = DateTime::decode_json(json_field(fields, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_json(json_field(fields, "EndTime"), decoding_limits)?;
+        let processing_interval = f64::decode_json(json_field(fields, "ProcessingInterval"), decoding_limits)?;
+        let aggregate_type: Option<Vec<NodeId>> = decode_json_array(json_field(fields, "AggregateType"), decoding_limits)?;
+        let aggregate_configuration = AggregateConfiguration::decode_json(json_field(fields, "AggregateConfiguration"), decoding_limits)?;
+        Ok(ReadProcessedDetails {
+            start_time,
+            end_time,
+            processing_interval,
+            aggregate_type,
+            aggregate_configuration,
+        })
+    }
+}
+
+impl XmlEncoder for ReadProcessedDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.start_time.encode_xml("StartTime"));
+        element.add_child(self.end_time.encode_xml("EndTime"));
+        element.add_child(self.processing_interval.encode_xml("ProcessingInterval"));
+        element.add_child(encode_xml_array("AggregateType", "NodeId", &self.aggregate_type));
+        element.add_child(self.aggregate_configuration.encode_xml("AggregateConfiguration"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReadProcessedDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let start_time = DateTime::decode_xml(xml_child(element, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_xml(xml_child(element, "EndTime"), decoding_limits)?;
+        let processing_interval = f64::decode_xml(xml_child(element, "ProcessingInterval"), decoding_limits)?;
+        let aggregate_type: Option<Vec<NodeId>> = decode_xml_array(xml_child(element, "AggregateType"), decoding_limits)?;
+        let aggregate_configuration = AggregateConfiguration::decode_xml(xml_child(element, "AggregateConfiguration"), decoding_limits)?;
+        Ok(ReadProcessedDetails {
+            start_time,
+            end_time,
+            processing_interval,
+            aggregate_type,
+            aggregate_configuration,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     date_time::DateTime,
 };
@@ -57,3 +59,66 @@ impl BinaryEncoder<ReadRawModifiedDetails> for ReadRawModifiedDetails {
         })
     }
 }
+
+impl JsonEncoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "IsReadModified", self.is_read_modified.encode_json(context));
+        insert_json_field(&mut fields, "StartTime", self.start_time.encode_json(context));
+        insert_json_field(&mut fields, "EndTime", self.end_time.encode_json(context));
+        insert_json_field(&mut fields, "NumValuesPerNode", self.num_values_per_node.encode_json(context));
+        insert_json_field(&mut fields, "ReturnBounds", self.return_bounds.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let is_read_modified = bool::decode_json(json_field(fields, "IsReadModified"), decoding_limits)?;
+        let start_time = DateTime::decode_json(json_field(fields, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_json(json_field(fields, "EndTime"), decoding_limits)?;
+        let num_values_per_node = u32::decode_json(json_field(fields, "NumValuesPerNode"), decoding_limits)?;
+        let return_bounds = bool::decode_json(json_field(fields, "ReturnBounds"), decoding_limits)?;
+        Ok(ReadRawModifiedDetails {
+            is_read_modified,
+            start_time,
+            end_time,
+            num_values_per_node,
+            return_bounds,
+        })
+    }
+}
+
+impl XmlEncoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.is_read_modified.encode_xml("IsReadModified"));
+        element.add_child(self.start_time.encode_xml("StartTime"));
+        element.add_child(self.end_time.encode_xml("EndTime"));
+        element.add_child(self.num_values_per_node.encode_xml("NumValuesPerNode"));
+        element.add_child(self.return_bounds.encode_xml("ReturnBounds"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReadRawModifiedDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let is_read_modified = bool::decode_xml(xml_child(element, "IsReadModified"), decoding_limits)?;
+        let start_time = DateTime::decode_xml(xml_child(element, "StartTime"), decoding_limits)?;
+        let end_time = DateTime::decode_xml(xml_child(element, "EndTime"), decoding_limits)?;
+        let num_values_per_node = u32::decode_xml(xml_child(element, "NumValuesPerNode"), decoding_limits)?;
+        let return_bounds = bool::decode_xml(xml_child(element, "ReturnBounds"), decoding_limits)?;
+        Ok(ReadRawModifiedDetails {
+            is_read_modified,
+            start_time,
+            end_time,
+            num_values_per_node,
+            return_bounds,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<ReadRequest> for ReadRequest {
         })
     }
 }
+
+impl JsonEncoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "MaxAge", self.max_age.encode_json(context));
+        insert_json_field(&mut fields, "TimestampsToReturn", self.timestamps_to_return.encode_json(context));
+        insert_json_field(&mut fields, "NodesToRead", encode_json_array(&self.nodes_to_read, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let max_age = f64::decode_json(json_field(fields, "MaxAge"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_json(json_field(fields, "TimestampsToReturn"), decoding_limits)?;
+        let nodes_to_read: Option<Vec<ReadValueId>> = decode_json_array(json_field(fields, "NodesToRead"), decoding_limits)?;
+        Ok(ReadRequest {
+            request_header,
+            max_age,
+            timestamps_to_return,
+            nodes_to_read,
+        })
+    }
+}
+
+impl XmlEncoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.max_age.encode_xml("MaxAge"));
+        element.add_child(self.timestamps_to_return.encode_xml("TimestampsToReturn"));
+        element.add_child(encode_xml_array("NodesToRead", "ReadValueId", &self.nodes_to_read));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReadRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let max_age = f64::decode_xml(xml_child(element, "MaxAge"), decoding_limits)?;
+        let timestamps_to_return = TimestampsToReturn::decode_xml(xml_child(element, "TimestampsToReturn"), decoding_limits)?;
+        let nodes_to_read: Option<Vec<ReadValueId>> = decode_xml_array(xml_child(element, "NodesToRead"), decoding_limits)?;
+        Ok(ReadRequest {
+            request_header,
+            max_age,
+            timestamps_to_return,
+            nodes_to_read,
+        })
+    }
+}
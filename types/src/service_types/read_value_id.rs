@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<ReadValueId> for ReadValueId {
         })
     }
 }
+
+impl JsonEncoder for ReadValueId {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "AttributeId", self.attribute_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        insert_json_field(&mut fields, "DataEncoding", self.data_encoding.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReadValueId {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let attribute_id = u32::decode_json(json_field(fields, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        let data_encoding = QualifiedName::decode_json(json_field(fields, "DataEncoding"), decoding_limits)?;
+        Ok(ReadValueId {
+            node_id,
+            attribute_id,
+            index_range,
+            data_encoding,
+        })
+    }
+}
+
+impl XmlEncoder for ReadValueId {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.attribute_id.encode_xml("AttributeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        element.add_child(self.data_encoding.encode_xml("DataEncoding"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReadValueId {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let attribute_id = u32::decode_xml(xml_child(element, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        let data_encoding = QualifiedName::decode_xml(xml_child(element, "DataEncoding"), decoding_limits)?;
+        Ok(ReadValueId {
+            node_id,
+            attribute_id,
+            index_range,
+            data_encoding,
+        })
+    }
+}
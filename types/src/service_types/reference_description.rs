@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -80,3 +82,78 @@ impl BinaryEncoder<ReferenceDescription> for ReferenceDescription {
         })
     }
 }
+
+impl JsonEncoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IsForward", self.is_forward.encode_json(context));
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "BrowseName", self.browse_name.encode_json(context));
+        insert_json_field(&mut fields, "DisplayName", self.display_name.encode_json(context));
+        insert_json_field(&mut fields, "NodeClass", self.node_class.encode_json(context));
+        insert_json_field(&mut fields, "TypeDefinition", self.type_definition.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_json(json_field(fields, "IsForward"), decoding_limits)?;
+        let node_id = ExpandedNodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let browse_name = QualifiedName::decode_json(json_field(fields, "BrowseName"), decoding_limits)?;
+        let display_name = LocalizedText::decode_json(json_field(fields, "DisplayName"), decoding_limits)?;
+        let node_class = NodeClass::decode_json(json_field(fields, "NodeClass"), decoding_limits)?;
+        let type_definition = ExpandedNodeId::decode_json(json_field(fields, "TypeDefinition"), decoding_limits)?;
+        Ok(ReferenceDescription {
+            reference_type_id,
+            is_forward,
+            node_id,
+            browse_name,
+            display_name,
+            node_class,
+            type_definition,
+        })
+    }
+}
+
+impl XmlEncoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.is_forward.encode_xml("IsForward"));
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.browse_name.encode_xml("BrowseName"));
+        element.add_child(self.display_name.encode_xml("DisplayName"));
+        element.add_child(self.node_class.encode_xml("NodeClass"));
+        element.add_child(self.type_definition.encode_xml("TypeDefinition"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ReferenceDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let is_forward = bool::decode_xml(xml_child(element, "IsForward"), decoding_limits)?;
+        let node_id = ExpandedNodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let browse_name = QualifiedName::decode_xml(xml_child(element, "BrowseName"), decoding_limits)?;
+        let display_name = LocalizedText::decode_xml(xml_child(element, "DisplayName"), decoding_limits)?;
+        let node_class = NodeClass::decode_xml(xml_child(element, "NodeClass"), decoding_limits)?;
+        let type_definition = ExpandedNodeId::decode_xml(xml_child(element, "TypeDefinition"), decoding_limits)?;
+        Ok(ReferenceDescription {
+            reference_type_id,
+            is_forward,
+            node_id,
+            browse_name,
+            display_name,
+            node_class,
+            type_definition,
+        })
+    }
+}
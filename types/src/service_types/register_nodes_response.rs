@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<RegisterNodesResponse> for RegisterNodesResponse {
         })
     }
 }
+
+impl JsonEncoder for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "RegisteredNodeIds", encode_json_array(&self.registered_node_ids, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let registered_node_ids: Option<Vec<NodeId>> = decode_json_array(json_field(fields, "RegisteredNodeIds"), decoding_limits)?;
+        Ok(RegisterNodesResponse {
+            response_header,
+            registered_node_ids,
+        })
+    }
+}
+
+impl XmlEncoder for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("RegisteredNodeIds", "NodeId", &self.registered_node_ids));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RegisterNodesResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let registered_node_ids: Option<Vec<NodeId>> = decode_xml_array(xml_child(element, "RegisteredNodeIds"), decoding_limits)?;
+        Ok(RegisterNodesResponse {
+            response_header,
+            registered_node_ids,
+        })
+    }
+}
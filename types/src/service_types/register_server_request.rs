@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<RegisterServerRequest> for RegisterServerRequest {
         })
     }
 }
+
+impl JsonEncoder for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "Server", self.server.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let server = RegisteredServer::decode_json(json_field(fields, "Server"), decoding_limits)?;
+        Ok(RegisterServerRequest {
+            request_header,
+            server,
+        })
+    }
+}
+
+impl XmlEncoder for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.server.encode_xml("Server"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RegisterServerRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let server = RegisteredServer::decode_xml(xml_child(element, "Server"), decoding_limits)?;
+        Ok(RegisterServerRequest {
+            request_header,
+            server,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -83,3 +85,84 @@ impl BinaryEncoder<RegisteredServer> for RegisteredServer {
         })
     }
 }
+
+impl JsonEncoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ServerUri", self.server_uri.encode_json(context));
+        insert_json_field(&mut fields, "ProductUri", self.product_uri.encode_json(context));
+        insert_json_field(&mut fields, "ServerNames", encode_json_array(&self.server_names, context));
+        insert_json_field(&mut fields, "ServerType", self.server_type.encode_json(context));
+        insert_json_field(&mut fields, "GatewayServerUri", self.gateway_server_uri.encode_json(context));
+        insert_json_field(&mut fields, "DiscoveryUrls", encode_json_array(&self.discovery_urls, context));
+        insert_json_field(&mut fields, "SemaphoreFilePath", self.semaphore_file_path.encode_json(context));
+        insert_json_field(&mut fields, "IsOnline", self.is_online.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let server_uri = UAString::decode_json(json_field(fields, "ServerUri"), decoding_limits)?;
+        let product_uri = UAString::decode_json(json_field(fields, "ProductUri"), decoding_limits)?;
+        let server_names: Option<Vec<LocalizedText>> = decode_json_array(json_field(fields, "ServerNames"), decoding_limits)?;
+        let server_type = ApplicationType::decode_json(json_field(fields, "ServerType"), decoding_limits)?;
+        let gateway_server_uri = UAString::decode_json(json_field(fields, "GatewayServerUri"), decoding_limits)?;
+        let discovery_urls: Option<Vec<UAString>> = decode_json_array(json_field(fields, "DiscoveryUrls"), decoding_limits)?;
+        let semaphore_file_path = UAString::decode_json(json_field(fields, "SemaphoreFilePath"), decoding_limits)?;
+        let is_online = bool::decode_json(json_field(fields, "IsOnline"), decoding_limits)?;
+        Ok(RegisteredServer {
+            server_uri,
+            product_uri,
+            server_names,
+            server_type,
+            gateway_server_uri,
+            discovery_urls,
+            semaphore_file_path,
+            is_online,
+        })
+    }
+}
+
+impl XmlEncoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.server_uri.encode_xml("ServerUri"));
+        element.add_child(self.product_uri.encode_xml("ProductUri"));
+        element.add_child(encode_xml_array("ServerNames", "LocalizedText", &self.server_names));
+        element.add_child(self.server_type.encode_xml("ServerType"));
+        element.add_child(self.gateway_server_uri.encode_xml("GatewayServerUri"));
+        element.add_child(encode_xml_array("DiscoveryUrls", "String", &self.discovery_urls));
+        element.add_child(self.semaphore_file_path.encode_xml("SemaphoreFilePath"));
+        element.add_child(self.is_online.encode_xml("IsOnline"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RegisteredServer {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let server_uri = UAString::decode_xml(xml_child(element, "ServerUri"), decoding_limits)?;
+        let product_uri = UAString::decode_xml(xml_child(element, "ProductUri"), decoding_limits)?;
+        let server_names: Option<Vec<LocalizedText>> = decode_xml_array(xml_child(element, "ServerNames"), decoding_limits)?;
+        let server_type = ApplicationType::decode_xml(xml_child(element, "ServerType"), decoding_limits)?;
+        let gateway_server_uri = UAString::decode_xml(xml_child(element, "GatewayServerUri"), decoding_limits)?;
+        let discovery_urls: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "DiscoveryUrls"), decoding_limits)?;
+        let semaphore_file_path = UAString::decode_xml(xml_child(element, "SemaphoreFilePath"), decoding_limits)?;
+        let is_online = bool::decode_xml(xml_child(element, "IsOnline"), decoding_limits)?;
+        Ok(RegisteredServer {
+            server_uri,
+            product_uri,
+            server_names,
+            server_type,
+            gateway_server_uri,
+            discovery_urls,
+            semaphore_file_path,
+            is_online,
+        })
+    }
+}
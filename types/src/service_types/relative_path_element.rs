@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<RelativePathElement> for RelativePathElement {
         })
     }
 }
+
+impl JsonEncoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ReferenceTypeId", self.reference_type_id.encode_json(context));
+        insert_json_field(&mut fields, "IsInverse", self.is_inverse.encode_json(context));
+        insert_json_field(&mut fields, "IncludeSubtypes", self.include_subtypes.encode_json(context));
+        insert_json_field(&mut fields, "TargetName", self.target_name.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let reference_type_id = NodeId::decode_json(json_field(fields, "ReferenceTypeId"), decoding_limits)?;
+        let is_inverse = bool::decode_json(json_field(fields, "IsInverse"), decoding_limits)?;
+        let include_subtypes = bool::decode_json(json_field(fields, "IncludeSubtypes"), decoding_limits)?;
+        let target_name = QualifiedName::decode_json(json_field(fields, "TargetName"), decoding_limits)?;
+        Ok(RelativePathElement {
+            reference_type_id,
+            is_inverse,
+            include_subtypes,
+            target_name,
+        })
+    }
+}
+
+impl XmlEncoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.reference_type_id.encode_xml("ReferenceTypeId"));
+        element.add_child(self.is_inverse.encode_xml("IsInverse"));
+        element.add_child(self.include_subtypes.encode_xml("IncludeSubtypes"));
+        element.add_child(self.target_name.encode_xml("TargetName"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RelativePathElement {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let reference_type_id = NodeId::decode_xml(xml_child(element, "ReferenceTypeId"), decoding_limits)?;
+        let is_inverse = bool::decode_xml(xml_child(element, "IsInverse"), decoding_limits)?;
+        let include_subtypes = bool::decode_xml(xml_child(element, "IncludeSubtypes"), decoding_limits)?;
+        let target_name = QualifiedName::decode_xml(xml_child(element, "TargetName"), decoding_limits)?;
+        Ok(RelativePathElement {
+            reference_type_id,
+            is_inverse,
+            include_subtypes,
+            target_name,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -55,3 +57,54 @@ impl BinaryEncoder<RepublishRequest> for RepublishRequest {
         })
     }
 }
+
+impl JsonEncoder for RepublishRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "RetransmitSequenceNumber", self.retransmit_sequence_number.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for RepublishRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let retransmit_sequence_number = u32::decode_json(json_field(fields, "RetransmitSequenceNumber"), decoding_limits)?;
+        Ok(RepublishRequest {
+            request_header,
+            subscription_id,
+            retransmit_sequence_number,
+        })
+    }
+}
+
+impl XmlEncoder for RepublishRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.retransmit_sequence_number.encode_xml("RetransmitSequenceNumber"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for RepublishRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let retransmit_sequence_number = u32::decode_xml(xml_child(element, "RetransmitSequenceNumber"), decoding_limits)?;
+        Ok(RepublishRequest {
+            request_header,
+            subscription_id,
+            retransmit_sequence_number,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -59,3 +61,60 @@ impl BinaryEncoder<SamplingIntervalDiagnosticsDataType> for SamplingIntervalDiag
         })
     }
 }
+
+impl JsonEncoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SamplingInterval", self.sampling_interval.encode_json(context));
+        insert_json_field(&mut fields, "MonitoredItemCount", self.monitored_item_count.encode_json(context));
+        insert_json_field(&mut fields, "MaxMonitoredItemCount", self.max_monitored_item_count.encode_json(context));
+        insert_json_field(&mut fields, "DisabledMonitoredItemCount", self.disabled_monitored_item_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let sampling_interval = f64::decode_json(json_field(fields, "SamplingInterval"), decoding_limits)?;
+        let monitored_item_count = u32::decode_json(json_field(fields, "MonitoredItemCount"), decoding_limits)?;
+        let max_monitored_item_count = u32::decode_json(json_field(fields, "MaxMonitoredItemCount"), decoding_limits)?;
+        let disabled_monitored_item_count = u32::decode_json(json_field(fields, "DisabledMonitoredItemCount"), decoding_limits)?;
+        Ok(SamplingIntervalDiagnosticsDataType {
+            sampling_interval,
+            monitored_item_count,
+            max_monitored_item_count,
+            disabled_monitored_item_count,
+        })
+    }
+}
+
+impl XmlEncoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.sampling_interval.encode_xml("SamplingInterval"));
+        element.add_child(self.monitored_item_count.encode_xml("MonitoredItemCount"));
+        element.add_child(self.max_monitored_item_count.encode_xml("MaxMonitoredItemCount"));
+        element.add_child(self.disabled_monitored_item_count.encode_xml("DisabledMonitoredItemCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SamplingIntervalDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let sampling_interval = f64::decode_xml(xml_child(element, "SamplingInterval"), decoding_limits)?;
+        let monitored_item_count = u32::decode_xml(xml_child(element, "MonitoredItemCount"), decoding_limits)?;
+        let max_monitored_item_count = u32::decode_xml(xml_child(element, "MaxMonitoredItemCount"), decoding_limits)?;
+        let disabled_monitored_item_count = u32::decode_xml(xml_child(element, "DisabledMonitoredItemCount"), decoding_limits)?;
+        Ok(SamplingIntervalDiagnosticsDataType {
+            sampling_interval,
+            monitored_item_count,
+            max_monitored_item_count,
+            disabled_monitored_item_count,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -99,3 +101,108 @@ impl BinaryEncoder<ServerDiagnosticsSummaryDataType> for ServerDiagnosticsSummar
         })
     }
 }
+
+impl JsonEncoder for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ServerViewCount", self.server_view_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentSessionCount", self.current_session_count.encode_json(context));
+        insert_json_field(&mut fields, "CumulatedSessionCount", self.cumulated_session_count.encode_json(context));
+        insert_json_field(&mut fields, "SecurityRejectedSessionCount", self.security_rejected_session_count.encode_json(context));
+        insert_json_field(&mut fields, "RejectedSessionCount", self.rejected_session_count.encode_json(context));
+        insert_json_field(&mut fields, "SessionTimeoutCount", self.session_timeout_count.encode_json(context));
+        insert_json_field(&mut fields, "SessionAbortCount", self.session_abort_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentSubscriptionCount", self.current_subscription_count.encode_json(context));
+        insert_json_field(&mut fields, "CumulatedSubscriptionCount", self.cumulated_subscription_count.encode_json(context));
+        insert_json_field(&mut fields, "PublishingIntervalCount", self.publishing_interval_count.encode_json(context));
+        insert_json_field(&mut fields, "SecurityRejectedRequestsCount", self.security_rejected_requests_count.encode_json(context));
+        insert_json_field(&mut fields, "RejectedRequestsCount", self.rejected_requests_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let server_view_count = u32::decode_json(json_field(fields, "ServerViewCount"), decoding_limits)?;
+        let current_session_count = u32::decode_json(json_field(fields, "CurrentSessionCount"), decoding_limits)?;
+        let cumulated_session_count = u32::decode_json(json_field(fields, "CumulatedSessionCount"), decoding_limits)?;
+        let security_rejected_session_count = u32::decode_json(json_field(fields, "SecurityRejectedSessionCount"), decoding_limits)?;
+        let rejected_session_count = u32::decode_json(json_field(fields, "RejectedSessionCount"), decoding_limits)?;
+        let session_timeout_count = u32::decode_json(json_field(fields, "SessionTimeoutCount"), decoding_limits)?;
+        let session_abort_count = u32::decode_json(json_field(fields, "SessionAbortCount"), decoding_limits)?;
+        let current_subscription_count = u32::decode_json(json_field(fields, "CurrentSubscriptionCount"), decoding_limits)?;
+        let cumulated_subscription_count = u32::decode_json(json_field(fields, "CumulatedSubscriptionCount"), decoding_limits)?;
+        let publishing_interval_count = u32::decode_json(json_field(fields, "PublishingIntervalCount"), decoding_limits)?;
+        let security_rejected_requests_count = u32::decode_json(json_field(fields, "SecurityRejectedRequestsCount"), decoding_limits)?;
+        let rejected_requests_count = u32::decode_json(json_field(fields, "RejectedRequestsCount"), decoding_limits)?;
+        Ok(ServerDiagnosticsSummaryDataType {
+            server_view_count,
+            current_session_count,
+            cumulated_session_count,
+            security_rejected_session_count,
+            rejected_session_count,
+            session_timeout_count,
+            session_abort_count,
+            current_subscription_count,
+            cumulated_subscription_count,
+            publishing_interval_count,
+            security_rejected_requests_count,
+            rejected_requests_count,
+        })
+    }
+}
+
+impl XmlEncoder for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.server_view_count.encode_xml("ServerViewCount"));
+        element.add_child(self.current_session_count.encode_xml("CurrentSessionCount"));
+        element.add_child(self.cumulated_session_count.encode_xml("CumulatedSessionCount"));
+        element.add_child(self.security_rejected_session_count.encode_xml("SecurityRejectedSessionCount"));
+        element.add_child(self.rejected_session_count.encode_xml("RejectedSessionCount"));
+        element.add_child(self.session_timeout_count.encode_xml("SessionTimeoutCount"));
+        element.add_child(self.session_abort_count.encode_xml("SessionAbortCount"));
+        element.add_child(self.current_subscription_count.encode_xml("CurrentSubscriptionCount"));
+        element.add_child(self.cumulated_subscription_count.encode_xml("CumulatedSubscriptionCount"));
+        element.add_child(self.publishing_interval_count.encode_xml("PublishingIntervalCount"));
+        element.add_child(self.security_rejected_requests_count.encode_xml("SecurityRejectedRequestsCount"));
+        element.add_child(self.rejected_requests_count.encode_xml("RejectedRequestsCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ServerDiagnosticsSummaryDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let server_view_count = u32::decode_xml(xml_child(element, "ServerViewCount"), decoding_limits)?;
+        let current_session_count = u32::decode_xml(xml_child(element, "CurrentSessionCount"), decoding_limits)?;
+        let cumulated_session_count = u32::decode_xml(xml_child(element, "CumulatedSessionCount"), decoding_limits)?;
+        let security_rejected_session_count = u32::decode_xml(xml_child(element, "SecurityRejectedSessionCount"), decoding_limits)?;
+        let rejected_session_count = u32::decode_xml(xml_child(element, "RejectedSessionCount"), decoding_limits)?;
+        let session_timeout_count = u32::decode_xml(xml_child(element, "SessionTimeoutCount"), decoding_limits)?;
+        let session_abort_count = u32::decode_xml(xml_child(element, "SessionAbortCount"), decoding_limits)?;
+        let current_subscription_count = u32::decode_xml(xml_child(element, "CurrentSubscriptionCount"), decoding_limits)?;
+        let cumulated_subscription_count = u32::decode_xml(xml_child(element, "CumulatedSubscriptionCount"), decoding_limits)?;
+        let publishing_interval_count = u32::decode_xml(xml_child(element, "PublishingIntervalCount"), decoding_limits)?;
+        let security_rejected_requests_count = u32::decode_xml(xml_child(element, "SecurityRejectedRequestsCount"), decoding_limits)?;
+        let rejected_requests_count = u32::decode_xml(xml_child(element, "RejectedRequestsCount"), decoding_limits)?;
+        Ok(ServerDiagnosticsSummaryDataType {
+            server_view_count,
+            current_session_count,
+            cumulated_session_count,
+            security_rejected_session_count,
+            rejected_session_count,
+            session_timeout_count,
+            session_abort_count,
+            current_subscription_count,
+            cumulated_subscription_count,
+            publishing_interval_count,
+            security_rejected_requests_count,
+            rejected_requests_count,
+        })
+    }
+}
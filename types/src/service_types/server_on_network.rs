@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -60,3 +62,60 @@ impl BinaryEncoder<ServerOnNetwork> for ServerOnNetwork {
         })
     }
 }
+
+impl JsonEncoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RecordId", self.record_id.encode_json(context));
+        insert_json_field(&mut fields, "ServerName", self.server_name.encode_json(context));
+        insert_json_field(&mut fields, "DiscoveryUrl", self.discovery_url.encode_json(context));
+        insert_json_field(&mut fields, "ServerCapabilities", encode_json_array(&self.server_capabilities, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let record_id = u32::decode_json(json_field(fields, "RecordId"), decoding_limits)?;
+        let server_name = UAString::decode_json(json_field(fields, "ServerName"), decoding_limits)?;
+        let discovery_url = UAString::decode_json(json_field(fields, "DiscoveryUrl"), decoding_limits)?;
+        let server_capabilities: Option<Vec<UAString>> = decode_json_array(json_field(fields, "ServerCapabilities"), decoding_limits)?;
+        Ok(ServerOnNetwork {
+            record_id,
+            server_name,
+            discovery_url,
+            server_capabilities,
+        })
+    }
+}
+
+impl XmlEncoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.record_id.encode_xml("RecordId"));
+        element.add_child(self.server_name.encode_xml("ServerName"));
+        element.add_child(self.discovery_url.encode_xml("DiscoveryUrl"));
+        element.add_child(encode_xml_array("ServerCapabilities", "String", &self.server_capabilities));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ServerOnNetwork {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let record_id = u32::decode_xml(xml_child(element, "RecordId"), decoding_limits)?;
+        let server_name = UAString::decode_xml(xml_child(element, "ServerName"), decoding_limits)?;
+        let discovery_url = UAString::decode_xml(xml_child(element, "DiscoveryUrl"), decoding_limits)?;
+        let server_capabilities: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "ServerCapabilities"), decoding_limits)?;
+        Ok(ServerOnNetwork {
+            record_id,
+            server_name,
+            discovery_url,
+            server_capabilities,
+        })
+    }
+}
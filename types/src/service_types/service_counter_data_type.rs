@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<ServiceCounterDataType> for ServiceCounterDataType {
         })
     }
 }
+
+impl JsonEncoder for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "TotalCount", self.total_count.encode_json(context));
+        insert_json_field(&mut fields, "ErrorCount", self.error_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let total_count = u32::decode_json(json_field(fields, "TotalCount"), decoding_limits)?;
+        let error_count = u32::decode_json(json_field(fields, "ErrorCount"), decoding_limits)?;
+        Ok(ServiceCounterDataType {
+            total_count,
+            error_count,
+        })
+    }
+}
+
+impl XmlEncoder for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.total_count.encode_xml("TotalCount"));
+        element.add_child(self.error_count.encode_xml("ErrorCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ServiceCounterDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let total_count = u32::decode_xml(xml_child(element, "TotalCount"), decoding_limits)?;
+        let error_count = u32::decode_xml(xml_child(element, "ErrorCount"), decoding_limits)?;
+        Ok(ServiceCounterDataType {
+            total_count,
+            error_count,
+        })
+    }
+}
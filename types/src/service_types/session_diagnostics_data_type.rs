@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -259,3 +261,294 @@ impl BinaryEncoder<SessionDiagnosticsDataType> for SessionDiagnosticsDataType {
         })
     }
 }
+
+impl JsonEncoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SessionId", self.session_id.encode_json(context));
+        insert_json_field(&mut fields, "SessionName", self.session_name.encode_json(context));
+        insert_json_field(&mut fields, "ClientDescription", self.client_description.encode_json(context));
+        insert_json_field(&mut fields, "ServerUri", self.server_uri.encode_json(context));
+        insert_json_field(&mut fields, "EndpointUrl", self.endpoint_url.encode_json(context));
+        insert_json_field(&mut fields, "LocaleIds", encode_json_array(&self.locale_ids, context));
+        insert_json_field(&mut fields, "ActualSessionTimeout", self.actual_session_timeout.encode_json(context));
+        insert_json_field(&mut fields, "MaxResponseMessageSize", self.max_response_message_size.encode_json(context));
+        insert_json_field(&mut fields, "ClientConnectionTime", self.client_connection_time.encode_json(context));
+        insert_json_field(&mut fields, "ClientLastContactTime", self.client_last_contact_time.encode_json(context));
+        insert_json_field(&mut fields, "CurrentSubscriptionsCount", self.current_subscriptions_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentMonitoredItemsCount", self.current_monitored_items_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentPublishRequestsInQueue", self.current_publish_requests_in_queue.encode_json(context));
+        insert_json_field(&mut fields, "TotalRequestCount", self.total_request_count.encode_json(context));
+        insert_json_field(&mut fields, "UnauthorizedRequestCount", self.unauthorized_request_count.encode_json(context));
+        insert_json_field(&mut fields, "ReadCount", self.read_count.encode_json(context));
+        insert_json_field(&mut fields, "HistoryReadCount", self.history_read_count.encode_json(context));
+        insert_json_field(&mut fields, "WriteCount", self.write_count.encode_json(context));
+        insert_json_field(&mut fields, "HistoryUpdateCount", self.history_update_count.encode_json(context));
+        insert_json_field(&mut fields, "CallCount", self.call_count.encode_json(context));
+        insert_json_field(&mut fields, "CreateMonitoredItemsCount", self.create_monitored_items_count.encode_json(context));
+        insert_json_field(&mut fields, "ModifyMonitoredItemsCount", self.modify_monitored_items_count.encode_json(context));
+        insert_json_field(&mut fields, "SetMonitoringModeCount", self.set_monitoring_mode_count.encode_json(context));
+        insert_json_field(&mut fields, "SetTriggeringCount", self.set_triggering_count.encode_json(context));
+        insert_json_field(&mut fields, "DeleteMonitoredItemsCount", self.delete_monitored_items_count.encode_json(context));
+        insert_json_field(&mut fields, "CreateSubscriptionCount", self.create_subscription_count.encode_json(context));
+        insert_json_field(&mut fields, "ModifySubscriptionCount", self.modify_subscription_count.encode_json(context));
+        insert_json_field(&mut fields, "SetPublishingModeCount", self.set_publishing_mode_count.encode_json(context));
+        insert_json_field(&mut fields, "PublishCount", self.publish_count.encode_json(context));
+        insert_json_field(&mut fields, "RepublishCount", self.republish_count.encode_json(context));
+        insert_json_field(&mut fields, "TransferSubscriptionsCount", self.transfer_subscriptions_count.encode_json(context));
+        insert_json_field(&mut fields, "DeleteSubscriptionsCount", self.delete_subscriptions_count.encode_json(context));
+        insert_json_field(&mut fields, "AddNodesCount", self.add_nodes_count.encode_json(context));
+        insert_json_field(&mut fields, "AddReferencesCount", self.add_references_count.encode_json(context));
+        insert_json_field(&mut fields, "DeleteNodesCount", self.delete_nodes_count.encode_json(context));
+        insert_json_field(&mut fields, "DeleteReferencesCount", self.delete_references_count.encode_json(context));
+        insert_json_field(&mut fields, "BrowseCount", self.browse_count.encode_json(context));
+        insert_json_field(&mut fields, "BrowseNextCount", self.browse_next_count.encode_json(context));
+        insert_json_field(&mut fields, "TranslateBrowsePathsToNodeIdsCount", self.translate_browse_paths_to_node_ids_count.encode_json(context));
+        insert_json_field(&mut fields, "QueryFirstCount", self.query_first_count.encode_json(context));
+        insert_json_field(&mut fields, "QueryNextCount", self.query_next_count.encode_json(context));
+        insert_json_field(&mut fields, "RegisterNodesCount", self.register_nodes_count.encode_json(context));
+        insert_json_field(&mut fields, "UnregisterNodesCount", self.unregister_nodes_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let session_id = NodeId::decode_json(json_field(fields, "SessionId"), decoding_limits)?;
+        let session_name = UAString::decode_json(json_field(fields, "SessionName"), decoding_limits)?;
+        let client_description = ApplicationDescription::decode_json(json_field(fields, "ClientDescription"), decoding_limits)?;
+        let server_uri = UAString::decode_json(json_field(fields, "ServerUri"), decoding_limits)?;
+        let endpoint_url = UAString::decode_json(json_field(fields, "EndpointUrl"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_json_array(json_field(fields, "LocaleIds"), decoding_limits)?;
+        let actual_session_timeout = f64::decode_json(json_field(fields, "ActualSessionTimeout"), decoding_limits)?;
+        let max_response_message_size = u32::decode_json(json_field(fields, "MaxResponseMessageSize"), decoding_limits)?;
+        let client_connection_time = DateTime::decode_json(json_field(fields, "ClientConnectionTime"), decoding_limits)?;
+        let client_last_contact_time = DateTime::decode_json(json_field(fields, "ClientLastContactTime"), decoding_limits)?;
+        let current_subscriptions_count = u32::decode_json(json_field(fields, "CurrentSubscriptionsCount"), decoding_limits)?;
+        let current_monitored_items_count = u32::decode_json(json_field(fields, "CurrentMonitoredItemsCount"), decoding_limits)?;
+        let current_publish_requests_in_queue = u32::decode_json(json_field(fields, "CurrentPublishRequestsInQueue"), decoding_limits)?;
+        let total_request_count = ServiceCounterDataType::decode_json(json_field(fields, "TotalRequestCount"), decoding_limits)?;
+        let unauthorized_request_count = u32::decode_json(json_field(fields, "UnauthorizedRequestCount"), decoding_limits)?;
+        let read_count = ServiceCounterDataType::decode_json(json_field(fields, "ReadCount"), decoding_limits)?;
+        let history_read_count = ServiceCounterDataType::decode_json(json_field(fields, "HistoryReadCount"), decoding_limits)?;
+        let write_count = ServiceCounterDataType::decode_json(json_field(fields, "WriteCount"), decoding_limits)?;
+        let history_update_count = ServiceCounterDataType::decode_json(json_field(fields, "HistoryUpdateCount"), decoding_limits)?;
+        let call_count = ServiceCounterDataType::decode_json(json_field(fields, "CallCount"), decoding_limits)?;
+        let create_monitored_items_count = ServiceCounterDataType::decode_json(json_field(fields, "CreateMonitoredItemsCount"), decoding_limits)?;
+        let modify_monitored_items_count = ServiceCounterDataType::decode_json(json_field(fields, "ModifyMonitoredItemsCount"), decoding_limits)?;
+        let set_monitoring_mode_count = ServiceCounterDataType::decode_json(json_field(fields, "SetMonitoringModeCount"), decoding_limits)?;
+        let set_triggering_count = ServiceCounterDataType::decode_json(json_field(fields, "SetTriggeringCount"), decoding_limits)?;
+        let delete_monitored_items_count = ServiceCounterDataType::decode_json(json_field(fields, "DeleteMonitoredItemsCount"), decoding_limits)?;
+        let create_subscription_count = ServiceCounterDataType::decode_json(json_field(fields, "CreateSubscriptionCount"), decoding_limits)?;
+        let modify_subscription_count = ServiceCounterDataType::decode_json(json_field(fields, "ModifySubscriptionCount"), decoding_limits)?;
+        let set_publishing_mode_count = ServiceCounterDataType::decode_json(json_field(fields, "SetPublishingModeCount"), decoding_limits)?;
+        let publish_count = ServiceCounterDataType::decode_json(json_field(fields, "PublishCount"), decoding_limits)?;
+        let republish_count = ServiceCounterDataType::decode_json(json_field(fields, "RepublishCount"), decoding_limits)?;
+        let transfer_subscriptions_count = ServiceCounterDataType::decode_json(json_field(fields, "TransferSubscriptionsCount"), decoding_limits)?;
+        let delete_subscriptions_count = ServiceCounterDataType::decode_json(json_field(fields, "DeleteSubscriptionsCount"), decoding_limits)?;
+        let add_nodes_count = ServiceCounterDataType::decode_json(json_field(fields, "AddNodesCount"), decoding_limits)?;
+        let add_references_count = ServiceCounterDataType::decode_json(json_field(fields, "AddReferencesCount"), decoding_limits)?;
+        let delete_nodes_count = ServiceCounterDataType::decode_json(json_field(fields, "DeleteNodesCount"), decoding_limits)?;
+        let delete_references_count = ServiceCounterDataType::decode_json(json_field(fields, "DeleteReferencesCount"), decoding_limits)?;
+        let browse_count = ServiceCounterDataType::decode_json(json_field(fields, "BrowseCount"), decoding_limits)?;
+        let browse_next_count = ServiceCounterDataType::decode_json(json_field(fields, "BrowseNextCount"), decoding_limits)?;
+        let translate_browse_paths_to_node_ids_count = ServiceCounterDataType::decode_json(json_field(fields, "TranslateBrowsePathsToNodeIdsCount"), decoding_limits)?;
+        let query_first_count = ServiceCounterDataType::decode_json(json_field(fields, "QueryFirstCount"), decoding_limits)?;
+        let query_next_count = ServiceCounterDataType::decode_json(json_field(fields, "QueryNextCount"), decoding_limits)?;
+        let register_nodes_count = ServiceCounterDataType::decode_json(json_field(fields, "RegisterNodesCount"), decoding_limits)?;
+        let unregister_nodes_count = ServiceCounterDataType::decode_json(json_field(fields, "UnregisterNodesCount"), decoding_limits)?;
+        Ok(SessionDiagnosticsDataType {
+            session_id,
+            session_name,
+            client_description,
+            server_uri,
+            endpoint_url,
+            locale_ids,
+            actual_session_timeout,
+            max_response_message_size,
+            client_connection_time,
+            client_last_contact_time,
+            current_subscriptions_count,
+            current_monitored_items_count,
+            current_publish_requests_in_queue,
+            total_request_count,
+            unauthorized_request_count,
+            read_count,
+            history_read_count,
+            write_count,
+            history_update_count,
+            call_count,
+            create_monitored_items_count,
+            modify_monitored_items_count,
+            set_monitoring_mode_count,
+            set_triggering_count,
+            delete_monitored_items_count,
+            create_subscription_count,
+            modify_subscription_count,
+            set_publishing_mode_count,
+            publish_count,
+            republish_count,
+            transfer_subscriptions_count,
+            delete_subscriptions_count,
+            add_nodes_count,
+            add_references_count,
+            delete_nodes_count,
+            delete_references_count,
+            browse_count,
+            browse_next_count,
+            translate_browse_paths_to_node_ids_count,
+            query_first_count,
+            query_next_count,
+            register_nodes_count,
+            unregister_nodes_count,
+        })
+    }
+}
+
+impl XmlEncoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.session_id.encode_xml("SessionId"));
+        element.add_child(self.session_name.encode_xml("SessionName"));
+        element.add_child(self.client_description.encode_xml("ClientDescription"));
+        element.add_child(self.server_uri.encode_xml("ServerUri"));
+        element.add_child(self.endpoint_url.encode_xml("EndpointUrl"));
+        element.add_child(encode_xml_array("LocaleIds", "String", &self.locale_ids));
+        element.add_child(self.actual_session_timeout.encode_xml("ActualSessionTimeout"));
+        element.add_child(self.max_response_message_size.encode_xml("MaxResponseMessageSize"));
+        element.add_child(self.client_connection_time.encode_xml("ClientConnectionTime"));
+        element.add_child(self.client_last_contact_time.encode_xml("ClientLastContactTime"));
+        element.add_child(self.current_subscriptions_count.encode_xml("CurrentSubscriptionsCount"));
+        element.add_child(self.current_monitored_items_count.encode_xml("CurrentMonitoredItemsCount"));
+        element.add_child(self.current_publish_requests_in_queue.encode_xml("CurrentPublishRequestsInQueue"));
+        element.add_child(self.total_request_count.encode_xml("TotalRequestCount"));
+        element.add_child(self.unauthorized_request_count.encode_xml("UnauthorizedRequestCount"));
+        element.add_child(self.read_count.encode_xml("ReadCount"));
+        element.add_child(self.history_read_count.encode_xml("HistoryReadCount"));
+        element.add_child(self.write_count.encode_xml("WriteCount"));
+        element.add_child(self.history_update_count.encode_xml("HistoryUpdateCount"));
+        element.add_child(self.call_count.encode_xml("CallCount"));
+        element.add_child(self.create_monitored_items_count.encode_xml("CreateMonitoredItemsCount"));
+        element.add_child(self.modify_monitored_items_count.encode_xml("ModifyMonitoredItemsCount"));
+        element.add_child(self.set_monitoring_mode_count.encode_xml("SetMonitoringModeCount"));
+        element.add_child(self.set_triggering_count.encode_xml("SetTriggeringCount"));
+        element.add_child(self.delete_monitored_items_count.encode_xml("DeleteMonitoredItemsCount"));
+        element.add_child(self.create_subscription_count.encode_xml("CreateSubscriptionCount"));
+        element.add_child(self.modify_subscription_count.encode_xml("ModifySubscriptionCount"));
+        element.add_child(self.set_publishing_mode_count.encode_xml("SetPublishingModeCount"));
+        element.add_child(self.publish_count.encode_xml("PublishCount"));
+        element.add_child(self.republish_count.encode_xml("RepublishCount"));
+        element.add_child(self.transfer_subscriptions_count.encode_xml("TransferSubscriptionsCount"));
+        element.add_child(self.delete_subscriptions_count.encode_xml("DeleteSubscriptionsCount"));
+        element.add_child(self.add_nodes_count.encode_xml("AddNodesCount"));
+        element.add_child(self.add_references_count.encode_xml("AddReferencesCount"));
+        element.add_child(self.delete_nodes_count.encode_xml("DeleteNodesCount"));
+        element.add_child(self.delete_references_count.encode_xml("DeleteReferencesCount"));
+        element.add_child(self.browse_count.encode_xml("BrowseCount"));
+        element.add_child(self.browse_next_count.encode_xml("BrowseNextCount"));
+        element.add_child(self.translate_browse_paths_to_node_ids_count.encode_xml("TranslateBrowsePathsToNodeIdsCount"));
+        element.add_child(self.query_first_count.encode_xml("QueryFirstCount"));
+        element.add_child(self.query_next_count.encode_xml("QueryNextCount"));
+        element.add_child(self.register_nodes_count.encode_xml("RegisterNodesCount"));
+        element.add_child(self.unregister_nodes_count.encode_xml("UnregisterNodesCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SessionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let session_id = NodeId::decode_xml(xml_child(element, "SessionId"), decoding_limits)?;
+        let session_name = UAString::decode_xml(xml_child(element, "SessionName"), decoding_limits)?;
+        let client_description = ApplicationDescription::decode_xml(xml_child(element, "ClientDescription"), decoding_limits)?;
+        let server_uri = UAString::decode_xml(xml_child(element, "ServerUri"), decoding_limits)?;
+        let endpoint_url = UAString::decode_xml(xml_child(element, "EndpointUrl"), decoding_limits)?;
+        let locale_ids: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "LocaleIds"), decoding_limits)?;
+        let actual_session_timeout = f64::decode_xml(xml_child(element, "ActualSessionTimeout"), decoding_limits)?;
+        let max_response_message_size = u32::decode_xml(xml_child(element, "MaxResponseMessageSize"), decoding_limits)?;
+        let client_connection_time = DateTime::decode_xml(xml_child(element, "ClientConnectionTime"), decoding_limits)?;
+        let client_last_contact_time = DateTime::decode_xml(xml_child(element, "ClientLastContactTime"), decoding_limits)?;
+        let current_subscriptions_count = u32::decode_xml(xml_child(element, "CurrentSubscriptionsCount"), decoding_limits)?;
+        let current_monitored_items_count = u32::decode_xml(xml_child(element, "CurrentMonitoredItemsCount"), decoding_limits)?;
+        let current_publish_requests_in_queue = u32::decode_xml(xml_child(element, "CurrentPublishRequestsInQueue"), decoding_limits)?;
+        let total_request_count = ServiceCounterDataType::decode_xml(xml_child(element, "TotalRequestCount"), decoding_limits)?;
+        let unauthorized_request_count = u32::decode_xml(xml_child(element, "UnauthorizedRequestCount"), decoding_limits)?;
+        let read_count = ServiceCounterDataType::decode_xml(xml_child(element, "ReadCount"), decoding_limits)?;
+        let history_read_count = ServiceCounterDataType::decode_xml(xml_child(element, "HistoryReadCount"), decoding_limits)?;
+        let write_count = ServiceCounterDataType::decode_xml(xml_child(element, "WriteCount"), decoding_limits)?;
+        let history_update_count = ServiceCounterDataType::decode_xml(xml_child(element, "HistoryUpdateCount"), decoding_limits)?;
+        let call_count = ServiceCounterDataType::decode_xml(xml_child(element, "CallCount"), decoding_limits)?;
+        let create_monitored_items_count = ServiceCounterDataType::decode_xml(xml_child(element, "CreateMonitoredItemsCount"), decoding_limits)?;
+        let modify_monitored_items_count = ServiceCounterDataType::decode_xml(xml_child(element, "ModifyMonitoredItemsCount"), decoding_limits)?;
+        let set_monitoring_mode_count = ServiceCounterDataType::decode_xml(xml_child(element, "SetMonitoringModeCount"), decoding_limits)?;
+        let set_triggering_count = ServiceCounterDataType::decode_xml(xml_child(element, "SetTriggeringCount"), decoding_limits)?;
+        let delete_monitored_items_count = ServiceCounterDataType::decode_xml(xml_child(element, "DeleteMonitoredItemsCount"), decoding_limits)?;
+        let create_subscription_count = ServiceCounterDataType::decode_xml(xml_child(element, "CreateSubscriptionCount"), decoding_limits)?;
+        let modify_subscription_count = ServiceCounterDataType::decode_xml(xml_child(element, "ModifySubscriptionCount"), decoding_limits)?;
+        let set_publishing_mode_count = ServiceCounterDataType::decode_xml(xml_child(element, "SetPublishingModeCount"), decoding_limits)?;
+        let publish_count = ServiceCounterDataType::decode_xml(xml_child(element, "PublishCount"), decoding_limits)?;
+        let republish_count = ServiceCounterDataType::decode_xml(xml_child(element, "RepublishCount"), decoding_limits)?;
+        let transfer_subscriptions_count = ServiceCounterDataType::decode_xml(xml_child(element, "TransferSubscriptionsCount"), decoding_limits)?;
+        let delete_subscriptions_count = ServiceCounterDataType::decode_xml(xml_child(element, "DeleteSubscriptionsCount"), decoding_limits)?;
+        let add_nodes_count = ServiceCounterDataType::decode_xml(xml_child(element, "AddNodesCount"), decoding_limits)?;
+        let add_references_count = ServiceCounterDataType::decode_xml(xml_child(element, "AddReferencesCount"), decoding_limits)?;
+        let delete_nodes_count = ServiceCounterDataType::decode_xml(xml_child(element, "DeleteNodesCount"), decoding_limits)?;
+        let delete_references_count = ServiceCounterDataType::decode_xml(xml_child(element, "DeleteReferencesCount"), decoding_limits)?;
+        let browse_count = ServiceCounterDataType::decode_xml(xml_child(element, "BrowseCount"), decoding_limits)?;
+        let browse_next_count = ServiceCounterDataType::decode_xml(xml_child(element, "BrowseNextCount"), decoding_limits)?;
+        let translate_browse_paths_to_node_ids_count = ServiceCounterDataType::decode_xml(xml_child(element, "TranslateBrowsePathsToNodeIdsCount"), decoding_limits)?;
+        let query_first_count = ServiceCounterDataType::decode_xml(xml_child(element, "QueryFirstCount"), decoding_limits)?;
+        let query_next_count = ServiceCounterDataType::decode_xml(xml_child(element, "QueryNextCount"), decoding_limits)?;
+        let register_nodes_count = ServiceCounterDataType::decode_xml(xml_child(element, "RegisterNodesCount"), decoding_limits)?;
+        let unregister_nodes_count = ServiceCounterDataType::decode_xml(xml_child(element, "UnregisterNodesCount"), decoding_limits)?;
+        Ok(SessionDiagnosticsDataType {
+            session_id,
+            session_name,
+            client_description,
+            server_uri,
+            endpoint_url,
+            locale_ids,
+            actual_session_timeout,
+            max_response_message_size,
+            client_connection_time,
+            client_last_contact_time,
+            current_subscriptions_count,
+            current_monitored_items_count,
+            current_publish_requests_in_queue,
+            total_request_count,
+            unauthorized_request_count,
+            read_count,
+            history_read_count,
+            write_count,
+            history_update_count,
+            call_count,
+            create_monitored_items_count,
+            modify_monitored_items_count,
+            set_monitoring_mode_count,
+            set_triggering_count,
+            delete_monitored_items_count,
+            create_subscription_count,
+            modify_subscription_count,
+            set_publishing_mode_count,
+            publish_count,
+            republish_count,
+            transfer_subscriptions_count,
+            delete_subscriptions_count,
+            add_nodes_count,
+            add_references_count,
+            delete_nodes_count,
+            delete_references_count,
+            browse_count,
+            browse_next_count,
+            translate_browse_paths_to_node_ids_count,
+            query_first_count,
+            query_next_count,
+            register_nodes_count,
+            unregister_nodes_count,
+        })
+    }
+}
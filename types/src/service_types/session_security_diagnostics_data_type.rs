@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -88,3 +90,90 @@ impl BinaryEncoder<SessionSecurityDiagnosticsDataType> for SessionSecurityDiagno
         })
     }
 }
+
+impl JsonEncoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SessionId", self.session_id.encode_json(context));
+        insert_json_field(&mut fields, "ClientUserIdOfSession", self.client_user_id_of_session.encode_json(context));
+        insert_json_field(&mut fields, "ClientUserIdHistory", encode_json_array(&self.client_user_id_history, context));
+        insert_json_field(&mut fields, "AuthenticationMechanism", self.authentication_mechanism.encode_json(context));
+        insert_json_field(&mut fields, "Encoding", self.encoding.encode_json(context));
+        insert_json_field(&mut fields, "TransportProtocol", self.transport_protocol.encode_json(context));
+        insert_json_field(&mut fields, "SecurityMode", self.security_mode.encode_json(context));
+        insert_json_field(&mut fields, "SecurityPolicyUri", self.security_policy_uri.encode_json(context));
+        insert_json_field(&mut fields, "ClientCertificate", self.client_certificate.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let session_id = NodeId::decode_json(json_field(fields, "SessionId"), decoding_limits)?;
+        let client_user_id_of_session = UAString::decode_json(json_field(fields, "ClientUserIdOfSession"), decoding_limits)?;
+        let client_user_id_history: Option<Vec<UAString>> = decode_json_array(json_field(fields, "ClientUserIdHistory"), decoding_limits)?;
+        let authentication_mechanism = UAString::decode_json(json_field(fields, "AuthenticationMechanism"), decoding_limits)?;
+        let encoding = UAString::decode_json(json_field(fields, "Encoding"), decoding_limits)?;
+        let transport_protocol = UAString::decode_json(json_field(fields, "TransportProtocol"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_json(json_field(fields, "SecurityMode"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_json(json_field(fields, "SecurityPolicyUri"), decoding_limits)?;
+        let client_certificate = ByteString::decode_json(json_field(fields, "ClientCertificate"), decoding_limits)?;
+        Ok(SessionSecurityDiagnosticsDataType {
+            session_id,
+            client_user_id_of_session,
+            client_user_id_history,
+            authentication_mechanism,
+            encoding,
+            transport_protocol,
+            security_mode,
+            security_policy_uri,
+            client_certificate,
+        })
+    }
+}
+
+impl XmlEncoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.session_id.encode_xml("SessionId"));
+        element.add_child(self.client_user_id_of_session.encode_xml("ClientUserIdOfSession"));
+        element.add_child(encode_xml_array("ClientUserIdHistory", "String", &self.client_user_id_history));
+        element.add_child(self.authentication_mechanism.encode_xml("AuthenticationMechanism"));
+        element.add_child(self.encoding.encode_xml("Encoding"));
+        element.add_child(self.transport_protocol.encode_xml("TransportProtocol"));
+        element.add_child(self.security_mode.encode_xml("SecurityMode"));
+        element.add_child(self.security_policy_uri.encode_xml("SecurityPolicyUri"));
+        element.add_child(self.client_certificate.encode_xml("ClientCertificate"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SessionSecurityDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let session_id = NodeId::decode_xml(xml_child(element, "SessionId"), decoding_limits)?;
+        let client_user_id_of_session = UAString::decode_xml(xml_child(element, "ClientUserIdOfSession"), decoding_limits)?;
+        let client_user_id_history: Option<Vec<UAString>> = decode_xml_array(xml_child(element, "ClientUserIdHistory"), decoding_limits)?;
+        let authentication_mechanism = UAString::decode_xml(xml_child(element, "AuthenticationMechanism"), decoding_limits)?;
+        let encoding = UAString::decode_xml(xml_child(element, "Encoding"), decoding_limits)?;
+        let transport_protocol = UAString::decode_xml(xml_child(element, "TransportProtocol"), decoding_limits)?;
+        let security_mode = MessageSecurityMode::decode_xml(xml_child(element, "SecurityMode"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_xml(xml_child(element, "SecurityPolicyUri"), decoding_limits)?;
+        let client_certificate = ByteString::decode_xml(xml_child(element, "ClientCertificate"), decoding_limits)?;
+        Ok(SessionSecurityDiagnosticsDataType {
+            session_id,
+            client_user_id_of_session,
+            client_user_id_history,
+            authentication_mechanism,
+            encoding,
+            transport_protocol,
+            security_mode,
+            security_policy_uri,
+            client_certificate,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -61,3 +63,60 @@ impl BinaryEncoder<SetMonitoringModeRequest> for SetMonitoringModeRequest {
         })
     }
 }
+
+impl JsonEncoder for SetMonitoringModeRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "MonitoringMode", self.monitoring_mode.encode_json(context));
+        insert_json_field(&mut fields, "MonitoredItemIds", encode_json_array(&self.monitored_item_ids, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SetMonitoringModeRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let monitoring_mode = MonitoringMode::decode_json(json_field(fields, "MonitoringMode"), decoding_limits)?;
+        let monitored_item_ids: Option<Vec<u32>> = decode_json_array(json_field(fields, "MonitoredItemIds"), decoding_limits)?;
+        Ok(SetMonitoringModeRequest {
+            request_header,
+            subscription_id,
+            monitoring_mode,
+            monitored_item_ids,
+        })
+    }
+}
+
+impl XmlEncoder for SetMonitoringModeRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.monitoring_mode.encode_xml("MonitoringMode"));
+        element.add_child(encode_xml_array("MonitoredItemIds", "UInt32", &self.monitored_item_ids));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SetMonitoringModeRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let monitoring_mode = MonitoringMode::decode_xml(xml_child(element, "MonitoringMode"), decoding_limits)?;
+        let monitored_item_ids: Option<Vec<u32>> = decode_xml_array(xml_child(element, "MonitoredItemIds"), decoding_limits)?;
+        Ok(SetMonitoringModeRequest {
+            request_header,
+            subscription_id,
+            monitoring_mode,
+            monitored_item_ids,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -65,3 +67,66 @@ impl BinaryEncoder<SetTriggeringRequest> for SetTriggeringRequest {
         })
     }
 }
+
+impl JsonEncoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "TriggeringItemId", self.triggering_item_id.encode_json(context));
+        insert_json_field(&mut fields, "LinksToAdd", encode_json_array(&self.links_to_add, context));
+        insert_json_field(&mut fields, "LinksToRemove", encode_json_array(&self.links_to_remove, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let triggering_item_id = u32::decode_json(json_field(fields, "TriggeringItemId"), decoding_limits)?;
+        let links_to_add: Option<Vec<u32>> = decode_json_array(json_field(fields, "LinksToAdd"), decoding_limits)?;
+        let links_to_remove: Option<Vec<u32>> = decode_json_array(json_field(fields, "LinksToRemove"), decoding_limits)?;
+        Ok(SetTriggeringRequest {
+            request_header,
+            subscription_id,
+            triggering_item_id,
+            links_to_add,
+            links_to_remove,
+        })
+    }
+}
+
+impl XmlEncoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.triggering_item_id.encode_xml("TriggeringItemId"));
+        element.add_child(encode_xml_array("LinksToAdd", "UInt32", &self.links_to_add));
+        element.add_child(encode_xml_array("LinksToRemove", "UInt32", &self.links_to_remove));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SetTriggeringRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let triggering_item_id = u32::decode_xml(xml_child(element, "TriggeringItemId"), decoding_limits)?;
+        let links_to_add: Option<Vec<u32>> = decode_xml_array(xml_child(element, "LinksToAdd"), decoding_limits)?;
+        let links_to_remove: Option<Vec<u32>> = decode_xml_array(xml_child(element, "LinksToRemove"), decoding_limits)?;
+        Ok(SetTriggeringRequest {
+            request_header,
+            subscription_id,
+            triggering_item_id,
+            links_to_add,
+            links_to_remove,
+        })
+    }
+}
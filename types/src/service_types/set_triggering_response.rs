@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +69,66 @@ impl BinaryEncoder<SetTriggeringResponse> for SetTriggeringResponse {
         })
     }
 }
+
+impl JsonEncoder for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ResponseHeader", self.response_header.encode_json(context));
+        insert_json_field(&mut fields, "AddResults", encode_json_array(&self.add_results, context));
+        insert_json_field(&mut fields, "AddDiagnosticInfos", encode_json_array(&self.add_diagnostic_infos, context));
+        insert_json_field(&mut fields, "RemoveResults", encode_json_array(&self.remove_results, context));
+        insert_json_field(&mut fields, "RemoveDiagnosticInfos", encode_json_array(&self.remove_diagnostic_infos, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let response_header = ResponseHeader::decode_json(json_field(fields, "ResponseHeader"), decoding_limits)?;
+        let add_results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "AddResults"), decoding_limits)?;
+        let add_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "AddDiagnosticInfos"), decoding_limits)?;
+        let remove_results: Option<Vec<StatusCode>> = decode_json_array(json_field(fields, "RemoveResults"), decoding_limits)?;
+        let remove_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_json_array(json_field(fields, "RemoveDiagnosticInfos"), decoding_limits)?;
+        Ok(SetTriggeringResponse {
+            response_header,
+            add_results,
+            add_diagnostic_infos,
+            remove_results,
+            remove_diagnostic_infos,
+        })
+    }
+}
+
+impl XmlEncoder for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.response_header.encode_xml("ResponseHeader"));
+        element.add_child(encode_xml_array("AddResults", "StatusCode", &self.add_results));
+        element.add_child(encode_xml_array("AddDiagnosticInfos", "DiagnosticInfo", &self.add_diagnostic_infos));
+        element.add_child(encode_xml_array("RemoveResults", "StatusCode", &self.remove_results));
+        element.add_child(encode_xml_array("RemoveDiagnosticInfos", "DiagnosticInfo", &self.remove_diagnostic_infos));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SetTriggeringResponse {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let response_header = ResponseHeader::decode_xml(xml_child(element, "ResponseHeader"), decoding_limits)?;
+        let add_results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "AddResults"), decoding_limits)?;
+        let add_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "AddDiagnosticInfos"), decoding_limits)?;
+        let remove_results: Option<Vec<StatusCode>> = decode_xml_array(xml_child(element, "RemoveResults"), decoding_limits)?;
+        let remove_diagnostic_infos: Option<Vec<DiagnosticInfo>> = decode_xml_array(xml_child(element, "RemoveDiagnosticInfos"), decoding_limits)?;
+        Ok(SetTriggeringResponse {
+            response_header,
+            add_results,
+            add_diagnostic_infos,
+            remove_results,
+            remove_diagnostic_infos,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<SignatureData> for SignatureData {
         })
     }
 }
+
+impl JsonEncoder for SignatureData {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Algorithm", self.algorithm.encode_json(context));
+        insert_json_field(&mut fields, "Signature", self.signature.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SignatureData {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let algorithm = UAString::decode_json(json_field(fields, "Algorithm"), decoding_limits)?;
+        let signature = ByteString::decode_json(json_field(fields, "Signature"), decoding_limits)?;
+        Ok(SignatureData {
+            algorithm,
+            signature,
+        })
+    }
+}
+
+impl XmlEncoder for SignatureData {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.algorithm.encode_xml("Algorithm"));
+        element.add_child(self.signature.encode_xml("Signature"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SignatureData {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let algorithm = UAString::decode_xml(xml_child(element, "Algorithm"), decoding_limits)?;
+        let signature = ByteString::decode_xml(xml_child(element, "Signature"), decoding_limits)?;
+        Ok(SignatureData {
+            algorithm,
+            signature,
+        })
+    }
+}
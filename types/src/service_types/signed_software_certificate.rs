@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<SignedSoftwareCertificate> for SignedSoftwareCertificate {
         })
     }
 }
+
+impl JsonEncoder for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "CertificateData", self.certificate_data.encode_json(context));
+        insert_json_field(&mut fields, "Signature", self.signature.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let certificate_data = ByteString::decode_json(json_field(fields, "CertificateData"), decoding_limits)?;
+        let signature = ByteString::decode_json(json_field(fields, "Signature"), decoding_limits)?;
+        Ok(SignedSoftwareCertificate {
+            certificate_data,
+            signature,
+        })
+    }
+}
+
+impl XmlEncoder for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.certificate_data.encode_xml("CertificateData"));
+        element.add_child(self.signature.encode_xml("Signature"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SignedSoftwareCertificate {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let certificate_data = ByteString::decode_xml(xml_child(element, "CertificateData"), decoding_limits)?;
+        let signature = ByteString::decode_xml(xml_child(element, "Signature"), decoding_limits)?;
+        Ok(SignedSoftwareCertificate {
+            certificate_data,
+            signature,
+        })
+    }
+}
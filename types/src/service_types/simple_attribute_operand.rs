@@ -6,13 +6,15 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     node_id::NodeId,
     basic_types::QualifiedName,
     string::UAString,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SimpleAttributeOperand {
     pub type_definition_id: NodeId,
     pub browse_path: Option<Vec<QualifiedName>>,
@@ -54,3 +56,60 @@ impl BinaryEncoder<SimpleAttributeOperand> for SimpleAttributeOperand {
         })
     }
 }
+
+impl JsonEncoder for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "TypeDefinitionId", self.type_definition_id.encode_json(context));
+        insert_json_field(&mut fields, "BrowsePath", encode_json_array(&self.browse_path, context));
+        insert_json_field(&mut fields, "AttributeId", self.attribute_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let type_definition_id = NodeId::decode_json(json_field(fields, "TypeDefinitionId"), decoding_limits)?;
+        let browse_path: Option<Vec<QualifiedName>> = decode_json_array(json_field(fields, "BrowsePath"), decoding_limits)?;
+        let attribute_id = u32::decode_json(json_field(fields, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        Ok(SimpleAttributeOperand {
+            type_definition_id,
+            browse_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
+
+impl XmlEncoder for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.type_definition_id.encode_xml("TypeDefinitionId"));
+        element.add_child(encode_xml_array("BrowsePath", "QualifiedName", &self.browse_path));
+        element.add_child(self.attribute_id.encode_xml("AttributeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SimpleAttributeOperand {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let type_definition_id = NodeId::decode_xml(xml_child(element, "TypeDefinitionId"), decoding_limits)?;
+        let browse_path: Option<Vec<QualifiedName>> = decode_xml_array(xml_child(element, "BrowsePath"), decoding_limits)?;
+        let attribute_id = u32::decode_xml(xml_child(element, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        Ok(SimpleAttributeOperand {
+            type_definition_id,
+            browse_path,
+            attribute_id,
+            index_range,
+        })
+    }
+}
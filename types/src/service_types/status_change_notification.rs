@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     status_codes::StatusCode,
     diagnostic_info::DiagnosticInfo,
@@ -43,3 +45,48 @@ impl BinaryEncoder<StatusChangeNotification> for StatusChangeNotification {
         })
     }
 }
+
+impl JsonEncoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Status", self.status.encode_json(context));
+        insert_json_field(&mut fields, "DiagnosticInfo", self.diagnostic_info.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status = StatusCode::decode_json(json_field(fields, "Status"), decoding_limits)?;
+        let diagnostic_info = DiagnosticInfo::decode_json(json_field(fields, "DiagnosticInfo"), decoding_limits)?;
+        Ok(StatusChangeNotification {
+            status,
+            diagnostic_info,
+        })
+    }
+}
+
+impl XmlEncoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status.encode_xml("Status"));
+        element.add_child(self.diagnostic_info.encode_xml("DiagnosticInfo"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for StatusChangeNotification {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status = StatusCode::decode_xml(xml_child(element, "Status"), decoding_limits)?;
+        let diagnostic_info = DiagnosticInfo::decode_xml(xml_child(element, "DiagnosticInfo"), decoding_limits)?;
+        Ok(StatusChangeNotification {
+            status,
+            diagnostic_info,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<StatusResult> for StatusResult {
         })
     }
 }
+
+impl JsonEncoder for StatusResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "DiagnosticInfo", self.diagnostic_info.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for StatusResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let diagnostic_info = DiagnosticInfo::decode_json(json_field(fields, "DiagnosticInfo"), decoding_limits)?;
+        Ok(StatusResult {
+            status_code,
+            diagnostic_info,
+        })
+    }
+}
+
+impl XmlEncoder for StatusResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(self.diagnostic_info.encode_xml("DiagnosticInfo"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for StatusResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let diagnostic_info = DiagnosticInfo::decode_xml(xml_child(element, "DiagnosticInfo"), decoding_limits)?;
+        Ok(StatusResult {
+            status_code,
+            diagnostic_info,
+        })
+    }
+}
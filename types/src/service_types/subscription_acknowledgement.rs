@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<SubscriptionAcknowledgement> for SubscriptionAcknowledgement
         })
     }
 }
+
+impl JsonEncoder for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "SequenceNumber", self.sequence_number.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let sequence_number = u32::decode_json(json_field(fields, "SequenceNumber"), decoding_limits)?;
+        Ok(SubscriptionAcknowledgement {
+            subscription_id,
+            sequence_number,
+        })
+    }
+}
+
+impl XmlEncoder for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.sequence_number.encode_xml("SequenceNumber"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SubscriptionAcknowledgement {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let sequence_number = u32::decode_xml(xml_child(element, "SequenceNumber"), decoding_limits)?;
+        Ok(SubscriptionAcknowledgement {
+            subscription_id,
+            sequence_number,
+        })
+    }
+}
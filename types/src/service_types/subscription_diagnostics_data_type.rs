@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -195,3 +197,222 @@ impl BinaryEncoder<SubscriptionDiagnosticsDataType> for SubscriptionDiagnosticsD
         })
     }
 }
+
+impl JsonEncoder for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SessionId", self.session_id.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionId", self.subscription_id.encode_json(context));
+        insert_json_field(&mut fields, "Priority", self.priority.encode_json(context));
+        insert_json_field(&mut fields, "PublishingInterval", self.publishing_interval.encode_json(context));
+        insert_json_field(&mut fields, "MaxKeepAliveCount", self.max_keep_alive_count.encode_json(context));
+        insert_json_field(&mut fields, "MaxLifetimeCount", self.max_lifetime_count.encode_json(context));
+        insert_json_field(&mut fields, "MaxNotificationsPerPublish", self.max_notifications_per_publish.encode_json(context));
+        insert_json_field(&mut fields, "PublishingEnabled", self.publishing_enabled.encode_json(context));
+        insert_json_field(&mut fields, "ModifyCount", self.modify_count.encode_json(context));
+        insert_json_field(&mut fields, "EnableCount", self.enable_count.encode_json(context));
+        insert_json_field(&mut fields, "DisableCount", self.disable_count.encode_json(context));
+        insert_json_field(&mut fields, "RepublishRequestCount", self.republish_request_count.encode_json(context));
+        insert_json_field(&mut fields, "RepublishMessageRequestCount", self.republish_message_request_count.encode_json(context));
+        insert_json_field(&mut fields, "RepublishMessageCount", self.republish_message_count.encode_json(context));
+        insert_json_field(&mut fields, "TransferRequestCount", self.transfer_request_count.encode_json(context));
+        insert_json_field(&mut fields, "TransferredToAltClientCount", self.transferred_to_alt_client_count.encode_json(context));
+        insert_json_field(&mut fields, "TransferredToSameClientCount", self.transferred_to_same_client_count.encode_json(context));
+        insert_json_field(&mut fields, "PublishRequestCount", self.publish_request_count.encode_json(context));
+        insert_json_field(&mut fields, "DataChangeNotificationsCount", self.data_change_notifications_count.encode_json(context));
+        insert_json_field(&mut fields, "EventNotificationsCount", self.event_notifications_count.encode_json(context));
+        insert_json_field(&mut fields, "NotificationsCount", self.notifications_count.encode_json(context));
+        insert_json_field(&mut fields, "LatePublishRequestCount", self.late_publish_request_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentKeepAliveCount", self.current_keep_alive_count.encode_json(context));
+        insert_json_field(&mut fields, "CurrentLifetimeCount", self.current_lifetime_count.encode_json(context));
+        insert_json_field(&mut fields, "UnacknowledgedMessageCount", self.unacknowledged_message_count.encode_json(context));
+        insert_json_field(&mut fields, "DiscardedMessageCount", self.discarded_message_count.encode_json(context));
+        insert_json_field(&mut fields, "MonitoredItemCount", self.monitored_item_count.encode_json(context));
+        insert_json_field(&mut fields, "DisabledMonitoredItemCount", self.disabled_monitored_item_count.encode_json(context));
+        insert_json_field(&mut fields, "MonitoringQueueOverflowCount", self.monitoring_queue_overflow_count.encode_json(context));
+        insert_json_field(&mut fields, "NextSequenceNumber", self.next_sequence_number.encode_json(context));
+        insert_json_field(&mut fields, "EventQueueOverFlowCount", self.event_queue_over_flow_count.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let session_id = NodeId::decode_json(json_field(fields, "SessionId"), decoding_limits)?;
+        let subscription_id = u32::decode_json(json_field(fields, "SubscriptionId"), decoding_limits)?;
+        let priority = u8::decode_json(json_field(fields, "Priority"), decoding_limits)?;
+        let publishing_interval = f64::decode_json(json_field(fields, "PublishingInterval"), decoding_limits)?;
+        let max_keep_alive_count = u32::decode_json(json_field(fields, "MaxKeepAliveCount"), decoding_limits)?;
+        let max_lifetime_count = u32::decode_json(json_field(fields, "MaxLifetimeCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_json(json_field(fields, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let publishing_enabled = bool::decode_json(json_field(fields, "PublishingEnabled"), decoding_limits)?;
+        let modify_count = u32::decode_json(json_field(fields, "ModifyCount"), decoding_limits)?;
+        let enable_count = u32::decode_json(json_field(fields, "EnableCount"), decoding_limits)?;
+        let disable_count = u32::decode_json(json_field(fields, "DisableCount"), decoding_limits)?;
+        let republish_request_count = u32::decode_json(json_field(fields, "RepublishRequestCount"), decoding_limits)?;
+        let republish_message_request_count = u32::decode_json(json_field(fields, "RepublishMessageRequestCount"), decoding_limits)?;
+        let republish_message_count = u32::decode_json(json_field(fields, "RepublishMessageCount"), decoding_limits)?;
+        let transfer_request_count = u32::decode_json(json_field(fields, "TransferRequestCount"), decoding_limits)?;
+        let transferred_to_alt_client_count = u32::decode_json(json_field(fields, "TransferredToAltClientCount"), decoding_limits)?;
+        let transferred_to_same_client_count = u32::decode_json(json_field(fields, "TransferredToSameClientCount"), decoding_limits)?;
+        let publish_request_count = u32::decode_json(json_field(fields, "PublishRequestCount"), decoding_limits)?;
+        let data_change_notifications_count = u32::decode_json(json_field(fields, "DataChangeNotificationsCount"), decoding_limits)?;
+        let event_notifications_count = u32::decode_json(json_field(fields, "EventNotificationsCount"), decoding_limits)?;
+        let notifications_count = u32::decode_json(json_field(fields, "NotificationsCount"), decoding_limits)?;
+        let late_publish_request_count = u32::decode_json(json_field(fields, "LatePublishRequestCount"), decoding_limits)?;
+        let current_keep_alive_count = u32::decode_json(json_field(fields, "CurrentKeepAliveCount"), decoding_limits)?;
+        let current_lifetime_count = u32::decode_json(json_field(fields, "CurrentLifetimeCount"), decoding_limits)?;
+        let unacknowledged_message_count = u32::decode_json(json_field(fields, "UnacknowledgedMessageCount"), decoding_limits)?;
+        let discarded_message_count = u32::decode_json(json_field(fields, "DiscardedMessageCount"), decoding_limits)?;
+        let monitored_item_count = u32::decode_json(json_field(fields, "MonitoredItemCount"), decoding_limits)?;
+        let disabled_monitored_item_count = u32::decode_json(json_field(fields, "DisabledMonitoredItemCount"), decoding_limits)?;
+        let monitoring_queue_overflow_count = u32::decode_json(json_field(fields, "MonitoringQueueOverflowCount"), decoding_limits)?;
+        let next_sequence_number = u32::decode_json(json_field(fields, "NextSequenceNumber"), decoding_limits)?;
+        let event_queue_over_flow_count = u32::decode_json(json_field(fields, "EventQueueOverFlowCount"), decoding_limits)?;
+        Ok(SubscriptionDiagnosticsDataType {
+            session_id,
+            subscription_id,
+            priority,
+            publishing_interval,
+            max_keep_alive_count,
+            max_lifetime_count,
+            max_notifications_per_publish,
+            publishing_enabled,
+            modify_count,
+            enable_count,
+            disable_count,
+            republish_request_count,
+            republish_message_request_count,
+            republish_message_count,
+            transfer_request_count,
+            transferred_to_alt_client_count,
+            transferred_to_same_client_count,
+            publish_request_count,
+            data_change_notifications_count,
+            event_notifications_count,
+            notifications_count,
+            late_publish_request_count,
+            current_keep_alive_count,
+            current_lifetime_count,
+            unacknowledged_message_count,
+            discarded_message_count,
+            monitored_item_count,
+            disabled_monitored_item_count,
+            monitoring_queue_overflow_count,
+            next_sequence_number,
+            event_queue_over_flow_count,
+        })
+    }
+}
+
+impl XmlEncoder for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.session_id.encode_xml("SessionId"));
+        element.add_child(self.subscription_id.encode_xml("SubscriptionId"));
+        element.add_child(self.priority.encode_xml("Priority"));
+        element.add_child(self.publishing_interval.encode_xml("PublishingInterval"));
+        element.add_child(self.max_keep_alive_count.encode_xml("MaxKeepAliveCount"));
+        element.add_child(self.max_lifetime_count.encode_xml("MaxLifetimeCount"));
+        element.add_child(self.max_notifications_per_publish.encode_xml("MaxNotificationsPerPublish"));
+        element.add_child(self.publishing_enabled.encode_xml("PublishingEnabled"));
+        element.add_child(self.modify_count.encode_xml("ModifyCount"));
+        element.add_child(self.enable_count.encode_xml("EnableCount"));
+        element.add_child(self.disable_count.encode_xml("DisableCount"));
+        element.add_child(self.republish_request_count.encode_xml("RepublishRequestCount"));
+        element.add_child(self.republish_message_request_count.encode_xml("RepublishMessageRequestCount"));
+        element.add_child(self.republish_message_count.encode_xml("RepublishMessageCount"));
+        element.add_child(self.transfer_request_count.encode_xml("TransferRequestCount"));
+        element.add_child(self.transferred_to_alt_client_count.encode_xml("TransferredToAltClientCount"));
+        element.add_child(self.transferred_to_same_client_count.encode_xml("TransferredToSameClientCount"));
+        element.add_child(self.publish_request_count.encode_xml("PublishRequestCount"));
+        element.add_child(self.data_change_notifications_count.encode_xml("DataChangeNotificationsCount"));
+        element.add_child(self.event_notifications_count.encode_xml("EventNotificationsCount"));
+        element.add_child(self.notifications_count.encode_xml("NotificationsCount"));
+        element.add_child(self.late_publish_request_count.encode_xml("LatePublishRequestCount"));
+        element.add_child(self.current_keep_alive_count.encode_xml("CurrentKeepAliveCount"));
+        element.add_child(self.current_lifetime_count.encode_xml("CurrentLifetimeCount"));
+        element.add_child(self.unacknowledged_message_count.encode_xml("UnacknowledgedMessageCount"));
+        element.add_child(self.discarded_message_count.encode_xml("DiscardedMessageCount"));
+        element.add_child(self.monitored_item_count.encode_xml("MonitoredItemCount"));
+        element.add_child(self.disabled_monitored_item_count.encode_xml("DisabledMonitoredItemCount"));
+        element.add_child(self.monitoring_queue_overflow_count.encode_xml("MonitoringQueueOverflowCount"));
+        element.add_child(self.next_sequence_number.encode_xml("NextSequenceNumber"));
+        element.add_child(self.event_queue_over_flow_count.encode_xml("EventQueueOverFlowCount"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for SubscriptionDiagnosticsDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let session_id = NodeId::decode_xml(xml_child(element, "SessionId"), decoding_limits)?;
+        let subscription_id = u32::decode_xml(xml_child(element, "SubscriptionId"), decoding_limits)?;
+        let priority = u8::decode_xml(xml_child(element, "Priority"), decoding_limits)?;
+        let publishing_interval = f64::decode_xml(xml_child(element, "PublishingInterval"), decoding_limits)?;
+        let max_keep_alive_count = u32::decode_xml(xml_child(element, "MaxKeepAliveCount"), decoding_limits)?;
+        let max_lifetime_count = u32::decode_xml(xml_child(element, "MaxLifetimeCount"), decoding_limits)?;
+        let max_notifications_per_publish = u32::decode_xml(xml_child(element, "MaxNotificationsPerPublish"), decoding_limits)?;
+        let publishing_enabled = bool::decode_xml(xml_child(element, "PublishingEnabled"), decoding_limits)?;
+        let modify_count = u32::decode_xml(xml_child(element, "ModifyCount"), decoding_limits)?;
+        let enable_count = u32::decode_xml(xml_child(element, "EnableCount"), decoding_limits)?;
+        let disable_count = u32::decode_xml(xml_child(element, "DisableCount"), decoding_limits)?;
+        let republish_request_count = u32::decode_xml(xml_child(element, "RepublishRequestCount"), decoding_limits)?;
+        let republish_message_request_count = u32::decode_xml(xml_child(element, "RepublishMessageRequestCount"), decoding_limits)?;
+        let republish_message_count = u32::decode_xml(xml_child(element, "RepublishMessageCount"), decoding_limits)?;
+        let transfer_request_count = u32::decode_xml(xml_child(element, "TransferRequestCount"), decoding_limits)?;
+        let transferred_to_alt_client_count = u32::decode_xml(xml_child(element, "TransferredToAltClientCount"), decoding_limits)?;
+        let transferred_to_same_client_count = u32::decode_xml(xml_child(element, "TransferredToSameClientCount"), decoding_limits)?;
+        let publish_request_count = u32::decode_xml(xml_child(element, "PublishRequestCount"), decoding_limits)?;
+        let data_change_notifications_count = u32::decode_xml(xml_child(element, "DataChangeNotificationsCount"), decoding_limits)?;
+        let event_notifications_count = u32::decode_xml(xml_child(element, "EventNotificationsCount"), decoding_limits)?;
+        let notifications_count = u32::decode_xml(xml_child(element, "NotificationsCount"), decoding_limits)?;
+        let late_publish_request_count = u32::decode_xml(xml_child(element, "LatePublishRequestCount"), decoding_limits)?;
+        let current_keep_alive_count = u32::decode_xml(xml_child(element, "CurrentKeepAliveCount"), decoding_limits)?;
+        let current_lifetime_count = u32::decode_xml(xml_child(element, "CurrentLifetimeCount"), decoding_limits)?;
+        let unacknowledged_message_count = u32::decode_xml(xml_child(element, "UnacknowledgedMessageCount"), decoding_limits)?;
+        let discarded_message_count = u32::decode_xml(xml_child(element, "DiscardedMessageCount"), decoding_limits)?;
+        let monitored_item_count = u32::decode_xml(xml_child(element, "MonitoredItemCount"), decoding_limits)?;
+        let disabled_monitored_item_count = u32::decode_xml(xml_child(element, "DisabledMonitoredItemCount"), decoding_limits)?;
+        let monitoring_queue_overflow_count = u32::decode_xml(xml_child(element, "MonitoringQueueOverflowCount"), decoding_limits)?;
+        let next_sequence_number = u32::decode_xml(xml_child(element, "NextSequenceNumber"), decoding_limits)?;
+        let event_queue_over_flow_count = u32::decode_xml(xml_child(element, "EventQueueOverFlowCount"), decoding_limits)?;
+        Ok(SubscriptionDiagnosticsDataType {
+            session_id,
+            subscription_id,
+            priority,
+            publishing_interval,
+            max_keep_alive_count,
+            max_lifetime_count,
+            max_notifications_per_publish,
+            publishing_enabled,
+            modify_count,
+            enable_count,
+            disable_count,
+            republish_request_count,
+            republish_message_request_count,
+            republish_message_count,
+            transfer_request_count,
+            transferred_to_alt_client_count,
+            transferred_to_same_client_count,
+            publish_request_count,
+            data_change_notifications_count,
+            event_notifications_count,
+            notifications_count,
+            late_publish_request_count,
+            current_keep_alive_count,
+            current_lifetime_count,
+            unacknowledged_message_count,
+            discarded_message_count,
+            monitored_item_count,
+            disabled_monitored_item_count,
+            monitoring_queue_overflow_count,
+            next_sequence_number,
+            event_queue_over_flow_count,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<TimeZoneDataType> for TimeZoneDataType {
         })
     }
 }
+
+impl JsonEncoder for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "Offset", self.offset.encode_json(context));
+        insert_json_field(&mut fields, "DaylightSavingInOffset", self.daylight_saving_in_offset.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let offset = i16::decode_json(json_field(fields, "Offset"), decoding_limits)?;
+        let daylight_saving_in_offset = bool::decode_json(json_field(fields, "DaylightSavingInOffset"), decoding_limits)?;
+        Ok(TimeZoneDataType {
+            offset,
+            daylight_saving_in_offset,
+        })
+    }
+}
+
+impl XmlEncoder for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.offset.encode_xml("Offset"));
+        element.add_child(self.daylight_saving_in_offset.encode_xml("DaylightSavingInOffset"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for TimeZoneDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let offset = i16::decode_xml(xml_child(element, "Offset"), decoding_limits)?;
+        let daylight_saving_in_offset = bool::decode_xml(xml_child(element, "DaylightSavingInOffset"), decoding_limits)?;
+        Ok(TimeZoneDataType {
+            offset,
+            daylight_saving_in_offset,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -50,3 +52,48 @@ impl BinaryEncoder<TransferResult> for TransferResult {
         })
     }
 }
+
+impl JsonEncoder for TransferResult {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "StatusCode", self.status_code.encode_json(context));
+        insert_json_field(&mut fields, "AvailableSequenceNumbers", encode_json_array(&self.available_sequence_numbers, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for TransferResult {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let status_code = StatusCode::decode_json(json_field(fields, "StatusCode"), decoding_limits)?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_json_array(json_field(fields, "AvailableSequenceNumbers"), decoding_limits)?;
+        Ok(TransferResult {
+            status_code,
+            available_sequence_numbers,
+        })
+    }
+}
+
+impl XmlEncoder for TransferResult {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.status_code.encode_xml("StatusCode"));
+        element.add_child(encode_xml_array("AvailableSequenceNumbers", "UInt32", &self.available_sequence_numbers));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for TransferResult {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let status_code = StatusCode::decode_xml(xml_child(element, "StatusCode"), decoding_limits)?;
+        let available_sequence_numbers: Option<Vec<u32>> = decode_xml_array(xml_child(element, "AvailableSequenceNumbers"), decoding_limits)?;
+        Ok(TransferResult {
+            status_code,
+            available_sequence_numbers,
+        })
+    }
+}
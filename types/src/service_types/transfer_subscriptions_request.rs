@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -55,3 +57,54 @@ impl BinaryEncoder<TransferSubscriptionsRequest> for TransferSubscriptionsReques
         })
     }
 }
+
+impl JsonEncoder for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "SubscriptionIds", encode_json_array(&self.subscription_ids, context));
+        insert_json_field(&mut fields, "SendInitialValues", self.send_initial_values.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let subscription_ids: Option<Vec<u32>> = decode_json_array(json_field(fields, "SubscriptionIds"), decoding_limits)?;
+        let send_initial_values = bool::decode_json(json_field(fields, "SendInitialValues"), decoding_limits)?;
+        Ok(TransferSubscriptionsRequest {
+            request_header,
+            subscription_ids,
+            send_initial_values,
+        })
+    }
+}
+
+impl XmlEncoder for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("SubscriptionIds", "UInt32", &self.subscription_ids));
+        element.add_child(self.send_initial_values.encode_xml("SendInitialValues"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for TransferSubscriptionsRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let subscription_ids: Option<Vec<u32>> = decode_xml_array(xml_child(element, "SubscriptionIds"), decoding_limits)?;
+        let send_initial_values = bool::decode_xml(xml_child(element, "SendInitialValues"), decoding_limits)?;
+        Ok(TransferSubscriptionsRequest {
+            request_header,
+            subscription_ids,
+            send_initial_values,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -52,3 +54,48 @@ impl BinaryEncoder<TranslateBrowsePathsToNodeIdsRequest> for TranslateBrowsePath
         })
     }
 }
+
+impl JsonEncoder for TranslateBrowsePathsToNodeIdsRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "BrowsePaths", encode_json_array(&self.browse_paths, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for TranslateBrowsePathsToNodeIdsRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let browse_paths: Option<Vec<BrowsePath>> = decode_json_array(json_field(fields, "BrowsePaths"), decoding_limits)?;
+        Ok(TranslateBrowsePathsToNodeIdsRequest {
+            request_header,
+            browse_paths,
+        })
+    }
+}
+
+impl XmlEncoder for TranslateBrowsePathsToNodeIdsRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("BrowsePaths", "BrowsePath", &self.browse_paths));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for TranslateBrowsePathsToNodeIdsRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let browse_paths: Option<Vec<BrowsePath>> = decode_xml_array(xml_child(element, "BrowsePaths"), decoding_limits)?;
+        Ok(TranslateBrowsePathsToNodeIdsRequest {
+            request_header,
+            browse_paths,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -65,3 +67,66 @@ impl BinaryEncoder<TrustListDataType> for TrustListDataType {
         })
     }
 }
+
+impl JsonEncoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SpecifiedLists", self.specified_lists.encode_json(context));
+        insert_json_field(&mut fields, "TrustedCertificates", encode_json_array(&self.trusted_certificates, context));
+        insert_json_field(&mut fields, "TrustedCrls", encode_json_array(&self.trusted_crls, context));
+        insert_json_field(&mut fields, "IssuerCertificates", encode_json_array(&self.issuer_certificates, context));
+        insert_json_field(&mut fields, "IssuerCrls", encode_json_array(&self.issuer_crls, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let specified_lists = u32::decode_json(json_field(fields, "SpecifiedLists"), decoding_limits)?;
+        let trusted_certificates: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "TrustedCertificates"), decoding_limits)?;
+        let trusted_crls: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "TrustedCrls"), decoding_limits)?;
+        let issuer_certificates: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "IssuerCertificates"), decoding_limits)?;
+        let issuer_crls: Option<Vec<ByteString>> = decode_json_array(json_field(fields, "IssuerCrls"), decoding_limits)?;
+        Ok(TrustListDataType {
+            specified_lists,
+            trusted_certificates,
+            trusted_crls,
+            issuer_certificates,
+            issuer_crls,
+        })
+    }
+}
+
+impl XmlEncoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.specified_lists.encode_xml("SpecifiedLists"));
+        element.add_child(encode_xml_array("TrustedCertificates", "ByteString", &self.trusted_certificates));
+        element.add_child(encode_xml_array("TrustedCrls", "ByteString", &self.trusted_crls));
+        element.add_child(encode_xml_array("IssuerCertificates", "ByteString", &self.issuer_certificates));
+        element.add_child(encode_xml_array("IssuerCrls", "ByteString", &self.issuer_crls));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for TrustListDataType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let specified_lists = u32::decode_xml(xml_child(element, "SpecifiedLists"), decoding_limits)?;
+        let trusted_certificates: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "TrustedCertificates"), decoding_limits)?;
+        let trusted_crls: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "TrustedCrls"), decoding_limits)?;
+        let issuer_certificates: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "IssuerCertificates"), decoding_limits)?;
+        let issuer_crls: Option<Vec<ByteString>> = decode_xml_array(xml_child(element, "IssuerCrls"), decoding_limits)?;
+        Ok(TrustListDataType {
+            specified_lists,
+            trusted_certificates,
+            trusted_crls,
+            issuer_certificates,
+            issuer_crls,
+        })
+    }
+}
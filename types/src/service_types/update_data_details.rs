@@ -0,0 +1,104 @@
+// This file was autogenerated from Opc.Ua.Types.bsd.xml by tools/schema/gen_types.js
+// DO NOT EDIT THIS FILE
+
+use std::io::{Read, Write};
+
+#[allow(unused_imports)]
+use crate::{
+    encoding::*,
+    json::*,
+    xml::*,
+    basic_types::*,
+    node_id::NodeId,
+    service_types::enums::PerformUpdateType,
+    data_value::DataValue,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateDataDetails {
+    pub node_id: NodeId,
+    pub perform_insert_replace: PerformUpdateType,
+    pub update_values: Option<Vec<DataValue>>,
+}
+
+impl BinaryEncoder<UpdateDataDetails> for UpdateDataDetails {
+    fn byte_len(&self) -> usize {
+        let mut size = 0;
+        size += self.node_id.byte_len();
+        size += self.perform_insert_replace.byte_len();
+        size += byte_len_array(&self.update_values);
+        size
+    }
+
+    #[allow(unused_variables)]
+    fn encode<S: Write>(&self, stream: &mut S) -> EncodingResult<usize> {
+        let mut size = 0;
+        size += self.node_id.encode(stream)?;
+        size += self.perform_insert_replace.encode(stream)?;
+        size += write_array(stream, &self.update_values)?;
+        Ok(size)
+    }
+
+    #[allow(unused_variables)]
+    fn decode<S: Read>(stream: &mut S, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode(stream, decoding_limits)?;
+        let perform_insert_replace = PerformUpdateType::decode(stream, decoding_limits)?;
+        let update_values: Option<Vec<DataValue>> = read_array(stream, decoding_limits)?;
+        Ok(UpdateDataDetails {
+            node_id,
+            perform_insert_replace,
+            update_values,
+        })
+    }
+}
+
+impl JsonEncoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "PerformInsertReplace", self.perform_insert_replace.encode_json(context));
+        insert_json_field(&mut fields, "UpdateValues", encode_json_array(&self.update_values, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let perform_insert_replace = PerformUpdateType::decode_json(json_field(fields, "PerformInsertReplace"), decoding_limits)?;
+        let update_values: Option<Vec<DataValue>> = decode_json_array(json_field(fields, "UpdateValues"), decoding_limits)?;
+        Ok(UpdateDataDetails {
+            node_id,
+            perform_insert_replace,
+            update_values,
+        })
+    }
+}
+
+impl XmlEncoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.perform_insert_replace.encode_xml("PerformInsertReplace"));
+        element.add_child(encode_xml_array("UpdateValues", "DataValue", &self.update_values));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for UpdateDataDetails {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let perform_insert_replace = PerformUpdateType::decode_xml(xml_child(element, "PerformInsertReplace"), decoding_limits)?;
+        let update_values: Option<Vec<DataValue>> = decode_xml_array(xml_child(element, "UpdateValues"), decoding_limits)?;
+        Ok(UpdateDataDetails {
+            node_id,
+            perform_insert_replace,
+            update_values,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     string::UAString,
     byte_string::ByteString,
@@ -54,3 +56,60 @@ impl BinaryEncoder<UserNameIdentityToken> for UserNameIdentityToken {
         })
     }
 }
+
+impl JsonEncoder for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "PolicyId", self.policy_id.encode_json(context));
+        insert_json_field(&mut fields, "UserName", self.user_name.encode_json(context));
+        insert_json_field(&mut fields, "Password", self.password.encode_json(context));
+        insert_json_field(&mut fields, "EncryptionAlgorithm", self.encryption_algorithm.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let policy_id = UAString::decode_json(json_field(fields, "PolicyId"), decoding_limits)?;
+        let user_name = UAString::decode_json(json_field(fields, "UserName"), decoding_limits)?;
+        let password = ByteString::decode_json(json_field(fields, "Password"), decoding_limits)?;
+        let encryption_algorithm = UAString::decode_json(json_field(fields, "EncryptionAlgorithm"), decoding_limits)?;
+        Ok(UserNameIdentityToken {
+            policy_id,
+            user_name,
+            password,
+            encryption_algorithm,
+        })
+    }
+}
+
+impl XmlEncoder for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.policy_id.encode_xml("PolicyId"));
+        element.add_child(self.user_name.encode_xml("UserName"));
+        element.add_child(self.password.encode_xml("Password"));
+        element.add_child(self.encryption_algorithm.encode_xml("EncryptionAlgorithm"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for UserNameIdentityToken {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let policy_id = UAString::decode_xml(xml_child(element, "PolicyId"), decoding_limits)?;
+        let user_name = UAString::decode_xml(xml_child(element, "UserName"), decoding_limits)?;
+        let password = ByteString::decode_xml(xml_child(element, "Password"), decoding_limits)?;
+        let encryption_algorithm = UAString::decode_xml(xml_child(element, "EncryptionAlgorithm"), decoding_limits)?;
+        Ok(UserNameIdentityToken {
+            policy_id,
+            user_name,
+            password,
+            encryption_algorithm,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -67,3 +69,66 @@ impl BinaryEncoder<UserTokenPolicy> for UserTokenPolicy {
         })
     }
 }
+
+impl JsonEncoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "PolicyId", self.policy_id.encode_json(context));
+        insert_json_field(&mut fields, "TokenType", self.token_type.encode_json(context));
+        insert_json_field(&mut fields, "IssuedTokenType", self.issued_token_type.encode_json(context));
+        insert_json_field(&mut fields, "IssuerEndpointUrl", self.issuer_endpoint_url.encode_json(context));
+        insert_json_field(&mut fields, "SecurityPolicyUri", self.security_policy_uri.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let policy_id = UAString::decode_json(json_field(fields, "PolicyId"), decoding_limits)?;
+        let token_type = UserTokenType::decode_json(json_field(fields, "TokenType"), decoding_limits)?;
+        let issued_token_type = UAString::decode_json(json_field(fields, "IssuedTokenType"), decoding_limits)?;
+        let issuer_endpoint_url = UAString::decode_json(json_field(fields, "IssuerEndpointUrl"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_json(json_field(fields, "SecurityPolicyUri"), decoding_limits)?;
+        Ok(UserTokenPolicy {
+            policy_id,
+            token_type,
+            issued_token_type,
+            issuer_endpoint_url,
+            security_policy_uri,
+        })
+    }
+}
+
+impl XmlEncoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.policy_id.encode_xml("PolicyId"));
+        element.add_child(self.token_type.encode_xml("TokenType"));
+        element.add_child(self.issued_token_type.encode_xml("IssuedTokenType"));
+        element.add_child(self.issuer_endpoint_url.encode_xml("IssuerEndpointUrl"));
+        element.add_child(self.security_policy_uri.encode_xml("SecurityPolicyUri"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for UserTokenPolicy {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let policy_id = UAString::decode_xml(xml_child(element, "PolicyId"), decoding_limits)?;
+        let token_type = UserTokenType::decode_xml(xml_child(element, "TokenType"), decoding_limits)?;
+        let issued_token_type = UAString::decode_xml(xml_child(element, "IssuedTokenType"), decoding_limits)?;
+        let issuer_endpoint_url = UAString::decode_xml(xml_child(element, "IssuerEndpointUrl"), decoding_limits)?;
+        let security_policy_uri = UAString::decode_xml(xml_child(element, "SecurityPolicyUri"), decoding_limits)?;
+        Ok(UserTokenPolicy {
+            policy_id,
+            token_type,
+            issued_token_type,
+            issuer_endpoint_url,
+            security_policy_uri,
+        })
+    }
+}
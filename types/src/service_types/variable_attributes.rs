@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     basic_types::LocalizedText,
     variant::Variant,
@@ -100,3 +102,114 @@ impl BinaryEncoder<VariableAttributes> for VariableAttributes {
         })
     }
 }
+
+impl JsonEncoder for VariableAttributes {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "SpecifiedAttributes", self.specified_attributes.encode_json(context));
+        insert_json_field(&mut fields, "DisplayName", self.display_name.encode_json(context));
+        insert_json_field(&mut fields, "Description", self.description.encode_json(context));
+        insert_json_field(&mut fields, "WriteMask", self.write_mask.encode_json(context));
+        insert_json_field(&mut fields, "UserWriteMask", self.user_write_mask.encode_json(context));
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        insert_json_field(&mut fields, "DataType", self.data_type.encode_json(context));
+        insert_json_field(&mut fields, "ValueRank", self.value_rank.encode_json(context));
+        insert_json_field(&mut fields, "ArrayDimensions", encode_json_array(&self.array_dimensions, context));
+        insert_json_field(&mut fields, "AccessLevel", self.access_level.encode_json(context));
+        insert_json_field(&mut fields, "UserAccessLevel", self.user_access_level.encode_json(context));
+        insert_json_field(&mut fields, "MinimumSamplingInterval", self.minimum_sampling_interval.encode_json(context));
+        insert_json_field(&mut fields, "Historizing", self.historizing.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for VariableAttributes {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let specified_attributes = u32::decode_json(json_field(fields, "SpecifiedAttributes"), decoding_limits)?;
+        let display_name = LocalizedText::decode_json(json_field(fields, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_json(json_field(fields, "Description"), decoding_limits)?;
+        let write_mask = u32::decode_json(json_field(fields, "WriteMask"), decoding_limits)?;
+        let user_write_mask = u32::decode_json(json_field(fields, "UserWriteMask"), decoding_limits)?;
+        let value = Variant::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        let data_type = NodeId::decode_json(json_field(fields, "DataType"), decoding_limits)?;
+        let value_rank = i32::decode_json(json_field(fields, "ValueRank"), decoding_limits)?;
+        let array_dimensions: Option<Vec<u32>> = decode_json_array(json_field(fields, "ArrayDimensions"), decoding_limits)?;
+        let access_level = u8::decode_json(json_field(fields, "AccessLevel"), decoding_limits)?;
+        let user_access_level = u8::decode_json(json_field(fields, "UserAccessLevel"), decoding_limits)?;
+        let minimum_sampling_interval = f64::decode_json(json_field(fields, "MinimumSamplingInterval"), decoding_limits)?;
+        let historizing = bool::decode_json(json_field(fields, "Historizing"), decoding_limits)?;
+        Ok(VariableAttributes {
+            specified_attributes,
+            display_name,
+            description,
+            write_mask,
+            user_write_mask,
+            value,
+            data_type,
+            value_rank,
+            array_dimensions,
+            access_level,
+            user_access_level,
+            minimum_sampling_interval,
+            historizing,
+        })
+    }
+}
+
+impl XmlEncoder for VariableAttributes {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.specified_attributes.encode_xml("SpecifiedAttributes"));
+        element.add_child(self.display_name.encode_xml("DisplayName"));
+        element.add_child(self.description.encode_xml("Description"));
+        element.add_child(self.write_mask.encode_xml("WriteMask"));
+        element.add_child(self.user_write_mask.encode_xml("UserWriteMask"));
+        element.add_child(self.value.encode_xml("Value"));
+        element.add_child(self.data_type.encode_xml("DataType"));
+        element.add_child(self.value_rank.encode_xml("ValueRank"));
+        element.add_child(encode_xml_array("ArrayDimensions", "UInt32", &self.array_dimensions));
+        element.add_child(self.access_level.encode_xml("AccessLevel"));
+        element.add_child(self.user_access_level.encode_xml("UserAccessLevel"));
+        element.add_child(self.minimum_sampling_interval.encode_xml("MinimumSamplingInterval"));
+        element.add_child(self.historizing.encode_xml("Historizing"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for VariableAttributes {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let specified_attributes = u32::decode_xml(xml_child(element, "SpecifiedAttributes"), decoding_limits)?;
+        let display_name = LocalizedText::decode_xml(xml_child(element, "DisplayName"), decoding_limits)?;
+        let description = LocalizedText::decode_xml(xml_child(element, "Description"), decoding_limits)?;
+        let write_mask = u32::decode_xml(xml_child(element, "WriteMask"), decoding_limits)?;
+        let user_write_mask = u32::decode_xml(xml_child(element, "UserWriteMask"), decoding_limits)?;
+        let value = Variant::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        let data_type = NodeId::decode_xml(xml_child(element, "DataType"), decoding_limits)?;
+        let value_rank = i32::decode_xml(xml_child(element, "ValueRank"), decoding_limits)?;
+        let array_dimensions: Option<Vec<u32>> = decode_xml_array(xml_child(element, "ArrayDimensions"), decoding_limits)?;
+        let access_level = u8::decode_xml(xml_child(element, "AccessLevel"), decoding_limits)?;
+        let user_access_level = u8::decode_xml(xml_child(element, "UserAccessLevel"), decoding_limits)?;
+        let minimum_sampling_interval = f64::decode_xml(xml_child(element, "MinimumSamplingInterval"), decoding_limits)?;
+        let historizing = bool::decode_xml(xml_child(element, "Historizing"), decoding_limits)?;
+        Ok(VariableAttributes {
+            specified_attributes,
+            display_name,
+            description,
+            write_mask,
+            user_write_mask,
+            value,
+            data_type,
+            value_rank,
+            array_dimensions,
+            access_level,
+            user_access_level,
+            minimum_sampling_interval,
+            historizing,
+        })
+    }
+}
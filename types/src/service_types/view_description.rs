@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -57,3 +59,54 @@ impl BinaryEncoder<ViewDescription> for ViewDescription {
         })
     }
 }
+
+impl JsonEncoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "ViewId", self.view_id.encode_json(context));
+        insert_json_field(&mut fields, "Timestamp", self.timestamp.encode_json(context));
+        insert_json_field(&mut fields, "ViewVersion", self.view_version.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let view_id = NodeId::decode_json(json_field(fields, "ViewId"), decoding_limits)?;
+        let timestamp = DateTime::decode_json(json_field(fields, "Timestamp"), decoding_limits)?;
+        let view_version = u32::decode_json(json_field(fields, "ViewVersion"), decoding_limits)?;
+        Ok(ViewDescription {
+            view_id,
+            timestamp,
+            view_version,
+        })
+    }
+}
+
+impl XmlEncoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.view_id.encode_xml("ViewId"));
+        element.add_child(self.timestamp.encode_xml("Timestamp"));
+        element.add_child(self.view_version.encode_xml("ViewVersion"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for ViewDescription {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let view_id = NodeId::decode_xml(xml_child(element, "ViewId"), decoding_limits)?;
+        let timestamp = DateTime::decode_xml(xml_child(element, "Timestamp"), decoding_limits)?;
+        let view_version = u32::decode_xml(xml_child(element, "ViewVersion"), decoding_limits)?;
+        Ok(ViewDescription {
+            view_id,
+            timestamp,
+            view_version,
+        })
+    }
+}
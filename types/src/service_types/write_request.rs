@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -51,3 +53,48 @@ impl BinaryEncoder<WriteRequest> for WriteRequest {
         })
     }
 }
+
+impl JsonEncoder for WriteRequest {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "RequestHeader", self.request_header.encode_json(context));
+        insert_json_field(&mut fields, "NodesToWrite", encode_json_array(&self.nodes_to_write, context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for WriteRequest {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let request_header = RequestHeader::decode_json(json_field(fields, "RequestHeader"), decoding_limits)?;
+        let nodes_to_write: Option<Vec<WriteValue>> = decode_json_array(json_field(fields, "NodesToWrite"), decoding_limits)?;
+        Ok(WriteRequest {
+            request_header,
+            nodes_to_write,
+        })
+    }
+}
+
+impl XmlEncoder for WriteRequest {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.request_header.encode_xml("RequestHeader"));
+        element.add_child(encode_xml_array("NodesToWrite", "WriteValue", &self.nodes_to_write));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for WriteRequest {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let request_header = RequestHeader::decode_xml(xml_child(element, "RequestHeader"), decoding_limits)?;
+        let nodes_to_write: Option<Vec<WriteValue>> = decode_xml_array(xml_child(element, "NodesToWrite"), decoding_limits)?;
+        Ok(WriteRequest {
+            request_header,
+            nodes_to_write,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -62,3 +64,60 @@ impl BinaryEncoder<WriteValue> for WriteValue {
         })
     }
 }
+
+impl JsonEncoder for WriteValue {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "NodeId", self.node_id.encode_json(context));
+        insert_json_field(&mut fields, "AttributeId", self.attribute_id.encode_json(context));
+        insert_json_field(&mut fields, "IndexRange", self.index_range.encode_json(context));
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for WriteValue {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let node_id = NodeId::decode_json(json_field(fields, "NodeId"), decoding_limits)?;
+        let attribute_id = u32::decode_json(json_field(fields, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_json(json_field(fields, "IndexRange"), decoding_limits)?;
+        let value = DataValue::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        Ok(WriteValue {
+            node_id,
+            attribute_id,
+            index_range,
+            value,
+        })
+    }
+}
+
+impl XmlEncoder for WriteValue {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.node_id.encode_xml("NodeId"));
+        element.add_child(self.attribute_id.encode_xml("AttributeId"));
+        element.add_child(self.index_range.encode_xml("IndexRange"));
+        element.add_child(self.value.encode_xml("Value"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for WriteValue {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let node_id = NodeId::decode_xml(xml_child(element, "NodeId"), decoding_limits)?;
+        let attribute_id = u32::decode_xml(xml_child(element, "AttributeId"), decoding_limits)?;
+        let index_range = UAString::decode_xml(xml_child(element, "IndexRange"), decoding_limits)?;
+        let value = DataValue::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        Ok(WriteValue {
+            node_id,
+            attribute_id,
+            index_range,
+            value,
+        })
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     string::UAString,
     byte_string::ByteString,
@@ -44,3 +46,48 @@ impl BinaryEncoder<X509IdentityToken> for X509IdentityToken {
         })
     }
 }
+
+impl JsonEncoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "PolicyId", self.policy_id.encode_json(context));
+        insert_json_field(&mut fields, "CertificateData", self.certificate_data.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let policy_id = UAString::decode_json(json_field(fields, "PolicyId"), decoding_limits)?;
+        let certificate_data = ByteString::decode_json(json_field(fields, "CertificateData"), decoding_limits)?;
+        Ok(X509IdentityToken {
+            policy_id,
+            certificate_data,
+        })
+    }
+}
+
+impl XmlEncoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.policy_id.encode_xml("PolicyId"));
+        element.add_child(self.certificate_data.encode_xml("CertificateData"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for X509IdentityToken {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let policy_id = UAString::decode_xml(xml_child(element, "PolicyId"), decoding_limits)?;
+        let certificate_data = ByteString::decode_xml(xml_child(element, "CertificateData"), decoding_limits)?;
+        Ok(X509IdentityToken {
+            policy_id,
+            certificate_data,
+        })
+    }
+}
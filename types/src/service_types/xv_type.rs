@@ -6,6 +6,8 @@ use std::io::{Read, Write};
 #[allow(unused_imports)]
 use crate::{
     encoding::*,
+    json::*,
+    xml::*,
     basic_types::*,
     service_types::impls::MessageInfo,
     node_ids::ObjectId,
@@ -49,3 +51,48 @@ impl BinaryEncoder<XVType> for XVType {
         })
     }
 }
+
+impl JsonEncoder for XVType {
+    #[allow(unused_variables)]
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let mut fields = JsonObject::new();
+        insert_json_field(&mut fields, "X", self.x.encode_json(context));
+        insert_json_field(&mut fields, "Value", self.value.encode_json(context));
+        JsonValue::Object(fields)
+    }
+}
+
+impl JsonDecoder for XVType {
+    #[allow(unused_variables)]
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let fields = json_object(value)?;
+        let x = f64::decode_json(json_field(fields, "X"), decoding_limits)?;
+        let value = f32::decode_json(json_field(fields, "Value"), decoding_limits)?;
+        Ok(XVType {
+            x,
+            value,
+        })
+    }
+}
+
+impl XmlEncoder for XVType {
+    #[allow(unused_variables)]
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.x.encode_xml("X"));
+        element.add_child(self.value.encode_xml("Value"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for XVType {
+    #[allow(unused_variables)]
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let x = f64::decode_xml(xml_child(element, "X"), decoding_limits)?;
+        let value = f32::decode_xml(xml_child(element, "Value"), decoding_limits)?;
+        Ok(XVType {
+            x,
+            value,
+        })
+    }
+}
@@ -7,6 +7,12 @@ use serde::{
     de::{self, Visitor},
 };
 
+use crate::{
+    encoding::{DecodingLimits, EncodingResult},
+    json::*,
+    xml::*,
+};
+
 pub use crate::status_codes::StatusCode;
 
 // The bitflags! macro implements Debug for StatusCode but it fouls the display because status
@@ -62,4 +68,39 @@ impl<'de> Deserialize<'de> for StatusCode {
         where D: Deserializer<'de> {
         Ok(StatusCode::from_bits_truncate(deserializer.deserialize_u32(StatusCodeVisitor)?))
     }
-}
\ No newline at end of file
+}
+
+impl JsonEncoder for StatusCode {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        if context.reversible {
+            JsonValue::from(self.bits())
+        } else {
+            // The non-reversible form also holds the symbolic name of the code
+            let mut fields = JsonObject::new();
+            fields.insert("Code".to_string(), JsonValue::from(self.bits()));
+            fields.insert("Symbol".to_string(), JsonValue::from(self.name()));
+            JsonValue::Object(fields)
+        }
+    }
+}
+
+impl JsonDecoder for StatusCode {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(StatusCode::from_bits_truncate(u32::decode_json(value, decoding_limits)?))
+    }
+}
+
+impl XmlEncoder for StatusCode {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let mut element = XmlNode::new(name);
+        element.add_child(self.bits().encode_xml("Code"));
+        Some(element)
+    }
+}
+
+impl XmlDecoder for StatusCode {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let code = u32::decode_xml(xml_child(element, "Code"), decoding_limits)?;
+        Ok(StatusCode::from_bits_truncate(code))
+    }
+}
@@ -6,6 +6,8 @@ use std::{
 
 use crate::{
     encoding::{write_i32, BinaryEncoder, EncodingResult, DecodingLimits, process_encode_io_result, process_decode_io_result},
+    json::{JsonEncoder, JsonDecoder, JsonContext, JsonValue, json_str},
+    xml::{XmlEncoder, XmlDecoder, XmlNode},
     status_codes::StatusCode,
 };
 
@@ -74,6 +76,54 @@ impl BinaryEncoder<UAString> for UAString {
     }
 }
 
+impl JsonEncoder for UAString {
+    fn encode_json(&self, _: &JsonContext) -> JsonValue {
+        // A null string is omitted, an empty string is not
+        if let Some(ref value) = self.value {
+            JsonValue::from(value.as_str())
+        } else {
+            JsonValue::Null
+        }
+    }
+}
+
+impl JsonDecoder for UAString {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(value) = json_str(value)? {
+            if value.len() > decoding_limits.max_string_length as usize {
+                error!("String length {} exceeds decoding limit {}", value.len(), decoding_limits.max_string_length);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                Ok(UAString::from(value))
+            }
+        } else {
+            Ok(UAString::null())
+        }
+    }
+}
+
+impl XmlEncoder for UAString {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        // A null string is omitted, an empty string is not
+        self.value.as_ref().map(|value| XmlNode::new_text(name, value.as_str()))
+    }
+}
+
+impl XmlDecoder for UAString {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if let Some(element) = element {
+            if element.text.len() > decoding_limits.max_string_length as usize {
+                error!("String length {} exceeds decoding limit {}", element.text.len(), decoding_limits.max_string_length);
+                Err(StatusCode::BadDecodingError)
+            } else {
+                Ok(UAString::from(element.text.as_str()))
+            }
+        } else {
+            Ok(UAString::null())
+        }
+    }
+}
+
 impl From<UAString> for String {
     fn from(value: UAString) -> Self {
         value.as_ref().to_string()
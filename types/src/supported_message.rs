@@ -109,6 +109,10 @@ impl SupportedMessage {
             SupportedMessage::ModifyMonitoredItemsResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::DeleteMonitoredItemsRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::DeleteMonitoredItemsResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::SetMonitoringModeRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::SetMonitoringModeResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::SetTriggeringRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::SetTriggeringResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::CreateSubscriptionRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::CreateSubscriptionResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::ModifySubscriptionRequest(ref r) => r.request_header.request_handle,
@@ -125,14 +129,36 @@ impl SupportedMessage {
             SupportedMessage::PublishResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::RepublishRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::RepublishResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::TransferSubscriptionsRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::TransferSubscriptionsResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::TranslateBrowsePathsToNodeIdsRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::TranslateBrowsePathsToNodeIdsResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::RegisterNodesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::RegisterNodesResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::UnregisterNodesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::UnregisterNodesResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::QueryFirstRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::QueryFirstResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::QueryNextRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::QueryNextResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::ReadRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::ReadResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::WriteRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::WriteResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::HistoryReadRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::HistoryReadResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::HistoryUpdateRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::HistoryUpdateResponse(ref r) => r.response_header.request_handle,
             SupportedMessage::CallRequest(ref r) => r.request_header.request_handle,
             SupportedMessage::CallResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::AddNodesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::AddNodesResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::AddReferencesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::AddReferencesResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::DeleteNodesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::DeleteNodesResponse(ref r) => r.response_header.request_handle,
+            SupportedMessage::DeleteReferencesRequest(ref r) => r.request_header.request_handle,
+            SupportedMessage::DeleteReferencesResponse(ref r) => r.response_header.request_handle,
         }
     }
 
@@ -214,6 +240,18 @@ impl SupportedMessage {
             ObjectId::DeleteMonitoredItemsResponse_Encoding_DefaultBinary => {
                 DeleteMonitoredItemsResponse::decode(stream, decoding_limits)?.into()
             }
+            ObjectId::SetMonitoringModeRequest_Encoding_DefaultBinary => {
+                SetMonitoringModeRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::SetMonitoringModeResponse_Encoding_DefaultBinary => {
+                SetMonitoringModeResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::SetTriggeringRequest_Encoding_DefaultBinary => {
+                SetTriggeringRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::SetTriggeringResponse_Encoding_DefaultBinary => {
+                SetTriggeringResponse::decode(stream, decoding_limits)?.into()
+            }
             ObjectId::CreateSubscriptionRequest_Encoding_DefaultBinary => {
                 CreateSubscriptionRequest::decode(stream, decoding_limits)?.into()
             }
@@ -262,12 +300,42 @@ impl SupportedMessage {
             ObjectId::RepublishResponse_Encoding_DefaultBinary => {
                 RepublishResponse::decode(stream, decoding_limits)?.into()
             }
+            ObjectId::TransferSubscriptionsRequest_Encoding_DefaultBinary => {
+                TransferSubscriptionsRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::TransferSubscriptionsResponse_Encoding_DefaultBinary => {
+                TransferSubscriptionsResponse::decode(stream, decoding_limits)?.into()
+            }
             ObjectId::TranslateBrowsePathsToNodeIdsRequest_Encoding_DefaultBinary => {
                 TranslateBrowsePathsToNodeIdsRequest::decode(stream, decoding_limits)?.into()
             }
             ObjectId::TranslateBrowsePathsToNodeIdsResponse_Encoding_DefaultBinary => {
                 TranslateBrowsePathsToNodeIdsResponse::decode(stream, decoding_limits)?.into()
             }
+            ObjectId::RegisterNodesRequest_Encoding_DefaultBinary => {
+                RegisterNodesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::RegisterNodesResponse_Encoding_DefaultBinary => {
+                RegisterNodesResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::UnregisterNodesRequest_Encoding_DefaultBinary => {
+                UnregisterNodesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::UnregisterNodesResponse_Encoding_DefaultBinary => {
+                UnregisterNodesResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::QueryFirstRequest_Encoding_DefaultBinary => {
+                QueryFirstRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::QueryFirstResponse_Encoding_DefaultBinary => {
+                QueryFirstResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::QueryNextRequest_Encoding_DefaultBinary => {
+                QueryNextRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::QueryNextResponse_Encoding_DefaultBinary => {
+                QueryNextResponse::decode(stream, decoding_limits)?.into()
+            }
             ObjectId::ReadRequest_Encoding_DefaultBinary => {
                 ReadRequest::decode(stream, decoding_limits)?.into()
             }
@@ -280,12 +348,48 @@ impl SupportedMessage {
             ObjectId::WriteResponse_Encoding_DefaultBinary => {
                 WriteResponse::decode(stream, decoding_limits)?.into()
             }
+            ObjectId::HistoryReadRequest_Encoding_DefaultBinary => {
+                HistoryReadRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::HistoryReadResponse_Encoding_DefaultBinary => {
+                HistoryReadResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::HistoryUpdateRequest_Encoding_DefaultBinary => {
+                HistoryUpdateRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::HistoryUpdateResponse_Encoding_DefaultBinary => {
+                HistoryUpdateResponse::decode(stream, decoding_limits)?.into()
+            }
             ObjectId::CallRequest_Encoding_DefaultBinary => {
                 CallRequest::decode(stream, decoding_limits)?.into()
             }
             ObjectId::CallResponse_Encoding_DefaultBinary => {
                 CallResponse::decode(stream, decoding_limits)?.into()
             }
+            ObjectId::AddNodesRequest_Encoding_DefaultBinary => {
+                AddNodesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::AddNodesResponse_Encoding_DefaultBinary => {
+                AddNodesResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::AddReferencesRequest_Encoding_DefaultBinary => {
+                AddReferencesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::AddReferencesResponse_Encoding_DefaultBinary => {
+                AddReferencesResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::DeleteNodesRequest_Encoding_DefaultBinary => {
+                DeleteNodesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::DeleteNodesResponse_Encoding_DefaultBinary => {
+                DeleteNodesResponse::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::DeleteReferencesRequest_Encoding_DefaultBinary => {
+                DeleteReferencesRequest::decode(stream, decoding_limits)?.into()
+            }
+            ObjectId::DeleteReferencesResponse_Encoding_DefaultBinary => {
+                DeleteReferencesResponse::decode(stream, decoding_limits)?.into()
+            }
 
             _ => {
                 debug!("decoding unsupported for object id {:?}", object_id);
@@ -323,6 +427,10 @@ supported_messages_enum![
     ModifyMonitoredItemsResponse,
     DeleteMonitoredItemsRequest,
     DeleteMonitoredItemsResponse,
+    SetMonitoringModeRequest,
+    SetMonitoringModeResponse,
+    SetTriggeringRequest,
+    SetTriggeringResponse,
     CreateSubscriptionRequest,
     CreateSubscriptionResponse,
     ModifySubscriptionRequest,
@@ -339,12 +447,34 @@ supported_messages_enum![
     PublishResponse,
     RepublishRequest,
     RepublishResponse,
+    TransferSubscriptionsRequest,
+    TransferSubscriptionsResponse,
     TranslateBrowsePathsToNodeIdsRequest,
     TranslateBrowsePathsToNodeIdsResponse,
+    RegisterNodesRequest,
+    RegisterNodesResponse,
+    UnregisterNodesRequest,
+    UnregisterNodesResponse,
+    QueryFirstRequest,
+    QueryFirstResponse,
+    QueryNextRequest,
+    QueryNextResponse,
     ReadRequest,
     ReadResponse,
     WriteRequest,
     WriteResponse,
+    HistoryReadRequest,
+    HistoryReadResponse,
+    HistoryUpdateRequest,
+    HistoryUpdateResponse,
     CallRequest,
     CallResponse,
+    AddNodesRequest,
+    AddNodesResponse,
+    AddReferencesRequest,
+    AddReferencesResponse,
+    DeleteNodesRequest,
+    DeleteNodesResponse,
+    DeleteReferencesRequest,
+    DeleteReferencesResponse,
 ];
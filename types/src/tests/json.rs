@@ -0,0 +1,252 @@
+use std::fmt::Debug;
+
+use serde_json::json;
+
+use crate::{
+    *,
+    node_ids::{DataTypeId, ObjectId},
+    service_types::{Argument, ReadRequest, ReadValueId, RequestHeader, TimestampsToReturn},
+    status_codes::StatusCode,
+};
+
+fn json_round_trip<T>(value: T, expected_json: JsonValue)
+    where T: JsonEncoder + JsonDecoder + Debug + PartialEq
+{
+    let json = value.encode_json(&JsonContext::reversible());
+    assert_eq!(json, expected_json);
+    let decoded = T::decode_json(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, value);
+}
+
+fn non_reversible() -> JsonContext {
+    JsonContext::non_reversible(vec!["http://opcfoundation.org/UA/".to_string(), "urn:local".to_string(), "urn:other".to_string()], vec!["urn:server".to_string()])
+}
+
+#[test]
+fn json_numbers() {
+    json_round_trip(true, json!(true));
+    json_round_trip(-5i8, json!(-5));
+    json_round_trip(4000000000u32, json!(4000000000u32));
+    // 64-bit integers are strings
+    json_round_trip(-9000000000i64, json!("-9000000000"));
+    json_round_trip(u64::MAX, json!("18446744073709551615"));
+    json_round_trip(1.5f32, json!(1.5));
+    json_round_trip(0.1f32, json!(0.1));
+    json_round_trip(f64::INFINITY, json!("Infinity"));
+    json_round_trip(f64::NEG_INFINITY, json!("-Infinity"));
+    assert!(f64::decode_json(&json!("NaN"), &DecodingLimits::default()).unwrap().is_nan());
+    assert_eq!(f64::NAN.encode_json(&JsonContext::reversible()), json!("NaN"));
+
+    // Out of range or the wrong type
+    assert_eq!(u8::decode_json(&json!(256), &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(i32::decode_json(&json!(true), &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn json_strings() {
+    json_round_trip(UAString::from("Hello"), json!("Hello"));
+    json_round_trip(UAString::null(), JsonValue::Null);
+    json_round_trip(ByteString::from(&[1u8, 2, 3, 4]), json!("AQIDBA=="));
+    json_round_trip(ByteString::null(), JsonValue::Null);
+    let guid = Guid::new();
+    json_round_trip(guid.clone(), json!(guid.to_string()));
+    json_round_trip(DateTime::ymd_hms_nano(2018, 11, 5, 8, 21, 44, 123_000_000), json!("2018-11-05T08:21:44.123Z"));
+
+    // Dates outside of the range are clamped
+    let decoding_limits = DecodingLimits::default();
+    assert_eq!(DateTime::decode_json(&json!("1500-01-01T00:00:00Z"), &decoding_limits).unwrap(), DateTime::epoch());
+
+    // Strings are subject to the decoding limits
+    let decoding_limits = DecodingLimits { max_string_length: 3, ..DecodingLimits::default() };
+    assert_eq!(UAString::decode_json(&json!("Hello"), &decoding_limits).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn json_node_id() {
+    json_round_trip(NodeId::new(0, 2256), json!({"Id": 2256}));
+    json_round_trip(NodeId::new(2, "Hello"), json!({"IdType": 1, "Id": "Hello", "Namespace": 2}));
+    json_round_trip(NodeId::new(1, ByteString::from(&[1u8, 2, 3, 4])), json!({"IdType": 3, "Id": "AQIDBA==", "Namespace": 1}));
+    json_round_trip(NodeId::null(), JsonValue::Null);
+
+    // The non-reversible form uses the URI of namespaces other than 0 and 1
+    let context = non_reversible();
+    assert_eq!(NodeId::new(1, 5).encode_json(&context), json!({"Id": 5, "Namespace": 1}));
+    assert_eq!(NodeId::new(2, 5).encode_json(&context), json!({"Id": 5, "Namespace": "urn:other"}));
+
+    let node_id = ExpandedNodeId {
+        node_id: NodeId::new(0, "Hello"),
+        namespace_uri: UAString::from("urn:other"),
+        server_index: 3,
+    };
+    json_round_trip(node_id, json!({"IdType": 1, "Id": "Hello", "Namespace": "urn:other", "ServerUri": 3}));
+    let node_id = ExpandedNodeId {
+        node_id: NodeId::new(2, 5),
+        namespace_uri: UAString::null(),
+        server_index: 0,
+    };
+    assert_eq!(node_id.encode_json(&context), json!({"Id": 5, "Namespace": "urn:other"}));
+}
+
+#[test]
+fn json_status_code() {
+    json_round_trip(StatusCode::BadNodeIdUnknown, json!(StatusCode::BadNodeIdUnknown.bits()));
+    assert_eq!(StatusCode::BadNodeIdUnknown.encode_json(&non_reversible()),
+               json!({"Code": StatusCode::BadNodeIdUnknown.bits(), "Symbol": "BadNodeIdUnknown"}));
+}
+
+#[test]
+fn json_names_and_text() {
+    json_round_trip(QualifiedName::new(2, "Temperature"), json!({"Name": "Temperature", "Uri": 2}));
+    assert_eq!(QualifiedName::new(2, "Temperature").encode_json(&non_reversible()), json!({"Name": "Temperature", "Uri": "urn:other"}));
+    json_round_trip(LocalizedText::new("en", "Hello"), json!({"Locale": "en", "Text": "Hello"}));
+    json_round_trip(LocalizedText::null(), JsonValue::Null);
+    assert_eq!(LocalizedText::new("en", "Hello").encode_json(&non_reversible()), json!("Hello"));
+}
+
+#[test]
+fn json_enum() {
+    json_round_trip(TimestampsToReturn::Server, json!(1));
+    assert_eq!(TimestampsToReturn::Server.encode_json(&non_reversible()), json!("Server_1"));
+    assert!(TimestampsToReturn::decode_json(&json!(10), &DecodingLimits::default()).is_err());
+}
+
+#[test]
+fn json_variant() {
+    json_round_trip(Variant::Empty, JsonValue::Null);
+    json_round_trip(Variant::from(5i32), json!({"Type": 6, "Body": 5}));
+    json_round_trip(Variant::from("Hello"), json!({"Type": 12, "Body": "Hello"}));
+    json_round_trip(Variant::XmlElement(XmlElement::from("<a/>")), json!({"Type": 16, "Body": "<a/>"}));
+    json_round_trip(Variant::from(vec![1u32, 2, 3]), json!({"Type": 7, "Body": [1, 2, 3]}));
+    json_round_trip(Variant::from(vec![Variant::from(1i64), Variant::from(2i64)]), json!({"Type": 8, "Body": ["1", "2"]}));
+
+    let mda = Variant::new_multi_dimension_array(vec![
+        Variant::from(1i32), Variant::from(2i32), Variant::from(3i32),
+        Variant::from(4i32), Variant::from(5i32), Variant::from(6i32),
+    ], vec![2, 3]);
+    json_round_trip(mda.clone(), json!({"Type": 6, "Body": [1, 2, 3, 4, 5, 6], "Dimensions": [2, 3]}));
+
+    // The non-reversible form is just the body, with nested arrays for each dimension
+    let context = non_reversible();
+    assert_eq!(Variant::from(5i32).encode_json(&context), json!(5));
+    assert_eq!(mda.encode_json(&context), json!([[1, 2, 3], [4, 5, 6]]));
+
+    // Dimensions must match the values
+    let json = json!({"Type": 6, "Body": [1, 2, 3], "Dimensions": [2, 2]});
+    assert_eq!(Variant::decode_json(&json, &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+    // Arrays are subject to the decoding limits
+    let decoding_limits = DecodingLimits { max_array_length: 2, ..DecodingLimits::default() };
+    let json = json!({"Type": 6, "Body": [1, 2, 3]});
+    assert_eq!(Variant::decode_json(&json, &decoding_limits).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn json_data_value() {
+    let now = DateTime::ymd_hms(2018, 11, 5, 8, 21, 44);
+    let data_value = DataValue {
+        value: Some(Variant::from(100u16)),
+        status: Some(StatusCode::Good.bits()),
+        source_timestamp: Some(now.clone()),
+        source_picoseconds: None,
+        server_timestamp: Some(now.clone()),
+        server_picoseconds: Some(10),
+    };
+    // A good status is omitted
+    let json = data_value.encode_json(&JsonContext::reversible());
+    assert_eq!(json, json!({
+        "Value": {"Type": 5, "Body": 100},
+        "SourceTimestamp": "2018-11-05T08:21:44Z",
+        "ServerTimestamp": "2018-11-05T08:21:44Z",
+        "ServerPicoseconds": 10
+    }));
+
+    let data_value = DataValue {
+        value: None,
+        status: Some(StatusCode::BadNodeIdUnknown.bits()),
+        source_timestamp: None,
+        source_picoseconds: None,
+        server_timestamp: None,
+        server_picoseconds: None,
+    };
+    json_round_trip(data_value, json!({"Status": StatusCode::BadNodeIdUnknown.bits()}));
+}
+
+#[test]
+fn json_diagnostic_info() {
+    let diagnostic_info = DiagnosticInfo {
+        symbolic_id: Some(1),
+        namespace_uri: None,
+        locale: None,
+        localized_text: Some(2),
+        additional_info: Some(UAString::from("More")),
+        inner_status_code: Some(StatusCode::BadTimeout),
+        inner_diagnostic_info: Some(Box::new(DiagnosticInfo {
+            symbolic_id: Some(3),
+            ..DiagnosticInfo::default()
+        })),
+    };
+    json_round_trip(diagnostic_info, json!({
+        "SymbolicId": 1,
+        "LocalizedText": 2,
+        "AdditionalInfo": "More",
+        "InnerStatusCode": StatusCode::BadTimeout.bits(),
+        "InnerDiagnosticInfo": {"SymbolicId": 3}
+    }));
+    json_round_trip(DiagnosticInfo::default(), JsonValue::Null);
+}
+
+#[test]
+fn json_extension_object() {
+    // The body of a generated type is a JSON object identified by its data type
+    let argument = Argument {
+        name: UAString::from("x"),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::null(),
+    };
+    let extension_object = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &argument);
+    json_round_trip(extension_object.clone(), json!({
+        "TypeId": {"Id": DataTypeId::Argument as u32},
+        "Body": {"Name": "x", "DataType": {"Id": DataTypeId::Int32 as u32}, "ValueRank": -1}
+    }));
+    assert_eq!(extension_object.encode_json(&non_reversible()),
+               json!({"Name": "x", "DataType": {"Id": DataTypeId::Int32 as u32}, "ValueRank": -1}));
+
+    // Other bodies keep their encoding
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(2, 1000),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(&[1u8, 2, 3, 4])),
+    };
+    json_round_trip(extension_object, json!({"TypeId": {"Id": 1000, "Namespace": 2}, "Encoding": 1, "Body": "AQIDBA=="}));
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(2, 1001),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<a/>")),
+    };
+    json_round_trip(extension_object, json!({"TypeId": {"Id": 1001, "Namespace": 2}, "Encoding": 2, "Body": "<a/>"}));
+    json_round_trip(ExtensionObject::null(), JsonValue::Null);
+
+    // A JSON body of an unknown type cannot be decoded
+    let json = json!({"TypeId": {"Id": 1000, "Namespace": 2}, "Body": {"A": 1}});
+    assert_eq!(ExtensionObject::decode_json(&json, &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn json_service_types() {
+    let request = ReadRequest {
+        request_header: RequestHeader::new(&NodeId::new(0, 99), &DateTime::ymd_hms(2018, 11, 5, 8, 21, 44), 5),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![ReadValueId::from(NodeId::new(2, "v1"))]),
+    };
+    let json = request.encode_json(&JsonContext::reversible());
+    assert_eq!(json["RequestHeader"]["RequestHandle"], json!(5));
+    assert_eq!(json["TimestampsToReturn"], json!(2));
+    assert_eq!(json["NodesToRead"][0]["NodeId"], json!({"IdType": 1, "Id": "v1", "Namespace": 2}));
+    let decoded = ReadRequest::decode_json(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+
+    // And through a string
+    let json = request.to_json_string(&JsonContext::reversible());
+    let decoded = ReadRequest::from_json_str(&json, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+}
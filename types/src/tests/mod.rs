@@ -4,6 +4,7 @@ mod parse;
 mod serialize;
 mod variant;
 mod hello;
+mod json;
 
 use std::fmt::Debug;
 use std::cmp::PartialEq;
@@ -88,3 +89,4 @@ pub fn serialize_and_compare<T>(value: T, expected: &[u8])
         assert_eq!(actual[i], expected[i])
     }
 }
+mod xml;
@@ -1,4 +1,10 @@
-use crate::variant::{Variant, VariantTypeId, MultiDimensionArray};
+use crate::{
+    variant::{Variant, VariantTypeId, MultiDimensionArray},
+    numeric_range::NumericRange,
+    status_code::StatusCode,
+    byte_string::ByteString,
+    string::UAString,
+};
 
 #[test]
 fn is_numeric() {
@@ -148,4 +154,90 @@ fn variant_multi_dimensional_array() {
     assert!(v.is_array());
     assert!(v.is_numeric_array());
     assert!(!v.is_valid());
-}
\ No newline at end of file
+}
+fn string_array(values: &[&str]) -> Variant {
+    Variant::Array(values.iter().map(|v| Variant::from(*v)).collect())
+}
+
+#[test]
+fn variant_range_of() {
+    let range = |s: &str| s.parse::<NumericRange>().unwrap();
+
+    // Arrays
+    let v = Variant::from(vec![0i32, 1, 2, 3]);
+    assert_eq!(v.range_of(&range("2")).unwrap(), Variant::from(vec![2i32]));
+    assert_eq!(v.range_of(&range("1:10")).unwrap(), Variant::from(vec![1i32, 2, 3]));
+    assert_eq!(v.range_of(&range("4")).unwrap_err(), StatusCode::BadIndexRangeNoData);
+    assert_eq!(v.range_of(&range("1,1")).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+
+    // A 2x3x2 array, selecting the second row of each of its planes
+    let values = (0..12).map(Variant::from).collect::<Vec<Variant>>();
+    let v = Variant::new_multi_dimension_array(values, vec![2, 3, 2]);
+    let expected = Variant::new_multi_dimension_array(vec![Variant::from(2), Variant::from(3), Variant::from(8), Variant::from(9)], vec![2, 1, 2]);
+    assert_eq!(v.range_of(&range("0:1,1,0:1")).unwrap(), expected);
+    assert_eq!(v.range_of(&range("0,3,0")).unwrap_err(), StatusCode::BadIndexRangeNoData);
+    assert_eq!(v.range_of(&range("0,0")).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+
+    // Strings and byte strings
+    let v = Variant::from("abcdef");
+    assert_eq!(v.range_of(&range("1:3")).unwrap(), Variant::from("bcd"));
+    assert_eq!(v.range_of(&range("6")).unwrap_err(), StatusCode::BadIndexRangeNoData);
+    let v = Variant::from(ByteString::from(vec![1u8, 2, 3]));
+    assert_eq!(v.range_of(&range("1:2")).unwrap(), Variant::from(ByteString::from(vec![2u8, 3])));
+
+    // An extra dimension selects from each string of an array of them
+    let v = string_array(&["abc", "d", "efg"]);
+    let expected = Variant::Array(vec![Variant::from("bc"), Variant::from(UAString::null()), Variant::from("fg")]);
+    assert_eq!(v.range_of(&range("0:2,1:2")).unwrap(), expected);
+
+    // Other values cannot be ranged
+    assert_eq!(Variant::from(1).range_of(&range("0")).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+}
+
+#[test]
+fn variant_set_range_of() {
+    let range = |s: &str| s.parse::<NumericRange>().unwrap();
+
+    let mut v = Variant::from(vec![0i32, 1, 2, 3]);
+    assert!(v.set_range_of(&range("1:2"), &Variant::from(vec![10i32, 20])).is_ok());
+    assert_eq!(v, Variant::from(vec![0i32, 10, 20, 3]));
+    assert_eq!(v.set_range_of(&range("3:4"), &Variant::from(vec![1i32, 2])).unwrap_err(), StatusCode::BadIndexRangeNoData);
+    assert_eq!(v.set_range_of(&range("0:1"), &Variant::from(vec![1i32])).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+    assert_eq!(v.set_range_of(&range("0"), &Variant::from(1i32)).unwrap_err(), StatusCode::BadTypeMismatch);
+    assert_eq!(v.set_range_of(&range("0"), &string_array(&["a"])).unwrap_err(), StatusCode::BadTypeMismatch);
+
+    // A row of a 2x2 array
+    let mut v = Variant::new_multi_dimension_array(vec![Variant::from(0), Variant::from(1), Variant::from(2), Variant::from(3)], vec![2, 2]);
+    let row = Variant::new_multi_dimension_array(vec![Variant::from(20), Variant::from(30)], vec![1, 2]);
+    assert!(v.set_range_of(&range("1,0:1"), &row).is_ok());
+    assert_eq!(v, Variant::new_multi_dimension_array(vec![Variant::from(0), Variant::from(1), Variant::from(20), Variant::from(30)], vec![2, 2]));
+
+    // Substrings, none of which are written unless all of them can be
+    let mut v = Variant::from("abcdef");
+    assert!(v.set_range_of(&range("0:1"), &Variant::from("AB")).is_ok());
+    assert_eq!(v, Variant::from("ABcdef"));
+    let mut v = string_array(&["abc", "d"]);
+    assert_eq!(v.set_range_of(&range("0:1,0"), &string_array(&["A", ""])).unwrap_err(), StatusCode::BadIndexRangeInvalid);
+    assert_eq!(v, string_array(&["abc", "d"]));
+    assert!(v.set_range_of(&range("0:1,0"), &string_array(&["A", "D"])).is_ok());
+    assert_eq!(v, string_array(&["Abc", "D"]));
+}
+
+#[test]
+fn variant_try_from() {
+    use std::convert::TryFrom;
+    use crate::{node_id::NodeId, basic_types::LocalizedText};
+
+    assert!(bool::try_from(&Variant::from(true)).unwrap());
+    assert_eq!(u16::try_from(Variant::from(10u16)).unwrap(), 10);
+    assert_eq!(f64::try_from(&Variant::from(1.5f64)).unwrap(), 1.5);
+    assert_eq!(UAString::try_from(&Variant::from("abc")).unwrap(), UAString::from("abc"));
+    assert_eq!(String::try_from(&Variant::from("abc")).unwrap(), "abc");
+    assert_eq!(NodeId::try_from(&Variant::from(NodeId::new(2, "x"))).unwrap(), NodeId::new(2, "x"));
+    assert_eq!(LocalizedText::try_from(Variant::from(LocalizedText::new("en", "x"))).unwrap(), LocalizedText::new("en", "x"));
+
+    // Only the same type converts, even if the value would fit
+    assert_eq!(u16::try_from(&Variant::from(10u32)).unwrap_err(), StatusCode::BadTypeMismatch);
+    assert_eq!(f64::try_from(&Variant::from(1.5f32)).unwrap_err(), StatusCode::BadTypeMismatch);
+    assert_eq!(ByteString::try_from(&Variant::Empty).unwrap_err(), StatusCode::BadTypeMismatch);
+}
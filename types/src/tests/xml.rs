@@ -0,0 +1,215 @@
+use std::fmt::Debug;
+
+use crate::{
+    *,
+    node_ids::{DataTypeId, ObjectId},
+    service_types::{Argument, ReadRequest, ReadValueId, RequestHeader, TimestampsToReturn},
+    status_codes::StatusCode,
+};
+
+fn xml_round_trip<T>(value: T, expected_xml: &str)
+    where T: XmlEncoder + XmlDecoder + Debug + PartialEq
+{
+    let xml = value.to_xml_string("Value");
+    assert_eq!(xml, expected_xml);
+    let decoded = T::from_xml_str(&xml, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn xml_parse() {
+    let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<!-- A comment -->
+<uax:Root xmlns:uax="http://opcfoundation.org/UA/2008/02/Types.xsd" a="1 &amp; 2">
+  <Child>Hello &lt;World&gt; &#65;&#x42;</Child>
+  <Empty/>
+  <Data><![CDATA[<not an element>]]></Data>
+</uax:Root>"#;
+    let root = XmlNode::parse(xml).unwrap();
+    assert_eq!(root.name, "uax:Root");
+    assert_eq!(root.local_name(), "Root");
+    assert_eq!(root.attribute("a"), Some("1 & 2"));
+    assert_eq!(root.children.len(), 3);
+    assert_eq!(root.child("Child").unwrap().text, "Hello <World> AB");
+    assert_eq!(root.child("Data").unwrap().text, "<not an element>");
+    assert!(root.child("Missing").is_none());
+
+    // Written and parsed again
+    let written = root.to_xml_string();
+    assert_eq!(XmlNode::parse(&written).unwrap().child("Child"), root.child("Child"));
+    assert!(root.to_pretty_xml_string().contains("\n  <Empty/>\n"));
+
+    // Malformed documents
+    assert_eq!(XmlNode::parse("<a><b></a>").unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(XmlNode::parse("<a>").unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(XmlNode::parse("<a/><b/>").unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(XmlNode::parse("<a>&bogus;</a>").unwrap_err(), StatusCode::BadDecodingError);
+
+    // Elements nested too deeply
+    let nested = |depth: usize| format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+    assert!(XmlNode::parse(&nested(100)).is_ok());
+    assert_eq!(XmlNode::parse(&nested(100_000)).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn xml_numbers() {
+    xml_round_trip(true, "<Value>true</Value>");
+    xml_round_trip(-5i8, "<Value>-5</Value>");
+    xml_round_trip(u64::MAX, "<Value>18446744073709551615</Value>");
+    xml_round_trip(1.5f32, "<Value>1.5</Value>");
+    xml_round_trip(f64::INFINITY, "<Value>INF</Value>");
+    xml_round_trip(f64::NEG_INFINITY, "<Value>-INF</Value>");
+    assert!(f64::from_xml_str("<Value>NaN</Value>", &DecodingLimits::default()).unwrap().is_nan());
+
+    // Absent values are the default, malformed or out of range values are errors
+    assert_eq!(i32::decode_xml(None, &DecodingLimits::default()).unwrap(), 0);
+    assert_eq!(u8::from_xml_str("<Value>256</Value>", &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+    assert_eq!(bool::from_xml_str("<Value>yes</Value>", &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn xml_strings() {
+    xml_round_trip(UAString::from("a < b"), "<Value>a &lt; b</Value>");
+    assert_eq!(UAString::null().encode_xml("Value"), None);
+    assert!(UAString::decode_xml(None, &DecodingLimits::default()).unwrap().is_null());
+    xml_round_trip(ByteString::from(&[1u8, 2, 3, 4]), "<Value>AQIDBA==</Value>");
+    let guid = Guid::new();
+    xml_round_trip(guid.clone(), &format!("<Value><String>{}</String></Value>", guid.to_string()));
+    xml_round_trip(DateTime::ymd_hms_nano(2018, 11, 5, 8, 21, 44, 123_000_000), "<Value>2018-11-05T08:21:44.123Z</Value>");
+
+    // Strings are subject to the decoding limits
+    let decoding_limits = DecodingLimits { max_string_length: 3, ..DecodingLimits::default() };
+    assert_eq!(UAString::from_xml_str("<Value>abcd</Value>", &decoding_limits).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn xml_node_id() {
+    xml_round_trip(NodeId::new(0, 2253), "<Value><Identifier>i=2253</Identifier></Value>");
+    xml_round_trip(NodeId::new(2, "Hello"), "<Value><Identifier>ns=2;s=Hello</Identifier></Value>");
+    assert_eq!(NodeId::null().encode_xml("Value"), None);
+    assert_eq!(NodeId::from_xml_str("<Value><Identifier>x=1</Identifier></Value>", &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+
+    let mut node_id = ExpandedNodeId::new(NodeId::new(0, "Hello"));
+    node_id.namespace_uri = UAString::from("urn:x");
+    node_id.server_index = 3;
+    xml_round_trip(node_id, "<Value><Identifier>svr=3;nsu=urn:x;s=Hello</Identifier></Value>");
+    xml_round_trip(ExpandedNodeId::new(NodeId::new(1, 5)), "<Value><Identifier>ns=1;i=5</Identifier></Value>");
+}
+
+#[test]
+fn xml_built_in_structures() {
+    xml_round_trip(StatusCode::BadNodeIdUnknown, "<Value><Code>2150891520</Code></Value>");
+    xml_round_trip(QualifiedName::new(2, "Name"), "<Value><NamespaceIndex>2</NamespaceIndex><Name>Name</Name></Value>");
+    xml_round_trip(LocalizedText::new("en", "Text"), "<Value><Locale>en</Locale><Text>Text</Text></Value>");
+    xml_round_trip(TimestampsToReturn::Both, "<Value>Both_2</Value>");
+
+    let data_value = DataValue {
+        value: Some(Variant::from(5i32)),
+        status: Some(StatusCode::BadNodeIdUnknown.bits()),
+        source_timestamp: Some(DateTime::ymd_hms(2018, 11, 5, 8, 21, 44)),
+        source_picoseconds: Some(10),
+        server_timestamp: None,
+        server_picoseconds: None,
+    };
+    xml_round_trip(data_value, "<Value><Value><Int32>5</Int32></Value><StatusCode><Code>2150891520</Code></StatusCode>\
+<SourceTimestamp>2018-11-05T08:21:44Z</SourceTimestamp><SourcePicoseconds>10</SourcePicoseconds></Value>");
+
+    let diagnostic_info = DiagnosticInfo {
+        symbolic_id: Some(1),
+        additional_info: Some(UAString::from("info")),
+        inner_diagnostic_info: Some(Box::new(DiagnosticInfo {
+            locale: Some(2),
+            ..DiagnosticInfo::default()
+        })),
+        ..DiagnosticInfo::default()
+    };
+    xml_round_trip(diagnostic_info, "<Value><SymbolicId>1</SymbolicId><AdditionalInfo>info</AdditionalInfo>\
+<InnerDiagnosticInfo><Locale>2</Locale></InnerDiagnosticInfo></Value>");
+}
+
+#[test]
+fn xml_variant() {
+    xml_round_trip(Variant::from(UAString::from("Hello")), "<Value><String>Hello</String></Value>");
+    xml_round_trip(Variant::from(NodeId::new(1, 5)), "<Value><NodeId><Identifier>ns=1;i=5</Identifier></NodeId></Value>");
+    xml_round_trip(Variant::XmlElement(XmlElement::from("<a><b>1</b></a>")), "<Value><XmlElement><a><b>1</b></a></XmlElement></Value>");
+    assert_eq!(Variant::Empty.encode_xml("Value"), None);
+    assert_eq!(Variant::from_xml_str("<Value/>", &DecodingLimits::default()).unwrap(), Variant::Empty);
+
+    // Arrays of one type are lists of the type, otherwise of variants
+    xml_round_trip(Variant::from(vec![1i32, 2]), "<Value><ListOfInt32><Int32>1</Int32><Int32>2</Int32></ListOfInt32></Value>");
+    xml_round_trip(Variant::Array(vec![Variant::from(1i32), Variant::from(true)]),
+                   "<Value><ListOfVariant><Variant><Int32>1</Int32></Variant><Variant><Boolean>true</Boolean></Variant></ListOfVariant></Value>");
+
+    // Multi dimensional arrays are matrices
+    let mda = MultiDimensionArray::new(vec![Variant::from(1u8), Variant::from(2u8), Variant::from(3u8), Variant::from(4u8)], vec![2, 2]);
+    xml_round_trip(Variant::from(mda), "<Value><Matrix><Dimensions><Int32>2</Int32><Int32>2</Int32></Dimensions>\
+<Elements><Byte>1</Byte><Byte>2</Byte><Byte>3</Byte><Byte>4</Byte></Elements></Matrix></Value>");
+    let xml = "<Value><Matrix><Dimensions><Int32>3</Int32></Dimensions><Elements><Byte>1</Byte></Elements></Matrix></Value>";
+    assert_eq!(Variant::from_xml_str(xml, &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+
+    // Unknown types and arrays over the decoding limits
+    assert_eq!(Variant::from_xml_str("<Value><Foo/></Value>", &DecodingLimits::default()).unwrap_err(), StatusCode::BadDecodingError);
+    let decoding_limits = DecodingLimits { max_array_length: 1, ..DecodingLimits::default() };
+    let xml = "<Value><ListOfInt32><Int32>1</Int32><Int32>2</Int32></ListOfInt32></Value>";
+    assert_eq!(Variant::from_xml_str(xml, &decoding_limits).unwrap_err(), StatusCode::BadDecodingError);
+}
+
+#[test]
+fn xml_extension_object() {
+    // The body of a generated type is the XML of the structure identified by its XML encoding
+    let argument = Argument {
+        name: UAString::from("x"),
+        data_type: DataTypeId::Int32.into(),
+        value_rank: -1,
+        array_dimensions: None,
+        description: LocalizedText::null(),
+    };
+    let extension_object = ExtensionObject::from_encodable(ObjectId::Argument_Encoding_DefaultBinary, &argument);
+    let xml = extension_object.to_xml_string("Value");
+    assert_eq!(xml, format!("<Value><TypeId><Identifier>i={}</Identifier></TypeId><Body>\
+<Argument xmlns=\"{}\"><Name>x</Name><DataType><Identifier>i=6</Identifier></DataType><ValueRank>-1</ValueRank></Argument>\
+</Body></Value>", ObjectId::Argument_Encoding_DefaultXml as u32, TYPES_XML_NAMESPACE));
+    // and is decoded to the binary encoding
+    assert_eq!(ExtensionObject::from_xml_str(&xml, &DecodingLimits::default()).unwrap(), extension_object);
+
+    // Conversions between the encodings
+    let xml_object = extension_object.to_xml_encoding(&DecodingLimits::default()).unwrap();
+    assert_eq!(xml_object.node_id, ObjectId::Argument_Encoding_DefaultXml.into());
+    assert_eq!(xml_object.to_binary_encoding(&DecodingLimits::default()).unwrap(), extension_object);
+    assert_eq!(ExtensionObject::null().to_xml_encoding(&DecodingLimits::default()).unwrap_err(), StatusCode::BadDataEncodingUnsupported);
+
+    // Other bodies keep their encoding
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(2, 1000),
+        body: ExtensionObjectEncoding::ByteString(ByteString::from(&[1u8, 2, 3, 4])),
+    };
+    xml_round_trip(extension_object, "<Value><TypeId><Identifier>ns=2;i=1000</Identifier></TypeId><Body><ByteString>AQIDBA==</ByteString></Body></Value>");
+    let extension_object = ExtensionObject {
+        node_id: NodeId::new(2, 1001),
+        body: ExtensionObjectEncoding::XmlElement(XmlElement::from("<a>1</a>")),
+    };
+    xml_round_trip(extension_object, "<Value><TypeId><Identifier>ns=2;i=1001</Identifier></TypeId><Body><a>1</a></Body></Value>");
+    assert_eq!(ExtensionObject::null().encode_xml("Value"), None);
+}
+
+#[test]
+fn xml_service_types() {
+    let request = ReadRequest {
+        request_header: RequestHeader::new(&NodeId::new(0, 99), &DateTime::ymd_hms(2018, 11, 5, 8, 21, 44), 5),
+        max_age: 0f64,
+        timestamps_to_return: TimestampsToReturn::Both,
+        nodes_to_read: Some(vec![ReadValueId::from(NodeId::new(2, "v1"))]),
+    };
+    let element = request.encode_xml("ReadRequest").unwrap();
+    assert_eq!(element.child("RequestHeader").unwrap().child("RequestHandle").unwrap().text, "5");
+    assert_eq!(element.child("TimestampsToReturn").unwrap().text, "Both_2");
+    let read_value_id = element.child("NodesToRead").unwrap().child("ReadValueId").unwrap();
+    assert_eq!(read_value_id.child("NodeId").unwrap().child("Identifier").unwrap().text, "ns=2;s=v1");
+    let decoded = ReadRequest::decode_xml(Some(&element), &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+
+    // And through a string, which may be indented
+    let xml = element.to_pretty_xml_string();
+    let decoded = ReadRequest::from_xml_str(&xml, &DecodingLimits::default()).unwrap();
+    assert_eq!(decoded, request);
+}
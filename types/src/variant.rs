@@ -1,5 +1,6 @@
 //! Contains the implementation of `Variant`.
 
+use std::convert::TryFrom;
 use std::io::{Read, Write};
 
 use crate::{
@@ -11,8 +12,11 @@ use crate::{
     date_time::DateTime,
     encoding::*,
     guid::Guid,
+    json::*,
+    xml::*,
     node_id::{ExpandedNodeId, NodeId},
     node_ids::DataTypeId,
+    numeric_range::NumericRange,
     status_codes::StatusCode,
     string::{UAString, XmlElement},
 };
@@ -270,6 +274,55 @@ impl From<MultiDimensionArray> for Variant {
     }
 }
 
+/// Implements `TryFrom` a variant for a scalar type, which succeeds only if the variant holds
+/// that type. Any other variant is a `BadTypeMismatch`.
+macro_rules! try_from_variant_impl {
+    ($t: ty, $v: ident, $value: ident => $e: expr) => {
+        impl<'a> TryFrom<&'a Variant> for $t {
+            type Error = StatusCode;
+
+            fn try_from(value: &'a Variant) -> Result<Self, Self::Error> {
+                if let Variant::$v(ref $value) = *value {
+                    Ok($e)
+                } else {
+                    Err(StatusCode::BadTypeMismatch)
+                }
+            }
+        }
+
+        impl TryFrom<Variant> for $t {
+            type Error = StatusCode;
+
+            fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                <$t>::try_from(&value)
+            }
+        }
+    }
+}
+
+try_from_variant_impl!(bool, Boolean, value => *value);
+try_from_variant_impl!(i8, SByte, value => *value);
+try_from_variant_impl!(u8, Byte, value => *value);
+try_from_variant_impl!(i16, Int16, value => *value);
+try_from_variant_impl!(u16, UInt16, value => *value);
+try_from_variant_impl!(i32, Int32, value => *value);
+try_from_variant_impl!(u32, UInt32, value => *value);
+try_from_variant_impl!(i64, Int64, value => *value);
+try_from_variant_impl!(u64, UInt64, value => *value);
+try_from_variant_impl!(f32, Float, value => *value);
+try_from_variant_impl!(f64, Double, value => *value);
+try_from_variant_impl!(UAString, String, value => value.clone());
+try_from_variant_impl!(String, String, value => value.as_ref().to_string());
+try_from_variant_impl!(DateTime, DateTime, value => (**value).clone());
+try_from_variant_impl!(Guid, Guid, value => (**value).clone());
+try_from_variant_impl!(StatusCode, StatusCode, value => *value);
+try_from_variant_impl!(ByteString, ByteString, value => value.clone());
+try_from_variant_impl!(QualifiedName, QualifiedName, value => (**value).clone());
+try_from_variant_impl!(LocalizedText, LocalizedText, value => (**value).clone());
+try_from_variant_impl!(NodeId, NodeId, value => (**value).clone());
+try_from_variant_impl!(ExpandedNodeId, ExpandedNodeId, value => (**value).clone());
+try_from_variant_impl!(ExtensionObject, ExtensionObject, value => (**value).clone());
+
 /// A `Variant` holds all other OPC UA types, including single and multi dimensional arrays,
 /// data values and extension objects.
 ///
@@ -368,6 +421,113 @@ fn array_is_same_type(values: &[Variant], numeric_only: bool) -> bool {
     }
 }
 
+/// The bounds of a range over the dimensions of an array, and over the strings in it
+type SplitRangeBounds<'a> = (&'a [(u32, u32)], Option<(u32, u32)>);
+
+/// Returns the indices of the elements of an array that the bounds select, in the order they are
+/// held, and the dimensions of the selection. There must be bounds for each dimension of the
+/// array. A lower bound outside its dimension is an error, and so is an upper bound unless it is
+/// clipped to the end of the dimension.
+fn array_range_indices(array_dimensions: &[usize], bounds: &[(u32, u32)], clip: bool) -> Result<(Vec<usize>, Vec<usize>), StatusCode> {
+    if array_dimensions.is_empty() || array_dimensions.len() != bounds.len() {
+        return Err(StatusCode::BadIndexRangeInvalid);
+    }
+    let mut clipped_bounds = Vec::with_capacity(bounds.len());
+    for (len, (min, max)) in array_dimensions.iter().zip(bounds.iter()) {
+        let (min, max) = (*min as usize, *max as usize);
+        if min >= *len || (!clip && max >= *len) {
+            return Err(StatusCode::BadIndexRangeNoData);
+        }
+        clipped_bounds.push((min, max.min(*len - 1)));
+    }
+
+    // The last dimension varies fastest
+    let mut strides = vec![1; array_dimensions.len()];
+    for i in (0..array_dimensions.len() - 1).rev() {
+        strides[i] = strides[i + 1] * array_dimensions[i + 1];
+    }
+    let selection_dimensions = clipped_bounds.iter().map(|(min, max)| max - min + 1).collect::<Vec<usize>>();
+    let mut indices = Vec::with_capacity(selection_dimensions.iter().product());
+    let mut position = clipped_bounds.iter().map(|(min, _)| *min).collect::<Vec<usize>>();
+    loop {
+        indices.push(position.iter().zip(strides.iter()).map(|(p, stride)| p * stride).sum());
+        // Move to the next position, carrying over from the last dimension to the first
+        let mut dimension = position.len();
+        loop {
+            if dimension == 0 {
+                return Ok((indices, selection_dimensions));
+            }
+            dimension -= 1;
+            if position[dimension] < clipped_bounds[dimension].1 {
+                position[dimension] += 1;
+                break;
+            }
+            position[dimension] = clipped_bounds[dimension].0;
+        }
+    }
+}
+
+/// Returns the characters of a string or the bytes of a byte string that the bounds select. An
+/// upper bound past the end is clipped to the end.
+fn substring_of(value: &Variant, bounds: (u32, u32)) -> Result<Variant, StatusCode> {
+    let (min, max) = (bounds.0 as usize, bounds.1 as usize);
+    match *value {
+        Variant::String(ref value) => {
+            let chars = value.as_ref().chars().collect::<Vec<char>>();
+            if min >= chars.len() {
+                Err(StatusCode::BadIndexRangeNoData)
+            } else {
+                Ok(Variant::from(chars[min..=max.min(chars.len() - 1)].iter().collect::<String>()))
+            }
+        }
+        Variant::ByteString(ref value) => {
+            let bytes = value.as_ref();
+            if min >= bytes.len() {
+                Err(StatusCode::BadIndexRangeNoData)
+            } else {
+                Ok(Variant::from(ByteString::from(bytes[min..=max.min(bytes.len() - 1)].to_vec())))
+            }
+        }
+        _ => Err(StatusCode::BadIndexRangeInvalid)
+    }
+}
+
+/// Replaces the characters of a string or the bytes of a byte string that the bounds select with
+/// those of a value of the same type. The bounds must be inside the string and the value must have
+/// as many characters or bytes as they select.
+fn set_substring_of(target: &mut Variant, bounds: (u32, u32), value: &Variant) -> Result<(), StatusCode> {
+    let (min, max) = (bounds.0 as usize, bounds.1 as usize);
+    match (target, value) {
+        (Variant::String(ref mut target), Variant::String(ref value)) => {
+            let mut chars = target.as_ref().chars().collect::<Vec<char>>();
+            let value = value.as_ref().chars().collect::<Vec<char>>();
+            if max >= chars.len() {
+                Err(StatusCode::BadIndexRangeNoData)
+            } else if value.len() != max - min + 1 {
+                Err(StatusCode::BadIndexRangeInvalid)
+            } else {
+                chars.splice(min..=max, value);
+                *target = UAString::from(chars.into_iter().collect::<String>());
+                Ok(())
+            }
+        }
+        (Variant::ByteString(ref mut target), Variant::ByteString(ref value)) => {
+            let mut bytes = target.as_ref().to_vec();
+            if max >= bytes.len() {
+                Err(StatusCode::BadIndexRangeNoData)
+            } else if value.as_ref().len() != max - min + 1 {
+                Err(StatusCode::BadIndexRangeInvalid)
+            } else {
+                bytes.splice(min..=max, value.as_ref().iter().cloned());
+                *target = ByteString::from(bytes);
+                Ok(())
+            }
+        }
+        (Variant::String(_), _) | (Variant::ByteString(_), _) => Err(StatusCode::BadTypeMismatch),
+        _ => Err(StatusCode::BadIndexRangeInvalid)
+    }
+}
+
 /// A multi dimensional array is a vector of values, followed by a vector of sizes of each dimension.
 /// It is expected that the multi-dimensional array is valid, or it might not be encoded or decoded
 /// properly.
@@ -577,6 +737,151 @@ impl BinaryEncoder<Variant> for Variant {
     }
 }
 
+/// The reversible form is an object holding the built-in type id of the value, the value or array
+/// of values as its body and the dimensions of a multi dimensional array. The non-reversible form
+/// is just the body, with a multi dimensional array as nested arrays.
+impl JsonEncoder for Variant {
+    fn encode_json(&self, context: &JsonContext) -> JsonValue {
+        let body = match *self {
+            Variant::Empty => return JsonValue::Null,
+            Variant::Array(ref values) => {
+                JsonValue::Array(values.iter().map(|v| Variant::encode_json_variant_value(v, context)).collect())
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let values = mda.values.iter().map(|v| Variant::encode_json_variant_value(v, context)).collect::<Vec<_>>();
+                if context.reversible {
+                    JsonValue::Array(values)
+                } else {
+                    return Variant::nest_json_array(&values, &mda.dimensions);
+                }
+            }
+            _ => Variant::encode_json_variant_value(self, context)
+        };
+        if context.reversible {
+            let mut fields = JsonObject::new();
+            fields.insert("Type".to_string(), JsonValue::from(self.get_encoding_mask() & !(ARRAY_DIMENSIONS_BIT | ARRAY_VALUES_BIT)));
+            insert_json_field(&mut fields, "Body", body);
+            if let Variant::MultiDimensionArray(ref mda) = *self {
+                fields.insert("Dimensions".to_string(), JsonValue::from(mda.dimensions.clone()));
+            }
+            JsonValue::Object(fields)
+        } else {
+            body
+        }
+    }
+}
+
+impl JsonDecoder for Variant {
+    fn decode_json(value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        if value.is_null() {
+            return Ok(Variant::Empty);
+        }
+        let fields = json_object(value)?;
+        let type_id = u8::decode_json(json_field(fields, "Type"), decoding_limits)?;
+        let dimensions: Option<Vec<i32>> = decode_json_array(json_field(fields, "Dimensions"), decoding_limits)?;
+        match *json_field(fields, "Body") {
+            JsonValue::Array(ref values) => {
+                if values.len() > decoding_limits.max_array_length as usize {
+                    error!("Array length {} exceeds decoding limit {}", values.len(), decoding_limits.max_array_length);
+                    return Err(StatusCode::BadDecodingError);
+                }
+                let values = values.iter()
+                    .map(|v| Variant::decode_json_variant_value(type_id, v, decoding_limits))
+                    .collect::<EncodingResult<Vec<Variant>>>()?;
+                if let Some(dimensions) = dimensions {
+                    let mda = MultiDimensionArray::new(values, dimensions);
+                    if !mda.is_valid() {
+                        error!("Array dimensions do not match the array length");
+                        return Err(StatusCode::BadDecodingError);
+                    }
+                    Ok(Variant::from(mda))
+                } else {
+                    Ok(Variant::Array(values))
+                }
+            }
+            ref body => {
+                if dimensions.is_some() {
+                    error!("Array dimensions specified without any values");
+                    Err(StatusCode::BadDecodingError)
+                } else {
+                    Variant::decode_json_variant_value(type_id, body, decoding_limits)
+                }
+            }
+        }
+    }
+}
+
+/// Variant encoded as an element holding a single element named after the value's type, e.g.
+/// `<Value><Int32>5</Int32></Value>`. Arrays are held by a `ListOf<Type>` element, or a
+/// `ListOfVariant` element when the values are of different types, and multi dimensional arrays
+/// by a `Matrix` element holding the dimensions and the elements.
+impl XmlEncoder for Variant {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        let body = match *self {
+            Variant::Empty => return None,
+            Variant::Array(ref values) => {
+                let type_name = if !values.is_empty() && array_is_same_type(values, false) {
+                    values[0].xml_type_name()
+                } else {
+                    None
+                };
+                if let Some(type_name) = type_name {
+                    let mut list = XmlNode::new(&format!("ListOf{}", type_name));
+                    list.children = values.iter().map(Variant::encode_xml_variant_value).collect();
+                    list
+                } else {
+                    let mut list = XmlNode::new("ListOfVariant");
+                    list.children = values.iter()
+                        .map(|v| v.encode_xml("Variant").unwrap_or_else(|| XmlNode::new("Variant")))
+                        .collect();
+                    list
+                }
+            }
+            Variant::MultiDimensionArray(ref mda) => {
+                let mut matrix = XmlNode::new("Matrix");
+                matrix.add_child(encode_xml_array("Dimensions", "Int32", &Some(mda.dimensions.clone())));
+                let mut elements = XmlNode::new("Elements");
+                elements.children = mda.values.iter().map(Variant::encode_xml_variant_value).collect();
+                matrix.add_child(elements);
+                matrix
+            }
+            _ => Variant::encode_xml_variant_value(self)
+        };
+        let mut element = XmlNode::new(name);
+        element.add_child(body);
+        Some(element)
+    }
+}
+
+impl XmlDecoder for Variant {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let body = if let Some(body) = element.and_then(|element| element.children.first()) {
+            body
+        } else {
+            return Ok(Variant::Empty);
+        };
+        let type_name = body.local_name();
+        if type_name == "Matrix" {
+            let dimensions: Option<Vec<i32>> = decode_xml_array(body.child("Dimensions"), decoding_limits)?;
+            let values = Variant::decode_xml_variant_values(body.child("Elements"), decoding_limits)?;
+            let mda = MultiDimensionArray::new(values, dimensions.unwrap_or_default());
+            if !mda.is_valid() {
+                error!("Matrix dimensions do not match its elements");
+                Err(StatusCode::BadDecodingError)
+            } else {
+                Ok(Variant::from(mda))
+            }
+        } else if type_name == "ListOfVariant" {
+            let values: Option<Vec<Variant>> = decode_xml_array(Some(body), decoding_limits)?;
+            Ok(Variant::Array(values.unwrap_or_default()))
+        } else if type_name.starts_with("ListOf") {
+            Ok(Variant::Array(Variant::decode_xml_variant_values(Some(body), decoding_limits)?))
+        } else {
+            Variant::decode_xml_variant_value(body, decoding_limits)
+        }
+    }
+}
+
 impl Default for Variant {
     fn default() -> Self {
         Variant::Empty
@@ -648,7 +953,7 @@ impl Variant {
     }
 
     /// Encodes just the value, not the encoding flag
-    fn encode_variant_value<S: Write>(stream: &mut S, value: &Variant) -> EncodingResult<usize> {
+    pub fn encode_variant_value<S: Write>(stream: &mut S, value: &Variant) -> EncodingResult<usize> {
         match *value {
             Variant::Empty => Ok(0),
             Variant::Boolean(ref value) => value.encode(stream),
@@ -681,8 +986,9 @@ impl Variant {
         }
     }
 
-    /// Reads just the variant value from the stream
-    fn decode_variant_value<S: Read>(stream: &mut S, encoding_mask: u8, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+    /// Reads just the variant value from the stream, where the encoding mask is the id of its
+    /// built-in data type
+    pub fn decode_variant_value<S: Read>(stream: &mut S, encoding_mask: u8, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
         let result = if encoding_mask == 0 {
             Variant::Empty
         } else if Self::test_encoding_flag(encoding_mask, DataTypeId::Boolean) {
@@ -738,6 +1044,222 @@ impl Variant {
         Ok(result)
     }
 
+    /// Encodes just the variant value as JSON
+    fn encode_json_variant_value(value: &Variant, context: &JsonContext) -> JsonValue {
+        match *value {
+            Variant::Empty => JsonValue::Null,
+            Variant::Boolean(ref value) => value.encode_json(context),
+            Variant::SByte(ref value) => value.encode_json(context),
+            Variant::Byte(ref value) => value.encode_json(context),
+            Variant::Int16(ref value) => value.encode_json(context),
+            Variant::UInt16(ref value) => value.encode_json(context),
+            Variant::Int32(ref value) => value.encode_json(context),
+            Variant::UInt32(ref value) => value.encode_json(context),
+            Variant::Int64(ref value) => value.encode_json(context),
+            Variant::UInt64(ref value) => value.encode_json(context),
+            Variant::Float(ref value) => value.encode_json(context),
+            Variant::Double(ref value) => value.encode_json(context),
+            Variant::String(ref value) => value.encode_json(context),
+            Variant::DateTime(ref value) => value.encode_json(context),
+            Variant::Guid(ref value) => value.encode_json(context),
+            Variant::ByteString(ref value) => value.encode_json(context),
+            Variant::XmlElement(ref value) => value.encode_json(context),
+            Variant::NodeId(ref value) => value.encode_json(context),
+            Variant::ExpandedNodeId(ref value) => value.encode_json(context),
+            Variant::StatusCode(ref value) => value.encode_json(context),
+            Variant::QualifiedName(ref value) => value.encode_json(context),
+            Variant::LocalizedText(ref value) => value.encode_json(context),
+            Variant::ExtensionObject(ref value) => value.encode_json(context),
+            Variant::DataValue(ref value) => value.encode_json(context),
+            _ => {
+                warn!("Cannot encode this variant value type (probably nested array)");
+                JsonValue::Null
+            }
+        }
+    }
+
+    /// Decodes just the variant value of the built-in type from JSON
+    fn decode_json_variant_value(type_id: u8, value: &JsonValue, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let result = match type_id {
+            0 => Variant::Empty,
+            1 => Self::from(bool::decode_json(value, decoding_limits)?),
+            2 => Self::from(i8::decode_json(value, decoding_limits)?),
+            3 => Self::from(u8::decode_json(value, decoding_limits)?),
+            4 => Self::from(i16::decode_json(value, decoding_limits)?),
+            5 => Self::from(u16::decode_json(value, decoding_limits)?),
+            6 => Self::from(i32::decode_json(value, decoding_limits)?),
+            7 => Self::from(u32::decode_json(value, decoding_limits)?),
+            8 => Self::from(i64::decode_json(value, decoding_limits)?),
+            9 => Self::from(u64::decode_json(value, decoding_limits)?),
+            10 => Self::from(f32::decode_json(value, decoding_limits)?),
+            11 => Self::from(f64::decode_json(value, decoding_limits)?),
+            12 => Self::from(UAString::decode_json(value, decoding_limits)?),
+            13 => Self::from(DateTime::decode_json(value, decoding_limits)?),
+            14 => Self::from(Guid::decode_json(value, decoding_limits)?),
+            15 => Self::from(ByteString::decode_json(value, decoding_limits)?),
+            // Force the type to be XmlElement since its typedef'd to UAString
+            16 => Variant::XmlElement(XmlElement::decode_json(value, decoding_limits)?),
+            17 => Self::from(NodeId::decode_json(value, decoding_limits)?),
+            18 => Self::from(ExpandedNodeId::decode_json(value, decoding_limits)?),
+            19 => Self::from(StatusCode::decode_json(value, decoding_limits)?),
+            20 => Self::from(QualifiedName::decode_json(value, decoding_limits)?),
+            21 => Self::from(LocalizedText::decode_json(value, decoding_limits)?),
+            22 => Self::from(ExtensionObject::decode_json(value, decoding_limits)?),
+            23 => Self::from(DataValue::decode_json(value, decoding_limits)?),
+            _ => {
+                error!("Variant has an invalid type {}", type_id);
+                return Err(StatusCode::BadDecodingError);
+            }
+        };
+        Ok(result)
+    }
+
+    /// Nests the encoded values of a multi dimensional array into an array for each dimension
+    fn nest_json_array(values: &[JsonValue], dimensions: &[i32]) -> JsonValue {
+        if dimensions.len() <= 1 {
+            JsonValue::Array(values.to_vec())
+        } else {
+            let chunk_size = dimensions[1..].iter().product::<i32>().max(1) as usize;
+            JsonValue::Array(values.chunks(chunk_size).map(|v| Variant::nest_json_array(v, &dimensions[1..])).collect())
+        }
+    }
+
+    /// Returns the name of the XML element of a scalar value, which is the name of its type
+    fn xml_type_name(&self) -> Option<&'static str> {
+        let name = match *self {
+            Variant::Boolean(_) => "Boolean",
+            Variant::SByte(_) => "SByte",
+            Variant::Byte(_) => "Byte",
+            Variant::Int16(_) => "Int16",
+            Variant::UInt16(_) => "UInt16",
+            Variant::Int32(_) => "Int32",
+            Variant::UInt32(_) => "UInt32",
+            Variant::Int64(_) => "Int64",
+            Variant::UInt64(_) => "UInt64",
+            Variant::Float(_) => "Float",
+            Variant::Double(_) => "Double",
+            Variant::String(_) => "String",
+            Variant::DateTime(_) => "DateTime",
+            Variant::Guid(_) => "Guid",
+            Variant::ByteString(_) => "ByteString",
+            Variant::XmlElement(_) => "XmlElement",
+            Variant::NodeId(_) => "NodeId",
+            Variant::ExpandedNodeId(_) => "ExpandedNodeId",
+            Variant::StatusCode(_) => "StatusCode",
+            Variant::QualifiedName(_) => "QualifiedName",
+            Variant::LocalizedText(_) => "LocalizedText",
+            Variant::ExtensionObject(_) => "ExtensionObject",
+            Variant::DataValue(_) => "DataValue",
+            _ => return None
+        };
+        Some(name)
+    }
+
+    /// Encodes just the variant value as an XML element named after its type. A null value is an
+    /// empty element.
+    fn encode_xml_variant_value(value: &Variant) -> XmlNode {
+        let name = if let Some(name) = value.xml_type_name() {
+            name
+        } else {
+            warn!("Cannot encode this variant value type (probably nested array)");
+            return XmlNode::new("Null");
+        };
+        let element = match *value {
+            Variant::Boolean(ref value) => value.encode_xml(name),
+            Variant::SByte(ref value) => value.encode_xml(name),
+            Variant::Byte(ref value) => value.encode_xml(name),
+            Variant::Int16(ref value) => value.encode_xml(name),
+            Variant::UInt16(ref value) => value.encode_xml(name),
+            Variant::Int32(ref value) => value.encode_xml(name),
+            Variant::UInt32(ref value) => value.encode_xml(name),
+            Variant::Int64(ref value) => value.encode_xml(name),
+            Variant::UInt64(ref value) => value.encode_xml(name),
+            Variant::Float(ref value) => value.encode_xml(name),
+            Variant::Double(ref value) => value.encode_xml(name),
+            Variant::String(ref value) => value.encode_xml(name),
+            Variant::DateTime(ref value) => value.encode_xml(name),
+            Variant::Guid(ref value) => value.encode_xml(name),
+            Variant::ByteString(ref value) => value.encode_xml(name),
+            Variant::XmlElement(ref value) => {
+                // The element holds the XML itself rather than its escaped text
+                let mut element = XmlNode::new(name);
+                if let Some(ref value) = value.value {
+                    if let Ok(xml) = XmlNode::parse(value) {
+                        element.add_child(xml);
+                    } else {
+                        element.text = value.clone();
+                    }
+                }
+                Some(element)
+            }
+            Variant::NodeId(ref value) => value.encode_xml(name),
+            Variant::ExpandedNodeId(ref value) => value.encode_xml(name),
+            Variant::StatusCode(ref value) => value.encode_xml(name),
+            Variant::QualifiedName(ref value) => value.encode_xml(name),
+            Variant::LocalizedText(ref value) => value.encode_xml(name),
+            Variant::ExtensionObject(ref value) => value.encode_xml(name),
+            Variant::DataValue(ref value) => value.encode_xml(name),
+            _ => None
+        };
+        element.unwrap_or_else(|| XmlNode::new(name))
+    }
+
+    /// Decodes just the variant value of the built-in type named by the element
+    fn decode_xml_variant_value(element: &XmlNode, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let value = Some(element);
+        let result = match element.local_name() {
+            "Boolean" => Self::from(bool::decode_xml(value, decoding_limits)?),
+            "SByte" => Self::from(i8::decode_xml(value, decoding_limits)?),
+            "Byte" => Self::from(u8::decode_xml(value, decoding_limits)?),
+            "Int16" => Self::from(i16::decode_xml(value, decoding_limits)?),
+            "UInt16" => Self::from(u16::decode_xml(value, decoding_limits)?),
+            "Int32" => Self::from(i32::decode_xml(value, decoding_limits)?),
+            "UInt32" => Self::from(u32::decode_xml(value, decoding_limits)?),
+            "Int64" => Self::from(i64::decode_xml(value, decoding_limits)?),
+            "UInt64" => Self::from(u64::decode_xml(value, decoding_limits)?),
+            "Float" => Self::from(f32::decode_xml(value, decoding_limits)?),
+            "Double" => Self::from(f64::decode_xml(value, decoding_limits)?),
+            "String" => Self::from(UAString::decode_xml(value, decoding_limits)?),
+            "DateTime" => Self::from(DateTime::decode_xml(value, decoding_limits)?),
+            "Guid" => Self::from(Guid::decode_xml(value, decoding_limits)?),
+            "ByteString" => Self::from(ByteString::decode_xml(value, decoding_limits)?),
+            "XmlElement" => {
+                let xml = if let Some(xml) = element.children.first() {
+                    XmlElement::from(xml.to_xml_string())
+                } else {
+                    XmlElement::from(element.text.as_str())
+                };
+                Variant::XmlElement(xml)
+            }
+            "NodeId" => Self::from(NodeId::decode_xml(value, decoding_limits)?),
+            "ExpandedNodeId" => Self::from(ExpandedNodeId::decode_xml(value, decoding_limits)?),
+            "StatusCode" => Self::from(StatusCode::decode_xml(value, decoding_limits)?),
+            "QualifiedName" => Self::from(QualifiedName::decode_xml(value, decoding_limits)?),
+            "LocalizedText" => Self::from(LocalizedText::decode_xml(value, decoding_limits)?),
+            "ExtensionObject" => Self::from(ExtensionObject::decode_xml(value, decoding_limits)?),
+            "DataValue" => Self::from(DataValue::decode_xml(value, decoding_limits)?),
+            "Null" => Variant::Empty,
+            name => {
+                error!("Variant has an invalid type {}", name);
+                return Err(StatusCode::BadDecodingError);
+            }
+        };
+        Ok(result)
+    }
+
+    /// Decodes the variant values held by the child elements of an element
+    fn decode_xml_variant_values(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Vec<Variant>> {
+        let values = element.map(|element| element.children.as_slice()).unwrap_or(&[]);
+        if values.len() > decoding_limits.max_array_length as usize {
+            error!("Array length {} exceeds decoding limit {}", values.len(), decoding_limits.max_array_length);
+            Err(StatusCode::BadDecodingError)
+        } else {
+            values.iter()
+                .map(|v| Variant::decode_xml_variant_value(v, decoding_limits))
+                .collect()
+        }
+    }
+
     pub fn type_id(&self) -> VariantTypeId {
         match *self {
             Variant::Empty => VariantTypeId::Empty,
@@ -826,6 +1348,125 @@ impl Variant {
         }
     }
 
+    /// Returns the part of an array, multi dimensional array, string or byte string value that the
+    /// numeric range selects. Strings and byte strings are arrays of characters and bytes, and the
+    /// range may have an extra dimension that selects from each string in an array of them. An
+    /// upper bound past the end of the value is clipped to the end, but if a lower bound is past
+    /// it the result is `BadIndexRangeNoData`.
+    pub fn range_of(&self, range: &NumericRange) -> Result<Variant, StatusCode> {
+        if !range.is_valid() {
+            return Err(StatusCode::BadIndexRangeInvalid);
+        }
+        let bounds = range.dimensions();
+        let (values, array_dimensions) = match *self {
+            Variant::String(_) | Variant::ByteString(_) if bounds.len() == 1 => {
+                return substring_of(self, bounds[0]);
+            }
+            Variant::Array(ref values) => (values, vec![values.len()]),
+            Variant::MultiDimensionArray(ref mda) => (&mda.values, mda.dimensions.iter().map(|d| *d as usize).collect()),
+            _ => {
+                return Err(StatusCode::BadIndexRangeInvalid);
+            }
+        };
+        let (array_bounds, element_bounds) = Self::split_range_bounds(&bounds, array_dimensions.len())?;
+        let (indices, selection_dimensions) = array_range_indices(&array_dimensions, array_bounds, true)?;
+        let mut selection = Vec::with_capacity(indices.len());
+        for idx in indices {
+            let value = if let Some(element_bounds) = element_bounds {
+                match substring_of(&values[idx], element_bounds) {
+                    Ok(value) => value,
+                    // A string that is too short to select from is null
+                    Err(StatusCode::BadIndexRangeNoData) => match values[idx] {
+                        Variant::String(_) => Variant::from(UAString::null()),
+                        _ => Variant::from(ByteString::null()),
+                    },
+                    Err(status_code) => {
+                        return Err(status_code);
+                    }
+                }
+            } else {
+                values[idx].clone()
+            };
+            selection.push(value);
+        }
+        if let Variant::Array(_) = *self {
+            Ok(Variant::Array(selection))
+        } else {
+            Ok(Variant::new_multi_dimension_array(selection, selection_dimensions.iter().map(|d| *d as i32).collect()))
+        }
+    }
+
+    /// Replaces the part of an array, multi dimensional array, string or byte string value that
+    /// the numeric range selects with the supplied value, following the rules of `range_of()`.
+    /// The range must lie inside this value, and the supplied value must have the dimensions of
+    /// the part it replaces and the same type of elements.
+    pub fn set_range_of(&mut self, range: &NumericRange, value: &Variant) -> Result<(), StatusCode> {
+        if !range.is_valid() {
+            return Err(StatusCode::BadIndexRangeInvalid);
+        }
+        let bounds = range.dimensions();
+        let (values, array_dimensions) = match *self {
+            Variant::String(_) | Variant::ByteString(_) if bounds.len() == 1 => {
+                return set_substring_of(self, bounds[0], value);
+            }
+            Variant::Array(ref mut values) => {
+                let array_dimensions = vec![values.len()];
+                (values, array_dimensions)
+            }
+            Variant::MultiDimensionArray(ref mut mda) => {
+                let array_dimensions = mda.dimensions.iter().map(|d| *d as usize).collect();
+                (&mut mda.values, array_dimensions)
+            }
+            _ => {
+                return Err(StatusCode::BadIndexRangeInvalid);
+            }
+        };
+        let (array_bounds, element_bounds) = Self::split_range_bounds(&bounds, array_dimensions.len())?;
+        let (indices, selection_dimensions) = array_range_indices(&array_dimensions, array_bounds, false)?;
+        let (new_values, new_value_dimensions) = match *value {
+            Variant::Array(ref new_values) => (new_values, vec![new_values.len()]),
+            Variant::MultiDimensionArray(ref mda) => (&mda.values, mda.dimensions.iter().map(|d| *d as usize).collect()),
+            _ => {
+                return Err(StatusCode::BadTypeMismatch);
+            }
+        };
+        if new_value_dimensions != selection_dimensions {
+            return Err(StatusCode::BadIndexRangeInvalid);
+        }
+        if indices.iter().zip(new_values.iter()).any(|(idx, new_value)| values[*idx].type_id() != new_value.type_id()) {
+            return Err(StatusCode::BadTypeMismatch);
+        }
+        if let Some(element_bounds) = element_bounds {
+            // Check every string can be written before any is
+            let mut elements = Vec::with_capacity(indices.len());
+            for (idx, new_value) in indices.iter().zip(new_values.iter()) {
+                let mut element = values[*idx].clone();
+                set_substring_of(&mut element, element_bounds, new_value)?;
+                elements.push(element);
+            }
+            for (idx, element) in indices.into_iter().zip(elements) {
+                values[idx] = element;
+            }
+        } else {
+            for (idx, new_value) in indices.into_iter().zip(new_values.iter()) {
+                values[idx] = new_value.clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits the bounds of a range into those for each dimension of an array and those of the
+    /// extra dimension that selects from the strings in it, if there is one
+    fn split_range_bounds(bounds: &[(u32, u32)], array_dimensions: usize) -> Result<SplitRangeBounds, StatusCode> {
+        if bounds.len() == array_dimensions {
+            Ok((bounds, None))
+        } else if bounds.len() == array_dimensions + 1 {
+            Ok((&bounds[..array_dimensions], Some(bounds[array_dimensions])))
+        } else {
+            Err(StatusCode::BadIndexRangeInvalid)
+        }
+    }
+
     /// Converts the numeric type to a double or returns None
     pub fn as_f64(&self) -> Option<f64> {
         match *self {
@@ -930,8 +1571,9 @@ impl Variant {
         }
     }
 
-    // Gets the encoding mask to write the variant to disk
-    fn get_encoding_mask(&self) -> u8 {
+    /// Gets the encoding mask to write the variant to disk, which for a scalar is the id of its
+    /// built-in data type
+    pub fn get_encoding_mask(&self) -> u8 {
         match *self {
             Variant::Empty => 0,
             Variant::Boolean(_) => DataTypeId::Boolean as u8,
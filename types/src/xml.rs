@@ -0,0 +1,572 @@
+//! Contains the `XmlEncoder` and `XmlDecoder` traits for the OPC UA XML encoding described in
+//! Part 6, helpers for encoding and decoding scalar values, arrays and structures, and `XmlNode`,
+//! a small XML document model that values are encoded to and decoded from.
+//!
+//! Elements are matched by their local name, i.e. without any namespace prefix, because documents
+//! such as NodeSet2 files qualify the elements of values with a prefix of their own choosing.
+
+use std::{
+    fmt::{Debug, Write},
+    io::Cursor,
+    str::FromStr,
+};
+
+use crate::{
+    encoding::*,
+    status_codes::StatusCode,
+};
+
+/// The namespace of the elements of the OPC UA XML encoding
+pub const TYPES_XML_NAMESPACE: &str = "http://opcfoundation.org/UA/2008/02/Types.xsd";
+
+/// An element of an XML document with its attributes, child elements and text. The text of
+/// elements with mixed content is concatenated.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct XmlNode {
+    /// The name of the element as it was written, including any namespace prefix
+    pub name: String,
+    /// The attributes of the element in the order they were written
+    pub attributes: Vec<(String, String)>,
+    /// The child elements
+    pub children: Vec<XmlNode>,
+    /// The text of the element with entities and character references resolved
+    pub text: String,
+}
+
+impl XmlNode {
+    /// Creates an empty element
+    pub fn new(name: &str) -> XmlNode {
+        XmlNode {
+            name: name.to_string(),
+            ..XmlNode::default()
+        }
+    }
+
+    /// Creates an element holding the text
+    pub fn new_text<T>(name: &str, text: T) -> XmlNode where T: Into<String> {
+        XmlNode {
+            name: name.to_string(),
+            text: text.into(),
+            ..XmlNode::default()
+        }
+    }
+
+    /// Parses an XML document and returns its root element
+    pub fn parse(xml: &str) -> EncodingResult<XmlNode> {
+        XmlParser { xml, position: 0, depth: 0 }.parse_document()
+    }
+
+    /// Returns the name of the element without its namespace prefix
+    pub fn local_name(&self) -> &str {
+        local_name(&self.name)
+    }
+
+    /// Returns the value of the attribute with the name, ignoring any namespace prefix
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter()
+            .find(|(n, _)| n == name || local_name(n) == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets the value of an attribute, replacing any previous value
+    pub fn set_attribute<T>(&mut self, name: &str, value: T) where T: Into<String> {
+        let value = value.into();
+        if let Some(attribute) = self.attributes.iter_mut().find(|(n, _)| n == name) {
+            attribute.1 = value;
+        } else {
+            self.attributes.push((name.to_string(), value));
+        }
+    }
+
+    /// Adds a child element, or does nothing for `None`, i.e. a null value which is omitted
+    pub fn add_child<T>(&mut self, child: T) where T: Into<Option<XmlNode>> {
+        if let Some(child) = child.into() {
+            self.children.push(child);
+        }
+    }
+
+    /// Returns the first child element with the local name
+    pub fn child(&self, name: &str) -> Option<&XmlNode> {
+        self.children.iter().find(|c| c.local_name() == name)
+    }
+
+    /// Returns the child elements with the local name
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item=&'a XmlNode> {
+        self.children.iter().filter(move |c| c.local_name() == name)
+    }
+
+    /// Writes the element as a compact XML string
+    pub fn to_xml_string(&self) -> String {
+        let mut xml = String::new();
+        self.write_xml(&mut xml, None);
+        xml
+    }
+
+    /// Writes the element as an XML string with an element on each line, indented by its depth.
+    /// The text of elements that have children is not written.
+    pub fn to_pretty_xml_string(&self) -> String {
+        let mut xml = String::new();
+        self.write_xml(&mut xml, Some(0));
+        xml
+    }
+
+    fn write_xml(&self, xml: &mut String, depth: Option<usize>) {
+        if let Some(depth) = depth {
+            for _ in 0..depth {
+                xml.push_str("  ");
+            }
+        }
+        let _ = write!(xml, "<{}", self.name);
+        for (name, value) in &self.attributes {
+            let _ = write!(xml, " {}=\"{}\"", name, escape_xml(value, true));
+        }
+        if self.children.is_empty() && self.text.is_empty() {
+            xml.push_str("/>");
+        } else if self.children.is_empty() {
+            let _ = write!(xml, ">{}</{}>", escape_xml(&self.text, false), self.name);
+        } else {
+            xml.push('>');
+            if depth.is_none() {
+                xml.push_str(&escape_xml(&self.text, false));
+            }
+            for child in &self.children {
+                if depth.is_some() {
+                    xml.push('\n');
+                }
+                child.write_xml(xml, depth.map(|depth| depth + 1));
+            }
+            if let Some(depth) = depth {
+                xml.push('\n');
+                for _ in 0..depth {
+                    xml.push_str("  ");
+                }
+            }
+            let _ = write!(xml, "</{}>", self.name);
+        }
+    }
+}
+
+/// Returns a name without its namespace prefix
+fn local_name(name: &str) -> &str {
+    if let Some(pos) = name.find(':') { &name[pos + 1..] } else { name }
+}
+
+/// Escapes the characters of text or an attribute value that XML reserves
+fn escape_xml(value: &str, attribute: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' if attribute => escaped.push_str("&quot;"),
+            '\n' if attribute => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The deepest that elements may be nested in a document, so that a malicious document cannot
+/// overflow the stack of the recursive parser
+const MAX_ELEMENT_DEPTH: usize = 100;
+
+/// A parser for the subset of XML that documents of values use - elements, attributes, text,
+/// CDATA, comments, processing instructions and a DOCTYPE which is skipped.
+struct XmlParser<'a> {
+    xml: &'a str,
+    position: usize,
+    /// The number of elements that enclose the element being parsed
+    depth: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn error<T>(&self, message: &str) -> EncodingResult<T> {
+        error!("Invalid XML at position {}, {}", self.position, message);
+        Err(StatusCode::BadDecodingError)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips past the terminator, returning the text before it
+    fn skip_past(&mut self, terminator: &str) -> EncodingResult<&'a str> {
+        if let Some(pos) = self.rest().find(terminator) {
+            let skipped = &self.rest()[..pos];
+            self.position += pos + terminator.len();
+            Ok(skipped)
+        } else {
+            self.error(&format!("expected {}", terminator))
+        }
+    }
+
+    /// Skips the prolog, comments and processing instructions outside of the root element
+    fn skip_misc(&mut self) -> EncodingResult<()> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!DOCTYPE") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_document(mut self) -> EncodingResult<XmlNode> {
+        // Skip a byte order mark
+        if self.rest().starts_with('\u{feff}') {
+            self.position += '\u{feff}'.len_utf8();
+        }
+        self.skip_misc()?;
+        let root = self.parse_element()?;
+        self.skip_misc()?;
+        if !self.rest().is_empty() {
+            self.error("content after the root element")
+        } else {
+            Ok(root)
+        }
+    }
+
+    fn parse_name(&mut self) -> EncodingResult<&'a str> {
+        let rest = self.rest();
+        let len = rest.find(|c: char| c.is_whitespace() || c == '/' || c == '>' || c == '=').unwrap_or(rest.len());
+        if len == 0 {
+            self.error("expected a name")
+        } else {
+            self.position += len;
+            Ok(&rest[..len])
+        }
+    }
+
+    fn parse_element(&mut self) -> EncodingResult<XmlNode> {
+        if !self.rest().starts_with('<') {
+            return self.error("expected an element");
+        }
+        self.position += 1;
+        let mut element = XmlNode::new(self.parse_name()?);
+
+        // Attributes
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            } else if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let name = self.parse_name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return self.error("expected = after an attribute name");
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => quote,
+                _ => return self.error("expected a quoted attribute value"),
+            };
+            self.position += 1;
+            let value = self.skip_past(if quote == '"' { "\"" } else { "'" })?;
+            element.attributes.push((name.to_string(), self.unescape(value)?));
+        }
+
+        // Content
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.parse_name()?;
+                if name != element.name {
+                    return self.error(&format!("end tag {} does not match start tag {}", name, element.name));
+                }
+                self.skip_whitespace();
+                self.skip_past(">")?;
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += 9;
+                let text = self.skip_past("]]>")?;
+                element.text.push_str(text);
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with('<') {
+                if self.depth == MAX_ELEMENT_DEPTH {
+                    return self.error(&format!("elements are nested more than {} deep", MAX_ELEMENT_DEPTH));
+                }
+                self.depth += 1;
+                let child = self.parse_element()?;
+                self.depth -= 1;
+                element.children.push(child);
+            } else if rest.is_empty() {
+                return self.error(&format!("element {} is not closed", element.name));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                self.position += len;
+                let text = self.unescape(&rest[..len])?;
+                element.text.push_str(&text);
+            }
+        }
+    }
+
+    /// Resolves the entities and character references of text
+    fn unescape(&self, text: &str) -> EncodingResult<String> {
+        let mut unescaped = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(pos) = rest.find('&') {
+            unescaped.push_str(&rest[..pos]);
+            rest = &rest[pos..];
+            let end = match rest.find(';') {
+                Some(end) => end,
+                None => return self.error("unterminated entity"),
+            };
+            let c = match &rest[1..end] {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                entity if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16).ok().and_then(std::char::from_u32),
+                entity if entity.starts_with('#') => entity[1..].parse::<u32>().ok().and_then(std::char::from_u32),
+                _ => None,
+            };
+            if let Some(c) = c {
+                unescaped.push(c);
+            } else {
+                return self.error(&format!("unknown entity {}", &rest[..=end]));
+            }
+            rest = &rest[end + 1..];
+        }
+        unescaped.push_str(rest);
+        Ok(unescaped)
+    }
+}
+
+/// OPC UA XML encoding interface. Anything that encodes to XML must implement this.
+pub trait XmlEncoder {
+    /// Encodes the instance as an element with the name, or returns `None` for a null value
+    /// which is omitted from structures.
+    fn encode_xml(&self, name: &str) -> Option<XmlNode>;
+    /// Convenience method for encoding straight into an XML string. A null value is an empty
+    /// element.
+    fn to_xml_string(&self, name: &str) -> String {
+        self.encode_xml(name).unwrap_or_else(|| XmlNode::new(name)).to_xml_string()
+    }
+}
+
+/// OPC UA XML decoding interface. Anything that decodes from XML must implement this.
+pub trait XmlDecoder: Sized {
+    /// Decodes an instance from an element. An absent element, i.e. `None`, decodes to the null or
+    /// default value of the type. The decoding limits restrict the length of strings, byte strings
+    /// and arrays, and exceeding them or any malformed value is a `BadDecodingError`.
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self>;
+    /// Convenience method for decoding from an XML string
+    fn from_xml_str(xml: &str, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        let element = XmlNode::parse(xml)?;
+        Self::decode_xml(Some(&element), decoding_limits)
+    }
+}
+
+/// Encodes an array of the encoded type as an element holding an element for each value named
+/// after the type, e.g. `<Names><String>a</String><String>b</String></Names>`. A null array is
+/// `None`.
+pub fn encode_xml_array<T: XmlEncoder>(name: &str, element_name: &str, values: &Option<Vec<T>>) -> Option<XmlNode> {
+    values.as_ref().map(|values| {
+        let mut element = XmlNode::new(name);
+        for value in values {
+            element.children.push(value.encode_xml(element_name).unwrap_or_else(|| XmlNode::new(element_name)));
+        }
+        element
+    })
+}
+
+/// Decodes an array of the encoded type from the child elements of an element. An absent
+/// element is a null array.
+pub fn decode_xml_array<T: XmlDecoder>(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Option<Vec<T>>> {
+    if let Some(element) = element {
+        if element.children.len() > decoding_limits.max_array_length as usize {
+            error!("Array length {} exceeds decoding limit {}", element.children.len(), decoding_limits.max_array_length);
+            Err(StatusCode::BadDecodingError)
+        } else {
+            let values = element.children.iter()
+                .map(|value| T::decode_xml(Some(value), decoding_limits))
+                .collect::<EncodingResult<Vec<T>>>()?;
+            Ok(Some(values))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// Decodes an optional value, which is `None` when the element is absent
+pub fn decode_xml_option<T: XmlDecoder>(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Option<T>> {
+    if element.is_some() {
+        Ok(Some(T::decode_xml(element, decoding_limits)?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the child element with the local name of an element, or `None` if either is absent
+pub fn xml_child<'a>(element: Option<&'a XmlNode>, name: &str) -> Option<&'a XmlNode> {
+    element.and_then(|element| element.child(name))
+}
+
+/// Returns the trimmed text of an element, or `None` if the element is absent or holds no text
+pub fn xml_text(element: Option<&XmlNode>) -> Option<&str> {
+    element.map(|element| element.text.trim()).filter(|text| !text.is_empty())
+}
+
+/// Encodes an enumeration as its name and value joined by an underscore, e.g. "Server_1"
+pub fn encode_xml_enum<T: Debug>(name: &str, value: &T, number: i32) -> Option<XmlNode> {
+    Some(XmlNode::new_text(name, format!("{:?}_{}", value, number)))
+}
+
+/// Decodes an enumeration from its value, which follows the last underscore of the text if
+/// there is one. Enumerations are Int32s so the value is decoded by the enumeration's binary
+/// decoder, which checks that it is one of the enumeration's values.
+pub fn decode_xml_enum<T: BinaryEncoder<T>>(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<T> {
+    let value = if let Some(text) = xml_text(element) {
+        let number = if let Some(pos) = text.rfind('_') { &text[pos + 1..] } else { text };
+        number.parse::<i32>().map_err(|_| {
+            error!("Enumeration value {} is invalid", text);
+            StatusCode::BadDecodingError
+        })?
+    } else {
+        0
+    };
+    let mut stream = Cursor::new(Vec::with_capacity(4));
+    write_i32(&mut stream, value)?;
+    stream.set_position(0);
+    T::decode(&mut stream, decoding_limits)
+}
+
+/// Implements `XmlEncoder` and `XmlDecoder` for an enumeration that has a binary encoder
+macro_rules! xml_enum_impl {
+    ($t: ty) => {
+        impl $crate::xml::XmlEncoder for $t {
+            fn encode_xml(&self, name: &str) -> Option<$crate::xml::XmlNode> {
+                $crate::xml::encode_xml_enum(name, self, *self as i32)
+            }
+        }
+
+        impl $crate::xml::XmlDecoder for $t {
+            fn decode_xml(element: Option<&$crate::xml::XmlNode>, decoding_limits: &$crate::encoding::DecodingLimits) -> $crate::encoding::EncodingResult<Self> {
+                $crate::xml::decode_xml_enum(element, decoding_limits)
+            }
+        }
+    }
+}
+
+/// Decodes a number from the text of an element. An absent or empty element is the number's
+/// default.
+fn decode_xml_number<T: FromStr + Default>(element: Option<&XmlNode>) -> EncodingResult<T> {
+    if let Some(text) = xml_text(element) {
+        text.parse::<T>().map_err(|_| {
+            error!("XML value {} is not a number in range of its type", text);
+            StatusCode::BadDecodingError
+        })
+    } else {
+        Ok(T::default())
+    }
+}
+
+impl XmlEncoder for bool {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        Some(XmlNode::new_text(name, if *self { "true" } else { "false" }))
+    }
+}
+
+impl XmlDecoder for bool {
+    fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+        match xml_text(element) {
+            None | Some("false") | Some("0") => Ok(false),
+            Some("true") | Some("1") => Ok(true),
+            Some(text) => {
+                error!("XML value {} is not a boolean", text);
+                Err(StatusCode::BadDecodingError)
+            }
+        }
+    }
+}
+
+macro_rules! xml_number_impl {
+    ($t: ty) => {
+        impl XmlEncoder for $t {
+            fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+                Some(XmlNode::new_text(name, self.to_string()))
+            }
+        }
+
+        impl XmlDecoder for $t {
+            fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+                decode_xml_number(element)
+            }
+        }
+    }
+}
+
+xml_number_impl!(i8);
+xml_number_impl!(u8);
+xml_number_impl!(i16);
+xml_number_impl!(u16);
+xml_number_impl!(i32);
+xml_number_impl!(u32);
+xml_number_impl!(i64);
+xml_number_impl!(u64);
+
+/// Floating point values are XML schema floats and doubles whose NaN and infinities are "NaN",
+/// "INF" and "-INF"
+macro_rules! xml_float_impl {
+    ($t: ty) => {
+        impl XmlEncoder for $t {
+            fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+                let text = if self.is_nan() {
+                    "NaN".to_string()
+                } else if self.is_infinite() {
+                    if self.is_sign_positive() { "INF" } else { "-INF" }.to_string()
+                } else {
+                    self.to_string()
+                };
+                Some(XmlNode::new_text(name, text))
+            }
+        }
+
+        impl XmlDecoder for $t {
+            fn decode_xml(element: Option<&XmlNode>, _: &DecodingLimits) -> EncodingResult<Self> {
+                match xml_text(element) {
+                    Some("NaN") => Ok(<$t>::NAN),
+                    Some("INF") => Ok(<$t>::INFINITY),
+                    Some("-INF") => Ok(<$t>::NEG_INFINITY),
+                    _ => decode_xml_number(element)
+                }
+            }
+        }
+    }
+}
+
+xml_float_impl!(f32);
+xml_float_impl!(f64);
+
+impl<T: XmlEncoder> XmlEncoder for Box<T> {
+    fn encode_xml(&self, name: &str) -> Option<XmlNode> {
+        self.as_ref().encode_xml(name)
+    }
+}
+
+impl<T: XmlDecoder> XmlDecoder for Box<T> {
+    fn decode_xml(element: Option<&XmlNode>, decoding_limits: &DecodingLimits) -> EncodingResult<Self> {
+        Ok(Box::new(T::decode_xml(element, decoding_limits)?))
+    }
+}